@@ -1,3 +1,5 @@
+use crate::analyzer_error::AnalyzerError;
+use miette::Diagnostic;
 use std::cell::RefCell;
 use veryl_parser::resource_table::{self, StrId};
 
@@ -37,3 +39,11 @@ pub fn contains(text: &str) -> bool {
         false
     }
 }
+
+/// Whether the error is suppressed by `#[allow]` attribute at the current position
+pub fn allows(error: &AnalyzerError) -> bool {
+    match error.code() {
+        Some(code) => contains(&code.to_string()),
+        None => false,
+    }
+}
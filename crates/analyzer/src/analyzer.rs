@@ -1,10 +1,14 @@
 use crate::analyzer_error::AnalyzerError;
+use crate::dependency_table;
+use crate::feature_table;
 use crate::handlers::*;
+use crate::namespace::Namespace;
 use crate::namespace_table;
-use crate::symbol::SymbolKind;
+use crate::symbol::{Direction, SymbolKind};
 use crate::symbol_table;
+use miette::{Diagnostic, Severity};
 use std::path::Path;
-use veryl_metadata::{Lint, Metadata};
+use veryl_metadata::{Build, Lint, LintLevel, Metadata};
 use veryl_parser::resource_table;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::VerylToken;
@@ -15,14 +19,18 @@ pub struct AnalyzerPass1<'a> {
 }
 
 impl<'a> AnalyzerPass1<'a> {
-    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+    pub fn new(text: &'a str, build_opt: &'a Build, lint_opt: &'a Lint) -> Self {
         AnalyzerPass1 {
-            handlers: Pass1Handlers::new(text, lint_opt),
+            handlers: Pass1Handlers::new(text, build_opt, lint_opt),
         }
     }
 }
 
 impl<'a> VerylWalker for AnalyzerPass1<'a> {
+    fn is_enabled(&mut self, arg: &Attribute) -> bool {
+        feature_table::is_enabled(arg)
+    }
+
     fn get_handlers(&mut self) -> Option<Vec<&mut dyn Handler>> {
         Some(self.handlers.get_handlers())
     }
@@ -33,20 +41,25 @@ pub struct AnalyzerPass2<'a> {
 }
 
 impl<'a> AnalyzerPass2<'a> {
-    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+    pub fn new(text: &'a str, build_opt: &'a Build) -> Self {
         AnalyzerPass2 {
-            handlers: Pass2Handlers::new(text, lint_opt),
+            handlers: Pass2Handlers::new(text, build_opt),
         }
     }
 }
 
 impl<'a> VerylWalker for AnalyzerPass2<'a> {
+    fn is_enabled(&mut self, arg: &Attribute) -> bool {
+        feature_table::is_enabled(arg)
+    }
+
     fn get_handlers(&mut self) -> Option<Vec<&mut dyn Handler>> {
         Some(self.handlers.get_handlers())
     }
 }
 
 pub struct Analyzer {
+    build_opt: Build,
     lint_opt: Lint,
 }
 
@@ -54,6 +67,8 @@ impl Analyzer {
     pub fn new<T: AsRef<str>>(project_name: &T, metadata: &Metadata) -> Self {
         let ids = vec![resource_table::insert_str(project_name.as_ref())];
         namespace_table::set_default(&ids);
+        let declared = metadata.features.keys().cloned().collect();
+        feature_table::set(&metadata.enabled_features, &declared);
         for locks in metadata.lockfile.lock_table.values() {
             for lock in locks {
                 let prj = resource_table::insert_str(&lock.name);
@@ -67,6 +82,7 @@ impl Analyzer {
             }
         }
         Analyzer {
+            build_opt: metadata.build.clone(),
             lint_opt: metadata.lint.clone(),
         }
     }
@@ -79,11 +95,11 @@ impl Analyzer {
     ) -> Vec<AnalyzerError> {
         let mut ret = Vec::new();
 
-        let mut pass1 = AnalyzerPass1::new(text, &self.lint_opt);
+        let mut pass1 = AnalyzerPass1::new(text, &self.build_opt, &self.lint_opt);
         pass1.veryl(input);
         ret.append(&mut pass1.handlers.get_errors());
 
-        ret
+        self.apply_rules(ret)
     }
 
     pub fn analyze_pass2<T: AsRef<Path>>(
@@ -94,11 +110,11 @@ impl Analyzer {
     ) -> Vec<AnalyzerError> {
         let mut ret = Vec::new();
 
-        let mut pass2 = AnalyzerPass2::new(text, &self.lint_opt);
+        let mut pass2 = AnalyzerPass2::new(text, &self.build_opt);
         pass2.veryl(input);
         ret.append(&mut pass2.handlers.get_errors());
 
-        ret
+        self.apply_rules(ret)
     }
 
     pub fn analyze_pass3<T: AsRef<Path>>(
@@ -110,7 +126,26 @@ impl Analyzer {
         let mut ret = Vec::new();
 
         ret.append(&mut Analyzer::check_symbol_table(path.as_ref(), text));
+        ret.append(&mut Analyzer::check_combinational_loop(path.as_ref(), text));
 
+        self.apply_rules(ret)
+    }
+
+    /// Applies severities configured by `[lint.rules]` of Veryl.toml
+    fn apply_rules(&self, errors: Vec<AnalyzerError>) -> Vec<AnalyzerError> {
+        let mut ret = Vec::new();
+        for x in errors {
+            let level = x
+                .code()
+                .and_then(|code| self.lint_opt.level(&code.to_string()));
+            let error = matches!(x.severity(), Some(Severity::Error) | None);
+            match level {
+                Some(LintLevel::Allow) => (),
+                Some(LintLevel::Warn) if error => ret.push(AnalyzerError::warned(x)),
+                Some(LintLevel::Deny) if !error => ret.push(AnalyzerError::denied(x)),
+                _ => ret.push(x),
+            }
+        }
         ret
     }
 
@@ -118,28 +153,84 @@ impl Analyzer {
         let path = resource_table::get_path_id(path.to_path_buf()).unwrap();
         let mut ret = Vec::new();
         let symbols = symbol_table::get_all();
+
+        // namespaces of modules declared in the file
+        let modules: Vec<Namespace> = symbols
+            .iter()
+            .filter(|x| {
+                x.token.file_path == path
+                    && matches!(x.kind, SymbolKind::Module(ref x) if !x.is_extern)
+            })
+            .map(|x| {
+                let mut namespace = x.namespace.clone();
+                namespace.push(x.token.text);
+                namespace
+            })
+            .collect();
+
         for symbol in symbols {
             if symbol.token.file_path == path {
-                if let SymbolKind::Variable(_) = symbol.kind {
-                    if symbol.references.is_empty() && !symbol.allow_unused {
-                        let name = format!("{}", symbol.token.text);
-                        if name.starts_with('_') {
+                let name = format!("{}", symbol.token.text);
+                let token = VerylToken {
+                    token: symbol.token,
+                    comments: Vec::new(),
+                };
+                match symbol.kind {
+                    SymbolKind::Variable(_) => {
+                        if symbol.references.is_empty() && !symbol.allow_unused {
+                            if name.starts_with('_') {
+                                continue;
+                            }
+
+                            ret.push(AnalyzerError::unused_variable(&name, text, &token));
+                        } else if symbol.assignments.is_empty()
+                            && !symbol.references.is_empty()
+                            && !symbol.allow_undriven_variable
+                            && modules.iter().any(|x| symbol.namespace.included(x))
+                        {
+                            ret.push(AnalyzerError::undriven_variable(&name, text, &token));
+                        }
+                    }
+                    SymbolKind::Port(ref x) => {
+                        if !modules.iter().any(|x| symbol.namespace.matched(x)) {
                             continue;
                         }
-
-                        let token = VerylToken {
-                            token: symbol.token,
-                            comments: Vec::new(),
-                        };
-                        ret.push(AnalyzerError::unused_variable(
-                            &format!("{}", symbol.token.text),
-                            text,
-                            &token,
-                        ));
+                        let input = matches!(x.direction, Direction::Input);
+                        let output = matches!(x.direction, Direction::Output);
+                        if input
+                            && symbol.reads().is_empty()
+                            && !symbol.allow_unused_input
+                            && !name.starts_with('_')
+                        {
+                            ret.push(AnalyzerError::unused_input(&name, text, &token));
+                        } else if output
+                            && symbol.assignments.is_empty()
+                            && !symbol.allow_undriven_output
+                        {
+                            ret.push(AnalyzerError::undriven_output(&name, text, &token));
+                        }
                     }
+                    _ => (),
                 }
             }
         }
         ret
     }
+
+    fn check_combinational_loop(path: &Path, text: &str) -> Vec<AnalyzerError> {
+        let path = resource_table::get_path_id(path.to_path_buf()).unwrap();
+        let mut ret = Vec::new();
+        for x in dependency_table::get_loops(path) {
+            let token = VerylToken {
+                token: x.dependencies[0].token,
+                comments: Vec::new(),
+            };
+            ret.push(AnalyzerError::combinational_loop(
+                &format!("{x}"),
+                text,
+                &token,
+            ));
+        }
+        ret
+    }
 }
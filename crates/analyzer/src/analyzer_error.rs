@@ -5,6 +5,107 @@ use veryl_parser::veryl_token::VerylToken;
 
 #[derive(Error, Diagnostic, Debug)]
 pub enum AnalyzerError {
+    #[diagnostic(
+        severity(Error),
+        code(assignment_to_input),
+        help("remove the assignment or change the direction"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#assignment_to_input")
+    )]
+    #[error("\"{identifier}\" is input and can't be assigned")]
+    AssignmentToInput {
+        identifier: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(clock_domain_crossing),
+        help("insert a synchronizer marked by #[synchronizer]"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#clock_domain_crossing")
+    )]
+    #[error("\"{identifier}\" belongs to clock domain '{domain}, but it is used in clock domain '{expected}")]
+    ClockDomainCrossing {
+        identifier: String,
+        domain: String,
+        expected: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(combinational_loop),
+        help("insert a flip-flop or remove one of the dependencies"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#combinational_loop")
+    )]
+    #[error("combinational loop is detected: {path}")]
+    CombinationalLoop {
+        path: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(conflicting_generic_instance),
+        help("rename the generic argument"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#conflicting_generic_instance")
+    )]
+    #[error("generic instance \"{name}\" conflicts with \"{previous}\" as \"{mangled}\"")]
+    ConflictingGenericInstance {
+        name: String,
+        previous: String,
+        mangled: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(severity(Error), forward(error))]
+    #[error(transparent)]
+    Denied { error: Box<AnalyzerError> },
+
+    #[diagnostic(
+        severity(Error),
+        code(diverged_evaluation),
+        help("check termination conditions of loops and recursive calls"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#diverged_evaluation")
+    )]
+    #[error("evaluation of {identifier} doesn't finish within {limit}")]
+    DivergedEvaluation {
+        identifier: String,
+        limit: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(duplicated_case_item),
+        help("remove the duplicated item"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#duplicated_case_item")
+    )]
+    #[error("case item \"{item}\" is duplicated")]
+    DuplicatedCaseItem {
+        item: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        #[label("Previous item")]
+        previous_location: SourceSpan,
+    },
+
     #[diagnostic(
         severity(Error),
         code(duplicated_identifier),
@@ -20,6 +121,88 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        severity(Warning),
+        code(implicit_extension),
+        help("extend the value explicitly by concatenation"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#implicit_extension")
+    )]
+    #[error("{actual}-bit value is implicitly extended to {expected}-bit \"{identifier}\"")]
+    ImplicitExtension {
+        identifier: String,
+        expected: usize,
+        actual: usize,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(implicit_truncation),
+        help("select the required bits explicitly"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#implicit_truncation")
+    )]
+    #[error("{actual}-bit value is implicitly truncated to {expected}-bit \"{identifier}\"")]
+    ImplicitTruncation {
+        identifier: String,
+        expected: usize,
+        actual: usize,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(include_failure),
+        help(""),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#include_failure")
+    )]
+    #[error("\"{name}\" can't be read because \"{cause}\"")]
+    IncludeFailure {
+        name: String,
+        cause: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(incomplete_case),
+        help("add items for the missing variants or `default` item"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#incomplete_case")
+    )]
+    #[error("case of enum \"{identifier}\" doesn't cover {missing}")]
+    IncompleteCase {
+        identifier: String,
+        missing: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(inferred_latch),
+        help("assign \"{identifier}\" in all branches or before the branches"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#inferred_latch")
+    )]
+    #[error("latch is inferred because \"{identifier}\" is not assigned in {branch}")]
+    InferredLatch {
+        identifier: String,
+        branch: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(
         severity(Error),
         code(invalid_allow),
@@ -37,6 +220,21 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        severity(Error),
+        code(invalid_attribute),
+        help("remove {identifier} attribute"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#invalid_attribute")
+    )]
+    #[error("{identifier} attribute can't be placed at here")]
+    InvalidAttribute {
+        identifier: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(
         severity(Error),
         code(invalid_direction),
@@ -175,6 +373,63 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        severity(Error),
+        code(mismatch_generics_arity),
+        help("fix generic arguments"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#mismatch_generics_arity")
+    )]
+    #[error(
+        "\"{name}\" has {arity} generic parameters, but {args} generic arguments are supplied"
+    )]
+    MismatchGenericsArity {
+        name: String,
+        arity: usize,
+        args: usize,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(mismatch_port_type),
+        help("connect a signal of the port type"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#mismatch_port_type")
+    )]
+    #[error("port \"{port}\" of \"{name}\" is \"{expected}\", but \"{actual}\" is connected")]
+    MismatchPortType {
+        name: String,
+        port: String,
+        expected: String,
+        actual: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(mismatch_port_width),
+        help("adjust the width of the connected value"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#mismatch_port_width")
+    )]
+    #[error(
+        "port \"{port}\" of \"{name}\" is {expected}-bit, but {actual}-bit value is connected"
+    )]
+    MismatchPortWidth {
+        name: String,
+        port: String,
+        expected: usize,
+        actual: usize,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(
         severity(Error),
         code(mismatch_type),
@@ -194,6 +449,24 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        severity(Error),
+        code(mismatch_union_width),
+        help("make all union members the same width"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#mismatch_union_width")
+    )]
+    #[error("union member \"{name}\" is {width} bits, but \"{union}\" is {expected} bits")]
+    MismatchUnionWidth {
+        name: String,
+        union: String,
+        width: usize,
+        expected: usize,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(
         severity(Error),
         code(missing_if_reset),
@@ -255,6 +528,70 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        severity(Error),
+        code(missing_return),
+        help("add return statement to every path"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#missing_return")
+    )]
+    #[error("function \"{name}\" doesn't return a value on every path")]
+    MissingReturn {
+        name: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(multiple_drivers),
+        help("drive \"{identifier}\" from a single block"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#multiple_drivers")
+    )]
+    #[error("\"{identifier}\" is driven by multiple blocks")]
+    MultipleDrivers {
+        identifier: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        #[label("Another driver")]
+        driver_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(override_localparam),
+        help("remove \"{parameter}\" parameter"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#override_localparam")
+    )]
+    #[error("\"{parameter}\" of {kind} \"{name}\" is localparam, but it is overridden")]
+    OverrideLocalparam {
+        kind: String,
+        name: String,
+        parameter: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(reference_to_output),
+        help("change the direction of the modport member to inout"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#reference_to_output")
+    )]
+    #[error("\"{identifier}\" is output-only modport member and can't be read")]
+    ReferenceToOutput {
+        identifier: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(
         severity(Error),
         code(too_large_enum_variant),
@@ -264,7 +601,7 @@ pub enum AnalyzerError {
     #[error("The value of enum variant {identifier} is {value}, it is can't be represented by {width} bits")]
     TooLargeEnumVariant {
         identifier: String,
-        value: isize,
+        value: String,
         width: usize,
         #[source_code]
         input: NamedSource,
@@ -321,6 +658,36 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        severity(Warning),
+        code(undriven_output),
+        help("assign a value to the output port"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#undriven_output")
+    )]
+    #[error("{identifier} is never driven")]
+    UndrivenOutput {
+        identifier: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(undriven_variable),
+        help("assign a value to the variable or remove it"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#undriven_variable")
+    )]
+    #[error("{identifier} is read but never assigned")]
+    UndrivenVariable {
+        identifier: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(
         severity(Error),
         code(unknown_attribute),
@@ -338,14 +705,13 @@ pub enum AnalyzerError {
 
     #[diagnostic(
         severity(Error),
-        code(unknown_member),
+        code(unknown_embed_lang),
         help(""),
-        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_member")
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_embed_lang")
     )]
-    #[error("\"{name}\" doesn't have member \"{member}\"")]
-    UnknownMember {
+    #[error("\"{name}\" is not valid embed language")]
+    UnknownEmbedLang {
         name: String,
-        member: String,
         #[source_code]
         input: NamedSource,
         #[label("Error location")]
@@ -354,12 +720,13 @@ pub enum AnalyzerError {
 
     #[diagnostic(
         severity(Error),
-        code(unknown_msb),
+        code(unknown_embed_way),
         help(""),
-        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_msb")
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_embed_way")
     )]
-    #[error("resolving msb is failed")]
-    UnknownMsb {
+    #[error("\"{name}\" is not valid embed way")]
+    UnknownEmbedWay {
+        name: String,
         #[source_code]
         input: NamedSource,
         #[label("Error location")]
@@ -368,16 +735,13 @@ pub enum AnalyzerError {
 
     #[diagnostic(
         severity(Error),
-        code(unknown_port),
-        help("remove \"{port}\" port"),
-        url(
-            "https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_port"
-        )
+        code(unknown_feature),
+        help("declare the feature in [features] section of Veryl.toml"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_feature")
     )]
-    #[error("module \"{name}\" doesn't have port \"{port}\", but it is connected")]
-    UnknownPort {
+    #[error("\"{name}\" is not declared feature")]
+    UnknownFeature {
         name: String,
-        port: String,
         #[source_code]
         input: NamedSource,
         #[label("Error location")]
@@ -385,10 +749,138 @@ pub enum AnalyzerError {
     },
 
     #[diagnostic(
-        severity(Warning),
-        code(unused_variable),
-        help("add prefix `_` to unused variable name"),
-        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unused_variable")
+        severity(Error),
+        code(unknown_include_way),
+        help(""),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_include_way")
+    )]
+    #[error("\"{name}\" is not valid include way")]
+    UnknownIncludeWay {
+        name: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_member),
+        help(""),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_member")
+    )]
+    #[error("\"{name}\" doesn't have member \"{member}\"")]
+    UnknownMember {
+        name: String,
+        member: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_msb),
+        help(""),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_msb")
+    )]
+    #[error("resolving msb is failed")]
+    UnknownMsb {
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_parameter),
+        help("remove \"{parameter}\" parameter"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_parameter")
+    )]
+    #[error("{kind} \"{name}\" doesn't have parameter \"{parameter}\", but it is overridden")]
+    UnknownParameter {
+        kind: String,
+        name: String,
+        parameter: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_port),
+        help("remove \"{port}\" port"),
+        url(
+            "https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unknown_port"
+        )
+    )]
+    #[error("{kind} \"{name}\" doesn't have port \"{port}\", but it is connected")]
+    UnknownPort {
+        kind: String,
+        name: String,
+        port: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unreachable_code),
+        help("remove statements after return statement"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unreachable_code")
+    )]
+    #[error("statement is unreachable because all paths before it return")]
+    UnreachableCode {
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unsupported_by_target),
+        help("rewrite without it, or use SystemVerilog as target language"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unsupported_by_target")
+    )]
+    #[error("{name} is not supported in {target}")]
+    UnsupportedByTarget {
+        name: String,
+        target: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unused_input),
+        help("add prefix `_` to unused input port name"),
+        url(
+            "https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unused_input"
+        )
+    )]
+    #[error("{identifier} is never read")]
+    UnusedInput {
+        identifier: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unused_variable),
+        help("add prefix `_` to unused variable name"),
+        url("https://dalance.github.io/veryl/book/06_appendix/02_semantic_error.html#unused_variable")
     )]
     #[error("{identifier} is unused")]
     UnusedVariable {
@@ -398,6 +890,10 @@ pub enum AnalyzerError {
         #[label("Error location")]
         error_location: SourceSpan,
     },
+
+    #[diagnostic(severity(Warning), forward(error))]
+    #[error(transparent)]
+    Warned { error: Box<AnalyzerError> },
 }
 
 impl AnalyzerError {
@@ -410,6 +906,89 @@ impl AnalyzerError {
         )
     }
 
+    pub fn assignment_to_input(identifier: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::AssignmentToInput {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn clock_domain_crossing(
+        identifier: &str,
+        domain: &str,
+        expected: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::ClockDomainCrossing {
+            identifier: identifier.to_string(),
+            domain: domain.to_string(),
+            expected: expected.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn combinational_loop(path: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::CombinationalLoop {
+            path: path.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn conflicting_generic_instance(
+        name: &str,
+        previous: &str,
+        mangled: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::ConflictingGenericInstance {
+            name: name.to_string(),
+            previous: previous.to_string(),
+            mangled: mangled.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    /// Raises the severity to error by `deny` lint rule
+    pub fn denied(error: AnalyzerError) -> Self {
+        AnalyzerError::Denied {
+            error: Box::new(error),
+        }
+    }
+
+    pub fn diverged_evaluation(
+        identifier: &str,
+        limit: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::DivergedEvaluation {
+            identifier: identifier.to_string(),
+            limit: limit.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn duplicated_case_item(
+        item: &str,
+        source: &str,
+        token: &VerylToken,
+        previous: &VerylToken,
+    ) -> Self {
+        AnalyzerError::DuplicatedCaseItem {
+            item: item.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+            previous_location: previous.token.into(),
+        }
+    }
+
     pub fn duplicated_identifier(identifier: &str, source: &str, token: &VerylToken) -> Self {
         AnalyzerError::DuplicatedIdentifier {
             identifier: identifier.to_string(),
@@ -418,6 +997,75 @@ impl AnalyzerError {
         }
     }
 
+    pub fn implicit_extension(
+        identifier: &str,
+        expected: usize,
+        actual: usize,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::ImplicitExtension {
+            identifier: identifier.to_string(),
+            expected,
+            actual,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn implicit_truncation(
+        identifier: &str,
+        expected: usize,
+        actual: usize,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::ImplicitTruncation {
+            identifier: identifier.to_string(),
+            expected,
+            actual,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn include_failure(name: &str, cause: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::IncludeFailure {
+            name: name.to_string(),
+            cause: cause.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn incomplete_case(
+        identifier: &str,
+        missing: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::IncompleteCase {
+            identifier: identifier.to_string(),
+            missing: missing.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn inferred_latch(
+        identifier: &str,
+        branch: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::InferredLatch {
+            identifier: identifier.to_string(),
+            branch: branch.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn invalid_allow(identifier: &str, source: &str, token: &VerylToken) -> Self {
         AnalyzerError::InvalidAllow {
             identifier: identifier.to_string(),
@@ -426,6 +1074,14 @@ impl AnalyzerError {
         }
     }
 
+    pub fn invalid_attribute(identifier: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::InvalidAttribute {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn invalid_direction(kind: &str, source: &str, token: &VerylToken) -> Self {
         AnalyzerError::InvalidDirection {
             kind: kind.to_string(),
@@ -508,6 +1164,42 @@ impl AnalyzerError {
         }
     }
 
+    pub fn mismatch_port_type(
+        name: &str,
+        port: &str,
+        expected: &str,
+        actual: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::MismatchPortType {
+            name: name.to_string(),
+            port: port.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn mismatch_port_width(
+        name: &str,
+        port: &str,
+        expected: usize,
+        actual: usize,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::MismatchPortWidth {
+            name: name.to_string(),
+            port: port.to_string(),
+            expected,
+            actual,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn mismatch_type(
         name: &str,
         expected: &str,
@@ -524,6 +1216,24 @@ impl AnalyzerError {
         }
     }
 
+    pub fn mismatch_union_width(
+        name: &str,
+        union: &str,
+        width: usize,
+        expected: usize,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::MismatchUnionWidth {
+            name: name.to_string(),
+            union: union.to_string(),
+            width,
+            expected,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn missing_if_reset(source: &str, token: &VerylToken) -> Self {
         AnalyzerError::MissingIfReset {
             input: AnalyzerError::named_source(source, token),
@@ -546,6 +1256,14 @@ impl AnalyzerError {
         }
     }
 
+    pub fn missing_return(name: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::MissingReturn {
+            name: name.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn mismatch_attribute_args(
         name: &str,
         expected: &str,
@@ -560,6 +1278,22 @@ impl AnalyzerError {
         }
     }
 
+    pub fn mismatch_generics_arity(
+        name: &str,
+        arity: usize,
+        args: usize,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::MismatchGenericsArity {
+            name: name.to_string(),
+            arity,
+            args,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn missing_port(name: &str, port: &str, source: &str, token: &VerylToken) -> Self {
         AnalyzerError::MissingPort {
             name: name.to_string(),
@@ -569,16 +1303,54 @@ impl AnalyzerError {
         }
     }
 
+    pub fn multiple_drivers(
+        identifier: &str,
+        source: &str,
+        token: &VerylToken,
+        driver: &VerylToken,
+    ) -> Self {
+        AnalyzerError::MultipleDrivers {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+            driver_location: driver.token.into(),
+        }
+    }
+
+    pub fn override_localparam(
+        kind: &str,
+        name: &str,
+        parameter: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::OverrideLocalparam {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            parameter: parameter.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn reference_to_output(identifier: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::ReferenceToOutput {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn too_large_enum_variant(
         identifier: &str,
-        value: isize,
+        value: &str,
         width: usize,
         source: &str,
         token: &VerylToken,
     ) -> Self {
         AnalyzerError::TooLargeEnumVariant {
             identifier: identifier.to_string(),
-            value,
+            value: value.to_string(),
             width,
             input: AnalyzerError::named_source(source, token),
             error_location: token.token.into(),
@@ -617,6 +1389,22 @@ impl AnalyzerError {
         }
     }
 
+    pub fn undriven_output(identifier: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::UndrivenOutput {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn undriven_variable(identifier: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::UndrivenVariable {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn unknown_attribute(name: &str, source: &str, token: &VerylToken) -> Self {
         AnalyzerError::UnknownAttribute {
             name: name.to_string(),
@@ -625,6 +1413,38 @@ impl AnalyzerError {
         }
     }
 
+    pub fn unknown_embed_lang(name: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::UnknownEmbedLang {
+            name: name.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn unknown_embed_way(name: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::UnknownEmbedWay {
+            name: name.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn unknown_feature(name: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::UnknownFeature {
+            name: name.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn unknown_include_way(name: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::UnknownIncludeWay {
+            name: name.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn unknown_member(name: &str, member: &str, source: &str, token: &VerylToken) -> Self {
         AnalyzerError::UnknownMember {
             name: name.to_string(),
@@ -641,8 +1461,31 @@ impl AnalyzerError {
         }
     }
 
-    pub fn unknown_port(name: &str, port: &str, source: &str, token: &VerylToken) -> Self {
+    pub fn unknown_parameter(
+        kind: &str,
+        name: &str,
+        parameter: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::UnknownParameter {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            parameter: parameter.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn unknown_port(
+        kind: &str,
+        name: &str,
+        port: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
         AnalyzerError::UnknownPort {
+            kind: kind.to_string(),
             name: name.to_string(),
             port: port.to_string(),
             input: AnalyzerError::named_source(source, token),
@@ -650,6 +1493,35 @@ impl AnalyzerError {
         }
     }
 
+    pub fn unreachable_code(source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::UnreachableCode {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn unsupported_by_target(
+        name: &str,
+        target: &str,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzerError::UnsupportedByTarget {
+            name: name.to_string(),
+            target: target.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn unused_input(identifier: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzerError::UnusedInput {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn unused_variable(identifier: &str, source: &str, token: &VerylToken) -> Self {
         AnalyzerError::UnusedVariable {
             identifier: identifier.to_string(),
@@ -657,4 +1529,11 @@ impl AnalyzerError {
             error_location: token.token.into(),
         }
     }
+
+    /// Lowers the severity to warning by `warn` lint rule
+    pub fn warned(error: AnalyzerError) -> Self {
+        AnalyzerError::Warned {
+            error: Box::new(error),
+        }
+    }
 }
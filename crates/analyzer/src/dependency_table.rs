@@ -0,0 +1,309 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use veryl_parser::resource_table::{PathId, StrId, TokenId};
+use veryl_parser::veryl_token::Token;
+
+/// Bits of a signal in a dependency
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DependencyRange {
+    Whole,
+    Bits(usize, usize),
+    Unknown,
+}
+
+impl DependencyRange {
+    /// Non-constant selects are assumed to be disjoint with other selects
+    pub fn overlaps(&self, other: &DependencyRange) -> bool {
+        match (self, other) {
+            (DependencyRange::Whole, _) | (_, DependencyRange::Whole) => true,
+            (DependencyRange::Bits(lsb0, width0), DependencyRange::Bits(lsb1, width1)) => {
+                lsb0 < &(lsb1 + width1) && lsb1 < &(lsb0 + width0)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Signal {
+    pub id: TokenId,
+    pub name: StrId,
+    pub range: DependencyRange,
+}
+
+/// Path through an instance as `(instance, input port, output port)`
+pub type Through = (StrId, StrId, StrId);
+
+/// `dst` depends on `src` combinationally
+#[derive(Clone, Debug)]
+pub struct Dependency {
+    pub src: Signal,
+    pub dst: Signal,
+    pub token: Token,
+    pub through: Option<Through>,
+}
+
+impl Dependency {
+    fn follows(&self, other: &Dependency) -> bool {
+        other.dst.id == self.src.id && other.dst.range.overlaps(&self.src.range)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Connection {
+    pub port: StrId,
+    pub signals: Vec<Signal>,
+    pub token: Token,
+}
+
+#[derive(Clone, Debug)]
+pub struct Instance {
+    pub name: StrId,
+    pub module: TokenId,
+    pub connections: Vec<Connection>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ModulePort {
+    pub name: StrId,
+    pub id: TokenId,
+    pub input: bool,
+    pub output: bool,
+}
+
+/// Signal dependency graph of a module
+#[derive(Clone, Debug)]
+pub struct DependencyGraph {
+    pub file_path: PathId,
+    pub ports: Vec<ModulePort>,
+    pub dependencies: Vec<Dependency>,
+    pub instances: Vec<Instance>,
+}
+
+impl DependencyGraph {
+    pub fn new(file_path: PathId) -> Self {
+        Self {
+            file_path,
+            ports: Vec::new(),
+            dependencies: Vec::new(),
+            instances: Vec::new(),
+        }
+    }
+}
+
+/// Cycle of dependencies
+#[derive(Clone, Debug)]
+pub struct Loop {
+    pub dependencies: Vec<Dependency>,
+}
+
+impl fmt::Display for Loop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut text = format!("{}", self.dependencies[0].src.name);
+        for x in &self.dependencies {
+            if let Some((instance, input, output)) = x.through {
+                text.push_str(&format!(" -> {instance}.{input} -> {instance}.{output}"));
+            }
+            text.push_str(&format!(" -> {}", x.dst.name));
+        }
+        text.fmt(f)
+    }
+}
+
+/// Combinational paths of a module as `(input port, output port)`
+type Paths = Vec<(StrId, StrId)>;
+
+#[derive(Clone, Default, Debug)]
+pub struct DependencyTable {
+    table: HashMap<TokenId, DependencyGraph>,
+}
+
+impl DependencyTable {
+    pub fn insert(&mut self, id: TokenId, graph: DependencyGraph) {
+        self.table.insert(id, graph);
+    }
+
+    pub fn drop(&mut self, file_path: PathId) {
+        self.table.retain(|_, x| x.file_path != file_path);
+    }
+
+    /// Returns dependencies of the module including ones through instances
+    fn dependencies(
+        &self,
+        id: TokenId,
+        visiting: &mut Vec<TokenId>,
+        cache: &mut HashMap<TokenId, Paths>,
+    ) -> Vec<Dependency> {
+        let graph = match self.table.get(&id) {
+            Some(x) => x,
+            None => return Vec::new(),
+        };
+
+        let mut ret = graph.dependencies.clone();
+        visiting.push(id);
+        for instance in &graph.instances {
+            for (input, output) in self.paths(instance.module, visiting, cache) {
+                let src = instance.connections.iter().find(|x| x.port == input);
+                let dst = instance.connections.iter().find(|x| x.port == output);
+                if let (Some(src), Some(dst)) = (src, dst) {
+                    for x in &src.signals {
+                        for y in &dst.signals {
+                            ret.push(Dependency {
+                                src: *x,
+                                dst: *y,
+                                token: dst.token,
+                                through: Some((instance.name, input, output)),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        visiting.pop();
+        ret
+    }
+
+    /// Returns combinational paths from input ports to output ports of the module
+    fn paths(
+        &self,
+        id: TokenId,
+        visiting: &mut Vec<TokenId>,
+        cache: &mut HashMap<TokenId, Paths>,
+    ) -> Paths {
+        // recursive instantiation has no path to avoid infinite recursion
+        if visiting.contains(&id) {
+            return Vec::new();
+        }
+        if let Some(x) = cache.get(&id) {
+            return x.clone();
+        }
+        let graph = match self.table.get(&id) {
+            Some(x) => x,
+            None => return Vec::new(),
+        };
+
+        let dependencies = self.dependencies(id, visiting, cache);
+        let successors = Self::successors(&dependencies);
+        let mut ret = Vec::new();
+        for input in graph.ports.iter().filter(|x| x.input) {
+            let mut visited = HashSet::new();
+            let mut queue: Vec<usize> = (0..dependencies.len())
+                .filter(|x| dependencies[*x].src.id == input.id)
+                .collect();
+            while let Some(x) = queue.pop() {
+                if !visited.insert(x) {
+                    continue;
+                }
+                let dst = dependencies[x].dst.id;
+                if let Some(output) = graph.ports.iter().find(|y| y.output && y.id == dst) {
+                    if !ret.contains(&(input.name, output.name)) {
+                        ret.push((input.name, output.name));
+                    }
+                }
+                queue.extend(successors[x].iter().cloned());
+            }
+        }
+
+        cache.insert(id, ret.clone());
+        ret
+    }
+
+    pub fn get_loops(&self, file_path: PathId) -> Vec<Loop> {
+        let mut ids: Vec<TokenId> = self
+            .table
+            .iter()
+            .filter(|(_, x)| x.file_path == file_path)
+            .map(|(x, _)| *x)
+            .collect();
+        ids.sort();
+
+        let mut ret = Vec::new();
+        let mut cache = HashMap::new();
+        for id in ids {
+            let dependencies = self.dependencies(id, &mut Vec::new(), &mut cache);
+            for x in Self::find_loops(&dependencies) {
+                ret.push(Loop {
+                    dependencies: x.iter().map(|x| dependencies[*x].clone()).collect(),
+                });
+            }
+        }
+        ret
+    }
+
+    /// Returns indices of dependencies which follow each dependency
+    fn successors(dependencies: &[Dependency]) -> Vec<Vec<usize>> {
+        let mut sources: HashMap<TokenId, Vec<usize>> = HashMap::new();
+        for (i, x) in dependencies.iter().enumerate() {
+            sources.entry(x.src.id).or_default().push(i);
+        }
+        dependencies
+            .iter()
+            .map(|x| match sources.get(&x.dst.id) {
+                Some(y) => y
+                    .iter()
+                    .filter(|y| dependencies[**y].follows(x))
+                    .cloned()
+                    .collect(),
+                None => Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Finds loops by depth-first search over dependencies.
+    /// Loops sharing a dependency with a found loop are not reported.
+    fn find_loops(dependencies: &[Dependency]) -> Vec<Vec<usize>> {
+        let successors = Self::successors(dependencies);
+
+        let mut ret = Vec::new();
+        let mut reported = HashSet::new();
+        let mut visited = vec![false; dependencies.len()];
+        for start in 0..dependencies.len() {
+            if visited[start] {
+                continue;
+            }
+
+            // stack of (dependency, index of the next successor)
+            let mut stack = vec![(start, 0)];
+            let mut on_stack = HashSet::new();
+            visited[start] = true;
+            on_stack.insert(start);
+            while let Some((x, i)) = stack.last_mut() {
+                let x = *x;
+                if let Some(y) = successors[x].get(*i).cloned() {
+                    *i += 1;
+                    if on_stack.contains(&y) {
+                        let begin = stack.iter().position(|(z, _)| *z == y).unwrap();
+                        let cycle: Vec<usize> = stack[begin..].iter().map(|(z, _)| *z).collect();
+                        if cycle.iter().all(|z| !reported.contains(z)) {
+                            reported.extend(cycle.iter().cloned());
+                            ret.push(cycle);
+                        }
+                    } else if !visited[y] {
+                        visited[y] = true;
+                        on_stack.insert(y);
+                        stack.push((y, 0));
+                    }
+                } else {
+                    on_stack.remove(&x);
+                    stack.pop();
+                }
+            }
+        }
+        ret
+    }
+}
+
+thread_local!(static DEPENDENCY_TABLE: RefCell<DependencyTable> = RefCell::new(DependencyTable::default()));
+
+pub fn insert(id: TokenId, graph: DependencyGraph) {
+    DEPENDENCY_TABLE.with(|f| f.borrow_mut().insert(id, graph))
+}
+
+pub fn drop(file_path: PathId) {
+    DEPENDENCY_TABLE.with(|f| f.borrow_mut().drop(file_path))
+}
+
+pub fn get_loops(file_path: PathId) -> Vec<Loop> {
+    DEPENDENCY_TABLE.with(|f| f.borrow().get_loops(file_path))
+}
@@ -1,279 +1,399 @@
-use crate::symbol::{Type, TypeKind};
+use crate::symbol::{Direction, Parameter, ParameterValue, Symbol, SymbolKind, Type, TypeKind};
 use crate::symbol_table;
+use crate::value::Value;
+use num_bigint::BigUint;
+use num_traits::{One, Signed, Zero};
+use std::collections::HashMap;
+use std::fmt;
+use veryl_parser::resource_table::{StrId, TokenId};
 use veryl_parser::veryl_grammar_trait::*;
 
-#[derive(Clone, Copy, Debug)]
+/// Maximum depth of nested function calls in constant evaluation
+pub const MAX_CALL_DEPTH: usize = 64;
+
+/// Maximum number of loop iterations in constant evaluation
+pub const MAX_LOOP_ITERATIONS: usize = 65536;
+
+/// Maximum width of concatenation in constant evaluation
+pub const MAX_CONCATENATION_WIDTH: usize = 1 << 24;
+
+/// Maximum number of evaluated factors and statements in constant evaluation
+pub const MAX_EVALUATION_STEPS: usize = 1 << 20;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Evaluated {
-    Fixed { width: usize, value: isize },
+    Fixed(Value),
     Variable { width: usize },
     Unknown,
 }
 
 impl Evaluated {
-    fn binary_op<T: Fn(usize, usize) -> usize, U: Fn(isize, isize) -> Option<isize>>(
+    pub fn width(&self) -> Option<usize> {
+        match self {
+            Evaluated::Fixed(x) => Some(x.width),
+            Evaluated::Variable { width } => Some(*width),
+            Evaluated::Unknown => None,
+        }
+    }
+
+    pub fn cast(self, width: usize, signed: bool) -> Evaluated {
+        match self {
+            Evaluated::Fixed(x) => Evaluated::Fixed(x.cast(width, signed)),
+            Evaluated::Variable { .. } => Evaluated::Variable { width },
+            Evaluated::Unknown => Evaluated::Unknown,
+        }
+    }
+
+    fn concat(items: Vec<Evaluated>) -> Evaluated {
+        let mut ret = Evaluated::Fixed(Value::new(BigUint::zero(), 0, false));
+        for x in items {
+            ret = match (ret, x) {
+                (Evaluated::Fixed(x), Evaluated::Fixed(y)) => Evaluated::Fixed(x.concat(&y)),
+                (x, y) => match (x.width(), y.width()) {
+                    (Some(x), Some(y)) => Evaluated::Variable { width: x + y },
+                    _ => return Evaluated::Unknown,
+                },
+            };
+        }
+        ret
+    }
+
+    /// Replicates the value `count` times like `{x repeat count}`
+    fn repeat(self, count: usize) -> Evaluated {
+        let width = match self.width().and_then(|x| x.checked_mul(count)) {
+            Some(x) if x <= MAX_CONCATENATION_WIDTH => x,
+            _ => return Evaluated::Unknown,
+        };
+        match self {
+            Evaluated::Fixed(x) => {
+                // concatenate by doubling to avoid allocation for each repetition
+                let mut ret = Value::new(BigUint::zero(), 0, false);
+                let mut unit = x;
+                let mut count = count;
+                while count > 0 {
+                    if count & 1 == 1 {
+                        ret = ret.concat(&unit);
+                    }
+                    count >>= 1;
+                    if count > 0 {
+                        unit = unit.concat(&unit);
+                    }
+                }
+                Evaluated::Fixed(ret)
+            }
+            _ => Evaluated::Variable { width },
+        }
+    }
+
+    /// Shrinks a non-negative constant to the minimum width to represent it
+    fn minimize(self) -> Evaluated {
+        match self {
+            Evaluated::Fixed(x)
+                if !x.is_xz() && !x.to_bigint().map(|x| x.is_negative()).unwrap_or(true) =>
+            {
+                let width = (x.payload.bits() as usize).max(1);
+                Evaluated::Fixed(x.cast(width, false))
+            }
+            x => x,
+        }
+    }
+
+    fn binary_op<T: Fn(usize, usize) -> usize, U: Fn(&Value, &Value) -> Value>(
         left: Evaluated,
         right: Evaluated,
         width: T,
         value: U,
     ) -> Evaluated {
-        if let (
-            Evaluated::Fixed {
-                width: width0,
-                value: value0,
-            },
-            Evaluated::Fixed {
-                width: width1,
-                value: value1,
-            },
-        ) = (left, right)
-        {
-            let value = value(value0, value1);
-            if let Some(value) = value {
-                Evaluated::Fixed {
-                    width: width(width0, width1),
-                    value,
-                }
-            } else {
-                Evaluated::Variable {
-                    width: width(width0, width1),
-                }
-            }
-        } else if let (
-            Evaluated::Fixed { width: width0, .. },
-            Evaluated::Variable { width: width1 },
-        ) = (left, right)
-        {
-            Evaluated::Variable {
-                width: width(width0, width1),
-            }
-        } else if let (
-            Evaluated::Variable { width: width0 },
-            Evaluated::Fixed { width: width1, .. },
-        ) = (left, right)
-        {
-            Evaluated::Variable {
-                width: width(width0, width1),
-            }
-        } else if let (
-            Evaluated::Variable { width: width0 },
-            Evaluated::Variable { width: width1 },
-        ) = (left, right)
-        {
-            Evaluated::Variable {
-                width: width(width0, width1),
-            }
+        if let (Evaluated::Fixed(x), Evaluated::Fixed(y)) = (&left, &right) {
+            Evaluated::Fixed(value(x, y))
+        } else if let (Some(x), Some(y)) = (left.width(), right.width()) {
+            Evaluated::Variable { width: width(x, y) }
         } else {
             Evaluated::Unknown
         }
     }
 
-    fn unary_op<T: Fn(usize) -> usize, U: Fn(isize) -> Option<isize>>(
+    fn unary_op<T: Fn(usize) -> usize, U: Fn(&Value) -> Value>(
         left: Evaluated,
         width: T,
         value: U,
     ) -> Evaluated {
-        if let Evaluated::Fixed {
-            width: width0,
-            value: value0,
-        } = left
-        {
-            let value = value(value0);
-            if let Some(value) = value {
-                Evaluated::Fixed {
-                    width: width(width0),
-                    value,
-                }
-            } else {
-                Evaluated::Variable {
-                    width: width(width0),
-                }
-            }
-        } else if let Evaluated::Variable { width: width0 } = left {
-            Evaluated::Variable {
-                width: width(width0),
-            }
-        } else {
-            Evaluated::Unknown
+        match left {
+            Evaluated::Fixed(x) => Evaluated::Fixed(value(&x)),
+            Evaluated::Variable { width: x } => Evaluated::Variable { width: width(x) },
+            Evaluated::Unknown => Evaluated::Unknown,
         }
     }
 
-    fn pow(self, exp: Evaluated) -> Evaluated {
+    fn pow(self, exp: Evaluated, context: usize) -> Evaluated {
+        Self::binary_op(self, exp, |x, _| x.max(context), |x, y| x.pow(y, context))
+    }
+
+    fn div(self, exp: Evaluated, context: usize) -> Evaluated {
         Self::binary_op(
             self,
             exp,
-            |x, _| x,
-            |x, y| y.try_into().map(|y| x.checked_pow(y)).ok().flatten(),
+            |x, y| x.max(y).max(context),
+            |x, y| x.div(y, context),
         )
     }
 
-    fn div(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| x.checked_div(y))
-    }
-
-    fn rem(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| x.checked_rem(y))
-    }
-
-    fn mul(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| x.checked_mul(y))
-    }
-
-    fn add(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| x.checked_add(y))
+    fn rem(self, exp: Evaluated, context: usize) -> Evaluated {
+        Self::binary_op(
+            self,
+            exp,
+            |x, y| x.max(y).max(context),
+            |x, y| x.rem(y, context),
+        )
     }
 
-    fn sub(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| x.checked_sub(y))
+    fn mul(self, exp: Evaluated, context: usize) -> Evaluated {
+        Self::binary_op(
+            self,
+            exp,
+            |x, y| x.max(y).max(context),
+            |x, y| x.mul(y, context),
+        )
     }
 
-    fn unsigned_shl(self, exp: Evaluated) -> Evaluated {
+    fn add(self, exp: Evaluated, context: usize) -> Evaluated {
         Self::binary_op(
             self,
             exp,
-            |x, _| x,
-            |x, y| {
-                y.try_into()
-                    .map(|y| (x as usize).checked_shl(y).map(|x| x as isize))
-                    .ok()
-                    .flatten()
-            },
+            |x, y| x.max(y).max(context),
+            |x, y| x.add(y, context),
         )
     }
 
-    fn unsigned_shr(self, exp: Evaluated) -> Evaluated {
+    fn sub(self, exp: Evaluated, context: usize) -> Evaluated {
         Self::binary_op(
             self,
             exp,
-            |x, _| x,
-            |x, y| {
-                y.try_into()
-                    .map(|y| (x as usize).checked_shr(y).map(|x| x as isize))
-                    .ok()
-                    .flatten()
-            },
+            |x, y| x.max(y).max(context),
+            |x, y| x.sub(y, context),
         )
     }
 
-    fn signed_shl(self, exp: Evaluated) -> Evaluated {
+    fn shl(self, exp: Evaluated, context: usize) -> Evaluated {
+        Self::binary_op(self, exp, |x, _| x.max(context), |x, y| x.shl(y, context))
+    }
+
+    fn unsigned_shr(self, exp: Evaluated, context: usize) -> Evaluated {
         Self::binary_op(
             self,
             exp,
-            |x, _| x,
-            |x, y| y.try_into().map(|y| x.checked_shl(y)).ok().flatten(),
+            |x, _| x.max(context),
+            |x, y| x.shr(y, context, false),
         )
     }
 
-    fn signed_shr(self, exp: Evaluated) -> Evaluated {
+    fn signed_shr(self, exp: Evaluated, context: usize) -> Evaluated {
         Self::binary_op(
             self,
             exp,
-            |x, _| x,
-            |x, y| y.try_into().map(|y| x.checked_shr(y)).ok().flatten(),
+            |x, _| x.max(context),
+            |x, y| x.shr(y, context, true),
         )
     }
 
     fn le(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |_, _| 1, |x, y| Some(x.cmp(&y).is_le() as isize))
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.le(y))
     }
 
     fn ge(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |_, _| 1, |x, y| Some(x.cmp(&y).is_ge() as isize))
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.ge(y))
     }
 
     fn lt(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |_, _| 1, |x, y| Some(x.cmp(&y).is_lt() as isize))
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.lt(y))
     }
 
     fn gt(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |_, _| 1, |x, y| Some(x.cmp(&y).is_gt() as isize))
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.gt(y))
     }
 
     fn eq(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |_, _| 1, |x, y| Some(x.cmp(&y).is_eq() as isize))
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.eq(y))
     }
 
     fn ne(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |_, _| 1, |x, y| Some(x.cmp(&y).is_ne() as isize))
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.eq(y).logical_not())
+    }
+
+    fn case_eq(self, exp: Evaluated) -> Evaluated {
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.case_eq(y))
+    }
+
+    fn case_ne(self, exp: Evaluated) -> Evaluated {
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.case_eq(y).logical_not())
+    }
+
+    fn wildcard_eq(self, exp: Evaluated) -> Evaluated {
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.wildcard_eq(y))
+    }
+
+    fn wildcard_ne(self, exp: Evaluated) -> Evaluated {
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.wildcard_eq(y).logical_not())
     }
 
     fn andand(self, exp: Evaluated) -> Evaluated {
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.logical_and(y))
+    }
+
+    fn oror(self, exp: Evaluated) -> Evaluated {
+        Self::binary_op(self, exp, |_, _| 1, |x, y| x.logical_or(y))
+    }
+
+    fn and(self, exp: Evaluated, context: usize) -> Evaluated {
         Self::binary_op(
             self,
             exp,
-            |_, _| 1,
-            |x, y| Some((x != 0 && y != 0) as isize),
+            |x, y| x.max(y).max(context),
+            |x, y| x.and(y, context),
         )
     }
 
-    fn oror(self, exp: Evaluated) -> Evaluated {
+    fn or(self, exp: Evaluated, context: usize) -> Evaluated {
         Self::binary_op(
             self,
             exp,
-            |_, _| 1,
-            |x, y| Some((x != 0 || y != 0) as isize),
+            |x, y| x.max(y).max(context),
+            |x, y| x.or(y, context),
         )
     }
 
-    fn and(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| Some(x & y))
-    }
-
-    fn or(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| Some(x | y))
-    }
-
-    fn xor(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| Some(x ^ y))
+    fn xor(self, exp: Evaluated, context: usize) -> Evaluated {
+        Self::binary_op(
+            self,
+            exp,
+            |x, y| x.max(y).max(context),
+            |x, y| x.xor(y, context),
+        )
     }
 
-    fn xnor(self, exp: Evaluated) -> Evaluated {
-        Self::binary_op(self, exp, |x, y| x.max(y), |x, y| Some(!(x ^ y)))
+    fn xnor(self, exp: Evaluated, context: usize) -> Evaluated {
+        Self::binary_op(
+            self,
+            exp,
+            |x, y| x.max(y).max(context),
+            |x, y| x.xnor(y, context),
+        )
     }
 
-    fn plus(self) -> Evaluated {
-        Self::unary_op(self, |x| x, Some)
+    fn plus(self, context: usize) -> Evaluated {
+        Self::unary_op(
+            self,
+            |x| x.max(context),
+            |x| x.cast(x.width.max(context), x.signed),
+        )
     }
 
-    fn minus(self) -> Evaluated {
-        Self::unary_op(self, |x| x, |x| Some(-x))
+    fn minus(self, context: usize) -> Evaluated {
+        Self::unary_op(self, |x| x.max(context), |x| x.neg(context))
     }
 
     fn not(self) -> Evaluated {
-        Self::unary_op(self, |_| 1, |x| Some((x == 0) as isize))
+        Self::unary_op(self, |_| 1, |x| x.logical_not())
     }
 
-    fn inv(self) -> Evaluated {
-        Self::unary_op(self, |x| x, |x| Some(!x))
+    fn inv(self, context: usize) -> Evaluated {
+        Self::unary_op(self, |x| x.max(context), |x| x.inv(context))
     }
 
     fn reduction_and(self) -> Evaluated {
-        Self::unary_op(self, |_| 1, |_| None)
+        Self::unary_op(self, |_| 1, |x| x.reduction_and())
     }
 
     fn reduction_or(self) -> Evaluated {
-        Self::unary_op(self, |_| 1, |_| None)
+        Self::unary_op(self, |_| 1, |x| x.reduction_or())
     }
 
     fn reduction_nand(self) -> Evaluated {
-        Self::unary_op(self, |_| 1, |_| None)
+        Self::unary_op(self, |_| 1, |x| x.reduction_and().logical_not())
     }
 
     fn reduction_nor(self) -> Evaluated {
-        Self::unary_op(self, |_| 1, |_| None)
+        Self::unary_op(self, |_| 1, |x| x.reduction_or().logical_not())
     }
 
     fn reduction_xor(self) -> Evaluated {
-        Self::unary_op(self, |_| 1, |_| None)
+        Self::unary_op(self, |_| 1, |x| x.reduction_xor())
     }
 
     fn reduction_xnor(self) -> Evaluated {
-        Self::unary_op(self, |_| 1, |_| None)
+        Self::unary_op(self, |_| 1, |x| x.reduction_xor().logical_not())
+    }
+}
+
+impl fmt::Display for Evaluated {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Evaluated::Fixed(x) => write!(f, "Fixed {{ width: {}, value: {x} }}", x.width),
+            Evaluated::Variable { width } => write!(f, "Variable {{ width: {width} }}"),
+            Evaluated::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Divergence {
+    CallDepth,
+    LoopIteration,
+    EvaluationStep,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Divergence::CallDepth => write!(f, "{MAX_CALL_DEPTH} nested calls"),
+            Divergence::LoopIteration => write!(f, "{MAX_LOOP_ITERATIONS} loop iterations"),
+            Divergence::EvaluationStep => write!(f, "{MAX_EVALUATION_STEPS} evaluation steps"),
+        }
     }
 }
 
+/// Variable declared in function
+#[derive(Clone, Debug)]
+struct Local {
+    /// Packed dimensions from the outermost one
+    dims: Vec<usize>,
+    signed: bool,
+    value: Evaluated,
+}
+
+impl Local {
+    fn width(&self) -> usize {
+        self.dims.iter().product()
+    }
+}
+
+/// Function call under evaluation
+#[derive(Default)]
+struct Frame {
+    locals: HashMap<StrId, Local>,
+    return_width: usize,
+    return_signed: bool,
+}
+
+enum Control {
+    Next,
+    Return(Evaluated),
+    Abort,
+}
+
 #[derive(Default)]
 pub struct Evaluator {
     pub context_width: Vec<usize>,
+    /// Treats unsized numbers and parameters as the minimum width to represent their values
+    pub minimize_constant: bool,
+    pub diverged: Option<Divergence>,
+    frames: Vec<Frame>,
+    iterations: usize,
+    steps: usize,
+    /// Evaluates only the width, so function bodies and unselected branches are not executed
+    width_only: bool,
+    /// Results of function calls keyed by the function and the argument values
+    calls: HashMap<(TokenId, Vec<Value>), Evaluated>,
 }
 
 impl Evaluator {
@@ -281,45 +401,108 @@ impl Evaluator {
         Default::default()
     }
 
+    fn context(&self) -> usize {
+        self.context_width.last().copied().unwrap_or(0)
+    }
+
+    /// Counts evaluation steps, and returns `false` if the evaluation is diverged
+    fn step(&mut self) -> bool {
+        if self.diverged.is_some() {
+            return false;
+        }
+        self.steps += 1;
+        if self.steps > MAX_EVALUATION_STEPS {
+            self.diverged = Some(Divergence::EvaluationStep);
+            return false;
+        }
+        true
+    }
+
+    fn with_context<T: FnOnce(&mut Self) -> Evaluated>(&mut self, width: usize, f: T) -> Evaluated {
+        self.context_width.push(width);
+        let ret = f(self);
+        self.context_width.pop();
+        ret
+    }
+
+    /// Evaluates the self-determined width of the expression
+    fn self_width<T: FnOnce(&mut Self) -> Evaluated>(&mut self, f: T) -> Option<usize> {
+        let width_only = std::mem::replace(&mut self.width_only, true);
+        let ret = self.with_context(0, f).width();
+        self.width_only = width_only;
+        ret
+    }
+
+    /// Evaluates the width of the expression itself at first,
+    /// and evaluates the value with the width extended to `context`.
+    fn sized<T: FnMut(&mut Self) -> Evaluated>(&mut self, context: usize, mut f: T) -> Evaluated {
+        // The width extended by `context` is the same as evaluating with `context` directly
+        if self.width_only {
+            return self.with_context(context, f);
+        }
+        let width = self.self_width(&mut f).unwrap_or(0);
+        self.with_context(width.max(context), f)
+    }
+
+    /// Evaluates both operands with the width of the wider one
+    fn mutual<T, U>(&mut self, mut left: T, mut right: U) -> (Evaluated, Evaluated)
+    where
+        T: FnMut(&mut Self) -> Evaluated,
+        U: FnMut(&mut Self) -> Evaluated,
+    {
+        if self.width_only {
+            return (self.with_context(0, left), self.with_context(0, right));
+        }
+        let width0 = self.self_width(&mut left).unwrap_or(0);
+        let width1 = self.self_width(&mut right).unwrap_or(0);
+        let width = width0.max(width1);
+        (
+            self.with_context(width, left),
+            self.with_context(width, right),
+        )
+    }
+
     fn binary_operator(&mut self, operator: &str, left: Evaluated, right: Evaluated) -> Evaluated {
+        let context = self.context();
         match operator {
-            "**" => left.pow(right),
-            "/" => left.div(right),
-            "*" => left.mul(right),
-            "%" => left.rem(right),
-            "+" => left.add(right),
-            "-" => left.sub(right),
-            "<<<" => left.signed_shl(right),
-            ">>>" => left.signed_shr(right),
-            "<<" => left.unsigned_shl(right),
-            ">>" => left.unsigned_shr(right),
+            "**" => left.pow(right, context),
+            "/" => left.div(right, context),
+            "*" => left.mul(right, context),
+            "%" => left.rem(right, context),
+            "+" => left.add(right, context),
+            "-" => left.sub(right, context),
+            "<<<" => left.shl(right, context),
+            ">>>" => left.signed_shr(right, context),
+            "<<" => left.shl(right, context),
+            ">>" => left.unsigned_shr(right, context),
             "<=" => left.le(right),
             ">=" => left.ge(right),
-            "<" => left.lt(right),
-            ">" => left.gt(right),
-            "===" => left.eq(right),
-            "==?" => left.eq(right),
-            "!==" => left.ne(right),
-            "!=?" => left.ne(right),
+            "<:" => left.lt(right),
+            ">:" => left.gt(right),
+            "===" => left.case_eq(right),
+            "==?" => left.wildcard_eq(right),
+            "!==" => left.case_ne(right),
+            "!=?" => left.wildcard_ne(right),
             "==" => left.eq(right),
             "!=" => left.ne(right),
             "&&" => left.andand(right),
             "||" => left.oror(right),
-            "&" => left.and(right),
-            "^~" => left.xnor(right),
-            "^" => left.xor(right),
-            "~^" => left.xnor(right),
-            "|" => left.or(right),
+            "&" => left.and(right, context),
+            "^~" => left.xnor(right, context),
+            "^" => left.xor(right, context),
+            "~^" => left.xnor(right, context),
+            "|" => left.or(right, context),
             _ => Evaluated::Unknown,
         }
     }
 
     fn unary_operator(&mut self, operator: &str, left: Evaluated) -> Evaluated {
+        let context = self.context();
         match operator {
-            "+" => left.plus(),
-            "-" => left.minus(),
+            "+" => left.plus(context),
+            "-" => left.minus(context),
             "!" => left.not(),
-            "~" => left.inv(),
+            "~" => left.inv(context),
             "~&" => left.reduction_nand(),
             "~|" => left.reduction_nor(),
             "&" => left.reduction_and(),
@@ -347,26 +530,186 @@ impl Evaluator {
                     None
                 }
             }
-            TypeKind::Bit | TypeKind::Logic => {
-                if x.width.len() == 1 {
-                    let width = self.expression(&x.width[0]);
-                    if let Evaluated::Fixed { value, .. } = width {
-                        if let Ok(width) = value.try_into() {
-                            Some(width)
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    None
+            TypeKind::Clock | TypeKind::Reset | TypeKind::Bit | TypeKind::Logic => {
+                let mut ret: usize = 1;
+                for x in &x.width {
+                    ret = ret.checked_mul(self.constant(x)?)?;
                 }
+                Some(ret)
             }
             _ => None,
         }
     }
 
+    /// Returns the width of the type declared in a module or interface
+    /// whose parameters are overridden by `overrides` of an instance.
+    pub fn overridden_type_width(
+        &mut self,
+        x: Type,
+        parameters: &[Parameter],
+        overrides: &[InstParameterItem],
+    ) -> Option<usize> {
+        // overriding values are evaluated in the scope of the instance
+        let mut values = HashMap::new();
+        for item in overrides {
+            let value = match item.inst_parameter_item_opt {
+                Some(ref x) => self.with_context(0, |y| y.expression(&x.expression)),
+                None => match symbol_table::resolve(item.identifier.as_ref()) {
+                    Ok(symbol) => self.symbol(symbol.found, &[]),
+                    Err(_) => Evaluated::Unknown,
+                },
+            };
+            values.insert(item.identifier.identifier_token.token.text, value);
+        }
+
+        self.frames.push(Frame::default());
+        for parameter in parameters {
+            let mut local = match self.local(&parameter.property.r#type) {
+                Some(x) => x,
+                None => continue,
+            };
+            let width = local.width();
+            let value = match values.remove(&parameter.name) {
+                Some(x) => x,
+                None => match parameter.property.value {
+                    ParameterValue::Expression(ref x) => {
+                        self.with_context(width, |y| y.expression(x))
+                    }
+                    ParameterValue::TypeExpression(_) => continue,
+                },
+            };
+            local.value = value.cast(width, local.signed);
+            self.set_local(parameter.name, local);
+        }
+        let ret = self.type_width(x);
+        self.frames.pop();
+        ret
+    }
+
+    /// Returns packed dimensions of the type.
+    /// Array dimensions are placed before width dimensions.
+    fn type_dims(&mut self, x: &Type) -> Option<Vec<usize>> {
+        let mut ret = Vec::new();
+        for x in x.array.iter().chain(x.width.iter()) {
+            ret.push(self.constant(x)?);
+        }
+        match x.kind {
+            TypeKind::U32 | TypeKind::I32 if x.width.is_empty() => ret.push(32),
+            TypeKind::U64 | TypeKind::I64 if x.width.is_empty() => ret.push(64),
+            TypeKind::Clock | TypeKind::Reset | TypeKind::Bit | TypeKind::Logic => (),
+            _ => return None,
+        }
+        Some(ret)
+    }
+
+    fn constant(&mut self, x: &Expression) -> Option<usize> {
+        // The value is required even if only the width is evaluated
+        let width_only = std::mem::replace(&mut self.width_only, false);
+        let ret = self.with_context(0, |y| y.expression(x));
+        self.width_only = width_only;
+        if let Evaluated::Fixed(x) = ret {
+            x.to_usize()
+        } else {
+            None
+        }
+    }
+
+    fn condition(&mut self, x: &Expression) -> Option<bool> {
+        if let Evaluated::Fixed(x) = self.with_context(0, |y| y.expression(x)) {
+            Some(x.reduction_or().to_usize() == Some(1))
+        } else {
+            None
+        }
+    }
+
+    fn local(&mut self, x: &Type) -> Option<Local> {
+        let dims = self.type_dims(x)?;
+        let width = dims.iter().product();
+        let signed = x.is_signed();
+        let value = match x.kind {
+            TypeKind::Bit | TypeKind::U32 | TypeKind::U64 | TypeKind::I32 | TypeKind::I64 => {
+                Value::new(BigUint::zero(), width, signed)
+            }
+            _ => Value::new_x(width, signed),
+        };
+        Some(Local {
+            dims,
+            signed,
+            value: Evaluated::Fixed(value),
+        })
+    }
+
+    fn get_local(&self, name: StrId) -> Option<Local> {
+        self.frames.last()?.locals.get(&name).cloned()
+    }
+
+    fn set_local(&mut self, name: StrId, local: Local) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.locals.insert(name, local);
+        }
+    }
+
+    /// Returns the LSB position and the width selected by `selects`.
+    /// The LSB position is `None` if any index is not constant.
+    fn select_range(
+        &mut self,
+        dims: &[usize],
+        selects: &[&Select],
+    ) -> Option<(Option<usize>, usize)> {
+        if selects.len() > dims.len() {
+            return None;
+        }
+
+        let mut lsb = Some(0);
+        let mut width = dims.iter().product();
+        for (i, x) in selects.iter().enumerate() {
+            let elem: usize = dims[i + 1..].iter().product();
+            let index = self.constant(&x.expression);
+            let (offset, size) = if let Some(ref y) = x.select_opt {
+                // Part-select can't be followed by other selects
+                if i + 1 != selects.len() {
+                    return None;
+                }
+                let second = self.constant(&y.expression);
+                match &*y.select_operator {
+                    SelectOperator::Colon(_) => {
+                        let (msb, lsb) = (index?, second?);
+                        (Some(lsb), msb.checked_sub(lsb)? + 1)
+                    }
+                    SelectOperator::PlusColon(_) => (index, second?),
+                    SelectOperator::MinusColon(_) => {
+                        let size = second?;
+                        (index.and_then(|x| (x + 1).checked_sub(size)), size)
+                    }
+                    SelectOperator::Step(_) => {
+                        let size = second?;
+                        (index.map(|x| x * size), size)
+                    }
+                }
+            } else {
+                (index, 1)
+            };
+            lsb = lsb.zip(offset).map(|(x, y)| x + y * elem);
+            width = size * elem;
+        }
+        Some((lsb, width))
+    }
+
+    fn select(&mut self, value: Evaluated, dims: &[usize], selects: &[&Select]) -> Evaluated {
+        if selects.is_empty() {
+            return value;
+        }
+        match (value, self.select_range(dims, selects)) {
+            (Evaluated::Fixed(x), Some((Some(lsb), width))) => {
+                Evaluated::Fixed(x.select(lsb, width))
+            }
+            (Evaluated::Fixed(_) | Evaluated::Variable { .. }, Some((_, width))) => {
+                Evaluated::Variable { width }
+            }
+            _ => Evaluated::Unknown,
+        }
+    }
+
     fn exponent(&mut self, _arg: &Exponent) -> Evaluated {
         Evaluated::Unknown
     }
@@ -386,10 +729,9 @@ impl Evaluator {
                 "h" => 16,
                 _ => unreachable!(),
             };
-            let width = width.parse();
-            let value = isize::from_str_radix(value, radix);
-            if let (Ok(width), Ok(value)) = (width, value) {
-                Evaluated::Fixed { width, value }
+            let width = width.parse().ok();
+            if let Some(value) = Value::from_digits(value, radix, width) {
+                Evaluated::Fixed(value)
             } else {
                 Evaluated::Unknown
             }
@@ -400,8 +742,14 @@ impl Evaluator {
 
     fn base_less(&mut self, arg: &BaseLess) -> Evaluated {
         let text = arg.base_less_token.text().replace('_', "");
-        if let Ok(value) = text.parse() {
-            Evaluated::Fixed { width: 32, value }
+        if let Ok(value) = text.parse::<BigUint>() {
+            if self.minimize_constant {
+                let width = (value.bits() as usize).max(1);
+                return Evaluated::Fixed(Value::new(value, width, false));
+            }
+            // Unsized decimal number is signed and at least 32 bits
+            let width = 32.max(value.bits() as usize + 1);
+            Evaluated::Fixed(Value::new(value, width, true))
         } else {
             Evaluated::Unknown
         }
@@ -409,34 +757,16 @@ impl Evaluator {
 
     fn all_bit(&mut self, arg: &AllBit) -> Evaluated {
         let text = arg.all_bit_token.text();
-        let mut unknown = false;
-        let value = match text.as_str() {
-            "'1" => {
-                let mut ret: isize = 0;
-                for _ in 0..*self.context_width.last().unwrap_or(&0) {
-                    if let Some(x) = ret.checked_shl(1) {
-                        ret = x;
-                    } else {
-                        unknown = true;
-                    }
-                    ret |= 1;
-                }
-                ret
-            }
-            "'0" => 0,
-            _ => {
-                unknown = true;
-                0
-            }
+        let (width, value) = text.split_once('\'').unwrap();
+        let width = width.parse().unwrap_or_else(|_| self.context().max(1));
+        let value = match value {
+            "0" => Value::new(BigUint::zero(), width, false),
+            "1" => Value::new(BigUint::zero(), width, false).inv(0),
+            "x" | "X" => Value::new_x(width, false),
+            "z" | "Z" => Value::new_z(width, false),
+            _ => return Evaluated::Unknown,
         };
-        if unknown {
-            Evaluated::Unknown
-        } else {
-            Evaluated::Fixed {
-                width: *self.context_width.last().unwrap_or(&0),
-                value,
-            }
-        }
+        Evaluated::Fixed(value)
     }
 
     fn number(&mut self, arg: &Number) -> Evaluated {
@@ -461,24 +791,73 @@ impl Evaluator {
         }
     }
 
-    pub fn expression(&mut self, arg: &Expression) -> Evaluated {
-        let mut ret = self.expression01(&arg.expression01);
-        for x in &arg.expression_list {
-            let operator = x.operator01.operator01_token.text();
-            let operand = self.expression01(&x.expression01);
-            ret = self.binary_operator(&operator, ret, operand);
+    /// Operands of logical operators are self-determined
+    fn logical<T>(
+        &mut self,
+        first: &T,
+        list: Vec<(String, &T)>,
+        operand: fn(&mut Self, &T) -> Evaluated,
+    ) -> Evaluated {
+        if list.is_empty() {
+            return operand(self, first);
+        }
+        let mut ret = self.sized(0, |x| operand(x, first));
+        for (operator, x) in list {
+            let right = self.sized(0, |y| operand(y, x));
+            ret = self.binary_operator(&operator, ret, right);
         }
         ret
     }
 
-    fn expression01(&mut self, arg: &Expression01) -> Evaluated {
-        let mut ret = self.expression02(&arg.expression02);
-        for x in &arg.expression01_list {
-            let operator = x.operator02.operator02_token.text();
-            let operand = self.expression02(&x.expression02);
-            ret = self.binary_operator(&operator, ret, operand);
+    /// Operands of equality and relational operators are sized to each other
+    fn relational<T>(
+        &mut self,
+        first: &T,
+        list: Vec<(String, &T)>,
+        operand: fn(&mut Self, &T) -> Evaluated,
+    ) -> Evaluated {
+        let mut ret = None;
+        for (operator, x) in list {
+            let (left, right) = match ret {
+                Some(left) => self.mutual(|_| Evaluated::clone(&left), |y| operand(y, x)),
+                None => self.mutual(|y| operand(y, first), |y| operand(y, x)),
+            };
+            ret = Some(self.binary_operator(&operator, left, right));
         }
-        ret
+        ret.unwrap_or_else(|| operand(self, first))
+    }
+
+    pub fn expression(&mut self, arg: &Expression) -> Evaluated {
+        let context = self.context();
+        self.sized(context, |x| x.context_determined_expression(arg))
+    }
+
+    fn context_determined_expression(&mut self, arg: &Expression) -> Evaluated {
+        let list = arg
+            .expression_list
+            .iter()
+            .map(|x| {
+                (
+                    x.operator01.operator01_token.text(),
+                    x.expression01.as_ref(),
+                )
+            })
+            .collect();
+        self.logical(arg.expression01.as_ref(), list, Self::expression01)
+    }
+
+    fn expression01(&mut self, arg: &Expression01) -> Evaluated {
+        let list = arg
+            .expression01_list
+            .iter()
+            .map(|x| {
+                (
+                    x.operator02.operator02_token.text(),
+                    x.expression02.as_ref(),
+                )
+            })
+            .collect();
+        self.logical(arg.expression02.as_ref(), list, Self::expression02)
     }
 
     fn expression02(&mut self, arg: &Expression02) -> Evaluated {
@@ -512,30 +891,39 @@ impl Evaluator {
     }
 
     fn expression05(&mut self, arg: &Expression05) -> Evaluated {
-        let mut ret = self.expression06(&arg.expression06);
-        for x in &arg.expression05_list {
-            let operator = x.operator06.operator06_token.text();
-            let operand = self.expression06(&x.expression06);
-            ret = self.binary_operator(&operator, ret, operand);
-        }
-        ret
+        let list = arg
+            .expression05_list
+            .iter()
+            .map(|x| {
+                (
+                    x.operator06.operator06_token.text(),
+                    x.expression06.as_ref(),
+                )
+            })
+            .collect();
+        self.relational(arg.expression06.as_ref(), list, Self::expression06)
     }
 
     fn expression06(&mut self, arg: &Expression06) -> Evaluated {
-        let mut ret = self.expression07(&arg.expression07);
-        for x in &arg.expression06_list {
-            let operator = x.operator07.operator07_token.text();
-            let operand = self.expression07(&x.expression07);
-            ret = self.binary_operator(&operator, ret, operand);
-        }
-        ret
+        let list = arg
+            .expression06_list
+            .iter()
+            .map(|x| {
+                (
+                    x.operator07.operator07_token.text(),
+                    x.expression07.as_ref(),
+                )
+            })
+            .collect();
+        self.relational(arg.expression07.as_ref(), list, Self::expression07)
     }
 
     fn expression07(&mut self, arg: &Expression07) -> Evaluated {
         let mut ret = self.expression08(&arg.expression08);
         for x in &arg.expression07_list {
             let operator = x.operator08.operator08_token.text();
-            let operand = self.expression08(&x.expression08);
+            // Shift amount is self-determined
+            let operand = self.sized(0, |y| y.expression08(&x.expression08));
             ret = self.binary_operator(&operator, ret, operand);
         }
         ret
@@ -568,7 +956,8 @@ impl Evaluator {
         let mut ret = self.expression11(&arg.expression11);
         for x in &arg.expression10_list {
             let operator = x.operator11.operator11_token.text();
-            let operand = self.expression11(&x.expression11);
+            // Exponent is self-determined
+            let operand = self.sized(0, |y| y.expression11(&x.expression11));
             ret = self.binary_operator(&operator, ret, operand);
         }
         ret
@@ -579,9 +968,10 @@ impl Evaluator {
     }
 
     fn expression12(&mut self, arg: &Expression12) -> Evaluated {
-        let mut ret = self.factor(&arg.factor);
-        for x in arg.expression12_list.iter().rev() {
-            let operator = match &*x.expression12_list_group {
+        let operators: Vec<_> = arg
+            .expression12_list
+            .iter()
+            .map(|x| match &*x.expression12_list_group {
                 Expression12ListGroup::UnaryOperator(x) => {
                     x.unary_operator.unary_operator_token.text()
                 }
@@ -589,38 +979,47 @@ impl Evaluator {
                 Expression12ListGroup::Operator04(x) => x.operator04.operator04_token.text(),
                 Expression12ListGroup::Operator05(x) => x.operator05.operator05_token.text(),
                 Expression12ListGroup::Operator09(x) => x.operator09.operator09_token.text(),
-            };
-            ret = self.unary_operator(&operator, ret);
+            })
+            .collect();
+
+        // Operands of reduction and logical negation operators are self-determined
+        let self_determined = operators
+            .iter()
+            .rposition(|x| !matches!(x.as_str(), "+" | "-" | "~"));
+        let (operators, mut ret) = if let Some(i) = self_determined {
+            let inner = &operators[i + 1..];
+            let ret = self.sized(0, |x| {
+                let mut ret = x.factor(&arg.factor);
+                for operator in inner.iter().rev() {
+                    ret = x.unary_operator(operator, ret);
+                }
+                ret
+            });
+            (&operators[..i + 1], ret)
+        } else {
+            (&operators[..], self.factor(&arg.factor))
+        };
+
+        for operator in operators.iter().rev() {
+            ret = self.unary_operator(operator, ret);
         }
         ret
     }
 
     fn factor(&mut self, arg: &Factor) -> Evaluated {
+        if !self.step() {
+            return Evaluated::Unknown;
+        }
         match arg {
             Factor::Number(x) => self.number(&x.number),
             Factor::ExpressionIdentifierFactorOpt(x) => {
-                if x.factor_opt.is_some() {
-                    // Function call
-                    Evaluated::Unknown
+                if let Some(ref y) = x.factor_opt {
+                    self.function_call(&x.expression_identifier, &y.function_call)
                 } else {
-                    // Identifier
-                    let symbol = symbol_table::resolve(x.expression_identifier.as_ref());
-                    if let Ok(symbol) = symbol {
-                        if let Some(symbol) = symbol.found {
-                            if let Some(evaluated) = symbol.evaluated.get() {
-                                evaluated
-                            } else {
-                                Evaluated::Unknown
-                            }
-                        } else {
-                            Evaluated::Unknown
-                        }
-                    } else {
-                        Evaluated::Unknown
-                    }
+                    self.expression_identifier(&x.expression_identifier)
                 }
             }
-            Factor::LParenExpressionRParen(x) => self.expression(&x.expression),
+            Factor::LParenExpressionRParen(x) => self.context_determined_expression(&x.expression),
             Factor::LBraceConcatenationListRBrace(x) => {
                 self.concatenation_list(&x.concatenation_list)
             }
@@ -633,15 +1032,592 @@ impl Evaluator {
         }
     }
 
-    fn concatenation_list(&mut self, _arg: &ConcatenationList) -> Evaluated {
-        Evaluated::Unknown
+    fn concatenation_list(&mut self, arg: &ConcatenationList) -> Evaluated {
+        let mut items = vec![arg.concatenation_item.as_ref()];
+        for x in &arg.concatenation_list_list {
+            items.push(x.concatenation_item.as_ref());
+        }
+
+        // Operands of concatenation are self-determined
+        let mut ret: Option<Evaluated> = None;
+        for x in items {
+            let mut item = self.sized(0, |y| y.expression(&x.expression));
+            if let Some(ref x) = x.concatenation_item_opt {
+                let repeat = match self.constant(&x.expression) {
+                    Some(x) => x,
+                    None => return Evaluated::Unknown,
+                };
+                item = item.repeat(repeat);
+            }
+            ret = Some(match ret {
+                Some(x) => Evaluated::concat(vec![x, item]),
+                None => item,
+            });
+        }
+        ret.unwrap_or(Evaluated::Unknown)
     }
 
-    fn if_expression(&mut self, _arg: &IfExpression) -> Evaluated {
-        Evaluated::Unknown
+    /// Evaluates the branch selected by `conditions`.
+    /// All branches are sized to the widest one.
+    fn branch<T>(&mut self, conditions: Vec<T>, values: Vec<&Expression>) -> Evaluated
+    where
+        T: FnMut(&mut Self) -> Evaluated,
+    {
+        let mut width = self.context();
+        for x in &values {
+            match self.self_width(|y| y.expression(x)) {
+                Some(x) => width = width.max(x),
+                None => return Evaluated::Unknown,
+            }
+        }
+        if self.width_only {
+            return Evaluated::Variable { width };
+        }
+
+        let mut selected = None;
+        for (i, mut condition) in conditions.into_iter().enumerate() {
+            match condition(self) {
+                Evaluated::Fixed(x) if x.to_usize() == Some(1) => {
+                    selected = Some(values[i]);
+                    break;
+                }
+                Evaluated::Fixed(_) => (),
+                _ => return Evaluated::Variable { width },
+            }
+        }
+        let selected = selected.unwrap_or(values[values.len() - 1]);
+        self.with_context(width, |x| x.expression(selected))
     }
 
-    fn case_expression(&mut self, _arg: &CaseExpression) -> Evaluated {
-        Evaluated::Unknown
+    fn if_expression(&mut self, arg: &IfExpression) -> Evaluated {
+        let mut conditions = vec![arg.expression.as_ref()];
+        let mut values = vec![arg.expression0.as_ref()];
+        for x in &arg.if_expression_list {
+            conditions.push(x.expression.as_ref());
+            values.push(x.expression0.as_ref());
+        }
+        values.push(arg.expression1.as_ref());
+
+        let conditions = conditions
+            .into_iter()
+            .map(|x| {
+                move |y: &mut Self| {
+                    let condition = y.with_context(0, |z| z.expression(x));
+                    condition.reduction_or()
+                }
+            })
+            .collect();
+        self.branch(conditions, values)
+    }
+
+    fn case_expression(&mut self, arg: &CaseExpression) -> Evaluated {
+        let mut items = vec![arg.expression0.as_ref()];
+        let mut values = vec![arg.expression1.as_ref()];
+        for x in &arg.case_expression_list {
+            items.push(x.expression.as_ref());
+            values.push(x.expression0.as_ref());
+        }
+        values.push(arg.expression2.as_ref());
+
+        let conditions = items
+            .into_iter()
+            .map(|x| {
+                move |y: &mut Self| {
+                    let (left, right) =
+                        y.mutual(|z| z.expression(&arg.expression), |z| z.expression(x));
+                    left.case_eq(right)
+                }
+            })
+            .collect();
+        self.branch(conditions, values)
+    }
+
+    pub fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Evaluated {
+        let (selects, member): (Vec<&Select>, bool) = match &*arg.expression_identifier_group {
+            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => (
+                x.expression_identifier_group_list0
+                    .iter()
+                    .map(|x| x.select.as_ref())
+                    .collect(),
+                false,
+            ),
+            ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) => (
+                x.expression_identifier_group_list1
+                    .iter()
+                    .map(|x| x.select.as_ref())
+                    .collect(),
+                !x.expression_identifier_group_list2.is_empty(),
+            ),
+        };
+
+        if member {
+            return Evaluated::Unknown;
+        }
+
+        if arg.expression_identifier_opt.is_none() {
+            if let Some(local) = self.get_local(arg.identifier.identifier_token.token.text) {
+                return self.select(local.value, &local.dims, &selects);
+            }
+        }
+
+        match symbol_table::resolve(arg) {
+            Ok(symbol) => self.symbol(symbol.found, &selects),
+            Err(_) => Evaluated::Unknown,
+        }
+    }
+
+    pub fn hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier) -> Evaluated {
+        if !arg.hierarchical_identifier_list0.is_empty() {
+            return Evaluated::Unknown;
+        }
+
+        let selects: Vec<&Select> = arg
+            .hierarchical_identifier_list
+            .iter()
+            .map(|x| x.select.as_ref())
+            .collect();
+        match symbol_table::resolve(arg) {
+            Ok(symbol) => self.symbol(symbol.found, &selects),
+            Err(_) => Evaluated::Unknown,
+        }
+    }
+
+    fn symbol(&mut self, symbol: Option<Symbol>, selects: &[&Select]) -> Evaluated {
+        let symbol = match symbol {
+            Some(x) => x,
+            None => return Evaluated::Unknown,
+        };
+        let evaluated = symbol
+            .evaluated
+            .borrow()
+            .clone()
+            .unwrap_or(Evaluated::Unknown);
+        if selects.is_empty() {
+            return if self.minimize_constant && matches!(symbol.kind, SymbolKind::Parameter(_)) {
+                evaluated.minimize()
+            } else {
+                evaluated
+            };
+        }
+
+        if let Some(dims) = Self::symbol_type(&symbol).and_then(|x| self.type_dims(&x)) {
+            self.select(evaluated, &dims, selects)
+        } else {
+            Evaluated::Unknown
+        }
+    }
+
+    fn symbol_type(symbol: &Symbol) -> Option<Type> {
+        match &symbol.kind {
+            SymbolKind::Variable(x) => Some(x.r#type.clone()),
+            SymbolKind::Parameter(x) => Some(x.r#type.clone()),
+            SymbolKind::Port(x) => x.r#type.clone(),
+            _ => None,
+        }
+    }
+
+    /// Returns the bit range selected from the symbol as `(lsb, width)`.
+    /// `None` is returned if the range can't be determined at compile time.
+    pub fn selected_bits(
+        &mut self,
+        symbol: &Symbol,
+        selects: &[&Select],
+    ) -> Option<(usize, usize)> {
+        let dims = self.type_dims(&Self::symbol_type(symbol)?)?;
+        match self.select_range(&dims, selects)? {
+            (Some(lsb), width) => Some((lsb, width)),
+            _ => None,
+        }
+    }
+
+    fn system_function(&mut self, name: &str, args: &[ArgumentItem]) -> Evaluated {
+        if args.len() != 1 {
+            return Evaluated::Unknown;
+        }
+        let arg = &args[0].expression;
+        let value = self.with_context(0, |x| x.expression(arg));
+        match name {
+            "clog2" => match value {
+                Evaluated::Fixed(x) if x.is_xz() => Evaluated::Fixed(Value::new_x(32, true)),
+                Evaluated::Fixed(x) => {
+                    let ret = if x.payload <= BigUint::one() {
+                        0
+                    } else {
+                        (x.payload - 1u32).bits()
+                    };
+                    Evaluated::Fixed(Value::new(BigUint::from(ret), 32, true))
+                }
+                Evaluated::Variable { .. } => Evaluated::Variable { width: 32 },
+                Evaluated::Unknown => Evaluated::Unknown,
+            },
+            "bits" => match value.width() {
+                Some(x) => Evaluated::Fixed(Value::new(BigUint::from(x), 32, true)),
+                None => Evaluated::Unknown,
+            },
+            "signed" | "unsigned" => match value {
+                Evaluated::Fixed(mut x) => {
+                    x.signed = name == "signed";
+                    Evaluated::Fixed(x)
+                }
+                x => x,
+            },
+            _ => Evaluated::Unknown,
+        }
+    }
+
+    /// Evaluates a function call by executing the function body over constant arguments
+    pub fn function_call(&mut self, arg: &ExpressionIdentifier, call: &FunctionCall) -> Evaluated {
+        let args: Vec<ArgumentItem> = if let Some(ref x) = call.function_call_opt {
+            x.argument_list.as_ref().into()
+        } else {
+            Vec::new()
+        };
+
+        if arg.expression_identifier_opt.is_some() {
+            let name = arg.identifier.identifier_token.text();
+            return self.system_function(&name, &args);
+        }
+
+        let symbol = match symbol_table::resolve(arg) {
+            Ok(symbol) => symbol.found,
+            Err(_) => None,
+        };
+        let (id, property) = match symbol {
+            Some(Symbol {
+                token,
+                kind: SymbolKind::Function(x),
+                ..
+            }) => (token.id, x),
+            _ => return Evaluated::Unknown,
+        };
+        let ret = match self.local(&property.return_type) {
+            Some(x) => x,
+            None => return Evaluated::Unknown,
+        };
+        let non_constant = Evaluated::Variable { width: ret.width() };
+
+        if self.width_only
+            || !property.generic_parameters.is_empty()
+            || args.len() != property.ports.len()
+            || self.diverged.is_some()
+        {
+            return non_constant;
+        }
+        if self.frames.len() >= MAX_CALL_DEPTH {
+            self.diverged = Some(Divergence::CallDepth);
+            return non_constant;
+        }
+
+        let mut frame = Frame {
+            locals: HashMap::new(),
+            return_width: ret.width(),
+            return_signed: ret.signed,
+        };
+        let mut values = Vec::new();
+        for (port, arg) in property.ports.iter().zip(args.iter()) {
+            if !matches!(port.property.direction, Direction::Input) {
+                return non_constant;
+            }
+            let mut local = match port.property.r#type.as_ref().and_then(|x| self.local(x)) {
+                Some(x) => x,
+                None => return non_constant,
+            };
+            let value = self
+                .with_context(local.width(), |x| x.expression(&arg.expression))
+                .cast(local.width(), local.signed);
+            match value {
+                Evaluated::Fixed(ref x) => values.push(x.clone()),
+                _ => return non_constant,
+            }
+            local.value = value;
+            frame.locals.insert(port.name, local);
+        }
+
+        let key = (id, values);
+        if let Some(x) = self.calls.get(&key) {
+            return x.clone();
+        }
+
+        self.frames.push(frame);
+        let control = self.function_items(&property.items);
+        self.frames.pop();
+
+        let ret = match control {
+            Control::Return(x @ Evaluated::Fixed(_)) => x,
+            _ => non_constant,
+        };
+        if self.diverged.is_none() {
+            self.calls.insert(key, ret.clone());
+        }
+        ret
+    }
+
+    fn function_items(&mut self, arg: &[FunctionItem]) -> Control {
+        for x in arg {
+            let control = match x {
+                FunctionItem::VarDeclaration(x) => self.var_declaration(&x.var_declaration),
+                FunctionItem::Statement(x) => self.statement(&x.statement),
+            };
+            if !matches!(control, Control::Next) {
+                return control;
+            }
+        }
+        Control::Next
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Control {
+        let r#type: Type = arg.array_type.as_ref().into();
+        let mut local = match self.local(&r#type) {
+            Some(x) => x,
+            None => return Control::Abort,
+        };
+        if let Some(ref x) = arg.var_declaration_opt0 {
+            local.value = self
+                .with_context(local.width(), |y| y.expression(&x.expression))
+                .cast(local.width(), local.signed);
+        }
+        self.set_local(arg.identifier.identifier_token.token.text, local);
+        Control::Next
+    }
+
+    fn statements<'b, T: Iterator<Item = &'b Statement>>(&mut self, arg: T) -> Control {
+        for x in arg {
+            let control = self.statement(x);
+            if !matches!(control, Control::Next) {
+                return control;
+            }
+        }
+        Control::Next
+    }
+
+    fn statement(&mut self, arg: &Statement) -> Control {
+        if !self.step() {
+            return Control::Abort;
+        }
+        match arg {
+            Statement::IdentifierStatement(x) => self.identifier_statement(&x.identifier_statement),
+            Statement::IfStatement(x) => self.if_statement(&x.if_statement),
+            Statement::IfResetStatement(_) => Control::Abort,
+            Statement::ReturnStatement(x) => self.return_statement(&x.return_statement),
+            Statement::ForStatement(x) => self.for_statement(&x.for_statement),
+            Statement::CaseStatement(x) => self.case_statement(&x.case_statement),
+        }
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Control {
+        let assignment = match &*arg.identifier_statement_group {
+            IdentifierStatementGroup::Assignment(x) => &x.assignment,
+            // Function call statement has no effect because output ports are not supported
+            IdentifierStatementGroup::FunctionCall(_) => return Control::Next,
+        };
+
+        let target = &arg.expression_identifier;
+        let selects: Vec<&Select> = match &*target.expression_identifier_group {
+            ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x)
+                if x.expression_identifier_group_list2.is_empty() =>
+            {
+                x.expression_identifier_group_list1
+                    .iter()
+                    .map(|x| x.select.as_ref())
+                    .collect()
+            }
+            _ => return Control::Abort,
+        };
+        let name = target.identifier.identifier_token.token.text;
+        let mut local = match self.get_local(name) {
+            Some(x) => x,
+            None => return Control::Abort,
+        };
+        let (lsb, width) = match self.select_range(&local.dims, &selects) {
+            Some((Some(lsb), width)) => (lsb, width),
+            _ => return Control::Abort,
+        };
+        // Part-select is unsigned
+        let signed = local.signed && selects.is_empty();
+
+        let value = match &*assignment.assignment_group {
+            AssignmentGroup::Equ(_) => {
+                self.with_context(width, |x| x.expression(&assignment.expression))
+            }
+            AssignmentGroup::AssignmentOperator(x) => {
+                let operator = x.assignment_operator.assignment_operator_token.text();
+                let operator = operator.trim_end_matches('=');
+                let current = self.select(local.value.clone(), &local.dims, &selects);
+                self.with_context(width, |x| {
+                    let right = x.expression(&assignment.expression);
+                    x.binary_operator(operator, current, right)
+                })
+            }
+        };
+
+        match (&local.value, value.cast(width, signed)) {
+            (Evaluated::Fixed(x), Evaluated::Fixed(y)) => {
+                local.value = Evaluated::Fixed(x.deposit(lsb, &y));
+                self.set_local(name, local);
+                Control::Next
+            }
+            _ => Control::Abort,
+        }
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) -> Control {
+        match self.condition(&arg.expression) {
+            Some(true) => {
+                return self.statements(arg.if_statement_list.iter().map(|x| x.statement.as_ref()))
+            }
+            Some(false) => (),
+            None => return Control::Abort,
+        }
+        for x in &arg.if_statement_list0 {
+            match self.condition(&x.expression) {
+                Some(true) => {
+                    return self.statements(
+                        x.if_statement_list0_list
+                            .iter()
+                            .map(|x| x.statement.as_ref()),
+                    )
+                }
+                Some(false) => (),
+                None => return Control::Abort,
+            }
+        }
+        if let Some(ref x) = arg.if_statement_opt {
+            self.statements(x.if_statement_opt_list.iter().map(|x| x.statement.as_ref()))
+        } else {
+            Control::Next
+        }
+    }
+
+    fn return_statement(&mut self, arg: &ReturnStatement) -> Control {
+        let frame = match self.frames.last() {
+            Some(x) => x,
+            None => return Control::Abort,
+        };
+        let (width, signed) = (frame.return_width, frame.return_signed);
+        let value = self
+            .with_context(width, |x| x.expression(&arg.expression))
+            .cast(width, signed);
+        Control::Return(value)
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) -> Control {
+        let r#type: Type = arg.scalar_type.as_ref().into();
+        let mut local = match self.local(&r#type) {
+            Some(x) => x,
+            None => return Control::Abort,
+        };
+        let (width, signed) = (local.width(), local.signed);
+        let bound = |x: &Expression, y: &mut Self| {
+            y.with_context(width, |z| z.expression(x))
+                .cast(width, signed)
+        };
+        local.value = bound(&arg.range.expression, self);
+        let (end, inclusive) = if let Some(ref x) = arg.range.range_opt {
+            let inclusive = matches!(&*x.range_operator, RangeOperator::DotDotEqu(_));
+            (bound(&x.expression, self), inclusive)
+        } else {
+            (local.value.clone(), true)
+        };
+
+        let name = arg.identifier.identifier_token.token.text;
+        let shadowed = self.get_local(name);
+        self.set_local(name, local);
+
+        let control = loop {
+            let local = match self.get_local(name) {
+                Some(x) => x,
+                None => break Control::Abort,
+            };
+            let condition = if inclusive {
+                local.value.clone().le(end.clone())
+            } else {
+                local.value.clone().lt(end.clone())
+            };
+            match condition {
+                Evaluated::Fixed(x) if x.to_usize() == Some(1) => (),
+                Evaluated::Fixed(_) => break Control::Next,
+                _ => break Control::Abort,
+            }
+
+            self.iterations += 1;
+            if self.iterations > MAX_LOOP_ITERATIONS {
+                self.diverged = Some(Divergence::LoopIteration);
+                break Control::Abort;
+            }
+
+            let control =
+                self.statements(arg.for_statement_list.iter().map(|x| x.statement.as_ref()));
+            if !matches!(control, Control::Next) {
+                break control;
+            }
+
+            let mut local = match self.get_local(name) {
+                Some(x) => x,
+                None => break Control::Abort,
+            };
+            let current = local.value.clone();
+            let next = if let Some(ref x) = arg.for_statement_opt {
+                let operator = x.assignment_operator.assignment_operator_token.text();
+                let operator = operator.trim_end_matches('=');
+                self.with_context(width, |y| {
+                    let right = y.expression(&x.expression);
+                    y.binary_operator(operator, current, right)
+                })
+            } else {
+                let one = Evaluated::Fixed(Value::new(BigUint::one(), 32, true));
+                self.with_context(width, |y| y.binary_operator("+", current, one))
+            };
+            local.value = next.cast(width, signed);
+            self.set_local(name, local);
+        };
+
+        match shadowed {
+            Some(x) => self.set_local(name, x),
+            None => {
+                if let Some(frame) = self.frames.last_mut() {
+                    frame.locals.remove(&name);
+                }
+            }
+        }
+        control
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Control {
+        let mut default = None;
+        for x in &arg.case_statement_list {
+            let item = &x.case_item;
+            let condition = match &*item.case_item_group {
+                CaseItemGroup::Expression(y) => {
+                    let (left, right) = self.mutual(
+                        |z| z.expression(&arg.expression),
+                        |z| z.expression(&y.expression),
+                    );
+                    left.case_eq(right)
+                }
+                CaseItemGroup::Defaul(_) => {
+                    default = Some(item);
+                    continue;
+                }
+            };
+            match condition {
+                Evaluated::Fixed(x) if x.to_usize() == Some(1) => return self.case_item(item),
+                Evaluated::Fixed(_) => (),
+                _ => return Control::Abort,
+            }
+        }
+        if let Some(item) = default {
+            self.case_item(item)
+        } else {
+            Control::Next
+        }
+    }
+
+    fn case_item(&mut self, arg: &CaseItem) -> Control {
+        match &*arg.case_item_group0 {
+            CaseItemGroup0::Statement(x) => self.statement(&x.statement),
+            CaseItemGroup0::LBraceCaseItemGroup0ListRBrace(x) => {
+                self.statements(x.case_item_group0_list.iter().map(|x| x.statement.as_ref()))
+            }
+        }
     }
 }
@@ -0,0 +1,125 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+
+#[derive(Clone, Debug, Default)]
+pub struct FeatureTable {
+    table: HashSet<String>,
+    declared: HashSet<String>,
+}
+
+impl FeatureTable {
+    pub fn set(&mut self, features: &HashSet<String>, declared: &HashSet<String>) {
+        self.table = features.clone();
+        self.declared = declared.clone();
+    }
+
+    pub fn contains(&self, feature: &str) -> bool {
+        self.table.contains(feature)
+    }
+
+    /// Returns string literals of `feature = "..."` in the predicate
+    /// which are not declared in `[features]` section.
+    pub fn unknown_features(&self, arg: &AttributeItem) -> Vec<VerylToken> {
+        let x = match arg {
+            AttributeItem::IdentifierAttributeItemOpt(x) => x,
+            AttributeItem::StringLiteral(_) => return Vec::new(),
+        };
+        let identifier = x.identifier.identifier_token.text();
+        let x = match x.attribute_item_opt {
+            Some(ref x) => x,
+            None => return Vec::new(),
+        };
+
+        match &*x.attribute_item_opt_group {
+            AttributeItemOptGroup::EquStringLiteral(x) if identifier.as_str() == "feature" => {
+                let token = &x.string_literal.string_literal_token;
+                let text = token.text();
+                if self.declared.contains(&text[1..text.len() - 1]) {
+                    Vec::new()
+                } else {
+                    vec![token.clone()]
+                }
+            }
+            AttributeItemOptGroup::LParenAttributeListRParen(x) => {
+                let args: Vec<AttributeItem> = x.attribute_list.as_ref().into();
+                args.iter().flat_map(|x| self.unknown_features(x)).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Evaluates a predicate of `cfg` attribute.
+    /// `None` is returned if the predicate is invalid.
+    pub fn eval(&self, arg: &AttributeItem) -> Option<bool> {
+        let x = match arg {
+            AttributeItem::IdentifierAttributeItemOpt(x) => x,
+            AttributeItem::StringLiteral(_) => return None,
+        };
+        let identifier = x.identifier.identifier_token.text();
+        let x = match x.attribute_item_opt {
+            Some(ref x) => x,
+            None => return None,
+        };
+
+        match &*x.attribute_item_opt_group {
+            AttributeItemOptGroup::EquStringLiteral(x) if identifier.as_str() == "feature" => {
+                let text = x.string_literal.string_literal_token.text();
+                let text = &text[1..text.len() - 1];
+                Some(self.contains(text))
+            }
+            AttributeItemOptGroup::LParenAttributeListRParen(x) => {
+                let args: Vec<AttributeItem> = x.attribute_list.as_ref().into();
+                let mut values = Vec::new();
+                for arg in &args {
+                    values.push(self.eval(arg)?);
+                }
+                match identifier.as_str() {
+                    "all" => Some(values.iter().all(|x| *x)),
+                    "any" => Some(values.iter().any(|x| *x)),
+                    "not" if values.len() == 1 => Some(!values[0]),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+thread_local!(static FEATURE_TABLE: RefCell<FeatureTable> = RefCell::new(FeatureTable::default()));
+
+pub fn set(features: &HashSet<String>, declared: &HashSet<String>) {
+    FEATURE_TABLE.with(|f| f.borrow_mut().set(features, declared))
+}
+
+pub fn contains(feature: &str) -> bool {
+    FEATURE_TABLE.with(|f| f.borrow().contains(feature))
+}
+
+pub fn eval(arg: &AttributeItem) -> Option<bool> {
+    FEATURE_TABLE.with(|f| f.borrow().eval(arg))
+}
+
+pub fn unknown_features(arg: &AttributeItem) -> Vec<VerylToken> {
+    FEATURE_TABLE.with(|f| f.borrow().unknown_features(arg))
+}
+
+/// Returns whether the group annotated by the attribute is enabled.
+/// Attributes other than `cfg` are always enabled.
+/// Invalid predicates and predicates with unknown features are treated as enabled
+/// so that the attribute is walked and reported by the analyzer.
+pub fn is_enabled(arg: &Attribute) -> bool {
+    if arg.identifier.identifier_token.text().as_str() != "cfg" {
+        return true;
+    }
+    match arg.attribute_opt {
+        Some(ref x) => {
+            let args: Vec<AttributeItem> = x.attribute_list.as_ref().into();
+            args.len() != 1
+                || !unknown_features(&args[0]).is_empty()
+                || eval(&args[0]).unwrap_or(true)
+        }
+        None => true,
+    }
+}
@@ -1,37 +1,56 @@
+pub mod check_assignment;
 pub mod check_attribute;
+pub mod check_case;
+pub mod check_clock_domain;
 pub mod check_direction;
+pub mod check_embed_include;
 pub mod check_enum;
 pub mod check_function;
 pub mod check_identifier;
 pub mod check_instance;
+pub mod check_latch;
 pub mod check_msb_lsb;
 pub mod check_number;
 pub mod check_reset;
 pub mod check_statement;
 pub mod check_system_function;
+pub mod check_target;
+pub mod check_union;
+pub mod check_width;
+pub mod create_dependency_graph;
 pub mod create_reference;
 pub mod create_symbol_table;
+use check_assignment::*;
 use check_attribute::*;
+use check_case::*;
+use check_clock_domain::*;
 use check_direction::*;
+use check_embed_include::*;
 use check_enum::*;
 use check_function::*;
 use check_identifier::*;
 use check_instance::*;
+use check_latch::*;
 use check_msb_lsb::*;
 use check_number::*;
 use check_reset::*;
 use check_statement::*;
 use check_system_function::*;
+use check_target::*;
+use check_union::*;
+use check_width::*;
+use create_dependency_graph::*;
 use create_reference::*;
 use create_symbol_table::*;
 
 use crate::analyzer_error::AnalyzerError;
-use veryl_metadata::Lint;
+use veryl_metadata::{Build, Lint};
 use veryl_parser::veryl_walker::Handler;
 
 pub struct Pass1Handlers<'a> {
     check_attribute: CheckAttribute<'a>,
     check_direction: CheckDirection<'a>,
+    check_embed_include: CheckEmbedInclude<'a>,
     check_identifier: CheckIdentifier<'a>,
     check_number: CheckNumber<'a>,
     check_reset: CheckReset<'a>,
@@ -41,11 +60,12 @@ pub struct Pass1Handlers<'a> {
 }
 
 impl<'a> Pass1Handlers<'a> {
-    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+    pub fn new(text: &'a str, build_opt: &'a Build, lint_opt: &'a Lint) -> Self {
         Self {
             check_attribute: CheckAttribute::new(text),
             check_direction: CheckDirection::new(text),
-            check_identifier: CheckIdentifier::new(text, lint_opt),
+            check_embed_include: CheckEmbedInclude::new(text),
+            check_identifier: CheckIdentifier::new(text, build_opt, lint_opt),
             check_number: CheckNumber::new(text),
             check_reset: CheckReset::new(text),
             check_statement: CheckStatement::new(text),
@@ -58,6 +78,7 @@ impl<'a> Pass1Handlers<'a> {
         vec![
             &mut self.check_attribute as &mut dyn Handler,
             &mut self.check_direction as &mut dyn Handler,
+            &mut self.check_embed_include as &mut dyn Handler,
             &mut self.check_identifier as &mut dyn Handler,
             &mut self.check_number as &mut dyn Handler,
             &mut self.check_reset as &mut dyn Handler,
@@ -71,6 +92,7 @@ impl<'a> Pass1Handlers<'a> {
         let mut ret = Vec::new();
         ret.append(&mut self.check_attribute.errors);
         ret.append(&mut self.check_direction.errors);
+        ret.append(&mut self.check_embed_include.errors);
         ret.append(&mut self.check_identifier.errors);
         ret.append(&mut self.check_number.errors);
         ret.append(&mut self.check_reset.errors);
@@ -82,44 +104,75 @@ impl<'a> Pass1Handlers<'a> {
 }
 
 pub struct Pass2Handlers<'a> {
+    check_assignment: CheckAssignment<'a>,
     check_attribute: CheckAttribute<'a>,
+    check_case: CheckCase<'a>,
+    check_clock_domain: CheckClockDomain<'a>,
     check_enum: CheckEnum<'a>,
     check_function: CheckFunction<'a>,
     check_instance: CheckInstance<'a>,
+    check_latch: CheckLatch<'a>,
     check_msb_lsb: CheckMsbLsb<'a>,
+    check_target: CheckTarget<'a>,
+    check_union: CheckUnion<'a>,
+    check_width: CheckWidth<'a>,
+    create_dependency_graph: CreateDependencyGraph,
     create_reference: CreateReference<'a>,
 }
 
 impl<'a> Pass2Handlers<'a> {
-    pub fn new(text: &'a str, _lint_opt: &'a Lint) -> Self {
+    pub fn new(text: &'a str, build_opt: &'a Build) -> Self {
         Self {
+            check_assignment: CheckAssignment::new(text),
             check_attribute: CheckAttribute::new(text),
+            check_case: CheckCase::new(text),
+            check_clock_domain: CheckClockDomain::new(text),
             check_enum: CheckEnum::new(text),
             check_function: CheckFunction::new(text),
             check_instance: CheckInstance::new(text),
+            check_latch: CheckLatch::new(text),
             check_msb_lsb: CheckMsbLsb::new(text),
+            check_target: CheckTarget::new(text, build_opt),
+            check_union: CheckUnion::new(text),
+            check_width: CheckWidth::new(text),
+            create_dependency_graph: CreateDependencyGraph::new(),
             create_reference: CreateReference::new(text),
         }
     }
 
     pub fn get_handlers(&mut self) -> Vec<&mut dyn Handler> {
         vec![
+            &mut self.check_assignment as &mut dyn Handler,
             &mut self.check_attribute as &mut dyn Handler,
+            &mut self.check_case as &mut dyn Handler,
+            &mut self.check_clock_domain as &mut dyn Handler,
             &mut self.check_enum as &mut dyn Handler,
             &mut self.check_function as &mut dyn Handler,
             &mut self.check_instance as &mut dyn Handler,
+            &mut self.check_latch as &mut dyn Handler,
             &mut self.check_msb_lsb as &mut dyn Handler,
+            &mut self.check_target as &mut dyn Handler,
+            &mut self.check_union as &mut dyn Handler,
+            &mut self.check_width as &mut dyn Handler,
+            &mut self.create_dependency_graph as &mut dyn Handler,
             &mut self.create_reference as &mut dyn Handler,
         ]
     }
 
     pub fn get_errors(&mut self) -> Vec<AnalyzerError> {
         let mut ret = Vec::new();
+        ret.append(&mut self.check_assignment.errors);
         // errors of check_attribute are reported at pass1
+        ret.append(&mut self.check_case.errors);
+        ret.append(&mut self.check_clock_domain.errors);
         ret.append(&mut self.check_enum.errors);
         ret.append(&mut self.check_function.errors);
         ret.append(&mut self.check_instance.errors);
+        ret.append(&mut self.check_latch.errors);
         ret.append(&mut self.check_msb_lsb.errors);
+        ret.append(&mut self.check_target.errors);
+        ret.append(&mut self.check_union.errors);
+        ret.append(&mut self.check_width.errors);
         ret.append(&mut self.create_reference.errors);
         ret
     }
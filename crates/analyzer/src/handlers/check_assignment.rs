@@ -0,0 +1,451 @@
+use crate::allow_table;
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::Evaluator;
+use crate::symbol::{Direction, Symbol, SymbolKind, TypeKind};
+use crate::symbol_table::{self, ResolveResult, SymbolPath, SymbolPathNamespace};
+use std::collections::{HashMap, HashSet};
+use veryl_parser::resource_table::TokenId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::ParolError;
+
+/// Bits driven by a driver
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DriveRange {
+    Whole,
+    Bits(usize, usize),
+    Unknown,
+}
+
+impl DriveRange {
+    /// Non-constant selects are assumed to be disjoint with other selects
+    fn overlaps(&self, other: &DriveRange) -> bool {
+        match (self, other) {
+            (DriveRange::Whole, _) | (_, DriveRange::Whole) => true,
+            (DriveRange::Bits(lsb0, width0), DriveRange::Bits(lsb1, width1)) => {
+                lsb0 < &(lsb1 + width1) && lsb1 < &(lsb0 + width0)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Driver {
+    block: usize,
+    /// Branches of generate if which contain the driver
+    branches: Vec<(usize, usize)>,
+    range: DriveRange,
+    token: VerylToken,
+}
+
+impl Driver {
+    fn conflicts(&self, other: &Driver) -> bool {
+        let exclusive = self
+            .branches
+            .iter()
+            .any(|(x, y)| other.branches.iter().any(|(z, w)| x == z && y != w));
+        self.block != other.block && !exclusive && self.range.overlaps(&other.range)
+    }
+}
+
+/// Collects identifiers driven by an output port connection
+#[derive(Default)]
+struct Connection {
+    identifiers: Vec<ExpressionIdentifier>,
+}
+
+impl VerylWalker for Connection {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        // Selects are not walked because identifiers in them are not driven
+        self.identifiers.push(arg.clone());
+    }
+}
+
+#[derive(Default)]
+pub struct CheckAssignment<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    blocks: usize,
+    current_block: Option<usize>,
+    in_function: bool,
+    generate_ifs: Vec<(usize, Vec<(TokenId, TokenId)>)>,
+    drivers: HashMap<TokenId, Vec<Driver>>,
+    reported: HashSet<(TokenId, usize)>,
+}
+
+impl<'a> CheckAssignment<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+
+    fn new_block(&mut self) -> usize {
+        self.blocks += 1;
+        self.blocks
+    }
+
+    fn resolve<T: Into<SymbolPathNamespace>>(path: T) -> Option<Symbol> {
+        if let Ok(x) = symbol_table::resolve(path) {
+            x.full_path.first().cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the direction of the port or modport member referred by the path
+    fn direction(full_path: &[Symbol]) -> Option<Direction> {
+        let port = match full_path.first().map(|x| &x.kind) {
+            Some(SymbolKind::Port(x)) => x,
+            _ => return None,
+        };
+        if !matches!(port.direction, Direction::Modport) {
+            return Some(port.direction.clone());
+        }
+
+        let member = full_path.get(1)?;
+        let path = match port.r#type.as_ref().map(|x| &x.kind) {
+            Some(TypeKind::UserDefined(x)) => SymbolPath::new(x),
+            _ => return None,
+        };
+        match symbol_table::get(&path, &full_path[0].namespace) {
+            Ok(ResolveResult { found: Some(x), .. }) => match x.kind {
+                SymbolKind::Modport(x) => x
+                    .members
+                    .into_iter()
+                    .find(|x| x.name == member.token.text)
+                    .map(|x| x.direction),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn path_text(full_path: &[Symbol]) -> String {
+        let names: Vec<String> = full_path.iter().map(|x| x.token.text.to_string()).collect();
+        names.join(".")
+    }
+
+    /// Checks assignment to input ports of modules and input arguments of functions
+    fn check_input<T: Into<SymbolPathNamespace>>(&mut self, path: T, token: &VerylToken) {
+        if let Ok(x) = symbol_table::resolve(path) {
+            if let Some(Direction::Input) = Self::direction(&x.full_path) {
+                self.errors.push(AnalyzerError::assignment_to_input(
+                    &Self::path_text(&x.full_path),
+                    self.text,
+                    token,
+                ));
+            }
+        }
+    }
+
+    fn range(symbol: &Symbol, selects: &[&Select], member: bool) -> DriveRange {
+        if member {
+            DriveRange::Unknown
+        } else if selects.is_empty() {
+            DriveRange::Whole
+        } else if let Some((lsb, width)) = Evaluator::new().selected_bits(symbol, selects) {
+            DriveRange::Bits(lsb, width)
+        } else {
+            DriveRange::Unknown
+        }
+    }
+
+    fn drive_expression_identifier(&mut self, arg: &ExpressionIdentifier, block: usize) {
+        if let ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) =
+            &*arg.expression_identifier_group
+        {
+            if let Some(symbol) = Self::resolve(arg) {
+                let selects: Vec<&Select> = x
+                    .expression_identifier_group_list1
+                    .iter()
+                    .map(|x| x.select.as_ref())
+                    .collect();
+                let member = !x.expression_identifier_group_list2.is_empty();
+                let range = Self::range(&symbol, &selects, member);
+                self.drive(&symbol, range, &arg.identifier.identifier_token, block);
+            }
+        }
+    }
+
+    fn drive_hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier, block: usize) {
+        if let Some(symbol) = Self::resolve(arg) {
+            let selects: Vec<&Select> = arg
+                .hierarchical_identifier_list
+                .iter()
+                .map(|x| x.select.as_ref())
+                .collect();
+            let member = !arg.hierarchical_identifier_list0.is_empty();
+            let range = Self::range(&symbol, &selects, member);
+            self.drive(&symbol, range, &arg.identifier.identifier_token, block);
+        }
+    }
+
+    fn drive(&mut self, symbol: &Symbol, range: DriveRange, token: &VerylToken, block: usize) {
+        if !matches!(symbol.kind, SymbolKind::Variable(_) | SymbolKind::Port(_)) {
+            return;
+        }
+
+        let id = token.token.id;
+        let branches = self
+            .generate_ifs
+            .iter()
+            .filter_map(|(x, ranges)| {
+                ranges
+                    .iter()
+                    .position(|(begin, end)| *begin < id && id < *end)
+                    .map(|y| (*x, y))
+            })
+            .collect();
+        let driver = Driver {
+            block,
+            branches,
+            range,
+            token: token.clone(),
+        };
+
+        let drivers = self.drivers.entry(symbol.token.id).or_default();
+        let allowed = allow_table::contains("multiple_drivers");
+        if let (Some(x), false) = (drivers.iter().find(|x| x.conflicts(&driver)), allowed) {
+            if self.reported.insert((symbol.token.id, block)) {
+                self.errors.push(AnalyzerError::multiple_drivers(
+                    &token.text(),
+                    self.text,
+                    token,
+                    &x.token,
+                ));
+            }
+        }
+        drivers.push(driver);
+    }
+
+    fn enter_module(&mut self) {
+        self.drivers.clear();
+        self.reported.clear();
+    }
+}
+
+impl<'a> Handler for CheckAssignment<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckAssignment<'a> {
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::Assignment(_) = &*arg.identifier_statement_group {
+                let target = &arg.expression_identifier;
+                self.check_input(target.as_ref(), &target.identifier.identifier_token);
+                if let (Some(block), false) = (self.current_block, self.in_function) {
+                    self.drive_expression_identifier(&arg.expression_identifier, block);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if arg.var_declaration_opt0.is_some() && !self.in_function {
+                if let Some(symbol) = Self::resolve(arg.identifier.as_ref()) {
+                    let block = self.new_block();
+                    self.drive(
+                        &symbol,
+                        DriveRange::Whole,
+                        &arg.identifier.identifier_token,
+                        block,
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn factor(&mut self, arg: &Factor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let (Factor::ExpressionIdentifierFactorOpt(x), false) = (arg, self.in_function) {
+                let identifier = &x.expression_identifier;
+                if let (Ok(y), None) = (symbol_table::resolve(identifier.as_ref()), &x.factor_opt) {
+                    let modport = matches!(
+                        y.full_path.first().map(|x| &x.kind),
+                        Some(SymbolKind::Port(x)) if matches!(x.direction, Direction::Modport)
+                    );
+                    if let (Some(Direction::Output), true) =
+                        (Self::direction(&y.full_path), modport)
+                    {
+                        self.errors.push(AnalyzerError::reference_to_output(
+                            &Self::path_text(&y.full_path),
+                            self.text,
+                            &identifier.identifier.identifier_token,
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn always_ff_declaration(&mut self, _arg: &AlwaysFfDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.current_block = Some(self.new_block()),
+            HandlerPoint::After => self.current_block = None,
+        }
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, _arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.current_block = Some(self.new_block()),
+            HandlerPoint::After => self.current_block = None,
+        }
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let target = &arg.hierarchical_identifier;
+            self.check_input(target.as_ref(), &target.identifier.identifier_token);
+            let block = self.new_block();
+            self.drive_hierarchical_identifier(target, block);
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let ports = match Self::resolve(arg.scoped_identifier.as_ref()).map(|x| x.kind) {
+                Some(SymbolKind::Module(x)) => x.ports,
+                _ => return Ok(()),
+            };
+
+            let items: Vec<InstPortItem> = match arg.inst_declaration_opt1 {
+                Some(ref x) => match x.inst_declaration_opt2 {
+                    Some(ref x) => x.inst_port_list.as_ref().into(),
+                    None => return Ok(()),
+                },
+                None => return Ok(()),
+            };
+
+            for item in items {
+                let name = item.identifier.identifier_token.token.text;
+                let output = ports.iter().any(|x| {
+                    x.name == name
+                        && matches!(x.property.direction, Direction::Output | Direction::Inout)
+                });
+                if !output {
+                    continue;
+                }
+
+                let block = self.new_block();
+                if let Some(ref x) = item.inst_port_item_opt {
+                    let mut connection = Connection::default();
+                    connection.expression(&x.expression);
+                    for x in &connection.identifiers {
+                        self.check_input(x, &x.identifier.identifier_token);
+                        self.drive_expression_identifier(x, block);
+                    }
+                } else if let Some(symbol) = Self::resolve(item.identifier.as_ref()) {
+                    self.check_input(item.identifier.as_ref(), &item.identifier.identifier_token);
+                    self.drive(
+                        &symbol,
+                        DriveRange::Whole,
+                        &item.identifier.identifier_token,
+                        block,
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn module_if_declaration(&mut self, arg: &ModuleIfDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let x = &arg.module_named_block;
+                let mut ranges = vec![(
+                    x.l_brace.l_brace_token.token.id,
+                    x.r_brace.r_brace_token.token.id,
+                )];
+                for x in &arg.module_if_declaration_list {
+                    let x = &x.module_optional_named_block;
+                    ranges.push((
+                        x.l_brace.l_brace_token.token.id,
+                        x.r_brace.r_brace_token.token.id,
+                    ));
+                }
+                if let Some(ref x) = arg.module_if_declaration_opt {
+                    let x = &x.module_optional_named_block;
+                    ranges.push((
+                        x.l_brace.l_brace_token.token.id,
+                        x.r_brace.r_brace_token.token.id,
+                    ));
+                }
+                let id = self.new_block();
+                self.generate_ifs.push((id, ranges));
+            }
+            HandlerPoint::After => {
+                self.generate_ifs.pop();
+            }
+        }
+        Ok(())
+    }
+
+    fn interface_if_declaration(&mut self, arg: &InterfaceIfDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let x = &arg.interface_named_block;
+                let mut ranges = vec![(
+                    x.l_brace.l_brace_token.token.id,
+                    x.r_brace.r_brace_token.token.id,
+                )];
+                for x in &arg.interface_if_declaration_list {
+                    let x = &x.interface_optional_named_block;
+                    ranges.push((
+                        x.l_brace.l_brace_token.token.id,
+                        x.r_brace.r_brace_token.token.id,
+                    ));
+                }
+                if let Some(ref x) = arg.interface_if_declaration_opt {
+                    let x = &x.interface_optional_named_block;
+                    ranges.push((
+                        x.l_brace.l_brace_token.token.id,
+                        x.r_brace.r_brace_token.token.id,
+                    ));
+                }
+                let id = self.new_block();
+                self.generate_ifs.push((id, ranges));
+            }
+            HandlerPoint::After => {
+                self.generate_ifs.pop();
+            }
+        }
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, _arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_function = true,
+            HandlerPoint::After => self.in_function = false,
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, _arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.enter_module();
+        }
+        Ok(())
+    }
+
+    fn interface_declaration(&mut self, _arg: &InterfaceDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.enter_module();
+        }
+        Ok(())
+    }
+}
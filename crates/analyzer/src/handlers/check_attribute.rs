@@ -1,5 +1,7 @@
 use crate::allow_table;
 use crate::analyzer_error::AnalyzerError;
+use crate::feature_table;
+use veryl_metadata::Lint;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 use veryl_parser::ParolError;
@@ -19,6 +21,18 @@ impl<'a> CheckAttribute<'a> {
         }
     }
 
+    /// `cfg` attribute is not supported at the list groups
+    fn check_cfg(&mut self, x: &Attribute) {
+        let identifier = x.identifier.identifier_token.text();
+        if identifier.as_str() == "cfg" {
+            self.errors.push(AnalyzerError::invalid_attribute(
+                &identifier,
+                self.text,
+                &x.identifier.identifier_token,
+            ));
+        }
+    }
+
     pub fn allow_pop(x: &Attribute) {
         let identifier = x.identifier.identifier_token.text();
         if identifier.as_str() == "allow" {
@@ -44,7 +58,7 @@ impl<'a> VerylGrammarTrait for CheckAttribute<'a> {
                         if args.len() != 1 {
                             false
                         } else {
-                            matches!(args[0], AttributeItem::Identifier(_))
+                            args[0].identifier().is_some()
                         }
                     } else {
                         false
@@ -59,6 +73,37 @@ impl<'a> VerylGrammarTrait for CheckAttribute<'a> {
                         ));
                     }
                 }
+                "cfg" => {
+                    let valid_arg = if let Some(ref x) = arg.attribute_opt {
+                        let args: Vec<AttributeItem> = x.attribute_list.as_ref().into();
+                        if args.len() != 1 {
+                            false
+                        } else {
+                            feature_table::eval(&args[0]).is_some()
+                        }
+                    } else {
+                        false
+                    };
+
+                    if !valid_arg {
+                        self.errors.push(AnalyzerError::mismatch_attribute_args(
+                            &identifier,
+                            "single feature predicate",
+                            self.text,
+                            &arg.identifier.identifier_token,
+                        ));
+                    } else if let Some(ref x) = arg.attribute_opt {
+                        let args: Vec<AttributeItem> = x.attribute_list.as_ref().into();
+                        for token in feature_table::unknown_features(&args[0]) {
+                            let text = token.text();
+                            self.errors.push(AnalyzerError::unknown_feature(
+                                &text[1..text.len() - 1],
+                                self.text,
+                                &token,
+                            ));
+                        }
+                    }
+                }
                 "sv" => {
                     let valid_arg = if let Some(ref x) = arg.attribute_opt {
                         let args: Vec<AttributeItem> = x.attribute_list.as_ref().into();
@@ -85,16 +130,16 @@ impl<'a> VerylGrammarTrait for CheckAttribute<'a> {
                         let args: Vec<AttributeItem> = x.attribute_list.as_ref().into();
                         if args.is_empty() {
                             false
-                        } else if let AttributeItem::Identifier(x) = &args[0] {
-                            let text = x.identifier.identifier_token.text();
-                            if !ALLOWABLE_ERROR.contains(&text.as_str()) {
+                        } else if let Some(x) = args[0].identifier() {
+                            let text = x.identifier_token.text();
+                            if !Lint::RULES.contains(&text.as_str()) {
                                 self.errors.push(AnalyzerError::invalid_allow(
                                     &text,
                                     self.text,
                                     &arg.identifier.identifier_token,
                                 ));
                             }
-                            allow_table::push(x.identifier.identifier_token.token.text);
+                            allow_table::push(x.identifier_token.token.text);
                             true
                         } else {
                             false
@@ -112,6 +157,16 @@ impl<'a> VerylGrammarTrait for CheckAttribute<'a> {
                         ));
                     }
                 }
+                "synchronizer" => {
+                    if arg.attribute_opt.is_some() {
+                        self.errors.push(AnalyzerError::mismatch_attribute_args(
+                            &identifier,
+                            "no argument",
+                            self.text,
+                            &arg.identifier.identifier_token,
+                        ));
+                    }
+                }
                 _ => {
                     self.errors.push(AnalyzerError::unknown_attribute(
                         &identifier,
@@ -125,63 +180,112 @@ impl<'a> VerylGrammarTrait for CheckAttribute<'a> {
     }
 
     fn modport_group(&mut self, arg: &ModportGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            for x in &arg.modport_group_list {
-                Self::allow_pop(&x.attribute);
+        match self.point {
+            HandlerPoint::Before => {
+                for x in &arg.modport_group_list {
+                    self.check_cfg(&x.attribute);
+                }
+            }
+            HandlerPoint::After => {
+                for x in &arg.modport_group_list {
+                    Self::allow_pop(&x.attribute);
+                }
             }
         }
         Ok(())
     }
 
     fn enum_group(&mut self, arg: &EnumGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            for x in &arg.enum_group_list {
-                Self::allow_pop(&x.attribute);
+        match self.point {
+            HandlerPoint::Before => {
+                for x in &arg.enum_group_list {
+                    self.check_cfg(&x.attribute);
+                }
+            }
+            HandlerPoint::After => {
+                for x in &arg.enum_group_list {
+                    Self::allow_pop(&x.attribute);
+                }
             }
         }
         Ok(())
     }
 
     fn struct_group(&mut self, arg: &StructGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            for x in &arg.struct_group_list {
-                Self::allow_pop(&x.attribute);
+        match self.point {
+            HandlerPoint::Before => {
+                for x in &arg.struct_group_list {
+                    self.check_cfg(&x.attribute);
+                }
+            }
+            HandlerPoint::After => {
+                for x in &arg.struct_group_list {
+                    Self::allow_pop(&x.attribute);
+                }
             }
         }
         Ok(())
     }
 
     fn inst_parameter_group(&mut self, arg: &InstParameterGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            for x in &arg.inst_parameter_group_list {
-                Self::allow_pop(&x.attribute);
+        match self.point {
+            HandlerPoint::Before => {
+                for x in &arg.inst_parameter_group_list {
+                    self.check_cfg(&x.attribute);
+                }
+            }
+            HandlerPoint::After => {
+                for x in &arg.inst_parameter_group_list {
+                    Self::allow_pop(&x.attribute);
+                }
             }
         }
         Ok(())
     }
 
     fn inst_port_group(&mut self, arg: &InstPortGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            for x in &arg.inst_port_group_list {
-                Self::allow_pop(&x.attribute);
+        match self.point {
+            HandlerPoint::Before => {
+                for x in &arg.inst_port_group_list {
+                    self.check_cfg(&x.attribute);
+                }
+            }
+            HandlerPoint::After => {
+                for x in &arg.inst_port_group_list {
+                    Self::allow_pop(&x.attribute);
+                }
             }
         }
         Ok(())
     }
 
     fn with_parameter_group(&mut self, arg: &WithParameterGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            for x in &arg.with_parameter_group_list {
-                Self::allow_pop(&x.attribute);
+        match self.point {
+            HandlerPoint::Before => {
+                for x in &arg.with_parameter_group_list {
+                    self.check_cfg(&x.attribute);
+                }
+            }
+            HandlerPoint::After => {
+                for x in &arg.with_parameter_group_list {
+                    Self::allow_pop(&x.attribute);
+                }
             }
         }
         Ok(())
     }
 
     fn port_declaration_group(&mut self, arg: &PortDeclarationGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            for x in &arg.port_declaration_group_list {
-                Self::allow_pop(&x.attribute);
+        match self.point {
+            HandlerPoint::Before => {
+                for x in &arg.port_declaration_group_list {
+                    self.check_cfg(&x.attribute);
+                }
+            }
+            HandlerPoint::After => {
+                for x in &arg.port_declaration_group_list {
+                    Self::allow_pop(&x.attribute);
+                }
             }
         }
         Ok(())
@@ -223,5 +327,3 @@ impl<'a> VerylGrammarTrait for CheckAttribute<'a> {
         Ok(())
     }
 }
-
-const ALLOWABLE_ERROR: [&str; 3] = ["missing_port", "missing_reset_statement", "unused_variable"];
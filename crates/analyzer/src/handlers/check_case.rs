@@ -0,0 +1,249 @@
+use crate::allow_table;
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::namespace_table;
+use crate::symbol::{SymbolKind, Type, TypeKind};
+use crate::symbol_table::{self, ResolveResult, SymbolPath, SymbolPathNamespace};
+use num_bigint::BigUint;
+use std::collections::{HashMap, HashSet};
+use veryl_parser::resource_table::StrId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::{ParolError, Stringifier};
+
+/// Maximum selector width whose case items are checked to cover all values
+const MAX_FULL_CASE_WIDTH: usize = 16;
+
+/// Collects tokens of an expression
+#[derive(Default)]
+struct Tokens {
+    tokens: Vec<VerylToken>,
+}
+
+impl VerylWalker for Tokens {
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        self.tokens.push(arg.clone());
+    }
+}
+
+impl Tokens {
+    fn new(arg: &Expression) -> Self {
+        let mut ret = Self::default();
+        ret.expression(arg);
+        ret
+    }
+
+    /// Returns the path if the expression consists of a scoped identifier only
+    fn path(&self) -> Option<Vec<StrId>> {
+        let mut ret = Vec::new();
+        for (i, x) in self.tokens.iter().enumerate() {
+            let text = x.text();
+            if i % 2 == 0 {
+                if !text.starts_with(|x: char| x.is_ascii_alphabetic() || x == '_') {
+                    return None;
+                }
+                ret.push(x.token.text);
+            } else if text != "::" {
+                return None;
+            }
+        }
+        if self.tokens.len() % 2 == 1 {
+            Some(ret)
+        } else {
+            None
+        }
+    }
+}
+
+/// Case item evaluated to a constant
+struct Item {
+    value: BigUint,
+    text: String,
+    token: VerylToken,
+}
+
+pub struct CheckCase<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+}
+
+impl<'a> CheckCase<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+        }
+    }
+
+    /// Items are compared without truncation because they are extended to the widest operand
+    fn item(arg: &Expression) -> Option<Item> {
+        let value = match Evaluator::new().expression(arg) {
+            Evaluated::Fixed(x) if !x.is_xz() => x.payload,
+            _ => return None,
+        };
+        let mut stringifier = Stringifier::new();
+        stringifier.expression(arg);
+        let token = Tokens::new(arg).tokens.first()?.clone();
+        Some(Item {
+            value,
+            text: stringifier.as_str().to_string(),
+            token,
+        })
+    }
+
+    /// Reports items which have the same value as a previous item
+    fn check_duplicated(&mut self, items: &[Item]) {
+        if allow_table::contains("duplicated_case_item") {
+            return;
+        }
+        let mut values: HashMap<&BigUint, &Item> = HashMap::new();
+        for x in items {
+            if let Some(previous) = values.get(&x.value) {
+                self.errors.push(AnalyzerError::duplicated_case_item(
+                    &x.text,
+                    self.text,
+                    &x.token,
+                    &previous.token,
+                ));
+            } else {
+                values.insert(&x.value, x);
+            }
+        }
+    }
+
+    /// Returns whether the case statement has `default` item,
+    /// or its items cover all values or enum members of the selector
+    pub fn is_full(arg: &CaseStatement) -> bool {
+        let mut values = HashSet::new();
+        for x in &arg.case_statement_list {
+            match &*x.case_item.case_item_group {
+                CaseItemGroup::Expression(x) => {
+                    if let Some(x) = Self::item(&x.expression) {
+                        values.insert(x.value);
+                    }
+                }
+                CaseItemGroup::Defaul(_) => return true,
+            }
+        }
+
+        let full = match Evaluator::new().expression(&arg.expression).width() {
+            Some(x) if x <= MAX_FULL_CASE_WIDTH => values.len() >= 1 << x,
+            _ => false,
+        };
+        full || match Self::selector_enum(&arg.expression) {
+            Some((_, members)) => members.iter().all(|(_, x)| values.contains(x)),
+            None => false,
+        }
+    }
+
+    /// Returns the name and member values of the enum type of the selector
+    pub fn selector_enum(arg: &Expression) -> Option<(StrId, Vec<(StrId, BigUint)>)> {
+        let tokens = Tokens::new(arg);
+        let path = SymbolPath::new(&tokens.path()?);
+        let namespace = namespace_table::get(tokens.tokens[0].token.id)?;
+        let symbol = symbol_table::resolve(SymbolPathNamespace(path, namespace))
+            .ok()?
+            .found?;
+        let r#type: Option<Type> = match symbol.kind {
+            SymbolKind::Variable(x) => Some(x.r#type),
+            SymbolKind::Port(x) => x.r#type,
+            SymbolKind::Parameter(x) => Some(x.r#type),
+            _ => None,
+        };
+        let r#type = r#type?;
+        let path = match r#type.kind {
+            TypeKind::UserDefined(ref x) if r#type.array.is_empty() => SymbolPath::new(x),
+            _ => return None,
+        };
+
+        let r#enum = match symbol_table::get(&path, &symbol.namespace) {
+            Ok(ResolveResult { found: Some(x), .. }) => x,
+            _ => return None,
+        };
+        let members = match r#enum.kind {
+            SymbolKind::Enum(ref x) => &x.members,
+            _ => return None,
+        };
+
+        let mut namespace = r#enum.namespace.clone();
+        namespace.push(r#enum.token.text);
+        let mut ret = Vec::new();
+        for x in members {
+            let member = symbol_table::get(&SymbolPath::new(&[*x]), &namespace)
+                .ok()?
+                .found?;
+            match member.evaluate() {
+                Evaluated::Fixed(value) if !value.is_xz() => ret.push((*x, value.payload)),
+                _ => return None,
+            }
+        }
+        Some((r#enum.token.text, ret))
+    }
+}
+
+impl<'a> Handler for CheckCase<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckCase<'a> {
+    fn case_statement(&mut self, arg: &CaseStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let mut items = Vec::new();
+            let mut default = false;
+            for x in &arg.case_statement_list {
+                match &*x.case_item.case_item_group {
+                    CaseItemGroup::Expression(x) => {
+                        if let Some(x) = Self::item(&x.expression) {
+                            items.push(x);
+                        }
+                    }
+                    CaseItemGroup::Defaul(_) => default = true,
+                }
+            }
+            self.check_duplicated(&items);
+
+            if default || allow_table::contains("incomplete_case") {
+                return Ok(());
+            }
+            if let Some((name, members)) = Self::selector_enum(&arg.expression) {
+                let missing: Vec<String> = members
+                    .iter()
+                    .filter(|(_, value)| items.iter().all(|x| x.value != *value))
+                    .map(|(x, _)| x.to_string())
+                    .collect();
+                if !missing.is_empty() {
+                    self.errors.push(AnalyzerError::incomplete_case(
+                        &name.to_string(),
+                        &missing.join(", "),
+                        self.text,
+                        &arg.case.case_token,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn case_expression(&mut self, arg: &CaseExpression) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let mut items = Vec::new();
+            let expressions = std::iter::once(arg.expression0.as_ref()).chain(
+                arg.case_expression_list
+                    .iter()
+                    .map(|x| x.expression.as_ref()),
+            );
+            for x in expressions {
+                if let Some(x) = Self::item(x) {
+                    items.push(x);
+                }
+            }
+            self.check_duplicated(&items);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,358 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::symbol::{Symbol, SymbolKind, TypeKind};
+use crate::symbol_table::{self, SymbolPathNamespace};
+use std::collections::HashMap;
+use std::fmt;
+use veryl_parser::resource_table::{StrId, TokenId};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Domain {
+    /// Domain given by annotation like `'a`
+    Explicit(StrId),
+    /// Domain of a clock signal without annotation
+    Clock(TokenId, StrId),
+}
+
+impl Domain {
+    /// Explicit domains and clock signals without annotation can't be compared
+    /// because the relation between them is unknown.
+    fn crosses(&self, other: &Domain) -> bool {
+        match (self, other) {
+            (Domain::Explicit(x), Domain::Explicit(y)) => x != y,
+            (Domain::Clock(x, _), Domain::Clock(y, _)) => x != y,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Domain::Explicit(x) => write!(f, "{x}"),
+            Domain::Clock(_, x) => write!(f, "{x}"),
+        }
+    }
+}
+
+/// Assignments in an assign declaration or always_comb
+#[derive(Default)]
+struct CombBlock {
+    targets: Vec<Symbol>,
+    sources: Vec<(Symbol, VerylToken)>,
+}
+
+#[derive(Default)]
+pub struct CheckClockDomain<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    in_always_ff: bool,
+    in_synchronizer: usize,
+    clock_domain: Option<Domain>,
+    lefthand_side: Option<TokenId>,
+    assigned_domains: HashMap<TokenId, Domain>,
+    references: Vec<(Symbol, Domain, VerylToken)>,
+    comb_block: Option<CombBlock>,
+    comb_blocks: Vec<CombBlock>,
+}
+
+impl<'a> CheckClockDomain<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+
+    fn resolve<T: Into<SymbolPathNamespace>>(path: T) -> Option<Symbol> {
+        if let Ok(x) = symbol_table::resolve(path) {
+            x.full_path.first().cloned()
+        } else {
+            None
+        }
+    }
+
+    fn explicit_domain(symbol: &Symbol) -> Option<StrId> {
+        match &symbol.kind {
+            SymbolKind::Port(x) => x.clock_domain,
+            SymbolKind::Variable(x) => x.clock_domain,
+            _ => None,
+        }
+    }
+
+    fn type_kind(symbol: &Symbol) -> Option<&TypeKind> {
+        match &symbol.kind {
+            SymbolKind::Port(x) => x.r#type.as_ref().map(|x| &x.kind),
+            SymbolKind::Variable(x) => Some(&x.r#type.kind),
+            _ => None,
+        }
+    }
+
+    fn is_signal(symbol: &Symbol) -> bool {
+        matches!(symbol.kind, SymbolKind::Port(_) | SymbolKind::Variable(_))
+    }
+
+    fn check_signal_type(&mut self, arg: &HierarchicalIdentifier, expected: &str) {
+        if let Some(symbol) = Self::resolve(arg) {
+            let actual = match Self::type_kind(&symbol) {
+                Some(TypeKind::Clock) => "clock",
+                Some(TypeKind::Reset) => "reset",
+                _ => return,
+            };
+            if actual != expected {
+                self.errors.push(AnalyzerError::mismatch_type(
+                    &arg.identifier.identifier_token.text(),
+                    expected,
+                    actual,
+                    self.text,
+                    &arg.identifier.identifier_token,
+                ));
+            }
+        }
+    }
+
+    fn push_crossing(&mut self, token: &VerylToken, domain: &Domain, expected: &Domain) {
+        self.errors.push(AnalyzerError::clock_domain_crossing(
+            &token.text(),
+            &domain.to_string(),
+            &expected.to_string(),
+            self.text,
+            token,
+        ));
+    }
+
+    /// Collects the domains of the signal.
+    /// The domain of a signal driven by combinational logic is propagated from its sources.
+    fn domains(
+        &self,
+        symbol: &Symbol,
+        cache: &mut HashMap<TokenId, Vec<Domain>>,
+        stack: &mut Vec<TokenId>,
+    ) -> Vec<Domain> {
+        let id = symbol.token.id;
+        if let Some(x) = Self::explicit_domain(symbol) {
+            return vec![Domain::Explicit(x)];
+        }
+        if let Some(x) = self.assigned_domains.get(&id) {
+            return vec![*x];
+        }
+        if let Some(x) = cache.get(&id) {
+            return x.clone();
+        }
+        if stack.contains(&id) {
+            return Vec::new();
+        }
+
+        stack.push(id);
+        let mut ret = Vec::new();
+        for block in &self.comb_blocks {
+            if !block.targets.iter().any(|x| x.token.id == id) {
+                continue;
+            }
+            for (source, _) in &block.sources {
+                for domain in self.domains(source, cache, stack) {
+                    if !ret.contains(&domain) {
+                        ret.push(domain);
+                    }
+                }
+            }
+        }
+        stack.pop();
+
+        cache.insert(id, ret.clone());
+        ret
+    }
+
+    fn check_module(&mut self) {
+        let mut cache = HashMap::new();
+        let mut crossings = Vec::new();
+
+        // references in always_ff
+        for (symbol, expected, token) in &self.references {
+            let domains = self.domains(symbol, &mut cache, &mut Vec::new());
+            if let Some(domain) = domains.iter().find(|x| x.crosses(expected)) {
+                crossings.push((token.clone(), *domain, *expected));
+            }
+        }
+
+        // combinational paths to signals with explicit domain
+        for block in &self.comb_blocks {
+            for target in &block.targets {
+                let expected = match Self::explicit_domain(target) {
+                    Some(x) => Domain::Explicit(x),
+                    None => continue,
+                };
+                for (source, token) in &block.sources {
+                    let domains = self.domains(source, &mut cache, &mut Vec::new());
+                    if let Some(domain) = domains.iter().find(|x| x.crosses(&expected)) {
+                        crossings.push((token.clone(), *domain, expected));
+                    }
+                }
+            }
+        }
+
+        for (token, domain, expected) in &crossings {
+            self.push_crossing(token, domain, expected);
+        }
+    }
+}
+
+impl<'a> Handler for CheckClockDomain<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckClockDomain<'a> {
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::Assignment(_) = &*arg.identifier_statement_group {
+                let token = &arg.expression_identifier.identifier.identifier_token;
+                self.lefthand_side = Some(token.token.id);
+
+                if let (true, Some(domain)) = (self.in_always_ff, self.clock_domain) {
+                    if let Some(symbol) = Self::resolve(arg.expression_identifier.as_ref()) {
+                        let explicit = Self::explicit_domain(&symbol).map(Domain::Explicit);
+                        if let Some(explicit) = explicit {
+                            if explicit.crosses(&domain) && self.in_synchronizer == 0 {
+                                self.push_crossing(token, &explicit, &domain);
+                            }
+                        } else {
+                            self.assigned_domains
+                                .entry(symbol.token.id)
+                                .or_insert(domain);
+                        }
+                    }
+                }
+
+                if let Some(ref mut block) = self.comb_block {
+                    if let Some(symbol) = Self::resolve(arg.expression_identifier.as_ref()) {
+                        block.targets.push(symbol);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.identifier.identifier_token;
+            if self.lefthand_side == Some(token.token.id) {
+                self.lefthand_side = None;
+                return Ok(());
+            }
+
+            if let (true, Some(domain)) = (self.in_always_ff, self.clock_domain) {
+                if self.in_synchronizer == 0 {
+                    if let Some(symbol) = Self::resolve(arg) {
+                        if Self::is_signal(&symbol) {
+                            self.references.push((symbol, domain, token.clone()));
+                        }
+                    }
+                }
+            }
+
+            if let Some(ref mut block) = self.comb_block {
+                if let Some(symbol) = Self::resolve(arg) {
+                    if Self::is_signal(&symbol) {
+                        block.sources.push((symbol, token.clone()));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let clock = &arg.always_ff_clock.hierarchical_identifier;
+                self.check_signal_type(clock, "clock");
+                if let Some(ref x) = arg.always_ff_declaration_opt {
+                    let reset = &x.always_ff_reset.hierarchical_identifier;
+                    self.check_signal_type(reset, "reset");
+                }
+
+                // clock without annotation is a domain by itself
+                self.clock_domain =
+                    Self::resolve(clock.as_ref()).map(|x| match Self::explicit_domain(&x) {
+                        Some(domain) => Domain::Explicit(domain),
+                        None => Domain::Clock(x.token.id, x.token.text),
+                    });
+                self.in_always_ff = true;
+            }
+            HandlerPoint::After => {
+                self.clock_domain = None;
+                self.in_always_ff = false;
+            }
+        }
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, _arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.comb_block = Some(CombBlock::default()),
+            HandlerPoint::After => {
+                // all signals referenced in always_comb are treated as sources of its targets
+                if let Some(block) = self.comb_block.take() {
+                    self.comb_blocks.push(block);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let mut block = CombBlock::default();
+                if let Some(symbol) = Self::resolve(arg.hierarchical_identifier.as_ref()) {
+                    block.targets.push(symbol);
+                }
+                self.comb_block = Some(block);
+            }
+            HandlerPoint::After => {
+                if let Some(block) = self.comb_block.take() {
+                    self.comb_blocks.push(block);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn module_group(&mut self, arg: &ModuleGroup) -> Result<(), ParolError> {
+        let synchronizer = arg
+            .module_group_list
+            .iter()
+            .any(|x| x.attribute.identifier.identifier_token.text() == "synchronizer");
+        if synchronizer {
+            match self.point {
+                HandlerPoint::Before => self.in_synchronizer += 1,
+                HandlerPoint::After => self.in_synchronizer -= 1,
+            }
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, _arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.assigned_domains.clear();
+                self.references.clear();
+                self.comb_blocks.clear();
+            }
+            HandlerPoint::After => {
+                // The domain of a variable without explicit tag is inferred from
+                // the always_ff which assigns it, or propagated through combinational
+                // logic, so the check is deferred until the whole module is visited.
+                self.check_module();
+            }
+        }
+        Ok(())
+    }
+}
@@ -70,4 +70,15 @@ impl<'a> VerylGrammarTrait for CheckDirection<'a> {
         }
         Ok(())
     }
+
+    fn extern_module_declaration(
+        &mut self,
+        _arg: &ExternModuleDeclaration,
+    ) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_module = true,
+            HandlerPoint::After => self.in_module = false,
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,91 @@
+use crate::analyzer_error::AnalyzerError;
+use std::fs;
+use veryl_parser::resource_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+#[derive(Default)]
+pub struct CheckEmbedInclude<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+}
+
+impl<'a> CheckEmbedInclude<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> Handler for CheckEmbedInclude<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+const EMBED_WAY: [&str; 1] = ["inline"];
+const EMBED_LANG: [&str; 1] = ["sv"];
+const INCLUDE_WAY: [&str; 1] = ["inline"];
+
+impl<'a> VerylGrammarTrait for CheckEmbedInclude<'a> {
+    fn embed_declaration(&mut self, arg: &EmbedDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let way = arg.identifier.identifier_token.text();
+            let lang = arg.identifier0.identifier_token.text();
+
+            if !EMBED_WAY.contains(&way.as_str()) {
+                self.errors.push(AnalyzerError::unknown_embed_way(
+                    &way,
+                    self.text,
+                    &arg.identifier.identifier_token,
+                ));
+            }
+
+            if !EMBED_LANG.contains(&lang.as_str()) {
+                self.errors.push(AnalyzerError::unknown_embed_lang(
+                    &lang,
+                    self.text,
+                    &arg.identifier0.identifier_token,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn include_declaration(&mut self, arg: &IncludeDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let way = arg.identifier.identifier_token.text();
+
+            if !INCLUDE_WAY.contains(&way.as_str()) {
+                self.errors.push(AnalyzerError::unknown_include_way(
+                    &way,
+                    self.text,
+                    &arg.identifier.identifier_token,
+                ));
+            }
+
+            let name = arg.string_literal.string_literal_token.text();
+            let name = &name[1..name.len() - 1];
+            let source = resource_table::get_path_value(
+                arg.string_literal.string_literal_token.token.file_path,
+            );
+            let path = match source.as_ref().and_then(|x| x.parent()) {
+                Some(x) => x.join(name),
+                None => name.into(),
+            };
+            if let Err(err) = fs::read_to_string(path) {
+                self.errors.push(AnalyzerError::include_failure(
+                    name,
+                    &err.to_string(),
+                    self.text,
+                    &arg.string_literal.string_literal_token,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::analyzer_error::AnalyzerError;
 use crate::evaluator::{Evaluated, Evaluator};
+use num_traits::Signed;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::VerylToken;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
@@ -9,6 +10,7 @@ pub struct CheckEnum<'a> {
     pub errors: Vec<AnalyzerError>,
     text: &'a str,
     point: HandlerPoint,
+    enum_width: Option<usize>,
     enum_variants: usize,
     enum_member_values: Vec<(Evaluated, VerylToken)>,
 }
@@ -19,6 +21,7 @@ impl<'a> CheckEnum<'a> {
             errors: Vec::new(),
             text,
             point: HandlerPoint::Before,
+            enum_width: None,
             enum_variants: 0,
             enum_member_values: Vec::new(),
         }
@@ -35,32 +38,44 @@ impl<'a> VerylGrammarTrait for CheckEnum<'a> {
     fn enum_declaration(&mut self, arg: &EnumDeclaration) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => {
+                let mut evaluator = Evaluator::new();
+                let r#type: crate::symbol::Type = arg.scalar_type.as_ref().into();
+                self.enum_width = evaluator.type_width(r#type);
                 self.enum_variants = 0;
                 self.enum_member_values.clear();
             }
             HandlerPoint::After => {
-                let mut evaluator = Evaluator::new();
-                let r#type: crate::symbol::Type = arg.scalar_type.as_ref().into();
-                let width = evaluator.type_width(r#type);
-                if let Some(width) = width {
-                    let max_members = 2_usize.pow(width as u32);
-                    if self.enum_variants > max_members {
-                        let name = arg.identifier.identifier_token.text();
-                        self.errors.push(AnalyzerError::too_much_enum_variant(
-                            &name,
-                            self.enum_variants,
-                            width,
-                            self.text,
-                            &arg.identifier.identifier_token,
-                        ));
+                if let Some(width) = self.enum_width {
+                    let max_members = u32::try_from(width)
+                        .ok()
+                        .and_then(|x| 2_usize.checked_pow(x));
+                    if let Some(max_members) = max_members {
+                        if self.enum_variants > max_members {
+                            let name = arg.identifier.identifier_token.text();
+                            self.errors.push(AnalyzerError::too_much_enum_variant(
+                                &name,
+                                self.enum_variants,
+                                width,
+                                self.text,
+                                &arg.identifier.identifier_token,
+                            ));
+                        }
                     }
 
                     for (enum_value, token) in &self.enum_member_values {
-                        if let Evaluated::Fixed { value, .. } = enum_value {
-                            if *value as usize >= max_members {
+                        if let Evaluated::Fixed(value) = enum_value {
+                            // The value which is not wider than the enum can be represented
+                            // because the enum is unsigned
+                            let too_large = match value.to_bigint() {
+                                Some(x) if value.width > width => {
+                                    x.is_negative() || x.bits() > width as u64
+                                }
+                                _ => false,
+                            };
+                            if too_large {
                                 self.errors.push(AnalyzerError::too_large_enum_variant(
                                     &token.text(),
-                                    *value,
+                                    &value.to_string(),
                                     width,
                                     self.text,
                                     token,
@@ -80,6 +95,9 @@ impl<'a> VerylGrammarTrait for CheckEnum<'a> {
             if let Some(ref x) = arg.enum_item_opt {
                 let token = arg.identifier.identifier_token.clone();
                 let mut evaluator = Evaluator::new();
+                if let Some(width) = self.enum_width {
+                    evaluator.context_width.push(width);
+                }
                 let evaluated = evaluator.expression(&x.expression);
                 self.enum_member_values.push((evaluated, token));
             }
@@ -1,7 +1,11 @@
+use crate::allow_table;
 use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::Evaluator;
+use crate::handlers::check_case::CheckCase;
 use crate::symbol::SymbolKind;
 use crate::symbol_table::{self, SymbolPath};
 use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 use veryl_parser::ParolError;
 
@@ -19,6 +23,116 @@ impl<'a> CheckFunction<'a> {
             point: HandlerPoint::Before,
         }
     }
+
+    fn statement_token(arg: &Statement) -> &VerylToken {
+        match arg {
+            Statement::IdentifierStatement(x) => {
+                &x.identifier_statement
+                    .expression_identifier
+                    .identifier
+                    .identifier_token
+            }
+            Statement::IfStatement(x) => &x.if_statement.r#if.if_token,
+            Statement::IfResetStatement(x) => &x.if_reset_statement.if_reset.if_reset_token,
+            Statement::ReturnStatement(x) => &x.return_statement.r#return.return_token,
+            Statement::ForStatement(x) => &x.for_statement.r#for.for_token,
+            Statement::CaseStatement(x) => &x.case_statement.case.case_token,
+        }
+    }
+
+    /// Returns whether the statements return on every path.
+    /// Statements after the point are reported as unreachable.
+    fn statements<'b, T: Iterator<Item = &'b Statement>>(&mut self, statements: T) -> bool {
+        let mut returned = false;
+        for x in statements {
+            if returned {
+                if !allow_table::contains("unreachable_code") {
+                    self.errors.push(AnalyzerError::unreachable_code(
+                        self.text,
+                        Self::statement_token(x),
+                    ));
+                }
+                break;
+            }
+            returned = self.statement(x);
+        }
+        returned
+    }
+
+    fn statement(&mut self, arg: &Statement) -> bool {
+        match arg {
+            Statement::IdentifierStatement(_) => false,
+            Statement::IfStatement(x) => {
+                let x = &x.if_statement;
+                let mut ret =
+                    self.statements(x.if_statement_list.iter().map(|x| x.statement.as_ref()));
+                for x in &x.if_statement_list0 {
+                    ret &= self.statements(
+                        x.if_statement_list0_list
+                            .iter()
+                            .map(|x| x.statement.as_ref()),
+                    );
+                }
+                match x.if_statement_opt {
+                    Some(ref x) => {
+                        ret & self.statements(
+                            x.if_statement_opt_list.iter().map(|x| x.statement.as_ref()),
+                        )
+                    }
+                    None => false,
+                }
+            }
+            Statement::IfResetStatement(x) => {
+                let x = &x.if_reset_statement;
+                let mut ret = self.statements(
+                    x.if_reset_statement_list
+                        .iter()
+                        .map(|x| x.statement.as_ref()),
+                );
+                for x in &x.if_reset_statement_list0 {
+                    ret &= self.statements(
+                        x.if_reset_statement_list0_list
+                            .iter()
+                            .map(|x| x.statement.as_ref()),
+                    );
+                }
+                match x.if_reset_statement_opt {
+                    Some(ref x) => {
+                        ret & self.statements(
+                            x.if_reset_statement_opt_list
+                                .iter()
+                                .map(|x| x.statement.as_ref()),
+                        )
+                    }
+                    None => false,
+                }
+            }
+            Statement::ReturnStatement(_) => true,
+            // The loop body may not be executed
+            Statement::ForStatement(x) => {
+                self.statements(
+                    x.for_statement
+                        .for_statement_list
+                        .iter()
+                        .map(|x| x.statement.as_ref()),
+                );
+                false
+            }
+            Statement::CaseStatement(x) => {
+                let x = &x.case_statement;
+                let mut ret = true;
+                for item in &x.case_statement_list {
+                    ret &= match &*item.case_item.case_item_group0 {
+                        CaseItemGroup0::Statement(x) => self.statement(&x.statement),
+                        CaseItemGroup0::LBraceCaseItemGroup0ListRBrace(x) => self.statements(
+                            x.case_item_group0_list.iter().map(|x| x.statement.as_ref()),
+                        ),
+                    };
+                }
+                ret && CheckCase::is_full(x)
+            }
+        }
+    }
 }
 
 impl<'a> Handler for CheckFunction<'a> {
@@ -28,11 +142,53 @@ impl<'a> Handler for CheckFunction<'a> {
 }
 
 impl<'a> VerylGrammarTrait for CheckFunction<'a> {
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            // variable declarations don't affect control flow
+            let statements =
+                arg.function_declaration_list
+                    .iter()
+                    .filter_map(|x| match &*x.function_item {
+                        FunctionItem::Statement(x) => Some(x.statement.as_ref()),
+                        FunctionItem::VarDeclaration(_) => None,
+                    });
+            if !self.statements(statements) {
+                self.errors.push(AnalyzerError::missing_return(
+                    &arg.identifier.identifier_token.text(),
+                    self.text,
+                    &arg.identifier.identifier_token,
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn factor(&mut self, arg: &Factor) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             if let Factor::ExpressionIdentifierFactorOpt(x) = arg {
-                // skip system function
-                if x.factor_opt.is_some() {
+                if let Some(ref y) = x.factor_opt {
+                    // skip system function
+                    if x.expression_identifier.expression_identifier_opt.is_some() {
+                        return Ok(());
+                    }
+
+                    let mut evaluator = Evaluator::new();
+                    evaluator.function_call(&x.expression_identifier, &y.function_call);
+                    if let Some(limit) = evaluator.diverged {
+                        let name = format!(
+                            "{}",
+                            SymbolPath::from(x.expression_identifier.as_ref())
+                                .as_slice()
+                                .last()
+                                .unwrap()
+                        );
+                        self.errors.push(AnalyzerError::diverged_evaluation(
+                            &name,
+                            &limit.to_string(),
+                            self.text,
+                            &x.expression_identifier.identifier.identifier_token,
+                        ));
+                    }
                     return Ok(());
                 }
 
@@ -1,10 +1,13 @@
+use crate::allow_table;
 use crate::analyzer_error::AnalyzerError;
-use crate::symbol::Direction as SymDirection;
+use crate::symbol::{Direction as SymDirection, Type as SymType, TypeKind as SymTypeKind};
 use inflector::cases::{
     camelcase::is_camel_case, pascalcase::is_pascal_case,
     screamingsnakecase::is_screaming_snake_case, snakecase::is_snake_case,
 };
-use veryl_metadata::{Case, Lint};
+use std::collections::HashSet;
+use veryl_metadata::{Build, Case, Lint, ResetType};
+use veryl_parser::resource_table::StrId;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::VerylToken;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
@@ -13,17 +16,28 @@ use veryl_parser::ParolError;
 pub struct CheckIdentifier<'a> {
     pub errors: Vec<AnalyzerError>,
     text: &'a str,
+    build_opt: &'a Build,
     lint_opt: &'a Lint,
     point: HandlerPoint,
     in_always_comb: bool,
     in_always_ff: bool,
+    in_function: bool,
+    /// Identifiers in extern module follow the naming of SystemVerilog side
+    in_extern_module: bool,
+    /// Reset signals whose polarity is already checked in the current module
+    checked_resets: HashSet<StrId>,
 }
 
 enum Kind {
+    Clock,
     Enum,
+    EnumMember,
     Function,
+    FunctionArg,
+    Genvar,
     Instance,
     Interface,
+    Localparam,
     Modport,
     Module,
     Package,
@@ -33,30 +47,59 @@ enum Kind {
     PortModport,
     PortOutput,
     Reg,
+    Reset,
+    ResetHigh,
+    ResetLow,
     Struct,
+    StructMember,
+    Union,
     Wire,
 }
 
 impl<'a> CheckIdentifier<'a> {
-    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+    pub fn new(text: &'a str, build_opt: &'a Build, lint_opt: &'a Lint) -> Self {
         Self {
             errors: Vec::new(),
             text,
+            build_opt,
             lint_opt,
             point: HandlerPoint::Before,
             in_always_comb: false,
             in_always_ff: false,
+            in_function: false,
+            in_extern_module: false,
+            checked_resets: HashSet::new(),
+        }
+    }
+
+    /// Checks clock and reset signals by the declared type
+    fn check_type(&mut self, token: &VerylToken, arg: &ArrayType) {
+        let r#type: SymType = arg.into();
+        match r#type.kind {
+            SymTypeKind::Clock => self.check(token, Kind::Clock),
+            SymTypeKind::Reset => self.check(token, Kind::Reset),
+            _ => (),
         }
     }
 
     fn check(&mut self, token: &VerylToken, kind: Kind) {
+        if allow_table::contains("invalid_identifier") || self.in_extern_module {
+            return;
+        }
         let opt = &self.lint_opt.naming;
 
+        // Rules for localparam fall back to the rules for parameter if they are not specified.
         let prefix = match kind {
+            Kind::Clock => &opt.prefix_clock,
             Kind::Enum => &opt.prefix_enum,
+            Kind::EnumMember => &opt.prefix_enum_member,
             Kind::Function => &opt.prefix_function,
+            Kind::FunctionArg => &opt.prefix_function_arg,
+            Kind::Genvar => &opt.prefix_genvar,
             Kind::Instance => &opt.prefix_instance,
             Kind::Interface => &opt.prefix_interface,
+            Kind::Localparam if opt.prefix_localparam.is_some() => &opt.prefix_localparam,
+            Kind::Localparam => &opt.prefix_parameter,
             Kind::Modport => &opt.prefix_modport,
             Kind::Module => &opt.prefix_module,
             Kind::Package => &opt.prefix_package,
@@ -66,15 +109,26 @@ impl<'a> CheckIdentifier<'a> {
             Kind::PortModport => &opt.prefix_port_modport,
             Kind::PortOutput => &opt.prefix_port_output,
             Kind::Reg => &opt.prefix_reg,
+            Kind::Reset => &opt.prefix_reset,
+            Kind::ResetHigh => &opt.prefix_reset_high,
+            Kind::ResetLow => &opt.prefix_reset_low,
             Kind::Struct => &opt.prefix_struct,
+            Kind::StructMember => &opt.prefix_struct_member,
+            Kind::Union => &opt.prefix_union,
             Kind::Wire => &opt.prefix_wire,
         };
 
         let case = match kind {
+            Kind::Clock => &opt.case_clock,
             Kind::Enum => &opt.case_enum,
+            Kind::EnumMember => &opt.case_enum_member,
             Kind::Function => &opt.case_function,
+            Kind::FunctionArg => &opt.case_function_arg,
+            Kind::Genvar => &opt.case_genvar,
             Kind::Instance => &opt.case_instance,
             Kind::Interface => &opt.case_interface,
+            Kind::Localparam if opt.case_localparam.is_some() => &opt.case_localparam,
+            Kind::Localparam => &opt.case_parameter,
             Kind::Modport => &opt.case_modport,
             Kind::Module => &opt.case_module,
             Kind::Package => &opt.case_package,
@@ -84,15 +138,26 @@ impl<'a> CheckIdentifier<'a> {
             Kind::PortModport => &opt.case_port_modport,
             Kind::PortOutput => &opt.case_port_output,
             Kind::Reg => &opt.case_reg,
+            Kind::Reset => &opt.case_reset,
+            Kind::ResetHigh => &opt.case_reset_high,
+            Kind::ResetLow => &opt.case_reset_low,
             Kind::Struct => &opt.case_struct,
+            Kind::StructMember => &opt.case_struct_member,
+            Kind::Union => &opt.case_union,
             Kind::Wire => &opt.case_wire,
         };
 
         let re_required = match kind {
+            Kind::Clock => &opt.re_required_clock,
             Kind::Enum => &opt.re_required_enum,
+            Kind::EnumMember => &opt.re_required_enum_member,
             Kind::Function => &opt.re_required_function,
+            Kind::FunctionArg => &opt.re_required_function_arg,
+            Kind::Genvar => &opt.re_required_genvar,
             Kind::Instance => &opt.re_required_instance,
             Kind::Interface => &opt.re_required_interface,
+            Kind::Localparam if opt.re_required_localparam.is_some() => &opt.re_required_localparam,
+            Kind::Localparam => &opt.re_required_parameter,
             Kind::Modport => &opt.re_required_modport,
             Kind::Module => &opt.re_required_module,
             Kind::Package => &opt.re_required_package,
@@ -102,15 +167,28 @@ impl<'a> CheckIdentifier<'a> {
             Kind::PortModport => &opt.re_required_port_modport,
             Kind::PortOutput => &opt.re_required_port_output,
             Kind::Reg => &opt.re_required_reg,
+            Kind::Reset => &opt.re_required_reset,
+            Kind::ResetHigh => &opt.re_required_reset_high,
+            Kind::ResetLow => &opt.re_required_reset_low,
             Kind::Struct => &opt.re_required_struct,
+            Kind::StructMember => &opt.re_required_struct_member,
+            Kind::Union => &opt.re_required_union,
             Kind::Wire => &opt.re_required_wire,
         };
 
         let re_forbidden = match kind {
+            Kind::Clock => &opt.re_forbidden_clock,
             Kind::Enum => &opt.re_forbidden_enum,
+            Kind::EnumMember => &opt.re_forbidden_enum_member,
             Kind::Function => &opt.re_forbidden_function,
+            Kind::FunctionArg => &opt.re_forbidden_function_arg,
+            Kind::Genvar => &opt.re_forbidden_genvar,
             Kind::Instance => &opt.re_forbidden_instance,
             Kind::Interface => &opt.re_forbidden_interface,
+            Kind::Localparam if opt.re_forbidden_localparam.is_some() => {
+                &opt.re_forbidden_localparam
+            }
+            Kind::Localparam => &opt.re_forbidden_parameter,
             Kind::Modport => &opt.re_forbidden_modport,
             Kind::Module => &opt.re_forbidden_module,
             Kind::Package => &opt.re_forbidden_package,
@@ -120,10 +198,44 @@ impl<'a> CheckIdentifier<'a> {
             Kind::PortModport => &opt.re_forbidden_port_modport,
             Kind::PortOutput => &opt.re_forbidden_port_output,
             Kind::Reg => &opt.re_forbidden_reg,
+            Kind::Reset => &opt.re_forbidden_reset,
+            Kind::ResetHigh => &opt.re_forbidden_reset_high,
+            Kind::ResetLow => &opt.re_forbidden_reset_low,
             Kind::Struct => &opt.re_forbidden_struct,
+            Kind::StructMember => &opt.re_forbidden_struct_member,
+            Kind::Union => &opt.re_forbidden_union,
             Kind::Wire => &opt.re_forbidden_wire,
         };
 
+        let suffix = match kind {
+            Kind::Clock => &opt.suffix_clock,
+            Kind::Enum => &opt.suffix_enum,
+            Kind::EnumMember => &opt.suffix_enum_member,
+            Kind::Function => &opt.suffix_function,
+            Kind::FunctionArg => &opt.suffix_function_arg,
+            Kind::Genvar => &opt.suffix_genvar,
+            Kind::Instance => &opt.suffix_instance,
+            Kind::Interface => &opt.suffix_interface,
+            Kind::Localparam if opt.suffix_localparam.is_some() => &opt.suffix_localparam,
+            Kind::Localparam => &opt.suffix_parameter,
+            Kind::Modport => &opt.suffix_modport,
+            Kind::Module => &opt.suffix_module,
+            Kind::Package => &opt.suffix_package,
+            Kind::Parameter => &opt.suffix_parameter,
+            Kind::PortInout => &opt.suffix_port_inout,
+            Kind::PortInput => &opt.suffix_port_input,
+            Kind::PortModport => &opt.suffix_port_modport,
+            Kind::PortOutput => &opt.suffix_port_output,
+            Kind::Reg => &opt.suffix_reg,
+            Kind::Reset => &opt.suffix_reset,
+            Kind::ResetHigh => &opt.suffix_reset_high,
+            Kind::ResetLow => &opt.suffix_reset_low,
+            Kind::Struct => &opt.suffix_struct,
+            Kind::StructMember => &opt.suffix_struct_member,
+            Kind::Union => &opt.suffix_union,
+            Kind::Wire => &opt.suffix_wire,
+        };
+
         let identifier = token.text();
         if let Some(prefix) = prefix {
             if !identifier.starts_with(prefix) {
@@ -135,6 +247,16 @@ impl<'a> CheckIdentifier<'a> {
                 ));
             }
         }
+        if let Some(suffix) = suffix {
+            if !identifier.ends_with(suffix) {
+                self.errors.push(AnalyzerError::invalid_identifier(
+                    &identifier,
+                    &format!("suffix: {suffix}"),
+                    self.text,
+                    token,
+                ));
+            }
+        }
         if let Some(case) = case {
             let pass = match case {
                 Case::Snake => is_snake_case(&identifier),
@@ -209,9 +331,16 @@ impl<'a> VerylGrammarTrait for CheckIdentifier<'a> {
         Ok(())
     }
 
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_type(&arg.identifier.identifier_token, &arg.array_type);
+        }
+        Ok(())
+    }
+
     fn localparam_declaration(&mut self, arg: &LocalparamDeclaration) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
-            self.check(&arg.identifier.identifier_token, Kind::Parameter);
+            self.check(&arg.identifier.identifier_token, Kind::Localparam);
         }
         Ok(())
     }
@@ -230,6 +359,13 @@ impl<'a> VerylGrammarTrait for CheckIdentifier<'a> {
         Ok(())
     }
 
+    fn enum_item(&mut self, arg: &EnumItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check(&arg.identifier.identifier_token, Kind::EnumMember);
+        }
+        Ok(())
+    }
+
     fn struct_declaration(&mut self, arg: &StructDeclaration) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             self.check(&arg.identifier.identifier_token, Kind::Struct);
@@ -237,6 +373,21 @@ impl<'a> VerylGrammarTrait for CheckIdentifier<'a> {
         Ok(())
     }
 
+    fn union_declaration(&mut self, arg: &UnionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check(&arg.identifier.identifier_token, Kind::Union);
+        }
+        Ok(())
+    }
+
+    /// Members of both struct and union
+    fn struct_item(&mut self, arg: &StructItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check(&arg.identifier.identifier_token, Kind::StructMember);
+        }
+        Ok(())
+    }
+
     fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             self.check(&arg.identifier.identifier_token, Kind::Instance);
@@ -246,19 +397,29 @@ impl<'a> VerylGrammarTrait for CheckIdentifier<'a> {
 
     fn with_parameter_item(&mut self, arg: &WithParameterItem) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
-            self.check(&arg.identifier.identifier_token, Kind::Parameter);
+            let kind = match &*arg.with_parameter_item_group {
+                WithParameterItemGroup::Parameter(_) => Kind::Parameter,
+                WithParameterItemGroup::Localparam(_) => Kind::Localparam,
+            };
+            self.check(&arg.identifier.identifier_token, kind);
         }
         Ok(())
     }
 
     fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
+            if self.in_function {
+                self.check(&arg.identifier.identifier_token, Kind::FunctionArg);
+                return Ok(());
+            }
+
             let direction = match &*arg.port_declaration_item_group {
-                PortDeclarationItemGroup::DirectionArrayType(x) => {
+                PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
+                    self.check_type(&arg.identifier.identifier_token, &x.array_type);
                     let direction: SymDirection = x.direction.as_ref().into();
                     direction
                 }
-                PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(_) => {
+                PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(_) => {
                     SymDirection::Interface
                 }
             };
@@ -285,6 +446,30 @@ impl<'a> VerylGrammarTrait for CheckIdentifier<'a> {
         Ok(())
     }
 
+    fn always_ff_reset(&mut self, arg: &AlwaysFfReset) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.hierarchical_identifier.identifier.identifier_token;
+            if !self.checked_resets.insert(token.token.text) {
+                return Ok(());
+            }
+
+            // polarity is specified by always_ff or the default reset type
+            let low = match arg.always_ff_reset_opt {
+                Some(ref x) => matches!(
+                    *x.always_ff_reset_opt_group,
+                    AlwaysFfResetOptGroup::AsyncLow(_) | AlwaysFfResetOptGroup::SyncLow(_)
+                ),
+                None => matches!(
+                    self.build_opt.reset_type,
+                    ResetType::AsyncLow | ResetType::SyncLow
+                ),
+            };
+            let kind = if low { Kind::ResetLow } else { Kind::ResetHigh };
+            self.check(token, kind);
+        }
+        Ok(())
+    }
+
     fn always_comb_declaration(&mut self, _arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => self.in_always_comb = true,
@@ -294,8 +479,12 @@ impl<'a> VerylGrammarTrait for CheckIdentifier<'a> {
     }
 
     fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<(), ParolError> {
-        if let HandlerPoint::Before = self.point {
-            self.check(&arg.identifier.identifier_token, Kind::Function);
+        match self.point {
+            HandlerPoint::Before => {
+                self.check(&arg.identifier.identifier_token, Kind::Function);
+                self.in_function = true;
+            }
+            HandlerPoint::After => self.in_function = false,
         }
         Ok(())
     }
@@ -303,6 +492,35 @@ impl<'a> VerylGrammarTrait for CheckIdentifier<'a> {
     fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             self.check(&arg.identifier.identifier_token, Kind::Module);
+            self.checked_resets.clear();
+        }
+        Ok(())
+    }
+
+    fn extern_module_declaration(
+        &mut self,
+        _arg: &ExternModuleDeclaration,
+    ) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_extern_module = true,
+            HandlerPoint::After => self.in_extern_module = false,
+        }
+        Ok(())
+    }
+
+    fn module_for_declaration(&mut self, arg: &ModuleForDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check(&arg.identifier.identifier_token, Kind::Genvar);
+        }
+        Ok(())
+    }
+
+    fn interface_for_declaration(
+        &mut self,
+        arg: &InterfaceForDeclaration,
+    ) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check(&arg.identifier.identifier_token, Kind::Genvar);
         }
         Ok(())
     }
@@ -1,12 +1,112 @@
 use crate::allow_table;
 use crate::analyzer_error::AnalyzerError;
-use crate::symbol::SymbolKind;
-use crate::symbol_table;
-use veryl_parser::resource_table;
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::handlers::check_width::CheckWidth;
+use crate::namespace::Namespace;
+use crate::namespace_table;
+use crate::symbol::{
+    Direction, Parameter, ParameterScope, Port, Symbol, SymbolKind, Type, TypeKind,
+};
+use crate::symbol_table::{self, SymbolPath};
+use std::fmt;
+use veryl_parser::resource_table::{self, StrId};
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
 use veryl_parser::{ParolError, Stringifier};
 
+/// Collects identifiers in an expression
+#[derive(Default)]
+struct Identifiers {
+    identifiers: Vec<ExpressionIdentifier>,
+}
+
+impl VerylWalker for Identifiers {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        self.identifiers.push(arg.clone());
+    }
+}
+
+/// Kind of signals which are connected to ports
+enum Connection {
+    /// Interface instance, or interface port whose interface is not specified
+    Interface(Option<Symbol>),
+    /// Modport port with the interface and the modport name
+    Modport(Symbol, StrId),
+    Value,
+}
+
+impl Connection {
+    fn modport(r#type: &Option<Type>, namespace: &Namespace) -> Option<Self> {
+        let path = match r#type.as_ref().map(|x| &x.kind) {
+            Some(TypeKind::UserDefined(x)) if x.len() >= 2 => x,
+            _ => return None,
+        };
+        let interface = symbol_table::get(&SymbolPath::new(&path[..path.len() - 1]), namespace)
+            .ok()?
+            .found?;
+        Some(Connection::Modport(interface, *path.last().unwrap()))
+    }
+
+    fn port(port: &Port) -> Option<Self> {
+        match port.property.direction {
+            Direction::Interface => Some(Connection::Interface(None)),
+            Direction::Modport => {
+                let namespace = namespace_table::get(port.property.token.id)?;
+                Self::modport(&port.property.r#type, &namespace)
+            }
+            _ => Some(Connection::Value),
+        }
+    }
+
+    fn symbol(symbol: &Symbol) -> Option<Self> {
+        match symbol.kind {
+            SymbolKind::Instance(ref x) => {
+                let path = SymbolPath::new(&x.type_name);
+                let found = symbol_table::get(&path, &symbol.namespace).ok()?.found?;
+                match found.kind {
+                    SymbolKind::Interface(_) => Some(Connection::Interface(Some(found))),
+                    _ => None,
+                }
+            }
+            SymbolKind::Port(ref x) => match x.direction {
+                Direction::Interface => Some(Connection::Interface(None)),
+                Direction::Modport => Self::modport(&x.r#type, &symbol.namespace),
+                _ => Some(Connection::Value),
+            },
+            SymbolKind::Variable(_)
+            | SymbolKind::Parameter(_)
+            | SymbolKind::EnumMember(_)
+            | SymbolKind::Genvar => Some(Connection::Value),
+            _ => None,
+        }
+    }
+
+    /// Returns whether `self` can be connected to the port of `expected`
+    fn is_compatible(&self, expected: &Connection) -> bool {
+        match (expected, self) {
+            (Connection::Value, Connection::Value) => true,
+            (Connection::Value, _) | (_, Connection::Value) => false,
+            (Connection::Modport(x, _), Connection::Interface(Some(y))) => x.token.id == y.token.id,
+            (Connection::Modport(x, m), Connection::Modport(y, n)) => {
+                x.token.id == y.token.id && m == n
+            }
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Connection::Interface(Some(x)) => format!("interface {}", x.token.text),
+            Connection::Interface(None) => "interface".to_string(),
+            Connection::Modport(x, y) => format!("modport {}::{}", x.token.text, y),
+            Connection::Value => "value".to_string(),
+        };
+        text.fmt(f)
+    }
+}
+
 pub struct CheckInstance<'a> {
     pub errors: Vec<AnalyzerError>,
     text: &'a str,
@@ -21,6 +121,242 @@ impl<'a> CheckInstance<'a> {
             point: HandlerPoint::Before,
         }
     }
+
+    /// Errors suppressed by `#[allow]` attribute are dropped
+    fn push(&mut self, error: AnalyzerError) {
+        if !allow_table::allows(&error) {
+            self.errors.push(error);
+        }
+    }
+
+    /// Returns the kind name if the expression is not a type but a value, and vice versa
+    fn mismatch_kind(arg: &Expression, is_type: bool) -> Option<String> {
+        let mut identifiers = Identifiers::default();
+        identifiers.expression(arg);
+        let symbol = match identifiers.identifiers.first() {
+            Some(x) => symbol_table::resolve(x).ok().and_then(|x| x.found),
+            None => None,
+        };
+
+        let kind = match symbol {
+            Some(x) => match x.kind {
+                SymbolKind::Struct | SymbolKind::Union | SymbolKind::Enum(_) => Some(true),
+                SymbolKind::Parameter(ref y) => Some(matches!(y.r#type.kind, TypeKind::Type)),
+                SymbolKind::GenericParameter(_) => None,
+                _ => Some(false),
+            }
+            .map(|y| (y, x.kind.to_kind_name())),
+            None => match Evaluator::new().expression(arg) {
+                Evaluated::Fixed(_) => Some((false, "value".to_string())),
+                _ => None,
+            },
+        };
+
+        match kind {
+            Some((x, name)) if x != is_type => Some(name),
+            _ => None,
+        }
+    }
+
+    fn check_parameters(
+        &mut self,
+        kind: &str,
+        name: &str,
+        parameters: &[Parameter],
+        arg: &InstParameter,
+    ) {
+        let items: Vec<InstParameterItem> = match arg.inst_parameter_opt {
+            Some(ref x) => x.inst_parameter_list.as_ref().into(),
+            None => return,
+        };
+
+        for item in items {
+            let token = &item.identifier.identifier_token;
+            let text = token.text();
+            let parameter = match parameters.iter().find(|x| x.name == token.token.text) {
+                Some(x) => x,
+                None => {
+                    self.push(AnalyzerError::unknown_parameter(
+                        kind, name, &text, self.text, token,
+                    ));
+                    continue;
+                }
+            };
+
+            if let ParameterScope::Local = parameter.property.scope {
+                self.push(AnalyzerError::override_localparam(
+                    kind, name, &text, self.text, token,
+                ));
+                continue;
+            }
+
+            if let Some(ref x) = item.inst_parameter_item_opt {
+                let is_type = matches!(parameter.property.r#type.kind, TypeKind::Type);
+                if let Some(actual) = Self::mismatch_kind(&x.expression, is_type) {
+                    let expected = if is_type { "type" } else { "value" };
+                    self.push(AnalyzerError::mismatch_type(
+                        &text, expected, &actual, self.text, token,
+                    ));
+                }
+            }
+        }
+    }
+
+    fn check_port_type(&mut self, name: &str, port: &Port, item: &InstPortItem) {
+        let expected = match Connection::port(port) {
+            Some(x) => x,
+            None => return,
+        };
+        let actual = match item.inst_port_item_opt {
+            Some(ref x) => {
+                let mut identifiers = Identifiers::default();
+                identifiers.expression(&x.expression);
+                match identifiers.identifiers.first() {
+                    Some(x) => symbol_table::resolve(x)
+                        .ok()
+                        .and_then(|x| x.found)
+                        .and_then(|x| Connection::symbol(&x)),
+                    // constant value
+                    None => Some(Connection::Value),
+                }
+            }
+            None => symbol_table::resolve(item.identifier.as_ref())
+                .ok()
+                .and_then(|x| x.found)
+                .and_then(|x| Connection::symbol(&x)),
+        };
+        let actual = match actual {
+            Some(x) => x,
+            None => return,
+        };
+
+        if !actual.is_compatible(&expected) {
+            let token = &item.identifier.identifier_token;
+            self.push(AnalyzerError::mismatch_port_type(
+                name,
+                &token.text(),
+                &expected.to_string(),
+                &actual.to_string(),
+                self.text,
+                token,
+            ));
+        }
+    }
+
+    /// Checks whether the connection loses bits of the port or the connected signal.
+    /// Extension of input and output is not reported.
+    fn check_port_width(
+        &mut self,
+        name: &str,
+        port: &Port,
+        parameters: &[Parameter],
+        overrides: &[InstParameterItem],
+        item: &InstPortItem,
+    ) {
+        // port width is evaluated with parameter overrides of the instance
+        let port_width = match port.property.r#type {
+            Some(ref x) if x.array.is_empty() => {
+                Evaluator::new().overridden_type_width(x.clone(), parameters, overrides)
+            }
+            _ => None,
+        };
+        let port_width = match port_width {
+            Some(x) => x,
+            None => return,
+        };
+
+        let connected_width = || {
+            if let Some(ref x) = item.inst_port_item_opt {
+                Evaluator::new().expression(&x.expression).width()
+            } else if let Ok(symbol) = symbol_table::resolve(item.identifier.as_ref()) {
+                symbol.found.and_then(|x| x.evaluate().width())
+            } else {
+                None
+            }
+        };
+
+        let width = match port.property.direction {
+            Direction::Input | Direction::Inout | Direction::Ref => {
+                let width = match item.inst_port_item_opt {
+                    Some(ref x) => CheckWidth::unfit_width(port_width, &x.expression),
+                    None => connected_width(),
+                };
+                width.filter(|x| *x > port_width)
+            }
+            // output port is assigned to the connected variable
+            Direction::Output => connected_width().filter(|x| *x < port_width),
+            _ => None,
+        };
+
+        if let Some(width) = width {
+            self.push(AnalyzerError::mismatch_port_width(
+                name,
+                &item.identifier.identifier_token.text(),
+                port_width,
+                width,
+                self.text,
+                &item.identifier.identifier_token,
+            ));
+        }
+    }
+
+    fn check_ports(
+        &mut self,
+        kind: &str,
+        name: &str,
+        ports: &[Port],
+        parameters: &[Parameter],
+        arg: &InstDeclaration,
+    ) {
+        let overrides: Vec<InstParameterItem> = match arg.inst_declaration_opt0 {
+            Some(ref x) => match x.inst_parameter.inst_parameter_opt {
+                Some(ref x) => x.inst_parameter_list.as_ref().into(),
+                None => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+
+        let mut connected_ports = Vec::new();
+        if let Some(ref x) = arg.inst_declaration_opt1 {
+            if let Some(ref x) = x.inst_declaration_opt2 {
+                let items: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
+                for item in items {
+                    connected_ports.push(item.identifier.identifier_token.token.text);
+                    if let Some(port) = ports
+                        .iter()
+                        .find(|x| x.name == item.identifier.identifier_token.token.text)
+                    {
+                        self.check_port_type(name, port, &item);
+                        self.check_port_width(name, port, parameters, &overrides, &item);
+                    }
+                }
+            }
+        }
+
+        for port in ports {
+            if !connected_ports.contains(&port.name) {
+                let port = resource_table::get_str_value(port.name).unwrap();
+                self.push(AnalyzerError::missing_port(
+                    name,
+                    &port,
+                    self.text,
+                    &arg.identifier.identifier_token,
+                ));
+            }
+        }
+        for port in &connected_ports {
+            if !ports.iter().any(|x| &x.name == port) {
+                let port = resource_table::get_str_value(*port).unwrap();
+                self.push(AnalyzerError::unknown_port(
+                    kind,
+                    name,
+                    &port,
+                    self.text,
+                    &arg.identifier.identifier_token,
+                ));
+            }
+        }
+    }
 }
 
 impl<'a> Handler for CheckInstance<'a> {
@@ -32,59 +368,32 @@ impl<'a> Handler for CheckInstance<'a> {
 impl<'a> VerylGrammarTrait for CheckInstance<'a> {
     fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
-            let mut connected_ports = Vec::new();
-            if let Some(ref x) = arg.inst_declaration_opt1 {
-                if let Some(ref x) = x.inst_declaration_opt2 {
-                    let items: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
-                    for item in items {
-                        connected_ports.push(item.identifier.identifier_token.token.text);
-                    }
-                }
-            }
-
             if let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) {
                 let mut stringifier = Stringifier::new();
                 stringifier.scoped_identifier(&arg.scoped_identifier);
                 let name = stringifier.as_str();
                 if let Some(symbol) = symbol.found {
-                    match symbol.kind {
-                        SymbolKind::Module(ref x) => {
-                            for port in &x.ports {
-                                if !connected_ports.contains(&port.name)
-                                    && !allow_table::contains("missing_port")
-                                {
-                                    let port = resource_table::get_str_value(port.name).unwrap();
-                                    self.errors.push(AnalyzerError::missing_port(
-                                        name,
-                                        &port,
-                                        self.text,
-                                        &arg.identifier.identifier_token,
-                                    ));
-                                }
-                            }
-                            for port in &connected_ports {
-                                if !x.ports.iter().any(|x| &x.name == port) {
-                                    let port = resource_table::get_str_value(*port).unwrap();
-                                    self.errors.push(AnalyzerError::unknown_port(
-                                        name,
-                                        &port,
-                                        self.text,
-                                        &arg.identifier.identifier_token,
-                                    ));
-                                }
-                            }
-                        }
-                        SymbolKind::Interface(_) => (),
+                    let kind = symbol.kind.to_kind_name();
+                    let (parameters, ports) = match symbol.kind {
+                        SymbolKind::Module(x) => (x.parameters, x.ports),
+                        // interface doesn't have ports
+                        SymbolKind::Interface(x) => (x.parameters, Vec::new()),
                         _ => {
-                            self.errors.push(AnalyzerError::mismatch_type(
+                            self.push(AnalyzerError::mismatch_type(
                                 name,
                                 "module or interface",
-                                &symbol.kind.to_kind_name(),
+                                &kind,
                                 self.text,
                                 &arg.identifier.identifier_token,
                             ));
+                            return Ok(());
                         }
+                    };
+
+                    if let Some(ref x) = arg.inst_declaration_opt0 {
+                        self.check_parameters(&kind, name, &parameters, &x.inst_parameter);
                     }
+                    self.check_ports(&kind, name, &ports, &parameters, arg);
                 }
             }
         }
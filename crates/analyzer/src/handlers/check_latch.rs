@@ -0,0 +1,397 @@
+use crate::allow_table;
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::Evaluator;
+use crate::handlers::check_case::CheckCase;
+use crate::symbol::{Symbol, SymbolKind};
+use crate::symbol_table::{self, SymbolPathNamespace};
+use std::collections::{BTreeSet, HashMap};
+use veryl_parser::resource_table::TokenId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+/// Bits of a variable assigned in a path
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Coverage {
+    Whole,
+    Bits(BTreeSet<usize>),
+}
+
+impl Coverage {
+    fn union(&self, other: &Coverage, width: Option<usize>) -> Coverage {
+        match (self, other) {
+            (Coverage::Whole, _) | (_, Coverage::Whole) => Coverage::Whole,
+            (Coverage::Bits(x), Coverage::Bits(y)) => {
+                let bits: BTreeSet<usize> = x.union(y).cloned().collect();
+                if Some(bits.len()) == width {
+                    Coverage::Whole
+                } else {
+                    Coverage::Bits(bits)
+                }
+            }
+        }
+    }
+
+    fn intersection(&self, other: &Coverage) -> Coverage {
+        match (self, other) {
+            (Coverage::Whole, x) | (x, Coverage::Whole) => x.clone(),
+            (Coverage::Bits(x), Coverage::Bits(y)) => {
+                Coverage::Bits(x.intersection(y).cloned().collect())
+            }
+        }
+    }
+
+    fn contains(&self, other: &Coverage) -> bool {
+        match (self, other) {
+            (Coverage::Whole, _) => true,
+            (Coverage::Bits(_), Coverage::Whole) => false,
+            (Coverage::Bits(x), Coverage::Bits(y)) => x.is_superset(y),
+        }
+    }
+}
+
+/// Variables assigned on every path
+type Assigned = HashMap<TokenId, Coverage>;
+
+/// Variable assigned in any path of the current block
+struct Variable {
+    id: TokenId,
+    token: VerylToken,
+    width: Option<usize>,
+    coverage: Coverage,
+}
+
+/// Branch of if or case statement
+struct Branch {
+    assigned: Assigned,
+    token: VerylToken,
+    kind: &'static str,
+}
+
+pub struct CheckLatch<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    variables: Vec<Variable>,
+    /// The first branch which leaves the variable unassigned
+    missing: HashMap<TokenId, (VerylToken, &'static str)>,
+}
+
+impl<'a> CheckLatch<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+            variables: Vec::new(),
+            missing: HashMap::new(),
+        }
+    }
+
+    fn resolve<T: Into<SymbolPathNamespace>>(path: T) -> Option<Symbol> {
+        if let Ok(x) = symbol_table::resolve(path) {
+            x.full_path.first().cloned()
+        } else {
+            None
+        }
+    }
+
+    fn variable_width(&self, id: TokenId) -> Option<usize> {
+        self.variables
+            .iter()
+            .find(|x| x.id == id)
+            .and_then(|x| x.width)
+    }
+
+    fn statements<'b, T: Iterator<Item = &'b Statement>>(
+        &mut self,
+        statements: T,
+        mut assigned: Assigned,
+    ) -> Assigned {
+        for x in statements {
+            assigned = self.statement(x, assigned);
+        }
+        assigned
+    }
+
+    fn statement(&mut self, arg: &Statement, assigned: Assigned) -> Assigned {
+        match arg {
+            Statement::IdentifierStatement(x) => {
+                let x = &x.identifier_statement;
+                match &*x.identifier_statement_group {
+                    IdentifierStatementGroup::Assignment(_) => {
+                        self.assignment(&x.expression_identifier, assigned)
+                    }
+                    IdentifierStatementGroup::FunctionCall(_) => assigned,
+                }
+            }
+            Statement::IfStatement(x) => self.if_statement(&x.if_statement, assigned),
+            Statement::IfResetStatement(x) => {
+                self.if_reset_statement(&x.if_reset_statement, assigned)
+            }
+            Statement::CaseStatement(x) => self.case_statement(&x.case_statement, assigned),
+            // The loop body is assumed to be executed at least once
+            Statement::ForStatement(x) => self.statements(
+                x.for_statement
+                    .for_statement_list
+                    .iter()
+                    .map(|x| x.statement.as_ref()),
+                assigned,
+            ),
+            Statement::ReturnStatement(_) => assigned,
+        }
+    }
+
+    fn assignment(&mut self, arg: &ExpressionIdentifier, mut assigned: Assigned) -> Assigned {
+        let symbol = match Self::resolve(arg) {
+            Some(x) if matches!(x.kind, SymbolKind::Variable(_) | SymbolKind::Port(_)) => x,
+            _ => return assigned,
+        };
+
+        let mut evaluator = Evaluator::new();
+        let width = evaluator.selected_bits(&symbol, &[]).map(|(_, x)| x);
+        let coverage = match &*arg.expression_identifier_group {
+            ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x)
+                if x.expression_identifier_group_list2.is_empty() =>
+            {
+                let selects: Vec<&Select> = x
+                    .expression_identifier_group_list1
+                    .iter()
+                    .map(|x| x.select.as_ref())
+                    .collect();
+                // Non-constant selects are assumed to assign all bits (e.g. in for loop)
+                match evaluator.selected_bits(&symbol, &selects) {
+                    Some((lsb, w)) if Some(w) != width => Coverage::Bits((lsb..lsb + w).collect()),
+                    _ => Coverage::Whole,
+                }
+            }
+            _ => Coverage::Whole,
+        };
+
+        let id = symbol.token.id;
+        if let Some(x) = self.variables.iter_mut().find(|x| x.id == id) {
+            x.coverage = x.coverage.union(&coverage, width);
+        } else {
+            self.variables.push(Variable {
+                id,
+                token: arg.identifier.identifier_token.clone(),
+                width,
+                coverage: coverage.clone(),
+            });
+        }
+
+        let coverage = match assigned.get(&id) {
+            Some(x) => x.union(&coverage, width),
+            None => coverage,
+        };
+        assigned.insert(id, coverage);
+        assigned
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement, assigned: Assigned) -> Assigned {
+        let mut branches = Vec::new();
+        branches.push(Branch {
+            assigned: self.statements(
+                arg.if_statement_list.iter().map(|x| x.statement.as_ref()),
+                assigned.clone(),
+            ),
+            token: arg.r#if.if_token.clone(),
+            kind: "`if` branch",
+        });
+        for x in &arg.if_statement_list0 {
+            branches.push(Branch {
+                assigned: self.statements(
+                    x.if_statement_list0_list
+                        .iter()
+                        .map(|x| x.statement.as_ref()),
+                    assigned.clone(),
+                ),
+                token: x.r#if.if_token.clone(),
+                kind: "`else if` branch",
+            });
+        }
+        if let Some(ref x) = arg.if_statement_opt {
+            branches.push(Branch {
+                assigned: self.statements(
+                    x.if_statement_opt_list.iter().map(|x| x.statement.as_ref()),
+                    assigned,
+                ),
+                token: x.r#else.else_token.clone(),
+                kind: "`else` branch",
+            });
+        } else {
+            branches.push(Branch {
+                assigned,
+                token: arg.r#if.if_token.clone(),
+                kind: "implicit `else` branch",
+            });
+        }
+        self.merge(branches)
+    }
+
+    fn if_reset_statement(&mut self, arg: &IfResetStatement, assigned: Assigned) -> Assigned {
+        let mut branches = Vec::new();
+        branches.push(Branch {
+            assigned: self.statements(
+                arg.if_reset_statement_list
+                    .iter()
+                    .map(|x| x.statement.as_ref()),
+                assigned.clone(),
+            ),
+            token: arg.if_reset.if_reset_token.clone(),
+            kind: "`if_reset` branch",
+        });
+        for x in &arg.if_reset_statement_list0 {
+            branches.push(Branch {
+                assigned: self.statements(
+                    x.if_reset_statement_list0_list
+                        .iter()
+                        .map(|x| x.statement.as_ref()),
+                    assigned.clone(),
+                ),
+                token: x.r#if.if_token.clone(),
+                kind: "`else if` branch",
+            });
+        }
+        if let Some(ref x) = arg.if_reset_statement_opt {
+            branches.push(Branch {
+                assigned: self.statements(
+                    x.if_reset_statement_opt_list
+                        .iter()
+                        .map(|x| x.statement.as_ref()),
+                    assigned,
+                ),
+                token: x.r#else.else_token.clone(),
+                kind: "`else` branch",
+            });
+        } else {
+            branches.push(Branch {
+                assigned,
+                token: arg.if_reset.if_reset_token.clone(),
+                kind: "implicit `else` branch",
+            });
+        }
+        self.merge(branches)
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement, assigned: Assigned) -> Assigned {
+        let mut branches = Vec::new();
+        for x in &arg.case_statement_list {
+            let item = &x.case_item;
+            let (token, kind) = match &*item.case_item_group {
+                CaseItemGroup::Expression(_) => (item.colon.colon_token.clone(), "case item"),
+                CaseItemGroup::Defaul(x) => (x.defaul.default_token.clone(), "`default` item"),
+            };
+            let assigned = match &*item.case_item_group0 {
+                CaseItemGroup0::Statement(x) => self.statement(&x.statement, assigned.clone()),
+                CaseItemGroup0::LBraceCaseItemGroup0ListRBrace(x) => self.statements(
+                    x.case_item_group0_list.iter().map(|x| x.statement.as_ref()),
+                    assigned.clone(),
+                ),
+            };
+            branches.push(Branch {
+                assigned,
+                token,
+                kind,
+            });
+        }
+
+        if !CheckCase::is_full(arg) {
+            branches.push(Branch {
+                assigned,
+                token: arg.case.case_token.clone(),
+                kind: "implicit `default` item",
+            });
+        }
+        self.merge(branches)
+    }
+
+    /// Merges exclusive branches into the variables assigned on every branch
+    fn merge(&mut self, branches: Vec<Branch>) -> Assigned {
+        let mut ids: Vec<TokenId> = branches
+            .iter()
+            .flat_map(|x| x.assigned.keys().cloned())
+            .collect();
+        ids.sort();
+        ids.dedup();
+
+        let mut ret = Assigned::new();
+        for id in ids {
+            let width = self.variable_width(id);
+            let mut all: Option<Coverage> = None;
+            let mut any: Option<Coverage> = None;
+            for x in &branches {
+                let coverage = x
+                    .assigned
+                    .get(&id)
+                    .cloned()
+                    .unwrap_or(Coverage::Bits(BTreeSet::new()));
+                all = Some(match all {
+                    Some(x) => x.intersection(&coverage),
+                    None => coverage.clone(),
+                });
+                any = Some(match any {
+                    Some(x) => x.union(&coverage, width),
+                    None => coverage,
+                });
+            }
+            let (all, any) = (all.unwrap(), any.unwrap());
+
+            if !all.contains(&any) && !self.missing.contains_key(&id) {
+                let branch = branches.iter().find(|x| match x.assigned.get(&id) {
+                    Some(x) => !x.contains(&any),
+                    None => true,
+                });
+                if let Some(x) = branch {
+                    self.missing.insert(id, (x.token.clone(), x.kind));
+                }
+            }
+            if all != Coverage::Bits(BTreeSet::new()) {
+                ret.insert(id, all);
+            }
+        }
+        ret
+    }
+}
+
+impl<'a> Handler for CheckLatch<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckLatch<'a> {
+    fn always_comb_declaration(&mut self, arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if allow_table::contains("inferred_latch") {
+                return Ok(());
+            }
+
+            self.variables.clear();
+            self.missing.clear();
+            let assigned = self.statements(
+                arg.always_comb_declaration_list
+                    .iter()
+                    .map(|x| x.statement.as_ref()),
+                Assigned::new(),
+            );
+
+            for x in &self.variables {
+                let latched = match assigned.get(&x.id) {
+                    Some(y) => !y.contains(&x.coverage),
+                    None => true,
+                };
+                if let (true, Some((token, kind))) = (latched, self.missing.get(&x.id)) {
+                    self.errors.push(AnalyzerError::inferred_latch(
+                        &x.token.text(),
+                        kind,
+                        self.text,
+                        token,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::analyzer_error::AnalyzerError;
+use crate::value;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 use veryl_parser::ParolError;
@@ -75,17 +76,13 @@ impl<'a> VerylGrammarTrait for CheckNumber<'a> {
                 _ => unreachable!(),
             };
 
-            if let Some(actual_width) = strnum_bitwidth::bitwidth(number, base) {
+            if let Some(actual_width) = value::bitwidth(number, base) {
                 if let Some(width) = width {
                     if actual_width > width {
                         self.errors
                             .push(AnalyzerError::too_large_number(width, self.text, token));
                     }
                 }
-            } else if width.is_none() {
-                // bitwidth calculation may be failed over 128bit.
-                self.errors
-                    .push(AnalyzerError::too_large_number(128, self.text, token));
             }
         }
 
@@ -29,7 +29,7 @@ impl<'a> CheckReset<'a> {
         let mut ret = Vec::new();
         ret.push(x.identifier.identifier_token.text());
         match &*x.expression_identifier_group {
-            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
                 ret.push(x.identifier.identifier_token.text());
                 for x in &x.expression_identifier_group_list {
                     ret.push(x.identifier.identifier_token.text());
@@ -0,0 +1,399 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::symbol::{Symbol, SymbolKind, Type, TypeKind};
+use crate::symbol_table::{self, SymbolPathNamespace};
+use veryl_metadata::{Build, TargetLanguage};
+use veryl_parser::resource_table::TokenId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+/// Checks constructs which can't be emitted in the target language
+pub struct CheckTarget<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    build_opt: &'a Build,
+    point: HandlerPoint,
+    header_localparams: Vec<TokenId>,
+    in_port_declaration: bool,
+    in_function: bool,
+    in_package: bool,
+    in_dropped_unit: usize,
+}
+
+impl<'a> CheckTarget<'a> {
+    pub fn new(text: &'a str, build_opt: &'a Build) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            build_opt,
+            point: HandlerPoint::Before,
+            header_localparams: Vec::new(),
+            in_port_declaration: false,
+            in_function: false,
+            in_package: false,
+            in_dropped_unit: 0,
+        }
+    }
+
+    fn is_verilog(&self) -> bool {
+        self.build_opt.target_language == TargetLanguage::Verilog
+    }
+
+    fn is_vhdl(&self) -> bool {
+        self.build_opt.target_language == TargetLanguage::Vhdl
+    }
+
+    fn unsupported(&mut self, name: &str, token: &VerylToken) {
+        let target = match self.build_opt.target_language {
+            TargetLanguage::SystemVerilog => return,
+            TargetLanguage::Verilog => "Verilog-2005",
+            // constructs in the dropped unit are reported by the unit itself
+            TargetLanguage::Vhdl if self.in_dropped_unit > 0 => return,
+            TargetLanguage::Vhdl => "VHDL-2008",
+        };
+        self.errors.push(AnalyzerError::unsupported_by_target(
+            name, target, self.text, token,
+        ));
+    }
+
+    fn resolve<T: Into<SymbolPathNamespace>>(path: T) -> Vec<Symbol> {
+        match symbol_table::resolve(path) {
+            Ok(x) => x.full_path,
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// localparams in the module header are moved to the module body at Verilog,
+    /// so ports can't refer them
+    fn check_header_localparam(&mut self, path: &[Symbol], token: &VerylToken) {
+        if self.in_port_declaration {
+            if let Some(x) = path.last() {
+                if self.header_localparams.contains(&x.token.id) {
+                    self.unsupported("header localparam in port declaration", token);
+                }
+            }
+        }
+    }
+
+    /// The whole design unit is dropped at VHDL
+    fn dropped_unit(&mut self, name: &str, token: &VerylToken) {
+        match self.point {
+            HandlerPoint::Before => {
+                self.unsupported(name, token);
+                self.in_dropped_unit += 1;
+            }
+            HandlerPoint::After => self.in_dropped_unit -= 1,
+        }
+    }
+
+    /// Only the step by `+=` can be translated to VHDL
+    fn check_step(&mut self, arg: &AssignmentOperator) {
+        let token = &arg.assignment_operator_token;
+        if self.is_vhdl() && token.text() != "+=" {
+            self.unsupported(&format!("step by `{}`", token.text()), token);
+        }
+    }
+
+    fn is_generic_package(symbol: &Symbol) -> bool {
+        matches!(symbol.kind, SymbolKind::Package(ref x) if !x.generic_parameters.is_empty())
+    }
+
+    fn is_parameterized_interface(symbol: &Symbol) -> bool {
+        matches!(symbol.kind, SymbolKind::Interface(ref x) if !x.parameters.is_empty())
+    }
+}
+
+impl<'a> Handler for CheckTarget<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckTarget<'a> {
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.is_verilog() {
+                let path = Self::resolve(arg);
+                let token = &arg.identifier.identifier_token;
+                // members of generic packages can't be inlined into modules
+                if path.iter().any(Self::is_generic_package) {
+                    self.unsupported("generic package", token);
+                }
+                self.check_header_localparam(&path, token);
+            }
+        }
+        Ok(())
+    }
+
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.is_verilog() {
+                let path = Self::resolve(arg);
+                let token = &arg.identifier.identifier_token;
+                if path.iter().any(Self::is_generic_package) {
+                    self.unsupported("generic package", token);
+                }
+                self.check_header_localparam(&path, token);
+            }
+        }
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let r#type: Type = arg.array_type.as_ref().into();
+            if self.is_verilog() && matches!(r#type.kind, TypeKind::String) {
+                self.unsupported("string variable", &arg.identifier.identifier_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn localparam_declaration(&mut self, arg: &LocalparamDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let LocalparamDeclarationGroup::TypeEquTypeExpression(_) =
+                &*arg.localparam_declaration_group
+            {
+                self.unsupported("type parameter", &arg.identifier.identifier_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn with_parameter_item(&mut self, arg: &WithParameterItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let WithParameterItemGroup::Localparam(_) = &*arg.with_parameter_item_group {
+                self.header_localparams
+                    .push(arg.identifier.identifier_token.token.id);
+            }
+            if let WithParameterItemGroup0::TypeEquTypeExpression(_) =
+                &*arg.with_parameter_item_group0
+            {
+                self.unsupported("type parameter", &arg.identifier.identifier_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn port_declaration(&mut self, _arg: &PortDeclaration) -> Result<(), ParolError> {
+        self.in_port_declaration = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
+    fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.identifier.identifier_token;
+            match &*arg.port_declaration_item_group {
+                PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
+                    if self.is_vhdl() {
+                        match *x.direction {
+                            Direction::Modport(_) => self.unsupported("modport port", token),
+                            Direction::Input(_) => (),
+                            _ if self.in_function => self.unsupported("output argument", token),
+                            _ => (),
+                        }
+                        if x.array_type.array_type_opt.is_some() {
+                            self.unsupported("array port", token);
+                        }
+                    }
+                    if let Direction::Modport(_) = *x.direction {
+                        if let ScalarTypeGroup::VariableType(ref x) =
+                            *x.array_type.scalar_type.scalar_type_group
+                        {
+                            if let VariableTypeGroup::ScopedIdentifier(ref x) =
+                                *x.variable_type.variable_type_group
+                            {
+                                let path = Self::resolve(x.scoped_identifier.as_ref());
+                                if self.is_verilog()
+                                    && path.iter().any(Self::is_parameterized_interface)
+                                {
+                                    self.unsupported("parameterized interface", token);
+                                }
+                            }
+                        }
+                    }
+                }
+                PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(_) => {
+                    // signals of interface can't be expanded without the interface type
+                    if self.is_verilog() {
+                        self.unsupported("generic interface port", token);
+                    }
+                    if self.is_vhdl() {
+                        self.unsupported("interface port", token);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn direction(&mut self, arg: &Direction) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Direction::Ref(x) = arg {
+                if self.is_verilog() {
+                    self.unsupported("`ref` direction", &x.r#ref.ref_token);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn final_declaration(&mut self, arg: &FinalDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.is_verilog() || self.is_vhdl() {
+                self.unsupported("final declaration", &arg.r#final.final_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn initial_declaration(&mut self, arg: &InitialDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.is_vhdl() && self.in_package {
+                self.unsupported("initial declaration in package", &arg.initial.initial_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn union_declaration(&mut self, arg: &UnionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.is_vhdl() {
+                self.unsupported("union", &arg.identifier.identifier_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn attribute(&mut self, arg: &Attribute) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.identifier.identifier_token;
+            let name = token.text();
+            // VHDL has no preprocessor to select the items
+            if self.is_vhdl() && matches!(name.as_str(), "ifdef" | "ifndef") {
+                self.unsupported(&format!("`{name}` attribute"), token);
+            }
+        }
+        Ok(())
+    }
+
+    fn embed_declaration(&mut self, arg: &EmbedDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.is_vhdl() {
+                self.unsupported("embed declaration", &arg.embed.embed_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn include_declaration(&mut self, arg: &IncludeDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.is_vhdl() {
+                self.unsupported("include declaration", &arg.include.include_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::FunctionCall(_) = &*arg.identifier_statement_group {
+                let x = &arg.expression_identifier;
+                let name = x.identifier.identifier_token.text();
+                // system tasks which can be translated to VHDL
+                let supported = [
+                    "display", "info", "write", "warning", "error", "fatal", "finish", "stop",
+                ];
+                if self.is_vhdl()
+                    && x.expression_identifier_opt.is_some()
+                    && !supported.contains(&name.as_str())
+                {
+                    self.unsupported(&format!("${name}"), &x.identifier.identifier_token);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(ref x) = arg.for_statement_opt {
+                self.check_step(&x.assignment_operator);
+            }
+        }
+        Ok(())
+    }
+
+    fn module_for_declaration(&mut self, arg: &ModuleForDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(ref x) = arg.module_for_declaration_opt {
+                self.check_step(&x.assignment_operator);
+            }
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let path = Self::resolve(arg.scoped_identifier.as_ref());
+            let token = &arg.identifier.identifier_token;
+            match path.last().map(|x| &x.kind) {
+                // interface instances are expanded to individual signals
+                Some(SymbolKind::Interface(_)) => {
+                    if self.is_verilog() {
+                        if arg.inst_declaration_opt.is_some() {
+                            self.unsupported("interface instance array", token);
+                        } else if path.iter().any(Self::is_parameterized_interface) {
+                            self.unsupported("parameterized interface", token);
+                        }
+                    }
+                    if self.is_vhdl() {
+                        self.unsupported("interface instance", token);
+                    }
+                }
+                Some(SymbolKind::Module(x)) if self.is_vhdl() => {
+                    if !x.generic_parameters.is_empty() {
+                        self.unsupported("generic instance", token);
+                    } else if arg.inst_declaration_opt.is_some() {
+                        self.unsupported("instance array", token);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        if self.is_vhdl()
+            && (arg.function_declaration_opt.is_some() || arg.function_declaration_opt0.is_some())
+        {
+            self.dropped_unit("generic function", &arg.identifier.identifier_token);
+        }
+        self.in_function = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        if self.is_vhdl() && arg.module_declaration_opt.is_some() {
+            self.dropped_unit("generic module", &arg.identifier.identifier_token);
+        }
+        Ok(())
+    }
+
+    fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<(), ParolError> {
+        if self.is_vhdl() {
+            self.dropped_unit("interface", &arg.identifier.identifier_token);
+        }
+        Ok(())
+    }
+
+    fn package_declaration(&mut self, arg: &PackageDeclaration) -> Result<(), ParolError> {
+        if self.is_vhdl() && arg.package_declaration_opt.is_some() {
+            self.dropped_unit("generic package", &arg.identifier.identifier_token);
+        }
+        self.in_package = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::Evaluator;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+pub struct CheckUnion<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    in_union: bool,
+    union_member_widths: Vec<(Option<usize>, VerylToken)>,
+}
+
+impl<'a> CheckUnion<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+            in_union: false,
+            union_member_widths: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Handler for CheckUnion<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckUnion<'a> {
+    fn union_declaration(&mut self, arg: &UnionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.in_union = true;
+                self.union_member_widths.clear();
+            }
+            HandlerPoint::After => {
+                self.in_union = false;
+
+                // members whose width can't be evaluated (e.g. user-defined types) are skipped
+                let mut members = self
+                    .union_member_widths
+                    .iter()
+                    .filter_map(|(width, token)| width.map(|x| (x, token)));
+                if let Some((expected, _)) = members.next() {
+                    let name = arg.identifier.identifier_token.text();
+                    for (width, token) in members {
+                        if width != expected {
+                            self.errors.push(AnalyzerError::mismatch_union_width(
+                                &token.text(),
+                                &name,
+                                width,
+                                expected,
+                                self.text,
+                                token,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn struct_item(&mut self, arg: &StructItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.in_union {
+                let mut evaluator = Evaluator::new();
+                let r#type: crate::symbol::Type = arg.scalar_type.as_ref().into();
+                let width = evaluator.type_width(r#type);
+                let token = arg.identifier.identifier_token.clone();
+                self.union_member_widths.push((width, token));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+use crate::allow_table;
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::symbol::{SymbolKind, Type};
+use crate::symbol_table;
+use veryl_parser::resource_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::ParolError;
+
+pub struct CheckWidth<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+}
+
+impl<'a> CheckWidth<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+        }
+    }
+
+    /// Returns the width of the expression.
+    /// Constants are treated as the width of their values.
+    fn expression_width(arg: &Expression) -> Option<usize> {
+        let mut evaluator = Evaluator::new();
+        evaluator.minimize_constant = true;
+        evaluator.expression(arg).width()
+    }
+
+    /// Returns the width of `value` if it can't be kept in `width`
+    pub fn unfit_width(width: usize, value: &Expression) -> Option<usize> {
+        let mut evaluator = Evaluator::new();
+        match evaluator.expression(value) {
+            Evaluated::Fixed(x) => {
+                // Constant is checked whether its value is kept
+                let unsigned = x.cast(width, false).cast(x.width, false) == x.cast(x.width, false);
+                let signed = x.cast(width, true).cast(x.width, true) == x.cast(x.width, true);
+                if !unsigned && !signed {
+                    Self::expression_width(value)
+                } else {
+                    None
+                }
+            }
+            Evaluated::Variable { .. } => Self::expression_width(value),
+            Evaluated::Unknown => None,
+        }
+    }
+
+    /// Checks assignment of `value` to `target` which has `width`
+    fn check_expression(
+        &mut self,
+        target: &str,
+        width: Option<usize>,
+        value: &Expression,
+        token: &VerylToken,
+    ) {
+        let width = match width {
+            Some(x) => x,
+            None => return,
+        };
+
+        if let Some(actual) = Self::unfit_width(width, value) {
+            // carry and shifted-out bits are intentionally kept by extension
+            if actual < width && has_widening_operator(value) {
+                return;
+            }
+            self.check(target, width, actual, token);
+        }
+    }
+
+    fn check_parameter_override(&mut self, inst: &InstDeclaration, arg: &InstParameter) {
+        let parameters = match symbol_table::resolve(inst.scoped_identifier.as_ref()) {
+            Ok(symbol) => match symbol.found.map(|x| x.kind) {
+                Some(SymbolKind::Module(x)) => x.parameters,
+                Some(SymbolKind::Interface(x)) => x.parameters,
+                _ => return,
+            },
+            Err(_) => return,
+        };
+
+        let items: Vec<InstParameterItem> = match arg.inst_parameter_opt {
+            Some(ref x) => x.inst_parameter_list.as_ref().into(),
+            None => return,
+        };
+
+        for item in items {
+            let name = item.identifier.identifier_token.token.text;
+            let parameter = match parameters.iter().find(|x| x.name == name) {
+                Some(x) => x,
+                None => continue,
+            };
+            let r#type = &parameter.property.r#type;
+            if !r#type.array.is_empty() {
+                continue;
+            }
+            let width = Evaluator::new().type_width(r#type.clone());
+            let parameter_name = resource_table::get_str_value(name).unwrap();
+            let token = &item.identifier.identifier_token;
+            if let Some(ref x) = item.inst_parameter_item_opt {
+                self.check_expression(&parameter_name, width, &x.expression, token);
+            }
+        }
+    }
+
+    fn check(&mut self, target: &str, expected: usize, actual: usize, token: &VerylToken) {
+        if expected < actual && !allow_table::contains("implicit_truncation") {
+            self.errors.push(AnalyzerError::implicit_truncation(
+                target, expected, actual, self.text, token,
+            ));
+        }
+        if expected > actual && !allow_table::contains("implicit_extension") {
+            self.errors.push(AnalyzerError::implicit_extension(
+                target, expected, actual, self.text, token,
+            ));
+        }
+    }
+}
+
+/// Walker to check whether the expression has operators which widen the result
+#[derive(Default)]
+struct WideningOperator {
+    found: bool,
+}
+
+impl VerylWalker for WideningOperator {
+    fn operator08(&mut self, arg: &Operator08) {
+        if arg.operator08_token.text().starts_with("<<") {
+            self.found = true;
+        }
+    }
+
+    fn operator09(&mut self, _arg: &Operator09) {
+        self.found = true;
+    }
+
+    fn operator11(&mut self, _arg: &Operator11) {
+        self.found = true;
+    }
+
+    fn star(&mut self, _arg: &Star) {
+        self.found = true;
+    }
+}
+
+fn has_widening_operator(arg: &Expression) -> bool {
+    let mut walker = WideningOperator::default();
+    walker.expression(arg);
+    walker.found
+}
+
+impl<'a> Handler for CheckWidth<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckWidth<'a> {
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::Assignment(x) = &*arg.identifier_statement_group {
+                // compound assignment is not checked
+                if let AssignmentGroup::Equ(_) = &*x.assignment.assignment_group {
+                    let target = &arg.expression_identifier;
+                    let width = Evaluator::new().expression_identifier(target).width();
+                    self.check_expression(
+                        &target.identifier.identifier_token.text(),
+                        width,
+                        &x.assignment.expression,
+                        &target.identifier.identifier_token,
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let target = &arg.hierarchical_identifier;
+            let width = Evaluator::new().hierarchical_identifier(target).width();
+            self.check_expression(
+                &target.identifier.identifier_token.text(),
+                width,
+                &arg.expression,
+                &target.identifier.identifier_token,
+            );
+        }
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(ref x) = arg.var_declaration_opt0 {
+                let r#type: Type = arg.array_type.as_ref().into();
+                if r#type.array.is_empty() {
+                    let width = Evaluator::new().type_width(r#type);
+                    self.check_expression(
+                        &arg.identifier.identifier_token.text(),
+                        width,
+                        &x.expression,
+                        &arg.identifier.identifier_token,
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(ref x) = arg.inst_declaration_opt0 {
+                self.check_parameter_override(arg, &x.inst_parameter);
+            }
+        }
+        Ok(())
+    }
+}
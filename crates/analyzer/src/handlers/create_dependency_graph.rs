@@ -0,0 +1,375 @@
+use crate::allow_table;
+use crate::dependency_table::{
+    self, Connection, Dependency, DependencyGraph, DependencyRange, Instance, ModulePort, Signal,
+};
+use crate::evaluator::Evaluator;
+use crate::symbol::{Direction, Symbol, SymbolKind};
+use crate::symbol_table::{self, SymbolPathNamespace};
+use std::collections::HashMap;
+use veryl_parser::resource_table::TokenId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, VerylToken};
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::ParolError;
+
+/// Collects identifiers read in an expression
+#[derive(Default)]
+struct Reads {
+    identifiers: Vec<ExpressionIdentifier>,
+}
+
+impl VerylWalker for Reads {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        self.identifiers.push(arg.clone());
+        if let ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) =
+            &*arg.expression_identifier_group
+        {
+            for x in &x.expression_identifier_group_list1 {
+                self.select(&x.select);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CreateDependencyGraph {
+    point: HandlerPoint,
+    graph: Option<DependencyGraph>,
+    in_always_comb: bool,
+    in_function: bool,
+    /// Signals read in conditions of enclosing if and case statements
+    conditions: Vec<Vec<Signal>>,
+    /// Sources of variables assigned in the current always_comb
+    locals: HashMap<TokenId, Vec<Signal>>,
+}
+
+impl CreateDependencyGraph {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn resolve<T: Into<SymbolPathNamespace>>(path: T) -> Option<Symbol> {
+        if let Ok(x) = symbol_table::resolve(path) {
+            x.full_path.first().cloned()
+        } else {
+            None
+        }
+    }
+
+    fn signal(symbol: &Symbol, selects: &[&Select], member: bool, token: &Token) -> Option<Signal> {
+        if !matches!(symbol.kind, SymbolKind::Variable(_) | SymbolKind::Port(_)) {
+            return None;
+        }
+
+        let range = if member {
+            DependencyRange::Unknown
+        } else if selects.is_empty() {
+            DependencyRange::Whole
+        } else if let Some((lsb, width)) = Evaluator::new().selected_bits(symbol, selects) {
+            DependencyRange::Bits(lsb, width)
+        } else {
+            DependencyRange::Unknown
+        };
+
+        Some(Signal {
+            id: symbol.token.id,
+            name: token.text,
+            range,
+        })
+    }
+
+    fn expression_identifier_signal(arg: &ExpressionIdentifier) -> Option<Signal> {
+        let symbol = Self::resolve(arg)?;
+        let token = &arg.identifier.identifier_token.token;
+        match &*arg.expression_identifier_group {
+            ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) => {
+                let selects: Vec<&Select> = x
+                    .expression_identifier_group_list1
+                    .iter()
+                    .map(|x| x.select.as_ref())
+                    .collect();
+                let member = !x.expression_identifier_group_list2.is_empty();
+                Self::signal(&symbol, &selects, member, token)
+            }
+            _ => None,
+        }
+    }
+
+    fn hierarchical_identifier_signal(arg: &HierarchicalIdentifier) -> Option<Signal> {
+        let symbol = Self::resolve(arg)?;
+        let selects: Vec<&Select> = arg
+            .hierarchical_identifier_list
+            .iter()
+            .map(|x| x.select.as_ref())
+            .collect();
+        let member = !arg.hierarchical_identifier_list0.is_empty();
+        Self::signal(
+            &symbol,
+            &selects,
+            member,
+            &arg.identifier.identifier_token.token,
+        )
+    }
+
+    fn reads(reads: Reads) -> Vec<Signal> {
+        reads
+            .identifiers
+            .iter()
+            .filter_map(Self::expression_identifier_signal)
+            .collect()
+    }
+
+    fn expression_reads(arg: &Expression) -> Vec<Signal> {
+        let mut reads = Reads::default();
+        reads.expression(arg);
+        Self::reads(reads)
+    }
+
+    fn select_reads<'b, T: Iterator<Item = &'b Select>>(selects: T) -> Vec<Signal> {
+        let mut reads = Reads::default();
+        for x in selects {
+            reads.select(x);
+        }
+        Self::reads(reads)
+    }
+
+    fn add(&mut self, dst: Signal, srcs: &[Signal], token: &VerylToken) {
+        // allowed dependencies are excluded from loop detection
+        if allow_table::contains("combinational_loop") {
+            return;
+        }
+        if let Some(ref mut graph) = self.graph {
+            for src in srcs {
+                graph.dependencies.push(Dependency {
+                    src: *src,
+                    dst,
+                    token: token.token,
+                    through: None,
+                });
+            }
+        }
+    }
+
+    /// Replaces variables assigned in the current always_comb by their sources
+    fn substitute(&self, signals: Vec<Signal>) -> Vec<Signal> {
+        let mut ret = Vec::new();
+        for x in signals {
+            if let Some(x) = self.locals.get(&x.id) {
+                ret.extend(x.iter().cloned());
+            } else {
+                ret.push(x);
+            }
+        }
+        ret
+    }
+}
+
+impl Handler for CreateDependencyGraph {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CreateDependencyGraph {
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !self.in_always_comb {
+                return Ok(());
+            }
+            if let IdentifierStatementGroup::Assignment(x) = &*arg.identifier_statement_group {
+                let target = &arg.expression_identifier;
+                let dst = match Self::expression_identifier_signal(target) {
+                    Some(x) => x,
+                    None => return Ok(()),
+                };
+
+                let mut srcs = Self::expression_reads(&x.assignment.expression);
+                if let ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) =
+                    &*target.expression_identifier_group
+                {
+                    srcs.append(&mut Self::select_reads(
+                        x.expression_identifier_group_list1
+                            .iter()
+                            .map(|x| x.select.as_ref()),
+                    ));
+                }
+                // compound assignment reads the target
+                if !matches!(&*x.assignment.assignment_group, AssignmentGroup::Equ(_)) {
+                    srcs.push(dst);
+                }
+                for x in &self.conditions {
+                    srcs.extend(x.iter().cloned());
+                }
+
+                let srcs = self.substitute(srcs);
+                self.add(dst, &srcs, &target.identifier.identifier_token);
+                self.locals.entry(dst.id).or_default().extend(srcs);
+            }
+        }
+        Ok(())
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) -> Result<(), ParolError> {
+        if self.in_always_comb {
+            match self.point {
+                HandlerPoint::Before => {
+                    let mut reads = Self::expression_reads(&arg.expression);
+                    for x in &arg.if_statement_list0 {
+                        reads.append(&mut Self::expression_reads(&x.expression));
+                    }
+                    self.conditions.push(reads);
+                }
+                HandlerPoint::After => {
+                    self.conditions.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Result<(), ParolError> {
+        if self.in_always_comb {
+            match self.point {
+                HandlerPoint::Before => {
+                    let mut reads = Self::expression_reads(&arg.expression);
+                    for x in &arg.case_statement_list {
+                        if let CaseItemGroup::Expression(x) = &*x.case_item.case_item_group {
+                            reads.append(&mut Self::expression_reads(&x.expression));
+                        }
+                    }
+                    self.conditions.push(reads);
+                }
+                HandlerPoint::After => {
+                    self.conditions.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, _arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.in_always_comb = true;
+                self.locals.clear();
+            }
+            HandlerPoint::After => self.in_always_comb = false,
+        }
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let target = &arg.hierarchical_identifier;
+            if let Some(dst) = Self::hierarchical_identifier_signal(target) {
+                let mut srcs = Self::expression_reads(&arg.expression);
+                srcs.append(&mut Self::select_reads(
+                    target
+                        .hierarchical_identifier_list
+                        .iter()
+                        .map(|x| x.select.as_ref()),
+                ));
+                self.add(dst, &srcs, &target.identifier.identifier_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let (Some(ref x), false) = (&arg.var_declaration_opt0, self.in_function) {
+                if let Some(symbol) = Self::resolve(arg.identifier.as_ref()) {
+                    let token = &arg.identifier.identifier_token;
+                    if let Some(dst) = Self::signal(&symbol, &[], false, &token.token) {
+                        let srcs = Self::expression_reads(&x.expression);
+                        self.add(dst, &srcs, token);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let module = match Self::resolve(arg.scoped_identifier.as_ref()) {
+                Some(x) if matches!(x.kind, SymbolKind::Module(_)) => x.token.id,
+                _ => return Ok(()),
+            };
+
+            let items: Vec<InstPortItem> = match arg.inst_declaration_opt1 {
+                Some(ref x) => match x.inst_declaration_opt2 {
+                    Some(ref x) => x.inst_port_list.as_ref().into(),
+                    None => Vec::new(),
+                },
+                None => Vec::new(),
+            };
+
+            let mut connections = Vec::new();
+            for item in items {
+                let signals = if let Some(ref x) = item.inst_port_item_opt {
+                    Self::expression_reads(&x.expression)
+                } else if let Some(symbol) = Self::resolve(item.identifier.as_ref()) {
+                    let token = &item.identifier.identifier_token.token;
+                    Self::signal(&symbol, &[], false, token)
+                        .into_iter()
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+                connections.push(Connection {
+                    port: item.identifier.identifier_token.token.text,
+                    signals,
+                    token: item.identifier.identifier_token.token,
+                });
+            }
+
+            if let Some(ref mut graph) = self.graph {
+                graph.instances.push(Instance {
+                    name: arg.identifier.identifier_token.token.text,
+                    module,
+                    connections,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(symbol) = Self::resolve(arg.identifier.as_ref()) {
+                if let (SymbolKind::Port(x), Some(ref mut graph)) = (&symbol.kind, &mut self.graph)
+                {
+                    graph.ports.push(ModulePort {
+                        name: symbol.token.text,
+                        id: symbol.token.id,
+                        input: matches!(x.direction, Direction::Input | Direction::Inout),
+                        output: matches!(x.direction, Direction::Output | Direction::Inout),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, _arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_function = true,
+            HandlerPoint::After => self.in_function = false,
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        let token = &arg.identifier.identifier_token.token;
+        match self.point {
+            HandlerPoint::Before => self.graph = Some(DependencyGraph::new(token.file_path)),
+            HandlerPoint::After => {
+                if let Some(graph) = self.graph.take() {
+                    dependency_table::insert(token.id, graph);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,25 @@
 use crate::analyzer_error::AnalyzerError;
-use crate::symbol_table::{self, SymbolPath};
+use crate::namespace_table;
+use crate::symbol::{Direction, GenericInstance, Port, SymbolKind};
+use crate::symbol_table::{self, SymbolPath, SymbolPathNamespace};
+use veryl_parser::resource_table;
 use veryl_parser::veryl_grammar_trait::*;
-use veryl_parser::veryl_walker::{Handler, HandlerPoint};
-use veryl_parser::ParolError;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::{ParolError, Stringifier};
+
+/// Collects identifiers assigned by an output connection or argument
+#[derive(Default)]
+struct Assignee {
+    identifiers: Vec<ExpressionIdentifier>,
+}
+
+impl VerylWalker for Assignee {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        // Selects are not walked because identifiers in them are read
+        self.identifiers.push(arg.clone());
+    }
+}
 
 #[derive(Default)]
 pub struct CreateReference<'a> {
@@ -11,6 +28,11 @@ pub struct CreateReference<'a> {
     point: HandlerPoint,
 }
 
+fn generic_instance_name(base: &str, instance: &GenericInstance) -> String {
+    let arguments: Vec<_> = instance.arguments.iter().map(|x| x.to_string()).collect();
+    format!("{base}::<{}>", arguments.join(", "))
+}
+
 impl<'a> CreateReference<'a> {
     pub fn new(text: &'a str) -> Self {
         Self {
@@ -18,6 +40,167 @@ impl<'a> CreateReference<'a> {
             ..Default::default()
         }
     }
+
+    fn generic_instances(&mut self, segments: &[GenericSegment]) {
+        let head = &segments[0].0.identifier_token;
+        let namespace = if let Some(x) = namespace_table::get(head.token.id) {
+            x
+        } else {
+            return;
+        };
+
+        let mut path = SymbolPath::default();
+        for (identifier, arg) in segments {
+            path.push(identifier.identifier_token.token.text);
+            let symbol = if let Ok(x) = symbol_table::get(&path, &namespace) {
+                if let Some(x) = x.found {
+                    x
+                } else {
+                    return;
+                }
+            } else {
+                return;
+            };
+
+            let items: Vec<WithGenericArgumentItem> = if let Some(x) = arg {
+                x.with_generic_argument_list.as_ref().into()
+            } else {
+                Vec::new()
+            };
+            let arity = symbol.generic_parameters().len();
+            if arity != items.len() {
+                self.errors.push(AnalyzerError::mismatch_generics_arity(
+                    &identifier.identifier_token.text(),
+                    arity,
+                    items.len(),
+                    self.text,
+                    &identifier.identifier_token,
+                ));
+                return;
+            }
+            if items.is_empty() {
+                continue;
+            }
+
+            let mut arguments = Vec::new();
+            let mut owner = None;
+            for item in &items {
+                let mut stringifier = Stringifier::new();
+                stringifier.with_generic_argument_item(item);
+                arguments.push(resource_table::insert_str(stringifier.as_str()));
+
+                // argument depending on generic parameter of the enclosing declaration
+                if let WithGenericArgumentItem::ScopedIdentifier(x) = item {
+                    if let Ok(x) = symbol_table::resolve(x.scoped_identifier.as_ref()) {
+                        if let Some(x) = x.found {
+                            if let SymbolKind::GenericParameter(x) = x.kind {
+                                owner = Some(x.owner);
+                            }
+                        }
+                    }
+                }
+            }
+            let instance = GenericInstance {
+                token: identifier.identifier_token.token,
+                arguments,
+                owner,
+            };
+
+            // mangled names of different arguments must not be the same
+            let base = identifier.identifier_token.text();
+            let mangled = instance.mangled_name(&base);
+            let conflict = symbol
+                .generic_instances
+                .iter()
+                .find(|x| x.arguments != instance.arguments && x.mangled_name(&base) == mangled);
+            if let Some(previous) = conflict {
+                self.errors
+                    .push(AnalyzerError::conflicting_generic_instance(
+                        &generic_instance_name(&base, &instance),
+                        &generic_instance_name(&base, previous),
+                        &mangled,
+                        self.text,
+                        &identifier.identifier_token,
+                    ));
+                return;
+            }
+
+            symbol_table::add_generic_instance(symbol.token.id, instance);
+        }
+    }
+
+    fn add_assignment<T: Into<SymbolPathNamespace>>(path: T, token: &Token) {
+        if let Ok(symbol) = symbol_table::resolve(path) {
+            for symbol in symbol.full_path {
+                symbol_table::add_assignment(symbol.token.id, token);
+            }
+        }
+    }
+
+    /// Adds assignments to identifiers in the expression.
+    /// If the identifiers may also be read, `token` is used as the location of the assignment
+    /// so that the reference is kept as a read.
+    fn add_expression_assignment(arg: &Expression, token: Option<&Token>) {
+        let mut assignee = Assignee::default();
+        assignee.expression(arg);
+        for x in &assignee.identifiers {
+            let token = token.unwrap_or(&x.identifier.identifier_token.token);
+            Self::add_assignment(x, token);
+        }
+    }
+
+    fn add_function_call_assignment(identifier: &ExpressionIdentifier, arg: &FunctionCall) {
+        let ports = match symbol_table::resolve(identifier).map(|x| x.found.map(|x| x.kind)) {
+            Ok(Some(SymbolKind::Function(x))) => x.ports,
+            _ => return,
+        };
+        let items: Vec<ArgumentItem> = match arg.function_call_opt {
+            Some(ref x) => x.argument_list.as_ref().into(),
+            None => return,
+        };
+        for (port, item) in ports.iter().zip(items.iter()) {
+            if matches!(
+                port.property.direction,
+                Direction::Output | Direction::Inout | Direction::Ref
+            ) {
+                Self::add_expression_assignment(&item.expression, None);
+            }
+        }
+    }
+
+    /// Adds assignments by output connections.
+    /// All connections are assumed to be output if `ports` is unknown.
+    fn add_connection_assignment(arg: &InstDeclaration, ports: Option<&[Port]>) {
+        let items: Vec<InstPortItem> = match arg.inst_declaration_opt1 {
+            Some(ref x) => match x.inst_declaration_opt2 {
+                Some(ref x) => x.inst_port_list.as_ref().into(),
+                None => return,
+            },
+            None => return,
+        };
+
+        let instance = &arg.identifier.identifier_token.token;
+        for item in items {
+            let name = item.identifier.identifier_token.token.text;
+            let direction = ports.map(|x| {
+                x.iter()
+                    .find(|x| x.name == name)
+                    .map(|x| x.property.direction.clone())
+            });
+            let token = match direction {
+                Some(Some(Direction::Output)) => None,
+                Some(Some(Direction::Inout)) | None => Some(instance),
+                _ => continue,
+            };
+
+            if let Some(ref x) = item.inst_port_item_opt {
+                Self::add_expression_assignment(&x.expression, token);
+            } else {
+                let token = token.unwrap_or(&item.identifier.identifier_token.token);
+                Self::add_assignment(item.identifier.as_ref(), token);
+            }
+        }
+    }
 }
 
 impl<'a> Handler for CreateReference<'a> {
@@ -67,6 +250,13 @@ impl<'a> VerylGrammarTrait for CreateReference<'a> {
         Ok(())
     }
 
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.generic_instances(&Vec::from(arg));
+        }
+        Ok(())
+    }
+
     fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             // system function
@@ -74,6 +264,8 @@ impl<'a> VerylGrammarTrait for CreateReference<'a> {
                 return Ok(());
             }
 
+            self.generic_instances(&Vec::from(arg));
+
             match symbol_table::resolve(arg) {
                 Ok(symbol) => {
                     if symbol.found.is_some() {
@@ -152,8 +344,16 @@ impl<'a> VerylGrammarTrait for CreateReference<'a> {
                             );
                         }
                     }
+                    match symbol.found.map(|x| x.kind) {
+                        Some(SymbolKind::Module(x)) => {
+                            Self::add_connection_assignment(arg, Some(&x.ports))
+                        }
+                        Some(SymbolKind::Interface(_)) => (),
+                        _ => Self::add_connection_assignment(arg, None),
+                    }
                 }
                 Err(err) => {
+                    Self::add_connection_assignment(arg, None);
                     let name = format!("{}", err.last_found.token.text);
                     let member = format!("{}", err.not_found);
                     self.errors.push(AnalyzerError::unknown_member(
@@ -197,4 +397,59 @@ impl<'a> VerylGrammarTrait for CreateReference<'a> {
         }
         Ok(())
     }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let identifier = arg.expression_identifier.as_ref();
+            match &*arg.identifier_statement_group {
+                IdentifierStatementGroup::Assignment(_) => {
+                    Self::add_assignment(identifier, &identifier.identifier.identifier_token.token);
+                }
+                IdentifierStatementGroup::FunctionCall(x) => {
+                    Self::add_function_call_assignment(identifier, &x.function_call);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn factor(&mut self, arg: &Factor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Factor::ExpressionIdentifierFactorOpt(x) = arg {
+                if let Some(ref y) = x.factor_opt {
+                    Self::add_function_call_assignment(&x.expression_identifier, &y.function_call);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let identifier = &arg.hierarchical_identifier;
+            Self::add_assignment(
+                identifier.as_ref(),
+                &identifier.identifier.identifier_token.token,
+            );
+        }
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if arg.var_declaration_opt0.is_some() {
+                let token = &arg.identifier.identifier_token.token;
+                symbol_table::add_assignment(token.id, token);
+            }
+        }
+        Ok(())
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.identifier.identifier_token.token;
+            symbol_table::add_assignment(token.id, token);
+        }
+        Ok(())
+    }
 }
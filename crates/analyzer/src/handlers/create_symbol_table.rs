@@ -5,10 +5,10 @@ use crate::namespace_table;
 use crate::symbol::Direction as SymDirection;
 use crate::symbol::Type as SymType;
 use crate::symbol::{
-    EnumMemberProperty, EnumProperty, FunctionProperty, InstanceProperty, InterfaceProperty,
-    ModportMember, ModportProperty, ModuleProperty, ParameterProperty, ParameterScope,
-    ParameterValue, PortProperty, StructMemberProperty, Symbol, SymbolKind, TypeKind,
-    VariableProperty,
+    EnumMemberProperty, EnumProperty, FunctionProperty, GenericParameterProperty, InstanceProperty,
+    InterfaceProperty, ModportMember, ModportProperty, ModuleProperty, PackageProperty, Parameter,
+    ParameterProperty, ParameterScope, ParameterValue, Port, PortProperty, StructMemberProperty,
+    Symbol, SymbolKind, TypeKind, UnionMemberProperty, VariableProperty,
 };
 use crate::symbol_table;
 use std::collections::HashSet;
@@ -29,6 +29,7 @@ pub struct CreateSymbolTable<'a> {
     for_identifier: Option<VerylToken>,
     anonymous_namespace: usize,
     attribute_lines: HashSet<usize>,
+    in_union: bool,
 }
 
 impl<'a> CreateSymbolTable<'a> {
@@ -39,6 +40,42 @@ impl<'a> CreateSymbolTable<'a> {
         }
     }
 
+    fn insert_generic_parameters(
+        &mut self,
+        owner: &VerylToken,
+        items: &[WithGenericParameterItem],
+    ) {
+        for item in items {
+            let property = GenericParameterProperty {
+                owner: owner.token.id,
+            };
+            let kind = SymbolKind::GenericParameter(property);
+            self.insert_symbol(&item.identifier.identifier_token, kind);
+        }
+    }
+
+    fn parameters(arg: Option<&WithParameter>) -> Vec<Parameter> {
+        let mut ret = Vec::new();
+        if let Some(x) = arg.and_then(|x| x.with_parameter_opt.as_ref()) {
+            let items: Vec<WithParameterItem> = x.with_parameter_list.as_ref().into();
+            for item in items {
+                ret.push((&item).into());
+            }
+        }
+        ret
+    }
+
+    fn ports(arg: Option<&PortDeclaration>) -> Vec<Port> {
+        let mut ret = Vec::new();
+        if let Some(x) = arg.and_then(|x| x.port_declaration_opt.as_ref()) {
+            let items: Vec<PortDeclarationItem> = x.port_declaration_list.as_ref().into();
+            for item in items {
+                ret.push((&item).into());
+            }
+        }
+        ret
+    }
+
     fn insert_symbol(&mut self, token: &VerylToken, kind: SymbolKind) {
         let file = token.token.file_path;
         let line = token.token.line;
@@ -67,6 +104,15 @@ impl<'a> CreateSymbolTable<'a> {
         if allow_table::contains("unused_variable") {
             symbol.allow_unused = true;
         }
+        if allow_table::contains("undriven_variable") {
+            symbol.allow_undriven_variable = true;
+        }
+        if allow_table::contains("undriven_output") {
+            symbol.allow_undriven_output = true;
+        }
+        if allow_table::contains("unused_input") {
+            symbol.allow_unused_input = true;
+        }
 
         if !symbol_table::insert(&token.token, symbol) {
             let text = resource_table::get_str_value(token.token.text).unwrap();
@@ -109,7 +155,10 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
                 self.anonymous_namespace += 1;
 
                 let r#type: SymType = arg.scalar_type.as_ref().into();
-                let property = VariableProperty { r#type };
+                let property = VariableProperty {
+                    r#type,
+                    clock_domain: None,
+                };
                 let kind = SymbolKind::Variable(property);
                 self.insert_symbol(&arg.identifier.identifier_token, kind);
             }
@@ -121,7 +170,14 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             let r#type: SymType = arg.array_type.as_ref().into();
-            let property = VariableProperty { r#type };
+            let clock_domain = arg
+                .var_declaration_opt
+                .as_ref()
+                .map(|x| x.clock_domain.identifier.identifier_token.token.text);
+            let property = VariableProperty {
+                r#type,
+                clock_domain,
+            };
             let kind = SymbolKind::Variable(property);
             self.insert_symbol(&arg.identifier.identifier_token, kind);
         }
@@ -186,7 +242,12 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
         match self.point {
             HandlerPoint::Before => {
                 let r#type = arg.scalar_type.as_ref().into();
-                let property = EnumProperty { r#type };
+                let items: Vec<EnumItem> = arg.enum_list.as_ref().into();
+                let members = items
+                    .iter()
+                    .map(|x| x.identifier.identifier_token.token.text)
+                    .collect();
+                let property = EnumProperty { r#type, members };
                 let kind = SymbolKind::Enum(property);
                 self.insert_symbol(&arg.identifier.identifier_token, kind);
 
@@ -222,11 +283,32 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
         Ok(())
     }
 
+    fn union_declaration(&mut self, arg: &UnionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let kind = SymbolKind::Union;
+                self.insert_symbol(&arg.identifier.identifier_token, kind);
+
+                let name = arg.identifier.identifier_token.token.text;
+                self.namespace.push(name);
+                self.in_union = true;
+            }
+            HandlerPoint::After => {
+                self.namespace.pop();
+                self.in_union = false;
+            }
+        }
+        Ok(())
+    }
+
     fn struct_item(&mut self, arg: &StructItem) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             let r#type = arg.scalar_type.as_ref().into();
-            let property = StructMemberProperty { r#type };
-            let kind = SymbolKind::StructMember(property);
+            let kind = if self.in_union {
+                SymbolKind::UnionMember(UnionMemberProperty { r#type })
+            } else {
+                SymbolKind::StructMember(StructMemberProperty { r#type })
+            };
             self.insert_symbol(&arg.identifier.identifier_token, kind);
         }
         Ok(())
@@ -289,19 +371,25 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
         if let HandlerPoint::Before = self.point {
             let token = arg.identifier.identifier_token.token;
             let property = match &*arg.port_declaration_item_group {
-                PortDeclarationItemGroup::DirectionArrayType(x) => {
+                PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
                     let r#type: SymType = x.array_type.as_ref().into();
                     let direction: SymDirection = x.direction.as_ref().into();
+                    let clock_domain = x
+                        .port_declaration_item_opt
+                        .as_ref()
+                        .map(|x| x.clock_domain.identifier.identifier_token.token.text);
                     PortProperty {
                         token,
                         r#type: Some(r#type),
                         direction,
+                        clock_domain,
                     }
                 }
-                PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(_) => PortProperty {
+                PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(_) => PortProperty {
                     token,
                     r#type: None,
                     direction: SymDirection::Interface,
+                    clock_domain: None,
                 },
             };
             let kind = SymbolKind::Port(property);
@@ -313,8 +401,21 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => {
+                let generic_items: Vec<WithGenericParameterItem> =
+                    if let Some(ref x) = arg.function_declaration_opt {
+                        x.with_generic_parameter
+                            .with_generic_parameter_list
+                            .as_ref()
+                            .into()
+                    } else {
+                        vec![]
+                    };
+                let generic_parameters = generic_items
+                    .iter()
+                    .map(|x| x.identifier.identifier_token.token.text)
+                    .collect();
                 let mut parameters = Vec::new();
-                if let Some(ref x) = arg.function_declaration_opt {
+                if let Some(ref x) = arg.function_declaration_opt0 {
                     if let Some(ref x) = x.with_parameter.with_parameter_opt {
                         let items: Vec<WithParameterItem> = x.with_parameter_list.as_ref().into();
                         for item in items {
@@ -323,7 +424,7 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
                     }
                 }
                 let mut ports = Vec::new();
-                if let Some(ref x) = arg.function_declaration_opt0 {
+                if let Some(ref x) = arg.function_declaration_opt1 {
                     if let Some(ref x) = x.port_declaration.port_declaration_opt {
                         let items: Vec<PortDeclarationItem> =
                             x.port_declaration_list.as_ref().into();
@@ -332,14 +433,26 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
                         }
                     }
                 }
-                let property = FunctionProperty { parameters, ports };
+                let property = FunctionProperty {
+                    generic_parameters,
+                    parameters,
+                    ports,
+                    return_type: arg.scalar_type.as_ref().into(),
+                    items: arg
+                        .function_declaration_list
+                        .iter()
+                        .map(|x| x.function_item.as_ref().clone())
+                        .collect(),
+                };
                 self.insert_symbol(
                     &arg.identifier.identifier_token,
                     SymbolKind::Function(property),
                 );
 
                 let name = arg.identifier.identifier_token.token.text;
-                self.namespace.push(name)
+                self.namespace.push(name);
+
+                self.insert_generic_parameters(&arg.identifier.identifier_token, &generic_items);
             }
             HandlerPoint::After => self.namespace.pop(),
         }
@@ -349,33 +462,79 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => {
-                let mut parameters = Vec::new();
-                if let Some(ref x) = arg.module_declaration_opt {
-                    if let Some(ref x) = x.with_parameter.with_parameter_opt {
-                        let items: Vec<WithParameterItem> = x.with_parameter_list.as_ref().into();
-                        for item in items {
-                            parameters.push((&item).into());
-                        }
-                    }
-                }
-                let mut ports = Vec::new();
-                if let Some(ref x) = arg.module_declaration_opt0 {
-                    if let Some(ref x) = x.port_declaration.port_declaration_opt {
-                        let items: Vec<PortDeclarationItem> =
-                            x.port_declaration_list.as_ref().into();
-                        for item in items {
-                            ports.push((&item).into());
-                        }
-                    }
-                }
-                let property = ModuleProperty { parameters, ports };
+                let generic_items: Vec<WithGenericParameterItem> =
+                    if let Some(ref x) = arg.module_declaration_opt {
+                        x.with_generic_parameter
+                            .with_generic_parameter_list
+                            .as_ref()
+                            .into()
+                    } else {
+                        vec![]
+                    };
+                let generic_parameters = generic_items
+                    .iter()
+                    .map(|x| x.identifier.identifier_token.token.text)
+                    .collect();
+                let parameters = Self::parameters(
+                    arg.module_declaration_opt0
+                        .as_ref()
+                        .map(|x| x.with_parameter.as_ref()),
+                );
+                let ports = Self::ports(
+                    arg.module_declaration_opt1
+                        .as_ref()
+                        .map(|x| x.port_declaration.as_ref()),
+                );
+                let property = ModuleProperty {
+                    generic_parameters,
+                    parameters,
+                    ports,
+                    is_extern: false,
+                };
                 self.insert_symbol(
                     &arg.identifier.identifier_token,
                     SymbolKind::Module(property),
                 );
 
                 let name = arg.identifier.identifier_token.token.text;
-                self.namespace.push(name)
+                self.namespace.push(name);
+
+                self.insert_generic_parameters(&arg.identifier.identifier_token, &generic_items);
+            }
+            HandlerPoint::After => self.namespace.pop(),
+        }
+        Ok(())
+    }
+
+    fn extern_module_declaration(
+        &mut self,
+        arg: &ExternModuleDeclaration,
+    ) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let parameters = Self::parameters(
+                    arg.extern_module_declaration_opt
+                        .as_ref()
+                        .map(|x| x.with_parameter.as_ref()),
+                );
+                let ports = Self::ports(
+                    arg.extern_module_declaration_opt0
+                        .as_ref()
+                        .map(|x| x.port_declaration.as_ref()),
+                );
+                let property = ModuleProperty {
+                    generic_parameters: Vec::new(),
+                    parameters,
+                    ports,
+                    is_extern: true,
+                };
+                self.insert_symbol(
+                    &arg.identifier.identifier_token,
+                    SymbolKind::Module(property),
+                );
+
+                let name = arg.identifier.identifier_token.token.text;
+                self.namespace.push(name);
             }
             HandlerPoint::After => self.namespace.pop(),
         }
@@ -438,8 +597,21 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => {
+                let generic_items: Vec<WithGenericParameterItem> =
+                    if let Some(ref x) = arg.interface_declaration_opt {
+                        x.with_generic_parameter
+                            .with_generic_parameter_list
+                            .as_ref()
+                            .into()
+                    } else {
+                        vec![]
+                    };
+                let generic_parameters = generic_items
+                    .iter()
+                    .map(|x| x.identifier.identifier_token.token.text)
+                    .collect();
                 let mut parameters = Vec::new();
-                if let Some(ref x) = arg.interface_declaration_opt {
+                if let Some(ref x) = arg.interface_declaration_opt0 {
                     if let Some(ref x) = x.with_parameter.with_parameter_opt {
                         let items: Vec<WithParameterItem> = x.with_parameter_list.as_ref().into();
                         for item in items {
@@ -447,14 +619,19 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
                         }
                     }
                 }
-                let property = InterfaceProperty { parameters };
+                let property = InterfaceProperty {
+                    generic_parameters,
+                    parameters,
+                };
                 self.insert_symbol(
                     &arg.identifier.identifier_token,
                     SymbolKind::Interface(property),
                 );
 
                 let name = arg.identifier.identifier_token.token.text;
-                self.namespace.push(name)
+                self.namespace.push(name);
+
+                self.insert_generic_parameters(&arg.identifier.identifier_token, &generic_items);
             }
             HandlerPoint::After => self.namespace.pop(),
         }
@@ -520,10 +697,29 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn package_declaration(&mut self, arg: &PackageDeclaration) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => {
-                self.insert_symbol(&arg.identifier.identifier_token, SymbolKind::Package);
+                let generic_items: Vec<WithGenericParameterItem> =
+                    if let Some(ref x) = arg.package_declaration_opt {
+                        x.with_generic_parameter
+                            .with_generic_parameter_list
+                            .as_ref()
+                            .into()
+                    } else {
+                        vec![]
+                    };
+                let generic_parameters = generic_items
+                    .iter()
+                    .map(|x| x.identifier.identifier_token.token.text)
+                    .collect();
+                let property = PackageProperty { generic_parameters };
+                self.insert_symbol(
+                    &arg.identifier.identifier_token,
+                    SymbolKind::Package(property),
+                );
 
                 let name = arg.identifier.identifier_token.token.text;
-                self.namespace.push(name)
+                self.namespace.push(name);
+
+                self.insert_generic_parameters(&arg.identifier.identifier_token, &generic_items);
             }
             HandlerPoint::After => self.namespace.pop(),
         }
@@ -1,12 +1,17 @@
 pub mod allow_table;
 pub mod analyzer;
 pub mod analyzer_error;
+pub mod dependency_table;
 pub mod evaluator;
+pub mod feature_table;
 pub mod handlers;
 pub mod msb_table;
 pub mod namespace;
 pub mod namespace_table;
 pub mod symbol;
 pub mod symbol_table;
+pub mod value;
 pub use analyzer::Analyzer;
 pub use analyzer_error::AnalyzerError;
+#[cfg(test)]
+mod tests;
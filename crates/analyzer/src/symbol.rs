@@ -1,8 +1,12 @@
 use crate::evaluator::{Evaluated, Evaluator};
 use crate::namespace::Namespace;
-use std::cell::Cell;
+use crate::symbol_table::{self, ResolveResult, SymbolPath};
+use crate::value::Value;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::cell::RefCell;
 use std::fmt;
-use veryl_parser::resource_table::StrId;
+use veryl_parser::resource_table::{StrId, TokenId};
 use veryl_parser::veryl_grammar_trait as syntax_tree;
 use veryl_parser::veryl_token::Token;
 use veryl_parser::veryl_walker::VerylWalker;
@@ -14,8 +18,14 @@ pub struct Symbol {
     pub kind: SymbolKind,
     pub namespace: Namespace,
     pub references: Vec<Token>,
-    pub evaluated: Cell<Option<Evaluated>>,
+    /// References which assign to the symbol
+    pub assignments: Vec<Token>,
+    pub generic_instances: Vec<GenericInstance>,
+    pub evaluated: RefCell<Option<Evaluated>>,
     pub allow_unused: bool,
+    pub allow_undriven_variable: bool,
+    pub allow_undriven_output: bool,
+    pub allow_unused_input: bool,
     pub doc_comment: Vec<StrId>,
 }
 
@@ -31,14 +41,28 @@ impl Symbol {
             kind,
             namespace: namespace.to_owned(),
             references: Vec::new(),
-            evaluated: Cell::new(None),
+            assignments: Vec::new(),
+            generic_instances: Vec::new(),
+            evaluated: RefCell::new(None),
             allow_unused: false,
+            allow_undriven_variable: false,
+            allow_undriven_output: false,
+            allow_unused_input: false,
             doc_comment,
         }
     }
 
+    /// References which read the symbol
+    pub fn reads(&self) -> Vec<&Token> {
+        self.references
+            .iter()
+            .filter(|x| !self.assignments.iter().any(|y| y.id == x.id))
+            .collect()
+    }
+
     pub fn evaluate(&self) -> Evaluated {
-        if let Some(evaluated) = self.evaluated.get() {
+        let evaluated = self.evaluated.borrow().clone();
+        if let Some(evaluated) = evaluated {
             evaluated
         } else {
             let evaluated = match &self.kind {
@@ -50,22 +74,94 @@ impl Symbol {
                         Evaluated::Unknown
                     }
                 }
+                SymbolKind::Port(x) => {
+                    let mut evaluator = Evaluator::new();
+                    let width = x.r#type.clone().and_then(|x| evaluator.type_width(x));
+                    if let Some(width) = width {
+                        Evaluated::Variable { width }
+                    } else {
+                        Evaluated::Unknown
+                    }
+                }
                 SymbolKind::Parameter(x) => {
                     let mut evaluator = Evaluator::new();
-                    if let Some(width) = evaluator.type_width(x.r#type.clone()) {
+                    let width = evaluator.type_width(x.r#type.clone());
+                    if let Some(width) = width {
                         evaluator.context_width.push(width);
                     }
-                    match &x.value {
+                    let evaluated = match &x.value {
                         ParameterValue::Expression(x) => evaluator.expression(x),
                         ParameterValue::TypeExpression(_) => Evaluated::Unknown,
+                    };
+                    if let Some(width) = width {
+                        evaluated.cast(width, x.r#type.is_signed())
+                    } else {
+                        evaluated
                     }
                 }
+                SymbolKind::EnumMember(x) => self.evaluate_enum_member(x),
                 _ => Evaluated::Unknown,
             };
-            self.evaluated.replace(Some(evaluated));
+            self.evaluated.replace(Some(evaluated.clone()));
             evaluated
         }
     }
+
+    /// Member without value is the previous member plus one as SystemVerilog
+    fn evaluate_enum_member(&self, property: &EnumMemberProperty) -> Evaluated {
+        let mut namespace = self.namespace.clone();
+        let name = match namespace.paths.pop() {
+            Some(x) => x,
+            None => return Evaluated::Unknown,
+        };
+        let r#enum = match symbol_table::get(&SymbolPath::new(&[name]), &namespace) {
+            Ok(ResolveResult { found: Some(x), .. }) => x,
+            _ => return Evaluated::Unknown,
+        };
+        let r#enum = match r#enum.kind {
+            SymbolKind::Enum(x) => x,
+            _ => return Evaluated::Unknown,
+        };
+
+        let mut evaluator = Evaluator::new();
+        let width = match evaluator.type_width(r#enum.r#type.clone()) {
+            Some(x) => x,
+            None => return Evaluated::Unknown,
+        };
+        if let Some(ref x) = property.value {
+            evaluator.context_width.push(width);
+            return evaluator.expression(x).cast(width, false);
+        }
+
+        let index = r#enum.members.iter().position(|x| *x == self.token.text);
+        match index {
+            Some(0) => Evaluated::Fixed(Value::new(BigUint::zero(), width, false)),
+            Some(x) => {
+                let prev = SymbolPath::new(&[r#enum.members[x - 1]]);
+                match symbol_table::get(&prev, &self.namespace) {
+                    Ok(ResolveResult { found: Some(x), .. }) => match x.evaluate() {
+                        Evaluated::Fixed(x) if !x.is_xz() => {
+                            let one = Value::new(BigUint::one(), width, false);
+                            Evaluated::Fixed(x.add(&one, width))
+                        }
+                        _ => Evaluated::Unknown,
+                    },
+                    _ => Evaluated::Unknown,
+                }
+            }
+            None => Evaluated::Unknown,
+        }
+    }
+
+    pub fn generic_parameters(&self) -> &[StrId] {
+        match &self.kind {
+            SymbolKind::Module(x) => &x.generic_parameters,
+            SymbolKind::Interface(x) => &x.generic_parameters,
+            SymbolKind::Function(x) => &x.generic_parameters,
+            SymbolKind::Package(x) => &x.generic_parameters,
+            _ => &[],
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -78,13 +174,16 @@ pub enum SymbolKind {
     Parameter(ParameterProperty),
     Instance(InstanceProperty),
     Block,
-    Package,
+    Package(PackageProperty),
     Struct,
     StructMember(StructMemberProperty),
+    Union,
+    UnionMember(UnionMemberProperty),
     Enum(EnumProperty),
     EnumMember(EnumMemberProperty),
     Modport(ModportProperty),
     Genvar,
+    GenericParameter(GenericParameterProperty),
 }
 
 impl SymbolKind {
@@ -98,13 +197,16 @@ impl SymbolKind {
             SymbolKind::Parameter(_) => "parameter".to_string(),
             SymbolKind::Instance(_) => "instance".to_string(),
             SymbolKind::Block => "block".to_string(),
-            SymbolKind::Package => "package".to_string(),
+            SymbolKind::Package(_) => "package".to_string(),
             SymbolKind::Struct => "struct".to_string(),
             SymbolKind::StructMember(_) => "struct member".to_string(),
+            SymbolKind::Union => "union".to_string(),
+            SymbolKind::UnionMember(_) => "union member".to_string(),
             SymbolKind::Enum(_) => "enum".to_string(),
             SymbolKind::EnumMember(_) => "enum member".to_string(),
             SymbolKind::Modport(_) => "modport".to_string(),
             SymbolKind::Genvar => "genvar".to_string(),
+            SymbolKind::GenericParameter(_) => "generic parameter".to_string(),
         }
     }
 }
@@ -165,11 +267,15 @@ impl fmt::Display for SymbolKind {
                 format!("instance ({type_name})")
             }
             SymbolKind::Block => "block".to_string(),
-            SymbolKind::Package => "package".to_string(),
+            SymbolKind::Package(_) => "package".to_string(),
             SymbolKind::Struct => "struct".to_string(),
             SymbolKind::StructMember(x) => {
                 format!("struct member ({})", x.r#type)
             }
+            SymbolKind::Union => "union".to_string(),
+            SymbolKind::UnionMember(x) => {
+                format!("union member ({})", x.r#type)
+            }
             SymbolKind::Enum(x) => {
                 format!("enum ({})", x.r#type)
             }
@@ -186,6 +292,7 @@ impl fmt::Display for SymbolKind {
                 format!("modport ({} ports)", x.members.len())
             }
             SymbolKind::Genvar => "genvar".to_string(),
+            SymbolKind::GenericParameter(_) => "generic parameter".to_string(),
         };
         text.fmt(f)
     }
@@ -237,6 +344,8 @@ pub struct Type {
 
 #[derive(Debug, Clone)]
 pub enum TypeKind {
+    Clock,
+    Reset,
     Bit,
     Logic,
     U32,
@@ -256,6 +365,16 @@ pub enum TypeModifier {
     Signed,
 }
 
+impl Type {
+    pub fn is_signed(&self) -> bool {
+        matches!(self.kind, TypeKind::I32 | TypeKind::I64)
+            || self
+                .modifier
+                .iter()
+                .any(|x| matches!(x, TypeModifier::Signed))
+    }
+}
+
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut text = String::new();
@@ -266,6 +385,8 @@ impl fmt::Display for Type {
             }
         }
         match &self.kind {
+            TypeKind::Clock => text.push_str("clock"),
+            TypeKind::Reset => text.push_str("reset"),
             TypeKind::Bit => text.push_str("bit"),
             TypeKind::Logic => text.push_str("logic"),
             TypeKind::U32 => text.push_str("u32"),
@@ -324,6 +445,8 @@ impl From<&syntax_tree::ScalarType> for Type {
             syntax_tree::ScalarTypeGroup::VariableType(x) => {
                 let x = &x.variable_type;
                 let kind = match &*x.variable_type_group {
+                    syntax_tree::VariableTypeGroup::Clock(_) => TypeKind::Clock,
+                    syntax_tree::VariableTypeGroup::Reset(_) => TypeKind::Reset,
                     syntax_tree::VariableTypeGroup::Logic(_) => TypeKind::Logic,
                     syntax_tree::VariableTypeGroup::Bit(_) => TypeKind::Bit,
                     syntax_tree::VariableTypeGroup::ScopedIdentifier(x) => {
@@ -396,6 +519,7 @@ impl From<&syntax_tree::ArrayType> for Type {
 #[derive(Debug, Clone)]
 pub struct VariableProperty {
     pub r#type: Type,
+    pub clock_domain: Option<StrId>,
 }
 
 #[derive(Debug, Clone)]
@@ -403,6 +527,7 @@ pub struct PortProperty {
     pub token: Token,
     pub r#type: Option<Type>,
     pub direction: Direction,
+    pub clock_domain: Option<StrId>,
 }
 
 #[derive(Debug, Clone)]
@@ -422,20 +547,26 @@ impl From<&syntax_tree::PortDeclarationItem> for Port {
     fn from(value: &syntax_tree::PortDeclarationItem) -> Self {
         let token = value.identifier.identifier_token.token;
         let property = match &*value.port_declaration_item_group {
-            syntax_tree::PortDeclarationItemGroup::DirectionArrayType(x) => {
+            syntax_tree::PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
                 let r#type: Type = x.array_type.as_ref().into();
                 let direction: Direction = x.direction.as_ref().into();
+                let clock_domain = x
+                    .port_declaration_item_opt
+                    .as_ref()
+                    .map(|x| x.clock_domain.identifier.identifier_token.token.text);
                 PortProperty {
                     token,
                     r#type: Some(r#type),
                     direction,
+                    clock_domain,
                 }
             }
-            syntax_tree::PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(_) => {
+            syntax_tree::PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(_) => {
                 PortProperty {
                     token,
                     r#type: None,
                     direction: Direction::Interface,
+                    clock_domain: None,
                 }
             }
         };
@@ -524,19 +655,30 @@ impl From<&syntax_tree::WithParameterItem> for Parameter {
 
 #[derive(Debug, Clone)]
 pub struct ModuleProperty {
+    pub generic_parameters: Vec<StrId>,
     pub parameters: Vec<Parameter>,
     pub ports: Vec<Port>,
+    pub is_extern: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct InterfaceProperty {
+    pub generic_parameters: Vec<StrId>,
     pub parameters: Vec<Parameter>,
 }
 
 #[derive(Debug, Clone)]
 pub struct FunctionProperty {
+    pub generic_parameters: Vec<StrId>,
     pub parameters: Vec<Parameter>,
     pub ports: Vec<Port>,
+    pub return_type: Type,
+    pub items: Vec<syntax_tree::FunctionItem>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PackageProperty {
+    pub generic_parameters: Vec<StrId>,
 }
 
 #[derive(Debug, Clone)]
@@ -549,9 +691,15 @@ pub struct StructMemberProperty {
     pub r#type: Type,
 }
 
+#[derive(Debug, Clone)]
+pub struct UnionMemberProperty {
+    pub r#type: Type,
+}
+
 #[derive(Debug, Clone)]
 pub struct EnumProperty {
     pub r#type: Type,
+    pub members: Vec<StrId>,
 }
 
 #[derive(Debug, Clone)]
@@ -569,3 +717,34 @@ pub struct ModportMember {
     pub name: StrId,
     pub direction: Direction,
 }
+
+#[derive(Debug, Clone)]
+pub struct GenericParameterProperty {
+    pub owner: TokenId,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenericInstance {
+    pub token: Token,
+    pub arguments: Vec<StrId>,
+    pub owner: Option<TokenId>,
+}
+
+impl GenericInstance {
+    pub fn mangled_name(&self, base: &str) -> String {
+        mangled_name(base, &self.arguments)
+    }
+}
+
+pub fn mangled_name(base: &str, arguments: &[StrId]) -> String {
+    let mut ret = base.to_string();
+    for x in arguments {
+        let arg = format!("{x}").replace("::", "_");
+        let arg: String = arg
+            .chars()
+            .map(|x| if x.is_ascii_alphanumeric() { x } else { '_' })
+            .collect();
+        ret.push_str(&format!("__{arg}"));
+    }
+    ret
+}
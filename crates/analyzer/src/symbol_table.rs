@@ -1,7 +1,7 @@
 use crate::evaluator::Evaluated;
 use crate::namespace::Namespace;
 use crate::namespace_table;
-use crate::symbol::{Symbol, SymbolKind, TypeKind};
+use crate::symbol::{Direction, GenericInstance, Symbol, SymbolKind, TypeKind};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
@@ -98,7 +98,7 @@ impl From<&syntax_tree::ExpressionIdentifier> for SymbolPath {
         }
         path.push(value.identifier.identifier_token.token.text);
         match &*value.expression_identifier_group {
-            syntax_tree::ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+            syntax_tree::ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
                 path.push(x.identifier.identifier_token.token.text);
                 for x in &x.expression_identifier_group_list {
                     path.push(x.identifier.identifier_token.token.text);
@@ -234,16 +234,40 @@ impl SymbolTable {
                 if let Some(ret) = ret {
                     full_path.push(ret.clone());
                     match &ret.kind {
-                        SymbolKind::Variable(x) => {
-                            if let TypeKind::UserDefined(ref x) = x.r#type.kind {
+                        SymbolKind::Variable(_) | SymbolKind::Port(_) => {
+                            let r#type = match &ret.kind {
+                                SymbolKind::Variable(x) => Some(&x.r#type),
+                                SymbolKind::Port(x) => {
+                                    // members of interface port can't be resolved without modport
+                                    if let Direction::Interface = x.direction {
+                                        return Ok(ResolveResult {
+                                            found: Some(ret.clone()),
+                                            full_path,
+                                        });
+                                    }
+                                    x.r#type.as_ref()
+                                }
+                                _ => None,
+                            };
+                            if let Some(TypeKind::UserDefined(ref x)) = r#type.map(|x| &x.kind) {
                                 let path = SymbolPath::new(x);
                                 if let Ok(symbol) = self.get(&path, &namespace) {
                                     if let Some(found) = symbol.found {
+                                        // members of generic type can't be resolved until instantiation
+                                        if let SymbolKind::GenericParameter(_) = found.kind {
+                                            return Ok(ResolveResult {
+                                                found: Some(ret.clone()),
+                                                full_path,
+                                            });
+                                        }
                                         namespace = Namespace::new();
                                         for path in &found.namespace.paths {
                                             namespace.push(*path);
                                         }
-                                        namespace.push(found.token.text);
+                                        // members of modport are placed at the interface
+                                        if !matches!(found.kind, SymbolKind::Modport(_)) {
+                                            namespace.push(found.token.text);
+                                        }
                                         inner = true;
                                     }
                                 }
@@ -254,12 +278,14 @@ impl SymbolTable {
                             namespace.push(ret.token.text);
                             inner = true;
                         }
-                        SymbolKind::Package => {
+                        SymbolKind::Package(_) => {
                             namespace = Namespace::default();
                             namespace.push(ret.token.text);
                             inner = true;
                         }
                         SymbolKind::Enum(_) => {
+                            // enum may be referred from an inner namespace like function
+                            namespace = ret.namespace.clone();
                             namespace.push(ret.token.text);
                             inner = true;
                         }
@@ -270,6 +296,13 @@ impl SymbolTable {
                             }
                             inner = true;
                         }
+                        // members of generic parameter can't be resolved until instantiation
+                        SymbolKind::GenericParameter(_) => {
+                            return Ok(ResolveResult {
+                                found: Some(ret.clone()),
+                                full_path,
+                            });
+                        }
                         _ => (),
                     }
                 } else if let Some(last_found) = last_found {
@@ -321,6 +354,10 @@ impl SymbolTable {
             symbols.retain(|x| x.token.file_path != file_path);
             for symbol in symbols.iter_mut() {
                 symbol.references.retain(|x| x.file_path != file_path);
+                symbol.assignments.retain(|x| x.file_path != file_path);
+                symbol
+                    .generic_instances
+                    .retain(|x| x.token.file_path != file_path);
             }
         }
     }
@@ -335,6 +372,96 @@ impl SymbolTable {
         }
     }
 
+    pub fn add_assignment(&mut self, target: TokenId, token: &Token) {
+        for (_, symbols) in self.table.iter_mut() {
+            for symbol in symbols.iter_mut() {
+                if symbol.token.id == target {
+                    symbol.assignments.push(token.to_owned());
+                }
+            }
+        }
+    }
+
+    pub fn add_generic_instance(&mut self, target: TokenId, instance: GenericInstance) {
+        for (_, symbols) in self.table.iter_mut() {
+            for symbol in symbols.iter_mut() {
+                if symbol.token.id == target {
+                    symbol.generic_instances.push(instance.clone());
+                }
+            }
+        }
+    }
+
+    fn get_by_token_id(&self, id: TokenId) -> Option<&Symbol> {
+        for symbols in self.table.values() {
+            for symbol in symbols {
+                if symbol.token.id == id {
+                    return Some(symbol);
+                }
+            }
+        }
+        None
+    }
+
+    /// Collect concrete generic instances of the symbol.
+    /// Instances which depend on generic parameters of the enclosing declaration
+    /// are expanded by the instances of the enclosing declaration.
+    pub fn get_generic_instances(&self, target: TokenId) -> Vec<GenericInstance> {
+        let mut stack = Vec::new();
+        self.collect_generic_instances(target, &mut stack)
+    }
+
+    fn collect_generic_instances(
+        &self,
+        target: TokenId,
+        stack: &mut Vec<TokenId>,
+    ) -> Vec<GenericInstance> {
+        let mut ret: Vec<GenericInstance> = Vec::new();
+        let symbol = if let Some(x) = self.get_by_token_id(target) {
+            x
+        } else {
+            return ret;
+        };
+        if stack.contains(&target) {
+            return ret;
+        }
+        stack.push(target);
+
+        for instance in &symbol.generic_instances {
+            let mut expanded = Vec::new();
+            if let Some(owner) = instance.owner {
+                let owner_params = if let Some(x) = self.get_by_token_id(owner) {
+                    x.generic_parameters().to_vec()
+                } else {
+                    vec![]
+                };
+                for owner_instance in self.collect_generic_instances(owner, stack) {
+                    let mut instance = instance.clone();
+                    for arg in instance.arguments.iter_mut() {
+                        if let Some(i) = owner_params.iter().position(|x| x == arg) {
+                            if let Some(x) = owner_instance.arguments.get(i) {
+                                *arg = *x;
+                            }
+                        }
+                    }
+                    instance.owner = None;
+                    expanded.push(instance);
+                }
+            } else {
+                expanded.push(instance.clone());
+            }
+
+            for instance in expanded {
+                if !ret.iter().any(|x| x.arguments == instance.arguments) {
+                    ret.push(instance);
+                }
+            }
+        }
+
+        stack.pop();
+        ret
+    }
+
     pub fn add_project_local(&mut self, prj: StrId, from: StrId, to: StrId) {
         self.project_local_table
             .entry(prj)
@@ -366,10 +493,10 @@ impl fmt::Display for SymbolTable {
         }
         for (k, v) in &vec {
             for symbol in *v {
-                let evaluated = if let Some(evaluated) = symbol.evaluated.get() {
+                let evaluated = if let Some(evaluated) = symbol.evaluated.borrow().clone() {
                     match evaluated {
                         Evaluated::Unknown => "".to_string(),
-                        _ => format!(" ( {evaluated:?} )"),
+                        _ => format!(" ( {evaluated} )"),
                     }
                 } else {
                     "".to_string()
@@ -424,6 +551,18 @@ pub fn add_reference(target: TokenId, token: &Token) {
     SYMBOL_TABLE.with(|f| f.borrow_mut().add_reference(target, token))
 }
 
+pub fn add_assignment(target: TokenId, token: &Token) {
+    SYMBOL_TABLE.with(|f| f.borrow_mut().add_assignment(target, token))
+}
+
+pub fn add_generic_instance(target: TokenId, instance: GenericInstance) {
+    SYMBOL_TABLE.with(|f| f.borrow_mut().add_generic_instance(target, instance))
+}
+
+pub fn get_generic_instances(target: TokenId) -> Vec<GenericInstance> {
+    SYMBOL_TABLE.with(|f| f.borrow().get_generic_instances(target))
+}
+
 pub fn add_project_local(prj: StrId, from: StrId, to: StrId) {
     SYMBOL_TABLE.with(|f| f.borrow_mut().add_project_local(prj, from, to))
 }
@@ -435,6 +574,7 @@ pub fn get_project_local(prj: StrId) -> Option<HashMap<StrId, StrId>> {
 #[cfg(test)]
 mod tests {
     use crate::namespace::Namespace;
+    use crate::symbol::SymbolKind;
     use crate::symbol_table::SymbolPath;
     use crate::{symbol_table, Analyzer};
     use veryl_metadata::Metadata;
@@ -445,11 +585,13 @@ mod tests {
         parameter paramA: u32 = 1,
     ) (
         portA: input logic<10>,
+        portB: modport InterfaceA::modportA,
     ) {
         localparam paramB: u32 = 1;
 
         var memberA: logic;
         var memberB: PackageA::StructA;
+        var memberC: PackageA::UnionA;
     }
 
     interface InterfaceA #(
@@ -466,14 +608,49 @@ mod tests {
 
     package PackageA {
         localparam paramB: u32 = 1;
+        localparam paramC: logic<256> = '1;
+        localparam paramD: logic<128> = 1 << 100;
+        localparam paramE: i32 = -8 >>> 1;
+        localparam paramF: logic<4> = 4'b1x0z;
+        localparam paramG: logic = 8'hff + 8'h1 == 9'h100;
+        localparam paramH: u32 = $clog2(1000);
+        localparam paramI: u32 = funcA(1000);
+        localparam paramJ: logic<4> = $bits(paramF) + 1;
+        localparam paramK: logic<12> = {4'h1, 2'b10 repeat 2};
+        localparam paramL: u32 = if paramB == 1 { 3 } else { 4 };
+        localparam paramM: logic<160> = 'hffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff;
+
+        function funcA (
+            x: input u32,
+        ) -> u32 {
+            var r: u32;
+            r = 0;
+            for i: u32 in 0..32 {
+                if x >: (1 << i) {
+                    r = i + 1;
+                }
+            }
+            return r;
+        }
 
         struct StructA {
             memberA: logic,
         }
 
+        union UnionA {
+            memberD: logic<2>,
+            memberE: bit<2>,
+        }
+
         enum EnumA: logic<2> {
             memberA,
         }
+
+        enum EnumB: logic<3> {
+            memberF,
+            memberG = 5,
+            memberH,
+        }
     }
     "##;
 
@@ -788,4 +965,106 @@ mod tests {
 
         assert!(symbol.is_err());
     }
+
+    #[test]
+    fn modport_member() {
+        parse();
+
+        let mut symbol_path = SymbolPath::default();
+        symbol_path.push(resource_table::get_str_id("portB".to_string()).unwrap());
+        symbol_path.push(resource_table::get_str_id("memberA".to_string()).unwrap());
+
+        let mut namespace = Namespace::default();
+        namespace.push(resource_table::get_str_id("ModuleA".to_string()).unwrap());
+        let symbol = symbol_table::get(&symbol_path, &namespace).unwrap().found;
+
+        assert!(symbol.is_some());
+        assert_eq!(format!("{}", symbol.unwrap().namespace), "prj::InterfaceA");
+    }
+
+    #[test]
+    fn union_member() {
+        parse();
+
+        let mut symbol_path = SymbolPath::default();
+        symbol_path.push(resource_table::get_str_id("memberD".to_string()).unwrap());
+
+        let mut namespace = Namespace::default();
+        namespace.push(resource_table::get_str_id("PackageA".to_string()).unwrap());
+        namespace.push(resource_table::get_str_id("UnionA".to_string()).unwrap());
+        let symbol = symbol_table::get(&symbol_path, &namespace).unwrap().found;
+
+        assert!(symbol.is_some());
+        assert!(matches!(symbol.unwrap().kind, SymbolKind::UnionMember(_)));
+
+        let mut symbol_path = SymbolPath::default();
+        symbol_path.push(resource_table::get_str_id("memberC".to_string()).unwrap());
+        symbol_path.push(resource_table::get_str_id("memberE".to_string()).unwrap());
+
+        let mut namespace = Namespace::default();
+        namespace.push(resource_table::get_str_id("ModuleA".to_string()).unwrap());
+        let symbol = symbol_table::get(&symbol_path, &namespace).unwrap().found;
+
+        assert!(symbol.is_some());
+        assert_eq!(
+            format!("{}", symbol.unwrap().namespace),
+            "prj::PackageA::UnionA"
+        );
+    }
+
+    #[test]
+    fn evaluate() {
+        parse();
+
+        let mut namespace = Namespace::default();
+        namespace.push(resource_table::get_str_id("PackageA".to_string()).unwrap());
+
+        let evaluate = |name: &str| {
+            let mut symbol_path = SymbolPath::default();
+            symbol_path.push(resource_table::get_str_id(name.to_string()).unwrap());
+            let symbol = symbol_table::get(&symbol_path, &namespace).unwrap().found;
+            format!("{}", symbol.unwrap().evaluate())
+        };
+
+        assert_eq!(
+            evaluate("paramC"),
+            "Fixed { width: 256, value: 115792089237316195423570985008687907853269984665640564039457584007913129639935 }"
+        );
+        assert_eq!(
+            evaluate("paramD"),
+            "Fixed { width: 128, value: 1267650600228229401496703205376 }"
+        );
+        assert_eq!(evaluate("paramE"), "Fixed { width: 32, value: -4 }");
+        assert_eq!(evaluate("paramF"), "Fixed { width: 4, value: 4'b1x0z }");
+        assert_eq!(evaluate("paramG"), "Fixed { width: 1, value: 1 }");
+        assert_eq!(evaluate("paramH"), "Fixed { width: 32, value: 10 }");
+        assert_eq!(evaluate("paramI"), "Fixed { width: 32, value: 10 }");
+        assert_eq!(evaluate("paramJ"), "Fixed { width: 4, value: 5 }");
+        assert_eq!(evaluate("paramK"), "Fixed { width: 12, value: 26 }");
+        assert_eq!(evaluate("paramL"), "Fixed { width: 32, value: 3 }");
+        assert_eq!(
+            evaluate("paramM"),
+            "Fixed { width: 160, value: 1461501637330902918203684832716283019655932542975 }"
+        );
+    }
+
+    #[test]
+    fn enum_member() {
+        parse();
+
+        let mut namespace = Namespace::default();
+        namespace.push(resource_table::get_str_id("PackageA".to_string()).unwrap());
+        namespace.push(resource_table::get_str_id("EnumB".to_string()).unwrap());
+
+        let evaluate = |name: &str| {
+            let mut symbol_path = SymbolPath::default();
+            symbol_path.push(resource_table::get_str_id(name.to_string()).unwrap());
+            let symbol = symbol_table::get(&symbol_path, &namespace).unwrap().found;
+            format!("{}", symbol.unwrap().evaluate())
+        };
+
+        assert_eq!(evaluate("memberF"), "Fixed { width: 3, value: 0 }");
+        assert_eq!(evaluate("memberG"), "Fixed { width: 3, value: 5 }");
+        assert_eq!(evaluate("memberH"), "Fixed { width: 3, value: 6 }");
+    }
 }
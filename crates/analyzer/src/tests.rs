@@ -0,0 +1,1459 @@
+use crate::{dependency_table, namespace_table, symbol_table, Analyzer};
+use miette::Diagnostic;
+use std::path::PathBuf;
+use veryl_metadata::Metadata;
+use veryl_parser::{resource_table, Parser};
+
+fn analyze(code: &str) -> Vec<String> {
+    analyze_with(code, "")
+}
+
+/// Analyzes with additional configuration appended to the default Veryl.toml
+fn analyze_with(code: &str, config: &str) -> Vec<String> {
+    let toml = format!("{}\n{config}", Metadata::create_default_toml("prj"));
+    let metadata: Metadata = toml::from_str(&toml).unwrap();
+    let parser = Parser::parse(code, &"test.vl").unwrap();
+    if let Some(path) = resource_table::get_path_id(PathBuf::from("test.vl")) {
+        symbol_table::drop(path);
+        namespace_table::drop(path);
+        dependency_table::drop(path);
+    }
+    let analyzer = Analyzer::new(&"prj", &metadata);
+
+    let mut errors = analyzer.analyze_pass1(code, "test.vl", &parser.veryl);
+    errors.append(&mut analyzer.analyze_pass2(code, "test.vl", &parser.veryl));
+    errors.append(&mut analyzer.analyze_pass3(code, "test.vl", &parser.veryl));
+    dbg!(&errors);
+    errors
+        .iter()
+        .map(|x| x.code().unwrap().to_string())
+        .collect()
+}
+
+fn success(code: &str) {
+    let errors = analyze(code);
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+fn failure(code: &str, expect: &str) {
+    let errors = analyze(code);
+    assert!(errors.iter().any(|x| x == expect), "{errors:?}");
+}
+
+#[test]
+fn diverged_evaluation() {
+    let code = r#"
+package PkgA {
+    function add (
+        a: input u32,
+        b: input u32,
+    ) -> u32 {
+        return a + b;
+    }
+    localparam A: u32 = add(1, 2);
+    localparam B: u32 = $clog2(A + 1);
+}
+"#;
+    success(code);
+
+    let code = r#"
+package PkgA {
+    function f (
+        n: input u32,
+    ) -> u32 {
+        return f(n + 1);
+    }
+    localparam A: u32 = f(0);
+}
+"#;
+    failure(code, "diverged_evaluation");
+}
+
+#[test]
+fn recursive_function() {
+    let code = r#"
+package PkgA {
+    function fact (
+        n: input u32,
+    ) -> u64 {
+        if n <= 1 {
+            return 1;
+        }
+        return n * fact(n - 1);
+    }
+    localparam A: u64 = fact(20);
+    localparam B: u64 = fact(60);
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn evaluation_step_limit() {
+    let code = r#"
+package PkgA {
+    function sum (
+        n: input u32,
+    ) -> u32 {
+        var a: u32;
+        a = 0;
+        for i: u32 in 0..n {
+            a = a + i + i + i + i + i + i + i + i + i + i + i + i + i + i + i + i + i + i + i + i;
+        }
+        return a;
+    }
+    localparam A: u32 = sum(10);
+    localparam B: u32 = sum(60000);
+}
+"#;
+    failure(code, "diverged_evaluation");
+}
+
+#[test]
+fn implicit_truncation() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<8>,
+    o_b: output logic<4>,
+) {
+    assign o_b = i_a;
+}
+"#;
+    failure(code, "implicit_truncation");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<8>,
+    o_b: output logic<8>,
+) {
+    assign o_b = i_a;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn implicit_extension() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<4>,
+    o_b: output logic<8>,
+) {
+    assign o_b = i_a;
+}
+"#;
+    failure(code, "implicit_extension");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<4>,
+    i_b: input  logic<4>,
+    o_c: output logic<5>,
+    o_d: output logic<5>,
+) {
+    assign o_c = i_a + i_b;
+    assign o_d = (i_a << 1) | i_b;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn large_repeat() {
+    let code = r#"
+module ModuleA (
+    o_a: output logic<8>,
+    o_b: output logic<8>,
+) {
+    assign o_a = {1'b1 repeat 8};
+    assign o_b = {1'b1 repeat 200000000};
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn too_large_number() {
+    let code = r#"
+package PackageA {
+    localparam A: u32 = 4'h1f;
+}
+"#;
+    failure(code, "too_large_number");
+
+    let code = r#"
+package PackageA {
+    localparam A: bit<132> = 132'hf_ffffffff_ffffffff_ffffffff_ffffffff;
+    localparam B: bit<132> = 'hf_ffffffff_ffffffff_ffffffff_ffffffff;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn too_large_enum_variant() {
+    let code = r#"
+package PackageA {
+    enum EnumA: logic<2> {
+        A = 4,
+    }
+}
+"#;
+    failure(code, "too_large_enum_variant");
+
+    let code = r#"
+package PackageA {
+    enum EnumA: logic<132> {
+        A = 132'h8_00000000_00000000_00000000_00000000,
+    }
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn mismatch_generics_arity() {
+    let code = r#"
+module ModuleA::<W> {
+    var _a: logic<W>;
+}
+
+module ModuleB {
+    inst u: ModuleA::<2, 3>;
+}
+"#;
+    failure(code, "mismatch_generics_arity");
+
+    let code = r#"
+module ModuleA::<W> {
+    var _a: logic<W>;
+}
+
+module ModuleB {
+    inst u: ModuleA::<2>;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn conflicting_generic_instance() {
+    let code = r#"
+package PkgA {
+    localparam B: u32 = 1;
+}
+
+module ModuleA::<W> {
+    var _a: logic<W>;
+}
+
+module ModuleB {
+    localparam PkgA_B: u32 = 1;
+    inst u0: ModuleA::<PkgA::B>;
+    inst u1: ModuleA::<PkgA_B>;
+}
+"#;
+    failure(code, "conflicting_generic_instance");
+
+    let code = r#"
+package PkgA {
+    localparam B: u32 = 1;
+}
+
+module ModuleA::<W> {
+    var _a: logic<W>;
+}
+
+module ModuleB {
+    inst u0: ModuleA::<PkgA::B>;
+    inst u1: ModuleA::<PkgA::B>;
+    inst u2: ModuleA::<2>;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn mismatch_union_width() {
+    let code = r#"
+module ModuleA {
+    union A {
+        a: logic<8>,
+        b: logic<16>,
+    }
+    var _a: A;
+}
+"#;
+    failure(code, "mismatch_union_width");
+
+    let code = r#"
+module ModuleA {
+    union A {
+        a: logic<8, 2>,
+        b: logic<16>  ,
+    }
+    var _a: A;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn clock_domain_crossing() {
+    let code = r#"
+module ModuleA (
+    i_clk_a: input  'a clock   ,
+    i_clk_b: input  'b clock   ,
+    i_dat_a: input  'a logic<8>,
+    o_dat_b: output 'b logic<8>,
+) {
+    always_ff (i_clk_b) {
+        o_dat_b = i_dat_a;
+    }
+}
+"#;
+    failure(code, "clock_domain_crossing");
+
+    // domain inferred from clock and propagated through combinational logic
+    let code = r#"
+module ModuleA (
+    i_clk_a: input  clock   ,
+    i_clk_b: input  clock   ,
+    i_dat  : input  logic<8>,
+    o_dat  : output logic<8>,
+) {
+    var r_dat_a: logic<8>;
+    var w_dat_a: logic<8>;
+
+    always_ff (i_clk_a) {
+        r_dat_a = i_dat;
+    }
+
+    assign w_dat_a = r_dat_a + 1;
+
+    always_ff (i_clk_b) {
+        o_dat = w_dat_a;
+    }
+}
+"#;
+    failure(code, "clock_domain_crossing");
+
+    // combinational path to an output of another domain
+    let code = r#"
+module ModuleA (
+    i_clk_a: input  'a clock   ,
+    i_dat  : input  'a logic<8>,
+    o_dat  : output 'b logic<8>,
+) {
+    var r_dat: logic<8>;
+    var w_dat: logic<8>;
+
+    always_ff (i_clk_a) {
+        r_dat = i_dat;
+    }
+
+    always_comb {
+        w_dat = r_dat;
+    }
+
+    assign o_dat = w_dat;
+}
+"#;
+    failure(code, "clock_domain_crossing");
+
+    let code = r#"
+module ModuleA (
+    i_clk_a: input  clock   ,
+    i_clk_b: input  clock   ,
+    i_dat  : input  logic<8>,
+    o_dat  : output logic<8>,
+) {
+    var r_dat_a: logic<8>;
+    var w_dat_a: logic<8>;
+    var r_sync : logic<8>;
+
+    always_ff (i_clk_a) {
+        r_dat_a = i_dat;
+    }
+
+    assign w_dat_a = r_dat_a + 1;
+
+    #[synchronizer]
+    always_ff (i_clk_b) {
+        r_sync = w_dat_a;
+    }
+
+    always_ff (i_clk_b) {
+        o_dat = r_sync;
+    }
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn multiple_drivers() {
+    let code = r#"
+module ModuleA (
+    i_clk: input  clock,
+    i_a  : input  logic,
+    o_b  : output logic,
+) {
+    always_ff (i_clk) {
+        o_b = i_a;
+    }
+    assign o_b = i_a;
+}
+"#;
+    failure(code, "multiple_drivers");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    inst u: ModuleB (
+        i_a    ,
+        o_b    ,
+    );
+    assign o_b = i_a;
+}
+
+module ModuleB (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    assign o_b = i_a;
+}
+"#;
+    failure(code, "multiple_drivers");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    var a: logic;
+    always_comb {
+        a = 0;
+        a = i_a;
+    }
+    assign o_b = a;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn inferred_latch() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    i_b: input  logic,
+    o_c: output logic,
+) {
+    always_comb {
+        if i_a {
+            o_c = i_b;
+        }
+    }
+}
+"#;
+    failure(code, "inferred_latch");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    i_b: input  logic,
+    o_c: output logic,
+) {
+    always_comb {
+        if i_a {
+            o_c = i_b;
+        } else {
+            o_c = 0;
+        }
+    }
+}
+"#;
+    success(code);
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    i_b: input  logic,
+    o_c: output logic,
+) {
+    always_comb {
+        o_c = 0;
+        if i_a {
+            o_c = i_b;
+        }
+    }
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn combinational_loop() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    var a: logic;
+    var b: logic;
+    assign a = b & i_a;
+    always_comb {
+        b = a;
+    }
+    assign o_b = b;
+}
+"#;
+    failure(code, "combinational_loop");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    var a: logic;
+    inst u: ModuleB (
+        i_a: a  ,
+        o_b: a  ,
+    );
+    assign o_b = a;
+}
+
+module ModuleB (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    assign o_b = ~i_a;
+}
+"#;
+    failure(code, "combinational_loop");
+
+    let code = r#"
+module ModuleA (
+    i_clk: input  clock,
+    i_a  : input  logic,
+    o_b  : output logic,
+) {
+    var a: logic;
+    var b: logic;
+    assign a = b & i_a;
+    always_ff (i_clk) {
+        b = a;
+    }
+    assign o_b = b;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn undriven_variable() {
+    let code = r#"
+module ModuleA (
+    o_a: output logic,
+) {
+    var a: logic;
+    assign o_a = a;
+}
+"#;
+    failure(code, "undriven_variable");
+
+    let code = r#"
+module ModuleA (
+    o_a: output logic,
+) {
+    var a: logic;
+    assign a   = 1;
+    assign o_a = a;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn undriven_output() {
+    let code = r#"
+module ModuleA (
+    o_a: output logic,
+) {}
+"#;
+    failure(code, "undriven_output");
+
+    let code = r#"
+module ModuleA (
+    o_a: output logic,
+) {
+    inst u: ModuleB (
+        o_a  ,
+    );
+}
+
+module ModuleB (
+    o_a: output logic,
+) {
+    assign o_a = 1;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn unused_input() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    assign o_b = 1;
+}
+"#;
+    failure(code, "unused_input");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    assign o_b = i_a;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn incomplete_case() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<2>,
+    o_b: output logic   ,
+) {
+    enum EnumA: logic<2> {
+        A,
+        B,
+        C,
+    }
+
+    var a: EnumA;
+    assign a = i_a as EnumA;
+
+    always_comb {
+        case a {
+            EnumA::A: o_b = 0;
+            EnumA::B: o_b = 1;
+        }
+    }
+}
+"#;
+    failure(code, "incomplete_case");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<2>,
+    o_b: output logic   ,
+) {
+    enum EnumA: logic<2> {
+        A,
+        B,
+        C,
+    }
+
+    var a: EnumA;
+    assign a = i_a as EnumA;
+
+    always_comb {
+        case a {
+            EnumA::A: o_b = 0;
+            EnumA::B: o_b = 1;
+            EnumA::C: o_b = 1;
+        }
+    }
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn duplicated_case_item() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<2>,
+    o_b: output logic   ,
+) {
+    always_comb {
+        case i_a {
+            0      : o_b = 0;
+            1      : o_b = 1;
+            1      : o_b = 0;
+            default: o_b = 1;
+        }
+    }
+}
+"#;
+    failure(code, "duplicated_case_item");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<2>,
+    o_b: output logic   ,
+) {
+    always_comb {
+        case i_a {
+            0      : o_b = 0;
+            1      : o_b = 1;
+            default: o_b = 1;
+        }
+    }
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn assignment_to_input() {
+    let code = r#"
+module ModuleA (
+    i_a: input logic,
+) {
+    assign i_a = 1;
+}
+"#;
+    failure(code, "assignment_to_input");
+
+    let code = r#"
+interface InterfaceA {
+    var a: logic;
+    var b: logic;
+    modport mp {
+        a: input ,
+        b: output,
+    }
+}
+
+module ModuleA (
+    p: modport InterfaceA::mp,
+) {
+    assign p.a = 1;
+}
+"#;
+    failure(code, "assignment_to_input");
+
+    let code = r#"
+interface InterfaceA {
+    var a: logic;
+    var b: logic;
+    modport mp {
+        a: input ,
+        b: output,
+    }
+}
+
+module ModuleA (
+    p: modport InterfaceA::mp,
+) {
+    assign p.b = p.a;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn reference_to_output() {
+    let code = r#"
+interface InterfaceA {
+    var a: logic;
+    var b: logic;
+    modport mp {
+        a: output,
+        b: output,
+    }
+}
+
+module ModuleA (
+    p: modport InterfaceA::mp,
+) {
+    assign p.b = p.a;
+}
+"#;
+    failure(code, "reference_to_output");
+}
+
+#[test]
+fn unknown_parameter() {
+    let code = r#"
+module ModuleA {
+    inst u: ModuleB #(
+        X: 1,
+    );
+}
+
+module ModuleB #(
+    parameter W: u32 = 1,
+) {}
+"#;
+    failure(code, "unknown_parameter");
+
+    let code = r#"
+module ModuleA {
+    inst u: ModuleB #(
+        W: 2,
+    );
+}
+
+module ModuleB #(
+    parameter W: u32 = 1,
+) {}
+"#;
+    success(code);
+
+    let code = r#"
+#[allow(unknown_parameter)]
+module ModuleA {
+    inst u: ModuleB #(
+        X: 1,
+    );
+}
+
+module ModuleB #(
+    parameter W: u32 = 1,
+) {}
+"#;
+    assert!(analyze(code).contains(&"invalid_allow".to_string()));
+}
+
+#[test]
+fn override_localparam() {
+    let code = r#"
+module ModuleA {
+    inst u: ModuleB #(
+        X: 1,
+    );
+}
+
+module ModuleB #(
+    parameter  W: u32 = 1,
+    localparam X: u32 = W,
+) {}
+"#;
+    failure(code, "override_localparam");
+}
+
+#[test]
+fn mismatch_parameter_type() {
+    let code = r#"
+module ModuleA {
+    inst u: ModuleB #(
+        T: 1,
+    );
+}
+
+module ModuleB #(
+    parameter T: type = logic,
+) {}
+"#;
+    failure(code, "mismatch_type");
+
+    let code = r#"
+module ModuleA {
+    inst u: ModuleB #(
+        W: PackageA::StructA,
+    );
+}
+
+module ModuleB #(
+    parameter W: u32 = 1,
+) {}
+
+package PackageA {
+    struct StructA {
+        a: logic,
+    }
+}
+"#;
+    failure(code, "mismatch_type");
+}
+
+#[test]
+fn mismatch_port_width() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<8>,
+    o_b: output logic<8>,
+) {
+    inst u: ModuleB (
+        i_a    ,
+        o_b    ,
+    );
+}
+
+module ModuleB (
+    i_a: input  logic<4>,
+    o_b: output logic<4>,
+) {
+    assign o_b = i_a;
+}
+"#;
+    failure(code, "mismatch_port_width");
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<4>,
+    o_b: output logic<4>,
+) {
+    inst u: ModuleB (
+        i_a    ,
+        o_b    ,
+    );
+}
+
+module ModuleB (
+    i_a: input  logic<8>,
+    o_b: output logic<8>,
+) {
+    assign o_b = i_a;
+}
+"#;
+    failure(code, "mismatch_port_width");
+
+    // extension of input and output doesn't lose bits
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<4>,
+    o_b: output logic<8>,
+) {
+    inst u: ModuleB #(
+        W: 8,
+    ) (
+        i_a    ,
+        o_b    ,
+    );
+}
+
+module ModuleB #(
+    parameter W: u32 = 4,
+) (
+    i_a: input  logic<W>,
+    o_b: output logic<4>,
+) {
+    assign o_b = i_a[3:0];
+}
+"#;
+    success(code);
+
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<8>,
+    o_b: output logic<8>,
+) {
+    #[allow(mismatch_port_width)]
+    inst u: ModuleB (
+        i_a    ,
+        o_b    ,
+    );
+}
+
+module ModuleB (
+    i_a: input  logic<4>,
+    o_b: output logic<4>,
+) {
+    assign o_b = i_a;
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn mismatch_port_type() {
+    let code = r#"
+interface InterfaceA {
+    var a: logic;
+    modport mp {
+        a: input,
+    }
+}
+
+module ModuleA (
+    i_a: input logic,
+) {
+    inst u: ModuleB (
+        p: i_a,
+    );
+}
+
+module ModuleB (
+    p: modport InterfaceA::mp,
+) {}
+"#;
+    failure(code, "mismatch_port_type");
+}
+
+#[test]
+fn missing_return() {
+    let code = r#"
+package PackageA {
+    function FuncA (
+        a: input logic,
+    ) -> logic {
+        if a {
+            return 1;
+        }
+    }
+}
+"#;
+    failure(code, "missing_return");
+
+    let code = r#"
+package PackageA {
+    function FuncA (
+        a: input logic,
+    ) -> logic {
+        if a {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn unreachable_code() {
+    let code = r#"
+package PackageA {
+    function FuncA (
+        a: input logic,
+    ) -> logic {
+        var b: logic;
+        return a;
+        b = a;
+    }
+}
+"#;
+    failure(code, "unreachable_code");
+
+    let code = r#"
+package PackageA {
+    function FuncA (
+        a: input logic,
+    ) -> logic {
+        var b: logic;
+        b = a;
+        return b;
+    }
+}
+"#;
+    success(code);
+}
+
+#[test]
+fn lint_rules() {
+    let code = r#"
+module ModuleA (
+    i_a: input  logic<8>,
+    o_b: output logic<4>,
+) {
+    assign o_b = i_a;
+}
+"#;
+    let truncation = |config: &str| {
+        analyze_with(code, &format!("[lint.rules]\n{config}"))
+            .contains(&"implicit_truncation".to_string())
+    };
+
+    assert!(truncation(""));
+    assert!(truncation("implicit_truncation = \"deny\""));
+    assert!(!truncation("implicit_truncation = \"allow\""));
+}
+
+#[test]
+fn lint_codes() {
+    // all codes of the analyzer can be configured by `[lint.rules]`
+    let mut codes: Vec<&str> = include_str!("analyzer_error.rs")
+        .lines()
+        .filter_map(|x| x.trim().strip_prefix("code(")?.strip_suffix("),"))
+        .collect();
+    codes.sort();
+    assert_eq!(codes, veryl_metadata::Lint::CODES);
+}
+
+#[test]
+fn invalid_identifier() {
+    let naming = |config: &str, code: &str| {
+        analyze_with(code, &format!("[lint.naming]\n{config}"))
+            .iter()
+            .filter(|x| *x == "invalid_identifier")
+            .count()
+    };
+
+    // localparam falls back to the rules for parameter
+    let code = r#"
+module ModuleA #(
+    parameter ParamA: u32 = 1,
+) {
+    localparam ParamB: u32 = 1;
+    localparam param_c: u32 = 1;
+}
+"#;
+    assert_eq!(naming("case_parameter = \"upper_camel\"", code), 1);
+    assert_eq!(naming("prefix_parameter = \"Param\"", code), 1);
+    assert_eq!(
+        naming(
+            "prefix_parameter = \"Param\"\nprefix_localparam = \"param_\"",
+            code
+        ),
+        1
+    );
+
+    let code = r#"
+module ModuleA (
+    i_clk  : input clock    ,
+    i_rst_n: input reset    ,
+    clk    : input clock    ,
+    rst    : input reset    ,
+    i_dat  : input logic<2> ,
+) {
+    enum EnumA: logic<2> {
+        MemberA,
+        member_b,
+    }
+    var a: logic;
+    var b: logic;
+    always_ff (i_clk, async_low i_rst_n) {
+        if_reset {
+            a = 0;
+        } else {
+            a = i_dat == EnumA::MemberA;
+        }
+    }
+    always_ff (clk, async_high rst) {
+        if_reset {
+            b = 0;
+        } else {
+            b = 1;
+        }
+    }
+}
+"#;
+    assert_eq!(naming("prefix_clock = \"i_clk\"", code), 1);
+    assert_eq!(naming("prefix_reset = \"i_rst\"", code), 1);
+    assert_eq!(naming("prefix_enum = \"Enum\"", code), 0);
+    assert_eq!(naming("case_enum = \"snake\"", code), 1);
+    assert_eq!(naming("case_enum_member = \"upper_camel\"", code), 1);
+    assert_eq!(naming("suffix_reset_low = \"_n\"", code), 0);
+    assert_eq!(naming("suffix_reset_high = \"_n\"", code), 1);
+    assert_eq!(naming("suffix_reset_low = \"_x\"", code), 1);
+}
+
+#[test]
+fn invalid_attribute() {
+    let features = "[features]\ndebug = []";
+
+    let code = r#"
+module ModuleA {
+    #[cfg(feature = "debug")]
+    var _a: logic;
+}
+"#;
+    assert!(!analyze_with(code, features).contains(&"invalid_attribute".to_string()));
+
+    let code = r#"
+package PackageA {
+    enum EnumA: logic<2> {
+        A,
+        #[cfg(feature = "debug")]
+        B,
+    }
+}
+"#;
+    assert!(analyze_with(code, features).contains(&"invalid_attribute".to_string()));
+
+    let code = r#"
+module ModuleA {
+    #[cfg(debug, trace)]
+    var _a: logic;
+}
+"#;
+    assert!(analyze_with(code, features).contains(&"mismatch_attribute_args".to_string()));
+}
+
+#[test]
+fn unknown_feature() {
+    let features = "[features]\ndebug = []\ntrace = []";
+
+    let code = r#"
+module ModuleA {
+    #[cfg(any(feature = "debug", not(feature = "trace")))]
+    var a: logic;
+}
+"#;
+    assert!(!analyze_with(code, features).contains(&"unknown_feature".to_string()));
+
+    let code = r#"
+module ModuleA {
+    #[cfg(feature = "typo")]
+    var a: logic;
+}
+"#;
+    assert!(analyze_with(code, features).contains(&"unknown_feature".to_string()));
+
+    let code = r#"
+module ModuleA {
+    #[cfg(all(feature = "debug", not(feature = "typo")))]
+    var a: logic;
+}
+"#;
+    assert!(analyze_with(code, features).contains(&"unknown_feature".to_string()));
+}
+
+#[test]
+fn unknown_embed_way() {
+    let code = r#"
+module ModuleA {
+    embed (inline) sv{{{ logic a; }}}
+}
+"#;
+    success(code);
+
+    let code = r#"
+module ModuleA {
+    embed (outline) sv{{{ logic a; }}}
+}
+"#;
+    failure(code, "unknown_embed_way");
+}
+
+#[test]
+fn unknown_embed_lang() {
+    let code = r#"
+module ModuleA {
+    embed (inline) vhdl{{{ signal a: std_logic; }}}
+}
+"#;
+    failure(code, "unknown_embed_lang");
+}
+
+#[test]
+fn unknown_include_way() {
+    let code = r#"
+module ModuleA {
+    include (outline, "a.sv");
+}
+"#;
+    assert!(analyze(code).contains(&"unknown_include_way".to_string()));
+}
+
+#[test]
+fn include_failure() {
+    let code = r#"
+module ModuleA {
+    include (inline, "not_found.sv");
+}
+"#;
+    failure(code, "include_failure");
+}
+
+#[test]
+fn extern_module() {
+    let code = r#"
+extern module vendor_ram #(
+    parameter WIDTH: u32 = 8,
+) (
+    clk : input  logic       ,
+    addr: input  logic<4>    ,
+    dout: output logic<WIDTH>,
+);
+
+module ModuleA (
+    i_clk : input  clock   ,
+    i_addr: input  logic<4>,
+    o_d   : output logic<8>,
+) {
+    inst u_ram: vendor_ram #(
+        WIDTH: 8,
+    ) (
+        clk : i_clk ,
+        addr: i_addr,
+        dout: o_d   ,
+    );
+}
+"#;
+    // identifiers of extern module follow the naming of SystemVerilog side
+    let naming = "[lint.naming]\ncase_module = \"upper_camel\"\nprefix_port_input = \"i_\"";
+    assert!(analyze_with(code, naming).is_empty());
+
+    let code = r#"
+extern module vendor_ram (
+    clk : input  logic   ,
+    addr: input  logic<4>,
+);
+
+module ModuleA (
+    i_clk: input clock,
+) {
+    inst u_ram: vendor_ram (
+        clk : i_clk,
+        data: i_clk,
+    );
+}
+"#;
+    assert!(analyze(code).contains(&"unknown_port".to_string()));
+}
+
+#[test]
+fn unsupported_by_target() {
+    let verilog = |code| {
+        analyze_with(code, "[build]\ntarget_language = \"verilog\"")
+            .iter()
+            .filter(|x| *x == "unsupported_by_target")
+            .count()
+    };
+
+    let code = r#"
+module ModuleA #(
+    parameter  A: u32 = 1,
+    localparam B: u32 = A * 2,
+) (
+    i_a: input logic<A>,
+) {
+    localparam C: u32 = B;
+}
+"#;
+    assert_eq!(verilog(code), 0);
+    assert!(!analyze(code).contains(&"unsupported_by_target".to_string()));
+
+    let code = r#"
+module ModuleA #(
+    parameter  T: type = logic,
+) {
+    localparam U: type = logic<2>;
+}
+"#;
+    assert_eq!(verilog(code), 2);
+    assert!(!analyze(code).contains(&"unsupported_by_target".to_string()));
+
+    let code = r#"
+module ModuleA #(
+    localparam A: u32 = 1,
+) (
+    i_a: input logic<A>,
+) {}
+"#;
+    assert_eq!(verilog(code), 1);
+
+    let code = r#"
+interface InterfaceA #(
+    parameter  W: u32 = 1,
+) {
+    var a: logic<W>;
+    modport mp {
+        a: input,
+    }
+}
+module ModuleA (
+    x: interface,
+    y: modport InterfaceA::mp,
+) {
+    inst z: InterfaceA;
+}
+"#;
+    assert_eq!(verilog(code), 3);
+
+    let code = r#"
+package PackageA::<W> {
+    localparam X: u32 = W;
+}
+module ModuleA {
+    localparam A: u32 = PackageA::<2>::X;
+}
+"#;
+    assert_eq!(verilog(code), 1);
+
+    let code = r#"
+module ModuleA {
+    localparam A: string = "a";
+    var _b: string = "b";
+}
+"#;
+    assert_eq!(verilog(code), 1);
+
+    let vhdl = |code| {
+        analyze_with(code, "[build]\ntarget_language = \"vhdl\"")
+            .iter()
+            .filter(|x| *x == "unsupported_by_target")
+            .count()
+    };
+
+    let code = r#"
+module ModuleA #(
+    parameter A: u32 = 1,
+) (
+    i_a: input  logic<A>,
+    o_b: output logic<A>,
+) {
+    function FuncA (
+        a: input logic<A>,
+    ) -> logic<A> {
+        return a + 1;
+    }
+    assign o_b = FuncA(i_a);
+}
+"#;
+    assert_eq!(vhdl(code), 0);
+
+    // constructs in the interface are not reported individually
+    let code = r#"
+interface InterfaceA {
+    var a: logic;
+    modport mp {
+        a: input,
+    }
+    final {}
+}
+module ModuleA (
+    x: interface,
+    y: modport InterfaceA::mp,
+    z: input logic<2> [2],
+) {
+    inst w: InterfaceA;
+}
+"#;
+    assert_eq!(vhdl(code), 5);
+
+    let code = r#"
+module ModuleA::<W> {
+    inst u: ModuleB [2];
+}
+module ModuleB {
+    inst u: ModuleA::<2>;
+    function FuncA (
+        a: output logic,
+    ) -> logic {
+        a = 1;
+        return 0;
+    }
+    embed (inline) sv{{{
+    }}}
+}
+"#;
+    assert_eq!(vhdl(code), 4);
+
+    let code = r#"
+module ModuleA (
+    #[ifdef(DEFINE_A)]
+    i_a: input logic,
+) {
+    #[ifndef(DEFINE_A)]
+    var _b: logic;
+}
+"#;
+    assert_eq!(vhdl(code), 2);
+    assert_eq!(verilog(code), 0);
+}
@@ -0,0 +1,546 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::fmt;
+
+/// 4-state value with arbitrary width
+///
+/// Each bit is represented by `payload`, `mask_x` and `mask_z`.
+/// A bit set in `mask_x` or `mask_z` is x or z, and the corresponding bit of `payload` is 0.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Value {
+    pub width: usize,
+    pub signed: bool,
+    pub payload: BigUint,
+    pub mask_x: BigUint,
+    pub mask_z: BigUint,
+}
+
+fn ones(width: usize) -> BigUint {
+    (BigUint::one() << width) - 1u32
+}
+
+/// Returns the minimum bit width which can represent the digits of a based number
+pub fn bitwidth(digits: &str, radix: u32) -> Option<usize> {
+    let digits = digits.replace('_', "");
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Some(1);
+    }
+
+    if radix == 10 {
+        match digits {
+            "x" | "X" | "z" | "Z" => Some(1),
+            _ => BigUint::parse_bytes(digits.as_bytes(), 10).map(|x| x.bits() as usize),
+        }
+    } else {
+        let digit_width = radix.trailing_zeros() as usize;
+        let head = digits.chars().next().unwrap();
+        let head_width = if let Some(x) = head.to_digit(radix) {
+            (u32::BITS - x.leading_zeros()) as usize
+        } else {
+            digit_width
+        };
+        Some((digits.len() - 1) * digit_width + head_width)
+    }
+}
+
+impl Value {
+    pub fn new(payload: BigUint, width: usize, signed: bool) -> Self {
+        Self {
+            width,
+            signed,
+            payload: payload & ones(width),
+            mask_x: BigUint::zero(),
+            mask_z: BigUint::zero(),
+        }
+    }
+
+    pub fn new_x(width: usize, signed: bool) -> Self {
+        Self {
+            width,
+            signed,
+            payload: BigUint::zero(),
+            mask_x: ones(width),
+            mask_z: BigUint::zero(),
+        }
+    }
+
+    pub fn new_z(width: usize, signed: bool) -> Self {
+        Self {
+            width,
+            signed,
+            payload: BigUint::zero(),
+            mask_x: BigUint::zero(),
+            mask_z: ones(width),
+        }
+    }
+
+    pub fn from_bigint(value: &BigInt, width: usize, signed: bool) -> Self {
+        let modulus = BigInt::one() << width;
+        let value = ((value % &modulus) + &modulus) % &modulus;
+        Self::new(value.magnitude().clone(), width, signed)
+    }
+
+    /// Parses digits of a based number.
+    /// If `width` is `None`, the minimum width to represent the digits is used.
+    pub fn from_digits(digits: &str, radix: u32, width: Option<usize>) -> Option<Self> {
+        let digits = digits.replace('_', "").to_ascii_lowercase();
+        let width = if let Some(x) = width {
+            x
+        } else {
+            bitwidth(&digits, radix)?
+        };
+
+        if radix == 10 {
+            return match digits.as_str() {
+                "x" => Some(Self::new_x(width, false)),
+                "z" => Some(Self::new_z(width, false)),
+                _ => {
+                    BigUint::parse_bytes(digits.as_bytes(), 10).map(|x| Self::new(x, width, false))
+                }
+            };
+        }
+
+        let digit_width = radix.trailing_zeros() as usize;
+        let digit_mask = ones(digit_width);
+        let mut payload = BigUint::zero();
+        let mut mask_x = BigUint::zero();
+        let mut mask_z = BigUint::zero();
+        for c in digits.chars() {
+            payload <<= digit_width;
+            mask_x <<= digit_width;
+            mask_z <<= digit_width;
+            match c {
+                'x' => mask_x |= &digit_mask,
+                'z' => mask_z |= &digit_mask,
+                _ => payload |= BigUint::from(c.to_digit(radix)?),
+            }
+        }
+
+        // The leftmost x or z is extended to the upper bits
+        let digits_width = digits.len() * digit_width;
+        if width > digits_width {
+            let upper = ones(width) ^ ones(digits_width);
+            match digits.chars().next() {
+                Some('x') => mask_x |= upper,
+                Some('z') => mask_z |= upper,
+                _ => (),
+            }
+        }
+
+        let mask = ones(width);
+        Some(Self {
+            width,
+            signed: false,
+            payload: payload & &mask,
+            mask_x: mask_x & &mask,
+            mask_z: mask_z & mask,
+        })
+    }
+
+    fn from_bool(x: Option<bool>) -> Self {
+        if let Some(x) = x {
+            Self::new(BigUint::from(x as u32), 1, false)
+        } else {
+            Self::new_x(1, false)
+        }
+    }
+
+    pub fn is_xz(&self) -> bool {
+        !self.mask_x.is_zero() || !self.mask_z.is_zero()
+    }
+
+    fn mask_xz(&self) -> BigUint {
+        &self.mask_x | &self.mask_z
+    }
+
+    fn known(&self) -> BigUint {
+        ones(self.width) ^ self.mask_xz()
+    }
+
+    fn known_zero(&self) -> BigUint {
+        self.known() ^ &self.payload
+    }
+
+    /// Returns the value interpreted by the signedness, or `None` if it contains x or z
+    pub fn to_bigint(&self) -> Option<BigInt> {
+        if self.is_xz() {
+            return None;
+        }
+        let value = BigInt::from(self.payload.clone());
+        if self.signed && self.width > 0 && self.payload.bit(self.width as u64 - 1) {
+            Some(value - (BigInt::one() << self.width))
+        } else {
+            Some(value)
+        }
+    }
+
+    pub fn to_usize(&self) -> Option<usize> {
+        self.to_bigint()?.to_usize()
+    }
+
+    fn resize(&self, width: usize, sign_extend: bool, signed: bool) -> Self {
+        let mask = ones(width);
+        let mut ret = Self {
+            width,
+            signed,
+            payload: &self.payload & &mask,
+            mask_x: &self.mask_x & &mask,
+            mask_z: &self.mask_z & &mask,
+        };
+        if sign_extend && width > self.width && self.width > 0 {
+            let msb = self.width as u64 - 1;
+            let upper = mask ^ ones(self.width);
+            if self.payload.bit(msb) {
+                ret.payload |= &upper;
+            }
+            if self.mask_x.bit(msb) {
+                ret.mask_x |= &upper;
+            }
+            if self.mask_z.bit(msb) {
+                ret.mask_z |= &upper;
+            }
+        }
+        ret
+    }
+
+    /// Converts to the specified width and signedness like assignment
+    pub fn cast(&self, width: usize, signed: bool) -> Self {
+        self.resize(width, self.signed, signed)
+    }
+
+    /// Extracts `width` bits from `lsb`. Bits out of range are x.
+    pub fn select(&self, lsb: usize, width: usize) -> Self {
+        let mask = ones(width);
+        let outside = if lsb >= self.width {
+            mask.clone()
+        } else {
+            mask.clone() ^ (ones(self.width - lsb) & &mask)
+        };
+        Self {
+            width,
+            signed: false,
+            payload: (&self.payload >> lsb) & &mask,
+            mask_x: ((&self.mask_x >> lsb) & &mask) | outside,
+            mask_z: (&self.mask_z >> lsb) & &mask,
+        }
+    }
+
+    /// Overwrites the bits from `lsb` by `value` like part-select assignment
+    pub fn deposit(&self, lsb: usize, value: &Value) -> Self {
+        let all = ones(self.width);
+        let keep = &all ^ ((ones(value.width) << lsb) & &all);
+        let place = |x: &BigUint, y: &BigUint| ((x & &keep) | (y << lsb)) & &all;
+        Self {
+            width: self.width,
+            signed: self.signed,
+            payload: place(&self.payload, &value.payload),
+            mask_x: place(&self.mask_x, &value.mask_x),
+            mask_z: place(&self.mask_z, &value.mask_z),
+        }
+    }
+
+    /// Concatenates `y` to the LSB side
+    pub fn concat(&self, y: &Value) -> Self {
+        let join = |x: &BigUint, z: &BigUint| (x << y.width) | z;
+        Self {
+            width: self.width + y.width,
+            signed: false,
+            payload: join(&self.payload, &y.payload),
+            mask_x: join(&self.mask_x, &y.mask_x),
+            mask_z: join(&self.mask_z, &y.mask_z),
+        }
+    }
+
+    /// Extends both operands to the common width.
+    /// The operands are treated as signed only if both of them are signed.
+    fn operands(&self, y: &Value, width: usize) -> (Self, Self) {
+        let signed = self.signed && y.signed;
+        let width = width.max(self.width).max(y.width);
+        (
+            self.resize(width, signed, signed),
+            y.resize(width, signed, signed),
+        )
+    }
+
+    fn arithmetic<T: Fn(BigInt, BigInt) -> Option<BigInt>>(
+        &self,
+        y: &Value,
+        width: usize,
+        value: T,
+    ) -> Self {
+        let (x, y) = self.operands(y, width);
+        let value = match (x.to_bigint(), y.to_bigint()) {
+            (Some(x), Some(y)) => value(x, y),
+            _ => None,
+        };
+        if let Some(value) = value {
+            Self::from_bigint(&value, x.width, x.signed)
+        } else {
+            Self::new_x(x.width, x.signed)
+        }
+    }
+
+    pub fn add(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |x, y| Some(x + y))
+    }
+
+    pub fn sub(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |x, y| Some(x - y))
+    }
+
+    pub fn mul(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |x, y| Some(x * y))
+    }
+
+    pub fn div(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |x, y| (!y.is_zero()).then(|| x / y))
+    }
+
+    pub fn rem(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |x, y| (!y.is_zero()).then(|| x % y))
+    }
+
+    pub fn pow(&self, y: &Value, width: usize) -> Self {
+        let x = self.cast(width.max(self.width), self.signed);
+        let (base, exp) = match (x.to_bigint(), y.to_bigint()) {
+            (Some(base), Some(exp)) => (base, exp),
+            _ => return Self::new_x(x.width, x.signed),
+        };
+
+        if exp.is_negative() {
+            let value = if base.is_zero() {
+                return Self::new_x(x.width, x.signed);
+            } else if base.is_one() {
+                BigInt::one()
+            } else if base == -BigInt::one() {
+                if exp.magnitude().bit(0) {
+                    -BigInt::one()
+                } else {
+                    BigInt::one()
+                }
+            } else {
+                BigInt::zero()
+            };
+            Self::from_bigint(&value, x.width, x.signed)
+        } else {
+            let modulus = BigUint::one() << x.width;
+            let payload = x.payload.modpow(exp.magnitude(), &modulus);
+            Self::new(payload, x.width, x.signed)
+        }
+    }
+
+    fn shift_amount(&self) -> Option<usize> {
+        if self.is_xz() {
+            None
+        } else {
+            Some(self.payload.to_usize().unwrap_or(usize::MAX))
+        }
+    }
+
+    pub fn shl(&self, y: &Value, width: usize) -> Self {
+        let x = self.cast(width.max(self.width), self.signed);
+        if let Some(amount) = y.shift_amount() {
+            let amount = amount.min(x.width);
+            let mask = ones(x.width);
+            Self {
+                payload: (&x.payload << amount) & &mask,
+                mask_x: (&x.mask_x << amount) & &mask,
+                mask_z: (&x.mask_z << amount) & &mask,
+                ..x
+            }
+        } else {
+            Self::new_x(x.width, x.signed)
+        }
+    }
+
+    pub fn shr(&self, y: &Value, width: usize, arithmetic: bool) -> Self {
+        let x = self.cast(width.max(self.width), self.signed);
+        if let Some(amount) = y.shift_amount() {
+            let amount = amount.min(x.width);
+            let extended = x.resize(x.width + amount, arithmetic && x.signed, x.signed);
+            Self {
+                payload: &extended.payload >> amount,
+                mask_x: &extended.mask_x >> amount,
+                mask_z: &extended.mask_z >> amount,
+                ..x
+            }
+        } else {
+            Self::new_x(x.width, x.signed)
+        }
+    }
+
+    /// Builds the result of bitwise operation from the bits which are known to be 1 or 0.
+    /// The other bits become x.
+    fn bitwise(&self, one: BigUint, zero: BigUint) -> Self {
+        Self {
+            width: self.width,
+            signed: self.signed,
+            mask_x: ones(self.width) ^ (&one | &zero),
+            payload: one,
+            mask_z: BigUint::zero(),
+        }
+    }
+
+    pub fn and(&self, y: &Value, width: usize) -> Self {
+        let (x, y) = self.operands(y, width);
+        x.bitwise(&x.payload & &y.payload, x.known_zero() | y.known_zero())
+    }
+
+    pub fn or(&self, y: &Value, width: usize) -> Self {
+        let (x, y) = self.operands(y, width);
+        x.bitwise(&x.payload | &y.payload, x.known_zero() & y.known_zero())
+    }
+
+    pub fn xor(&self, y: &Value, width: usize) -> Self {
+        let (x, y) = self.operands(y, width);
+        let known = x.known() & y.known();
+        let one = (&x.payload ^ &y.payload) & &known;
+        x.bitwise(one.clone(), known ^ one)
+    }
+
+    pub fn xnor(&self, y: &Value, width: usize) -> Self {
+        let (x, y) = self.operands(y, width);
+        let known = x.known() & y.known();
+        let zero = (&x.payload ^ &y.payload) & &known;
+        x.bitwise(known ^ &zero, zero)
+    }
+
+    pub fn inv(&self, width: usize) -> Self {
+        let x = self.cast(width.max(self.width), self.signed);
+        x.bitwise(x.known_zero(), x.payload.clone())
+    }
+
+    pub fn neg(&self, width: usize) -> Self {
+        Self::new(BigUint::zero(), self.width, self.signed).sub(self, width)
+    }
+
+    fn truth(&self) -> Option<bool> {
+        if !self.payload.is_zero() {
+            Some(true)
+        } else if self.is_xz() {
+            None
+        } else {
+            Some(false)
+        }
+    }
+
+    pub fn logical_not(&self) -> Self {
+        Self::from_bool(self.truth().map(|x| !x))
+    }
+
+    pub fn logical_and(&self, y: &Value) -> Self {
+        Self::from_bool(match (self.truth(), y.truth()) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        })
+    }
+
+    pub fn logical_or(&self, y: &Value) -> Self {
+        Self::from_bool(match (self.truth(), y.truth()) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        })
+    }
+
+    pub fn reduction_and(&self) -> Self {
+        Self::from_bool(if !self.known_zero().is_zero() {
+            Some(false)
+        } else if self.is_xz() {
+            None
+        } else {
+            Some(true)
+        })
+    }
+
+    pub fn reduction_or(&self) -> Self {
+        Self::from_bool(self.truth())
+    }
+
+    pub fn reduction_xor(&self) -> Self {
+        Self::from_bool(if self.is_xz() {
+            None
+        } else {
+            Some(self.payload.count_ones() % 2 == 1)
+        })
+    }
+
+    pub fn eq(&self, y: &Value) -> Self {
+        let (x, y) = self.operands(y, 0);
+        let known = x.known() & y.known();
+        Self::from_bool(if !((&x.payload ^ &y.payload) & known).is_zero() {
+            Some(false)
+        } else if x.is_xz() || y.is_xz() {
+            None
+        } else {
+            Some(true)
+        })
+    }
+
+    pub fn case_eq(&self, y: &Value) -> Self {
+        let (x, y) = self.operands(y, 0);
+        Self::from_bool(Some(x == y))
+    }
+
+    /// x and z in the right operand are treated as wildcard
+    pub fn wildcard_eq(&self, y: &Value) -> Self {
+        let (x, y) = self.operands(y, 0);
+        let care = y.known();
+        let known = x.known() & &care;
+        Self::from_bool(if !((&x.payload ^ &y.payload) & known).is_zero() {
+            Some(false)
+        } else if !(x.mask_xz() & care).is_zero() {
+            None
+        } else {
+            Some(true)
+        })
+    }
+
+    fn relation<T: Fn(&BigInt, &BigInt) -> bool>(&self, y: &Value, value: T) -> Self {
+        let (x, y) = self.operands(y, 0);
+        Self::from_bool(match (x.to_bigint(), y.to_bigint()) {
+            (Some(x), Some(y)) => Some(value(&x, &y)),
+            _ => None,
+        })
+    }
+
+    pub fn lt(&self, y: &Value) -> Self {
+        self.relation(y, |x, y| x < y)
+    }
+
+    pub fn le(&self, y: &Value) -> Self {
+        self.relation(y, |x, y| x <= y)
+    }
+
+    pub fn gt(&self, y: &Value) -> Self {
+        self.relation(y, |x, y| x > y)
+    }
+
+    pub fn ge(&self, y: &Value) -> Self {
+        self.relation(y, |x, y| x >= y)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(x) = self.to_bigint() {
+            write!(f, "{x}")
+        } else {
+            let mut text = String::new();
+            for i in (0..self.width as u64).rev() {
+                if self.mask_x.bit(i) {
+                    text.push('x');
+                } else if self.mask_z.bit(i) {
+                    text.push('z');
+                } else if self.payload.bit(i) {
+                    text.push('1');
+                } else {
+                    text.push('0');
+                }
+            }
+            write!(f, "{}'b{}", self.width, text)
+        }
+    }
+}
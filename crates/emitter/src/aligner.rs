@@ -1,5 +1,8 @@
+use crate::emitter::{generic_reference_name, generic_substitution, unit_reference};
 use std::collections::HashMap;
+use veryl_analyzer::feature_table;
 use veryl_metadata::{Build, BuiltinType, Metadata};
+use veryl_parser::resource_table::StrId;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::{Token, VerylToken};
 use veryl_parser::veryl_walker::VerylWalker;
@@ -124,6 +127,7 @@ pub struct Aligner {
     aligns: [Align; 8],
     in_type_expression: bool,
     build_opt: Build,
+    generic_map: HashMap<StrId, StrId>,
 }
 
 impl Aligner {
@@ -138,6 +142,27 @@ impl Aligner {
     pub fn align(&mut self, input: &Veryl) {
         self.veryl(input);
         self.finish_group();
+        self.merge_additions();
+    }
+
+    /// Re-align lines of a generic declaration with substituted generic arguments
+    pub fn realign<F: Fn(&mut Aligner)>(
+        &mut self,
+        generic_map: &HashMap<StrId, StrId>,
+        lines: (usize, usize),
+        f: F,
+    ) {
+        self.generic_map = generic_map.clone();
+        self.aligns = Default::default();
+        f(self);
+        self.finish_group();
+        self.additions
+            .retain(|x, _| x.line < lines.0 || x.line > lines.1);
+        self.merge_additions();
+        self.generic_map.clear();
+    }
+
+    fn merge_additions(&mut self) {
         for align in &self.aligns {
             for (x, y) in &align.additions {
                 self.additions
@@ -185,6 +210,46 @@ impl Aligner {
             .implicit_parameter_types
             .contains(&BuiltinType::Type)
     }
+
+    fn generic_segment(&mut self, segments: &[GenericSegment], index: usize) {
+        let (identifier, arg) = &segments[index];
+        if arg.is_some() {
+            let name = generic_reference_name(segments, index, &self.generic_map, &self.build_opt);
+            self.veryl_token(&identifier.identifier_token.replace(&name));
+        } else if let Some(x) =
+            generic_substitution(segments, index, &self.generic_map, &self.build_opt)
+        {
+            self.veryl_token(&identifier.identifier_token.replace(&x));
+        } else {
+            self.identifier(identifier);
+        }
+    }
+
+    fn unit_segment(
+        &mut self,
+        segments: &[GenericSegment],
+        index: usize,
+        unit: &Option<(usize, String)>,
+    ) {
+        let identifier = &segments[index].0;
+        match unit {
+            Some((x, _)) if index < *x => {
+                self.veryl_token(&identifier.identifier_token.replace(""))
+            }
+            Some((x, name)) if index == *x => {
+                self.veryl_token(&identifier.identifier_token.replace(name))
+            }
+            _ => self.generic_segment(segments, index),
+        }
+    }
+
+    fn unit_colon_colon(&mut self, arg: &ColonColon, index: usize, unit: &Option<(usize, String)>) {
+        if matches!(unit, Some((x, _)) if index <= *x) {
+            self.veryl_token(&arg.colon_colon_token.replace(""));
+        } else {
+            self.colon_colon(arg);
+        }
+    }
 }
 
 impl VerylWalker for Aligner {
@@ -195,6 +260,20 @@ impl VerylWalker for Aligner {
         }
     }
 
+    fn is_enabled(&mut self, arg: &Attribute) -> bool {
+        feature_table::is_enabled(arg)
+    }
+
+    /// Semantic action for non-terminal 'Clock'
+    fn clock(&mut self, arg: &Clock) {
+        self.veryl_token(&arg.clock_token.replace("logic"));
+    }
+
+    /// Semantic action for non-terminal 'Reset'
+    fn reset(&mut self, arg: &Reset) {
+        self.veryl_token(&arg.reset_token.replace("logic"));
+    }
+
     /// Semantic action for non-terminal 'F32'
     fn f32(&mut self, arg: &F32) {
         self.veryl_token(&arg.f32_token.replace("shortreal"));
@@ -225,6 +304,52 @@ impl VerylWalker for Aligner {
         self.veryl_token(&arg.u64_token.replace("longint unsigned"));
     }
 
+    /// Semantic action for non-terminal 'ScopedIdentifier'
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) {
+        let segments: Vec<GenericSegment> = arg.into();
+        let unit = unit_reference(&segments, &self.generic_map, &self.build_opt);
+        self.unit_segment(&segments, 0, &unit);
+        for (i, x) in arg.scoped_identifier_list.iter().enumerate() {
+            self.unit_colon_colon(&x.colon_colon, i + 1, &unit);
+            self.unit_segment(&segments, i + 1, &unit);
+        }
+    }
+
+    /// Semantic action for non-terminal 'ExpressionIdentifier'
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        if let Some(ref x) = arg.expression_identifier_opt {
+            self.dollar(&x.dollar);
+        }
+        let segments: Vec<GenericSegment> = arg.into();
+        let unit = unit_reference(&segments, &self.generic_map, &self.build_opt);
+        self.unit_segment(&segments, 0, &unit);
+        match &*arg.expression_identifier_group {
+            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+                self.unit_colon_colon(&x.colon_colon, 1, &unit);
+                self.unit_segment(&segments, 1, &unit);
+                for (i, x) in x.expression_identifier_group_list.iter().enumerate() {
+                    self.unit_colon_colon(&x.colon_colon, i + 2, &unit);
+                    self.unit_segment(&segments, i + 2, &unit);
+                }
+                for x in &x.expression_identifier_group_list0 {
+                    self.select(&x.select);
+                }
+            }
+            ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) => {
+                for x in &x.expression_identifier_group_list1 {
+                    self.select(&x.select);
+                }
+                for x in &x.expression_identifier_group_list2 {
+                    self.dot(&x.dot);
+                    self.identifier(&x.identifier);
+                    for x in &x.expression_identifier_group_list2_list {
+                        self.select(&x.select);
+                    }
+                }
+            }
+        }
+    }
+
     /// Semantic action for non-terminal 'Expression'
     fn expression(&mut self, arg: &Expression) {
         self.expression01(&arg.expression01);
@@ -406,6 +531,8 @@ impl VerylWalker for Aligner {
     /// Semantic action for non-terminal 'VariableType'
     fn variable_type(&mut self, arg: &VariableType) {
         match &*arg.variable_type_group {
+            VariableTypeGroup::Clock(x) => self.clock(&x.clock),
+            VariableTypeGroup::Reset(x) => self.reset(&x.reset),
             VariableTypeGroup::Logic(x) => self.logic(&x.logic),
             VariableTypeGroup::Bit(x) => self.bit(&x.bit),
             VariableTypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
@@ -523,7 +650,7 @@ impl VerylWalker for Aligner {
         self.aligns[align_kind::IDENTIFIER].finish_item();
         self.colon(&arg.colon);
         self.array_type(&arg.array_type);
-        if let Some(ref x) = arg.var_declaration_opt {
+        if let Some(ref x) = arg.var_declaration_opt0 {
             self.equ(&x.equ);
             self.expression(&x.expression);
         }
@@ -600,13 +727,8 @@ impl VerylWalker for Aligner {
     fn inst_declaration(&mut self, arg: &InstDeclaration) {
         let single_line = arg.inst_declaration_opt1.is_none();
         self.inst(&arg.inst);
-        if single_line {
-            self.aligns[align_kind::IDENTIFIER].start_item();
-        }
+        // identifier is not aligned because it follows the module name in SystemVerilog
         self.identifier(&arg.identifier);
-        if single_line {
-            self.aligns[align_kind::IDENTIFIER].finish_item();
-        }
         self.colon(&arg.colon);
         self.scoped_identifier(&arg.scoped_identifier);
         // skip align at single line
@@ -719,13 +841,13 @@ impl VerylWalker for Aligner {
         self.aligns[align_kind::IDENTIFIER].finish_item();
         self.colon(&arg.colon);
         match &*arg.port_declaration_item_group {
-            PortDeclarationItemGroup::DirectionArrayType(x) => {
+            PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
                 self.direction(&x.direction);
                 self.array_type(&x.array_type);
             }
-            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(x) => {
+            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(x) => {
                 self.interface(&x.interface);
-                if let Some(ref x) = x.port_declaration_item_opt {
+                if let Some(ref x) = x.port_declaration_item_opt0 {
                     self.array(&x.array);
                 }
             }
@@ -753,10 +875,10 @@ impl VerylWalker for Aligner {
     fn function_declaration(&mut self, arg: &FunctionDeclaration) {
         self.function(&arg.function);
         self.identifier(&arg.identifier);
-        if let Some(ref x) = arg.function_declaration_opt {
+        if let Some(ref x) = arg.function_declaration_opt0 {
             self.with_parameter(&x.with_parameter);
         }
-        if let Some(ref x) = arg.function_declaration_opt0 {
+        if let Some(ref x) = arg.function_declaration_opt1 {
             self.port_declaration(&x.port_declaration);
         }
         self.minus_g_t(&arg.minus_g_t);
@@ -1,9 +1,21 @@
 use crate::aligner::{Aligner, Location};
+use crate::source_map::{Mapping, SourceMap};
+use crate::verilog::{self, AssignmentKey};
+use crate::vhdl::VhdlEmitter;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use veryl_analyzer::feature_table;
 use veryl_analyzer::msb_table;
-use veryl_analyzer::symbol::SymbolKind;
-use veryl_analyzer::symbol_table;
-use veryl_metadata::{Build, BuiltinType, ClockType, Format, Metadata, ResetType};
-use veryl_parser::resource_table;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::namespace_table;
+use veryl_analyzer::symbol::{
+    self, mangled_name, ParameterValue, Symbol, SymbolKind, Type, TypeKind,
+};
+use veryl_analyzer::symbol_table::{self, SymbolPath};
+use veryl_analyzer::value;
+use veryl_metadata::{Build, BuiltinType, ClockType, Format, Metadata, ResetType, TargetLanguage};
+use veryl_parser::resource_table::{self, StrId, TokenId};
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::{Token, VerylToken};
 use veryl_parser::veryl_walker::VerylWalker;
@@ -37,6 +49,18 @@ pub struct Emitter {
     file_scope_import: Vec<String>,
     attribute: Vec<AttributeType>,
     assignment_lefthand_side: Option<ExpressionIdentifier>,
+    generic_map: HashMap<StrId, StrId>,
+    skip_comments: Option<TokenId>,
+    no_comments: bool,
+    assigned: HashSet<AssignmentKey>,
+    symbols: Rc<Vec<Symbol>>,
+    enum_type: String,
+    enum_prev: Option<String>,
+    function_name: Option<String>,
+    in_module_header: bool,
+    inst_module: Option<Symbol>,
+    mappings: Vec<(usize, usize, Token)>,
+    identifiers: HashSet<TokenId>,
 }
 
 impl Default for Emitter {
@@ -64,6 +88,18 @@ impl Default for Emitter {
             file_scope_import: Vec::new(),
             attribute: Vec::new(),
             assignment_lefthand_side: None,
+            generic_map: HashMap::new(),
+            skip_comments: None,
+            no_comments: false,
+            assigned: HashSet::new(),
+            symbols: Rc::new(Vec::new()),
+            enum_type: String::new(),
+            enum_prev: None,
+            function_name: None,
+            in_module_header: false,
+            inst_module: None,
+            mappings: Vec::new(),
+            identifiers: HashSet::new(),
         }
     }
 }
@@ -81,7 +117,24 @@ impl Emitter {
     }
 
     pub fn emit(&mut self, input: &Veryl) {
-        self.aligner.align(input);
+        if self.build_opt.target_language == TargetLanguage::Vhdl {
+            let mut emitter = VhdlEmitter::new(&self.build_opt, &self.format_opt);
+            emitter.emit(input);
+            self.string = emitter.as_str().to_string();
+            return;
+        }
+        if self.is_verilog() {
+            // alignment is not applied because declarations are rewritten
+            let mut procedural = verilog::ProceduralAssignment::default();
+            procedural.veryl(input);
+            self.assigned = procedural.assigned;
+            self.symbols = Rc::new(symbol_table::get_all());
+        } else {
+            self.aligner.align(input);
+        }
+        let mut identifiers = IdentifierTokens::default();
+        identifiers.veryl(input);
+        self.identifiers = identifiers.ids;
         self.veryl(input);
     }
 
@@ -89,17 +142,83 @@ impl Emitter {
         &self.string
     }
 
+    /// Source map of the emitted codes.
+    /// Each entry maps the range of an emitted token to the source token.
+    pub fn source_map(&self, file: &Path) -> SourceMap {
+        let mut sources: Vec<PathBuf> = Vec::new();
+        let mut mappings = Vec::new();
+        let mut line = 1;
+        let mut line_head = 0;
+        let mut last = 0;
+        for (offset, length, token) in &self.mappings {
+            for (i, _) in self.string[last..*offset].match_indices('\n') {
+                line += 1;
+                line_head = last + i + 1;
+            }
+            last = *offset;
+
+            let path = resource_table::get_path_value(token.file_path).unwrap_or_default();
+            let source = match sources.iter().position(|x| *x == path) {
+                Some(x) => x,
+                None => {
+                    sources.push(path);
+                    sources.len() - 1
+                }
+            };
+            mappings.push(Mapping {
+                line,
+                column: self.string[line_head..*offset].chars().count() + 1,
+                length: self.string[*offset..offset + length].chars().count(),
+                source,
+                source_line: token.line,
+                source_column: token.column,
+                identifier: self.identifiers.contains(&token.id),
+            });
+        }
+        SourceMap {
+            version: SourceMap::VERSION,
+            file: file.to_path_buf(),
+            lines: self.string.lines().count(),
+            sources,
+            mappings,
+        }
+    }
+
     fn str(&mut self, x: &str) {
         self.string.push_str(x);
     }
 
+    /// Truncates the emitted codes with the mappings of the removed tokens
+    fn truncate(&mut self, len: usize) {
+        self.string.truncate(len);
+        while self
+            .mappings
+            .last()
+            .is_some_and(|(offset, length, _)| offset + length > len)
+        {
+            self.mappings.pop();
+        }
+    }
+
     fn unindent(&mut self) {
         if self
             .string
             .ends_with(&" ".repeat(self.indent * self.format_opt.indent_width))
         {
-            self.string
-                .truncate(self.string.len() - self.indent * self.format_opt.indent_width);
+            self.truncate(self.string.len() - self.indent * self.format_opt.indent_width);
+        }
+    }
+
+    /// Emits the text as is.
+    /// If the text starts with a newline, it is placed at the beginning of the line.
+    fn verbatim(&mut self, text: &str) {
+        let text = text.trim_end();
+        match text.split_once('\n') {
+            Some((head, tail)) if head.trim().is_empty() => {
+                self.unindent();
+                self.str(tail);
+            }
+            _ => self.str(text.trim_start()),
         }
     }
 
@@ -162,7 +281,8 @@ impl Emitter {
         self.str(&" ".repeat(repeat));
     }
 
-    fn push_token(&mut self, x: &Token) {
+    /// Returns the offset of the pushed text
+    fn push_token(&mut self, x: &Token) -> usize {
         if self.adjust_line && x.line > self.line + 1 {
             self.newline();
         }
@@ -175,12 +295,20 @@ impl Emitter {
             &text
         };
         self.last_newline = text.matches('\n').count();
+        let offset = self.string.len();
         self.str(text);
         self.line = x.line;
+        offset
     }
 
     fn process_token(&mut self, x: &VerylToken, will_push: bool, duplicated: Option<usize>) {
-        self.push_token(&x.token);
+        let offset = self.push_token(&x.token);
+        let has_source = resource_table::get_path_value(x.token.file_path)
+            .is_some_and(|x| !x.as_os_str().is_empty());
+        if self.string.len() > offset && has_source {
+            self.mappings
+                .push((offset, self.string.len() - offset, x.token));
+        }
 
         let mut loc: Location = x.token.into();
         loc.duplicated = duplicated;
@@ -188,7 +316,7 @@ impl Emitter {
             self.space(*width);
         }
 
-        if duplicated.is_some() {
+        if duplicated.is_some() || self.skip_comments == Some(x.token.id) || self.no_comments {
             return;
         }
 
@@ -203,7 +331,8 @@ impl Emitter {
             if x.line == self.line && !self.in_start_token {
                 self.space(1);
             }
-            for _ in 0..x.line - (self.line + self.last_newline) {
+            // tokens may be reordered at Verilog
+            for _ in 0..x.line.saturating_sub(self.line + self.last_newline) {
                 self.unindent();
                 self.str("\n");
                 self.indent();
@@ -257,6 +386,14 @@ impl Emitter {
         }
     }
 
+    /// Skip the group disabled by `cfg` attribute.
+    /// The line of the last token is kept to avoid inserting blank line at the skipped position.
+    fn skip_group<F: FnOnce(&mut LastLine)>(&mut self, f: F) {
+        let mut last_line = LastLine::default();
+        f(&mut last_line);
+        self.line = last_line.line;
+    }
+
     fn is_implicit_scalar_type(&mut self, x: &ScalarType) -> bool {
         let mut stringifier = Stringifier::new();
         stringifier.scalar_type(x);
@@ -282,6 +419,1429 @@ impl Emitter {
             .implicit_parameter_types
             .contains(&BuiltinType::Type)
     }
+
+    fn generic_segment(&mut self, segments: &[GenericSegment], index: usize) {
+        let (identifier, arg) = &segments[index];
+        if arg.is_some() {
+            let name = generic_reference_name(segments, index, &self.generic_map, &self.build_opt);
+            self.token(&identifier.identifier_token.replace(&name));
+        } else if let Some(x) =
+            generic_substitution(segments, index, &self.generic_map, &self.build_opt)
+        {
+            self.token(&identifier.identifier_token.replace(&x));
+        } else {
+            self.identifier(identifier);
+        }
+    }
+
+    /// Emit the segment which may be a part of the reference to module, interface or package
+    fn unit_segment(
+        &mut self,
+        segments: &[GenericSegment],
+        index: usize,
+        unit: &Option<(usize, String)>,
+    ) {
+        let identifier = &segments[index].0;
+        match unit {
+            Some((x, _)) if index < *x => self.token(&identifier.identifier_token.replace("")),
+            Some((x, name)) if index == *x => {
+                self.token(&identifier.identifier_token.replace(name))
+            }
+            _ => self.generic_segment(segments, index),
+        }
+    }
+
+    /// Emit the separator before the segment of the index
+    fn unit_colon_colon(&mut self, arg: &ColonColon, index: usize, unit: &Option<(usize, String)>) {
+        if matches!(unit, Some((x, _)) if index <= *x) {
+            self.token(&arg.colon_colon_token.replace(""));
+        } else {
+            self.colon_colon(arg);
+        }
+    }
+
+    fn declaration_identifier(&mut self, arg: &Identifier, name: Option<&str>) {
+        if let Some(name) = name {
+            self.token(&arg.identifier_token.replace(name));
+        } else {
+            self.identifier(arg);
+        }
+    }
+
+    /// Emit the identifier of module, interface or package declaration
+    fn unit_declaration_identifier(&mut self, arg: &Identifier, name: Option<&str>) {
+        if let Ok(symbol) = symbol_table::resolve(arg) {
+            if let Some(symbol) = symbol.found {
+                let text = arg.identifier_token.text();
+                let name = name.unwrap_or(&text);
+                let name = unit_name(&symbol.namespace.to_string(), name, &self.build_opt);
+                self.token(&arg.identifier_token.replace(&name));
+                return;
+            }
+        }
+        self.declaration_identifier(arg, name);
+    }
+
+    /// Emit the identifier referring module, interface or package
+    fn unit_identifier(&mut self, arg: &Identifier) {
+        if let Ok(symbol) = symbol_table::resolve(arg) {
+            if let Some(symbol) = symbol.found {
+                if matches!(
+                    symbol.kind,
+                    SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package(_)
+                ) {
+                    let name = symbol.token.text.to_string();
+                    let name = unit_name(&symbol.namespace.to_string(), &name, &self.build_opt);
+                    self.token(&arg.identifier_token.replace(&name));
+                    return;
+                }
+            }
+        }
+        self.identifier(arg);
+    }
+
+    /// Emit a copy of the generic declaration for each instance
+    fn generic_declaration<F, G>(
+        &mut self,
+        identifier: &Identifier,
+        first: &Token,
+        last: &Token,
+        align: F,
+        emit: G,
+    ) where
+        F: Fn(&mut Aligner),
+        G: Fn(&mut Emitter, &str),
+    {
+        let symbol = if let Ok(x) = symbol_table::resolve(identifier) {
+            x.found
+        } else {
+            None
+        };
+        if let Some(symbol) = symbol {
+            let parameters = symbol.generic_parameters().to_vec();
+            let base = identifier.identifier_token.text();
+            let generic_map = self.generic_map.clone();
+            let instances = symbol_table::get_generic_instances(symbol.token.id);
+            for (i, instance) in instances.iter().enumerate() {
+                if i != 0 {
+                    self.newline();
+                    self.newline();
+                }
+                for (param, arg) in parameters.iter().zip(instance.arguments.iter()) {
+                    self.generic_map.insert(*param, *arg);
+                }
+                // comments after the declaration are emitted at the last copy only
+                if i != instances.len() - 1 {
+                    self.skip_comments = Some(last.id);
+                }
+                self.aligner
+                    .realign(&self.generic_map, (first.line, last.line), &align);
+                emit(self, &instance.mangled_name(&base));
+                self.generic_map = generic_map.clone();
+                self.skip_comments = None;
+            }
+        }
+    }
+
+    fn is_verilog(&self) -> bool {
+        self.build_opt.target_language == TargetLanguage::Verilog
+    }
+
+    /// Declarations which Verilog doesn't have are not emitted in addition to the disabled groups
+    fn module_group_emitted(&self, arg: &ModuleGroup) -> bool {
+        if !module_group_enabled(arg) {
+            return false;
+        }
+        if !self.is_verilog() {
+            return true;
+        }
+        match &*arg.module_group_group {
+            ModuleGroupGroup::ModuleItem(x) => !matches!(
+                &*x.module_item,
+                ModuleItem::StructDeclaration(_)
+                    | ModuleItem::UnionDeclaration(_)
+                    | ModuleItem::ImportDeclaration(_)
+                    | ModuleItem::FinalDeclaration(_)
+            ),
+            ModuleGroupGroup::LBraceModuleGroupGroupListRBrace(x) => x
+                .module_group_group_list
+                .iter()
+                .any(|x| self.module_group_emitted(&x.module_group)),
+        }
+    }
+
+    /// Emitter to get the text of a part of the syntax tree
+    fn sub_emitter(&self) -> Emitter {
+        Emitter {
+            build_opt: self.build_opt.clone(),
+            format_opt: self.format_opt.clone(),
+            indent: self.indent,
+            generic_map: self.generic_map.clone(),
+            assigned: self.assigned.clone(),
+            in_module_header: self.in_module_header,
+            symbols: self.symbols.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn expression_text(&self, arg: &Expression) -> String {
+        let mut emitter = self.sub_emitter();
+        emitter.no_comments = true;
+        emitter.expression(arg);
+        emitter.string
+    }
+
+    /// Bit width of the type, or `None` if it can't be determined
+    fn type_width(&self, arg: &Type, namespace: &Namespace) -> Option<String> {
+        let base = match &arg.kind {
+            TypeKind::Clock | TypeKind::Reset | TypeKind::Bit | TypeKind::Logic => "1".to_string(),
+            TypeKind::U32 | TypeKind::I32 | TypeKind::F32 => "32".to_string(),
+            TypeKind::U64 | TypeKind::I64 | TypeKind::F64 => "64".to_string(),
+            TypeKind::UserDefined(x) => self.user_defined_width(x, namespace)?,
+            TypeKind::Type | TypeKind::String => return None,
+        };
+        let mut terms = vec![base];
+        for x in &arg.width {
+            terms.push(self.expression_text(x));
+        }
+        Some(verilog::product(&terms))
+    }
+
+    fn user_defined_width(&self, path: &[StrId], namespace: &Namespace) -> Option<String> {
+        // type given as generic argument is resolved at the project namespace
+        let symbol = if let Some(x) = self.generic_map.get(&path[0]) {
+            let text = format!("{x}");
+            let path: Vec<StrId> = text.split("::").map(resource_table::insert_str).collect();
+            let namespace = Namespace {
+                paths: namespace.paths[..1].to_vec(),
+            };
+            symbol_table::get(&SymbolPath::new(&path), &namespace)
+        } else {
+            symbol_table::get(&SymbolPath::new(path), namespace)
+        };
+        let symbol = symbol.ok()?.found?;
+        match &symbol.kind {
+            SymbolKind::Enum(x) => self.type_width(&x.r#type, &symbol.namespace),
+            SymbolKind::Struct | SymbolKind::Union => {
+                let namespace = verilog::inner_namespace(&symbol);
+                let mut widths = Vec::new();
+                for x in verilog::members(&self.symbols, &namespace) {
+                    match &x.kind {
+                        SymbolKind::StructMember(x) => {
+                            widths.push(self.type_width(&x.r#type, &namespace)?)
+                        }
+                        SymbolKind::UnionMember(x) => {
+                            widths.push(self.type_width(&x.r#type, &namespace)?)
+                        }
+                        _ => (),
+                    }
+                }
+                if matches!(symbol.kind, SymbolKind::Struct) {
+                    Some(verilog::sum(&widths))
+                } else {
+                    Some(verilog::max(&widths))
+                }
+            }
+            SymbolKind::Parameter(x) => match &x.value {
+                ParameterValue::TypeExpression(TypeExpression::ScalarType(x)) => {
+                    let r#type: Type = x.scalar_type.as_ref().into();
+                    self.type_width(&r#type, &symbol.namespace)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Verilog type of the declaration.
+    /// `kind` is `reg` or `wire` for variables, and empty for parameters and function ports.
+    fn verilog_type(&self, arg: &Type, namespace: &Namespace, kind: &str) -> String {
+        let mut kind = kind;
+        let mut signed = false;
+        for x in &arg.modifier {
+            match x {
+                symbol::TypeModifier::Tri if !kind.is_empty() => kind = "tri",
+                symbol::TypeModifier::Tri => (),
+                symbol::TypeModifier::Signed => signed = true,
+            }
+        }
+        let width = match &arg.kind {
+            TypeKind::F32 | TypeKind::F64 => return "real".to_string(),
+            // string is allowed only as the value of parameter
+            TypeKind::String => return kind.to_string(),
+            TypeKind::Type => return "type".to_string(),
+            TypeKind::I32 | TypeKind::I64 => {
+                signed = true;
+                self.type_width(arg, namespace)
+            }
+            TypeKind::Clock | TypeKind::Reset | TypeKind::Bit | TypeKind::Logic
+                if arg.width.is_empty() =>
+            {
+                None
+            }
+            TypeKind::UserDefined(x) => match self.type_width(arg, namespace) {
+                Some(x) => Some(x),
+                None => {
+                    // generic parameter or unknown type is emitted as is
+                    let name: Vec<String> = x
+                        .iter()
+                        .map(|x| format!("{}", self.generic_map.get(x).unwrap_or(x)))
+                        .collect();
+                    let name = name.join("_");
+                    return [kind, &name]
+                        .iter()
+                        .filter(|x| !x.is_empty())
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                }
+            },
+            _ => self.type_width(arg, namespace),
+        };
+        let mut ret = Vec::new();
+        if !kind.is_empty() {
+            ret.push(kind.to_string());
+        }
+        if signed {
+            ret.push("signed".to_string());
+        }
+        if let Some(x) = width {
+            ret.push(format!("[{}:0]", verilog::minus_one(&x)));
+        }
+        ret.join(" ")
+    }
+
+    /// Emit 'ScalarType' as Verilog type, and returns whether any text is emitted
+    fn verilog_scalar_type(&mut self, arg: &ScalarType, kind: &str) -> bool {
+        for x in &arg.scalar_type_list {
+            match &*x.type_modifier {
+                TypeModifier::Tri(x) => self.token(&x.tri.tri_token.replace("")),
+                TypeModifier::Signed(x) => self.token(&x.signed.signed_token.replace("")),
+            }
+        }
+        let token = verilog::scalar_type_token(arg);
+        let namespace = namespace_table::get(token.token.id).unwrap_or_default();
+        let r#type: Type = arg.into();
+        let text = self.verilog_type(&r#type, &namespace, kind);
+        self.token(&token.replace(&text));
+        !text.is_empty()
+    }
+
+    /// Emit the type of parameter declaration
+    fn parameter_scalar_type(&mut self, arg: &ScalarType) {
+        if self.is_implicit_scalar_type(arg) {
+            return;
+        }
+        if self.is_verilog() {
+            if self.verilog_scalar_type(arg, "") {
+                self.space(1);
+            }
+        } else {
+            self.scalar_type(arg);
+            self.space(1);
+        }
+    }
+
+    fn is_implicit_type_kind(&self, arg: &TypeKind) -> bool {
+        let r#type = match arg {
+            TypeKind::U32 => BuiltinType::U32,
+            TypeKind::U64 => BuiltinType::U64,
+            TypeKind::I32 => BuiltinType::I32,
+            TypeKind::I64 => BuiltinType::I64,
+            TypeKind::F32 => BuiltinType::F32,
+            TypeKind::F64 => BuiltinType::F64,
+            TypeKind::String => BuiltinType::String,
+            _ => return false,
+        };
+        self.build_opt.implicit_parameter_types.contains(&r#type)
+    }
+
+    /// Variables of the modport which are expanded to individual ports
+    fn modport_variables(
+        &self,
+        arg: &Type,
+        namespace: &Namespace,
+    ) -> Vec<(StrId, symbol::Direction, Type, Namespace)> {
+        let mut ret = Vec::new();
+        let path = match &arg.kind {
+            TypeKind::UserDefined(x) => x,
+            _ => return ret,
+        };
+        let modport = match symbol_table::get(&SymbolPath::new(path), namespace) {
+            Ok(x) => x.found,
+            Err(_) => None,
+        };
+        if let Some(modport) = modport {
+            if let SymbolKind::Modport(ref x) = modport.kind {
+                for member in &x.members {
+                    let path = SymbolPath::new(&[member.name]);
+                    if let Ok(symbol) = symbol_table::get(&path, &modport.namespace) {
+                        if let Some(SymbolKind::Variable(x)) = symbol.found.map(|x| x.kind) {
+                            ret.push((
+                                member.name,
+                                member.direction.clone(),
+                                x.r#type,
+                                modport.namespace.clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        ret
+    }
+
+    /// Emit the struct member access as the part select of the packed vector,
+    /// and the interface member access as the expanded signal.
+    /// Returns false if the access can't be converted.
+    fn verilog_member_access(
+        &mut self,
+        identifier: &Identifier,
+        selects: &[&Select],
+        members: &[(&Identifier, Vec<&Select>)],
+        full_path: &[Symbol],
+    ) -> bool {
+        if members.is_empty() || full_path.len() != members.len() + 1 {
+            return false;
+        }
+
+        if verilog::is_expanded_interface(&full_path[0]) {
+            let (member, member_selects) = &members[0];
+            let name = format!(
+                "{}_{}",
+                identifier.identifier_token.text(),
+                member.identifier_token.text()
+            );
+            self.token(&identifier.identifier_token.replace(&name));
+            self.token(&member.identifier_token.replace(""));
+            for x in member_selects {
+                self.select(x);
+            }
+            return true;
+        }
+
+        // offset from LSB is the total width of the following members
+        let mut offset = Vec::new();
+        let mut width = None;
+        for member in &full_path[1..] {
+            let mut following = false;
+            for x in verilog::members(&self.symbols, &member.namespace) {
+                let r#type = match &x.kind {
+                    SymbolKind::StructMember(x) => &x.r#type,
+                    SymbolKind::UnionMember(x) => &x.r#type,
+                    _ => continue,
+                };
+                let x_width = match self.type_width(r#type, &member.namespace) {
+                    Some(x) => x,
+                    None => return false,
+                };
+                if x.token.id == member.token.id {
+                    width = Some(x_width);
+                    following = matches!(x.kind, SymbolKind::StructMember(_));
+                } else if following {
+                    offset.push(x_width);
+                }
+            }
+            if width.is_none() {
+                return false;
+            }
+        }
+        let width = width.unwrap();
+        let lsb = verilog::sum(&offset);
+
+        let (_, last_selects) = members.last().unwrap();
+        if members[..members.len() - 1]
+            .iter()
+            .any(|(_, x)| !x.is_empty())
+        {
+            return false;
+        }
+        let select = match last_selects.as_slice() {
+            [] => format!(
+                "[{}:{}]",
+                verilog::minus_one(&verilog::sum(&[lsb.clone(), width])),
+                lsb
+            ),
+            [x] => {
+                let index = self.expression_text(&x.expression);
+                if let Some(ref y) = x.select_opt {
+                    let operand = self.expression_text(&y.expression);
+                    match &*y.select_operator {
+                        SelectOperator::Colon(_) => format!(
+                            "[{}:{}]",
+                            verilog::sum(&[lsb.clone(), index]),
+                            verilog::sum(&[lsb, operand])
+                        ),
+                        SelectOperator::PlusColon(_) => {
+                            format!("[{}+:{}]", verilog::sum(&[lsb, index]), operand)
+                        }
+                        SelectOperator::MinusColon(_) => {
+                            format!("[{}-:{}]", verilog::sum(&[lsb, index]), operand)
+                        }
+                        SelectOperator::Step(_) => {
+                            let index = verilog::product(&[index, operand.clone()]);
+                            format!("[{}+:{}]", verilog::sum(&[lsb, index]), operand)
+                        }
+                    }
+                } else {
+                    format!("[{}]", verilog::sum(&[lsb, index]))
+                }
+            }
+            _ => return false,
+        };
+
+        self.identifier(identifier);
+        for x in selects {
+            self.select(x);
+        }
+        for (x, _) in members {
+            self.token(&x.identifier_token.replace(""));
+        }
+        self.str(&select);
+        true
+    }
+
+    /// Emit 'ExpressionIdentifier' as Verilog.
+    /// Returns false if it is emitted as same as SystemVerilog.
+    fn verilog_expression_identifier(&mut self, arg: &ExpressionIdentifier) -> bool {
+        if arg.expression_identifier_opt.is_some() {
+            return false;
+        }
+        let symbol = match symbol_table::resolve(arg) {
+            Ok(x) => x,
+            Err(_) => return false,
+        };
+        if let Some(ref found) = symbol.found {
+            if verilog::is_package_member(found) {
+                // module header can't refer the package members declared in the module body
+                let name = match &found.kind {
+                    SymbolKind::Parameter(x) if self.in_module_header => match x.value {
+                        ParameterValue::Expression(ref x) => {
+                            format!("({})", self.expression_text(x))
+                        }
+                        _ => verilog::package_member_name(found),
+                    },
+                    _ => verilog::package_member_name(found),
+                };
+                self.token(&arg.identifier.identifier_token.replace(&name));
+                match &*arg.expression_identifier_group {
+                    ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+                        for x in &x.expression_identifier_group_list0 {
+                            self.select(&x.select);
+                        }
+                    }
+                    ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) => {
+                        for x in &x.expression_identifier_group_list1 {
+                            self.select(&x.select);
+                        }
+                    }
+                }
+                return true;
+            }
+        }
+        if let ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) = &*arg.expression_identifier_group {
+            let selects: Vec<&Select> = x
+                .expression_identifier_group_list1
+                .iter()
+                .map(|x| x.select.as_ref())
+                .collect();
+            let members: Vec<(&Identifier, Vec<&Select>)> = x
+                .expression_identifier_group_list2
+                .iter()
+                .map(|x| {
+                    let selects = x
+                        .expression_identifier_group_list2_list
+                        .iter()
+                        .map(|x| x.select.as_ref())
+                        .collect();
+                    (x.identifier.as_ref(), selects)
+                })
+                .collect();
+            self.verilog_member_access(&arg.identifier, &selects, &members, &symbol.full_path)
+        } else {
+            false
+        }
+    }
+
+    /// Emit the conditions of 'inside' as the comparisons because Verilog has no 'inside'
+    fn verilog_inside(&mut self, expression: &Expression, arg: &RangeList) {
+        let lhs = self.expression_text(expression);
+        let mut items = vec![arg.range_item.as_ref()];
+        for x in &arg.range_list_list {
+            items.push(x.range_item.as_ref());
+        }
+        for (i, x) in items.iter().enumerate() {
+            if i != 0 {
+                self.str(" || ");
+            }
+            let range = &x.range;
+            if let Some(ref y) = range.range_opt {
+                let operator = match &*y.range_operator {
+                    RangeOperator::DotDot(_) => "<",
+                    RangeOperator::DotDotEqu(_) => "<=",
+                };
+                self.str(&format!("(({lhs}) >= ("));
+                self.expression(&range.expression);
+                self.str(&format!(") && ({lhs}) {operator} ("));
+                self.expression(&y.expression);
+                self.str("))");
+            } else {
+                self.str(&format!("(({lhs}) == ("));
+                self.expression(&range.expression);
+                self.str("))");
+            }
+        }
+    }
+
+    /// Emit the step of for loop because Verilog has neither increment nor assignment operator
+    fn for_step(
+        &mut self,
+        identifier: &Identifier,
+        step: Option<(&AssignmentOperator, &Expression)>,
+    ) {
+        self.identifier(identifier);
+        match step {
+            Some((operator, expression)) => {
+                self.space(1);
+                if self.is_verilog() {
+                    let text = operator.assignment_operator_token.text();
+                    self.str("=");
+                    self.space(1);
+                    self.str(&identifier.identifier_token.text());
+                    self.space(1);
+                    self.token(
+                        &operator
+                            .assignment_operator_token
+                            .replace(&text[0..text.len() - 1]),
+                    );
+                    self.space(1);
+                    self.str("(");
+                    self.expression(expression);
+                    self.str(")");
+                } else {
+                    self.assignment_operator(operator);
+                    self.space(1);
+                    self.expression(expression);
+                }
+            }
+            None => {
+                if self.is_verilog() {
+                    self.str(&format!(" = {} + 1", identifier.identifier_token.text()));
+                } else {
+                    self.str("++");
+                }
+            }
+        }
+    }
+
+    /// Emit 'PortDeclarationItem' as Verilog.
+    /// Modport port is expanded to the ports of the individual signals.
+    fn verilog_port_declaration_item(
+        &mut self,
+        arg: &PortDeclarationItem,
+        direction: &Direction,
+        array_type: &ArrayType,
+    ) {
+        let id = arg.identifier.identifier_token.token.id;
+        if let Direction::Modport(_) = direction {
+            let r#type: Type = array_type.into();
+            let namespace = namespace_table::get(id).unwrap_or_default();
+            let mut ports = Vec::new();
+            for (name, direction, r#type, namespace) in self.modport_variables(&r#type, &namespace)
+            {
+                let kind = match direction {
+                    symbol::Direction::Output if self.assigned.contains(&(id, Some(name))) => "reg",
+                    symbol::Direction::Input
+                    | symbol::Direction::Output
+                    | symbol::Direction::Inout => "wire",
+                    _ => "",
+                };
+                let r#type = self.verilog_type(&r#type, &namespace, kind);
+                let name = format!("{}_{}", arg.identifier.identifier_token.text(), name);
+                let port: Vec<String> = [direction.to_string(), r#type, name]
+                    .into_iter()
+                    .filter(|x| !x.is_empty())
+                    .collect();
+                ports.push(port.join(" "));
+            }
+            for (i, x) in ports.iter().enumerate() {
+                if i != 0 {
+                    self.str(",");
+                    self.newline();
+                }
+                self.str(x);
+            }
+            self.token(&arg.identifier.identifier_token.replace(""));
+            return;
+        }
+
+        self.direction(direction);
+        self.space(1);
+        let kind = match direction {
+            _ if self.in_function => "",
+            Direction::Output(_) if self.assigned.contains(&(id, None)) => "reg",
+            Direction::Input(_) | Direction::Output(_) | Direction::Inout(_) => "wire",
+            _ => "",
+        };
+        if self.verilog_scalar_type(&array_type.scalar_type, kind) {
+            self.space(1);
+        }
+        self.identifier(&arg.identifier);
+        if let Some(ref x) = array_type.array_type_opt {
+            self.space(1);
+            self.array(&x.array);
+        }
+    }
+
+    /// Emit function items as Verilog.
+    /// Variable declarations are placed before the statements,
+    /// and their initializers are moved to the statements.
+    fn verilog_function_items(&mut self, items: &[FunctionItem]) {
+        let mut i = 0;
+        for x in items {
+            if let FunctionItem::VarDeclaration(x) = x {
+                self.newline_list(i);
+                i += 1;
+                self.var_declaration(&x.var_declaration);
+            }
+        }
+        self.newline_list(i);
+        self.str("begin");
+        let mut j = 0;
+        for x in items {
+            match x {
+                FunctionItem::VarDeclaration(x) => {
+                    let x = &x.var_declaration;
+                    if let Some(ref y) = x.var_declaration_opt0 {
+                        self.newline_list(j);
+                        j += 1;
+                        self.str(&x.identifier.identifier_token.text());
+                        self.space(1);
+                        self.equ(&y.equ);
+                        self.space(1);
+                        self.expression(&y.expression);
+                        self.str(";");
+                    }
+                }
+                FunctionItem::Statement(x) => {
+                    self.newline_list(j);
+                    j += 1;
+                    self.statement(&x.statement);
+                }
+            }
+        }
+        self.newline_list_post(j == 0);
+        self.str("end");
+        self.newline_pop();
+    }
+
+    /// Declarations of the packages referred from the module.
+    /// They are inlined into the module because Verilog has no package.
+    fn verilog_package_items(&self, arg: &ModuleDeclaration) -> Vec<String> {
+        let mut reference = verilog::PackageReference::default();
+        reference.module_declaration(arg);
+        let mut packages = Vec::new();
+        for x in &reference.packages {
+            self.verilog_package_order(x, &mut packages);
+        }
+
+        let mut ret = Vec::new();
+        for package in &packages {
+            let namespace = verilog::inner_namespace(package);
+            for symbol in verilog::members(&self.symbols, &namespace) {
+                match &symbol.kind {
+                    SymbolKind::Parameter(x) => {
+                        if let ParameterValue::Expression(ref value) = x.value {
+                            let r#type = if self.is_implicit_type_kind(&x.r#type.kind) {
+                                String::new()
+                            } else {
+                                self.verilog_type(&x.r#type, &namespace, "")
+                            };
+                            let name = verilog::package_member_name(&symbol);
+                            let value = self.expression_text(value);
+                            ret.push(localparam_text(&r#type, &name, &value));
+                        }
+                    }
+                    SymbolKind::Enum(x) => {
+                        let r#type = self.verilog_type(&x.r#type, &namespace, "");
+                        let mut prev = None;
+                        let members =
+                            verilog::members(&self.symbols, &verilog::inner_namespace(&symbol));
+                        for member in members {
+                            if let SymbolKind::EnumMember(ref x) = member.kind {
+                                let name = verilog::package_member_name(&member);
+                                let value = match x.value {
+                                    Some(ref x) => self.expression_text(x),
+                                    None => enum_value_text(prev.as_deref()),
+                                };
+                                ret.push(localparam_text(&r#type, &name, &value));
+                                prev = Some(name);
+                            }
+                        }
+                    }
+                    SymbolKind::Function(x) if x.generic_parameters.is_empty() => {
+                        ret.push(self.verilog_package_function(&symbol, x));
+                    }
+                    _ => (),
+                }
+            }
+        }
+        ret
+    }
+
+    /// Packages ordered to be declared after their dependencies
+    fn verilog_package_order(&self, package: &Symbol, packages: &mut Vec<Symbol>) {
+        if packages.iter().any(|x| x.token.id == package.token.id) {
+            return;
+        }
+        if let SymbolKind::Package(ref x) = package.kind {
+            if !x.generic_parameters.is_empty() {
+                return;
+            }
+        }
+        let mut reference = verilog::PackageReference::default();
+        reference.package(&self.symbols, package);
+        for x in &reference.packages {
+            if x.token.id != package.token.id {
+                self.verilog_package_order(x, packages);
+            }
+        }
+        packages.push(package.clone());
+    }
+
+    fn verilog_package_function(&self, symbol: &Symbol, arg: &symbol::FunctionProperty) -> String {
+        let name = verilog::package_member_name(symbol);
+        let namespace = verilog::inner_namespace(symbol);
+        let mut emitter = self.sub_emitter();
+        emitter.indent = self.indent + 1;
+        emitter.in_function = true;
+        emitter.function_name = Some(name.clone());
+        if let Some(x) = arg.items.first() {
+            let mut first_line = FirstLine::default();
+            first_line.function_item(x);
+            emitter.line = first_line.line;
+        }
+        emitter.str("function automatic");
+        let r#type = self.verilog_type(&arg.return_type, &symbol.namespace, "");
+        if !r#type.is_empty() {
+            emitter.space(1);
+            emitter.str(&r#type);
+        }
+        emitter.space(1);
+        emitter.str(&name);
+        if !arg.ports.is_empty() {
+            emitter.str("(");
+            emitter.newline_push();
+            for (i, x) in arg.ports.iter().enumerate() {
+                if i != 0 {
+                    emitter.str(",");
+                    emitter.newline();
+                }
+                emitter.str(&format!("{}", x.property.direction));
+                if let Some(ref x) = x.property.r#type {
+                    let r#type = self.verilog_type(x, &namespace, "");
+                    if !r#type.is_empty() {
+                        emitter.space(1);
+                        emitter.str(&r#type);
+                    }
+                }
+                emitter.space(1);
+                emitter.str(&format!("{}", x.name));
+            }
+            emitter.newline_pop();
+            emitter.str(")");
+        }
+        emitter.str(";");
+        emitter.verilog_function_items(&arg.items);
+        emitter.str("endfunction");
+        emitter.string
+    }
+
+    /// Emit the interface instance as the individual signals because Verilog has no interface
+    fn verilog_interface_instance(&mut self, arg: &InstDeclaration, interface: &Symbol) {
+        let namespace = verilog::inner_namespace(interface);
+        let instance = arg.identifier.identifier_token.text();
+        let mut declarations = Vec::new();
+        for x in verilog::members(&self.symbols, &namespace) {
+            if let SymbolKind::Variable(ref y) = x.kind {
+                let key = (arg.identifier.identifier_token.token.id, Some(x.token.text));
+                let kind = if self.assigned.contains(&key) {
+                    "reg"
+                } else {
+                    "wire"
+                };
+                let r#type = self.verilog_type(&y.r#type, &namespace, kind);
+                let mut array = String::new();
+                for x in &y.r#type.array {
+                    array.push_str(&format!(" [0:{}-1]", self.expression_text(x)));
+                }
+                declarations.push(format!(
+                    "{} {}_{}{};",
+                    r#type, instance, x.token.text, array
+                ));
+            }
+        }
+        self.token(&arg.inst.inst_token.replace(""));
+        for (i, x) in declarations.iter().enumerate() {
+            if i != 0 {
+                self.newline();
+            }
+            self.str(x);
+        }
+        self.token(&arg.semicolon.semicolon_token.replace(""));
+    }
+
+    /// Emit the connection of modport port as the connections of the individual signals
+    fn verilog_modport_connection(&mut self, arg: &InstPortItem, module: &Symbol) -> bool {
+        let ports = match module.kind {
+            SymbolKind::Module(ref x) => &x.ports,
+            _ => return false,
+        };
+        let port = arg.identifier.identifier_token.token.text;
+        let port = match ports.iter().find(|x| x.name == port) {
+            Some(x) => x,
+            None => return false,
+        };
+        if !matches!(port.property.direction, symbol::Direction::Modport) {
+            return false;
+        }
+        let r#type = match port.property.r#type {
+            Some(ref x) => x,
+            None => return false,
+        };
+        let namespace = namespace_table::get(port.property.token.id).unwrap_or_default();
+        let actual = match arg.inst_port_item_opt {
+            Some(ref x) => {
+                let mut stringifier = Stringifier::new();
+                stringifier.expression(&x.expression);
+                stringifier.as_str().to_string()
+            }
+            None => arg.identifier.identifier_token.text(),
+        };
+        let connections: Vec<String> = self
+            .modport_variables(r#type, &namespace)
+            .iter()
+            .map(|(x, _, _, _)| {
+                format!(
+                    ".{}_{x} ({actual}_{x})",
+                    arg.identifier.identifier_token.text()
+                )
+            })
+            .collect();
+        for (i, x) in connections.iter().enumerate() {
+            if i != 0 {
+                self.str(",");
+                self.newline();
+            }
+            self.str(x);
+        }
+        self.token(&arg.identifier.identifier_token.replace(""));
+        true
+    }
+
+    /// Emit 'FunctionDeclaration', renamed to the generic instance name if given
+    fn function_declaration_body(&mut self, arg: &FunctionDeclaration, name: Option<&str>) {
+        self.in_function = true;
+        if let Some(ref x) = arg.function_declaration_opt0 {
+            self.str("module");
+            self.space(1);
+            self.declaration_identifier(&arg.identifier, name);
+            self.space(1);
+            self.with_parameter(&x.with_parameter);
+            self.str(";");
+            self.newline_push();
+        }
+        self.function(&arg.function);
+        self.space(1);
+        self.str("automatic");
+        self.space(1);
+        if self.is_verilog() {
+            if self.verilog_scalar_type(&arg.scalar_type, "") {
+                self.space(1);
+            }
+        } else {
+            self.scalar_type(&arg.scalar_type);
+            self.space(1);
+        }
+        self.declaration_identifier(&arg.identifier, name);
+        if let Some(ref x) = arg.function_declaration_opt1 {
+            self.port_declaration(&x.port_declaration);
+            self.space(1);
+        }
+        self.token(&arg.minus_g_t.minus_g_t_token.replace(""));
+        self.str(";");
+        self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
+        if self.is_verilog() {
+            let name = match name {
+                Some(x) => x.to_string(),
+                None => arg.identifier.identifier_token.text(),
+            };
+            self.function_name = Some(name);
+            let items: Vec<FunctionItem> = arg
+                .function_declaration_list
+                .iter()
+                .map(|x| *x.function_item.clone())
+                .collect();
+            self.verilog_function_items(&items);
+            self.function_name = None;
+        } else {
+            for (i, x) in arg.function_declaration_list.iter().enumerate() {
+                self.newline_list(i);
+                self.function_item(&x.function_item);
+            }
+            self.newline_list_post(arg.function_declaration_list.is_empty());
+        }
+        if arg.function_declaration_opt0.is_some() {
+            self.str("endfunction");
+            self.newline_pop();
+            self.token(&arg.r_brace.r_brace_token.replace("endmodule"));
+        } else {
+            self.token(&arg.r_brace.r_brace_token.replace("endfunction"));
+        }
+        self.in_function = false;
+    }
+
+    /// Emit 'ModuleDeclaration', renamed to the generic instance name if given
+    fn module_declaration_body(&mut self, arg: &ModuleDeclaration, name: Option<&str>) {
+        self.module(&arg.module);
+        self.space(1);
+        self.unit_declaration_identifier(&arg.identifier, name);
+        let file_scope_import = self.file_scope_import.clone();
+        if !file_scope_import.is_empty() {
+            self.newline_push();
+        }
+        for (i, x) in file_scope_import.iter().enumerate() {
+            if i != 0 {
+                self.newline();
+            }
+            self.str(x);
+        }
+        if !file_scope_import.is_empty() {
+            self.newline_pop();
+        }
+        self.in_module_header = self.is_verilog();
+        if let Some(ref x) = arg.module_declaration_opt0 {
+            if self.is_verilog() {
+                self.verilog_with_parameter(&x.with_parameter);
+            } else {
+                self.space(1);
+                self.with_parameter(&x.with_parameter);
+            }
+        }
+        if let Some(ref x) = arg.module_declaration_opt1 {
+            self.space(1);
+            self.port_declaration(&x.port_declaration);
+        }
+        self.in_module_header = false;
+        self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
+        let mut i = 0;
+        if self.is_verilog() {
+            let declarations = self.verilog_package_items(arg);
+            for x in &declarations {
+                self.newline_list(i);
+                self.str(x);
+                i += 1;
+            }
+            if let Some(ref x) = arg.module_declaration_opt0 {
+                self.verilog_header_localparams(&x.with_parameter, &mut i);
+            }
+            let mut genvar = verilog::Genvar::default();
+            genvar.module_declaration(arg);
+            if !genvar.names.is_empty() {
+                self.newline_list(i);
+                self.str(&format!("genvar {};", genvar.names.join(", ")));
+                i += 1;
+            }
+        }
+        for x in &arg.module_declaration_list {
+            if self.module_group_emitted(&x.module_group) {
+                self.newline_list(i);
+                i += 1;
+            }
+            self.module_group(&x.module_group);
+        }
+        self.newline_list_post(i == 0);
+        self.token(&arg.r_brace.r_brace_token.replace("endmodule"));
+    }
+
+    /// Emit parameters of module in Verilog.
+    /// localparams are moved to the module body by `verilog_header_localparams`
+    /// because they are not allowed in the parameter port list of Verilog.
+    fn verilog_with_parameter(&mut self, arg: &WithParameter) {
+        let items = match arg.with_parameter_opt {
+            Some(ref x) => verilog::parameter_items(&x.with_parameter_list),
+            None => Vec::new(),
+        };
+        let mut remaining = items
+            .iter()
+            .filter(|(_, x, _)| !verilog::is_localparam(x))
+            .count();
+        if remaining == 0 {
+            return;
+        }
+
+        self.space(1);
+        self.hash(&arg.hash);
+        self.token_will_push(&arg.l_paren.l_paren_token);
+        self.newline_push();
+        for (attributes, item, comma) in &items {
+            // the line of skipped localparam is kept to avoid inserting blank line
+            if verilog::is_localparam(item) {
+                self.skip_group(|x| {
+                    x.with_parameter_item(item);
+                    if let Some(comma) = comma {
+                        x.comma(comma);
+                    }
+                });
+                continue;
+            }
+
+            for x in attributes {
+                self.attribute(x);
+            }
+            self.with_parameter_item(item);
+            for _ in attributes {
+                self.attribute_end();
+            }
+            remaining -= 1;
+            let last = remaining == 0;
+            match comma {
+                Some(x) if last => self.token(&x.comma_token.replace("")),
+                Some(x) => self.comma(x),
+                None if last => (),
+                None => self.str(","),
+            }
+            if !last {
+                self.newline();
+            }
+        }
+        if let Some(ref x) = arg.with_parameter_opt {
+            self.skip_group(|y| y.with_parameter_list(&x.with_parameter_list));
+        }
+        self.newline_pop();
+        self.r_paren(&arg.r_paren);
+    }
+
+    /// Emit localparams in the parameter port list of module as declarations in Verilog
+    fn verilog_header_localparams(&mut self, arg: &WithParameter, i: &mut usize) {
+        let items = match arg.with_parameter_opt {
+            Some(ref x) => verilog::parameter_items(&x.with_parameter_list),
+            None => return,
+        };
+        // keep the line of module body because the localparams are moved from the header
+        let line = self.line;
+        for (attributes, item, comma) in &items {
+            if !verilog::is_localparam(item) {
+                continue;
+            }
+            self.newline_list(*i);
+            *i += 1;
+            for x in attributes {
+                self.attribute(x);
+            }
+            self.with_parameter_item(item);
+            self.str(";");
+            if let Some(x) = comma {
+                self.token(&x.comma_token.replace(""));
+            }
+            for _ in attributes {
+                self.attribute_end();
+            }
+        }
+        self.line = line;
+    }
+
+    /// Emit 'InterfaceDeclaration', renamed to the generic instance name if given
+    fn interface_declaration_body(&mut self, arg: &InterfaceDeclaration, name: Option<&str>) {
+        self.interface(&arg.interface);
+        self.space(1);
+        self.unit_declaration_identifier(&arg.identifier, name);
+        let file_scope_import = self.file_scope_import.clone();
+        if !file_scope_import.is_empty() {
+            self.newline_push();
+        }
+        for (i, x) in file_scope_import.iter().enumerate() {
+            if i != 0 {
+                self.newline();
+            }
+            self.str(x);
+        }
+        if !file_scope_import.is_empty() {
+            self.newline_pop();
+        }
+        if let Some(ref x) = arg.interface_declaration_opt0 {
+            self.space(1);
+            self.with_parameter(&x.with_parameter);
+        }
+        self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
+        let mut i = 0;
+        for x in &arg.interface_declaration_list {
+            if interface_group_enabled(&x.interface_group) {
+                self.newline_list(i);
+                i += 1;
+            }
+            self.interface_group(&x.interface_group);
+        }
+        self.newline_list_post(i == 0);
+        self.token(&arg.r_brace.r_brace_token.replace("endinterface"));
+    }
+
+    /// Emit 'PackageDeclaration', renamed to the generic instance name if given
+    fn package_declaration_body(&mut self, arg: &PackageDeclaration, name: Option<&str>) {
+        self.package(&arg.package);
+        self.space(1);
+        self.unit_declaration_identifier(&arg.identifier, name);
+        self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
+        let mut i = 0;
+        for x in &arg.package_declaration_list {
+            if package_group_enabled(&x.package_group) {
+                self.newline_list(i);
+                if i == 0 {
+                    let file_scope_import = self.file_scope_import.clone();
+                    for x in &file_scope_import {
+                        self.str(x);
+                        self.newline();
+                    }
+                }
+                i += 1;
+            }
+            self.package_group(&x.package_group);
+        }
+        self.newline_list_post(i == 0);
+        self.token(&arg.r_brace.r_brace_token.replace("endpackage"));
+    }
+}
+
+/// Walker to get the last line of a syntax tree
+#[derive(Default)]
+struct LastLine {
+    line: usize,
+}
+
+impl VerylWalker for LastLine {
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        self.line = arg.token.line;
+    }
+}
+
+/// Walker to get the first line of a syntax tree
+#[derive(Default)]
+struct FirstLine {
+    line: usize,
+}
+
+impl VerylWalker for FirstLine {
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        if self.line == 0 {
+            self.line = arg.token.line;
+        }
+    }
+}
+
+/// Walker to collect identifier tokens for source map
+#[derive(Default)]
+struct IdentifierTokens {
+    ids: HashSet<TokenId>,
+}
+
+impl VerylWalker for IdentifierTokens {
+    fn identifier(&mut self, arg: &Identifier) {
+        self.ids.insert(arg.identifier_token.token.id);
+    }
+}
+
+fn localparam_text(r#type: &str, name: &str, value: &str) -> String {
+    if r#type.is_empty() {
+        format!("localparam {name} = {value};")
+    } else {
+        format!("localparam {} {} = {};", r#type, name, value)
+    }
+}
+
+/// Value of the enum member without explicit value
+fn enum_value_text(prev: Option<&str>) -> String {
+    match prev {
+        Some(x) => format!("{x} + 1"),
+        None => "0".to_string(),
+    }
+}
+
+/// Groups disabled by `cfg` attribute are not emitted
+fn module_group_enabled(arg: &ModuleGroup) -> bool {
+    arg.module_group_list
+        .iter()
+        .all(|x| feature_table::is_enabled(&x.attribute))
+}
+
+fn interface_group_enabled(arg: &InterfaceGroup) -> bool {
+    arg.interface_group_list
+        .iter()
+        .all(|x| feature_table::is_enabled(&x.attribute))
+}
+
+fn package_group_enabled(arg: &PackageGroup) -> bool {
+    arg.package_group_list
+        .iter()
+        .all(|x| feature_table::is_enabled(&x.attribute))
+}
+
+fn description_group_enabled(arg: &DescriptionGroup) -> bool {
+    arg.description_group_list
+        .iter()
+        .all(|x| feature_table::is_enabled(&x.attribute))
+}
+
+/// Whether the description group emits any code.
+/// Generic declarations without instance and extern declarations emit nothing.
+fn description_group_emitted(arg: &DescriptionGroup, verilog: bool) -> bool {
+    let has_instance = |x: &Identifier| {
+        symbol_table::resolve(x)
+            .ok()
+            .and_then(|x| x.found)
+            .map(|x| !symbol_table::get_generic_instances(x.token.id).is_empty())
+            .unwrap_or(false)
+    };
+    let items: Vec<DescriptionItem> = arg.into();
+    items.iter().any(|x| match x {
+        DescriptionItem::ModuleDeclaration(x) => {
+            let x = &x.module_declaration;
+            x.module_declaration_opt.is_none() || has_instance(&x.identifier)
+        }
+        DescriptionItem::ExternModuleDeclaration(_) => false,
+        DescriptionItem::InterfaceDeclaration(x) => {
+            let x = &x.interface_declaration;
+            !verilog && (x.interface_declaration_opt.is_none() || has_instance(&x.identifier))
+        }
+        DescriptionItem::PackageDeclaration(x) => {
+            let x = &x.package_declaration;
+            !verilog && (x.package_declaration_opt.is_none() || has_instance(&x.identifier))
+        }
+        DescriptionItem::ImportDeclaration(_) => !verilog,
+    })
+}
+
+/// Emitted name of the reference with generic arguments
+pub(crate) fn generic_reference_name(
+    segments: &[GenericSegment],
+    index: usize,
+    generic_map: &HashMap<StrId, StrId>,
+    build_opt: &Build,
+) -> String {
+    let (identifier, arg) = &segments[index];
+    let mut arguments = Vec::new();
+    if let Some(arg) = arg {
+        let items: Vec<WithGenericArgumentItem> = arg.with_generic_argument_list.as_ref().into();
+        for item in &items {
+            let mut stringifier = Stringifier::new();
+            stringifier.with_generic_argument_item(item);
+            let text = resource_table::insert_str(stringifier.as_str());
+            arguments.push(*generic_map.get(&text).unwrap_or(&text));
+        }
+    }
+    let name = mangled_name(&identifier.identifier_token.text(), &arguments);
+
+    let path: Vec<StrId> = segments[..=index]
+        .iter()
+        .map(|x| x.0.identifier_token.token.text)
+        .collect();
+    if let Some(namespace) = namespace_table::get(segments[0].0.identifier_token.token.id) {
+        if let Ok(symbol) = symbol_table::get(&SymbolPath::new(&path), &namespace) {
+            if let Some(symbol) = symbol.found {
+                if matches!(
+                    symbol.kind,
+                    SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package(_)
+                ) {
+                    return unit_name(&symbol.namespace.to_string(), &name, build_opt);
+                }
+            }
+        }
+    }
+    name
+}
+
+/// Emitted name of module, interface and package.
+/// The project name is prefixed to avoid collision in the global namespace of SystemVerilog.
+pub(crate) fn unit_name(namespace: &str, name: &str, build_opt: &Build) -> String {
+    let name = format!("{namespace}_{name}").replace("::", "_");
+    format!("{}{}{}", build_opt.prefix, name, build_opt.suffix)
+}
+
+/// Index of the segment referring module, interface or package, and the emitted name of it.
+/// The segments before it (e.g. the project name of a dependency) are included in the name.
+pub(crate) fn unit_reference(
+    segments: &[GenericSegment],
+    generic_map: &HashMap<StrId, StrId>,
+    build_opt: &Build,
+) -> Option<(usize, String)> {
+    let namespace = namespace_table::get(segments[0].0.identifier_token.token.id)?;
+    for index in 0..segments.len() {
+        let path: Vec<StrId> = segments[..=index]
+            .iter()
+            .map(|x| x.0.identifier_token.token.text)
+            .collect();
+        let symbol = match symbol_table::get(&SymbolPath::new(&path), &namespace) {
+            Ok(x) => x.found,
+            Err(_) => None,
+        };
+        let Some(symbol) = symbol else {
+            continue;
+        };
+        let name = match symbol.kind {
+            SymbolKind::Module(ref x) if x.is_extern => symbol.token.text.to_string(),
+            SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package(_) => {
+                if segments[index].1.is_some() {
+                    generic_reference_name(segments, index, generic_map, build_opt)
+                } else {
+                    let name = symbol.token.text.to_string();
+                    unit_name(&symbol.namespace.to_string(), &name, build_opt)
+                }
+            }
+            _ => return None,
+        };
+        return Some((index, name));
+    }
+    None
+}
+
+/// Argument text replacing the generic parameter at the head of the path
+pub(crate) fn generic_substitution(
+    segments: &[GenericSegment],
+    index: usize,
+    generic_map: &HashMap<StrId, StrId>,
+    build_opt: &Build,
+) -> Option<String> {
+    if index == 0 {
+        let token = &segments[0].0.identifier_token.token;
+        let text = generic_map.get(&token.text)?.to_string();
+
+        // Replace module, interface or package at the head of the actual argument
+        let (head, tail) = match text.split_once("::") {
+            Some((head, tail)) => (head, Some(tail)),
+            None => (text.as_str(), None),
+        };
+        let namespace = namespace_table::get(token.id)?;
+        let path = SymbolPath::new(&[resource_table::insert_str(head)]);
+        let symbol = match symbol_table::get(&path, &namespace) {
+            Ok(x) => x.found,
+            Err(_) => None,
+        };
+        let head = match symbol {
+            Some(symbol) => match symbol.kind {
+                SymbolKind::Module(ref x) if x.is_extern => head.to_string(),
+                SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package(_) => {
+                    unit_name(&symbol.namespace.to_string(), head, build_opt)
+                }
+                _ => head.to_string(),
+            },
+            None => head.to_string(),
+        };
+        match tail {
+            Some(tail) => Some(format!("{head}::{tail}")),
+            None => Some(head),
+        }
+    } else {
+        None
+    }
 }
 
 impl VerylWalker for Emitter {
@@ -307,7 +1867,7 @@ impl VerylWalker for Emitter {
                 _ => unreachable!(),
             };
 
-            if let Some(actual_width) = strnum_bitwidth::bitwidth(number, base_num) {
+            if let Some(actual_width) = value::bitwidth(number, base_num) {
                 let text = format!("{actual_width}'{base}{number}");
                 self.veryl_token(&arg.based_token.replace(&text));
             } else {
@@ -324,7 +1884,18 @@ impl VerylWalker for Emitter {
         let (width, tail) = text.split_once('\'').unwrap();
 
         if width.is_empty() {
-            self.veryl_token(&arg.all_bit_token);
+            if self.is_verilog() {
+                // Verilog has no unbased unsized literal
+                let text = match tail {
+                    "0" => "0",
+                    "1" => "~0",
+                    "x" | "X" => "'bx",
+                    _ => "'bz",
+                };
+                self.veryl_token(&arg.all_bit_token.replace(text));
+            } else {
+                self.veryl_token(&arg.all_bit_token);
+            }
         } else {
             let width: usize = width.parse().unwrap();
             let text = format!("{width}'b{}", tail.repeat(width));
@@ -335,7 +1906,7 @@ impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'Comma'
     fn comma(&mut self, arg: &Comma) {
         if self.string.ends_with("`endif") {
-            self.string.truncate(self.string.len() - "`endif".len());
+            self.truncate(self.string.len() - "`endif".len());
 
             let trailing_endif = format!(
                 "`endif\n{}",
@@ -343,8 +1914,7 @@ impl VerylWalker for Emitter {
             );
             let mut additional_endif = 0;
             while self.string.ends_with(&trailing_endif) {
-                self.string
-                    .truncate(self.string.len() - trailing_endif.len());
+                self.truncate(self.string.len() - trailing_endif.len());
                 additional_endif += 1;
             }
 
@@ -359,6 +1929,16 @@ impl VerylWalker for Emitter {
         }
     }
 
+    /// Semantic action for non-terminal 'Clock'
+    fn clock(&mut self, arg: &Clock) {
+        self.veryl_token(&arg.clock_token.replace("logic"));
+    }
+
+    /// Semantic action for non-terminal 'Reset'
+    fn reset(&mut self, arg: &Reset) {
+        self.veryl_token(&arg.reset_token.replace("logic"));
+    }
+
     /// Semantic action for non-terminal 'F32'
     fn f32(&mut self, arg: &F32) {
         self.veryl_token(&arg.f32_token.replace("shortreal"));
@@ -413,24 +1993,33 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'ScopedIdentifier'
     fn scoped_identifier(&mut self, arg: &ScopedIdentifier) {
-        self.identifier(&arg.identifier);
-        for x in &arg.scoped_identifier_list {
-            if self.in_direction_modport {
+        let segments: Vec<GenericSegment> = arg.into();
+        let unit = unit_reference(&segments, &self.generic_map, &self.build_opt);
+        self.unit_segment(&segments, 0, &unit);
+        for (i, x) in arg.scoped_identifier_list.iter().enumerate() {
+            if matches!(unit, Some((n, _)) if i < n) {
+                self.unit_colon_colon(&x.colon_colon, i + 1, &unit);
+            } else if self.in_direction_modport {
                 self.str(".");
             } else {
                 self.colon_colon(&x.colon_colon);
             }
-            self.identifier(&x.identifier);
+            self.unit_segment(&segments, i + 1, &unit);
         }
     }
 
     /// Semantic action for non-terminal 'ExpressionIdentifier'
     fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        if self.is_verilog() && self.verilog_expression_identifier(arg) {
+            return;
+        }
         if let Some(ref x) = arg.expression_identifier_opt {
             self.dollar(&x.dollar);
         }
 
-        self.identifier(&arg.identifier);
+        let segments: Vec<GenericSegment> = arg.into();
+        let unit = unit_reference(&segments, &self.generic_map, &self.build_opt);
+        self.unit_segment(&segments, 0, &unit);
         let symbol = symbol_table::resolve(arg);
         let is_enum_member = if let Ok(ref symbol) = symbol {
             if let Some(ref symbol) = symbol.found {
@@ -443,16 +2032,16 @@ impl VerylWalker for Emitter {
         };
 
         match &*arg.expression_identifier_group {
-            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
-                if is_enum_member {
+            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+                if unit.is_none() && is_enum_member {
                     self.str("_");
                 } else {
-                    self.colon_colon(&x.colon_colon);
+                    self.unit_colon_colon(&x.colon_colon, 1, &unit);
                 }
-                self.identifier(&x.identifier);
-                for x in &x.expression_identifier_group_list {
-                    self.colon_colon(&x.colon_colon);
-                    self.identifier(&x.identifier);
+                self.unit_segment(&segments, 1, &unit);
+                for (i, x) in x.expression_identifier_group_list.iter().enumerate() {
+                    self.unit_colon_colon(&x.colon_colon, i + 2, &unit);
+                    self.unit_segment(&segments, i + 2, &unit);
                 }
                 for x in &x.expression_identifier_group_list0 {
                     self.select(&x.select);
@@ -599,6 +2188,11 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'Expression11'
     fn expression11(&mut self, arg: &Expression11) {
+        // Verilog has no cast
+        if self.is_verilog() {
+            self.expression12(&arg.expression12);
+            return;
+        }
         for x in &arg.expression11_list {
             self.scoped_identifier(&x.scoped_identifier);
             self.str("'(");
@@ -717,6 +2311,12 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'InsideExpression'
     fn inside_expression(&mut self, arg: &InsideExpression) {
+        if self.is_verilog() {
+            self.token(&arg.inside.inside_token.replace("("));
+            self.verilog_inside(&arg.expression, &arg.range_list);
+            self.str(")");
+            return;
+        }
         self.str("(");
         self.expression(&arg.expression);
         self.space(1);
@@ -730,6 +2330,12 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'OutsideExpression'
     fn outside_expression(&mut self, arg: &OutsideExpression) {
+        if self.is_verilog() {
+            self.token(&arg.outside.outside_token.replace("!("));
+            self.verilog_inside(&arg.expression, &arg.range_list);
+            self.str(")");
+            return;
+        }
         self.str("!(");
         self.expression(&arg.expression);
         self.space(1);
@@ -826,6 +2432,8 @@ impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'VariableType'
     fn variable_type(&mut self, arg: &VariableType) {
         match &*arg.variable_type_group {
+            VariableTypeGroup::Clock(x) => self.clock(&x.clock),
+            VariableTypeGroup::Reset(x) => self.reset(&x.reset),
             VariableTypeGroup::Logic(x) => self.logic(&x.logic),
             VariableTypeGroup::Bit(x) => self.bit(&x.bit),
             VariableTypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
@@ -911,6 +2519,22 @@ impl VerylWalker for Emitter {
         } else {
             match &*arg.assignment_group {
                 AssignmentGroup::Equ(x) => self.equ(&x.equ),
+                // Verilog has no assignment operator
+                AssignmentGroup::AssignmentOperator(x) if self.is_verilog() => {
+                    let token = &x.assignment_operator.assignment_operator_token;
+                    let text = token.text();
+                    self.str("=");
+                    self.space(1);
+                    let identifier = self.assignment_lefthand_side.take().unwrap();
+                    self.expression_identifier(&identifier);
+                    self.space(1);
+                    self.token(&token.replace(&text[0..text.len() - 1]));
+                    self.space(1);
+                    self.str("(");
+                    self.expression(&arg.expression);
+                    self.str(")");
+                    return;
+                }
                 AssignmentGroup::AssignmentOperator(x) => {
                     self.assignment_operator(&x.assignment_operator)
                 }
@@ -1017,7 +2641,14 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'ReturnStatement'
     fn return_statement(&mut self, arg: &ReturnStatement) {
-        self.r#return(&arg.r#return);
+        // Verilog function returns the value by assigning to the function name
+        if let Some(name) = self.function_name.clone() {
+            self.token(&arg.r#return.return_token.replace(&name));
+            self.space(1);
+            self.str("=");
+        } else {
+            self.r#return(&arg.r#return);
+        }
         self.space(1);
         self.expression(&arg.expression);
         self.semicolon(&arg.semicolon);
@@ -1025,11 +2656,27 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'ForStatement'
     fn for_statement(&mut self, arg: &ForStatement) {
+        let verilog = self.is_verilog();
+        if verilog {
+            // loop variable is declared at the named block because Verilog can't declare it in for
+            let name = arg.identifier.identifier_token.text();
+            let line = arg.r#for.for_token.token.line;
+            self.str(&format!("begin : loop_{name}_{line}"));
+            self.newline_push();
+            if self.verilog_scalar_type(&arg.scalar_type, "reg") {
+                self.space(1);
+            }
+            self.str(&name);
+            self.str(";");
+            self.newline();
+        }
         self.r#for(&arg.r#for);
         self.space(1);
         self.str("(");
-        self.scalar_type(&arg.scalar_type);
-        self.space(1);
+        if !verilog {
+            self.scalar_type(&arg.scalar_type);
+            self.space(1);
+        }
         self.identifier(&arg.identifier);
         self.space(1);
         self.str("=");
@@ -1055,16 +2702,11 @@ impl VerylWalker for Emitter {
         }
         self.str(";");
         self.space(1);
-        if let Some(ref x) = arg.for_statement_opt {
-            self.identifier(&arg.identifier);
-            self.space(1);
-            self.assignment_operator(&x.assignment_operator);
-            self.space(1);
-            self.expression(&x.expression);
-        } else {
-            self.identifier(&arg.identifier);
-            self.str("++");
-        }
+        let step = arg
+            .for_statement_opt
+            .as_ref()
+            .map(|x| (x.assignment_operator.as_ref(), x.expression.as_ref()));
+        self.for_step(&arg.identifier, step);
         self.str(")");
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
@@ -1073,6 +2715,10 @@ impl VerylWalker for Emitter {
             self.statement(&x.statement);
         }
         self.newline_list_post(arg.for_statement_list.is_empty());
+        if verilog {
+            self.str("end");
+            self.newline_pop();
+        }
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
@@ -1122,7 +2768,7 @@ impl VerylWalker for Emitter {
                 if let Some(ref x) = arg.attribute_opt {
                     let comma = if self.string.trim_end().ends_with(',') {
                         self.unindent();
-                        self.string.truncate(self.string.len() - ",\n".len());
+                        self.truncate(self.string.len() - ",\n".len());
                         self.newline();
                         true
                     } else {
@@ -1133,8 +2779,8 @@ impl VerylWalker for Emitter {
                     self.str("`");
                     self.identifier(&arg.identifier);
                     self.space(1);
-                    if let AttributeItem::Identifier(x) = &*x.attribute_list.attribute_item {
-                        self.identifier(&x.identifier);
+                    if let Some(x) = x.attribute_list.attribute_item.identifier() {
+                        self.identifier(x);
                     }
                     self.newline();
                     self.attribute.push(AttributeType::Ifdef);
@@ -1167,14 +2813,31 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'VarDeclaration'
     fn var_declaration(&mut self, arg: &VarDeclaration) {
-        self.scalar_type(&arg.array_type.scalar_type);
-        self.space(1);
+        if self.is_verilog() {
+            let key = (arg.identifier.identifier_token.token.id, None);
+            let kind = if self.in_function || self.assigned.contains(&key) {
+                "reg"
+            } else {
+                "wire"
+            };
+            if self.verilog_scalar_type(&arg.array_type.scalar_type, kind) {
+                self.space(1);
+            }
+        } else {
+            self.scalar_type(&arg.array_type.scalar_type);
+            self.space(1);
+        }
         self.identifier(&arg.identifier);
         if let Some(ref x) = arg.array_type.array_type_opt {
             self.space(1);
             self.array(&x.array);
         }
-        if let Some(ref x) = arg.var_declaration_opt {
+        // initializer in function is emitted at the statements in Verilog
+        if self.is_verilog() && self.in_function {
+            if let Some(ref x) = arg.var_declaration_opt0 {
+                self.token(&x.equ.equ_token.replace(""));
+            }
+        } else if let Some(ref x) = arg.var_declaration_opt0 {
             self.str(";");
             self.newline();
             if !self.in_function {
@@ -1196,10 +2859,7 @@ impl VerylWalker for Emitter {
         self.space(1);
         match &*arg.localparam_declaration_group {
             LocalparamDeclarationGroup::ArrayTypeEquExpression(x) => {
-                if !self.is_implicit_scalar_type(&x.array_type.scalar_type) {
-                    self.scalar_type(&x.array_type.scalar_type);
-                    self.space(1);
-                }
+                self.parameter_scalar_type(&x.array_type.scalar_type);
                 self.identifier(&arg.identifier);
                 if let Some(ref x) = x.array_type.array_type_opt {
                     self.space(1);
@@ -1228,7 +2888,11 @@ impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'AlwaysFfDeclaration'
     fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
         self.in_always_ff = true;
-        self.always_ff(&arg.always_ff);
+        if self.is_verilog() {
+            self.token(&arg.always_ff.always_ff_token.replace("always"));
+        } else {
+            self.always_ff(&arg.always_ff);
+        }
         self.space(1);
         self.str("@");
         self.space(1);
@@ -1236,7 +2900,11 @@ impl VerylWalker for Emitter {
         self.always_ff_clock(&arg.always_ff_clock);
         if let Some(ref x) = arg.always_ff_declaration_opt {
             if self.always_ff_reset_exist_in_sensitivity_list(&x.always_ff_reset) {
-                self.comma(&x.comma);
+                if self.is_verilog() {
+                    self.token(&x.comma.comma_token.replace(" or"));
+                } else {
+                    self.comma(&x.comma);
+                }
                 self.space(1);
             }
             self.always_ff_reset(&x.always_ff_reset);
@@ -1310,14 +2978,26 @@ impl VerylWalker for Emitter {
             self.hierarchical_identifier(&arg.hierarchical_identifier);
         }
 
-        let mut stringifier = Stringifier::new();
-        stringifier.hierarchical_identifier(&arg.hierarchical_identifier);
-        self.reset_signal = Some(format!("{}{}", prefix, stringifier.as_str()));
+        let reset_signal = if self.is_verilog() {
+            let mut emitter = self.sub_emitter();
+            emitter.no_comments = true;
+            emitter.hierarchical_identifier(&arg.hierarchical_identifier);
+            emitter.string
+        } else {
+            let mut stringifier = Stringifier::new();
+            stringifier.hierarchical_identifier(&arg.hierarchical_identifier);
+            stringifier.as_str().to_string()
+        };
+        self.reset_signal = Some(format!("{}{}", prefix, reset_signal));
     }
 
     /// Semantic action for non-terminal 'AlwaysCombDeclaration'
     fn always_comb_declaration(&mut self, arg: &AlwaysCombDeclaration) {
-        self.always_comb(&arg.always_comb);
+        if self.is_verilog() {
+            self.token(&arg.always_comb.always_comb_token.replace("always @*"));
+        } else {
+            self.always_comb(&arg.always_comb);
+        }
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
         for (i, x) in arg.always_comb_declaration_list.iter().enumerate() {
@@ -1328,6 +3008,50 @@ impl VerylWalker for Emitter {
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
+    /// Semantic action for non-terminal 'HierarchicalIdentifier'
+    fn hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier) {
+        if self.is_verilog() && !arg.hierarchical_identifier_list0.is_empty() {
+            if let Ok(symbol) = symbol_table::resolve(arg) {
+                let selects: Vec<&Select> = arg
+                    .hierarchical_identifier_list
+                    .iter()
+                    .map(|x| x.select.as_ref())
+                    .collect();
+                let members: Vec<(&Identifier, Vec<&Select>)> = arg
+                    .hierarchical_identifier_list0
+                    .iter()
+                    .map(|x| {
+                        let selects = x
+                            .hierarchical_identifier_list0_list
+                            .iter()
+                            .map(|x| x.select.as_ref())
+                            .collect();
+                        (x.identifier.as_ref(), selects)
+                    })
+                    .collect();
+                if self.verilog_member_access(
+                    &arg.identifier,
+                    &selects,
+                    &members,
+                    &symbol.full_path,
+                ) {
+                    return;
+                }
+            }
+        }
+        self.identifier(&arg.identifier);
+        for x in &arg.hierarchical_identifier_list {
+            self.select(&x.select);
+        }
+        for x in &arg.hierarchical_identifier_list0 {
+            self.dot(&x.dot);
+            self.identifier(&x.identifier);
+            for x in &x.hierarchical_identifier_list0_list {
+                self.select(&x.select);
+            }
+        }
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, arg: &AssignDeclaration) {
         self.assign(&arg.assign);
@@ -1393,6 +3117,20 @@ impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'EnumDeclaration'
     fn enum_declaration(&mut self, arg: &EnumDeclaration) {
         self.enum_name = Some(arg.identifier.identifier_token.text());
+        if self.is_verilog() {
+            // enum members are emitted as localparams because Verilog has no enum
+            let namespace =
+                namespace_table::get(arg.identifier.identifier_token.token.id).unwrap_or_default();
+            let r#type: Type = arg.scalar_type.as_ref().into();
+            self.enum_type = self.verilog_type(&r#type, &namespace, "");
+            self.enum_prev = None;
+            self.token(&arg.r#enum.enum_token.replace(""));
+            self.token(&arg.identifier.identifier_token.replace(""));
+            self.token(&arg.l_brace.l_brace_token.replace(""));
+            self.enum_list(&arg.enum_list);
+            self.token(&arg.r_brace.r_brace_token.replace(""));
+            return;
+        }
         self.str("typedef");
         self.space(1);
         self.r#enum(&arg.r#enum);
@@ -1412,14 +3150,21 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'EnumList'
     fn enum_list(&mut self, arg: &EnumList) {
+        let verilog = self.is_verilog();
         self.enum_group(&arg.enum_group);
         for x in &arg.enum_list_list {
-            self.comma(&x.comma);
+            if verilog {
+                self.token(&x.comma.comma_token.replace(";"));
+            } else {
+                self.comma(&x.comma);
+            }
             self.newline();
             self.enum_group(&x.enum_group);
         }
         if let Some(ref x) = arg.enum_list_opt {
-            self.token(&x.comma.comma_token.replace(""));
+            self.token(&x.comma.comma_token.replace(if verilog { ";" } else { "" }));
+        } else if verilog {
+            self.str(";");
         }
     }
 
@@ -1441,6 +3186,33 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'EnumItem'
     fn enum_item(&mut self, arg: &EnumItem) {
+        if self.is_verilog() {
+            let name = format!(
+                "{}_{}",
+                self.enum_name.clone().unwrap(),
+                arg.identifier.identifier_token.text()
+            );
+            let r#type = self.enum_type.clone();
+            self.str("localparam");
+            self.space(1);
+            if !r#type.is_empty() {
+                self.str(&r#type);
+                self.space(1);
+            }
+            self.token(&arg.identifier.identifier_token.replace(&name));
+            self.space(1);
+            if let Some(ref x) = arg.enum_item_opt {
+                self.equ(&x.equ);
+                self.space(1);
+                self.expression(&x.expression);
+            } else {
+                self.str("=");
+                self.space(1);
+                self.str(&enum_value_text(self.enum_prev.clone().as_deref()));
+            }
+            self.enum_prev = Some(name);
+            return;
+        }
         self.str(&self.enum_name.clone().unwrap());
         self.str("_");
         self.identifier(&arg.identifier);
@@ -1509,6 +3281,25 @@ impl VerylWalker for Emitter {
         self.identifier(&arg.identifier);
     }
 
+    /// Semantic action for non-terminal 'UnionDeclaration'
+    fn union_declaration(&mut self, arg: &UnionDeclaration) {
+        self.str("typedef");
+        self.space(1);
+        self.union(&arg.union);
+        self.space(1);
+        self.str("packed");
+        self.space(1);
+        self.token_will_push(&arg.l_brace.l_brace_token);
+        self.newline_push();
+        self.struct_list(&arg.struct_list);
+        self.newline_pop();
+        self.str("}");
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.str(";");
+        self.token(&arg.r_brace.r_brace_token.replace(""));
+    }
+
     /// Semantic action for non-terminal 'InitialDeclaration'
     fn initial_declaration(&mut self, arg: &InitialDeclaration) {
         self.initial(&arg.initial);
@@ -1537,14 +3328,47 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'InstDeclaration'
     fn inst_declaration(&mut self, arg: &InstDeclaration) {
+        if self.is_verilog() {
+            if let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) {
+                if let Some(symbol) = symbol.found {
+                    match symbol.kind {
+                        SymbolKind::Interface(_) => {
+                            self.verilog_interface_instance(arg, &symbol);
+                            return;
+                        }
+                        SymbolKind::Module(_) => self.inst_module = Some(symbol),
+                        _ => (),
+                    }
+                }
+            }
+        }
         if arg.inst_declaration_opt1.is_none() {
             self.single_line = true;
         }
         self.token(&arg.inst.inst_token.replace(""));
         if let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) {
             if let Some(symbol) = symbol.found {
-                self.str(&format!("{}_", symbol.namespace).replace("::", "_"));
-                self.str(&format!("{}", symbol.token.text));
+                let segments: Vec<GenericSegment> = arg.scoped_identifier.as_ref().into();
+                let index = segments.len() - 1;
+                if segments[index].1.is_some() {
+                    let name = generic_reference_name(
+                        &segments,
+                        index,
+                        &self.generic_map,
+                        &self.build_opt,
+                    );
+                    self.str(&name);
+                } else if let Some(x) =
+                    generic_substitution(&segments, index, &self.generic_map, &self.build_opt)
+                {
+                    self.str(&x.replace("::", "_"));
+                } else if matches!(symbol.kind, SymbolKind::Module(ref x) if x.is_extern) {
+                    self.str(&format!("{}", symbol.token.text));
+                } else {
+                    let name = symbol.token.text.to_string();
+                    let name = unit_name(&symbol.namespace.to_string(), &name, &self.build_opt);
+                    self.str(&name);
+                }
             } else {
                 self.scoped_identifier(&arg.scoped_identifier);
             }
@@ -1575,6 +3399,7 @@ impl VerylWalker for Emitter {
         }
         self.semicolon(&arg.semicolon);
         self.single_line = false;
+        self.inst_module = None;
     }
 
     /// Semantic action for non-terminal 'InstParameter'
@@ -1676,6 +3501,11 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'InstPortItem'
     fn inst_port_item(&mut self, arg: &InstPortItem) {
+        if let Some(module) = self.inst_module.clone() {
+            if self.verilog_modport_connection(arg, &module) {
+                return;
+            }
+        }
         self.str(".");
         self.identifier(&arg.identifier);
         self.space(1);
@@ -1749,10 +3579,7 @@ impl VerylWalker for Emitter {
         self.space(1);
         match &*arg.with_parameter_item_group0 {
             WithParameterItemGroup0::ArrayTypeEquExpression(x) => {
-                if !self.is_implicit_scalar_type(&x.array_type.scalar_type) {
-                    self.scalar_type(&x.array_type.scalar_type);
-                    self.space(1);
-                }
+                self.parameter_scalar_type(&x.array_type.scalar_type);
                 self.identifier(&arg.identifier);
                 if let Some(ref x) = x.array_type.array_type_opt {
                     self.space(1);
@@ -1825,7 +3652,12 @@ impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'PortDeclarationItem'
     fn port_declaration_item(&mut self, arg: &PortDeclarationItem) {
         match &*arg.port_declaration_item_group {
-            PortDeclarationItemGroup::DirectionArrayType(x) => {
+            PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x)
+                if self.is_verilog() =>
+            {
+                self.verilog_port_declaration_item(arg, &x.direction, &x.array_type);
+            }
+            PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
                 self.direction(&x.direction);
                 if let Direction::Modport(_) = *x.direction {
                     self.in_direction_modport = true;
@@ -1841,11 +3673,11 @@ impl VerylWalker for Emitter {
                 }
                 self.in_direction_modport = false;
             }
-            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(x) => {
+            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(x) => {
                 self.interface(&x.interface);
                 self.space(1);
                 self.identifier(&arg.identifier);
-                if let Some(ref x) = x.port_declaration_item_opt {
+                if let Some(ref x) = x.port_declaration_item_opt0 {
                     self.space(1);
                     self.array(&x.array);
                 }
@@ -1866,50 +3698,24 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'FunctionDeclaration'
     fn function_declaration(&mut self, arg: &FunctionDeclaration) {
-        self.in_function = true;
-        if let Some(ref x) = arg.function_declaration_opt {
-            self.str("module");
-            self.space(1);
-            self.identifier(&arg.identifier);
-            self.space(1);
-            self.with_parameter(&x.with_parameter);
-            self.str(";");
-            self.newline_push();
-        }
-        self.function(&arg.function);
-        self.space(1);
-        self.str("automatic");
-        self.space(1);
-        self.scalar_type(&arg.scalar_type);
-        self.space(1);
-        self.identifier(&arg.identifier);
-        if let Some(ref x) = arg.function_declaration_opt0 {
-            self.port_declaration(&x.port_declaration);
-            self.space(1);
-        }
-        self.token(&arg.minus_g_t.minus_g_t_token.replace(""));
-        self.str(";");
-        self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
-        for (i, x) in arg.function_declaration_list.iter().enumerate() {
-            self.newline_list(i);
-            self.function_item(&x.function_item);
-        }
-        self.newline_list_post(arg.function_declaration_list.is_empty());
         if arg.function_declaration_opt.is_some() {
-            self.str("endfunction");
-            self.newline_pop();
-            self.token(&arg.r_brace.r_brace_token.replace("endmodule"));
+            self.generic_declaration(
+                &arg.identifier,
+                &arg.function.function_token.token,
+                &arg.r_brace.r_brace_token.token,
+                |x| x.function_declaration(arg),
+                |x, name| x.function_declaration_body(arg, Some(name)),
+            );
         } else {
-            self.token(&arg.r_brace.r_brace_token.replace("endfunction"));
+            self.function_declaration_body(arg, None);
         }
-        self.in_function = false;
     }
 
     /// Semantic action for non-terminal 'ImportDeclaration'
     fn import_declaration(&mut self, arg: &ImportDeclaration) {
         self.import(&arg.import);
         self.space(1);
-        self.identifier(&arg.identifier);
+        self.unit_identifier(&arg.identifier);
         self.colon_colon(&arg.colon_colon);
         match &*arg.import_declaration_group {
             ImportDeclarationGroup::Identifier(x) => self.identifier(&x.identifier),
@@ -1923,7 +3729,7 @@ impl VerylWalker for Emitter {
         self.export(&arg.export);
         self.space(1);
         match &*arg.export_declaration_group {
-            ExportDeclarationGroup::Identifier(x) => self.identifier(&x.identifier),
+            ExportDeclarationGroup::Identifier(x) => self.unit_identifier(&x.identifier),
             ExportDeclarationGroup::Star(x) => self.star(&x.star),
         }
         self.colon_colon(&arg.colon_colon);
@@ -1934,44 +3740,60 @@ impl VerylWalker for Emitter {
         self.semicolon(&arg.semicolon);
     }
 
-    /// Semantic action for non-terminal 'ModuleDeclaration'
-    fn module_declaration(&mut self, arg: &ModuleDeclaration) {
-        self.module(&arg.module);
-        self.space(1);
-        if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
-            if let Some(symbol) = symbol.found {
-                self.str(&format!("{}_", symbol.namespace).replace("::", "_"));
-            }
+    /// Semantic action for non-terminal 'EmbedDeclaration'
+    fn embed_declaration(&mut self, arg: &EmbedDeclaration) {
+        let way = arg.identifier.identifier_token.text();
+        let lang = arg.identifier0.identifier_token.text();
+        if way != "inline" || lang != "sv" {
+            return;
         }
-        self.identifier(&arg.identifier);
-        let file_scope_import = self.file_scope_import.clone();
-        if !file_scope_import.is_empty() {
-            self.newline_push();
+
+        self.token(&arg.embed.embed_token.replace(""));
+        let text = arg.embed_content.embed_content_token.text();
+        let text = &text[3..text.len() - 3];
+        self.verbatim(text);
+
+        // the line of the closing `}}}` is used to keep the following blank lines
+        let mut token = arg.embed_content.embed_content_token.replace("");
+        token.token.line += text.matches('\n').count();
+        self.token(&token);
+    }
+
+    /// Semantic action for non-terminal 'IncludeDeclaration'
+    fn include_declaration(&mut self, arg: &IncludeDeclaration) {
+        if arg.identifier.identifier_token.text() != "inline" {
+            return;
         }
-        for (i, x) in file_scope_import.iter().enumerate() {
-            if i != 0 {
-                self.newline();
+
+        self.token(&arg.include.include_token.replace(""));
+        let path = arg.string_literal.string_literal_token.text();
+        let path = &path[1..path.len() - 1];
+        let source = resource_table::get_path_value(arg.include.include_token.token.file_path);
+        if let Some(source) = source {
+            let path = match source.parent() {
+                Some(x) => x.join(path),
+                None => PathBuf::from(path),
+            };
+            if let Ok(text) = std::fs::read_to_string(path) {
+                self.verbatim(&format!("\n{text}"));
             }
-            self.str(x);
-        }
-        if !file_scope_import.is_empty() {
-            self.newline_pop();
-        }
-        if let Some(ref x) = arg.module_declaration_opt {
-            self.space(1);
-            self.with_parameter(&x.with_parameter);
         }
-        if let Some(ref x) = arg.module_declaration_opt0 {
-            self.space(1);
-            self.port_declaration(&x.port_declaration);
-        }
-        self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
-        for (i, x) in arg.module_declaration_list.iter().enumerate() {
-            self.newline_list(i);
-            self.module_group(&x.module_group);
+        self.token(&arg.semicolon.semicolon_token.replace(""));
+    }
+
+    /// Semantic action for non-terminal 'ModuleDeclaration'
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) {
+        if arg.module_declaration_opt.is_some() {
+            self.generic_declaration(
+                &arg.identifier,
+                &arg.module.module_token.token,
+                &arg.r_brace.r_brace_token.token,
+                |x| x.module_declaration(arg),
+                |x, name| x.module_declaration_body(arg, Some(name)),
+            );
+        } else {
+            self.module_declaration_body(arg, None);
         }
-        self.newline_list_post(arg.module_declaration_list.is_empty());
-        self.token(&arg.r_brace.r_brace_token.replace("endmodule"));
     }
 
     /// Semantic action for non-terminal 'ModuleIfDeclaration'
@@ -2011,8 +3833,11 @@ impl VerylWalker for Emitter {
         self.r#for(&arg.r#for);
         self.space(1);
         self.str("(");
-        self.str("genvar");
-        self.space(1);
+        // genvar is declared at the top of module in Verilog
+        if !self.is_verilog() {
+            self.str("genvar");
+            self.space(1);
+        }
         self.identifier(&arg.identifier);
         self.space(1);
         self.str("=");
@@ -2038,16 +3863,11 @@ impl VerylWalker for Emitter {
         }
         self.str(";");
         self.space(1);
-        if let Some(ref x) = arg.module_for_declaration_opt {
-            self.identifier(&arg.identifier);
-            self.space(1);
-            self.assignment_operator(&x.assignment_operator);
-            self.space(1);
-            self.expression(&x.expression);
-        } else {
-            self.identifier(&arg.identifier);
-            self.str("++");
-        }
+        let step = arg
+            .module_for_declaration_opt
+            .as_ref()
+            .map(|x| (x.assignment_operator.as_ref(), x.expression.as_ref()));
+        self.for_step(&arg.identifier, step);
         self.str(")");
         self.space(1);
         self.module_named_block(&arg.module_named_block);
@@ -2068,11 +3888,15 @@ impl VerylWalker for Emitter {
         self.identifier(&arg.identifier);
         self.default_block = Some(arg.identifier.identifier_token.text());
         self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
-        for (i, x) in arg.module_named_block_list.iter().enumerate() {
-            self.newline_list(i);
+        let mut i = 0;
+        for x in &arg.module_named_block_list {
+            if self.module_group_emitted(&x.module_group) {
+                self.newline_list(i);
+                i += 1;
+            }
             self.module_group(&x.module_group);
         }
-        self.newline_list_post(arg.module_named_block_list.is_empty());
+        self.newline_list_post(i == 0);
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
@@ -2090,24 +3914,36 @@ impl VerylWalker for Emitter {
             self.str(&name);
         }
         self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
-        for (i, x) in arg.module_optional_named_block_list.iter().enumerate() {
-            self.newline_list(i);
+        let mut i = 0;
+        for x in &arg.module_optional_named_block_list {
+            if self.module_group_emitted(&x.module_group) {
+                self.newline_list(i);
+                i += 1;
+            }
             self.module_group(&x.module_group);
         }
-        self.newline_list_post(arg.module_optional_named_block_list.is_empty());
+        self.newline_list_post(i == 0);
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
     /// Semantic action for non-terminal 'ModuleGroup'
     fn module_group(&mut self, arg: &ModuleGroup) {
+        if !self.module_group_emitted(arg) {
+            self.skip_group(|x| x.module_group(arg));
+            return;
+        }
         for x in &arg.module_group_list {
             self.attribute(&x.attribute);
         }
         match &*arg.module_group_group {
             ModuleGroupGroup::LBraceModuleGroupGroupListRBrace(x) => {
-                for (i, x) in x.module_group_group_list.iter().enumerate() {
-                    if i != 0 {
-                        self.newline();
+                let mut i = 0;
+                for x in &x.module_group_group_list {
+                    if self.module_group_emitted(&x.module_group) {
+                        if i != 0 {
+                            self.newline();
+                        }
+                        i += 1;
                     }
                     self.module_group(&x.module_group);
                 }
@@ -2121,38 +3957,17 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'InterfaceDeclaration'
     fn interface_declaration(&mut self, arg: &InterfaceDeclaration) {
-        self.interface(&arg.interface);
-        self.space(1);
-        if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
-            if let Some(symbol) = symbol.found {
-                self.str(&format!("{}_", symbol.namespace).replace("::", "_"));
-            }
-        }
-        self.identifier(&arg.identifier);
-        let file_scope_import = self.file_scope_import.clone();
-        if !file_scope_import.is_empty() {
-            self.newline_push();
-        }
-        for (i, x) in file_scope_import.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.str(x);
-        }
-        if !file_scope_import.is_empty() {
-            self.newline_pop();
-        }
-        if let Some(ref x) = arg.interface_declaration_opt {
-            self.space(1);
-            self.with_parameter(&x.with_parameter);
-        }
-        self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
-        for (i, x) in arg.interface_declaration_list.iter().enumerate() {
-            self.newline_list(i);
-            self.interface_group(&x.interface_group);
+        if arg.interface_declaration_opt.is_some() {
+            self.generic_declaration(
+                &arg.identifier,
+                &arg.interface.interface_token.token,
+                &arg.r_brace.r_brace_token.token,
+                |x| x.interface_declaration(arg),
+                |x, name| x.interface_declaration_body(arg, Some(name)),
+            );
+        } else {
+            self.interface_declaration_body(arg, None);
         }
-        self.newline_list_post(arg.interface_declaration_list.is_empty());
-        self.token(&arg.r_brace.r_brace_token.replace("endinterface"));
     }
 
     /// Semantic action for non-terminal 'InterfaceIfDeclaration'
@@ -2249,11 +4064,15 @@ impl VerylWalker for Emitter {
         self.identifier(&arg.identifier);
         self.default_block = Some(arg.identifier.identifier_token.text());
         self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
-        for (i, x) in arg.interface_named_block_list.iter().enumerate() {
-            self.newline_list(i);
+        let mut i = 0;
+        for x in &arg.interface_named_block_list {
+            if interface_group_enabled(&x.interface_group) {
+                self.newline_list(i);
+                i += 1;
+            }
             self.interface_group(&x.interface_group);
         }
-        self.newline_list_post(arg.interface_named_block_list.is_empty());
+        self.newline_list_post(i == 0);
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
@@ -2271,24 +4090,36 @@ impl VerylWalker for Emitter {
             self.str(&name);
         }
         self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
-        for (i, x) in arg.interface_optional_named_block_list.iter().enumerate() {
-            self.newline_list(i);
+        let mut i = 0;
+        for x in &arg.interface_optional_named_block_list {
+            if interface_group_enabled(&x.interface_group) {
+                self.newline_list(i);
+                i += 1;
+            }
             self.interface_group(&x.interface_group);
         }
-        self.newline_list_post(arg.interface_optional_named_block_list.is_empty());
+        self.newline_list_post(i == 0);
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
     /// Semantic action for non-terminal 'InterfaceGroup'
     fn interface_group(&mut self, arg: &InterfaceGroup) {
+        if !interface_group_enabled(arg) {
+            self.skip_group(|x| x.interface_group(arg));
+            return;
+        }
         for x in &arg.interface_group_list {
             self.attribute(&x.attribute);
         }
         match &*arg.interface_group_group {
             InterfaceGroupGroup::LBraceInterfaceGroupGroupListRBrace(x) => {
-                for (i, x) in x.interface_group_group_list.iter().enumerate() {
-                    if i != 0 {
-                        self.newline();
+                let mut i = 0;
+                for x in &x.interface_group_group_list {
+                    if interface_group_enabled(&x.interface_group) {
+                        if i != 0 {
+                            self.newline();
+                        }
+                        i += 1;
                     }
                     self.interface_group(&x.interface_group);
                 }
@@ -2302,40 +4133,37 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'PackageDeclaration'
     fn package_declaration(&mut self, arg: &PackageDeclaration) {
-        self.package(&arg.package);
-        self.space(1);
-        if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
-            if let Some(symbol) = symbol.found {
-                self.str(&format!("{}_", symbol.namespace).replace("::", "_"));
-            }
-        }
-        self.identifier(&arg.identifier);
-        self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
-        for (i, x) in arg.package_declaration_list.iter().enumerate() {
-            self.newline_list(i);
-            if i == 0 {
-                let file_scope_import = self.file_scope_import.clone();
-                for x in &file_scope_import {
-                    self.str(x);
-                    self.newline();
-                }
-            }
-            self.package_group(&x.package_group);
+        if arg.package_declaration_opt.is_some() {
+            self.generic_declaration(
+                &arg.identifier,
+                &arg.package.package_token.token,
+                &arg.r_brace.r_brace_token.token,
+                |x| x.package_declaration(arg),
+                |x, name| x.package_declaration_body(arg, Some(name)),
+            );
+        } else {
+            self.package_declaration_body(arg, None);
         }
-        self.newline_list_post(arg.package_declaration_list.is_empty());
-        self.token(&arg.r_brace.r_brace_token.replace("endpackage"));
     }
 
     /// Semantic action for non-terminal 'PackageGroup'
     fn package_group(&mut self, arg: &PackageGroup) {
+        if !package_group_enabled(arg) {
+            self.skip_group(|x| x.package_group(arg));
+            return;
+        }
         for x in &arg.package_group_list {
             self.attribute(&x.attribute);
         }
         match &*arg.package_group_group {
             PackageGroupGroup::LBracePackageGroupGroupListRBrace(x) => {
-                for (i, x) in x.package_group_group_list.iter().enumerate() {
-                    if i != 0 {
-                        self.newline();
+                let mut i = 0;
+                for x in &x.package_group_group_list {
+                    if package_group_enabled(&x.package_group) {
+                        if i != 0 {
+                            self.newline();
+                        }
+                        i += 1;
                     }
                     self.package_group(&x.package_group);
                 }
@@ -2349,14 +4177,31 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'DescriptionGroup'
     fn description_group(&mut self, arg: &DescriptionGroup) {
+        if !description_group_enabled(arg) {
+            self.skip_group(|x| x.description_group(arg));
+            return;
+        }
+        // keep blank line before attributes which are not emitted
+        if let Some(x) = arg.description_group_list.first() {
+            let identifier = x.attribute.identifier.identifier_token.text();
+            let emitted = matches!(identifier.as_str(), "ifdef" | "ifndef" | "sv");
+            let x = &x.attribute.hash.hash_token.token;
+            if !emitted && self.adjust_line && x.line > self.line + 1 {
+                self.newline();
+            }
+        }
         for x in &arg.description_group_list {
             self.attribute(&x.attribute);
         }
         match &*arg.description_group_group {
             DescriptionGroupGroup::LBraceDescriptionGroupGroupListRBrace(x) => {
-                for (i, x) in x.description_group_group_list.iter().enumerate() {
-                    if i != 0 {
-                        self.newline();
+                let mut i = 0;
+                for x in &x.description_group_group_list {
+                    if description_group_enabled(&x.description_group) {
+                        if i != 0 {
+                            self.newline();
+                        }
+                        i += 1;
                     }
                     self.description_group(&x.description_group);
                 }
@@ -2372,6 +4217,15 @@ impl VerylWalker for Emitter {
     fn description_item(&mut self, arg: &DescriptionItem) {
         match arg {
             DescriptionItem::ModuleDeclaration(x) => self.module_declaration(&x.module_declaration),
+            // extern module is declared at SystemVerilog
+            DescriptionItem::ExternModuleDeclaration(_) => (),
+            // interface and package are expanded into modules at Verilog
+            DescriptionItem::InterfaceDeclaration(x) if self.is_verilog() => {
+                self.skip_group(|y| y.interface_declaration(&x.interface_declaration))
+            }
+            DescriptionItem::PackageDeclaration(x) if self.is_verilog() => {
+                self.skip_group(|y| y.package_declaration(&x.package_declaration))
+            }
             DescriptionItem::InterfaceDeclaration(x) => {
                 self.interface_declaration(&x.interface_declaration)
             }
@@ -2392,20 +4246,35 @@ impl VerylWalker for Emitter {
             self.newline();
         }
         for x in &arg.veryl_list {
+            if !description_group_enabled(&x.description_group) || self.is_verilog() {
+                continue;
+            }
             let items: Vec<DescriptionItem> = x.description_group.as_ref().into();
             for item in items {
                 if let DescriptionItem::ImportDeclaration(x) = item {
-                    let mut emitter = Emitter::default();
+                    let mut emitter = Emitter {
+                        build_opt: self.build_opt.clone(),
+                        ..Default::default()
+                    };
                     emitter.import_declaration(&x.import_declaration);
                     self.file_scope_import.push(emitter.as_str().to_string());
                 }
             }
         }
-        for (i, x) in arg.veryl_list.iter().enumerate() {
+        let mut i = 0;
+        for x in &arg.veryl_list {
+            if !description_group_enabled(&x.description_group) {
+                self.description_group(&x.description_group);
+                continue;
+            }
+            if !description_group_emitted(&x.description_group, self.is_verilog()) {
+                continue;
+            }
             if i != 0 {
                 self.newline();
             }
             self.description_group(&x.description_group);
+            i += 1;
         }
         self.newline();
     }
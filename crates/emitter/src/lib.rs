@@ -1,3 +1,6 @@
 pub mod aligner;
 pub mod emitter;
+pub mod source_map;
+mod verilog;
+mod vhdl;
 pub use emitter::Emitter;
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Mapping from the generated codes to the Veryl source codes.
+/// Lines and columns start from 1.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SourceMap {
+    pub version: usize,
+    pub file: PathBuf,
+    /// Number of lines of the generated code
+    pub lines: usize,
+    pub sources: Vec<PathBuf>,
+    pub mappings: Vec<Mapping>,
+}
+
+/// Range of a generated token and the location of the source token
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Mapping {
+    pub line: usize,
+    pub column: usize,
+    pub length: usize,
+    pub source: usize,
+    pub source_line: usize,
+    pub source_column: usize,
+    /// Whether the source token is an identifier
+    pub identifier: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourceMap {
+    pub const VERSION: usize = 2;
+
+    /// Source location of the generated line and column.
+    /// If the line has no mapping (e.g. `endmodule`), the nearest preceding mapping is used.
+    /// If the column is not specified, the first identifier of the line is preferred
+    /// because it points the signal or instance rather than the keyword like `input`.
+    pub fn lookup(&self, line: usize, column: Option<usize>) -> Option<SourceLocation> {
+        if line == 0 || line > self.lines {
+            return None;
+        }
+
+        let end = self.mappings.partition_point(|x| x.line <= line);
+        let start = self.mappings[..end].partition_point(|x| x.line < line);
+
+        let mapping = if start == end {
+            self.mappings[..end].last()?
+        } else {
+            let candidates = &self.mappings[start..end];
+            match column {
+                Some(column) => candidates
+                    .iter()
+                    .rev()
+                    .find(|x| x.column <= column)
+                    .unwrap_or(&candidates[0]),
+                None => candidates
+                    .iter()
+                    .find(|x| x.identifier)
+                    .unwrap_or(&candidates[0]),
+            }
+        };
+
+        let path = self.sources.get(mapping.source)?.clone();
+        Some(SourceLocation {
+            path,
+            line: mapping.source_line,
+            column: mapping.source_column,
+        })
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::HashSet;
+use veryl_analyzer::feature_table;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::{Direction, ParameterValue, Symbol, SymbolKind, Type, TypeKind};
+use veryl_analyzer::symbol_table::{self, SymbolPath};
+use veryl_parser::resource_table::{StrId, TokenId};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::VerylWalker;
+
+/// Key of an assigned variable.
+/// Members of expanded interfaces are distinguished by the member name.
+pub type AssignmentKey = (TokenId, Option<StrId>);
+
+/// Returns whether the symbol is an interface which is expanded to individual signals
+pub fn is_expanded_interface(symbol: &Symbol) -> bool {
+    match &symbol.kind {
+        SymbolKind::Port(x) => matches!(x.direction, Direction::Modport),
+        SymbolKind::Instance(x) => {
+            let path = SymbolPath::new(&x.type_name);
+            matches!(
+                symbol_table::get(&path, &symbol.namespace),
+                Ok(x) if x.found.as_ref().is_some_and(|x| matches!(x.kind, SymbolKind::Interface(_)))
+            )
+        }
+        _ => false,
+    }
+}
+
+pub fn assignment_key(arg: &ExpressionIdentifier) -> Option<AssignmentKey> {
+    let symbol = symbol_table::resolve(arg).ok()?;
+    let head = symbol.full_path.first()?;
+    let member = if is_expanded_interface(head) {
+        symbol.full_path.get(1).map(|x| x.token.text)
+    } else {
+        None
+    };
+    Some((head.token.id, member))
+}
+
+/// Returns the package which the symbol is declared at directly
+pub fn package_of(symbol: &Symbol) -> Option<Symbol> {
+    let paths = &symbol.namespace.paths;
+    if paths.len() < 2 {
+        return None;
+    }
+    let namespace = Namespace {
+        paths: vec![paths[0]],
+    };
+    let package = symbol_table::get(&SymbolPath::new(&[paths[1]]), &namespace)
+        .ok()?
+        .found?;
+    if matches!(package.kind, SymbolKind::Package(_)) {
+        Some(package)
+    } else {
+        None
+    }
+}
+
+/// Returns whether the symbol is a package member which is inlined into modules
+pub fn is_package_member(symbol: &Symbol) -> bool {
+    let depth = match symbol.kind {
+        SymbolKind::Parameter(_) | SymbolKind::Function(_) => 2,
+        SymbolKind::EnumMember(_) => 3,
+        _ => return false,
+    };
+    if symbol.namespace.depth() != depth {
+        return false;
+    }
+    // generic package is not supported
+    match package_of(symbol).map(|x| x.kind) {
+        Some(SymbolKind::Package(x)) => x.generic_parameters.is_empty(),
+        _ => false,
+    }
+}
+
+/// Emitted name of the package member which is inlined into modules
+pub fn package_member_name(symbol: &Symbol) -> String {
+    format!("{}_{}", symbol.namespace, symbol.token.text).replace("::", "_")
+}
+
+/// Namespace of the members declared in the symbol
+pub fn inner_namespace(symbol: &Symbol) -> Namespace {
+    let mut namespace = symbol.namespace.clone();
+    namespace.push(symbol.token.text);
+    namespace
+}
+
+/// Symbols declared in the namespace directly in declaration order
+pub fn members(symbols: &[Symbol], namespace: &Namespace) -> Vec<Symbol> {
+    let mut ret: Vec<Symbol> = symbols
+        .iter()
+        .filter(|x| x.namespace == *namespace)
+        .cloned()
+        .collect();
+    ret.sort_by_key(|x| x.token.id);
+    ret
+}
+
+/// Walker to collect variables assigned in procedural blocks
+#[derive(Default)]
+pub struct ProceduralAssignment {
+    pub assigned: HashSet<AssignmentKey>,
+}
+
+impl VerylWalker for ProceduralAssignment {
+    fn is_enabled(&mut self, arg: &Attribute) -> bool {
+        feature_table::is_enabled(arg)
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) {
+        match &*arg.identifier_statement_group {
+            IdentifierStatementGroup::Assignment(_) => {
+                if let Some(x) = assignment_key(&arg.expression_identifier) {
+                    self.assigned.insert(x);
+                }
+            }
+            IdentifierStatementGroup::FunctionCall(x) => {
+                let ports = match symbol_table::resolve(arg.expression_identifier.as_ref())
+                    .map(|x| x.found.map(|x| x.kind))
+                {
+                    Ok(Some(SymbolKind::Function(x))) => x.ports,
+                    _ => return,
+                };
+                let items: Vec<ArgumentItem> = match x.function_call.function_call_opt {
+                    Some(ref x) => x.argument_list.as_ref().into(),
+                    None => return,
+                };
+                for (port, item) in ports.iter().zip(items.iter()) {
+                    if matches!(
+                        port.property.direction,
+                        Direction::Output | Direction::Inout
+                    ) {
+                        let mut assignee = Assignee::default();
+                        assignee.expression(&item.expression);
+                        for x in &assignee.identifiers {
+                            if let Some(x) = assignment_key(x) {
+                                self.assigned.insert(x);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Assignee {
+    identifiers: Vec<ExpressionIdentifier>,
+}
+
+impl VerylWalker for Assignee {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        self.identifiers.push(arg.clone());
+    }
+}
+
+/// Walker to collect genvars which are declared at the top of module
+#[derive(Default)]
+pub struct Genvar {
+    pub names: Vec<String>,
+}
+
+impl VerylWalker for Genvar {
+    fn is_enabled(&mut self, arg: &Attribute) -> bool {
+        feature_table::is_enabled(arg)
+    }
+
+    fn module_for_declaration(&mut self, arg: &ModuleForDeclaration) {
+        let name = arg.identifier.identifier_token.text();
+        if !self.names.contains(&name) {
+            self.names.push(name);
+        }
+        self.module_named_block(&arg.module_named_block);
+    }
+}
+
+/// Walker to collect packages referred from the syntax tree
+#[derive(Default)]
+pub struct PackageReference {
+    pub packages: Vec<Symbol>,
+}
+
+impl PackageReference {
+    fn push(&mut self, symbol: Symbol) {
+        if !self.packages.iter().any(|x| x.token.id == symbol.token.id) {
+            self.packages.push(symbol);
+        }
+    }
+
+    fn found(&mut self, symbols: &[Symbol]) {
+        for symbol in symbols {
+            if matches!(symbol.kind, SymbolKind::Package(_)) {
+                self.push(symbol.clone());
+            } else if let Some(x) = package_of(symbol) {
+                self.push(x);
+            }
+        }
+    }
+
+    fn r#type(&mut self, arg: &Type, namespace: &Namespace) {
+        for x in &arg.width {
+            self.expression(x);
+        }
+        for x in &arg.array {
+            self.expression(x);
+        }
+        if let TypeKind::UserDefined(ref x) = arg.kind {
+            if let Ok(x) = symbol_table::get(&SymbolPath::new(x), namespace) {
+                self.found(&x.full_path);
+            }
+        }
+    }
+
+    /// Collects packages referred from the members of the package
+    pub fn package(&mut self, symbols: &[Symbol], package: &Symbol) {
+        let namespace = inner_namespace(package);
+        for symbol in members(symbols, &namespace) {
+            match &symbol.kind {
+                SymbolKind::Parameter(x) => {
+                    self.r#type(&x.r#type, &namespace);
+                    if let ParameterValue::Expression(ref x) = x.value {
+                        self.expression(x);
+                    }
+                }
+                SymbolKind::Enum(x) => {
+                    self.r#type(&x.r#type, &namespace);
+                    for member in members(symbols, &inner_namespace(&symbol)) {
+                        if let SymbolKind::EnumMember(ref x) = member.kind {
+                            if let Some(ref x) = x.value {
+                                self.expression(x);
+                            }
+                        }
+                    }
+                }
+                SymbolKind::Struct | SymbolKind::Union => {
+                    let namespace = inner_namespace(&symbol);
+                    for member in members(symbols, &namespace) {
+                        match member.kind {
+                            SymbolKind::StructMember(ref x) => self.r#type(&x.r#type, &namespace),
+                            SymbolKind::UnionMember(ref x) => self.r#type(&x.r#type, &namespace),
+                            _ => (),
+                        }
+                    }
+                }
+                SymbolKind::Function(x) => {
+                    self.r#type(&x.return_type, &namespace);
+                    for port in &x.ports {
+                        if let Some(ref x) = port.property.r#type {
+                            self.r#type(x, &namespace);
+                        }
+                    }
+                    for item in &x.items {
+                        self.function_item(item);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+impl VerylWalker for PackageReference {
+    fn is_enabled(&mut self, arg: &Attribute) -> bool {
+        feature_table::is_enabled(arg)
+    }
+
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) {
+        if let Ok(x) = symbol_table::resolve(arg) {
+            self.found(&x.full_path);
+        }
+    }
+
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        if arg.expression_identifier_opt.is_some() {
+            return;
+        }
+        if let Ok(x) = symbol_table::resolve(arg) {
+            self.found(&x.full_path);
+        }
+        match &*arg.expression_identifier_group {
+            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+                for x in &x.expression_identifier_group_list0 {
+                    self.select(&x.select);
+                }
+            }
+            ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) => {
+                for x in &x.expression_identifier_group_list1 {
+                    self.select(&x.select);
+                }
+                for x in &x.expression_identifier_group_list2 {
+                    for x in &x.expression_identifier_group_list2_list {
+                        self.select(&x.select);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parameter of 'WithParameterList' with the attributes of the enclosing groups and the following comma
+pub type ParameterItem = (Vec<Attribute>, WithParameterItem, Option<Comma>);
+
+/// Flattens 'WithParameterList' into the parameter items
+pub fn parameter_items(arg: &WithParameterList) -> Vec<ParameterItem> {
+    let mut ret = Vec::new();
+    let mut groups = vec![(&arg.with_parameter_group, None)];
+    for (i, x) in arg.with_parameter_list_list.iter().enumerate() {
+        groups[i].1 = Some(x.comma.as_ref().clone());
+        groups.push((&x.with_parameter_group, None));
+    }
+    if let Some(ref x) = arg.with_parameter_list_opt {
+        groups.last_mut().unwrap().1 = Some(x.comma.as_ref().clone());
+    }
+
+    for (group, comma) in groups {
+        let attributes: Vec<Attribute> = group
+            .with_parameter_group_list
+            .iter()
+            .map(|x| x.attribute.as_ref().clone())
+            .collect();
+        match &*group.with_parameter_group_group {
+            WithParameterGroupGroup::LBraceWithParameterListRBrace(x) => {
+                let mut items = parameter_items(&x.with_parameter_list);
+                for (x, _, _) in &mut items {
+                    x.splice(0..0, attributes.iter().cloned());
+                }
+                if let Some((_, _, last)) = items.last_mut() {
+                    *last = comma;
+                }
+                ret.append(&mut items);
+            }
+            WithParameterGroupGroup::WithParameterItem(x) => {
+                ret.push((attributes, x.with_parameter_item.as_ref().clone(), comma));
+            }
+        }
+    }
+    ret
+}
+
+pub fn is_localparam(arg: &WithParameterItem) -> bool {
+    matches!(
+        *arg.with_parameter_item_group,
+        WithParameterItemGroup::Localparam(_)
+    )
+}
+
+/// The first token of 'ScalarType' except type modifiers
+pub fn scalar_type_token(arg: &ScalarType) -> VerylToken {
+    match &*arg.scalar_type_group {
+        ScalarTypeGroup::VariableType(x) => match &*x.variable_type.variable_type_group {
+            VariableTypeGroup::Clock(x) => x.clock.clock_token.clone(),
+            VariableTypeGroup::Reset(x) => x.reset.reset_token.clone(),
+            VariableTypeGroup::Logic(x) => x.logic.logic_token.clone(),
+            VariableTypeGroup::Bit(x) => x.bit.bit_token.clone(),
+            VariableTypeGroup::ScopedIdentifier(x) => {
+                x.scoped_identifier.identifier.identifier_token.clone()
+            }
+        },
+        ScalarTypeGroup::FixedType(x) => match &*x.fixed_type {
+            FixedType::U32(x) => x.u32.u32_token.clone(),
+            FixedType::U64(x) => x.u64.u64_token.clone(),
+            FixedType::I32(x) => x.i32.i32_token.clone(),
+            FixedType::I64(x) => x.i64.i64_token.clone(),
+            FixedType::F32(x) => x.f32.f32_token.clone(),
+            FixedType::F64(x) => x.f64.f64_token.clone(),
+            FixedType::Strin(x) => x.strin.string_token.clone(),
+        },
+    }
+}
+
+/// Sum of widths. Numeric terms are folded.
+pub fn sum(terms: &[String]) -> String {
+    fold(terms, "+", 0, |x, y| x + y)
+}
+
+/// Product of widths. Numeric terms are folded.
+pub fn product(terms: &[String]) -> String {
+    fold(terms, "*", 1, |x, y| x * y)
+}
+
+/// Maximum of widths. Numeric terms are folded.
+pub fn max(terms: &[String]) -> String {
+    let mut value = None;
+    let mut others = Vec::new();
+    for x in terms {
+        match x.parse::<usize>() {
+            Ok(x) => value = Some(value.map_or(x, |y: usize| y.max(x))),
+            Err(_) => others.push(x.clone()),
+        }
+    }
+    if let Some(x) = value {
+        others.push(format!("{x}"));
+    }
+    let mut ret = match others.pop() {
+        Some(x) => x,
+        None => return "0".to_string(),
+    };
+    for x in others {
+        ret = format!("(({x}) > ({ret}) ? ({x}) : ({ret}))");
+    }
+    ret
+}
+
+fn fold<F: Fn(usize, usize) -> usize>(terms: &[String], op: &str, unit: usize, f: F) -> String {
+    let mut value = unit;
+    let mut others = Vec::new();
+    for x in terms {
+        match x.parse::<usize>() {
+            Ok(x) => value = f(value, x),
+            Err(_) => others.push(x),
+        }
+    }
+    if others.is_empty() {
+        return format!("{value}");
+    }
+    if others.len() == 1 && value == unit {
+        return others[0].clone();
+    }
+    let mut ret: Vec<String> = others.iter().map(|x| format!("({x})")).collect();
+    if value != unit {
+        ret.push(format!("{value}"));
+    }
+    ret.join(op)
+}
+
+/// Subtracts one from the width
+pub fn minus_one(x: &str) -> String {
+    if let Ok(x) = x.parse::<usize>() {
+        format!("{}", x.saturating_sub(1))
+    } else if x.chars().all(|x| x.is_ascii_alphanumeric() || x == '_') || is_enclosed(x) {
+        format!("{x}-1")
+    } else {
+        format!("({x})-1")
+    }
+}
+
+/// Returns whether the whole text is enclosed by parentheses
+fn is_enclosed(x: &str) -> bool {
+    if !x.starts_with('(') {
+        return false;
+    }
+    let mut depth = 0;
+    for (i, c) in x.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+        if depth == 0 {
+            return i == x.len() - 1;
+        }
+    }
+    false
+}
@@ -0,0 +1,2602 @@
+use crate::emitter;
+use crate::verilog::{self, PackageReference};
+use std::iter;
+use veryl_analyzer::feature_table;
+use veryl_analyzer::msb_table;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::namespace_table;
+use veryl_analyzer::symbol::{self, Symbol, SymbolKind, Type, TypeKind};
+use veryl_analyzer::symbol_table;
+use veryl_metadata::{Build, ClockType, Format, ResetType};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::VerylWalker;
+
+const RESERVED: &[&str] = &[
+    "abs",
+    "access",
+    "after",
+    "alias",
+    "all",
+    "and",
+    "architecture",
+    "array",
+    "assert",
+    "assume",
+    "attribute",
+    "begin",
+    "block",
+    "body",
+    "buffer",
+    "bus",
+    "case",
+    "component",
+    "configuration",
+    "constant",
+    "context",
+    "cover",
+    "default",
+    "disconnect",
+    "downto",
+    "else",
+    "elsif",
+    "end",
+    "entity",
+    "exit",
+    "fairness",
+    "file",
+    "for",
+    "force",
+    "function",
+    "generate",
+    "generic",
+    "group",
+    "guarded",
+    "if",
+    "impure",
+    "in",
+    "inertial",
+    "inout",
+    "is",
+    "label",
+    "library",
+    "linkage",
+    "literal",
+    "loop",
+    "map",
+    "mod",
+    "nand",
+    "new",
+    "next",
+    "nor",
+    "not",
+    "null",
+    "of",
+    "on",
+    "open",
+    "or",
+    "others",
+    "out",
+    "package",
+    "parameter",
+    "port",
+    "postponed",
+    "procedure",
+    "process",
+    "property",
+    "protected",
+    "pure",
+    "range",
+    "record",
+    "register",
+    "reject",
+    "release",
+    "rem",
+    "report",
+    "restrict",
+    "return",
+    "rol",
+    "ror",
+    "select",
+    "sequence",
+    "severity",
+    "shared",
+    "signal",
+    "sla",
+    "sll",
+    "sra",
+    "srl",
+    "strong",
+    "subtype",
+    "then",
+    "to",
+    "transport",
+    "type",
+    "unaffected",
+    "units",
+    "until",
+    "use",
+    "variable",
+    "vmode",
+    "vprop",
+    "vunit",
+    "wait",
+    "when",
+    "while",
+    "with",
+    "xnor",
+    "xor",
+];
+
+/// Identifier in VHDL.
+/// Names which are not basic identifiers are emitted as extended identifiers.
+fn ident(name: &str) -> String {
+    let basic = name.starts_with(|x: char| x.is_ascii_alphabetic())
+        && !name.ends_with('_')
+        && !name.contains("__")
+        && name.chars().all(|x| x.is_ascii_alphanumeric() || x == '_');
+    if basic && !RESERVED.contains(&name.to_ascii_lowercase().as_str()) {
+        name.to_string()
+    } else {
+        format!("\\{name}\\")
+    }
+}
+
+fn token_ident(token: &VerylToken) -> String {
+    ident(&token.text())
+}
+
+/// Name of the design unit which is prefixed by the project name
+fn unit_name(symbol: &Symbol, build_opt: &Build) -> String {
+    match symbol.kind {
+        SymbolKind::Module(ref x) if x.is_extern => ident(&format!("{}", symbol.token.text)),
+        _ => {
+            let name = symbol.token.text.to_string();
+            ident(&emitter::unit_name(
+                &symbol.namespace.to_string(),
+                &name,
+                build_opt,
+            ))
+        }
+    }
+}
+
+/// Name of the enum member which is prefixed by the enum name like SystemVerilog
+fn enum_member_name(symbol: &Symbol) -> String {
+    match symbol.namespace.paths.last() {
+        Some(x) => ident(&format!("{}_{}", x, symbol.token.text)),
+        None => ident(&format!("{}", symbol.token.text)),
+    }
+}
+
+/// Upper bound of the width
+fn high(width: &str) -> String {
+    match width.parse::<usize>() {
+        Ok(x) => format!("{}", x.saturating_sub(1)),
+        Err(_) => format!("{width} - 1"),
+    }
+}
+
+/// Text of the term which is parenthesized if it is compound
+fn paren(term: &(String, bool)) -> String {
+    if term.1 {
+        format!("({})", term.0)
+    } else {
+        term.0.clone()
+    }
+}
+
+fn indent_lines(lines: Vec<String>, unit: &str) -> impl Iterator<Item = String> + '_ {
+    lines.into_iter().map(move |x| {
+        if x.is_empty() {
+            x
+        } else {
+            format!("{unit}{x}")
+        }
+    })
+}
+
+/// How operators of a level are chained
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Chain {
+    /// Arithmetic operators are left associative
+    Any,
+    /// Logical operators can be chained only if they are the same
+    Same,
+    /// Relational operators can't be chained
+    Never,
+}
+
+fn chain(first: (String, bool), rest: Vec<(&str, (String, bool))>, kind: Chain) -> (String, bool) {
+    if rest.is_empty() {
+        return first;
+    }
+    let mut ret = paren(&first);
+    let mut prev: Option<&str> = None;
+    for (op, term) in rest {
+        let wrap = match kind {
+            Chain::Any => false,
+            Chain::Same => prev.is_some_and(|x| x != op),
+            Chain::Never => prev.is_some(),
+        };
+        if wrap {
+            ret = format!("({ret})");
+        }
+        ret = format!("{ret} {op} {}", paren(&term));
+        prev = Some(op);
+    }
+    (ret, true)
+}
+
+/// Kind of the value which decides literal conversion and operators
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Bit,
+    Vector { signed: bool },
+    Integer,
+    Other,
+}
+
+impl Kind {
+    fn is_logic(&self) -> bool {
+        matches!(self, Kind::Bit | Kind::Vector { .. })
+    }
+}
+
+fn type_kind(r#type: &Type) -> Kind {
+    match r#type.kind {
+        TypeKind::Clock | TypeKind::Reset | TypeKind::Logic | TypeKind::Bit => {
+            if r#type.width.is_empty() {
+                Kind::Bit
+            } else {
+                let signed = r#type
+                    .modifier
+                    .iter()
+                    .any(|x| matches!(x, symbol::TypeModifier::Signed));
+                Kind::Vector { signed }
+            }
+        }
+        TypeKind::U32 | TypeKind::U64 | TypeKind::I32 | TypeKind::I64 => Kind::Integer,
+        _ => Kind::Other,
+    }
+}
+
+fn identifier_selects(arg: &ExpressionIdentifier) -> Vec<&Select> {
+    match &*arg.expression_identifier_group {
+        ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+            x.expression_identifier_group_list0.iter().map(|x| x.select.as_ref()).collect()
+        }
+        ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) => {
+            match x.expression_identifier_group_list2.last() {
+                Some(x) => x.expression_identifier_group_list2_list.iter().map(|x| x.select.as_ref()).collect(),
+                None => x.expression_identifier_group_list1.iter().map(|x| x.select.as_ref()).collect(),
+            }
+        }
+    }
+}
+
+fn identifier_kind(arg: &ExpressionIdentifier) -> Kind {
+    let symbol = match symbol_table::resolve(arg) {
+        Ok(x) => match x.found {
+            Some(x) => x,
+            None => return Kind::Other,
+        },
+        Err(_) => return Kind::Other,
+    };
+    let r#type = match symbol.kind {
+        SymbolKind::Variable(x) => x.r#type,
+        SymbolKind::Port(x) => match x.r#type {
+            Some(x) => x,
+            None => return Kind::Other,
+        },
+        SymbolKind::Parameter(x) => x.r#type,
+        SymbolKind::StructMember(x) => x.r#type,
+        SymbolKind::UnionMember(x) => x.r#type,
+        SymbolKind::Genvar => return Kind::Integer,
+        _ => return Kind::Other,
+    };
+    let selects = identifier_selects(arg);
+    let arrays = r#type.array.len();
+    if selects.len() < arrays {
+        Kind::Other
+    } else if selects.len() == arrays {
+        type_kind(&r#type)
+    } else if selects[selects.len() - 1].select_opt.is_some() {
+        match type_kind(&r#type) {
+            Kind::Vector { signed } => Kind::Vector { signed },
+            _ => Kind::Vector { signed: false },
+        }
+    } else {
+        Kind::Bit
+    }
+}
+
+/// Walker to check whether the expression refers logic values
+#[derive(Default)]
+struct LogicReference {
+    found: bool,
+}
+
+impl VerylWalker for LogicReference {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        if identifier_kind(arg).is_logic() {
+            self.found = true;
+        }
+    }
+}
+
+fn has_logic<F: FnOnce(&mut LogicReference)>(f: F) -> bool {
+    let mut walker = LogicReference::default();
+    f(&mut walker);
+    walker.found
+}
+
+/// The factor if the expression consists of only one factor
+fn single_factor(arg: &Expression) -> Option<&Factor> {
+    single_comparison(arg).and_then(single_factor05)
+}
+
+/// The comparison if the expression has no logical and bitwise operators
+fn single_comparison(arg: &Expression) -> Option<&Expression05> {
+    if !arg.expression_list.is_empty() {
+        return None;
+    }
+    let x = &arg.expression01;
+    if !x.expression01_list.is_empty() {
+        return None;
+    }
+    single_comparison02(&x.expression02)
+}
+
+fn single_comparison02(arg: &Expression02) -> Option<&Expression05> {
+    if !arg.expression02_list.is_empty() {
+        return None;
+    }
+    let x = &arg.expression03;
+    if !x.expression03_list.is_empty() {
+        return None;
+    }
+    let x = &x.expression04;
+    if !x.expression04_list.is_empty() {
+        return None;
+    }
+    Some(&x.expression05)
+}
+
+/// Whether the expression is a comparison of non-logic values, which results in boolean
+fn is_boolean(arg: &Expression) -> bool {
+    let x = match single_comparison(arg) {
+        Some(x) => x,
+        None => return false,
+    };
+    if !x.expression05_list.is_empty() {
+        return !has_logic(|y| y.expression05(x));
+    }
+    let x = &x.expression06;
+    if !x.expression06_list.is_empty() {
+        return !has_logic(|y| y.expression06(x));
+    }
+    match single_factor07(&x.expression07) {
+        Some(Factor::LParenExpressionRParen(x)) => is_boolean(&x.expression),
+        Some(Factor::InsideExpression(x)) => {
+            !has_logic(|y| y.expression(&x.inside_expression.expression))
+        }
+        Some(Factor::OutsideExpression(x)) => {
+            !has_logic(|y| y.expression(&x.outside_expression.expression))
+        }
+        _ => false,
+    }
+}
+
+fn single_factor05(arg: &Expression05) -> Option<&Factor> {
+    if !arg.expression05_list.is_empty() {
+        return None;
+    }
+    single_factor06(&arg.expression06)
+}
+
+fn single_factor06(arg: &Expression06) -> Option<&Factor> {
+    if !arg.expression06_list.is_empty() {
+        return None;
+    }
+    single_factor07(&arg.expression07)
+}
+
+fn single_factor07(arg: &Expression07) -> Option<&Factor> {
+    if !arg.expression07_list.is_empty() {
+        return None;
+    }
+    let x = &arg.expression08;
+    if !x.expression08_list.is_empty() {
+        return None;
+    }
+    let x = &x.expression09;
+    if !x.expression09_list.is_empty() {
+        return None;
+    }
+    let x = &x.expression10;
+    if !x.expression10_list.is_empty() {
+        return None;
+    }
+    let x = &x.expression11;
+    if !x.expression11_list.is_empty() {
+        return None;
+    }
+    let x = &x.expression12;
+    if !x.expression12_list.is_empty() {
+        return None;
+    }
+    Some(&x.factor)
+}
+
+/// Text of the decimal number if the factor is a decimal number
+fn decimal(arg: &Factor) -> Option<String> {
+    if let Factor::Number(x) = arg {
+        if let Number::IntegralNumber(x) = &*x.number {
+            if let IntegralNumber::BaseLess(x) = &*x.integral_number {
+                return Some(x.base_less.base_less_token.text().replace('_', ""));
+            }
+        }
+    }
+    None
+}
+
+/// Character of the all-bit number if the factor is an all-bit number
+fn all_bit(arg: &Factor) -> Option<char> {
+    if let Factor::Number(x) = arg {
+        if let Number::IntegralNumber(x) = &*x.number {
+            if let IntegralNumber::AllBit(x) = &*x.integral_number {
+                let text = x.all_bit.all_bit_token.text();
+                return text.chars().last().map(|x| x.to_ascii_uppercase());
+            }
+        }
+    }
+    None
+}
+
+fn factor_kind(arg: &Factor) -> Option<Kind> {
+    match arg {
+        Factor::ExpressionIdentifierFactorOpt(x) if x.factor_opt.is_none() => {
+            Some(identifier_kind(&x.expression_identifier))
+        }
+        _ => None,
+    }
+}
+
+fn groups_enabled<'a, T: 'a, I: Iterator<Item = &'a T>>(
+    attributes: I,
+    f: fn(&T) -> &Attribute,
+) -> bool {
+    attributes
+        .into_iter()
+        .all(|x| feature_table::is_enabled(f(x)))
+}
+
+fn port_items(arg: &PortDeclarationList) -> Vec<PortDeclarationItem> {
+    let mut ret = Vec::new();
+    let groups = iter::once(&arg.port_declaration_group).chain(
+        arg.port_declaration_list_list
+            .iter()
+            .map(|x| &x.port_declaration_group),
+    );
+    for x in groups {
+        if !groups_enabled(x.port_declaration_group_list.iter(), |x| &x.attribute) {
+            continue;
+        }
+        match &*x.port_declaration_group_group {
+            PortDeclarationGroupGroup::LBracePortDeclarationListRBrace(x) => {
+                ret.append(&mut port_items(&x.port_declaration_list));
+            }
+            PortDeclarationGroupGroup::PortDeclarationItem(x) => {
+                ret.push(x.port_declaration_item.as_ref().clone());
+            }
+        }
+    }
+    ret
+}
+
+fn with_parameter_items(arg: &WithParameterList) -> Vec<WithParameterItem> {
+    let mut ret = Vec::new();
+    let groups = iter::once(&arg.with_parameter_group).chain(
+        arg.with_parameter_list_list
+            .iter()
+            .map(|x| &x.with_parameter_group),
+    );
+    for x in groups {
+        if !groups_enabled(x.with_parameter_group_list.iter(), |x| &x.attribute) {
+            continue;
+        }
+        match &*x.with_parameter_group_group {
+            WithParameterGroupGroup::LBraceWithParameterListRBrace(x) => {
+                ret.append(&mut with_parameter_items(&x.with_parameter_list));
+            }
+            WithParameterGroupGroup::WithParameterItem(x) => {
+                ret.push(x.with_parameter_item.as_ref().clone());
+            }
+        }
+    }
+    ret
+}
+
+fn inst_parameter_items(arg: &InstParameterList) -> Vec<InstParameterItem> {
+    let mut ret = Vec::new();
+    let groups = iter::once(&arg.inst_parameter_group).chain(
+        arg.inst_parameter_list_list
+            .iter()
+            .map(|x| &x.inst_parameter_group),
+    );
+    for x in groups {
+        if !groups_enabled(x.inst_parameter_group_list.iter(), |x| &x.attribute) {
+            continue;
+        }
+        match &*x.inst_parameter_group_group {
+            InstParameterGroupGroup::LBraceInstParameterListRBrace(x) => {
+                ret.append(&mut inst_parameter_items(&x.inst_parameter_list));
+            }
+            InstParameterGroupGroup::InstParameterItem(x) => {
+                ret.push(x.inst_parameter_item.as_ref().clone());
+            }
+        }
+    }
+    ret
+}
+
+fn inst_port_items(arg: &InstPortList) -> Vec<InstPortItem> {
+    let mut ret = Vec::new();
+    let groups = iter::once(&arg.inst_port_group)
+        .chain(arg.inst_port_list_list.iter().map(|x| &x.inst_port_group));
+    for x in groups {
+        if !groups_enabled(x.inst_port_group_list.iter(), |x| &x.attribute) {
+            continue;
+        }
+        match &*x.inst_port_group_group {
+            InstPortGroupGroup::LBraceInstPortListRBrace(x) => {
+                ret.append(&mut inst_port_items(&x.inst_port_list));
+            }
+            InstPortGroupGroup::InstPortItem(x) => {
+                ret.push(x.inst_port_item.as_ref().clone());
+            }
+        }
+    }
+    ret
+}
+
+fn enum_items(arg: &EnumList) -> Vec<EnumItem> {
+    let mut ret = Vec::new();
+    let groups =
+        iter::once(&arg.enum_group).chain(arg.enum_list_list.iter().map(|x| &x.enum_group));
+    for x in groups {
+        if !groups_enabled(x.enum_group_list.iter(), |x| &x.attribute) {
+            continue;
+        }
+        match &*x.enum_group_group {
+            EnumGroupGroup::LBraceEnumListRBrace(x) => ret.append(&mut enum_items(&x.enum_list)),
+            EnumGroupGroup::EnumItem(x) => ret.push(x.enum_item.as_ref().clone()),
+        }
+    }
+    ret
+}
+
+fn struct_items(arg: &StructList) -> Vec<StructItem> {
+    let mut ret = Vec::new();
+    let groups =
+        iter::once(&arg.struct_group).chain(arg.struct_list_list.iter().map(|x| &x.struct_group));
+    for x in groups {
+        if !groups_enabled(x.struct_group_list.iter(), |x| &x.attribute) {
+            continue;
+        }
+        match &*x.struct_group_group {
+            StructGroupGroup::LBraceStructListRBrace(x) => {
+                ret.append(&mut struct_items(&x.struct_list))
+            }
+            StructGroupGroup::StructItem(x) => ret.push(x.struct_item.as_ref().clone()),
+        }
+    }
+    ret
+}
+
+/// Declarations and concurrent statements of an architecture or a generate block
+#[derive(Default)]
+struct Region {
+    decls: Vec<String>,
+    stmts: Vec<String>,
+    decls_multi: bool,
+    stmts_multi: bool,
+}
+
+impl Region {
+    fn push(lines: &mut Vec<String>, last_multi: &mut bool, mut x: Vec<String>) {
+        let multi = x.len() > 1;
+        if !lines.is_empty() && (multi || *last_multi) {
+            lines.push(String::new());
+        }
+        lines.append(&mut x);
+        *last_multi = multi;
+    }
+
+    fn decl(&mut self, x: Vec<String>) {
+        Self::push(&mut self.decls, &mut self.decls_multi, x);
+    }
+
+    fn stmt(&mut self, x: Vec<String>) {
+        Self::push(&mut self.stmts, &mut self.stmts_multi, x);
+    }
+}
+
+/// Function which is being emitted
+struct FunctionContext {
+    return_kind: Kind,
+    return_length: String,
+}
+
+/// Emitter of VHDL-2008.
+/// Design units are built from the syntax tree and the symbol table directly,
+/// so comments and the source layout are not preserved.
+pub struct VhdlEmitter {
+    build_opt: Build,
+    unit: String,
+    string: String,
+    integer: bool,
+    math_real: bool,
+    reset: Option<String>,
+    function: Option<FunctionContext>,
+}
+
+impl VhdlEmitter {
+    pub fn new(build_opt: &Build, format_opt: &Format) -> Self {
+        Self {
+            build_opt: build_opt.clone(),
+            unit: " ".repeat(format_opt.indent_width),
+            string: String::new(),
+            integer: false,
+            math_real: false,
+            reset: None,
+            function: None,
+        }
+    }
+
+    pub fn emit(&mut self, input: &Veryl) {
+        let mut units = Vec::new();
+        for x in &input.veryl_list {
+            self.description_group(&x.description_group, &mut units);
+        }
+        let units: Vec<String> = units.iter().map(|x| x.join("\n")).collect();
+        self.string = units.join("\n\n");
+        if !self.string.is_empty() {
+            self.string.push('\n');
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    fn indented(&self, lines: Vec<String>) -> Vec<String> {
+        indent_lines(lines, &self.unit).collect()
+    }
+
+    fn description_group(&mut self, arg: &DescriptionGroup, units: &mut Vec<Vec<String>>) {
+        if !groups_enabled(arg.description_group_list.iter(), |x| &x.attribute) {
+            return;
+        }
+        match &*arg.description_group_group {
+            DescriptionGroupGroup::LBraceDescriptionGroupGroupListRBrace(x) => {
+                for x in &x.description_group_group_list {
+                    self.description_group(&x.description_group, units);
+                }
+            }
+            DescriptionGroupGroup::DescriptionItem(x) => match &*x.description_item {
+                DescriptionItem::ModuleDeclaration(x) => {
+                    units.push(self.module_declaration(&x.module_declaration));
+                }
+                DescriptionItem::PackageDeclaration(x) => {
+                    units.push(self.package_declaration(&x.package_declaration));
+                }
+                DescriptionItem::InterfaceDeclaration(x) => {
+                    let name = x.interface_declaration.identifier.identifier_token.text();
+                    units.push(vec![format!(
+                        "-- interface {name} is not supported in VHDL"
+                    )]);
+                }
+                // extern modules are declared in other files
+                DescriptionItem::ExternModuleDeclaration(_) => (),
+                // packages are referred by use clauses of each design unit
+                DescriptionItem::ImportDeclaration(_) => (),
+            },
+        }
+    }
+
+    fn context_clause(&self, packages: &[Symbol], own: Option<&Symbol>) -> Vec<String> {
+        let mut ret = vec![
+            "library ieee;".to_string(),
+            "use ieee.std_logic_1164.all;".to_string(),
+            "use ieee.numeric_std.all;".to_string(),
+        ];
+        if self.math_real {
+            ret.push("use ieee.math_real.all;".to_string());
+        }
+        for x in packages {
+            if own.is_some_and(|own| own.token.id == x.token.id) {
+                continue;
+            }
+            if matches!(x.kind, SymbolKind::Package(ref x) if !x.generic_parameters.is_empty()) {
+                continue;
+            }
+            ret.push(format!("use work.{}.all;", unit_name(x, &self.build_opt)));
+        }
+        ret
+    }
+
+    fn declaration_name(&self, arg: &Identifier) -> String {
+        match symbol_table::resolve(arg) {
+            Ok(x) => match x.found {
+                Some(x) => unit_name(&x, &self.build_opt),
+                None => token_ident(&arg.identifier_token),
+            },
+            Err(_) => token_ident(&arg.identifier_token),
+        }
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Vec<String> {
+        let text = arg.identifier.identifier_token.text();
+        if arg.module_declaration_opt.is_some() {
+            return vec![format!("-- generic module {text} is not supported in VHDL")];
+        }
+        let name = self.declaration_name(&arg.identifier);
+        let mut packages = PackageReference::default();
+        packages.module_declaration(arg);
+        self.math_real = false;
+
+        let mut body = Region::default();
+        let mut generics = Vec::new();
+        if let Some(ref x) = arg.module_declaration_opt0 {
+            if let Some(ref x) = x.with_parameter.with_parameter_opt {
+                for item in with_parameter_items(&x.with_parameter_list) {
+                    match self.with_parameter_item(&item) {
+                        (true, x) => generics.push(x),
+                        (false, x) => body.decl(vec![x]),
+                    }
+                }
+            }
+        }
+        let mut ports = Vec::new();
+        if let Some(ref x) = arg.module_declaration_opt1 {
+            if let Some(ref x) = x.port_declaration.port_declaration_opt {
+                for item in port_items(&x.port_declaration_list) {
+                    ports.push(self.port_declaration_item(&item));
+                }
+            }
+        }
+        for x in &arg.module_declaration_list {
+            self.module_group(&x.module_group, &mut body);
+        }
+
+        let mut ret = self.context_clause(&packages.packages, None);
+        ret.push(String::new());
+        ret.push(format!("entity {name} is"));
+        ret.append(&mut self.interface_list("generic", generics));
+        ret.append(&mut self.interface_list("port", ports));
+        ret.push(format!("end entity {name};"));
+        ret.push(String::new());
+        ret.push(format!("architecture rtl of {name} is"));
+        ret.append(&mut self.indented(body.decls));
+        ret.push("begin".to_string());
+        ret.append(&mut self.indented(body.stmts));
+        ret.push("end architecture rtl;".to_string());
+        ret
+    }
+
+    /// Generic or port clause. Items starting with "--" are comments.
+    fn interface_list(&self, keyword: &str, items: Vec<String>) -> Vec<String> {
+        let count = items.iter().filter(|x| !x.starts_with("--")).count();
+        if items.is_empty() {
+            return Vec::new();
+        }
+        let mut lines = Vec::new();
+        let mut i = 0;
+        for x in items {
+            if x.starts_with("--") {
+                lines.push(x);
+            } else {
+                i += 1;
+                if i == count {
+                    lines.push(x);
+                } else {
+                    lines.push(format!("{x};"));
+                }
+            }
+        }
+        let mut ret = vec![format!("{}{keyword} (", self.unit)];
+        for x in indent_lines(lines, &self.unit) {
+            ret.push(format!("{}{x}", self.unit));
+        }
+        ret.push(format!("{});", self.unit));
+        ret
+    }
+
+    /// Returns whether the item is a generic, and the generic or the constant declaration
+    fn with_parameter_item(&mut self, arg: &WithParameterItem) -> (bool, String) {
+        let name = token_ident(&arg.identifier.identifier_token);
+        let generic = matches!(
+            &*arg.with_parameter_item_group,
+            WithParameterItemGroup::Parameter(_)
+        );
+        match &*arg.with_parameter_item_group0 {
+            WithParameterItemGroup0::ArrayTypeEquExpression(x) => {
+                let namespace = namespace_table::get(arg.identifier.identifier_token.token.id)
+                    .unwrap_or_default();
+                let (r#type, value) = self.constant(&x.array_type, &x.expression, &namespace);
+                if generic {
+                    (true, format!("{name} : {type} := {value}"))
+                } else {
+                    (false, format!("constant {name} : {type} := {value};"))
+                }
+            }
+            WithParameterItemGroup0::TypeEquTypeExpression(_) => (
+                generic,
+                format!("-- type parameter {name} is not supported in VHDL"),
+            ),
+        }
+    }
+
+    /// Type and value of the constant
+    fn constant(
+        &mut self,
+        r#type: &ArrayType,
+        value: &Expression,
+        namespace: &Namespace,
+    ) -> (String, String) {
+        let r#type: Type = r#type.into();
+        let kind = type_kind(&r#type);
+        let text = match r#type.kind {
+            TypeKind::U32 | TypeKind::U64 => "natural".to_string(),
+            TypeKind::I32 | TypeKind::I64 => "integer".to_string(),
+            _ => self.type_text(&r#type, namespace),
+        };
+        let length = self.width(&r#type);
+        let value = self.value(value, kind, &length);
+        (text, value)
+    }
+
+    fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> String {
+        let name = token_ident(&arg.identifier.identifier_token);
+        match &*arg.port_declaration_item_group {
+            PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
+                let direction = match &*x.direction {
+                    Direction::Input(_) => "in",
+                    Direction::Output(_) => "out",
+                    Direction::Inout(_) | Direction::Ref(_) => "inout",
+                    Direction::Modport(_) => {
+                        return format!("-- modport port {name} is not supported in VHDL");
+                    }
+                };
+                let r#type: Type = x.array_type.as_ref().into();
+                if !r#type.array.is_empty() {
+                    return format!("-- array port {name} is not supported in VHDL");
+                }
+                let namespace = namespace_table::get(arg.identifier.identifier_token.token.id)
+                    .unwrap_or_default();
+                let r#type = self.type_text(&r#type, &namespace);
+                format!("{name} : {direction} {type}")
+            }
+            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(_) => {
+                format!("-- interface port {name} is not supported in VHDL")
+            }
+        }
+    }
+
+    /// Width of the type as the product of all dimensions
+    fn width(&mut self, r#type: &Type) -> String {
+        let integer = self.integer;
+        self.integer = true;
+        let width: Vec<String> = r#type.width.iter().map(|x| self.expression(x)).collect();
+        self.integer = integer;
+        verilog::product(&width)
+    }
+
+    /// Element type of the type in VHDL
+    fn type_text(&mut self, r#type: &Type, namespace: &Namespace) -> String {
+        match &r#type.kind {
+            TypeKind::Clock | TypeKind::Reset | TypeKind::Logic | TypeKind::Bit => {
+                match type_kind(r#type) {
+                    Kind::Vector { signed } => {
+                        let width = self.width(r#type);
+                        let mark = if signed { "signed" } else { "unsigned" };
+                        format!("{mark}({} downto 0)", high(&width))
+                    }
+                    _ => "std_logic".to_string(),
+                }
+            }
+            TypeKind::U32 | TypeKind::U64 => "natural".to_string(),
+            TypeKind::I32 | TypeKind::I64 => "integer".to_string(),
+            TypeKind::F32 | TypeKind::F64 => "real".to_string(),
+            TypeKind::String => "string".to_string(),
+            TypeKind::Type => "type".to_string(),
+            TypeKind::UserDefined(x) => {
+                let path = symbol_table::SymbolPath::new(x);
+                match symbol_table::get(&path, namespace) {
+                    Ok(symbol) if symbol.found.is_some() => {
+                        let symbol = symbol.found.unwrap();
+                        ident(&format!("{}", symbol.token.text))
+                    }
+                    _ => ident(&format!("{}", x[x.len() - 1])),
+                }
+            }
+        }
+    }
+
+    /// Type mark of the type which is used as function return type
+    fn type_mark(&mut self, r#type: &Type, namespace: &Namespace) -> String {
+        match type_kind(r#type) {
+            Kind::Vector { signed: true } => "signed".to_string(),
+            Kind::Vector { signed: false } => "unsigned".to_string(),
+            _ => self.type_text(r#type, namespace),
+        }
+    }
+
+    /// Object declarations. Array types are declared for each object.
+    fn object_declaration(&mut self, class: &str, name: &Identifier, r#type: &Type) -> Vec<String> {
+        let text = name.identifier_token.text();
+        let namespace = namespace_table::get(name.identifier_token.token.id).unwrap_or_default();
+        let mut ret = Vec::new();
+        let mut element = self.type_text(r#type, &namespace);
+        let integer = self.integer;
+        self.integer = true;
+        for (i, x) in r#type.array.iter().enumerate().rev() {
+            let type_name = if i == 0 {
+                ident(&format!("{text}_t"))
+            } else {
+                ident(&format!("{text}_t{i}"))
+            };
+            let size = self.expression(x);
+            ret.push(format!(
+                "type {type_name} is array (0 to {}) of {element};",
+                high(&size)
+            ));
+            element = type_name;
+        }
+        self.integer = integer;
+        ret.push(format!("{class} {} : {element};", ident(&text)));
+        ret
+    }
+
+    fn module_group(&mut self, arg: &ModuleGroup, region: &mut Region) {
+        if !groups_enabled(arg.module_group_list.iter(), |x| &x.attribute) {
+            return;
+        }
+        match &*arg.module_group_group {
+            ModuleGroupGroup::LBraceModuleGroupGroupListRBrace(x) => {
+                for x in &x.module_group_group_list {
+                    self.module_group(&x.module_group, region);
+                }
+            }
+            ModuleGroupGroup::ModuleItem(x) => self.module_item(&x.module_item, region),
+        }
+    }
+
+    fn module_item(&mut self, arg: &ModuleItem, region: &mut Region) {
+        match arg {
+            ModuleItem::VarDeclaration(x) => {
+                let x = &x.var_declaration;
+                let r#type: Type = x.array_type.as_ref().into();
+                region.decl(self.object_declaration("signal", &x.identifier, &r#type));
+                if let Some(ref y) = x.var_declaration_opt0 {
+                    let target = token_ident(&x.identifier.identifier_token);
+                    let value = self.assigned_value(
+                        &y.expression,
+                        type_kind(&r#type),
+                        &format!("{target}'length"),
+                    );
+                    region.stmt(vec![format!("{target} <= {value};")]);
+                }
+            }
+            ModuleItem::InstDeclaration(x) => {
+                region.stmt(self.inst_declaration(&x.inst_declaration))
+            }
+            ModuleItem::LocalparamDeclaration(x) => {
+                region.decl(vec![self.localparam_declaration(&x.localparam_declaration)]);
+            }
+            ModuleItem::AlwaysFfDeclaration(x) => {
+                region.stmt(self.always_ff_declaration(&x.always_ff_declaration));
+            }
+            ModuleItem::AlwaysCombDeclaration(x) => {
+                let statements: Vec<&Statement> = x
+                    .always_comb_declaration
+                    .always_comb_declaration_list
+                    .iter()
+                    .map(|x| x.statement.as_ref())
+                    .collect();
+                region.stmt(self.process("process (all)", &statements, false));
+            }
+            ModuleItem::AssignDeclaration(x) => {
+                let x = &x.assign_declaration;
+                let target = self.hierarchical_identifier(&x.hierarchical_identifier);
+                let kind = self.hierarchical_identifier_kind(&x.hierarchical_identifier);
+                let value = self.assigned_value(&x.expression, kind, &format!("{target}'length"));
+                region.stmt(vec![format!("{target} <= {value};")]);
+            }
+            ModuleItem::FunctionDeclaration(x) => {
+                region.decl(self.function_declaration(&x.function_declaration).1);
+            }
+            ModuleItem::ModuleIfDeclaration(x) => {
+                region.stmt(self.module_if_declaration(&x.module_if_declaration));
+            }
+            ModuleItem::ModuleForDeclaration(x) => {
+                region.stmt(self.module_for_declaration(&x.module_for_declaration));
+            }
+            ModuleItem::EnumDeclaration(x) => {
+                region.decl(self.enum_declaration(&x.enum_declaration))
+            }
+            ModuleItem::StructDeclaration(x) => {
+                region.decl(self.struct_declaration(&x.struct_declaration));
+            }
+            ModuleItem::UnionDeclaration(x) => {
+                let name = x.union_declaration.identifier.identifier_token.text();
+                region.decl(vec![format!("-- union {name} is not supported in VHDL")]);
+            }
+            ModuleItem::ModuleNamedBlock(x) => {
+                region.stmt(self.module_named_block(&x.module_named_block));
+            }
+            // packages are referred by use clauses
+            ModuleItem::ImportDeclaration(_) => (),
+            ModuleItem::InitialDeclaration(x) => {
+                let statements: Vec<&Statement> = x
+                    .initial_declaration
+                    .initial_declaration_list
+                    .iter()
+                    .map(|x| x.statement.as_ref())
+                    .collect();
+                region.stmt(self.process("process", &statements, true));
+            }
+            ModuleItem::FinalDeclaration(_) => {
+                region.stmt(vec!["-- final is not supported in VHDL".to_string()]);
+            }
+            ModuleItem::EmbedDeclaration(_) | ModuleItem::IncludeDeclaration(_) => {
+                region.stmt(vec![
+                    "-- embedded SystemVerilog is not supported in VHDL".to_string()
+                ]);
+            }
+        }
+    }
+
+    fn localparam_declaration(&mut self, arg: &LocalparamDeclaration) -> String {
+        let name = token_ident(&arg.identifier.identifier_token);
+        match &*arg.localparam_declaration_group {
+            LocalparamDeclarationGroup::ArrayTypeEquExpression(x) => {
+                let namespace = namespace_table::get(arg.identifier.identifier_token.token.id)
+                    .unwrap_or_default();
+                let (r#type, value) = self.constant(&x.array_type, &x.expression, &namespace);
+                format!("constant {name} : {type} := {value};")
+            }
+            LocalparamDeclarationGroup::TypeEquTypeExpression(_) => {
+                format!("-- type localparam {name} is not supported in VHDL")
+            }
+        }
+    }
+
+    fn process(&mut self, head: &str, statements: &[&Statement], wait: bool) -> Vec<String> {
+        let mut body = self.statements(statements.iter().copied());
+        if wait {
+            body.push("wait;".to_string());
+        }
+        let mut ret = vec![head.to_string(), "begin".to_string()];
+        ret.append(&mut self.indented(body));
+        ret.push("end process;".to_string());
+        ret
+    }
+
+    fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) -> Vec<String> {
+        let clock = self.hierarchical_identifier(&arg.always_ff_clock.hierarchical_identifier);
+        let edge = match arg.always_ff_clock.always_ff_clock_opt {
+            Some(ref x) => match &*x.always_ff_clock_opt_group {
+                AlwaysFfClockOptGroup::Posedge(_) => ClockType::PosEdge,
+                AlwaysFfClockOptGroup::Negedge(_) => ClockType::NegEdge,
+            },
+            None => self.build_opt.clock_type,
+        };
+        let edge = match edge {
+            ClockType::PosEdge => format!("rising_edge({clock})"),
+            ClockType::NegEdge => format!("falling_edge({clock})"),
+        };
+
+        let mut sensitivity = vec![clock];
+        let mut is_async = false;
+        if let Some(ref x) = arg.always_ff_declaration_opt {
+            let reset = self.hierarchical_identifier(&x.always_ff_reset.hierarchical_identifier);
+            let reset_type = match x.always_ff_reset.always_ff_reset_opt {
+                Some(ref x) => match &*x.always_ff_reset_opt_group {
+                    AlwaysFfResetOptGroup::AsyncLow(_) => ResetType::AsyncLow,
+                    AlwaysFfResetOptGroup::AsyncHigh(_) => ResetType::AsyncHigh,
+                    AlwaysFfResetOptGroup::SyncLow(_) => ResetType::SyncLow,
+                    AlwaysFfResetOptGroup::SyncHigh(_) => ResetType::SyncHigh,
+                },
+                None => self.build_opt.reset_type,
+            };
+            let level = match reset_type {
+                ResetType::AsyncLow | ResetType::SyncLow => '0',
+                ResetType::AsyncHigh | ResetType::SyncHigh => '1',
+            };
+            is_async = matches!(reset_type, ResetType::AsyncLow | ResetType::AsyncHigh);
+            if is_async {
+                sensitivity.push(reset.clone());
+            }
+            self.reset = Some(format!("{reset} = '{level}'"));
+        }
+
+        let statements: Vec<&Statement> = arg
+            .always_ff_declaration_list
+            .iter()
+            .map(|x| x.statement.as_ref())
+            .collect();
+        let mut body = Vec::new();
+        match statements.as_slice() {
+            // asynchronous reset is checked before the clock edge
+            [Statement::IfResetStatement(x)] if is_async => {
+                let x = &x.if_reset_statement;
+                let reset = self.reset.clone().unwrap_or_default();
+                body.push(format!("if {reset} then"));
+                let mut lines = self.statements(
+                    x.if_reset_statement_list
+                        .iter()
+                        .map(|x| x.statement.as_ref()),
+                );
+                body.append(&mut self.indented(lines));
+                body.push(format!("elsif {edge} then"));
+                lines = Vec::new();
+                for (i, y) in x.if_reset_statement_list0.iter().enumerate() {
+                    let cond = self.condition(&y.expression);
+                    let keyword = if i == 0 { "if" } else { "elsif" };
+                    lines.push(format!("{keyword} {cond} then"));
+                    let mut stmts = self.statements(
+                        y.if_reset_statement_list0_list
+                            .iter()
+                            .map(|x| x.statement.as_ref()),
+                    );
+                    lines.append(&mut self.indented(stmts));
+                    stmts = Vec::new();
+                    if i == x.if_reset_statement_list0.len() - 1 {
+                        if let Some(ref y) = x.if_reset_statement_opt {
+                            lines.push("else".to_string());
+                            stmts = self.statements(
+                                y.if_reset_statement_opt_list
+                                    .iter()
+                                    .map(|x| x.statement.as_ref()),
+                            );
+                        }
+                        lines.append(&mut self.indented(stmts));
+                        lines.push("end if;".to_string());
+                    }
+                }
+                if x.if_reset_statement_list0.is_empty() {
+                    if let Some(ref y) = x.if_reset_statement_opt {
+                        lines = self.statements(
+                            y.if_reset_statement_opt_list
+                                .iter()
+                                .map(|x| x.statement.as_ref()),
+                        );
+                    }
+                }
+                body.append(&mut self.indented(lines));
+                body.push("end if;".to_string());
+            }
+            _ => {
+                body.push(format!("if {edge} then"));
+                let lines = self.statements(statements.iter().copied());
+                body.append(&mut self.indented(lines));
+                body.push("end if;".to_string());
+            }
+        }
+        self.reset = None;
+
+        let mut ret = vec![
+            format!("process ({})", sensitivity.join(", ")),
+            "begin".to_string(),
+        ];
+        ret.append(&mut self.indented(body));
+        ret.push("end process;".to_string());
+        ret
+    }
+
+    fn module_named_block(&mut self, arg: &ModuleNamedBlock) -> Vec<String> {
+        let label = token_ident(&arg.identifier.identifier_token);
+        let mut region = Region::default();
+        for x in &arg.module_named_block_list {
+            self.module_group(&x.module_group, &mut region);
+        }
+        let mut ret = vec![format!("{label}: block")];
+        ret.append(&mut self.indented(region.decls));
+        ret.push("begin".to_string());
+        ret.append(&mut self.indented(region.stmts));
+        ret.push(format!("end block {label};"));
+        ret
+    }
+
+    /// Body of generate statement
+    fn generate_body<'a, I: Iterator<Item = &'a ModuleGroup>>(&mut self, groups: I) -> Vec<String> {
+        let mut region = Region::default();
+        for x in groups {
+            self.module_group(x, &mut region);
+        }
+        if region.decls.is_empty() {
+            self.indented(region.stmts)
+        } else {
+            let mut ret = self.indented(region.decls);
+            ret.push("begin".to_string());
+            ret.append(&mut self.indented(region.stmts));
+            ret
+        }
+    }
+
+    fn module_if_declaration(&mut self, arg: &ModuleIfDeclaration) -> Vec<String> {
+        let label = token_ident(&arg.module_named_block.identifier.identifier_token);
+        let cond = self.condition(&arg.expression);
+        let mut ret = vec![format!("{label}: if {cond} generate")];
+        ret.append(
+            &mut self.generate_body(
+                arg.module_named_block
+                    .module_named_block_list
+                    .iter()
+                    .map(|x| x.module_group.as_ref()),
+            ),
+        );
+        for x in &arg.module_if_declaration_list {
+            let cond = self.condition(&x.expression);
+            let block = &x.module_optional_named_block;
+            match block.module_optional_named_block_opt {
+                Some(ref y) => {
+                    let alternative = token_ident(&y.identifier.identifier_token);
+                    ret.push(format!("elsif {alternative}: {cond} generate"));
+                }
+                None => ret.push(format!("elsif {cond} generate")),
+            }
+            ret.append(
+                &mut self.generate_body(
+                    block
+                        .module_optional_named_block_list
+                        .iter()
+                        .map(|x| x.module_group.as_ref()),
+                ),
+            );
+        }
+        if let Some(ref x) = arg.module_if_declaration_opt {
+            let block = &x.module_optional_named_block;
+            match block.module_optional_named_block_opt {
+                Some(ref y) => {
+                    let alternative = token_ident(&y.identifier.identifier_token);
+                    ret.push(format!("else {alternative}: generate"));
+                }
+                None => ret.push("else generate".to_string()),
+            }
+            ret.append(
+                &mut self.generate_body(
+                    block
+                        .module_optional_named_block_list
+                        .iter()
+                        .map(|x| x.module_group.as_ref()),
+                ),
+            );
+        }
+        ret.push(format!("end generate {label};"));
+        ret
+    }
+
+    fn module_for_declaration(&mut self, arg: &ModuleForDeclaration) -> Vec<String> {
+        let label = token_ident(&arg.module_named_block.identifier.identifier_token);
+        let name = token_ident(&arg.identifier.identifier_token);
+        let (start, range) = self.range(&arg.range);
+        let mut ret = vec![format!("{label}: for {name} in {range} generate")];
+        let mut body = self.generate_body(
+            arg.module_named_block
+                .module_named_block_list
+                .iter()
+                .map(|x| x.module_group.as_ref()),
+        );
+        if let Some(ref x) = arg.module_for_declaration_opt {
+            match self.step(&name, &start, &x.assignment_operator, &x.expression) {
+                Some(cond) => {
+                    let step = ident(&format!(
+                        "{}_step",
+                        arg.module_named_block.identifier.identifier_token.text()
+                    ));
+                    let mut lines = vec![format!("{step}: if {cond} = 0 generate")];
+                    lines.append(&mut body);
+                    lines.push(format!("end generate {step};"));
+                    body = self.indented(lines);
+                }
+                None => ret.push(format!("{}-- step is not supported in VHDL", self.unit)),
+            }
+        }
+        ret.append(&mut body);
+        ret.push(format!("end generate {label};"));
+        ret
+    }
+
+    /// Start and discrete range of the range
+    fn range(&mut self, arg: &Range) -> (String, String) {
+        let integer = self.integer;
+        self.integer = true;
+        let start = self.expression_term(&arg.expression);
+        let end = match arg.range_opt {
+            Some(ref x) => {
+                let end = self.expression_term(&x.expression);
+                match &*x.range_operator {
+                    RangeOperator::DotDot(_) => high(&end.0),
+                    RangeOperator::DotDotEqu(_) => end.0,
+                }
+            }
+            None => start.0.clone(),
+        };
+        self.integer = integer;
+        let start = paren(&start);
+        let range = format!("{start} to {end}");
+        (start, range)
+    }
+
+    /// Expression which is zero at the iteration of the step
+    fn step(
+        &mut self,
+        name: &str,
+        start: &str,
+        operator: &AssignmentOperator,
+        arg: &Expression,
+    ) -> Option<String> {
+        if operator.assignment_operator_token.text() != "+=" {
+            return None;
+        }
+        let integer = self.integer;
+        self.integer = true;
+        let step = self.expression_term(arg);
+        self.integer = integer;
+        let offset = if start == "0" {
+            name.to_string()
+        } else {
+            format!("({name} - {start})")
+        };
+        Some(format!("{offset} mod {}", paren(&step)))
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Vec<String> {
+        let label = token_ident(&arg.identifier.identifier_token);
+        let symbol = match symbol_table::resolve(arg.scoped_identifier.as_ref()) {
+            Ok(x) => x.found,
+            Err(_) => None,
+        };
+        let property = match symbol.as_ref().map(|x| &x.kind) {
+            Some(SymbolKind::Module(x)) => x.clone(),
+            Some(SymbolKind::Interface(_)) => {
+                return vec![format!(
+                    "-- interface instance {label} is not supported in VHDL"
+                )];
+            }
+            _ => return vec![format!("-- instance {label} is not supported in VHDL")],
+        };
+        let symbol = symbol.unwrap();
+        let segments: Vec<GenericSegment> = arg.scoped_identifier.as_ref().into();
+        if segments.iter().any(|x| x.1.is_some()) || !property.generic_parameters.is_empty() {
+            return vec![format!(
+                "-- generic instance {label} is not supported in VHDL"
+            )];
+        }
+        if arg.inst_declaration_opt.is_some() {
+            return vec![format!(
+                "-- instance array {label} is not supported in VHDL"
+            )];
+        }
+
+        let mut generics = Vec::new();
+        if let Some(ref x) = arg.inst_declaration_opt0 {
+            if let Some(ref x) = x.inst_parameter.inst_parameter_opt {
+                for item in inst_parameter_items(&x.inst_parameter_list) {
+                    let name = item.identifier.identifier_token.token.text;
+                    let kind = property
+                        .parameters
+                        .iter()
+                        .find(|x| x.name == name)
+                        .map(|x| type_kind(&x.property.r#type))
+                        .unwrap_or(Kind::Other);
+                    let name = token_ident(&item.identifier.identifier_token);
+                    let value = match item.inst_parameter_item_opt {
+                        Some(ref x) => self.value(&x.expression, kind, ""),
+                        None => name.clone(),
+                    };
+                    generics.push(format!("{name} => {value}"));
+                }
+            }
+        }
+        let mut ports = Vec::new();
+        if let Some(ref x) = arg.inst_declaration_opt1 {
+            if let Some(ref x) = x.inst_declaration_opt2 {
+                for item in inst_port_items(&x.inst_port_list) {
+                    let name = item.identifier.identifier_token.token.text;
+                    let port = property.ports.iter().find(|x| x.name == name);
+                    let (kind, width) = match port.and_then(|x| x.property.r#type.as_ref()) {
+                        Some(x) => (type_kind(x), self.width(x)),
+                        None => (Kind::Other, String::new()),
+                    };
+                    // conversion of literals requires the width which is independent from generics
+                    let kind = match kind {
+                        Kind::Vector { .. } if width.parse::<usize>().is_err() => Kind::Other,
+                        _ => kind,
+                    };
+                    let name = token_ident(&item.identifier.identifier_token);
+                    let value = match item.inst_port_item_opt {
+                        Some(ref x) if Self::is_unconnected(&x.expression) => "open".to_string(),
+                        Some(ref x) => self.value(&x.expression, kind, &width),
+                        None => name.clone(),
+                    };
+                    ports.push(format!("{name} => {value}"));
+                }
+            }
+        }
+
+        let mut ret = vec![format!(
+            "{label}: entity work.{}",
+            unit_name(&symbol, &self.build_opt)
+        )];
+        for (keyword, items) in [("generic", generics), ("port", ports)] {
+            if items.is_empty() {
+                continue;
+            }
+            ret.push(format!("{}{keyword} map (", self.unit));
+            let last = items.len() - 1;
+            for (i, x) in items.into_iter().enumerate() {
+                let comma = if i == last { "" } else { "," };
+                ret.push(format!("{0}{0}{x}{comma}", self.unit));
+            }
+            ret.push(format!("{})", self.unit));
+        }
+        let last = ret.len() - 1;
+        ret[last].push(';');
+        ret
+    }
+
+    /// Whether the port is connected to `_`
+    fn is_unconnected(arg: &Expression) -> bool {
+        match single_factor(arg) {
+            Some(Factor::ExpressionIdentifierFactorOpt(x)) => {
+                x.factor_opt.is_none()
+                    && x.expression_identifier.identifier.identifier_token.text() == "_"
+            }
+            _ => false,
+        }
+    }
+
+    fn enum_declaration(&mut self, arg: &EnumDeclaration) -> Vec<String> {
+        let name = arg.identifier.identifier_token.text();
+        let items = enum_items(&arg.enum_list);
+        let mut ret = vec![format!("type {} is (", ident(&name))];
+        let last = items.len().saturating_sub(1);
+        for (i, x) in items.iter().enumerate() {
+            let member = ident(&format!("{name}_{}", x.identifier.identifier_token.text()));
+            let comma = if i == last { "" } else { "," };
+            ret.push(format!("{}{member}{comma}", self.unit));
+        }
+        ret.push(");".to_string());
+        ret
+    }
+
+    fn struct_declaration(&mut self, arg: &StructDeclaration) -> Vec<String> {
+        let name = token_ident(&arg.identifier.identifier_token);
+        let mut ret = vec![format!("type {name} is record")];
+        for x in struct_items(&arg.struct_list) {
+            let member = token_ident(&x.identifier.identifier_token);
+            let namespace =
+                namespace_table::get(x.identifier.identifier_token.token.id).unwrap_or_default();
+            let r#type: Type = x.scalar_type.as_ref().into();
+            let r#type = self.type_text(&r#type, &namespace);
+            ret.push(format!("{}{member} : {type};", self.unit));
+        }
+        ret.push("end record;".to_string());
+        ret
+    }
+
+    /// Function specification and function body
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> (Vec<String>, Vec<String>) {
+        let name = token_ident(&arg.identifier.identifier_token);
+        if arg.function_declaration_opt.is_some() || arg.function_declaration_opt0.is_some() {
+            let note = vec![format!(
+                "-- generic function {name} is not supported in VHDL"
+            )];
+            return (note.clone(), note);
+        }
+        let mut notes = Vec::new();
+        let mut parameters = Vec::new();
+        if let Some(ref x) = arg.function_declaration_opt1 {
+            if let Some(ref x) = x.port_declaration.port_declaration_opt {
+                for item in port_items(&x.port_declaration_list) {
+                    let port = self.port_declaration_item(&item);
+                    if port.starts_with("--") {
+                        notes.push(port);
+                    } else if let Some(x) = port.strip_suffix("in ") {
+                        parameters.push(x.to_string());
+                    } else {
+                        match port.split_once(" : in ") {
+                            Some((name, r#type)) => parameters.push(format!("{name} : {type}")),
+                            None => notes.push(format!(
+                                "-- output argument {} is not supported in VHDL",
+                                token_ident(&item.identifier.identifier_token)
+                            )),
+                        }
+                    }
+                }
+            }
+        }
+        let namespace =
+            namespace_table::get(arg.identifier.identifier_token.token.id).unwrap_or_default();
+        let return_type: Type = arg.scalar_type.as_ref().into();
+        let return_mark = self.type_mark(&return_type, &namespace);
+        let return_length = self.width(&return_type);
+        let specification = if parameters.is_empty() {
+            format!("function {name} return {return_mark}")
+        } else {
+            format!(
+                "function {name}({}) return {return_mark}",
+                parameters.join("; ")
+            )
+        };
+
+        self.function = Some(FunctionContext {
+            return_kind: type_kind(&return_type),
+            return_length,
+        });
+        let mut decls = Vec::new();
+        let mut statements = notes;
+        for x in &arg.function_declaration_list {
+            match &*x.function_item {
+                FunctionItem::VarDeclaration(x) => {
+                    let x = &x.var_declaration;
+                    let r#type: Type = x.array_type.as_ref().into();
+                    decls.append(&mut self.object_declaration("variable", &x.identifier, &r#type));
+                    if let Some(ref y) = x.var_declaration_opt0 {
+                        let target = token_ident(&x.identifier.identifier_token);
+                        let value = self.value(
+                            &y.expression,
+                            type_kind(&r#type),
+                            &format!("{target}'length"),
+                        );
+                        statements.push(format!("{target} := {value};"));
+                    }
+                }
+                FunctionItem::Statement(x) => {
+                    statements.append(&mut self.statement(&x.statement));
+                }
+            }
+        }
+        self.function = None;
+
+        let mut body = vec![format!("{specification} is")];
+        body.append(&mut self.indented(decls));
+        body.push("begin".to_string());
+        body.append(&mut self.indented(statements));
+        body.push(format!("end function {name};"));
+        (vec![format!("{specification};")], body)
+    }
+
+    fn package_declaration(&mut self, arg: &PackageDeclaration) -> Vec<String> {
+        let text = arg.identifier.identifier_token.text();
+        if arg.package_declaration_opt.is_some() {
+            return vec![format!(
+                "-- generic package {text} is not supported in VHDL"
+            )];
+        }
+        let name = self.declaration_name(&arg.identifier);
+        let own = match symbol_table::resolve(arg.identifier.as_ref()) {
+            Ok(x) => x.found,
+            Err(_) => None,
+        };
+        let mut packages = PackageReference::default();
+        packages.package_declaration(arg);
+        self.math_real = false;
+
+        let mut decls = Region::default();
+        let mut bodies = Region::default();
+        for x in &arg.package_declaration_list {
+            self.package_group(&x.package_group, &mut decls, &mut bodies);
+        }
+
+        let mut ret = self.context_clause(&packages.packages, own.as_ref());
+        ret.push(String::new());
+        ret.push(format!("package {name} is"));
+        ret.append(&mut self.indented(decls.decls));
+        ret.push(format!("end package {name};"));
+        if !bodies.decls.is_empty() {
+            ret.push(String::new());
+            ret.push(format!("package body {name} is"));
+            ret.append(&mut self.indented(bodies.decls));
+            ret.push(format!("end package body {name};"));
+        }
+        ret
+    }
+
+    fn package_group(&mut self, arg: &PackageGroup, decls: &mut Region, bodies: &mut Region) {
+        if !groups_enabled(arg.package_group_list.iter(), |x| &x.attribute) {
+            return;
+        }
+        match &*arg.package_group_group {
+            PackageGroupGroup::LBracePackageGroupGroupListRBrace(x) => {
+                for x in &x.package_group_group_list {
+                    self.package_group(&x.package_group, decls, bodies);
+                }
+            }
+            PackageGroupGroup::PackageItem(x) => match &*x.package_item {
+                PackageItem::VarDeclaration(x) => {
+                    let x = &x.var_declaration;
+                    let r#type: Type = x.array_type.as_ref().into();
+                    decls.decl(self.object_declaration("signal", &x.identifier, &r#type));
+                }
+                PackageItem::LocalparamDeclaration(x) => {
+                    decls.decl(vec![self.localparam_declaration(&x.localparam_declaration)]);
+                }
+                PackageItem::EnumDeclaration(x) => {
+                    decls.decl(self.enum_declaration(&x.enum_declaration));
+                }
+                PackageItem::StructDeclaration(x) => {
+                    decls.decl(self.struct_declaration(&x.struct_declaration));
+                }
+                PackageItem::UnionDeclaration(x) => {
+                    let name = x.union_declaration.identifier.identifier_token.text();
+                    decls.decl(vec![format!("-- union {name} is not supported in VHDL")]);
+                }
+                PackageItem::FunctionDeclaration(x) => {
+                    let (specification, body) = self.function_declaration(&x.function_declaration);
+                    decls.decl(specification);
+                    bodies.decl(body);
+                }
+                // packages are referred by use clauses
+                PackageItem::ImportDeclaration(_) | PackageItem::ExportDeclaration(_) => (),
+                PackageItem::InitialDeclaration(_) | PackageItem::FinalDeclaration(_) => {
+                    decls.decl(vec![
+                        "-- initial and final are not supported in VHDL packages".to_string(),
+                    ]);
+                }
+                PackageItem::EmbedDeclaration(_) | PackageItem::IncludeDeclaration(_) => {
+                    decls.decl(vec![
+                        "-- embedded SystemVerilog is not supported in VHDL".to_string()
+                    ]);
+                }
+            },
+        }
+    }
+
+    fn statements<'a, I: Iterator<Item = &'a Statement>>(&mut self, arg: I) -> Vec<String> {
+        let mut ret = Vec::new();
+        for x in arg {
+            ret.append(&mut self.statement(x));
+        }
+        ret
+    }
+
+    fn statement(&mut self, arg: &Statement) -> Vec<String> {
+        match arg {
+            Statement::IdentifierStatement(x) => self.identifier_statement(&x.identifier_statement),
+            Statement::IfStatement(x) => {
+                let x = &x.if_statement;
+                let mut branches = vec![(
+                    Some(self.condition(&x.expression)),
+                    x.if_statement_list
+                        .iter()
+                        .map(|x| x.statement.as_ref())
+                        .collect::<Vec<_>>(),
+                )];
+                for y in &x.if_statement_list0 {
+                    branches.push((
+                        Some(self.condition(&y.expression)),
+                        y.if_statement_list0_list
+                            .iter()
+                            .map(|x| x.statement.as_ref())
+                            .collect(),
+                    ));
+                }
+                if let Some(ref y) = x.if_statement_opt {
+                    branches.push((
+                        None,
+                        y.if_statement_opt_list
+                            .iter()
+                            .map(|x| x.statement.as_ref())
+                            .collect(),
+                    ));
+                }
+                self.if_branches(branches)
+            }
+            Statement::IfResetStatement(x) => {
+                let x = &x.if_reset_statement;
+                let reset = self.reset.clone().unwrap_or_else(|| "false".to_string());
+                let mut branches = vec![(
+                    Some(reset),
+                    x.if_reset_statement_list
+                        .iter()
+                        .map(|x| x.statement.as_ref())
+                        .collect::<Vec<_>>(),
+                )];
+                for y in &x.if_reset_statement_list0 {
+                    branches.push((
+                        Some(self.condition(&y.expression)),
+                        y.if_reset_statement_list0_list
+                            .iter()
+                            .map(|x| x.statement.as_ref())
+                            .collect(),
+                    ));
+                }
+                if let Some(ref y) = x.if_reset_statement_opt {
+                    branches.push((
+                        None,
+                        y.if_reset_statement_opt_list
+                            .iter()
+                            .map(|x| x.statement.as_ref())
+                            .collect(),
+                    ));
+                }
+                self.if_branches(branches)
+            }
+            Statement::ReturnStatement(x) => {
+                let (kind, length) = match self.function {
+                    Some(ref x) => (x.return_kind, x.return_length.clone()),
+                    None => (Kind::Other, String::new()),
+                };
+                let value = self.value(&x.return_statement.expression, kind, &length);
+                vec![format!("return {value};")]
+            }
+            Statement::ForStatement(x) => {
+                let x = &x.for_statement;
+                let name = token_ident(&x.identifier.identifier_token);
+                let (start, range) = self.range(&x.range);
+                let mut body = Vec::new();
+                if let Some(ref y) = x.for_statement_opt {
+                    match self.step(&name, &start, &y.assignment_operator, &y.expression) {
+                        Some(cond) => body.push(format!("next when {cond} /= 0;")),
+                        None => body.push("-- step is not supported in VHDL".to_string()),
+                    }
+                }
+                body.append(
+                    &mut self.statements(x.for_statement_list.iter().map(|x| x.statement.as_ref())),
+                );
+                let mut ret = vec![format!("for {name} in {range} loop")];
+                ret.append(&mut self.indented(body));
+                ret.push("end loop;".to_string());
+                ret
+            }
+            Statement::CaseStatement(x) => self.case_statement(&x.case_statement),
+        }
+    }
+
+    fn if_branches(&mut self, branches: Vec<(Option<String>, Vec<&Statement>)>) -> Vec<String> {
+        let mut ret = Vec::new();
+        for (i, (cond, statements)) in branches.into_iter().enumerate() {
+            match cond {
+                Some(x) if i == 0 => ret.push(format!("if {x} then")),
+                Some(x) => ret.push(format!("elsif {x} then")),
+                None => ret.push("else".to_string()),
+            }
+            let lines = self.statements(statements.into_iter());
+            ret.append(&mut self.indented(lines));
+        }
+        ret.push("end if;".to_string());
+        ret
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Vec<String> {
+        let kind = match single_factor(&arg.expression).and_then(factor_kind) {
+            Some(x) => x,
+            None if has_logic(|x| x.expression(&arg.expression)) => Kind::Vector { signed: false },
+            None => Kind::Other,
+        };
+        let selector = self.expression(&arg.expression);
+        let (selector, choice_kind) = match kind {
+            Kind::Vector { .. } => (format!("to_integer({selector})"), Kind::Integer),
+            _ => (selector, kind),
+        };
+        let mut ret = vec![format!("case {selector} is")];
+        let mut others = false;
+        for x in &arg.case_statement_list {
+            let item = &x.case_item;
+            let choice = match &*item.case_item_group {
+                CaseItemGroup::Expression(x) => self.value(&x.expression, choice_kind, ""),
+                CaseItemGroup::Defaul(_) => {
+                    others = true;
+                    "others".to_string()
+                }
+            };
+            ret.push(format!("{}when {choice} =>", self.unit));
+            let lines = match &*item.case_item_group0 {
+                CaseItemGroup0::Statement(x) => self.statement(&x.statement),
+                CaseItemGroup0::LBraceCaseItemGroup0ListRBrace(x) => {
+                    self.statements(x.case_item_group0_list.iter().map(|x| x.statement.as_ref()))
+                }
+            };
+            for x in indent_lines(lines, &self.unit) {
+                ret.push(format!("{}{x}", self.unit));
+            }
+        }
+        if !others {
+            ret.push(format!("{}when others =>", self.unit));
+            ret.push(format!("{0}{0}null;", self.unit));
+        }
+        ret.push("end case;".to_string());
+        ret
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Vec<String> {
+        match &*arg.identifier_statement_group {
+            IdentifierStatementGroup::Assignment(x) => {
+                let target = self.expression_identifier(&arg.expression_identifier);
+                let kind = identifier_kind(&arg.expression_identifier);
+                let op = if self.function.is_some() { ":=" } else { "<=" };
+                let value = match &*x.assignment.assignment_group {
+                    AssignmentGroup::Equ(_) => self.assigned_value(
+                        &x.assignment.expression,
+                        kind,
+                        &format!("{target}'length"),
+                    ),
+                    AssignmentGroup::AssignmentOperator(y) => {
+                        let operator = y.assignment_operator.assignment_operator_token.text();
+                        let operator = operator.trim_end_matches('=');
+                        let term = self.expression_term(&x.assignment.expression);
+                        match operator {
+                            "<<" | "<<<" => format!(
+                                "shift_left({target}, {})",
+                                self.shift_amount(&x.assignment.expression, term)
+                            ),
+                            ">>" | ">>>" => format!(
+                                "shift_right({target}, {})",
+                                self.shift_amount(&x.assignment.expression, term)
+                            ),
+                            _ => {
+                                let operator = match operator {
+                                    "%" => "mod",
+                                    "&" => "and",
+                                    "|" => "or",
+                                    "^" => "xor",
+                                    x => x,
+                                };
+                                format!("{target} {operator} {}", paren(&term))
+                            }
+                        }
+                    }
+                };
+                vec![format!("{target} {op} {value};")]
+            }
+            IdentifierStatementGroup::FunctionCall(x) => {
+                let call = self.function_call(&arg.expression_identifier, &x.function_call);
+                if arg
+                    .expression_identifier
+                    .expression_identifier_opt
+                    .is_some()
+                {
+                    let name = arg.expression_identifier.identifier.identifier_token.text();
+                    let args = self.arguments(&x.function_call);
+                    match name.as_str() {
+                        "display" | "info" | "write" => {
+                            vec![format!(
+                                "report {};",
+                                args.first().cloned().unwrap_or_default()
+                            )]
+                        }
+                        "warning" => vec![format!(
+                            "report {} severity warning;",
+                            args.first().cloned().unwrap_or_default()
+                        )],
+                        "error" => vec![format!(
+                            "report {} severity error;",
+                            args.first().cloned().unwrap_or_default()
+                        )],
+                        "fatal" => vec![format!(
+                            "report {} severity failure;",
+                            args.first().cloned().unwrap_or_default()
+                        )],
+                        "finish" => vec!["std.env.finish;".to_string()],
+                        "stop" => vec!["std.env.stop;".to_string()],
+                        _ => vec![format!("-- ${name} is not supported in VHDL")],
+                    }
+                } else {
+                    vec![format!("{call};")]
+                }
+            }
+        }
+    }
+
+    fn shift_amount(&mut self, arg: &Expression, term: (String, bool)) -> String {
+        if has_logic(|x| x.expression(arg)) {
+            format!("to_integer({})", term.0)
+        } else {
+            term.0
+        }
+    }
+
+    /// Condition of if statement and generate
+    fn condition(&mut self, arg: &Expression) -> String {
+        self.expression(arg)
+    }
+
+    /// Value which is converted to the kind of the target
+    fn value(&mut self, arg: &Expression, kind: Kind, length: &str) -> String {
+        if kind == Kind::Integer && !self.integer {
+            self.integer = true;
+            let ret = self.value(arg, kind, length);
+            self.integer = false;
+            return ret;
+        }
+        if let Some(factor) = single_factor(arg) {
+            if let Some(x) = decimal(factor) {
+                match kind {
+                    Kind::Bit if x == "0" || x == "1" => return format!("'{x}'"),
+                    Kind::Vector { .. } if x == "0" => return "(others => '0')".to_string(),
+                    Kind::Vector { signed } if !length.is_empty() => {
+                        let mark = if signed { "signed" } else { "unsigned" };
+                        return format!("to_{mark}({x}, {length})");
+                    }
+                    _ => (),
+                }
+            }
+            if let Some(x) = all_bit(factor) {
+                match kind {
+                    Kind::Bit => return format!("'{x}'"),
+                    Kind::Integer if x == '0' => return "0".to_string(),
+                    _ => (),
+                }
+            }
+            match factor {
+                Factor::IfExpression(x) => {
+                    return self.if_expression(&x.if_expression, kind, length)
+                }
+                Factor::CaseExpression(x) => {
+                    return self.case_expression(&x.case_expression, kind, length);
+                }
+                _ => (),
+            }
+        }
+        self.expression(arg)
+    }
+
+    /// Value assigned to the target. Boolean results of comparison are converted to logic.
+    fn assigned_value(&mut self, arg: &Expression, kind: Kind, length: &str) -> String {
+        if kind.is_logic() && is_boolean(arg) {
+            let (one, zero) = match kind {
+                Kind::Bit => ("'1'", "'0'"),
+                _ => ("(0 => '1', others => '0')", "(others => '0')"),
+            };
+            format!("{one} when {} else {zero}", self.expression(arg))
+        } else {
+            self.value(arg, kind, length)
+        }
+    }
+
+    fn expression(&mut self, arg: &Expression) -> String {
+        self.expression_term(arg).0
+    }
+
+    /// Text of the expression and whether it is compound
+    fn expression_term(&mut self, arg: &Expression) -> (String, bool) {
+        let first = self.expression01(&arg.expression01);
+        let mut rest = Vec::new();
+        for x in &arg.expression_list {
+            rest.push(("or", self.expression01(&x.expression01)));
+        }
+        chain(first, rest, Chain::Same)
+    }
+
+    fn expression01(&mut self, arg: &Expression01) -> (String, bool) {
+        let first = self.expression02(&arg.expression02);
+        let mut rest = Vec::new();
+        for x in &arg.expression01_list {
+            rest.push(("and", self.expression02(&x.expression02)));
+        }
+        chain(first, rest, Chain::Same)
+    }
+
+    fn expression02(&mut self, arg: &Expression02) -> (String, bool) {
+        let first = self.expression03(&arg.expression03);
+        let mut rest = Vec::new();
+        for x in &arg.expression02_list {
+            rest.push(("or", self.expression03(&x.expression03)));
+        }
+        chain(first, rest, Chain::Same)
+    }
+
+    fn expression03(&mut self, arg: &Expression03) -> (String, bool) {
+        let first = self.expression04(&arg.expression04);
+        let mut rest = Vec::new();
+        for x in &arg.expression03_list {
+            let op = match x.operator04.operator04_token.text().as_str() {
+                "^" => "xor",
+                _ => "xnor",
+            };
+            rest.push((op, self.expression04(&x.expression04)));
+        }
+        chain(first, rest, Chain::Same)
+    }
+
+    fn expression04(&mut self, arg: &Expression04) -> (String, bool) {
+        let first = self.expression05(&arg.expression05);
+        let mut rest = Vec::new();
+        for x in &arg.expression04_list {
+            rest.push(("and", self.expression05(&x.expression05)));
+        }
+        chain(first, rest, Chain::Same)
+    }
+
+    fn expression05(&mut self, arg: &Expression05) -> (String, bool) {
+        if arg.expression05_list.is_empty() {
+            return self.expression06(&arg.expression06);
+        }
+        let logic = has_logic(|x| x.expression05(arg));
+        let mut operands = vec![&arg.expression06];
+        let mut rest = Vec::new();
+        for x in &arg.expression05_list {
+            let op = match (x.operator06.operator06_token.text().as_str(), logic) {
+                ("==" | "==?", true) => "?=",
+                ("!=" | "!=?", true) => "?/=",
+                ("!=" | "!==" | "!=?", _) => "/=",
+                _ => "=",
+            };
+            operands.push(&x.expression06);
+            rest.push(op);
+        }
+        let terms =
+            self.comparison_operands(&operands, |x| single_factor06(x), |s, x| s.expression06(x));
+        let mut terms = terms.into_iter();
+        let first = terms.next().unwrap();
+        chain(first, rest.into_iter().zip(terms).collect(), Chain::Never)
+    }
+
+    fn expression06(&mut self, arg: &Expression06) -> (String, bool) {
+        if arg.expression06_list.is_empty() {
+            return self.expression07(&arg.expression07);
+        }
+        let logic = has_logic(|x| x.expression06(arg));
+        let mut operands = vec![&arg.expression07];
+        let mut rest = Vec::new();
+        for x in &arg.expression06_list {
+            let op = match x.operator07.operator07_token.text().as_str() {
+                "<=" => "<=",
+                ">=" => ">=",
+                "<:" => "<",
+                _ => ">",
+            };
+            operands.push(&x.expression07);
+            rest.push(if logic {
+                match op {
+                    "<=" => "?<=",
+                    ">=" => "?>=",
+                    "<" => "?<",
+                    _ => "?>",
+                }
+            } else {
+                op
+            });
+        }
+        let terms =
+            self.comparison_operands(&operands, |x| single_factor07(x), |s, x| s.expression07(x));
+        let mut terms = terms.into_iter();
+        let first = terms.next().unwrap();
+        chain(first, rest.into_iter().zip(terms).collect(), Chain::Never)
+    }
+
+    /// Operands of comparison. Decimal numbers compared with a bit are converted to characters.
+    fn comparison_operands<T, F, G>(
+        &mut self,
+        operands: &[&T],
+        factor: F,
+        emit: G,
+    ) -> Vec<(String, bool)>
+    where
+        F: Fn(&T) -> Option<&Factor>,
+        G: Fn(&mut Self, &T) -> (String, bool),
+    {
+        let bit = operands
+            .iter()
+            .any(|x| factor(x).and_then(factor_kind) == Some(Kind::Bit));
+        operands
+            .iter()
+            .map(|x| match factor(x).and_then(decimal) {
+                Some(x) if bit && (x == "0" || x == "1") => (format!("'{x}'"), false),
+                _ => emit(self, x),
+            })
+            .collect()
+    }
+
+    fn expression07(&mut self, arg: &Expression07) -> (String, bool) {
+        let mut ret = self.expression08(&arg.expression08);
+        for x in &arg.expression07_list {
+            let function = if x.operator08.operator08_token.text().starts_with('<') {
+                "shift_left"
+            } else {
+                "shift_right"
+            };
+            let term = self.expression08(&x.expression08);
+            let amount = if has_logic(|y| y.expression08(&x.expression08)) {
+                format!("to_integer({})", term.0)
+            } else {
+                term.0
+            };
+            ret = (format!("{function}({}, {amount})", ret.0), false);
+        }
+        ret
+    }
+
+    fn expression08(&mut self, arg: &Expression08) -> (String, bool) {
+        let first = self.expression09(&arg.expression09);
+        let mut rest = Vec::new();
+        for x in &arg.expression08_list {
+            let op = if x.operator09.operator09_token.text() == "+" {
+                "+"
+            } else {
+                "-"
+            };
+            rest.push((op, self.expression09(&x.expression09)));
+        }
+        chain(first, rest, Chain::Any)
+    }
+
+    fn expression09(&mut self, arg: &Expression09) -> (String, bool) {
+        let first = self.expression10(&arg.expression10);
+        let mut rest = Vec::new();
+        for x in &arg.expression09_list {
+            let op = match &*x.expression09_list_group {
+                Expression09ListGroup::Operator10(x) => {
+                    if x.operator10.operator10_token.text() == "/" {
+                        "/"
+                    } else {
+                        "mod"
+                    }
+                }
+                Expression09ListGroup::Star(_) => "*",
+            };
+            rest.push((op, self.expression10(&x.expression10)));
+        }
+        chain(first, rest, Chain::Any)
+    }
+
+    fn expression10(&mut self, arg: &Expression10) -> (String, bool) {
+        let first = self.expression11(&arg.expression11);
+        let mut rest = Vec::new();
+        for x in &arg.expression10_list {
+            rest.push(("**", self.expression11(&x.expression11)));
+        }
+        chain(first, rest, Chain::Never)
+    }
+
+    fn expression11(&mut self, arg: &Expression11) -> (String, bool) {
+        // type casts are not emitted because VHDL requires explicit conversion functions
+        self.expression12(&arg.expression12)
+    }
+
+    fn expression12(&mut self, arg: &Expression12) -> (String, bool) {
+        let mut ret = self.factor(&arg.factor);
+        for x in arg.expression12_list.iter().rev() {
+            let op = match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => {
+                    match x.unary_operator.unary_operator_token.text().as_str() {
+                        "~&" => "nand ",
+                        "~|" => "nor ",
+                        _ => "not ",
+                    }
+                }
+                Expression12ListGroup::Operator09(x) => {
+                    if x.operator09.operator09_token.text() == "+" {
+                        "+"
+                    } else {
+                        "-"
+                    }
+                }
+                Expression12ListGroup::Operator05(_) => "and ",
+                Expression12ListGroup::Operator03(_) => "or ",
+                Expression12ListGroup::Operator04(x) => {
+                    if x.operator04.operator04_token.text() == "^" {
+                        "xor "
+                    } else {
+                        "xnor "
+                    }
+                }
+            };
+            ret = (format!("{op}{}", paren(&ret)), true);
+        }
+        ret
+    }
+
+    fn factor(&mut self, arg: &Factor) -> (String, bool) {
+        match arg {
+            Factor::Number(x) => (self.number(&x.number), false),
+            Factor::ExpressionIdentifierFactorOpt(x) => match x.factor_opt {
+                Some(ref y) => (
+                    self.function_call(&x.expression_identifier, &y.function_call),
+                    false,
+                ),
+                None => (self.expression_identifier(&x.expression_identifier), false),
+            },
+            Factor::LParenExpressionRParen(x) => {
+                (format!("({})", self.expression(&x.expression)), false)
+            }
+            Factor::LBraceConcatenationListRBrace(x) => {
+                self.concatenation_list(&x.concatenation_list)
+            }
+            Factor::IfExpression(x) => {
+                (self.if_expression(&x.if_expression, Kind::Other, ""), true)
+            }
+            Factor::CaseExpression(x) => (
+                self.case_expression(&x.case_expression, Kind::Other, ""),
+                true,
+            ),
+            Factor::StringLiteral(x) => (x.string_literal.string_literal_token.text(), false),
+            Factor::FactorGroup(x) => match &*x.factor_group {
+                FactorGroup::Msb(x) => {
+                    let width = msb_table::get(x.msb.msb_token.token.id)
+                        .map(|x| {
+                            let integer = self.integer;
+                            self.integer = true;
+                            let width = self.expression_term(&x);
+                            self.integer = integer;
+                            width
+                        })
+                        .unwrap_or(("1".to_string(), false));
+                    (format!("({})", high(&paren(&width))), false)
+                }
+                FactorGroup::Lsb(_) => ("0".to_string(), false),
+            },
+            Factor::InsideExpression(x) => {
+                let x = &x.inside_expression;
+                (self.inside(&x.expression, &x.range_list), true)
+            }
+            Factor::OutsideExpression(x) => {
+                let x = &x.outside_expression;
+                (
+                    format!("not ({})", self.inside(&x.expression, &x.range_list)),
+                    true,
+                )
+            }
+        }
+    }
+
+    fn number(&mut self, arg: &Number) -> String {
+        match arg {
+            Number::IntegralNumber(x) => match &*x.integral_number {
+                IntegralNumber::Based(x) => self.based(&x.based.based_token.text()),
+                IntegralNumber::BaseLess(x) => x.base_less.base_less_token.text(),
+                IntegralNumber::AllBit(x) => {
+                    let text = x.all_bit.all_bit_token.text();
+                    let (width, value) = text.split_once('\'').unwrap_or(("", &text));
+                    let value = value.to_ascii_uppercase();
+                    if self.integer {
+                        // all ones is -1 in two's complement, and unknown values are not integer
+                        if value == "1" {
+                            "-1".to_string()
+                        } else {
+                            "0".to_string()
+                        }
+                    } else {
+                        match width.replace('_', "").parse::<usize>() {
+                            Ok(x) => format!("\"{}\"", value.repeat(x)),
+                            Err(_) => format!("(others => '{value}')"),
+                        }
+                    }
+                }
+            },
+            Number::RealNumber(x) => match &*x.real_number {
+                RealNumber::FixedPoint(x) => x.fixed_point.fixed_point_token.text(),
+                RealNumber::Exponent(x) => x.exponent.exponent_token.text(),
+            },
+        }
+    }
+
+    fn based(&self, text: &str) -> String {
+        let (width, value) = text.split_once('\'').unwrap_or(("", text));
+        let width = width.replace('_', "");
+        let (signed, value) = match value.strip_prefix('s') {
+            Some(x) => (true, x),
+            None => (false, value),
+        };
+        let base = value.chars().next().unwrap_or('d');
+        let digits = &value[1..];
+        if self.integer {
+            return match base {
+                'b' => format!("2#{digits}#"),
+                'o' => format!("8#{digits}#"),
+                'h' => format!("16#{digits}#"),
+                _ => digits.to_string(),
+            };
+        }
+        if width == "1" && (digits == "0" || digits == "1") {
+            return format!("'{digits}'");
+        }
+        let base = match base {
+            'b' => "b",
+            'o' => "o",
+            'h' => "x",
+            _ if width.is_empty() => return digits.to_string(),
+            _ => "d",
+        };
+        let signed = if signed { "s" } else { "" };
+        format!("{width}{signed}{base}\"{digits}\"")
+    }
+
+    fn arguments(&mut self, arg: &FunctionCall) -> Vec<String> {
+        match arg.function_call_opt {
+            Some(ref x) => {
+                let items: Vec<ArgumentItem> = x.argument_list.as_ref().into();
+                items
+                    .iter()
+                    .map(|x| self.expression(&x.expression))
+                    .collect()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn function_call(&mut self, name: &ExpressionIdentifier, arg: &FunctionCall) -> String {
+        if name.expression_identifier_opt.is_some() {
+            let text = name.identifier.identifier_token.text();
+            let integer = self.integer;
+            self.integer = text == "clog2";
+            let args = self.arguments(arg);
+            self.integer = integer;
+            let first = args.first().cloned().unwrap_or_default();
+            return match text.as_str() {
+                "clog2" => {
+                    self.math_real = true;
+                    format!("integer(ceil(log2(real({first}))))")
+                }
+                "bits" => format!("{first}'length"),
+                "signed" => format!("signed({first})"),
+                "unsigned" => format!("unsigned({first})"),
+                _ => format!("${text}({})", args.join(", ")),
+            };
+        }
+        let name = self.expression_identifier(name);
+        let args = self.arguments(arg);
+        if args.is_empty() {
+            name
+        } else {
+            format!("{name}({})", args.join(", "))
+        }
+    }
+
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> String {
+        let symbol = match symbol_table::resolve(arg) {
+            Ok(x) => x.found,
+            Err(_) => None,
+        };
+        match &*arg.expression_identifier_group {
+            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+                // package members are visible through use clauses
+                let mut ret = match symbol {
+                    Some(ref symbol) if matches!(symbol.kind, SymbolKind::EnumMember(_)) => {
+                        enum_member_name(symbol)
+                    }
+                    Some(ref symbol) => ident(&format!("{}", symbol.token.text)),
+                    None => {
+                        let last = x.expression_identifier_group_list.last();
+                        match last {
+                            Some(x) => token_ident(&x.identifier.identifier_token),
+                            None => token_ident(&x.identifier.identifier_token),
+                        }
+                    }
+                };
+                for x in &x.expression_identifier_group_list0 {
+                    ret = self.select(ret, &x.select);
+                }
+                ret
+            }
+            ExpressionIdentifierGroup::ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(x) => {
+                let mut ret = token_ident(&arg.identifier.identifier_token);
+                for x in &x.expression_identifier_group_list1 {
+                    ret = self.select(ret, &x.select);
+                }
+                for x in &x.expression_identifier_group_list2 {
+                    ret = format!("{ret}.{}", token_ident(&x.identifier.identifier_token));
+                    for x in &x.expression_identifier_group_list2_list {
+                        ret = self.select(ret, &x.select);
+                    }
+                }
+                ret
+            }
+        }
+    }
+
+    fn hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier) -> String {
+        let mut ret = token_ident(&arg.identifier.identifier_token);
+        for x in &arg.hierarchical_identifier_list {
+            ret = self.select(ret, &x.select);
+        }
+        for x in &arg.hierarchical_identifier_list0 {
+            ret = format!("{ret}.{}", token_ident(&x.identifier.identifier_token));
+            for x in &x.hierarchical_identifier_list0_list {
+                ret = self.select(ret, &x.select);
+            }
+        }
+        ret
+    }
+
+    fn hierarchical_identifier_kind(&self, arg: &HierarchicalIdentifier) -> Kind {
+        let symbol = match symbol_table::resolve(arg) {
+            Ok(x) => x.found,
+            Err(_) => None,
+        };
+        let r#type = match symbol.map(|x| x.kind) {
+            Some(SymbolKind::Variable(x)) => x.r#type,
+            Some(SymbolKind::Port(x)) => match x.r#type {
+                Some(x) => x,
+                None => return Kind::Other,
+            },
+            Some(SymbolKind::StructMember(x)) => x.r#type,
+            _ => return Kind::Other,
+        };
+        let selects = match arg.hierarchical_identifier_list0.last() {
+            Some(x) => x
+                .hierarchical_identifier_list0_list
+                .iter()
+                .map(|x| x.select.as_ref())
+                .collect::<Vec<_>>(),
+            None => arg
+                .hierarchical_identifier_list
+                .iter()
+                .map(|x| x.select.as_ref())
+                .collect(),
+        };
+        let arrays = r#type.array.len();
+        if selects.len() < arrays {
+            Kind::Other
+        } else if selects.len() == arrays {
+            type_kind(&r#type)
+        } else if selects[selects.len() - 1].select_opt.is_some() {
+            Kind::Vector { signed: false }
+        } else {
+            Kind::Bit
+        }
+    }
+
+    /// Index of select. Logic values are converted to integer.
+    fn index(&mut self, arg: &Expression) -> (String, bool) {
+        let integer = self.integer;
+        self.integer = true;
+        let ret = self.expression_term(arg);
+        self.integer = integer;
+        if has_logic(|x| x.expression(arg)) {
+            (format!("to_integer({})", ret.0), false)
+        } else {
+            ret
+        }
+    }
+
+    fn select(&mut self, base: String, arg: &Select) -> String {
+        let index = self.index(&arg.expression);
+        match arg.select_opt {
+            None => format!("{base}({})", index.0),
+            Some(ref x) => {
+                let width = self.index(&x.expression);
+                let (a, b) = (paren(&index), paren(&width));
+                match &*x.select_operator {
+                    SelectOperator::Colon(_) => format!("{base}({} downto {})", index.0, width.0),
+                    SelectOperator::PlusColon(_) => {
+                        format!("{base}({a} + {b} - 1 downto {})", index.0)
+                    }
+                    SelectOperator::MinusColon(_) => {
+                        format!("{base}({} downto {a} - {b} + 1)", index.0)
+                    }
+                    SelectOperator::Step(_) => {
+                        format!("{base}(({a} + 1) * {b} - 1 downto {a} * {b})")
+                    }
+                }
+            }
+        }
+    }
+
+    fn concatenation_list(&mut self, arg: &ConcatenationList) -> (String, bool) {
+        let items = iter::once(&arg.concatenation_item).chain(
+            arg.concatenation_list_list
+                .iter()
+                .map(|x| &x.concatenation_item),
+        );
+        let mut terms = Vec::new();
+        for x in items {
+            let term = self.expression_term(&x.expression);
+            match x.concatenation_item_opt {
+                Some(ref y) => {
+                    let integer = self.integer;
+                    self.integer = true;
+                    let count = self.expression(&y.expression);
+                    self.integer = integer;
+                    match count.parse::<usize>() {
+                        Ok(count) => {
+                            for _ in 0..count {
+                                terms.push(paren(&term));
+                            }
+                        }
+                        Err(_) => terms.push(format!("(1 to {count} => {})", term.0)),
+                    }
+                }
+                None => terms.push(paren(&term)),
+            }
+        }
+        if terms.len() == 1 {
+            (terms.remove(0), false)
+        } else {
+            (format!("({})", terms.join(" & ")), false)
+        }
+    }
+
+    fn if_expression(&mut self, arg: &IfExpression, kind: Kind, length: &str) -> String {
+        let mut ret = Vec::new();
+        let value = self.value(&arg.expression0, kind, length);
+        let cond = self.condition(&arg.expression);
+        ret.push(format!("{value} when {cond} else"));
+        for x in &arg.if_expression_list {
+            let value = self.value(&x.expression0, kind, length);
+            let cond = self.condition(&x.expression);
+            ret.push(format!("{value} when {cond} else"));
+        }
+        ret.push(self.value(&arg.expression1, kind, length));
+        ret.join(" ")
+    }
+
+    fn case_expression(&mut self, arg: &CaseExpression, kind: Kind, length: &str) -> String {
+        let logic = has_logic(|x| x.expression(&arg.expression));
+        let op = if logic { "?=" } else { "=" };
+        let selector = self.expression_term(&arg.expression);
+        let selector = paren(&selector);
+        let mut ret = Vec::new();
+        let items = iter::once((&arg.expression0, &arg.expression1)).chain(
+            arg.case_expression_list
+                .iter()
+                .map(|x| (&x.expression, &x.expression0)),
+        );
+        let bit = single_factor(&arg.expression).and_then(factor_kind) == Some(Kind::Bit);
+        for (choice, value) in items {
+            let choice = match single_factor(choice).and_then(decimal) {
+                Some(x) if bit && (x == "0" || x == "1") => (format!("'{x}'"), false),
+                _ => self.expression_term(choice),
+            };
+            let value = self.value(value, kind, length);
+            ret.push(format!(
+                "{value} when {selector} {op} {} else",
+                paren(&choice)
+            ));
+        }
+        ret.push(self.value(&arg.expression2, kind, length));
+        ret.join(" ")
+    }
+
+    fn inside(&mut self, arg: &Expression, ranges: &RangeList) -> String {
+        let logic = has_logic(|x| x.expression(arg));
+        let (eq, ge, lt, le) = if logic {
+            ("?=", "?>=", "?<", "?<=")
+        } else {
+            ("=", ">=", "<", "<=")
+        };
+        let target = self.expression_term(arg);
+        let target = paren(&target);
+        let items = iter::once(&ranges.range_item)
+            .chain(ranges.range_list_list.iter().map(|x| &x.range_item));
+        let mut ret = Vec::new();
+        for x in items {
+            let start = self.expression_term(&x.range.expression);
+            match x.range.range_opt {
+                Some(ref y) => {
+                    let end = self.expression_term(&y.expression);
+                    let op = match &*y.range_operator {
+                        RangeOperator::DotDot(_) => lt,
+                        RangeOperator::DotDotEqu(_) => le,
+                    };
+                    ret.push(format!(
+                        "({target} {ge} {} and {target} {op} {})",
+                        paren(&start),
+                        paren(&end)
+                    ));
+                }
+                None => ret.push(format!("{target} {eq} {}", paren(&start))),
+            }
+        }
+        if ret.len() == 1 {
+            ret.remove(0)
+        } else {
+            ret.iter()
+                .map(|x| {
+                    if x.starts_with('(') {
+                        x.clone()
+                    } else {
+                        format!("({x})")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" or ")
+        }
+    }
+}
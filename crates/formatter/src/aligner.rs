@@ -110,12 +110,13 @@ mod align_kind {
     pub const ASSIGNMENT: usize = 5;
     pub const PARAMETER: usize = 6;
     pub const DIRECTION: usize = 7;
+    pub const CLOCK_DOMAIN: usize = 8;
 }
 
 #[derive(Default)]
 pub struct Aligner {
     pub additions: HashMap<Location, usize>,
-    aligns: [Align; 8],
+    aligns: [Align; 9],
     in_type_expression: bool,
 }
 
@@ -389,6 +390,8 @@ impl VerylWalker for Aligner {
     /// Semantic action for non-terminal 'VariableType'
     fn variable_type(&mut self, arg: &VariableType) {
         match &*arg.variable_type_group {
+            VariableTypeGroup::Clock(x) => self.clock(&x.clock),
+            VariableTypeGroup::Reset(x) => self.reset(&x.reset),
             VariableTypeGroup::Logic(x) => self.logic(&x.logic),
             VariableTypeGroup::Bit(x) => self.bit(&x.bit),
             VariableTypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
@@ -432,6 +435,15 @@ impl VerylWalker for Aligner {
         }
     }
 
+    /// Semantic action for non-terminal 'ClockDomain'
+    fn clock_domain(&mut self, arg: &ClockDomain) {
+        self.aligns[align_kind::CLOCK_DOMAIN].start_item();
+        self.quote(&arg.quote);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.aligns[align_kind::CLOCK_DOMAIN].finish_item();
+    }
+
     /// Semantic action for non-terminal 'ArrayType'
     fn array_type(&mut self, arg: &ArrayType) {
         self.scalar_type(&arg.scalar_type);
@@ -503,8 +515,15 @@ impl VerylWalker for Aligner {
         self.identifier(&arg.identifier);
         self.aligns[align_kind::IDENTIFIER].finish_item();
         self.colon(&arg.colon);
-        self.array_type(&arg.array_type);
         if let Some(ref x) = arg.var_declaration_opt {
+            self.clock_domain(&x.clock_domain);
+        } else {
+            self.aligns[align_kind::CLOCK_DOMAIN].start_item();
+            self.aligns[align_kind::CLOCK_DOMAIN].dummy_token(&arg.colon.colon_token);
+            self.aligns[align_kind::CLOCK_DOMAIN].finish_item();
+        }
+        self.array_type(&arg.array_type);
+        if let Some(ref x) = arg.var_declaration_opt0 {
             self.equ(&x.equ);
             self.expression(&x.expression);
         }
@@ -674,13 +693,21 @@ impl VerylWalker for Aligner {
         self.aligns[align_kind::IDENTIFIER].finish_item();
         self.colon(&arg.colon);
         match &*arg.port_declaration_item_group {
-            PortDeclarationItemGroup::DirectionArrayType(x) => {
+            PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
                 self.direction(&x.direction);
+                if let Some(ref x) = x.port_declaration_item_opt {
+                    self.clock_domain(&x.clock_domain);
+                } else {
+                    let loc = self.aligns[align_kind::DIRECTION].last_location;
+                    self.aligns[align_kind::CLOCK_DOMAIN].start_item();
+                    self.aligns[align_kind::CLOCK_DOMAIN].dummy_location(loc.unwrap());
+                    self.aligns[align_kind::CLOCK_DOMAIN].finish_item();
+                }
                 self.array_type(&x.array_type);
             }
-            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(x) => {
+            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(x) => {
                 self.interface(&x.interface);
-                if let Some(ref x) = x.port_declaration_item_opt {
+                if let Some(ref x) = x.port_declaration_item_opt0 {
                     self.array(&x.array);
                 }
             }
@@ -705,9 +732,12 @@ impl VerylWalker for Aligner {
         self.function(&arg.function);
         self.identifier(&arg.identifier);
         if let Some(ref x) = arg.function_declaration_opt {
-            self.with_parameter(&x.with_parameter);
+            self.with_generic_parameter(&x.with_generic_parameter);
         }
         if let Some(ref x) = arg.function_declaration_opt0 {
+            self.with_parameter(&x.with_parameter);
+        }
+        if let Some(ref x) = arg.function_declaration_opt1 {
             self.port_declaration(&x.port_declaration);
         }
         self.minus_g_t(&arg.minus_g_t);
@@ -725,6 +725,31 @@ impl VerylWalker for Formatter {
         }
     }
 
+    /// Semantic action for non-terminal 'AttributeItem'
+    fn attribute_item(&mut self, arg: &AttributeItem) {
+        match arg {
+            AttributeItem::IdentifierAttributeItemOpt(x) => {
+                self.identifier(&x.identifier);
+                if let Some(ref x) = x.attribute_item_opt {
+                    match &*x.attribute_item_opt_group {
+                        AttributeItemOptGroup::EquStringLiteral(x) => {
+                            self.space(1);
+                            self.equ(&x.equ);
+                            self.space(1);
+                            self.string_literal(&x.string_literal);
+                        }
+                        AttributeItemOptGroup::LParenAttributeListRParen(x) => {
+                            self.l_paren(&x.l_paren);
+                            self.attribute_list(&x.attribute_list);
+                            self.r_paren(&x.r_paren);
+                        }
+                    }
+                }
+            }
+            AttributeItem::StringLiteral(x) => self.string_literal(&x.string_literal),
+        }
+    }
+
     /// Semantic action for non-terminal 'VarDeclaration'
     fn var_declaration(&mut self, arg: &VarDeclaration) {
         self.var(&arg.var);
@@ -732,8 +757,12 @@ impl VerylWalker for Formatter {
         self.identifier(&arg.identifier);
         self.colon(&arg.colon);
         self.space(1);
-        self.array_type(&arg.array_type);
         if let Some(ref x) = arg.var_declaration_opt {
+            self.clock_domain(&x.clock_domain);
+            self.space(1);
+        }
+        self.array_type(&arg.array_type);
+        if let Some(ref x) = arg.var_declaration_opt0 {
             self.space(1);
             self.equ(&x.equ);
             self.space(1);
@@ -1009,6 +1038,19 @@ impl VerylWalker for Formatter {
         self.scalar_type(&arg.scalar_type);
     }
 
+    /// Semantic action for non-terminal 'UnionDeclaration'
+    fn union_declaration(&mut self, arg: &UnionDeclaration) {
+        self.union(&arg.union);
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.token_will_push(&arg.l_brace.l_brace_token);
+        self.newline_push();
+        self.struct_list(&arg.struct_list);
+        self.newline_pop();
+        self.r_brace(&arg.r_brace);
+    }
+
     /// Semantic action for non-terminal 'InitialDeclaration'
     fn initial_declaration(&mut self, arg: &InitialDeclaration) {
         self.initial(&arg.initial);
@@ -1257,6 +1299,32 @@ impl VerylWalker for Formatter {
         }
     }
 
+    /// Semantic action for non-terminal 'WithGenericParameterList'
+    fn with_generic_parameter_list(&mut self, arg: &WithGenericParameterList) {
+        self.with_generic_parameter_item(&arg.with_generic_parameter_item);
+        for x in &arg.with_generic_parameter_list_list {
+            self.comma(&x.comma);
+            self.space(1);
+            self.with_generic_parameter_item(&x.with_generic_parameter_item);
+        }
+        if let Some(ref x) = arg.with_generic_parameter_list_opt {
+            self.comma(&x.comma);
+        }
+    }
+
+    /// Semantic action for non-terminal 'WithGenericArgumentList'
+    fn with_generic_argument_list(&mut self, arg: &WithGenericArgumentList) {
+        self.with_generic_argument_item(&arg.with_generic_argument_item);
+        for x in &arg.with_generic_argument_list_list {
+            self.comma(&x.comma);
+            self.space(1);
+            self.with_generic_argument_item(&x.with_generic_argument_item);
+        }
+        if let Some(ref x) = arg.with_generic_argument_list_opt {
+            self.comma(&x.comma);
+        }
+    }
+
     /// Semantic action for non-terminal 'PortDeclaration'
     fn port_declaration(&mut self, arg: &PortDeclaration) {
         if let Some(ref x) = arg.port_declaration_opt {
@@ -1312,14 +1380,18 @@ impl VerylWalker for Formatter {
         self.colon(&arg.colon);
         self.space(1);
         match &*arg.port_declaration_item_group {
-            PortDeclarationItemGroup::DirectionArrayType(x) => {
+            PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
                 self.direction(&x.direction);
                 self.space(1);
+                if let Some(ref x) = x.port_declaration_item_opt {
+                    self.clock_domain(&x.clock_domain);
+                    self.space(1);
+                }
                 self.array_type(&x.array_type);
             }
-            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(x) => {
+            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(x) => {
                 self.interface(&x.interface);
-                if let Some(ref x) = x.port_declaration_item_opt {
+                if let Some(ref x) = x.port_declaration_item_opt0 {
                     self.space(1);
                     self.array(&x.array);
                 }
@@ -1332,12 +1404,15 @@ impl VerylWalker for Formatter {
         self.function(&arg.function);
         self.space(1);
         self.identifier(&arg.identifier);
-        self.space(1);
         if let Some(ref x) = arg.function_declaration_opt {
+            self.with_generic_parameter(&x.with_generic_parameter);
+        }
+        self.space(1);
+        if let Some(ref x) = arg.function_declaration_opt0 {
             self.with_parameter(&x.with_parameter);
             self.space(1);
         }
-        if let Some(ref x) = arg.function_declaration_opt0 {
+        if let Some(ref x) = arg.function_declaration_opt1 {
             self.port_declaration(&x.port_declaration);
             self.space(1);
         }
@@ -1383,17 +1458,54 @@ impl VerylWalker for Formatter {
         self.semicolon(&arg.semicolon);
     }
 
+    /// Semantic action for non-terminal 'EmbedDeclaration'
+    fn embed_declaration(&mut self, arg: &EmbedDeclaration) {
+        self.embed(&arg.embed);
+        self.space(1);
+        self.l_paren(&arg.l_paren);
+        self.identifier(&arg.identifier);
+        self.r_paren(&arg.r_paren);
+        self.space(1);
+        self.identifier(&arg.identifier0);
+        self.embed_content(&arg.embed_content);
+    }
+
+    /// Semantic action for non-terminal 'EmbedContent'
+    fn embed_content(&mut self, arg: &EmbedContent) {
+        self.token(&arg.embed_content_token);
+        // embed content may span multiple lines
+        if arg.embed_content_token.comments.is_empty() {
+            self.line += self.last_newline;
+        }
+    }
+
+    /// Semantic action for non-terminal 'IncludeDeclaration'
+    fn include_declaration(&mut self, arg: &IncludeDeclaration) {
+        self.include(&arg.include);
+        self.space(1);
+        self.l_paren(&arg.l_paren);
+        self.identifier(&arg.identifier);
+        self.comma(&arg.comma);
+        self.space(1);
+        self.string_literal(&arg.string_literal);
+        self.r_paren(&arg.r_paren);
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'ModuleDeclaration'
     fn module_declaration(&mut self, arg: &ModuleDeclaration) {
         self.module(&arg.module);
         self.space(1);
         self.identifier(&arg.identifier);
-        self.space(1);
         if let Some(ref x) = arg.module_declaration_opt {
+            self.with_generic_parameter(&x.with_generic_parameter);
+        }
+        self.space(1);
+        if let Some(ref x) = arg.module_declaration_opt0 {
             self.with_parameter(&x.with_parameter);
             self.space(1);
         }
-        if let Some(ref x) = arg.module_declaration_opt0 {
+        if let Some(ref x) = arg.module_declaration_opt1 {
             self.port_declaration(&x.port_declaration);
             self.space(1);
         }
@@ -1406,6 +1518,24 @@ impl VerylWalker for Formatter {
         self.r_brace(&arg.r_brace);
     }
 
+    /// Semantic action for non-terminal 'ExternModuleDeclaration'
+    fn extern_module_declaration(&mut self, arg: &ExternModuleDeclaration) {
+        self.r#extern(&arg.r#extern);
+        self.space(1);
+        self.module(&arg.module);
+        self.space(1);
+        self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.extern_module_declaration_opt {
+            self.space(1);
+            self.with_parameter(&x.with_parameter);
+        }
+        if let Some(ref x) = arg.extern_module_declaration_opt0 {
+            self.space(1);
+            self.port_declaration(&x.port_declaration);
+        }
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'ModuleIfDeclaration'
     fn module_if_declaration(&mut self, arg: &ModuleIfDeclaration) {
         self.r#if(&arg.r#if);
@@ -1507,8 +1637,11 @@ impl VerylWalker for Formatter {
         self.interface(&arg.interface);
         self.space(1);
         self.identifier(&arg.identifier);
-        self.space(1);
         if let Some(ref x) = arg.interface_declaration_opt {
+            self.with_generic_parameter(&x.with_generic_parameter);
+        }
+        self.space(1);
+        if let Some(ref x) = arg.interface_declaration_opt0 {
             self.with_parameter(&x.with_parameter);
             self.space(1);
         }
@@ -1623,6 +1756,9 @@ impl VerylWalker for Formatter {
         self.package(&arg.package);
         self.space(1);
         self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.package_declaration_opt {
+            self.with_generic_parameter(&x.with_generic_parameter);
+        }
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token);
         for (i, x) in arg.package_declaration_list.iter().enumerate() {
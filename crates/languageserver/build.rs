@@ -21,9 +21,8 @@ fn main() {
         let mut keywords = "pub const KEYWORDS: &[&str] = &[\n".to_string();
         for line in text.lines() {
             if line.contains("(?-u:\\b)") {
-                let keyword = line.split_ascii_whitespace().nth(2).unwrap();
-                let keyword = keyword.replace("/(?-u:\\b)", "");
-                let keyword = keyword.replace("(?-u:\\b)/", "");
+                let (_, keyword) = line.split_once("/(?-u:\\b)").unwrap();
+                let (keyword, _) = keyword.split_once("(?-u:\\b)/").unwrap();
                 keywords.push_str(&format!("    \"{keyword}\",\n"));
             }
         }
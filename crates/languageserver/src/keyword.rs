@@ -7,10 +7,13 @@ pub const KEYWORDS: &[&str] = &[
     "as",
     "bit",
     "case",
+    "clock",
     "default",
     "else",
+    "embed",
     "enum",
     "export",
+    "extern",
     "f32",
     "f64",
     "final",
@@ -21,6 +24,7 @@ pub const KEYWORDS: &[&str] = &[
     "if_reset",
     "if",
     "import",
+    "include",
     "initial",
     "inout",
     "input",
@@ -42,6 +46,7 @@ pub const KEYWORDS: &[&str] = &[
     "posedge",
     "ref",
     "repeat",
+    "reset",
     "return",
     "signed",
     "step",
@@ -51,6 +56,7 @@ pub const KEYWORDS: &[&str] = &[
     "sync_low",
     "tri",
     "type",
+    "union",
     "u32",
     "u64",
     "var",
@@ -8,7 +8,7 @@ use std::path::Path;
 use tower_lsp::lsp_types::*;
 use tower_lsp::Client;
 use veryl_analyzer::symbol_table::SymbolPath;
-use veryl_analyzer::{namespace_table, symbol_table, Analyzer, AnalyzerError};
+use veryl_analyzer::{dependency_table, namespace_table, symbol_table, Analyzer, AnalyzerError};
 use veryl_formatter::Formatter;
 use veryl_metadata::{Metadata, PathPair};
 use veryl_parser::veryl_token::Token;
@@ -268,13 +268,18 @@ impl Server {
                     veryl_analyzer::symbol::SymbolKind::Parameter(_) => SymbolKind::CONSTANT,
                     veryl_analyzer::symbol::SymbolKind::Instance(_) => SymbolKind::OBJECT,
                     veryl_analyzer::symbol::SymbolKind::Block => SymbolKind::NAMESPACE,
-                    veryl_analyzer::symbol::SymbolKind::Package => SymbolKind::PACKAGE,
+                    veryl_analyzer::symbol::SymbolKind::Package(_) => SymbolKind::PACKAGE,
                     veryl_analyzer::symbol::SymbolKind::Struct => SymbolKind::STRUCT,
                     veryl_analyzer::symbol::SymbolKind::StructMember(_) => SymbolKind::VARIABLE,
+                    veryl_analyzer::symbol::SymbolKind::Union => SymbolKind::STRUCT,
+                    veryl_analyzer::symbol::SymbolKind::UnionMember(_) => SymbolKind::VARIABLE,
                     veryl_analyzer::symbol::SymbolKind::Enum(_) => SymbolKind::ENUM,
                     veryl_analyzer::symbol::SymbolKind::EnumMember(_) => SymbolKind::ENUM_MEMBER,
                     veryl_analyzer::symbol::SymbolKind::Modport(_) => SymbolKind::INTERFACE,
                     veryl_analyzer::symbol::SymbolKind::Genvar => SymbolKind::VARIABLE,
+                    veryl_analyzer::symbol::SymbolKind::GenericParameter(_) => {
+                        SymbolKind::TYPE_PARAMETER
+                    }
                 };
                 let location = to_location(&symbol.token);
                 #[allow(deprecated)]
@@ -524,6 +529,7 @@ impl Server {
                     if let Some(uri) = resource_table::get_path_id(Path::new(uri).to_path_buf()) {
                         symbol_table::drop(uri);
                         namespace_table::drop(uri);
+                        dependency_table::drop(uri);
                     }
                     let analyzer = Analyzer::new(&path.prj, metadata);
                     let _ = analyzer.analyze_pass1(&text, uri, &x.veryl);
@@ -565,6 +571,7 @@ impl Server {
                     {
                         symbol_table::drop(path);
                         namespace_table::drop(path);
+                        dependency_table::drop(path);
                     }
                     let analyzer = Analyzer::new(&prj, &metadata);
                     let mut errors = analyzer.analyze_pass1(text, path, &x.veryl);
@@ -782,7 +789,7 @@ fn completion_toplevel_entity(
                     let text = format!("{}{} ();", prefix, symbol.token.text);
                     (text, Some(CompletionItemKind::INTERFACE))
                 }
-                veryl_analyzer::symbol::SymbolKind::Package => {
+                veryl_analyzer::symbol::SymbolKind::Package(_) => {
                     let text = format!("{}{}::", prefix, symbol.token.text);
                     (text, Some(CompletionItemKind::MODULE))
                 }
@@ -14,6 +14,14 @@ pub struct Build {
     pub target: Target,
     #[serde(default)]
     pub implicit_parameter_types: Vec<BuiltinType>,
+    #[serde(default)]
+    pub target_language: TargetLanguage,
+    #[serde(default)]
+    pub sourcemap: bool,
+    #[serde(default)]
+    pub prefix: String,
+    #[serde(default)]
+    pub suffix: String,
 }
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -59,6 +67,28 @@ pub enum Target {
     Directory { path: PathBuf },
 }
 
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TargetLanguage {
+    #[default]
+    #[serde(rename = "systemverilog")]
+    SystemVerilog,
+    #[serde(rename = "verilog")]
+    Verilog,
+    #[serde(rename = "vhdl")]
+    Vhdl,
+}
+
+impl TargetLanguage {
+    /// File extension of the generated codes
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TargetLanguage::SystemVerilog => "sv",
+            TargetLanguage::Verilog => "v",
+            TargetLanguage::Vhdl => "vhd",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum BuiltinType {
     #[serde(rename = "u32")]
@@ -11,9 +11,9 @@ mod publish;
 #[cfg(test)]
 mod tests;
 mod utils;
-pub use build::{Build, BuiltinType, ClockType, FilelistType, ResetType, Target};
+pub use build::{Build, BuiltinType, ClockType, FilelistType, ResetType, Target, TargetLanguage};
 pub use format::Format;
-pub use lint::{Case, Lint};
+pub use lint::{Case, Lint, LintLevel};
 pub use lockfile::Lockfile;
 pub use metadata::{BumpKind, Metadata, PathPair};
 pub use metadata_error::MetadataError;
@@ -1,5 +1,6 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -7,20 +8,127 @@ use std::fmt;
 pub struct Lint {
     #[serde(default)]
     pub naming: LintNaming,
+    #[serde(default)]
+    pub rules: HashMap<String, LintLevel>,
+}
+
+impl Lint {
+    /// Diagnostic codes of semantic errors which can be configured by `rules`
+    pub const CODES: [&'static str; 55] = [
+        "assignment_to_input",
+        "clock_domain_crossing",
+        "combinational_loop",
+        "conflicting_generic_instance",
+        "diverged_evaluation",
+        "duplicated_case_item",
+        "duplicated_identifier",
+        "implicit_extension",
+        "implicit_truncation",
+        "include_failure",
+        "incomplete_case",
+        "inferred_latch",
+        "invalid_allow",
+        "invalid_attribute",
+        "invalid_direction",
+        "invalid_identifier",
+        "invalid_lsb",
+        "invalid_msb",
+        "invalid_number_character",
+        "invalid_statement",
+        "invalid_system_function",
+        "mismatch_arity",
+        "mismatch_attribute_args",
+        "mismatch_generics_arity",
+        "mismatch_port_type",
+        "mismatch_port_width",
+        "mismatch_type",
+        "mismatch_union_width",
+        "missing_if_reset",
+        "missing_port",
+        "missing_reset_signal",
+        "missing_reset_statement",
+        "missing_return",
+        "multiple_drivers",
+        "override_localparam",
+        "reference_to_output",
+        "too_large_enum_variant",
+        "too_large_number",
+        "too_much_enum_variant",
+        "undefined_identifier",
+        "undriven_output",
+        "undriven_variable",
+        "unknown_attribute",
+        "unknown_embed_lang",
+        "unknown_embed_way",
+        "unknown_feature",
+        "unknown_include_way",
+        "unknown_member",
+        "unknown_msb",
+        "unknown_parameter",
+        "unknown_port",
+        "unreachable_code",
+        "unsupported_by_target",
+        "unused_input",
+        "unused_variable",
+    ];
+
+    /// Diagnostic codes which can be suppressed by `#[allow]` attribute
+    pub const RULES: [&'static str; 16] = [
+        "combinational_loop",
+        "duplicated_case_item",
+        "implicit_extension",
+        "implicit_truncation",
+        "incomplete_case",
+        "inferred_latch",
+        "invalid_identifier",
+        "mismatch_port_width",
+        "missing_port",
+        "missing_reset_statement",
+        "multiple_drivers",
+        "undriven_output",
+        "undriven_variable",
+        "unreachable_code",
+        "unused_input",
+        "unused_variable",
+    ];
+
+    pub fn level(&self, rule: &str) -> Option<LintLevel> {
+        self.rules.get(rule).copied()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LintLevel {
+    #[serde(rename = "allow")]
+    Allow,
+    #[serde(rename = "warn")]
+    Warn,
+    #[serde(rename = "deny")]
+    Deny,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct LintNaming {
+    #[serde(default)]
+    pub case_clock: Option<Case>,
     #[serde(default)]
     pub case_enum: Option<Case>,
     #[serde(default)]
+    pub case_enum_member: Option<Case>,
+    #[serde(default)]
     pub case_function: Option<Case>,
     #[serde(default)]
+    pub case_function_arg: Option<Case>,
+    #[serde(default)]
+    pub case_genvar: Option<Case>,
+    #[serde(default)]
     pub case_instance: Option<Case>,
     #[serde(default)]
     pub case_interface: Option<Case>,
     #[serde(default)]
+    pub case_localparam: Option<Case>,
+    #[serde(default)]
     pub case_modport: Option<Case>,
     #[serde(default)]
     pub case_module: Option<Case>,
@@ -39,18 +147,38 @@ pub struct LintNaming {
     #[serde(default)]
     pub case_reg: Option<Case>,
     #[serde(default)]
+    pub case_reset: Option<Case>,
+    #[serde(default)]
+    pub case_reset_high: Option<Case>,
+    #[serde(default)]
+    pub case_reset_low: Option<Case>,
+    #[serde(default)]
     pub case_struct: Option<Case>,
     #[serde(default)]
+    pub case_struct_member: Option<Case>,
+    #[serde(default)]
+    pub case_union: Option<Case>,
+    #[serde(default)]
     pub case_wire: Option<Case>,
     #[serde(default)]
+    pub prefix_clock: Option<String>,
+    #[serde(default)]
     pub prefix_enum: Option<String>,
     #[serde(default)]
+    pub prefix_enum_member: Option<String>,
+    #[serde(default)]
     pub prefix_function: Option<String>,
     #[serde(default)]
+    pub prefix_function_arg: Option<String>,
+    #[serde(default)]
+    pub prefix_genvar: Option<String>,
+    #[serde(default)]
     pub prefix_instance: Option<String>,
     #[serde(default)]
     pub prefix_interface: Option<String>,
     #[serde(default)]
+    pub prefix_localparam: Option<String>,
+    #[serde(default)]
     pub prefix_modport: Option<String>,
     #[serde(default)]
     pub prefix_module: Option<String>,
@@ -69,18 +197,38 @@ pub struct LintNaming {
     #[serde(default)]
     pub prefix_reg: Option<String>,
     #[serde(default)]
+    pub prefix_reset: Option<String>,
+    #[serde(default)]
+    pub prefix_reset_high: Option<String>,
+    #[serde(default)]
+    pub prefix_reset_low: Option<String>,
+    #[serde(default)]
     pub prefix_struct: Option<String>,
     #[serde(default)]
+    pub prefix_struct_member: Option<String>,
+    #[serde(default)]
+    pub prefix_union: Option<String>,
+    #[serde(default)]
     pub prefix_wire: Option<String>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_clock: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_enum: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_enum_member: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_function: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_function_arg: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_forbidden_genvar: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_instance: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_interface: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_localparam: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_modport: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_module: Option<Regex>,
@@ -99,18 +247,38 @@ pub struct LintNaming {
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_reg: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_reset: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_forbidden_reset_high: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_forbidden_reset_low: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_struct: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_struct_member: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_forbidden_union: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_wire: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_clock: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_enum: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_enum_member: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_function: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_function_arg: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_required_genvar: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_instance: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_required_interface: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_localparam: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_modport: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_required_module: Option<Regex>,
@@ -129,9 +297,69 @@ pub struct LintNaming {
     #[serde(default, with = "serde_regex")]
     pub re_required_reg: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_reset: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_required_reset_high: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_required_reset_low: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_struct: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_struct_member: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_required_union: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_wire: Option<Regex>,
+    #[serde(default)]
+    pub suffix_clock: Option<String>,
+    #[serde(default)]
+    pub suffix_enum: Option<String>,
+    #[serde(default)]
+    pub suffix_enum_member: Option<String>,
+    #[serde(default)]
+    pub suffix_function: Option<String>,
+    #[serde(default)]
+    pub suffix_function_arg: Option<String>,
+    #[serde(default)]
+    pub suffix_genvar: Option<String>,
+    #[serde(default)]
+    pub suffix_instance: Option<String>,
+    #[serde(default)]
+    pub suffix_interface: Option<String>,
+    #[serde(default)]
+    pub suffix_localparam: Option<String>,
+    #[serde(default)]
+    pub suffix_modport: Option<String>,
+    #[serde(default)]
+    pub suffix_module: Option<String>,
+    #[serde(default)]
+    pub suffix_package: Option<String>,
+    #[serde(default)]
+    pub suffix_parameter: Option<String>,
+    #[serde(default)]
+    pub suffix_port_inout: Option<String>,
+    #[serde(default)]
+    pub suffix_port_input: Option<String>,
+    #[serde(default)]
+    pub suffix_port_modport: Option<String>,
+    #[serde(default)]
+    pub suffix_port_output: Option<String>,
+    #[serde(default)]
+    pub suffix_reg: Option<String>,
+    #[serde(default)]
+    pub suffix_reset: Option<String>,
+    #[serde(default)]
+    pub suffix_reset_high: Option<String>,
+    #[serde(default)]
+    pub suffix_reset_low: Option<String>,
+    #[serde(default)]
+    pub suffix_struct: Option<String>,
+    #[serde(default)]
+    pub suffix_struct_member: Option<String>,
+    #[serde(default)]
+    pub suffix_union: Option<String>,
+    #[serde(default)]
+    pub suffix_wire: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
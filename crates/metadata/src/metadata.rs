@@ -14,7 +14,7 @@ use regex::Regex;
 use semver::VersionReq;
 use serde::{Deserialize, Serialize};
 use spdx::Expression;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -49,6 +49,10 @@ pub struct Metadata {
     pub publish: Publish,
     #[serde(default)]
     pub dependencies: HashMap<Url, Dependency>,
+    #[serde(default)]
+    pub features: HashMap<String, Vec<String>>,
+    #[serde(skip)]
+    pub enabled_features: HashSet<String>,
     #[serde(skip)]
     pub metadata_path: PathBuf,
     #[serde(skip)]
@@ -86,6 +90,10 @@ impl Metadata {
         metadata.lockfile_path = path.with_file_name("Veryl.lock");
         metadata.check()?;
 
+        if metadata.features.contains_key("default") {
+            metadata.enable_features(&["default"])?;
+        }
+
         if metadata.pubfile_path.exists() {
             metadata.pubfile = Pubfile::load(&metadata.pubfile_path)?;
         }
@@ -146,6 +154,34 @@ impl Metadata {
             let _ = Expression::parse(license)?;
         }
 
+        for rule in self.lint.rules.keys() {
+            if !Lint::CODES.contains(&rule.as_str()) {
+                return Err(MetadataError::InvalidLintRule(rule.clone()));
+            }
+        }
+
+        for feature in self.features.values().flatten() {
+            if !self.features.contains_key(feature) {
+                return Err(MetadataError::UnknownFeature(feature.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Enables the features and the features implied by them
+    pub fn enable_features<T: AsRef<str>>(&mut self, features: &[T]) -> Result<(), MetadataError> {
+        let mut features: Vec<String> = features.iter().map(|x| x.as_ref().to_string()).collect();
+        while let Some(feature) = features.pop() {
+            let implied = match self.features.get(&feature) {
+                Some(x) => x,
+                None => return Err(MetadataError::UnknownFeature(feature)),
+            };
+            if !self.enabled_features.contains(&feature) {
+                features.extend(implied.iter().cloned());
+                self.enabled_features.insert(feature);
+            }
+        }
         Ok(())
     }
 
@@ -223,12 +259,14 @@ impl Metadata {
             files.iter().map(|x| x.as_ref().to_path_buf()).collect()
         };
 
+        let extension = self.build.target_language.extension();
+
         let mut ret = Vec::new();
         for src in src_files {
             let dst = match self.build.target {
-                Target::Source => src.with_extension("sv"),
+                Target::Source => src.with_extension(extension),
                 Target::Directory { ref path } => {
-                    base.join(path.join(src.with_extension("sv").file_name().unwrap()))
+                    base.join(path.join(src.with_extension(extension).file_name().unwrap()))
                 }
             };
             ret.push(PathPair {
@@ -246,6 +284,9 @@ impl Metadata {
         self.update_lockfile()?;
 
         let mut deps = self.lockfile.paths(&base_dst)?;
+        for dep in &mut deps {
+            dep.dst.set_extension(extension);
+        }
         ret.append(&mut deps);
 
         Ok(ret)
@@ -44,6 +44,20 @@ pub enum MetadataError {
     #[error("license parse failed")]
     InvalidLicense(#[from] spdx::ParseError),
 
+    #[diagnostic(
+        code(MetadataError::InvalidLintRule),
+        help("diagnostic codes of semantic errors can be used as lint rule")
+    )]
+    #[error("lint rule \"{0}\" is invalid")]
+    InvalidLintRule(String),
+
+    #[diagnostic(
+        code(MetadataError::UnknownFeature),
+        help("add the feature to [features] section")
+    )]
+    #[error("feature \"{0}\" is not defined")]
+    UnknownFeature(String),
+
     #[diagnostic(code(MetadataError::PublishedVersion), help("bump up version"))]
     #[error("\"{0}\" is already published")]
     PublishedVersion(Version),
@@ -22,6 +22,16 @@ target = {type = "source"}
 
 [format]
 indent_width = 4
+
+[lint.rules]
+unused_variable = "allow"
+implicit_truncation = "deny"
+
+[features]
+default = ["a"]
+a = []
+b = ["a"]
+c = []
 "#;
 
 const MAIN_TOML: &'static str = r#"
@@ -131,7 +141,20 @@ fn check_toml() {
     assert_eq!(metadata.project.version, Version::parse("0.1.0").unwrap());
     assert_eq!(metadata.build.clock_type, ClockType::PosEdge);
     assert_eq!(metadata.build.reset_type, ResetType::AsyncLow);
+    assert_eq!(
+        metadata.build.target_language,
+        TargetLanguage::SystemVerilog
+    );
     assert_eq!(metadata.format.indent_width, 4);
+    assert_eq!(
+        metadata.lint.level("unused_variable"),
+        Some(LintLevel::Allow)
+    );
+    assert_eq!(
+        metadata.lint.level("implicit_truncation"),
+        Some(LintLevel::Deny)
+    );
+    assert_eq!(metadata.lint.level("unused_input"), None);
 }
 
 #[test]
@@ -156,6 +179,39 @@ fn check() {
 
     metadata.project.name = "---".to_string();
     assert!(metadata.check().is_err());
+
+    metadata.project.name = "test".to_string();
+    metadata
+        .lint
+        .rules
+        .insert("clock_domain_crossing".to_string(), LintLevel::Allow);
+    assert!(metadata.check().is_ok());
+
+    metadata
+        .lint
+        .rules
+        .insert("undefined_rule".to_string(), LintLevel::Allow);
+    assert!(metadata.check().is_err());
+
+    metadata.lint.rules.clear();
+    metadata
+        .features
+        .insert("d".to_string(), vec!["e".to_string()]);
+    assert!(metadata.check().is_err());
+}
+
+#[test]
+fn enable_features() {
+    let (mut metadata, _tempdir) = create_metadata_simple();
+    assert!(metadata.enabled_features.contains("default"));
+    assert!(metadata.enabled_features.contains("a"));
+    assert!(!metadata.enabled_features.contains("b"));
+
+    metadata.enable_features(&["b"]).unwrap();
+    assert!(metadata.enabled_features.contains("b"));
+    assert!(!metadata.enabled_features.contains("c"));
+
+    assert!(metadata.enable_features(&["d"]).is_err());
 }
 
 #[test]
@@ -90,7 +90,7 @@ impl VerylWalker for Finder {
         self.identifier(&arg.identifier);
         self.in_group = false;
         match &*arg.expression_identifier_group {
-            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
                 self.colon_colon(&x.colon_colon);
                 self.in_group = true;
                 self.identifier(&x.identifier);
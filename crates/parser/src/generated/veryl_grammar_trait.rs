@@ -135,6 +135,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ColonColonLAngleTerm'
+    fn colon_colon_l_angle_term(&mut self, _arg: &ColonColonLAngleTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ColonColonTerm'
     fn colon_colon_term(&mut self, _arg: &ColonColonTerm) -> Result<()> {
         Ok(())
@@ -200,6 +205,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'QuoteTerm'
+    fn quote_term(&mut self, _arg: &QuoteTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'RAngleTerm'
     fn r_angle_term(&mut self, _arg: &RAngleTerm) -> Result<()> {
         Ok(())
@@ -270,6 +280,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ClockTerm'
+    fn clock_term(&mut self, _arg: &ClockTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'DefaultTerm'
     fn default_term(&mut self, _arg: &DefaultTerm) -> Result<()> {
         Ok(())
@@ -280,6 +295,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'EmbedTerm'
+    fn embed_term(&mut self, _arg: &EmbedTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'EnumTerm'
     fn enum_term(&mut self, _arg: &EnumTerm) -> Result<()> {
         Ok(())
@@ -290,6 +310,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ExternTerm'
+    fn extern_term(&mut self, _arg: &ExternTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'F32Term'
     fn f32_term(&mut self, _arg: &F32Term) -> Result<()> {
         Ok(())
@@ -340,6 +365,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'IncludeTerm'
+    fn include_term(&mut self, _arg: &IncludeTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'InitialTerm'
     fn initial_term(&mut self, _arg: &InitialTerm) -> Result<()> {
         Ok(())
@@ -445,6 +475,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ResetTerm'
+    fn reset_term(&mut self, _arg: &ResetTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ReturnTerm'
     fn return_term(&mut self, _arg: &ReturnTerm) -> Result<()> {
         Ok(())
@@ -490,6 +525,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'UnionTerm'
+    fn union_term(&mut self, _arg: &UnionTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'U32Term'
     fn u32_term(&mut self, _arg: &U32Term) -> Result<()> {
         Ok(())
@@ -510,6 +550,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AnyTerm'
+    fn any_term(&mut self, _arg: &AnyTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Comments'
     fn comments(&mut self, _arg: &Comments) -> Result<()> {
         Ok(())
@@ -620,6 +665,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ColonColonLAngleToken'
+    fn colon_colon_l_angle_token(&mut self, _arg: &ColonColonLAngleToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ColonColonToken'
     fn colon_colon_token(&mut self, _arg: &ColonColonToken) -> Result<()> {
         Ok(())
@@ -695,6 +745,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'QuoteToken'
+    fn quote_token(&mut self, _arg: &QuoteToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'RAngleToken'
     fn r_angle_token(&mut self, _arg: &RAngleToken) -> Result<()> {
         Ok(())
@@ -765,6 +820,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ClockToken'
+    fn clock_token(&mut self, _arg: &ClockToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'DefaultToken'
     fn default_token(&mut self, _arg: &DefaultToken) -> Result<()> {
         Ok(())
@@ -775,6 +835,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'EmbedToken'
+    fn embed_token(&mut self, _arg: &EmbedToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'EnumToken'
     fn enum_token(&mut self, _arg: &EnumToken) -> Result<()> {
         Ok(())
@@ -785,6 +850,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ExternToken'
+    fn extern_token(&mut self, _arg: &ExternToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'F32Token'
     fn f32_token(&mut self, _arg: &F32Token) -> Result<()> {
         Ok(())
@@ -835,6 +905,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'IncludeToken'
+    fn include_token(&mut self, _arg: &IncludeToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'InitialToken'
     fn initial_token(&mut self, _arg: &InitialToken) -> Result<()> {
         Ok(())
@@ -940,6 +1015,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ResetToken'
+    fn reset_token(&mut self, _arg: &ResetToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ReturnToken'
     fn return_token(&mut self, _arg: &ReturnToken) -> Result<()> {
         Ok(())
@@ -985,6 +1065,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'UnionToken'
+    fn union_token(&mut self, _arg: &UnionToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'U32Token'
     fn u32_token(&mut self, _arg: &U32Token) -> Result<()> {
         Ok(())
@@ -1005,6 +1090,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'EmbedContentToken'
+    fn embed_content_token(&mut self, _arg: &EmbedContentToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Start'
     fn start(&mut self, _arg: &Start) -> Result<()> {
         Ok(())
@@ -1110,6 +1200,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ColonColonLAngle'
+    fn colon_colon_l_angle(&mut self, _arg: &ColonColonLAngle) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ColonColon'
     fn colon_colon(&mut self, _arg: &ColonColon) -> Result<()> {
         Ok(())
@@ -1185,6 +1280,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Quote'
+    fn quote(&mut self, _arg: &Quote) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'RAngle'
     fn r_angle(&mut self, _arg: &RAngle) -> Result<()> {
         Ok(())
@@ -1255,6 +1355,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Clock'
+    fn clock(&mut self, _arg: &Clock) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Defaul'
     fn defaul(&mut self, _arg: &Defaul) -> Result<()> {
         Ok(())
@@ -1265,6 +1370,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Embed'
+    fn embed(&mut self, _arg: &Embed) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Enum'
     fn r#enum(&mut self, _arg: &Enum) -> Result<()> {
         Ok(())
@@ -1275,6 +1385,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Extern'
+    fn r#extern(&mut self, _arg: &Extern) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'F32'
     fn f32(&mut self, _arg: &F32) -> Result<()> {
         Ok(())
@@ -1325,6 +1440,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Include'
+    fn include(&mut self, _arg: &Include) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'In'
     fn r#in(&mut self, _arg: &In) -> Result<()> {
         Ok(())
@@ -1430,6 +1550,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Reset'
+    fn reset(&mut self, _arg: &Reset) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Return'
     fn r#return(&mut self, _arg: &Return) -> Result<()> {
         Ok(())
@@ -1475,6 +1600,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Union'
+    fn r#union(&mut self, _arg: &Union) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'U32'
     fn u32(&mut self, _arg: &U32) -> Result<()> {
         Ok(())
@@ -1495,6 +1625,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'EmbedContent'
+    fn embed_content(&mut self, _arg: &EmbedContent) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Number'
     fn number(&mut self, _arg: &Number) -> Result<()> {
         Ok(())
@@ -1710,6 +1845,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ClockDomain'
+    fn clock_domain(&mut self, _arg: &ClockDomain) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Statement'
     fn statement(&mut self, _arg: &Statement) -> Result<()> {
         Ok(())
@@ -1865,6 +2005,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'UnionDeclaration'
+    fn union_declaration(&mut self, _arg: &UnionDeclaration) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'InitialDeclaration'
     fn initial_declaration(&mut self, _arg: &InitialDeclaration) -> Result<()> {
         Ok(())
@@ -1935,6 +2080,36 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'WithGenericParameter'
+    fn with_generic_parameter(&mut self, _arg: &WithGenericParameter) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WithGenericParameterList'
+    fn with_generic_parameter_list(&mut self, _arg: &WithGenericParameterList) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WithGenericParameterItem'
+    fn with_generic_parameter_item(&mut self, _arg: &WithGenericParameterItem) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WithGenericArgument'
+    fn with_generic_argument(&mut self, _arg: &WithGenericArgument) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WithGenericArgumentList'
+    fn with_generic_argument_list(&mut self, _arg: &WithGenericArgumentList) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'WithGenericArgumentItem'
+    fn with_generic_argument_item(&mut self, _arg: &WithGenericArgumentItem) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'PortDeclaration'
     fn port_declaration(&mut self, _arg: &PortDeclaration) -> Result<()> {
         Ok(())
@@ -1980,11 +2155,31 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'EmbedDeclaration'
+    fn embed_declaration(&mut self, _arg: &EmbedDeclaration) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EmbedItem'
+    fn embed_item(&mut self, _arg: &EmbedItem) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'IncludeDeclaration'
+    fn include_declaration(&mut self, _arg: &IncludeDeclaration) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ModuleDeclaration'
     fn module_declaration(&mut self, _arg: &ModuleDeclaration) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ExternModuleDeclaration'
+    fn extern_module_declaration(&mut self, _arg: &ExternModuleDeclaration) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ModuleIfDeclaration'
     fn module_if_declaration(&mut self, _arg: &ModuleIfDeclaration) -> Result<()> {
         Ok(())
@@ -2087,7 +2282,7 @@ pub trait VerylGrammarTrait {
 //
 
 ///
-/// Type derived for production 297
+/// Type derived for production 326
 ///
 /// Number: IntegralNumber;
 ///
@@ -2099,7 +2294,7 @@ pub struct NumberIntegralNumber {
 }
 
 ///
-/// Type derived for production 298
+/// Type derived for production 327
 ///
 /// Number: RealNumber;
 ///
@@ -2111,7 +2306,7 @@ pub struct NumberRealNumber {
 }
 
 ///
-/// Type derived for production 299
+/// Type derived for production 328
 ///
 /// IntegralNumber: Based;
 ///
@@ -2123,7 +2318,7 @@ pub struct IntegralNumberBased {
 }
 
 ///
-/// Type derived for production 300
+/// Type derived for production 329
 ///
 /// IntegralNumber: BaseLess;
 ///
@@ -2135,7 +2330,7 @@ pub struct IntegralNumberBaseLess {
 }
 
 ///
-/// Type derived for production 301
+/// Type derived for production 330
 ///
 /// IntegralNumber: AllBit;
 ///
@@ -2147,7 +2342,7 @@ pub struct IntegralNumberAllBit {
 }
 
 ///
-/// Type derived for production 302
+/// Type derived for production 331
 ///
 /// RealNumber: FixedPoint;
 ///
@@ -2159,7 +2354,7 @@ pub struct RealNumberFixedPoint {
 }
 
 ///
-/// Type derived for production 303
+/// Type derived for production 332
 ///
 /// RealNumber: Exponent;
 ///
@@ -2171,23 +2366,24 @@ pub struct RealNumberExponent {
 }
 
 ///
-/// Type derived for production 315
+/// Type derived for production 348
 ///
-/// ExpressionIdentifierGroup: ColonColon Identifier ExpressionIdentifierGroupList /* Vec */ ExpressionIdentifierGroupList0 /* Vec */;
+/// ExpressionIdentifierGroup: ColonColon Identifier ExpressionIdentifierOpt1 /* Option */ ExpressionIdentifierGroupList /* Vec */ ExpressionIdentifierGroupList0 /* Vec */;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct ExpressionIdentifierGroupColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0
+pub struct ExpressionIdentifierGroupColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0
 {
     pub colon_colon: Box<ColonColon>,
     pub identifier: Box<Identifier>,
+    pub expression_identifier_opt1: Option<Box<ExpressionIdentifierOpt1>>,
     pub expression_identifier_group_list: Vec<ExpressionIdentifierGroupList>,
     pub expression_identifier_group_list0: Vec<ExpressionIdentifierGroupList0>,
 }
 
 ///
-/// Type derived for production 320
+/// Type derived for production 353
 ///
 /// ExpressionIdentifierGroup: ExpressionIdentifierGroupList1 /* Vec */ ExpressionIdentifierGroupList2 /* Vec */;
 ///
@@ -2200,7 +2396,7 @@ pub struct ExpressionIdentifierGroupExpressionIdentifierGroupList1ExpressionIden
 }
 
 ///
-/// Type derived for production 358
+/// Type derived for production 397
 ///
 /// Expression09ListGroup: Operator10;
 ///
@@ -2212,7 +2408,7 @@ pub struct Expression09ListGroupOperator10 {
 }
 
 ///
-/// Type derived for production 359
+/// Type derived for production 398
 ///
 /// Expression09ListGroup: Star;
 ///
@@ -2224,7 +2420,7 @@ pub struct Expression09ListGroupStar {
 }
 
 ///
-/// Type derived for production 369
+/// Type derived for production 408
 ///
 /// Expression12ListGroup: UnaryOperator;
 ///
@@ -2236,7 +2432,7 @@ pub struct Expression12ListGroupUnaryOperator {
 }
 
 ///
-/// Type derived for production 370
+/// Type derived for production 409
 ///
 /// Expression12ListGroup: Operator09;
 ///
@@ -2248,7 +2444,7 @@ pub struct Expression12ListGroupOperator09 {
 }
 
 ///
-/// Type derived for production 371
+/// Type derived for production 410
 ///
 /// Expression12ListGroup: Operator05;
 ///
@@ -2260,7 +2456,7 @@ pub struct Expression12ListGroupOperator05 {
 }
 
 ///
-/// Type derived for production 372
+/// Type derived for production 411
 ///
 /// Expression12ListGroup: Operator03;
 ///
@@ -2272,7 +2468,7 @@ pub struct Expression12ListGroupOperator03 {
 }
 
 ///
-/// Type derived for production 373
+/// Type derived for production 412
 ///
 /// Expression12ListGroup: Operator04;
 ///
@@ -2284,7 +2480,7 @@ pub struct Expression12ListGroupOperator04 {
 }
 
 ///
-/// Type derived for production 375
+/// Type derived for production 414
 ///
 /// Factor: Number;
 ///
@@ -2296,7 +2492,7 @@ pub struct FactorNumber {
 }
 
 ///
-/// Type derived for production 376
+/// Type derived for production 415
 ///
 /// Factor: ExpressionIdentifier FactorOpt /* Option */;
 ///
@@ -2309,7 +2505,7 @@ pub struct FactorExpressionIdentifierFactorOpt {
 }
 
 ///
-/// Type derived for production 377
+/// Type derived for production 416
 ///
 /// Factor: LParen Expression RParen;
 ///
@@ -2323,7 +2519,7 @@ pub struct FactorLParenExpressionRParen {
 }
 
 ///
-/// Type derived for production 378
+/// Type derived for production 417
 ///
 /// Factor: LBrace ConcatenationList RBrace;
 ///
@@ -2337,7 +2533,7 @@ pub struct FactorLBraceConcatenationListRBrace {
 }
 
 ///
-/// Type derived for production 379
+/// Type derived for production 418
 ///
 /// Factor: IfExpression;
 ///
@@ -2349,7 +2545,7 @@ pub struct FactorIfExpression {
 }
 
 ///
-/// Type derived for production 380
+/// Type derived for production 419
 ///
 /// Factor: CaseExpression;
 ///
@@ -2361,7 +2557,7 @@ pub struct FactorCaseExpression {
 }
 
 ///
-/// Type derived for production 381
+/// Type derived for production 420
 ///
 /// Factor: StringLiteral;
 ///
@@ -2373,7 +2569,7 @@ pub struct FactorStringLiteral {
 }
 
 ///
-/// Type derived for production 382
+/// Type derived for production 421
 ///
 /// Factor: FactorGroup;
 ///
@@ -2385,7 +2581,7 @@ pub struct FactorFactorGroup {
 }
 
 ///
-/// Type derived for production 383
+/// Type derived for production 422
 ///
 /// FactorGroup: Msb;
 ///
@@ -2397,7 +2593,7 @@ pub struct FactorGroupMsb {
 }
 
 ///
-/// Type derived for production 384
+/// Type derived for production 423
 ///
 /// FactorGroup: Lsb;
 ///
@@ -2409,7 +2605,7 @@ pub struct FactorGroupLsb {
 }
 
 ///
-/// Type derived for production 385
+/// Type derived for production 424
 ///
 /// Factor: InsideExpression;
 ///
@@ -2421,7 +2617,7 @@ pub struct FactorInsideExpression {
 }
 
 ///
-/// Type derived for production 386
+/// Type derived for production 425
 ///
 /// Factor: OutsideExpression;
 ///
@@ -2433,7 +2629,7 @@ pub struct FactorOutsideExpression {
 }
 
 ///
-/// Type derived for production 414
+/// Type derived for production 453
 ///
 /// TypeExpression: ScalarType;
 ///
@@ -2445,7 +2641,7 @@ pub struct TypeExpressionScalarType {
 }
 
 ///
-/// Type derived for production 415
+/// Type derived for production 454
 ///
 /// TypeExpression: Type LParen Expression RParen;
 ///
@@ -2460,7 +2656,7 @@ pub struct TypeExpressionTypeLParenExpressionRParen {
 }
 
 ///
-/// Type derived for production 427
+/// Type derived for production 466
 ///
 /// SelectOperator: Colon;
 ///
@@ -2472,7 +2668,7 @@ pub struct SelectOperatorColon {
 }
 
 ///
-/// Type derived for production 428
+/// Type derived for production 467
 ///
 /// SelectOperator: PlusColon;
 ///
@@ -2484,7 +2680,7 @@ pub struct SelectOperatorPlusColon {
 }
 
 ///
-/// Type derived for production 429
+/// Type derived for production 468
 ///
 /// SelectOperator: MinusColon;
 ///
@@ -2496,7 +2692,7 @@ pub struct SelectOperatorMinusColon {
 }
 
 ///
-/// Type derived for production 430
+/// Type derived for production 469
 ///
 /// SelectOperator: Step;
 ///
@@ -2508,7 +2704,7 @@ pub struct SelectOperatorStep {
 }
 
 ///
-/// Type derived for production 440
+/// Type derived for production 479
 ///
 /// RangeOperator: DotDot;
 ///
@@ -2520,7 +2716,7 @@ pub struct RangeOperatorDotDot {
 }
 
 ///
-/// Type derived for production 441
+/// Type derived for production 480
 ///
 /// RangeOperator: DotDotEqu;
 ///
@@ -2532,7 +2728,7 @@ pub struct RangeOperatorDotDotEqu {
 }
 
 ///
-/// Type derived for production 442
+/// Type derived for production 481
 ///
 /// FixedType: U32;
 ///
@@ -2544,7 +2740,7 @@ pub struct FixedTypeU32 {
 }
 
 ///
-/// Type derived for production 443
+/// Type derived for production 482
 ///
 /// FixedType: U64;
 ///
@@ -2556,7 +2752,7 @@ pub struct FixedTypeU64 {
 }
 
 ///
-/// Type derived for production 444
+/// Type derived for production 483
 ///
 /// FixedType: I32;
 ///
@@ -2568,7 +2764,7 @@ pub struct FixedTypeI32 {
 }
 
 ///
-/// Type derived for production 445
+/// Type derived for production 484
 ///
 /// FixedType: I64;
 ///
@@ -2580,7 +2776,7 @@ pub struct FixedTypeI64 {
 }
 
 ///
-/// Type derived for production 446
+/// Type derived for production 485
 ///
 /// FixedType: F32;
 ///
@@ -2592,7 +2788,7 @@ pub struct FixedTypeF32 {
 }
 
 ///
-/// Type derived for production 447
+/// Type derived for production 486
 ///
 /// FixedType: F64;
 ///
@@ -2604,7 +2800,7 @@ pub struct FixedTypeF64 {
 }
 
 ///
-/// Type derived for production 448
+/// Type derived for production 487
 ///
 /// FixedType: Strin;
 ///
@@ -2616,7 +2812,31 @@ pub struct FixedTypeStrin {
 }
 
 ///
-/// Type derived for production 450
+/// Type derived for production 489
+///
+/// VariableTypeGroup: Clock;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct VariableTypeGroupClock {
+    pub clock: Box<Clock>,
+}
+
+///
+/// Type derived for production 490
+///
+/// VariableTypeGroup: Reset;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct VariableTypeGroupReset {
+    pub reset: Box<Reset>,
+}
+
+///
+/// Type derived for production 491
 ///
 /// VariableTypeGroup: Logic;
 ///
@@ -2628,7 +2848,7 @@ pub struct VariableTypeGroupLogic {
 }
 
 ///
-/// Type derived for production 451
+/// Type derived for production 492
 ///
 /// VariableTypeGroup: Bit;
 ///
@@ -2640,7 +2860,7 @@ pub struct VariableTypeGroupBit {
 }
 
 ///
-/// Type derived for production 452
+/// Type derived for production 493
 ///
 /// VariableTypeGroup: ScopedIdentifier;
 ///
@@ -2652,7 +2872,7 @@ pub struct VariableTypeGroupScopedIdentifier {
 }
 
 ///
-/// Type derived for production 455
+/// Type derived for production 496
 ///
 /// TypeModifier: Tri;
 ///
@@ -2664,7 +2884,7 @@ pub struct TypeModifierTri {
 }
 
 ///
-/// Type derived for production 456
+/// Type derived for production 497
 ///
 /// TypeModifier: Signed;
 ///
@@ -2676,7 +2896,7 @@ pub struct TypeModifierSigned {
 }
 
 ///
-/// Type derived for production 458
+/// Type derived for production 499
 ///
 /// ScalarTypeGroup: VariableType;
 ///
@@ -2688,7 +2908,7 @@ pub struct ScalarTypeGroupVariableType {
 }
 
 ///
-/// Type derived for production 459
+/// Type derived for production 500
 ///
 /// ScalarTypeGroup: FixedType;
 ///
@@ -2700,7 +2920,7 @@ pub struct ScalarTypeGroupFixedType {
 }
 
 ///
-/// Type derived for production 465
+/// Type derived for production 507
 ///
 /// Statement: IdentifierStatement;
 ///
@@ -2712,7 +2932,7 @@ pub struct StatementIdentifierStatement {
 }
 
 ///
-/// Type derived for production 466
+/// Type derived for production 508
 ///
 /// Statement: IfStatement;
 ///
@@ -2724,7 +2944,7 @@ pub struct StatementIfStatement {
 }
 
 ///
-/// Type derived for production 467
+/// Type derived for production 509
 ///
 /// Statement: IfResetStatement;
 ///
@@ -2736,7 +2956,7 @@ pub struct StatementIfResetStatement {
 }
 
 ///
-/// Type derived for production 468
+/// Type derived for production 510
 ///
 /// Statement: ReturnStatement;
 ///
@@ -2748,7 +2968,7 @@ pub struct StatementReturnStatement {
 }
 
 ///
-/// Type derived for production 469
+/// Type derived for production 511
 ///
 /// Statement: ForStatement;
 ///
@@ -2760,7 +2980,7 @@ pub struct StatementForStatement {
 }
 
 ///
-/// Type derived for production 470
+/// Type derived for production 512
 ///
 /// Statement: CaseStatement;
 ///
@@ -2772,7 +2992,7 @@ pub struct StatementCaseStatement {
 }
 
 ///
-/// Type derived for production 472
+/// Type derived for production 514
 ///
 /// IdentifierStatementGroup: FunctionCall;
 ///
@@ -2784,7 +3004,7 @@ pub struct IdentifierStatementGroupFunctionCall {
 }
 
 ///
-/// Type derived for production 473
+/// Type derived for production 515
 ///
 /// IdentifierStatementGroup: Assignment;
 ///
@@ -2796,7 +3016,7 @@ pub struct IdentifierStatementGroupAssignment {
 }
 
 ///
-/// Type derived for production 475
+/// Type derived for production 517
 ///
 /// AssignmentGroup: Equ;
 ///
@@ -2808,7 +3028,7 @@ pub struct AssignmentGroupEqu {
 }
 
 ///
-/// Type derived for production 476
+/// Type derived for production 518
 ///
 /// AssignmentGroup: AssignmentOperator;
 ///
@@ -2820,7 +3040,7 @@ pub struct AssignmentGroupAssignmentOperator {
 }
 
 ///
-/// Type derived for production 509
+/// Type derived for production 551
 ///
 /// CaseItemGroup0: Statement;
 ///
@@ -2832,7 +3052,7 @@ pub struct CaseItemGroup0Statement {
 }
 
 ///
-/// Type derived for production 510
+/// Type derived for production 552
 ///
 /// CaseItemGroup0: LBrace CaseItemGroup0List /* Vec */ RBrace;
 ///
@@ -2846,7 +3066,7 @@ pub struct CaseItemGroup0LBraceCaseItemGroup0ListRBrace {
 }
 
 ///
-/// Type derived for production 513
+/// Type derived for production 555
 ///
 /// CaseItemGroup: Expression;
 ///
@@ -2858,7 +3078,7 @@ pub struct CaseItemGroupExpression {
 }
 
 ///
-/// Type derived for production 514
+/// Type derived for production 556
 ///
 /// CaseItemGroup: Defaul;
 ///
@@ -2870,19 +3090,20 @@ pub struct CaseItemGroupDefaul {
 }
 
 ///
-/// Type derived for production 523
+/// Type derived for production 565
 ///
-/// AttributeItem: Identifier;
+/// AttributeItem: Identifier AttributeItemOpt /* Option */;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct AttributeItemIdentifier {
+pub struct AttributeItemIdentifierAttributeItemOpt {
     pub identifier: Box<Identifier>,
+    pub attribute_item_opt: Option<Box<AttributeItemOpt>>,
 }
 
 ///
-/// Type derived for production 524
+/// Type derived for production 566
 ///
 /// AttributeItem: StringLiteral;
 ///
@@ -2894,7 +3115,34 @@ pub struct AttributeItemStringLiteral {
 }
 
 ///
-/// Type derived for production 529
+/// Type derived for production 568
+///
+/// AttributeItemOptGroup: Equ StringLiteral;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeItemOptGroupEquStringLiteral {
+    pub equ: Box<Equ>,
+    pub string_literal: Box<StringLiteral>,
+}
+
+///
+/// Type derived for production 569
+///
+/// AttributeItemOptGroup: LParen AttributeList RParen;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeItemOptGroupLParenAttributeListRParen {
+    pub l_paren: Box<LParen>,
+    pub attribute_list: Box<AttributeList>,
+    pub r_paren: Box<RParen>,
+}
+
+///
+/// Type derived for production 577
 ///
 /// LocalparamDeclarationGroup: ArrayType Equ Expression;
 ///
@@ -2908,7 +3156,7 @@ pub struct LocalparamDeclarationGroupArrayTypeEquExpression {
 }
 
 ///
-/// Type derived for production 530
+/// Type derived for production 578
 ///
 /// LocalparamDeclarationGroup: Type Equ TypeExpression;
 ///
@@ -2922,7 +3170,7 @@ pub struct LocalparamDeclarationGroupTypeEquTypeExpression {
 }
 
 ///
-/// Type derived for production 538
+/// Type derived for production 586
 ///
 /// AlwaysFfClockOptGroup: Posedge;
 ///
@@ -2934,7 +3182,7 @@ pub struct AlwaysFfClockOptGroupPosedge {
 }
 
 ///
-/// Type derived for production 539
+/// Type derived for production 587
 ///
 /// AlwaysFfClockOptGroup: Negedge;
 ///
@@ -2946,7 +3194,7 @@ pub struct AlwaysFfClockOptGroupNegedge {
 }
 
 ///
-/// Type derived for production 543
+/// Type derived for production 591
 ///
 /// AlwaysFfResetOptGroup: AsyncLow;
 ///
@@ -2958,7 +3206,7 @@ pub struct AlwaysFfResetOptGroupAsyncLow {
 }
 
 ///
-/// Type derived for production 544
+/// Type derived for production 592
 ///
 /// AlwaysFfResetOptGroup: AsyncHigh;
 ///
@@ -2970,7 +3218,7 @@ pub struct AlwaysFfResetOptGroupAsyncHigh {
 }
 
 ///
-/// Type derived for production 545
+/// Type derived for production 593
 ///
 /// AlwaysFfResetOptGroup: SyncLow;
 ///
@@ -2982,7 +3230,7 @@ pub struct AlwaysFfResetOptGroupSyncLow {
 }
 
 ///
-/// Type derived for production 546
+/// Type derived for production 594
 ///
 /// AlwaysFfResetOptGroup: SyncHigh;
 ///
@@ -2994,7 +3242,7 @@ pub struct AlwaysFfResetOptGroupSyncHigh {
 }
 
 ///
-/// Type derived for production 559
+/// Type derived for production 607
 ///
 /// ModportGroupGroup: LBrace ModportList RBrace;
 ///
@@ -3008,7 +3256,7 @@ pub struct ModportGroupGroupLBraceModportListRBrace {
 }
 
 ///
-/// Type derived for production 560
+/// Type derived for production 608
 ///
 /// ModportGroupGroup: ModportItem;
 ///
@@ -3020,7 +3268,7 @@ pub struct ModportGroupGroupModportItem {
 }
 
 ///
-/// Type derived for production 571
+/// Type derived for production 619
 ///
 /// EnumGroupGroup: LBrace EnumList RBrace;
 ///
@@ -3034,7 +3282,7 @@ pub struct EnumGroupGroupLBraceEnumListRBrace {
 }
 
 ///
-/// Type derived for production 572
+/// Type derived for production 620
 ///
 /// EnumGroupGroup: EnumItem;
 ///
@@ -3046,7 +3294,7 @@ pub struct EnumGroupGroupEnumItem {
 }
 
 ///
-/// Type derived for production 585
+/// Type derived for production 633
 ///
 /// StructGroupGroup: LBrace StructList RBrace;
 ///
@@ -3060,7 +3308,7 @@ pub struct StructGroupGroupLBraceStructListRBrace {
 }
 
 ///
-/// Type derived for production 586
+/// Type derived for production 634
 ///
 /// StructGroupGroup: StructItem;
 ///
@@ -3072,7 +3320,7 @@ pub struct StructGroupGroupStructItem {
 }
 
 ///
-/// Type derived for production 614
+/// Type derived for production 663
 ///
 /// InstParameterGroupGroup: LBrace InstParameterList RBrace;
 ///
@@ -3086,7 +3334,7 @@ pub struct InstParameterGroupGroupLBraceInstParameterListRBrace {
 }
 
 ///
-/// Type derived for production 615
+/// Type derived for production 664
 ///
 /// InstParameterGroupGroup: InstParameterItem;
 ///
@@ -3098,7 +3346,7 @@ pub struct InstParameterGroupGroupInstParameterItem {
 }
 
 ///
-/// Type derived for production 627
+/// Type derived for production 676
 ///
 /// InstPortGroupGroup: LBrace InstPortList RBrace;
 ///
@@ -3112,7 +3360,7 @@ pub struct InstPortGroupGroupLBraceInstPortListRBrace {
 }
 
 ///
-/// Type derived for production 628
+/// Type derived for production 677
 ///
 /// InstPortGroupGroup: InstPortItem;
 ///
@@ -3124,7 +3372,7 @@ pub struct InstPortGroupGroupInstPortItem {
 }
 
 ///
-/// Type derived for production 643
+/// Type derived for production 692
 ///
 /// WithParameterGroupGroup: LBrace WithParameterList RBrace;
 ///
@@ -3138,7 +3386,7 @@ pub struct WithParameterGroupGroupLBraceWithParameterListRBrace {
 }
 
 ///
-/// Type derived for production 644
+/// Type derived for production 693
 ///
 /// WithParameterGroupGroup: WithParameterItem;
 ///
@@ -3150,7 +3398,7 @@ pub struct WithParameterGroupGroupWithParameterItem {
 }
 
 ///
-/// Type derived for production 648
+/// Type derived for production 697
 ///
 /// WithParameterItemGroup0: ArrayType Equ Expression;
 ///
@@ -3164,7 +3412,7 @@ pub struct WithParameterItemGroup0ArrayTypeEquExpression {
 }
 
 ///
-/// Type derived for production 649
+/// Type derived for production 698
 ///
 /// WithParameterItemGroup0: Type Equ TypeExpression;
 ///
@@ -3178,7 +3426,7 @@ pub struct WithParameterItemGroup0TypeEquTypeExpression {
 }
 
 ///
-/// Type derived for production 650
+/// Type derived for production 699
 ///
 /// WithParameterItemGroup: Parameter;
 ///
@@ -3190,7 +3438,7 @@ pub struct WithParameterItemGroupParameter {
 }
 
 ///
-/// Type derived for production 651
+/// Type derived for production 700
 ///
 /// WithParameterItemGroup: Localparam;
 ///
@@ -3202,7 +3450,31 @@ pub struct WithParameterItemGroupLocalparam {
 }
 
 ///
-/// Type derived for production 661
+/// Type derived for production 714
+///
+/// WithGenericArgumentItem: ScopedIdentifier;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericArgumentItemScopedIdentifier {
+    pub scoped_identifier: Box<ScopedIdentifier>,
+}
+
+///
+/// Type derived for production 715
+///
+/// WithGenericArgumentItem: Number;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericArgumentItemNumber {
+    pub number: Box<Number>,
+}
+
+///
+/// Type derived for production 725
 ///
 /// PortDeclarationGroupGroup: LBrace PortDeclarationList RBrace;
 ///
@@ -3216,7 +3488,7 @@ pub struct PortDeclarationGroupGroupLBracePortDeclarationListRBrace {
 }
 
 ///
-/// Type derived for production 662
+/// Type derived for production 726
 ///
 /// PortDeclarationGroupGroup: PortDeclarationItem;
 ///
@@ -3228,33 +3500,34 @@ pub struct PortDeclarationGroupGroupPortDeclarationItem {
 }
 
 ///
-/// Type derived for production 666
+/// Type derived for production 730
 ///
-/// PortDeclarationItemGroup: Direction ArrayType;
+/// PortDeclarationItemGroup: Direction PortDeclarationItemOpt /* Option */ ArrayType;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct PortDeclarationItemGroupDirectionArrayType {
+pub struct PortDeclarationItemGroupDirectionPortDeclarationItemOptArrayType {
     pub direction: Box<Direction>,
+    pub port_declaration_item_opt: Option<Box<PortDeclarationItemOpt>>,
     pub array_type: Box<ArrayType>,
 }
 
 ///
-/// Type derived for production 667
+/// Type derived for production 731
 ///
-/// PortDeclarationItemGroup: Interface PortDeclarationItemOpt /* Option */;
+/// PortDeclarationItemGroup: Interface PortDeclarationItemOpt0 /* Option */;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct PortDeclarationItemGroupInterfacePortDeclarationItemOpt {
+pub struct PortDeclarationItemGroupInterfacePortDeclarationItemOpt0 {
     pub interface: Box<Interface>,
-    pub port_declaration_item_opt: Option<Box<PortDeclarationItemOpt>>,
+    pub port_declaration_item_opt0: Option<Box<PortDeclarationItemOpt0>>,
 }
 
 ///
-/// Type derived for production 670
+/// Type derived for production 736
 ///
 /// Direction: Input;
 ///
@@ -3266,7 +3539,7 @@ pub struct DirectionInput {
 }
 
 ///
-/// Type derived for production 671
+/// Type derived for production 737
 ///
 /// Direction: Output;
 ///
@@ -3278,7 +3551,7 @@ pub struct DirectionOutput {
 }
 
 ///
-/// Type derived for production 672
+/// Type derived for production 738
 ///
 /// Direction: Inout;
 ///
@@ -3290,7 +3563,7 @@ pub struct DirectionInout {
 }
 
 ///
-/// Type derived for production 673
+/// Type derived for production 739
 ///
 /// Direction: Ref;
 ///
@@ -3302,7 +3575,7 @@ pub struct DirectionRef {
 }
 
 ///
-/// Type derived for production 674
+/// Type derived for production 740
 ///
 /// Direction: Modport;
 ///
@@ -3314,7 +3587,7 @@ pub struct DirectionModport {
 }
 
 ///
-/// Type derived for production 682
+/// Type derived for production 750
 ///
 /// FunctionItem: VarDeclaration;
 ///
@@ -3326,7 +3599,7 @@ pub struct FunctionItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 683
+/// Type derived for production 751
 ///
 /// FunctionItem: Statement;
 ///
@@ -3338,7 +3611,7 @@ pub struct FunctionItemStatement {
 }
 
 ///
-/// Type derived for production 685
+/// Type derived for production 753
 ///
 /// ImportDeclarationGroup: Identifier;
 ///
@@ -3350,7 +3623,7 @@ pub struct ImportDeclarationGroupIdentifier {
 }
 
 ///
-/// Type derived for production 686
+/// Type derived for production 754
 ///
 /// ImportDeclarationGroup: Star;
 ///
@@ -3362,7 +3635,7 @@ pub struct ImportDeclarationGroupStar {
 }
 
 ///
-/// Type derived for production 688
+/// Type derived for production 756
 ///
 /// ExportDeclarationGroup0: Identifier;
 ///
@@ -3374,7 +3647,7 @@ pub struct ExportDeclarationGroup0Identifier {
 }
 
 ///
-/// Type derived for production 689
+/// Type derived for production 757
 ///
 /// ExportDeclarationGroup0: Star;
 ///
@@ -3386,7 +3659,7 @@ pub struct ExportDeclarationGroup0Star {
 }
 
 ///
-/// Type derived for production 690
+/// Type derived for production 758
 ///
 /// ExportDeclarationGroup: Identifier;
 ///
@@ -3398,7 +3671,7 @@ pub struct ExportDeclarationGroupIdentifier {
 }
 
 ///
-/// Type derived for production 691
+/// Type derived for production 759
 ///
 /// ExportDeclarationGroup: Star;
 ///
@@ -3410,7 +3683,33 @@ pub struct ExportDeclarationGroupStar {
 }
 
 ///
-/// Type derived for production 716
+/// Type derived for production 761
+///
+/// EmbedItem: LBraceTerm EmbedItemList /* Vec */ RBraceTerm;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedItemLBraceTermEmbedItemListRBraceTerm {
+    pub l_brace_term: Box<LBraceTerm>,
+    pub embed_item_list: Vec<EmbedItemList>,
+    pub r_brace_term: Box<RBraceTerm>,
+}
+
+///
+/// Type derived for production 764
+///
+/// EmbedItem: AnyTerm;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedItemAnyTerm {
+    pub any_term: Box<AnyTerm>,
+}
+
+///
+/// Type derived for production 797
 ///
 /// ModuleGroupGroup: LBrace ModuleGroupGroupList /* Vec */ RBrace;
 ///
@@ -3424,7 +3723,7 @@ pub struct ModuleGroupGroupLBraceModuleGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 719
+/// Type derived for production 800
 ///
 /// ModuleGroupGroup: ModuleItem;
 ///
@@ -3436,7 +3735,7 @@ pub struct ModuleGroupGroupModuleItem {
 }
 
 ///
-/// Type derived for production 722
+/// Type derived for production 803
 ///
 /// ModuleItem: VarDeclaration;
 ///
@@ -3448,7 +3747,7 @@ pub struct ModuleItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 723
+/// Type derived for production 804
 ///
 /// ModuleItem: InstDeclaration;
 ///
@@ -3460,7 +3759,7 @@ pub struct ModuleItemInstDeclaration {
 }
 
 ///
-/// Type derived for production 724
+/// Type derived for production 805
 ///
 /// ModuleItem: LocalparamDeclaration;
 ///
@@ -3472,7 +3771,7 @@ pub struct ModuleItemLocalparamDeclaration {
 }
 
 ///
-/// Type derived for production 725
+/// Type derived for production 806
 ///
 /// ModuleItem: AlwaysFfDeclaration;
 ///
@@ -3484,7 +3783,7 @@ pub struct ModuleItemAlwaysFfDeclaration {
 }
 
 ///
-/// Type derived for production 726
+/// Type derived for production 807
 ///
 /// ModuleItem: AlwaysCombDeclaration;
 ///
@@ -3496,7 +3795,7 @@ pub struct ModuleItemAlwaysCombDeclaration {
 }
 
 ///
-/// Type derived for production 727
+/// Type derived for production 808
 ///
 /// ModuleItem: AssignDeclaration;
 ///
@@ -3508,7 +3807,7 @@ pub struct ModuleItemAssignDeclaration {
 }
 
 ///
-/// Type derived for production 728
+/// Type derived for production 809
 ///
 /// ModuleItem: FunctionDeclaration;
 ///
@@ -3520,7 +3819,7 @@ pub struct ModuleItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 729
+/// Type derived for production 810
 ///
 /// ModuleItem: ModuleIfDeclaration;
 ///
@@ -3532,7 +3831,7 @@ pub struct ModuleItemModuleIfDeclaration {
 }
 
 ///
-/// Type derived for production 730
+/// Type derived for production 811
 ///
 /// ModuleItem: ModuleForDeclaration;
 ///
@@ -3544,7 +3843,7 @@ pub struct ModuleItemModuleForDeclaration {
 }
 
 ///
-/// Type derived for production 731
+/// Type derived for production 812
 ///
 /// ModuleItem: EnumDeclaration;
 ///
@@ -3556,7 +3855,7 @@ pub struct ModuleItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 732
+/// Type derived for production 813
 ///
 /// ModuleItem: StructDeclaration;
 ///
@@ -3568,7 +3867,19 @@ pub struct ModuleItemStructDeclaration {
 }
 
 ///
-/// Type derived for production 733
+/// Type derived for production 814
+///
+/// ModuleItem: UnionDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleItemUnionDeclaration {
+    pub union_declaration: Box<UnionDeclaration>,
+}
+
+///
+/// Type derived for production 815
 ///
 /// ModuleItem: ModuleNamedBlock;
 ///
@@ -3580,7 +3891,7 @@ pub struct ModuleItemModuleNamedBlock {
 }
 
 ///
-/// Type derived for production 734
+/// Type derived for production 816
 ///
 /// ModuleItem: ImportDeclaration;
 ///
@@ -3592,7 +3903,7 @@ pub struct ModuleItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 735
+/// Type derived for production 817
 ///
 /// ModuleItem: InitialDeclaration;
 ///
@@ -3604,7 +3915,7 @@ pub struct ModuleItemInitialDeclaration {
 }
 
 ///
-/// Type derived for production 736
+/// Type derived for production 818
 ///
 /// ModuleItem: FinalDeclaration;
 ///
@@ -3616,7 +3927,31 @@ pub struct ModuleItemFinalDeclaration {
 }
 
 ///
-/// Type derived for production 759
+/// Type derived for production 819
+///
+/// ModuleItem: EmbedDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleItemEmbedDeclaration {
+    pub embed_declaration: Box<EmbedDeclaration>,
+}
+
+///
+/// Type derived for production 820
+///
+/// ModuleItem: IncludeDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleItemIncludeDeclaration {
+    pub include_declaration: Box<IncludeDeclaration>,
+}
+
+///
+/// Type derived for production 845
 ///
 /// InterfaceGroupGroup: LBrace InterfaceGroupGroupList /* Vec */ RBrace;
 ///
@@ -3630,7 +3965,7 @@ pub struct InterfaceGroupGroupLBraceInterfaceGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 762
+/// Type derived for production 848
 ///
 /// InterfaceGroupGroup: InterfaceItem;
 ///
@@ -3642,7 +3977,7 @@ pub struct InterfaceGroupGroupInterfaceItem {
 }
 
 ///
-/// Type derived for production 765
+/// Type derived for production 851
 ///
 /// InterfaceItem: VarDeclaration;
 ///
@@ -3654,7 +3989,7 @@ pub struct InterfaceItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 766
+/// Type derived for production 852
 ///
 /// InterfaceItem: LocalparamDeclaration;
 ///
@@ -3666,7 +4001,7 @@ pub struct InterfaceItemLocalparamDeclaration {
 }
 
 ///
-/// Type derived for production 767
+/// Type derived for production 853
 ///
 /// InterfaceItem: ModportDeclaration;
 ///
@@ -3678,7 +4013,7 @@ pub struct InterfaceItemModportDeclaration {
 }
 
 ///
-/// Type derived for production 768
+/// Type derived for production 854
 ///
 /// InterfaceItem: InterfaceIfDeclaration;
 ///
@@ -3690,7 +4025,7 @@ pub struct InterfaceItemInterfaceIfDeclaration {
 }
 
 ///
-/// Type derived for production 769
+/// Type derived for production 855
 ///
 /// InterfaceItem: InterfaceForDeclaration;
 ///
@@ -3702,7 +4037,7 @@ pub struct InterfaceItemInterfaceForDeclaration {
 }
 
 ///
-/// Type derived for production 770
+/// Type derived for production 856
 ///
 /// InterfaceItem: EnumDeclaration;
 ///
@@ -3714,7 +4049,7 @@ pub struct InterfaceItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 771
+/// Type derived for production 857
 ///
 /// InterfaceItem: StructDeclaration;
 ///
@@ -3726,21 +4061,33 @@ pub struct InterfaceItemStructDeclaration {
 }
 
 ///
-/// Type derived for production 772
+/// Type derived for production 858
 ///
-/// InterfaceItem: InterfaceNamedBlock;
+/// InterfaceItem: UnionDeclaration;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct InterfaceItemInterfaceNamedBlock {
-    pub interface_named_block: Box<InterfaceNamedBlock>,
+pub struct InterfaceItemUnionDeclaration {
+    pub union_declaration: Box<UnionDeclaration>,
 }
 
 ///
-/// Type derived for production 773
+/// Type derived for production 859
 ///
-/// InterfaceItem: FunctionDeclaration;
+/// InterfaceItem: InterfaceNamedBlock;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InterfaceItemInterfaceNamedBlock {
+    pub interface_named_block: Box<InterfaceNamedBlock>,
+}
+
+///
+/// Type derived for production 860
+///
+/// InterfaceItem: FunctionDeclaration;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
@@ -3750,7 +4097,7 @@ pub struct InterfaceItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 774
+/// Type derived for production 861
 ///
 /// InterfaceItem: ImportDeclaration;
 ///
@@ -3762,7 +4109,7 @@ pub struct InterfaceItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 775
+/// Type derived for production 862
 ///
 /// InterfaceItem: InitialDeclaration;
 ///
@@ -3774,7 +4121,7 @@ pub struct InterfaceItemInitialDeclaration {
 }
 
 ///
-/// Type derived for production 776
+/// Type derived for production 863
 ///
 /// InterfaceItem: FinalDeclaration;
 ///
@@ -3786,7 +4133,31 @@ pub struct InterfaceItemFinalDeclaration {
 }
 
 ///
-/// Type derived for production 781
+/// Type derived for production 864
+///
+/// InterfaceItem: EmbedDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InterfaceItemEmbedDeclaration {
+    pub embed_declaration: Box<EmbedDeclaration>,
+}
+
+///
+/// Type derived for production 865
+///
+/// InterfaceItem: IncludeDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InterfaceItemIncludeDeclaration {
+    pub include_declaration: Box<IncludeDeclaration>,
+}
+
+///
+/// Type derived for production 872
 ///
 /// PackageGroupGroup: LBrace PackageGroupGroupList /* Vec */ RBrace;
 ///
@@ -3800,7 +4171,7 @@ pub struct PackageGroupGroupLBracePackageGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 784
+/// Type derived for production 875
 ///
 /// PackageGroupGroup: PackageItem;
 ///
@@ -3812,7 +4183,7 @@ pub struct PackageGroupGroupPackageItem {
 }
 
 ///
-/// Type derived for production 787
+/// Type derived for production 878
 ///
 /// PackageItem: VarDeclaration;
 ///
@@ -3824,7 +4195,7 @@ pub struct PackageItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 788
+/// Type derived for production 879
 ///
 /// PackageItem: LocalparamDeclaration;
 ///
@@ -3836,7 +4207,7 @@ pub struct PackageItemLocalparamDeclaration {
 }
 
 ///
-/// Type derived for production 789
+/// Type derived for production 880
 ///
 /// PackageItem: EnumDeclaration;
 ///
@@ -3848,7 +4219,7 @@ pub struct PackageItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 790
+/// Type derived for production 881
 ///
 /// PackageItem: StructDeclaration;
 ///
@@ -3860,7 +4231,19 @@ pub struct PackageItemStructDeclaration {
 }
 
 ///
-/// Type derived for production 791
+/// Type derived for production 882
+///
+/// PackageItem: UnionDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PackageItemUnionDeclaration {
+    pub union_declaration: Box<UnionDeclaration>,
+}
+
+///
+/// Type derived for production 883
 ///
 /// PackageItem: FunctionDeclaration;
 ///
@@ -3872,7 +4255,7 @@ pub struct PackageItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 792
+/// Type derived for production 884
 ///
 /// PackageItem: ImportDeclaration;
 ///
@@ -3884,7 +4267,7 @@ pub struct PackageItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 793
+/// Type derived for production 885
 ///
 /// PackageItem: ExportDeclaration;
 ///
@@ -3896,7 +4279,7 @@ pub struct PackageItemExportDeclaration {
 }
 
 ///
-/// Type derived for production 794
+/// Type derived for production 886
 ///
 /// PackageItem: InitialDeclaration;
 ///
@@ -3908,7 +4291,7 @@ pub struct PackageItemInitialDeclaration {
 }
 
 ///
-/// Type derived for production 795
+/// Type derived for production 887
 ///
 /// PackageItem: FinalDeclaration;
 ///
@@ -3920,7 +4303,31 @@ pub struct PackageItemFinalDeclaration {
 }
 
 ///
-/// Type derived for production 797
+/// Type derived for production 888
+///
+/// PackageItem: EmbedDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PackageItemEmbedDeclaration {
+    pub embed_declaration: Box<EmbedDeclaration>,
+}
+
+///
+/// Type derived for production 889
+///
+/// PackageItem: IncludeDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PackageItemIncludeDeclaration {
+    pub include_declaration: Box<IncludeDeclaration>,
+}
+
+///
+/// Type derived for production 891
 ///
 /// DescriptionGroupGroup: LBrace DescriptionGroupGroupList /* Vec */ RBrace;
 ///
@@ -3934,7 +4341,7 @@ pub struct DescriptionGroupGroupLBraceDescriptionGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 800
+/// Type derived for production 894
 ///
 /// DescriptionGroupGroup: DescriptionItem;
 ///
@@ -3946,7 +4353,7 @@ pub struct DescriptionGroupGroupDescriptionItem {
 }
 
 ///
-/// Type derived for production 803
+/// Type derived for production 897
 ///
 /// DescriptionItem: ModuleDeclaration;
 ///
@@ -3958,7 +4365,19 @@ pub struct DescriptionItemModuleDeclaration {
 }
 
 ///
-/// Type derived for production 804
+/// Type derived for production 898
+///
+/// DescriptionItem: ExternModuleDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DescriptionItemExternModuleDeclaration {
+    pub extern_module_declaration: Box<ExternModuleDeclaration>,
+}
+
+///
+/// Type derived for production 899
 ///
 /// DescriptionItem: InterfaceDeclaration;
 ///
@@ -3970,7 +4389,7 @@ pub struct DescriptionItemInterfaceDeclaration {
 }
 
 ///
-/// Type derived for production 805
+/// Type derived for production 900
 ///
 /// DescriptionItem: PackageDeclaration;
 ///
@@ -3982,7 +4401,7 @@ pub struct DescriptionItemPackageDeclaration {
 }
 
 ///
-/// Type derived for production 806
+/// Type derived for production 901
 ///
 /// DescriptionItem: ImportDeclaration;
 ///
@@ -4216,6 +4635,16 @@ pub struct AlwaysFfToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal AnyTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AnyTerm {
+    pub any_term: crate::veryl_token::Token, /* [^{}]+ */
+}
+
 ///
 /// Type derived for non-terminal ArgumentItem
 ///
@@ -4514,10 +4943,30 @@ pub struct Attribute {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum AttributeItem {
-    Identifier(AttributeItemIdentifier),
+    IdentifierAttributeItemOpt(AttributeItemIdentifierAttributeItemOpt),
     StringLiteral(AttributeItemStringLiteral),
 }
 
+///
+/// Type derived for non-terminal AttributeItemOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeItemOpt {
+    pub attribute_item_opt_group: Box<AttributeItemOptGroup>,
+}
+
+///
+/// Type derived for non-terminal AttributeItemOptGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum AttributeItemOptGroup {
+    EquStringLiteral(AttributeItemOptGroupEquStringLiteral),
+    LParenAttributeListRParen(AttributeItemOptGroupLParenAttributeListRParen),
+}
+
 ///
 /// Type derived for non-terminal AttributeList
 ///
@@ -4798,6 +5247,48 @@ pub struct CaseToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Clock
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Clock {
+    pub clock_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal ClockDomain
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ClockDomain {
+    pub quote: Box<Quote>,
+    pub identifier: Box<Identifier>,
+}
+
+///
+/// Type derived for non-terminal ClockTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ClockTerm {
+    pub clock_term: crate::veryl_token::Token, /* (?-u:\b)clock(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal ClockToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ClockToken {
+    pub clock_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Colon
 ///
@@ -4818,6 +5309,37 @@ pub struct ColonColon {
     pub colon_colon_token: crate::veryl_token::VerylToken,
 }
 
+///
+/// Type derived for non-terminal ColonColonLAngle
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ColonColonLAngle {
+    pub colon_colon_l_angle_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal ColonColonLAngleTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ColonColonLAngleTerm {
+    pub colon_colon_l_angle_term: crate::veryl_token::Token, /* ::< */
+}
+
+///
+/// Type derived for non-terminal ColonColonLAngleToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ColonColonLAngleToken {
+    pub colon_colon_l_angle_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal ColonColonTerm
 ///
@@ -5057,6 +5579,7 @@ pub struct DescriptionGroupList {
 #[derive(Debug, Clone)]
 pub enum DescriptionItem {
     ModuleDeclaration(DescriptionItemModuleDeclaration),
+    ExternModuleDeclaration(DescriptionItemExternModuleDeclaration),
     InterfaceDeclaration(DescriptionItemInterfaceDeclaration),
     PackageDeclaration(DescriptionItemPackageDeclaration),
     ImportDeclaration(DescriptionItemImportDeclaration),
@@ -5230,6 +5753,109 @@ pub struct ElseToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Embed
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Embed {
+    pub embed_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal EmbedContent
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedContent {
+    pub embed_content_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal EmbedContentToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedContentToken {
+    pub l_brace_term: Box<LBraceTerm>,
+    pub l_brace_term0: Box<LBraceTerm>,
+    pub l_brace_term1: Box<LBraceTerm>,
+    pub embed_content_token_list: Vec<EmbedContentTokenList>,
+    pub r_brace_term: Box<RBraceTerm>,
+    pub r_brace_term0: Box<RBraceTerm>,
+    pub r_brace_term1: Box<RBraceTerm>,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal EmbedContentTokenList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedContentTokenList {
+    pub embed_item: Box<EmbedItem>,
+}
+
+///
+/// Type derived for non-terminal EmbedDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedDeclaration {
+    pub embed: Box<Embed>,
+    pub l_paren: Box<LParen>,
+    pub identifier: Box<Identifier>,
+    pub r_paren: Box<RParen>,
+    pub identifier0: Box<Identifier>,
+    pub embed_content: Box<EmbedContent>,
+}
+
+///
+/// Type derived for non-terminal EmbedItem
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum EmbedItem {
+    LBraceTermEmbedItemListRBraceTerm(EmbedItemLBraceTermEmbedItemListRBraceTerm),
+    AnyTerm(EmbedItemAnyTerm),
+}
+
+///
+/// Type derived for non-terminal EmbedItemList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedItemList {
+    pub embed_item: Box<EmbedItem>,
+}
+
+///
+/// Type derived for non-terminal EmbedTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedTerm {
+    pub embed_term: crate::veryl_token::Token, /* (?-u:\b)embed(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal EmbedToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct EmbedToken {
+    pub embed_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Enum
 ///
@@ -5796,6 +6422,7 @@ pub enum Expression12ListGroup {
 pub struct ExpressionIdentifier {
     pub expression_identifier_opt: Option<Box<ExpressionIdentifierOpt>>,
     pub identifier: Box<Identifier>,
+    pub expression_identifier_opt0: Option<Box<ExpressionIdentifierOpt0>>,
     pub expression_identifier_group: Box<ExpressionIdentifierGroup>,
 }
 
@@ -5805,7 +6432,7 @@ pub struct ExpressionIdentifier {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum ExpressionIdentifierGroup {
-    ColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0(ExpressionIdentifierGroupColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0),
+    ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(ExpressionIdentifierGroupColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0),
     ExpressionIdentifierGroupList1ExpressionIdentifierGroupList2(ExpressionIdentifierGroupExpressionIdentifierGroupList1ExpressionIdentifierGroupList2),
  }
 
@@ -5818,6 +6445,7 @@ pub enum ExpressionIdentifierGroup {
 pub struct ExpressionIdentifierGroupList {
     pub colon_colon: Box<ColonColon>,
     pub identifier: Box<Identifier>,
+    pub expression_identifier_opt2: Option<Box<ExpressionIdentifierOpt2>>,
 }
 
 ///
@@ -5873,102 +6501,198 @@ pub struct ExpressionIdentifierOpt {
 }
 
 ///
-/// Type derived for non-terminal ExpressionList
+/// Type derived for non-terminal ExpressionIdentifierOpt0
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct ExpressionList {
-    pub operator01: Box<Operator01>,
-    pub expression01: Box<Expression01>,
+pub struct ExpressionIdentifierOpt0 {
+    pub with_generic_argument: Box<WithGenericArgument>,
 }
 
 ///
-/// Type derived for non-terminal F32
+/// Type derived for non-terminal ExpressionIdentifierOpt1
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct F32 {
-    pub f32_token: crate::veryl_token::VerylToken,
+pub struct ExpressionIdentifierOpt1 {
+    pub with_generic_argument: Box<WithGenericArgument>,
 }
 
 ///
-/// Type derived for non-terminal F32Term
+/// Type derived for non-terminal ExpressionIdentifierOpt2
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct F32Term {
-    pub f32_term: crate::veryl_token::Token, /* (?-u:\b)f32(?-u:\b) */
+pub struct ExpressionIdentifierOpt2 {
+    pub with_generic_argument: Box<WithGenericArgument>,
 }
 
 ///
-/// Type derived for non-terminal F32Token
+/// Type derived for non-terminal ExpressionList
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct F32Token {
-    pub f32_term: crate::veryl_token::Token,
-    pub comments: Box<Comments>,
+pub struct ExpressionList {
+    pub operator01: Box<Operator01>,
+    pub expression01: Box<Expression01>,
 }
 
 ///
-/// Type derived for non-terminal F64
+/// Type derived for non-terminal Extern
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct F64 {
-    pub f64_token: crate::veryl_token::VerylToken,
+pub struct Extern {
+    pub extern_token: crate::veryl_token::VerylToken,
 }
 
 ///
-/// Type derived for non-terminal F64Term
+/// Type derived for non-terminal ExternModuleDeclaration
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct F64Term {
-    pub f64_term: crate::veryl_token::Token, /* (?-u:\b)f64(?-u:\b) */
+pub struct ExternModuleDeclaration {
+    pub r#extern: Box<Extern>,
+    pub module: Box<Module>,
+    pub identifier: Box<Identifier>,
+    pub extern_module_declaration_opt: Option<Box<ExternModuleDeclarationOpt>>,
+    pub extern_module_declaration_opt0: Option<Box<ExternModuleDeclarationOpt0>>,
+    pub semicolon: Box<Semicolon>,
 }
 
 ///
-/// Type derived for non-terminal F64Token
+/// Type derived for non-terminal ExternModuleDeclarationOpt
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct F64Token {
-    pub f64_term: crate::veryl_token::Token,
-    pub comments: Box<Comments>,
+pub struct ExternModuleDeclarationOpt {
+    pub with_parameter: Box<WithParameter>,
 }
 
 ///
-/// Type derived for non-terminal Factor
+/// Type derived for non-terminal ExternModuleDeclarationOpt0
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub enum Factor {
-    Number(FactorNumber),
-    ExpressionIdentifierFactorOpt(FactorExpressionIdentifierFactorOpt),
-    LParenExpressionRParen(FactorLParenExpressionRParen),
-    LBraceConcatenationListRBrace(FactorLBraceConcatenationListRBrace),
-    IfExpression(FactorIfExpression),
-    CaseExpression(FactorCaseExpression),
-    StringLiteral(FactorStringLiteral),
-    FactorGroup(FactorFactorGroup),
-    InsideExpression(FactorInsideExpression),
-    OutsideExpression(FactorOutsideExpression),
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExternModuleDeclarationOpt0 {
+    pub port_declaration: Box<PortDeclaration>,
 }
 
 ///
-/// Type derived for non-terminal FactorGroup
+/// Type derived for non-terminal ExternTerm
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub enum FactorGroup {
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExternTerm {
+    pub extern_term: crate::veryl_token::Token, /* (?-u:\b)extern(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal ExternToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExternToken {
+    pub extern_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal F32
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct F32 {
+    pub f32_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal F32Term
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct F32Term {
+    pub f32_term: crate::veryl_token::Token, /* (?-u:\b)f32(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal F32Token
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct F32Token {
+    pub f32_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal F64
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct F64 {
+    pub f64_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal F64Term
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct F64Term {
+    pub f64_term: crate::veryl_token::Token, /* (?-u:\b)f64(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal F64Token
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct F64Token {
+    pub f64_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal Factor
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Factor {
+    Number(FactorNumber),
+    ExpressionIdentifierFactorOpt(FactorExpressionIdentifierFactorOpt),
+    LParenExpressionRParen(FactorLParenExpressionRParen),
+    LBraceConcatenationListRBrace(FactorLBraceConcatenationListRBrace),
+    IfExpression(FactorIfExpression),
+    CaseExpression(FactorCaseExpression),
+    StringLiteral(FactorStringLiteral),
+    FactorGroup(FactorFactorGroup),
+    InsideExpression(FactorInsideExpression),
+    OutsideExpression(FactorOutsideExpression),
+}
+
+///
+/// Type derived for non-terminal FactorGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum FactorGroup {
     Msb(FactorGroupMsb),
     Lsb(FactorGroupLsb),
 }
@@ -6198,6 +6922,7 @@ pub struct FunctionDeclaration {
     pub identifier: Box<Identifier>,
     pub function_declaration_opt: Option<Box<FunctionDeclarationOpt>>,
     pub function_declaration_opt0: Option<Box<FunctionDeclarationOpt0>>,
+    pub function_declaration_opt1: Option<Box<FunctionDeclarationOpt1>>,
     pub minus_g_t: Box<MinusGT>,
     pub scalar_type: Box<ScalarType>,
     pub l_brace: Box<LBrace>,
@@ -6222,7 +6947,7 @@ pub struct FunctionDeclarationList {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionDeclarationOpt {
-    pub with_parameter: Box<WithParameter>,
+    pub with_generic_parameter: Box<WithGenericParameter>,
 }
 
 ///
@@ -6232,6 +6957,16 @@ pub struct FunctionDeclarationOpt {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionDeclarationOpt0 {
+    pub with_parameter: Box<WithParameter>,
+}
+
+///
+/// Type derived for non-terminal FunctionDeclarationOpt1
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FunctionDeclarationOpt1 {
     pub port_declaration: Box<PortDeclaration>,
 }
 
@@ -6785,6 +7520,53 @@ pub struct InToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Include
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Include {
+    pub include_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal IncludeDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct IncludeDeclaration {
+    pub include: Box<Include>,
+    pub l_paren: Box<LParen>,
+    pub identifier: Box<Identifier>,
+    pub comma: Box<Comma>,
+    pub string_literal: Box<StringLiteral>,
+    pub r_paren: Box<RParen>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal IncludeTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct IncludeTerm {
+    pub include_term: crate::veryl_token::Token, /* (?-u:\b)include(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal IncludeToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct IncludeToken {
+    pub include_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Initial
 ///
@@ -7262,6 +8044,7 @@ pub struct InterfaceDeclaration {
     pub interface: Box<Interface>,
     pub identifier: Box<Identifier>,
     pub interface_declaration_opt: Option<Box<InterfaceDeclarationOpt>>,
+    pub interface_declaration_opt0: Option<Box<InterfaceDeclarationOpt0>>,
     pub l_brace: Box<LBrace>,
     pub interface_declaration_list: Vec<InterfaceDeclarationList>,
     pub r_brace: Box<RBrace>,
@@ -7284,6 +8067,16 @@ pub struct InterfaceDeclarationList {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceDeclarationOpt {
+    pub with_generic_parameter: Box<WithGenericParameter>,
+}
+
+///
+/// Type derived for non-terminal InterfaceDeclarationOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InterfaceDeclarationOpt0 {
     pub with_parameter: Box<WithParameter>,
 }
 
@@ -7406,11 +8199,14 @@ pub enum InterfaceItem {
     InterfaceForDeclaration(InterfaceItemInterfaceForDeclaration),
     EnumDeclaration(InterfaceItemEnumDeclaration),
     StructDeclaration(InterfaceItemStructDeclaration),
+    UnionDeclaration(InterfaceItemUnionDeclaration),
     InterfaceNamedBlock(InterfaceItemInterfaceNamedBlock),
     FunctionDeclaration(InterfaceItemFunctionDeclaration),
     ImportDeclaration(InterfaceItemImportDeclaration),
     InitialDeclaration(InterfaceItemInitialDeclaration),
     FinalDeclaration(InterfaceItemFinalDeclaration),
+    EmbedDeclaration(InterfaceItemEmbedDeclaration),
+    IncludeDeclaration(InterfaceItemIncludeDeclaration),
 }
 
 ///
@@ -7937,6 +8733,7 @@ pub struct ModuleDeclaration {
     pub identifier: Box<Identifier>,
     pub module_declaration_opt: Option<Box<ModuleDeclarationOpt>>,
     pub module_declaration_opt0: Option<Box<ModuleDeclarationOpt0>>,
+    pub module_declaration_opt1: Option<Box<ModuleDeclarationOpt1>>,
     pub l_brace: Box<LBrace>,
     pub module_declaration_list: Vec<ModuleDeclarationList>,
     pub r_brace: Box<RBrace>,
@@ -7959,7 +8756,7 @@ pub struct ModuleDeclarationList {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationOpt {
-    pub with_parameter: Box<WithParameter>,
+    pub with_generic_parameter: Box<WithGenericParameter>,
 }
 
 ///
@@ -7969,6 +8766,16 @@ pub struct ModuleDeclarationOpt {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationOpt0 {
+    pub with_parameter: Box<WithParameter>,
+}
+
+///
+/// Type derived for non-terminal ModuleDeclarationOpt1
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleDeclarationOpt1 {
     pub port_declaration: Box<PortDeclaration>,
 }
 
@@ -8095,10 +8902,13 @@ pub enum ModuleItem {
     ModuleForDeclaration(ModuleItemModuleForDeclaration),
     EnumDeclaration(ModuleItemEnumDeclaration),
     StructDeclaration(ModuleItemStructDeclaration),
+    UnionDeclaration(ModuleItemUnionDeclaration),
     ModuleNamedBlock(ModuleItemModuleNamedBlock),
     ImportDeclaration(ModuleItemImportDeclaration),
     InitialDeclaration(ModuleItemInitialDeclaration),
     FinalDeclaration(ModuleItemFinalDeclaration),
+    EmbedDeclaration(ModuleItemEmbedDeclaration),
+    IncludeDeclaration(ModuleItemIncludeDeclaration),
 }
 
 ///
@@ -8688,6 +9498,7 @@ pub struct Package {
 pub struct PackageDeclaration {
     pub package: Box<Package>,
     pub identifier: Box<Identifier>,
+    pub package_declaration_opt: Option<Box<PackageDeclarationOpt>>,
     pub l_brace: Box<LBrace>,
     pub package_declaration_list: Vec<PackageDeclarationList>,
     pub r_brace: Box<RBrace>,
@@ -8703,6 +9514,16 @@ pub struct PackageDeclarationList {
     pub package_group: Box<PackageGroup>,
 }
 
+///
+/// Type derived for non-terminal PackageDeclarationOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PackageDeclarationOpt {
+    pub with_generic_parameter: Box<WithGenericParameter>,
+}
+
 ///
 /// Type derived for non-terminal PackageGroup
 ///
@@ -8754,11 +9575,14 @@ pub enum PackageItem {
     LocalparamDeclaration(PackageItemLocalparamDeclaration),
     EnumDeclaration(PackageItemEnumDeclaration),
     StructDeclaration(PackageItemStructDeclaration),
+    UnionDeclaration(PackageItemUnionDeclaration),
     FunctionDeclaration(PackageItemFunctionDeclaration),
     ImportDeclaration(PackageItemImportDeclaration),
     ExportDeclaration(PackageItemExportDeclaration),
     InitialDeclaration(PackageItemInitialDeclaration),
     FinalDeclaration(PackageItemFinalDeclaration),
+    EmbedDeclaration(PackageItemEmbedDeclaration),
+    IncludeDeclaration(PackageItemIncludeDeclaration),
 }
 
 ///
@@ -8905,8 +9729,10 @@ pub struct PortDeclarationItem {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum PortDeclarationItemGroup {
-    DirectionArrayType(PortDeclarationItemGroupDirectionArrayType),
-    InterfacePortDeclarationItemOpt(PortDeclarationItemGroupInterfacePortDeclarationItemOpt),
+    DirectionPortDeclarationItemOptArrayType(
+        PortDeclarationItemGroupDirectionPortDeclarationItemOptArrayType,
+    ),
+    InterfacePortDeclarationItemOpt0(PortDeclarationItemGroupInterfacePortDeclarationItemOpt0),
 }
 
 ///
@@ -8916,6 +9742,16 @@ pub enum PortDeclarationItemGroup {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationItemOpt {
+    pub clock_domain: Box<ClockDomain>,
+}
+
+///
+/// Type derived for non-terminal PortDeclarationItemOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PortDeclarationItemOpt0 {
     pub array: Box<Array>,
 }
 
@@ -8993,6 +9829,37 @@ pub struct PosedgeToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Quote
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Quote {
+    pub quote_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal QuoteTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct QuoteTerm {
+    pub quote_term: crate::veryl_token::Token, /* ' */
+}
+
+///
+/// Type derived for non-terminal QuoteToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct QuoteToken {
+    pub quote_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal RAngle
 ///
@@ -9264,6 +10131,37 @@ pub struct RepeatToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Reset
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Reset {
+    pub reset_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal ResetTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ResetTerm {
+    pub reset_term: crate::veryl_token::Token, /* (?-u:\b)reset(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal ResetToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ResetToken {
+    pub reset_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Return
 ///
@@ -9346,6 +10244,7 @@ pub struct ScalarTypeList {
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScopedIdentifier {
     pub identifier: Box<Identifier>,
+    pub scoped_identifier_opt: Option<Box<ScopedIdentifierOpt>>,
     pub scoped_identifier_list: Vec<ScopedIdentifierList>,
 }
 
@@ -9358,6 +10257,27 @@ pub struct ScopedIdentifier {
 pub struct ScopedIdentifierList {
     pub colon_colon: Box<ColonColon>,
     pub identifier: Box<Identifier>,
+    pub scoped_identifier_opt0: Option<Box<ScopedIdentifierOpt0>>,
+}
+
+///
+/// Type derived for non-terminal ScopedIdentifierOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScopedIdentifierOpt {
+    pub with_generic_argument: Box<WithGenericArgument>,
+}
+
+///
+/// Type derived for non-terminal ScopedIdentifierOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScopedIdentifierOpt0 {
+    pub with_generic_argument: Box<WithGenericArgument>,
 }
 
 ///
@@ -9975,59 +10895,115 @@ pub struct UnaryOperatorToken {
 }
 
 ///
-/// Type derived for non-terminal Var
+/// Type derived for non-terminal Union
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Var {
-    pub var_token: crate::veryl_token::VerylToken,
+pub struct Union {
+    pub union_token: crate::veryl_token::VerylToken,
 }
 
 ///
-/// Type derived for non-terminal VarDeclaration
+/// Type derived for non-terminal UnionDeclaration
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct VarDeclaration {
-    pub var: Box<Var>,
+pub struct UnionDeclaration {
+    pub r#union: Box<Union>,
     pub identifier: Box<Identifier>,
-    pub colon: Box<Colon>,
-    pub array_type: Box<ArrayType>,
-    pub var_declaration_opt: Option<Box<VarDeclarationOpt>>,
-    pub semicolon: Box<Semicolon>,
+    pub l_brace: Box<LBrace>,
+    pub struct_list: Box<StructList>,
+    pub r_brace: Box<RBrace>,
 }
 
 ///
-/// Type derived for non-terminal VarDeclarationOpt
+/// Type derived for non-terminal UnionTerm
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct VarDeclarationOpt {
-    pub equ: Box<Equ>,
-    pub expression: Box<Expression>,
+pub struct UnionTerm {
+    pub union_term: crate::veryl_token::Token, /* (?-u:\b)union(?-u:\b) */
 }
 
 ///
-/// Type derived for non-terminal VarTerm
+/// Type derived for non-terminal UnionToken
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct VarTerm {
-    pub var_term: crate::veryl_token::Token, /* (?-u:\b)var(?-u:\b) */
+pub struct UnionToken {
+    pub union_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
 }
 
 ///
-/// Type derived for non-terminal VarToken
+/// Type derived for non-terminal Var
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct VarToken {
-    pub var_term: crate::veryl_token::Token,
+pub struct Var {
+    pub var_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal VarDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct VarDeclaration {
+    pub var: Box<Var>,
+    pub identifier: Box<Identifier>,
+    pub colon: Box<Colon>,
+    pub var_declaration_opt: Option<Box<VarDeclarationOpt>>,
+    pub array_type: Box<ArrayType>,
+    pub var_declaration_opt0: Option<Box<VarDeclarationOpt0>>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal VarDeclarationOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct VarDeclarationOpt {
+    pub clock_domain: Box<ClockDomain>,
+}
+
+///
+/// Type derived for non-terminal VarDeclarationOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct VarDeclarationOpt0 {
+    pub equ: Box<Equ>,
+    pub expression: Box<Expression>,
+}
+
+///
+/// Type derived for non-terminal VarTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct VarTerm {
+    pub var_term: crate::veryl_token::Token, /* (?-u:\b)var(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal VarToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct VarToken {
+    pub var_term: crate::veryl_token::Token,
     pub comments: Box<Comments>,
 }
 
@@ -10048,6 +11024,8 @@ pub struct VariableType {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum VariableTypeGroup {
+    Clock(VariableTypeGroupClock),
+    Reset(VariableTypeGroupReset),
     Logic(VariableTypeGroupLogic),
     Bit(VariableTypeGroupBit),
     ScopedIdentifier(VariableTypeGroupScopedIdentifier),
@@ -10108,6 +11086,116 @@ pub struct WidthList {
     pub expression: Box<Expression>,
 }
 
+///
+/// Type derived for non-terminal WithGenericArgument
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericArgument {
+    pub colon_colon_l_angle: Box<ColonColonLAngle>,
+    pub with_generic_argument_list: Box<WithGenericArgumentList>,
+    pub r_angle: Box<RAngle>,
+}
+
+///
+/// Type derived for non-terminal WithGenericArgumentItem
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum WithGenericArgumentItem {
+    ScopedIdentifier(WithGenericArgumentItemScopedIdentifier),
+    Number(WithGenericArgumentItemNumber),
+}
+
+///
+/// Type derived for non-terminal WithGenericArgumentList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericArgumentList {
+    pub with_generic_argument_item: Box<WithGenericArgumentItem>,
+    pub with_generic_argument_list_list: Vec<WithGenericArgumentListList>,
+    pub with_generic_argument_list_opt: Option<Box<WithGenericArgumentListOpt>>,
+}
+
+///
+/// Type derived for non-terminal WithGenericArgumentListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericArgumentListList {
+    pub comma: Box<Comma>,
+    pub with_generic_argument_item: Box<WithGenericArgumentItem>,
+}
+
+///
+/// Type derived for non-terminal WithGenericArgumentListOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericArgumentListOpt {
+    pub comma: Box<Comma>,
+}
+
+///
+/// Type derived for non-terminal WithGenericParameter
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericParameter {
+    pub colon_colon_l_angle: Box<ColonColonLAngle>,
+    pub with_generic_parameter_list: Box<WithGenericParameterList>,
+    pub r_angle: Box<RAngle>,
+}
+
+///
+/// Type derived for non-terminal WithGenericParameterItem
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericParameterItem {
+    pub identifier: Box<Identifier>,
+}
+
+///
+/// Type derived for non-terminal WithGenericParameterList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericParameterList {
+    pub with_generic_parameter_item: Box<WithGenericParameterItem>,
+    pub with_generic_parameter_list_list: Vec<WithGenericParameterListList>,
+    pub with_generic_parameter_list_opt: Option<Box<WithGenericParameterListOpt>>,
+}
+
+///
+/// Type derived for non-terminal WithGenericParameterListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericParameterListList {
+    pub comma: Box<Comma>,
+    pub with_generic_parameter_item: Box<WithGenericParameterItem>,
+}
+
+///
+/// Type derived for non-terminal WithGenericParameterListOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithGenericParameterListOpt {
+    pub comma: Box<Comma>,
+}
+
 ///
 /// Type derived for non-terminal WithParameter
 ///
@@ -10256,6 +11344,7 @@ pub enum ASTType {
     AlwaysFfResetOptGroup(AlwaysFfResetOptGroup),
     AlwaysFfTerm(AlwaysFfTerm),
     AlwaysFfToken(AlwaysFfToken),
+    AnyTerm(AnyTerm),
     ArgumentItem(ArgumentItem),
     ArgumentList(ArgumentList),
     ArgumentListList(Vec<ArgumentListList>),
@@ -10284,6 +11373,8 @@ pub enum ASTType {
     AsyncLowToken(AsyncLowToken),
     Attribute(Attribute),
     AttributeItem(AttributeItem),
+    AttributeItemOpt(Option<Box<AttributeItemOpt>>),
+    AttributeItemOptGroup(AttributeItemOptGroup),
     AttributeList(AttributeList),
     AttributeListList(Vec<AttributeListList>),
     AttributeListOpt(Option<Box<AttributeListOpt>>),
@@ -10309,8 +11400,15 @@ pub enum ASTType {
     CaseStatementList(Vec<CaseStatementList>),
     CaseTerm(CaseTerm),
     CaseToken(CaseToken),
+    Clock(Clock),
+    ClockDomain(ClockDomain),
+    ClockTerm(ClockTerm),
+    ClockToken(ClockToken),
     Colon(Colon),
     ColonColon(ColonColon),
+    ColonColonLAngle(ColonColonLAngle),
+    ColonColonLAngleTerm(ColonColonLAngleTerm),
+    ColonColonLAngleToken(ColonColonLAngleToken),
     ColonColonTerm(ColonColonTerm),
     ColonColonToken(ColonColonToken),
     ColonTerm(ColonTerm),
@@ -10350,6 +11448,15 @@ pub enum ASTType {
     Else(Else),
     ElseTerm(ElseTerm),
     ElseToken(ElseToken),
+    Embed(Embed),
+    EmbedContent(EmbedContent),
+    EmbedContentToken(EmbedContentToken),
+    EmbedContentTokenList(Vec<EmbedContentTokenList>),
+    EmbedDeclaration(EmbedDeclaration),
+    EmbedItem(EmbedItem),
+    EmbedItemList(Vec<EmbedItemList>),
+    EmbedTerm(EmbedTerm),
+    EmbedToken(EmbedToken),
     Enum(Enum),
     EnumDeclaration(EnumDeclaration),
     EnumGroup(EnumGroup),
@@ -10409,7 +11516,16 @@ pub enum ASTType {
     ExpressionIdentifierGroupList2(Vec<ExpressionIdentifierGroupList2>),
     ExpressionIdentifierGroupList2List(Vec<ExpressionIdentifierGroupList2List>),
     ExpressionIdentifierOpt(Option<Box<ExpressionIdentifierOpt>>),
+    ExpressionIdentifierOpt0(Option<Box<ExpressionIdentifierOpt0>>),
+    ExpressionIdentifierOpt1(Option<Box<ExpressionIdentifierOpt1>>),
+    ExpressionIdentifierOpt2(Option<Box<ExpressionIdentifierOpt2>>),
     ExpressionList(Vec<ExpressionList>),
+    Extern(Extern),
+    ExternModuleDeclaration(ExternModuleDeclaration),
+    ExternModuleDeclarationOpt(Option<Box<ExternModuleDeclarationOpt>>),
+    ExternModuleDeclarationOpt0(Option<Box<ExternModuleDeclarationOpt0>>),
+    ExternTerm(ExternTerm),
+    ExternToken(ExternToken),
     F32(F32),
     F32Term(F32Term),
     F32Token(F32Token),
@@ -10441,6 +11557,7 @@ pub enum ASTType {
     FunctionDeclarationList(Vec<FunctionDeclarationList>),
     FunctionDeclarationOpt(Option<Box<FunctionDeclarationOpt>>),
     FunctionDeclarationOpt0(Option<Box<FunctionDeclarationOpt0>>),
+    FunctionDeclarationOpt1(Option<Box<FunctionDeclarationOpt1>>),
     FunctionItem(FunctionItem),
     FunctionTerm(FunctionTerm),
     FunctionToken(FunctionToken),
@@ -10490,6 +11607,10 @@ pub enum ASTType {
     In(In),
     InTerm(InTerm),
     InToken(InToken),
+    Include(Include),
+    IncludeDeclaration(IncludeDeclaration),
+    IncludeTerm(IncludeTerm),
+    IncludeToken(IncludeToken),
     Initial(Initial),
     InitialDeclaration(InitialDeclaration),
     InitialDeclarationList(Vec<InitialDeclarationList>),
@@ -10536,6 +11657,7 @@ pub enum ASTType {
     InterfaceDeclaration(InterfaceDeclaration),
     InterfaceDeclarationList(Vec<InterfaceDeclarationList>),
     InterfaceDeclarationOpt(Option<Box<InterfaceDeclarationOpt>>),
+    InterfaceDeclarationOpt0(Option<Box<InterfaceDeclarationOpt0>>),
     InterfaceForDeclaration(InterfaceForDeclaration),
     InterfaceForDeclarationOpt(Option<Box<InterfaceForDeclarationOpt>>),
     InterfaceGroup(InterfaceGroup),
@@ -10598,6 +11720,7 @@ pub enum ASTType {
     ModuleDeclarationList(Vec<ModuleDeclarationList>),
     ModuleDeclarationOpt(Option<Box<ModuleDeclarationOpt>>),
     ModuleDeclarationOpt0(Option<Box<ModuleDeclarationOpt0>>),
+    ModuleDeclarationOpt1(Option<Box<ModuleDeclarationOpt1>>),
     ModuleForDeclaration(ModuleForDeclaration),
     ModuleForDeclarationOpt(Option<Box<ModuleForDeclarationOpt>>),
     ModuleGroup(ModuleGroup),
@@ -10665,6 +11788,7 @@ pub enum ASTType {
     Package(Package),
     PackageDeclaration(PackageDeclaration),
     PackageDeclarationList(Vec<PackageDeclarationList>),
+    PackageDeclarationOpt(Option<Box<PackageDeclarationOpt>>),
     PackageGroup(PackageGroup),
     PackageGroupGroup(PackageGroupGroup),
     PackageGroupGroupList(Vec<PackageGroupGroupList>),
@@ -10685,6 +11809,7 @@ pub enum ASTType {
     PortDeclarationItem(PortDeclarationItem),
     PortDeclarationItemGroup(PortDeclarationItemGroup),
     PortDeclarationItemOpt(Option<Box<PortDeclarationItemOpt>>),
+    PortDeclarationItemOpt0(Option<Box<PortDeclarationItemOpt0>>),
     PortDeclarationList(PortDeclarationList),
     PortDeclarationListList(Vec<PortDeclarationListList>),
     PortDeclarationListOpt(Option<Box<PortDeclarationListOpt>>),
@@ -10692,6 +11817,9 @@ pub enum ASTType {
     Posedge(Posedge),
     PosedgeTerm(PosedgeTerm),
     PosedgeToken(PosedgeToken),
+    Quote(Quote),
+    QuoteTerm(QuoteTerm),
+    QuoteToken(QuoteToken),
     RAngle(RAngle),
     RAngleTerm(RAngleTerm),
     RAngleToken(RAngleToken),
@@ -10718,6 +11846,9 @@ pub enum ASTType {
     Repeat(Repeat),
     RepeatTerm(RepeatTerm),
     RepeatToken(RepeatToken),
+    Reset(Reset),
+    ResetTerm(ResetTerm),
+    ResetToken(ResetToken),
     Return(Return),
     ReturnStatement(ReturnStatement),
     ReturnTerm(ReturnTerm),
@@ -10727,6 +11858,8 @@ pub enum ASTType {
     ScalarTypeList(Vec<ScalarTypeList>),
     ScopedIdentifier(ScopedIdentifier),
     ScopedIdentifierList(Vec<ScopedIdentifierList>),
+    ScopedIdentifierOpt(Option<Box<ScopedIdentifierOpt>>),
+    ScopedIdentifierOpt0(Option<Box<ScopedIdentifierOpt0>>),
     Select(Select),
     SelectOperator(SelectOperator),
     SelectOpt(Option<Box<SelectOpt>>),
@@ -10785,9 +11918,14 @@ pub enum ASTType {
     UnaryOperator(UnaryOperator),
     UnaryOperatorTerm(UnaryOperatorTerm),
     UnaryOperatorToken(UnaryOperatorToken),
+    Union(Union),
+    UnionDeclaration(UnionDeclaration),
+    UnionTerm(UnionTerm),
+    UnionToken(UnionToken),
     Var(Var),
     VarDeclaration(VarDeclaration),
     VarDeclarationOpt(Option<Box<VarDeclarationOpt>>),
+    VarDeclarationOpt0(Option<Box<VarDeclarationOpt0>>),
     VarTerm(VarTerm),
     VarToken(VarToken),
     VariableType(VariableType),
@@ -10797,6 +11935,16 @@ pub enum ASTType {
     VerylList(Vec<VerylList>),
     Width(Width),
     WidthList(Vec<WidthList>),
+    WithGenericArgument(WithGenericArgument),
+    WithGenericArgumentItem(WithGenericArgumentItem),
+    WithGenericArgumentList(WithGenericArgumentList),
+    WithGenericArgumentListList(Vec<WithGenericArgumentListList>),
+    WithGenericArgumentListOpt(Option<Box<WithGenericArgumentListOpt>>),
+    WithGenericParameter(WithGenericParameter),
+    WithGenericParameterItem(WithGenericParameterItem),
+    WithGenericParameterList(WithGenericParameterList),
+    WithGenericParameterListList(Vec<WithGenericParameterListList>),
+    WithGenericParameterListOpt(Option<Box<WithGenericParameterListOpt>>),
     WithParameter(WithParameter),
     WithParameterGroup(WithParameterGroup),
     WithParameterGroupGroup(WithParameterGroupGroup),
@@ -10874,7 +12022,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 0:
     ///
-    /// CommentsTerm: "(?:(?:(?://.*(?:\r\n|\r|\n|$))|(?:(?ms)/\u{2a}.*?\u{2a}/))\s*)+" : Token;
+    /// CommentsTerm: <INITIAL, Generic>"(?:(?:(?://.*(?:\r\n|\r|\n|$))|(?:(?ms)/\u{2a}.*?\u{2a}/))\s*)+" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn comments_term(&mut self, comments_term: &ParseTreeType<'t>) -> Result<()> {
@@ -10893,7 +12041,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 1:
     ///
-    /// StringLiteralTerm: "\u{0022}(?:\\[\u{0022}\\/bfnrt]|u[0-9a-fA-F]{4}|[^\u{0022}\\\u0000-\u001F])*\u{0022}" : Token;
+    /// StringLiteralTerm: <INITIAL, Generic>"\u{0022}(?:\\[\u{0022}\\/bfnrt]|u[0-9a-fA-F]{4}|[^\u{0022}\\\u0000-\u001F])*\u{0022}" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn string_literal_term(&mut self, string_literal_term: &ParseTreeType<'t>) -> Result<()> {
@@ -10918,7 +12066,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 2:
     ///
-    /// ExponentTerm: /[0-9]+(?:_[0-9]+)*\.[0-9]+(?:_[0-9]+)*[eE][+-]?[0-9]+(?:_[0-9]+)*/ : Token;
+    /// ExponentTerm: <INITIAL, Generic>/[0-9]+(?:_[0-9]+)*\.[0-9]+(?:_[0-9]+)*[eE][+-]?[0-9]+(?:_[0-9]+)*/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn exponent_term(&mut self, exponent_term: &ParseTreeType<'t>) -> Result<()> {
@@ -10937,7 +12085,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 3:
     ///
-    /// FixedPointTerm: /[0-9]+(?:_[0-9]+)*\.[0-9]+(?:_[0-9]+)*/ : Token;
+    /// FixedPointTerm: <INITIAL, Generic>/[0-9]+(?:_[0-9]+)*\.[0-9]+(?:_[0-9]+)*/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn fixed_point_term(&mut self, fixed_point_term: &ParseTreeType<'t>) -> Result<()> {
@@ -10957,7 +12105,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 4:
     ///
-    /// BasedTerm: /(?:[0-9]+(?:_[0-9]+)*)?'[bodh][0-9a-fA-FxzXZ]+(?:_[0-9a-fA-FxzXZ]+)*/ : Token;
+    /// BasedTerm: <INITIAL, Generic>/(?:[0-9]+(?:_[0-9]+)*)?'[bodh][0-9a-fA-FxzXZ]+(?:_[0-9a-fA-FxzXZ]+)*/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn based_term(&mut self, based_term: &ParseTreeType<'t>) -> Result<()> {
@@ -10976,7 +12124,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 5:
     ///
-    /// AllBitTerm: /(?:[0-9]+(?:_[0-9]+)*)?'[01xzXZ]/ : Token;
+    /// AllBitTerm: <INITIAL, Generic>/(?:[0-9]+(?:_[0-9]+)*)?'[01xzXZ]/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn all_bit_term(&mut self, all_bit_term: &ParseTreeType<'t>) -> Result<()> {
@@ -10995,7 +12143,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 6:
     ///
-    /// BaseLessTerm: /[0-9]+(?:_[0-9]+)*/ : Token;
+    /// BaseLessTerm: <INITIAL, Generic>/[0-9]+(?:_[0-9]+)*/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn base_less_term(&mut self, base_less_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11014,7 +12162,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 7:
     ///
-    /// MinusColonTerm: '-:' : Token;
+    /// MinusColonTerm: <INITIAL, Generic>'-:' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn minus_colon_term(&mut self, minus_colon_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11034,7 +12182,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 8:
     ///
-    /// MinusGTTerm: '->' : Token;
+    /// MinusGTTerm: <INITIAL, Generic>'->' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn minus_g_t_term(&mut self, minus_g_t_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11053,7 +12201,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 9:
     ///
-    /// PlusColonTerm: '+:' : Token;
+    /// PlusColonTerm: <INITIAL, Generic>'+:' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn plus_colon_term(&mut self, plus_colon_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11100,7 +12248,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 11:
     ///
-    /// Operator11Term: "\*\*" : Token;
+    /// Operator11Term: <INITIAL, Generic>"\*\*" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator11_term(&mut self, operator11_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11119,7 +12267,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 12:
     ///
-    /// Operator10Term: "/|%" : Token;
+    /// Operator10Term: <INITIAL, Generic>"/|%" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator10_term(&mut self, operator10_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11138,7 +12286,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 13:
     ///
-    /// Operator09Term: "\+|-" : Token;
+    /// Operator09Term: <INITIAL, Generic>"\+|-" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator09_term(&mut self, operator09_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11195,7 +12343,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 16:
     ///
-    /// Operator06Term: "===|==\?|!==|!=\?|==|!=" : Token;
+    /// Operator06Term: <INITIAL, Generic>"===|==\?|!==|!=\?|==|!=" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator06_term(&mut self, operator06_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11214,7 +12362,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 17:
     ///
-    /// Operator02Term: "&&" : Token;
+    /// Operator02Term: <INITIAL, Generic>"&&" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator02_term(&mut self, operator02_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11233,7 +12381,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 18:
     ///
-    /// Operator01Term: "\|\|" : Token;
+    /// Operator01Term: <INITIAL, Generic>"\|\|" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator01_term(&mut self, operator01_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11252,7 +12400,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 19:
     ///
-    /// Operator05Term: "&" : Token;
+    /// Operator05Term: <INITIAL, Generic>"&" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator05_term(&mut self, operator05_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11271,7 +12419,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 20:
     ///
-    /// Operator04Term: "\^~|\^|~\^" : Token;
+    /// Operator04Term: <INITIAL, Generic>"\^~|\^|~\^" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator04_term(&mut self, operator04_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11290,7 +12438,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 21:
     ///
-    /// Operator03Term: "\|" : Token;
+    /// Operator03Term: <INITIAL, Generic>"\|" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn operator03_term(&mut self, operator03_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11309,7 +12457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 22:
     ///
-    /// UnaryOperatorTerm: "~&|~\||!|~" : Token;
+    /// UnaryOperatorTerm: <INITIAL, Generic>"~&|~\||!|~" : Token;
     ///
     #[parol_runtime::function_name::named]
     fn unary_operator_term(&mut self, unary_operator_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11334,7 +12482,35 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 23:
     ///
-    /// ColonColonTerm: '::' : Token;
+    /// ColonColonLAngleTerm: <INITIAL, Generic>'::<' : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn colon_colon_l_angle_term(
+        &mut self,
+        colon_colon_l_angle_term: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let colon_colon_l_angle_term = colon_colon_l_angle_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let colon_colon_l_angle_term_built = ColonColonLAngleTerm {
+            colon_colon_l_angle_term,
+        };
+        // Calling user action here
+        self.user_grammar
+            .colon_colon_l_angle_term(&colon_colon_l_angle_term_built)?;
+        self.push(
+            ASTType::ColonColonLAngleTerm(colon_colon_l_angle_term_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 24:
+    ///
+    /// ColonColonTerm: <INITIAL, Generic>'::' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn colon_colon_term(&mut self, colon_colon_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11352,9 +12528,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 24:
+    /// Semantic action for production 25:
     ///
-    /// ColonTerm: ':' : Token;
+    /// ColonTerm: <INITIAL, Generic>':' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn colon_term(&mut self, colon_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11371,9 +12547,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 25:
+    /// Semantic action for production 26:
     ///
-    /// CommaTerm: ',' : Token;
+    /// CommaTerm: <INITIAL, Generic>',' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn comma_term(&mut self, comma_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11390,9 +12566,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 26:
+    /// Semantic action for production 27:
     ///
-    /// DollarTerm: '$' : Token;
+    /// DollarTerm: <INITIAL, Generic>'$' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn dollar_term(&mut self, dollar_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11409,9 +12585,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 27:
+    /// Semantic action for production 28:
     ///
-    /// DotDotEquTerm: '..=' : Token;
+    /// DotDotEquTerm: <INITIAL, Generic>'..=' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn dot_dot_equ_term(&mut self, dot_dot_equ_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11429,9 +12605,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 28:
+    /// Semantic action for production 29:
     ///
-    /// DotDotTerm: '..' : Token;
+    /// DotDotTerm: <INITIAL, Generic>'..' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn dot_dot_term(&mut self, dot_dot_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11448,9 +12624,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 29:
+    /// Semantic action for production 30:
     ///
-    /// DotTerm: '.' : Token;
+    /// DotTerm: <INITIAL, Generic>'.' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn dot_term(&mut self, dot_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11467,9 +12643,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 30:
+    /// Semantic action for production 31:
     ///
-    /// EquTerm: '=' : Token;
+    /// EquTerm: <INITIAL, Generic>'=' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn equ_term(&mut self, equ_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11486,9 +12662,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 31:
+    /// Semantic action for production 32:
     ///
-    /// HashTerm: '#' : Token;
+    /// HashTerm: <INITIAL, Generic>'#' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn hash_term(&mut self, hash_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11505,9 +12681,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 32:
+    /// Semantic action for production 33:
     ///
-    /// LAngleTerm: '<' : Token;
+    /// LAngleTerm: <INITIAL, Generic>'<' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn l_angle_term(&mut self, l_angle_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11524,9 +12700,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 33:
+    /// Semantic action for production 34:
     ///
-    /// LBraceTerm: '{' : Token;
+    /// LBraceTerm: <INITIAL, Generic, Embed>'{' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn l_brace_term(&mut self, l_brace_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11543,9 +12719,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 34:
+    /// Semantic action for production 35:
     ///
-    /// LBracketTerm: '[' : Token;
+    /// LBracketTerm: <INITIAL, Generic>'[' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn l_bracket_term(&mut self, l_bracket_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11562,9 +12738,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 35:
+    /// Semantic action for production 36:
     ///
-    /// LParenTerm: '(' : Token;
+    /// LParenTerm: <INITIAL, Generic>'(' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn l_paren_term(&mut self, l_paren_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11581,9 +12757,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 36:
+    /// Semantic action for production 37:
+    ///
+    /// QuoteTerm: <INITIAL, Generic>"'" : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn quote_term(&mut self, quote_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let quote_term = quote_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let quote_term_built = QuoteTerm { quote_term };
+        // Calling user action here
+        self.user_grammar.quote_term(&quote_term_built)?;
+        self.push(ASTType::QuoteTerm(quote_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 38:
     ///
-    /// RAngleTerm: '>' : Token;
+    /// RAngleTerm: <INITIAL, Generic>'>' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn r_angle_term(&mut self, r_angle_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11600,9 +12795,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 37:
+    /// Semantic action for production 39:
     ///
-    /// RBraceTerm: '}' : Token;
+    /// RBraceTerm: <INITIAL, Generic, Embed>'}' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn r_brace_term(&mut self, r_brace_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11619,9 +12814,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 40:
     ///
-    /// RBracketTerm: ']' : Token;
+    /// RBracketTerm: <INITIAL, Generic>']' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn r_bracket_term(&mut self, r_bracket_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11638,9 +12833,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 41:
     ///
-    /// RParenTerm: ')' : Token;
+    /// RParenTerm: <INITIAL, Generic>')' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn r_paren_term(&mut self, r_paren_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11657,9 +12852,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 42:
     ///
-    /// SemicolonTerm: ';' : Token;
+    /// SemicolonTerm: <INITIAL, Generic>';' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn semicolon_term(&mut self, semicolon_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11676,9 +12871,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 43:
     ///
-    /// StarTerm: '*' : Token;
+    /// StarTerm: <INITIAL, Generic>'*' : Token;
     ///
     #[parol_runtime::function_name::named]
     fn star_term(&mut self, star_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11695,9 +12890,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 44:
     ///
-    /// AlwaysCombTerm: /(?-u:\b)always_comb(?-u:\b)/ : Token;
+    /// AlwaysCombTerm: <INITIAL, Generic>/(?-u:\b)always_comb(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn always_comb_term(&mut self, always_comb_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11715,9 +12910,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 45:
     ///
-    /// AlwaysFfTerm: /(?-u:\b)always_ff(?-u:\b)/ : Token;
+    /// AlwaysFfTerm: <INITIAL, Generic>/(?-u:\b)always_ff(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_term(&mut self, always_ff_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11734,9 +12929,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 46:
     ///
-    /// AssignTerm: /(?-u:\b)assign(?-u:\b)/ : Token;
+    /// AssignTerm: <INITIAL, Generic>/(?-u:\b)assign(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn assign_term(&mut self, assign_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11753,9 +12948,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 47:
     ///
-    /// AsyncHighTerm: /(?-u:\b)async_high(?-u:\b)/ : Token;
+    /// AsyncHighTerm: <INITIAL, Generic>/(?-u:\b)async_high(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn async_high_term(&mut self, async_high_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11772,9 +12967,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 48:
     ///
-    /// AsyncLowTerm: /(?-u:\b)async_low(?-u:\b)/ : Token;
+    /// AsyncLowTerm: <INITIAL, Generic>/(?-u:\b)async_low(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn async_low_term(&mut self, async_low_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11791,9 +12986,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 49:
     ///
-    /// AsTerm: /(?-u:\b)as(?-u:\b)/ : Token;
+    /// AsTerm: <INITIAL, Generic>/(?-u:\b)as(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn as_term(&mut self, as_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11810,9 +13005,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 50:
     ///
-    /// BitTerm: /(?-u:\b)bit(?-u:\b)/ : Token;
+    /// BitTerm: <INITIAL, Generic>/(?-u:\b)bit(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn bit_term(&mut self, bit_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11829,9 +13024,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 51:
     ///
-    /// CaseTerm: /(?-u:\b)case(?-u:\b)/ : Token;
+    /// CaseTerm: <INITIAL, Generic>/(?-u:\b)case(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn case_term(&mut self, case_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11848,9 +13043,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 52:
+    ///
+    /// ClockTerm: <INITIAL, Generic>/(?-u:\b)clock(?-u:\b)/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn clock_term(&mut self, clock_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let clock_term = clock_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let clock_term_built = ClockTerm { clock_term };
+        // Calling user action here
+        self.user_grammar.clock_term(&clock_term_built)?;
+        self.push(ASTType::ClockTerm(clock_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 53:
     ///
-    /// DefaultTerm: /(?-u:\b)default(?-u:\b)/ : Token;
+    /// DefaultTerm: <INITIAL, Generic>/(?-u:\b)default(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn default_term(&mut self, default_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11867,9 +13081,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 54:
     ///
-    /// ElseTerm: /(?-u:\b)else(?-u:\b)/ : Token;
+    /// ElseTerm: <INITIAL, Generic>/(?-u:\b)else(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn else_term(&mut self, else_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11886,9 +13100,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 55:
+    ///
+    /// EmbedTerm: <INITIAL, Generic>/(?-u:\b)embed(?-u:\b)/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_term(&mut self, embed_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_term = embed_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let embed_term_built = EmbedTerm { embed_term };
+        // Calling user action here
+        self.user_grammar.embed_term(&embed_term_built)?;
+        self.push(ASTType::EmbedTerm(embed_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 56:
     ///
-    /// EnumTerm: /(?-u:\b)enum(?-u:\b)/ : Token;
+    /// EnumTerm: <INITIAL, Generic>/(?-u:\b)enum(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn enum_term(&mut self, enum_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11905,9 +13138,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 57:
     ///
-    /// ExportTerm: /(?-u:\b)export(?-u:\b)/ : Token;
+    /// ExportTerm: <INITIAL, Generic>/(?-u:\b)export(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn export_term(&mut self, export_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11924,28 +13157,47 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 58:
     ///
-    /// F32Term: /(?-u:\b)f32(?-u:\b)/ : Token;
+    /// ExternTerm: <INITIAL, Generic>/(?-u:\b)extern(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
-    fn f32_term(&mut self, f32_term: &ParseTreeType<'t>) -> Result<()> {
+    fn extern_term(&mut self, extern_term: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let f32_term = f32_term
+        let extern_term = extern_term
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let f32_term_built = F32Term { f32_term };
+        let extern_term_built = ExternTerm { extern_term };
         // Calling user action here
-        self.user_grammar.f32_term(&f32_term_built)?;
+        self.user_grammar.extern_term(&extern_term_built)?;
+        self.push(ASTType::ExternTerm(extern_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 59:
+    ///
+    /// F32Term: <INITIAL, Generic>/(?-u:\b)f32(?-u:\b)/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn f32_term(&mut self, f32_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let f32_term = f32_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let f32_term_built = F32Term { f32_term };
+        // Calling user action here
+        self.user_grammar.f32_term(&f32_term_built)?;
         self.push(ASTType::F32Term(f32_term_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 60:
     ///
-    /// F64Term: /(?-u:\b)f64(?-u:\b)/ : Token;
+    /// F64Term: <INITIAL, Generic>/(?-u:\b)f64(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn f64_term(&mut self, f64_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11962,9 +13214,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 61:
     ///
-    /// FinalTerm: /(?-u:\b)final(?-u:\b)/ : Token;
+    /// FinalTerm: <INITIAL, Generic>/(?-u:\b)final(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn final_term(&mut self, final_term: &ParseTreeType<'t>) -> Result<()> {
@@ -11981,9 +13233,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 62:
     ///
-    /// ForTerm: /(?-u:\b)for(?-u:\b)/ : Token;
+    /// ForTerm: <INITIAL, Generic>/(?-u:\b)for(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn for_term(&mut self, for_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12000,9 +13252,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 63:
     ///
-    /// FunctionTerm: /(?-u:\b)function(?-u:\b)/ : Token;
+    /// FunctionTerm: <INITIAL, Generic>/(?-u:\b)function(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn function_term(&mut self, function_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12019,9 +13271,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 64:
     ///
-    /// I32Term: /(?-u:\b)i32(?-u:\b)/ : Token;
+    /// I32Term: <INITIAL, Generic>/(?-u:\b)i32(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn i32_term(&mut self, i32_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12038,9 +13290,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 65:
     ///
-    /// I64Term: /(?-u:\b)i64(?-u:\b)/ : Token;
+    /// I64Term: <INITIAL, Generic>/(?-u:\b)i64(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn i64_term(&mut self, i64_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12057,9 +13309,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 66:
     ///
-    /// IfResetTerm: /(?-u:\b)if_reset(?-u:\b)/ : Token;
+    /// IfResetTerm: <INITIAL, Generic>/(?-u:\b)if_reset(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_term(&mut self, if_reset_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12076,9 +13328,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 67:
     ///
-    /// IfTerm: /(?-u:\b)if(?-u:\b)/ : Token;
+    /// IfTerm: <INITIAL, Generic>/(?-u:\b)if(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn if_term(&mut self, if_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12095,9 +13347,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 68:
     ///
-    /// ImportTerm: /(?-u:\b)import(?-u:\b)/ : Token;
+    /// ImportTerm: <INITIAL, Generic>/(?-u:\b)import(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn import_term(&mut self, import_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12114,9 +13366,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 69:
+    ///
+    /// IncludeTerm: <INITIAL, Generic>/(?-u:\b)include(?-u:\b)/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn include_term(&mut self, include_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let include_term = include_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let include_term_built = IncludeTerm { include_term };
+        // Calling user action here
+        self.user_grammar.include_term(&include_term_built)?;
+        self.push(ASTType::IncludeTerm(include_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 70:
     ///
-    /// InitialTerm: /(?-u:\b)initial(?-u:\b)/ : Token;
+    /// InitialTerm: <INITIAL, Generic>/(?-u:\b)initial(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn initial_term(&mut self, initial_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12133,9 +13404,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 71:
     ///
-    /// InoutTerm: /(?-u:\b)inout(?-u:\b)/ : Token;
+    /// InoutTerm: <INITIAL, Generic>/(?-u:\b)inout(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn inout_term(&mut self, inout_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12152,9 +13423,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 72:
     ///
-    /// InputTerm: /(?-u:\b)input(?-u:\b)/ : Token;
+    /// InputTerm: <INITIAL, Generic>/(?-u:\b)input(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn input_term(&mut self, input_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12171,9 +13442,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 73:
     ///
-    /// InsideTerm: /(?-u:\b)inside(?-u:\b)/ : Token;
+    /// InsideTerm: <INITIAL, Generic>/(?-u:\b)inside(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn inside_term(&mut self, inside_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12190,9 +13461,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 74:
     ///
-    /// InstTerm: /(?-u:\b)inst(?-u:\b)/ : Token;
+    /// InstTerm: <INITIAL, Generic>/(?-u:\b)inst(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn inst_term(&mut self, inst_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12209,9 +13480,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 75:
     ///
-    /// InterfaceTerm: /(?-u:\b)interface(?-u:\b)/ : Token;
+    /// InterfaceTerm: <INITIAL, Generic>/(?-u:\b)interface(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn interface_term(&mut self, interface_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12228,9 +13499,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 76:
     ///
-    /// InTerm: /(?-u:\b)in(?-u:\b)/ : Token;
+    /// InTerm: <INITIAL, Generic>/(?-u:\b)in(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn in_term(&mut self, in_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12247,9 +13518,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 77:
     ///
-    /// LocalparamTerm: /(?-u:\b)localparam(?-u:\b)/ : Token;
+    /// LocalparamTerm: <INITIAL, Generic>/(?-u:\b)localparam(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn localparam_term(&mut self, localparam_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12266,9 +13537,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 78:
     ///
-    /// LogicTerm: /(?-u:\b)logic(?-u:\b)/ : Token;
+    /// LogicTerm: <INITIAL, Generic>/(?-u:\b)logic(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn logic_term(&mut self, logic_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12285,9 +13556,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 79:
     ///
-    /// LsbTerm: /(?-u:\b)lsb(?-u:\b)/ : Token;
+    /// LsbTerm: <INITIAL, Generic>/(?-u:\b)lsb(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn lsb_term(&mut self, lsb_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12304,9 +13575,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 80:
     ///
-    /// ModportTerm: /(?-u:\b)modport(?-u:\b)/ : Token;
+    /// ModportTerm: <INITIAL, Generic>/(?-u:\b)modport(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn modport_term(&mut self, modport_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12323,9 +13594,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 81:
     ///
-    /// ModuleTerm: /(?-u:\b)module(?-u:\b)/ : Token;
+    /// ModuleTerm: <INITIAL, Generic>/(?-u:\b)module(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn module_term(&mut self, module_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12342,9 +13613,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 82:
     ///
-    /// MsbTerm: /(?-u:\b)msb(?-u:\b)/ : Token;
+    /// MsbTerm: <INITIAL, Generic>/(?-u:\b)msb(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn msb_term(&mut self, msb_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12361,9 +13632,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 83:
     ///
-    /// NegedgeTerm: /(?-u:\b)negedge(?-u:\b)/ : Token;
+    /// NegedgeTerm: <INITIAL, Generic>/(?-u:\b)negedge(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn negedge_term(&mut self, negedge_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12380,9 +13651,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 84:
     ///
-    /// OutputTerm: /(?-u:\b)output(?-u:\b)/ : Token;
+    /// OutputTerm: <INITIAL, Generic>/(?-u:\b)output(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn output_term(&mut self, output_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12399,9 +13670,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 85:
     ///
-    /// OutsideTerm: /(?-u:\b)outside(?-u:\b)/ : Token;
+    /// OutsideTerm: <INITIAL, Generic>/(?-u:\b)outside(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn outside_term(&mut self, outside_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12418,9 +13689,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 86:
     ///
-    /// PackageTerm: /(?-u:\b)package(?-u:\b)/ : Token;
+    /// PackageTerm: <INITIAL, Generic>/(?-u:\b)package(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn package_term(&mut self, package_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12437,9 +13708,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 87:
     ///
-    /// ParameterTerm: /(?-u:\b)parameter(?-u:\b)/ : Token;
+    /// ParameterTerm: <INITIAL, Generic>/(?-u:\b)parameter(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn parameter_term(&mut self, parameter_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12456,9 +13727,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 88:
     ///
-    /// PosedgeTerm: /(?-u:\b)posedge(?-u:\b)/ : Token;
+    /// PosedgeTerm: <INITIAL, Generic>/(?-u:\b)posedge(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn posedge_term(&mut self, posedge_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12475,9 +13746,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 89:
     ///
-    /// RefTerm: /(?-u:\b)ref(?-u:\b)/ : Token;
+    /// RefTerm: <INITIAL, Generic>/(?-u:\b)ref(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn ref_term(&mut self, ref_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12494,9 +13765,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 90:
     ///
-    /// RepeatTerm: /(?-u:\b)repeat(?-u:\b)/ : Token;
+    /// RepeatTerm: <INITIAL, Generic>/(?-u:\b)repeat(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn repeat_term(&mut self, repeat_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12513,9 +13784,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 91:
+    ///
+    /// ResetTerm: <INITIAL, Generic>/(?-u:\b)reset(?-u:\b)/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn reset_term(&mut self, reset_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let reset_term = reset_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let reset_term_built = ResetTerm { reset_term };
+        // Calling user action here
+        self.user_grammar.reset_term(&reset_term_built)?;
+        self.push(ASTType::ResetTerm(reset_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 92:
     ///
-    /// ReturnTerm: /(?-u:\b)return(?-u:\b)/ : Token;
+    /// ReturnTerm: <INITIAL, Generic>/(?-u:\b)return(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn return_term(&mut self, return_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12532,9 +13822,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 86:
+    /// Semantic action for production 93:
     ///
-    /// SignedTerm: /(?-u:\b)signed(?-u:\b)/ : Token;
+    /// SignedTerm: <INITIAL, Generic>/(?-u:\b)signed(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn signed_term(&mut self, signed_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12551,9 +13841,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 87:
+    /// Semantic action for production 94:
     ///
-    /// StepTerm: /(?-u:\b)step(?-u:\b)/ : Token;
+    /// StepTerm: <INITIAL, Generic>/(?-u:\b)step(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn step_term(&mut self, step_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12570,9 +13860,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 88:
+    /// Semantic action for production 95:
     ///
-    /// StringTerm: /(?-u:\b)string(?-u:\b)/ : Token;
+    /// StringTerm: <INITIAL, Generic>/(?-u:\b)string(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn string_term(&mut self, string_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12589,9 +13879,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 89:
+    /// Semantic action for production 96:
     ///
-    /// StructTerm: /(?-u:\b)struct(?-u:\b)/ : Token;
+    /// StructTerm: <INITIAL, Generic>/(?-u:\b)struct(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn struct_term(&mut self, struct_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12608,9 +13898,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 90:
+    /// Semantic action for production 97:
     ///
-    /// SyncHighTerm: /(?-u:\b)sync_high(?-u:\b)/ : Token;
+    /// SyncHighTerm: <INITIAL, Generic>/(?-u:\b)sync_high(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn sync_high_term(&mut self, sync_high_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12627,9 +13917,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 91:
+    /// Semantic action for production 98:
     ///
-    /// SyncLowTerm: /(?-u:\b)sync_low(?-u:\b)/ : Token;
+    /// SyncLowTerm: <INITIAL, Generic>/(?-u:\b)sync_low(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn sync_low_term(&mut self, sync_low_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12646,9 +13936,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 92:
+    /// Semantic action for production 99:
     ///
-    /// TriTerm: /(?-u:\b)tri(?-u:\b)/ : Token;
+    /// TriTerm: <INITIAL, Generic>/(?-u:\b)tri(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn tri_term(&mut self, tri_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12665,9 +13955,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 93:
+    /// Semantic action for production 100:
     ///
-    /// TypeTerm: /(?-u:\b)type(?-u:\b)/ : Token;
+    /// TypeTerm: <INITIAL, Generic>/(?-u:\b)type(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn type_term(&mut self, type_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12684,9 +13974,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 94:
+    /// Semantic action for production 101:
+    ///
+    /// UnionTerm: <INITIAL, Generic>/(?-u:\b)union(?-u:\b)/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn union_term(&mut self, union_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let union_term = union_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let union_term_built = UnionTerm { union_term };
+        // Calling user action here
+        self.user_grammar.union_term(&union_term_built)?;
+        self.push(ASTType::UnionTerm(union_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 102:
     ///
-    /// U32Term: /(?-u:\b)u32(?-u:\b)/ : Token;
+    /// U32Term: <INITIAL, Generic>/(?-u:\b)u32(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn u32_term(&mut self, u32_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12703,9 +14012,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 95:
+    /// Semantic action for production 103:
     ///
-    /// U64Term: /(?-u:\b)u64(?-u:\b)/ : Token;
+    /// U64Term: <INITIAL, Generic>/(?-u:\b)u64(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn u64_term(&mut self, u64_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12722,9 +14031,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 96:
+    /// Semantic action for production 104:
     ///
-    /// VarTerm: /(?-u:\b)var(?-u:\b)/ : Token;
+    /// VarTerm: <INITIAL, Generic>/(?-u:\b)var(?-u:\b)/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn var_term(&mut self, var_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12741,9 +14050,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 97:
+    /// Semantic action for production 105:
     ///
-    /// IdentifierTerm: /[a-zA-Z_][0-9a-zA-Z_]*/ : Token;
+    /// IdentifierTerm: <INITIAL, Generic>/[a-zA-Z_][0-9a-zA-Z_]*/ : Token;
     ///
     #[parol_runtime::function_name::named]
     fn identifier_term(&mut self, identifier_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12760,7 +14069,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 98:
+    /// Semantic action for production 106:
+    ///
+    /// AnyTerm: <Embed>/[^{}]+/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn any_term(&mut self, any_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let any_term = any_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let any_term_built = AnyTerm { any_term };
+        // Calling user action here
+        self.user_grammar.any_term(&any_term_built)?;
+        self.push(ASTType::AnyTerm(any_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 107:
     ///
     /// Comments: CommentsOpt /* Option */;
     ///
@@ -12776,9 +14104,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 99:
+    /// Semantic action for production 108:
     ///
-    /// CommentsOpt /* Option<T>::Some */: CommentsTerm;
+    /// CommentsOpt /* `Option<T>::Some` */: CommentsTerm;
     ///
     #[parol_runtime::function_name::named]
     fn comments_opt_0(&mut self, _comments_term: &ParseTreeType<'t>) -> Result<()> {
@@ -12795,9 +14123,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 100:
+    /// Semantic action for production 109:
     ///
-    /// CommentsOpt /* Option<T>::None */: ;
+    /// CommentsOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn comments_opt_1(&mut self) -> Result<()> {
@@ -12807,7 +14135,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 101:
+    /// Semantic action for production 110:
     ///
     /// StartToken: Comments;
     ///
@@ -12825,7 +14153,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 102:
+    /// Semantic action for production 111:
     ///
     /// StringLiteralToken: StringLiteralTerm : Token Comments;
     ///
@@ -12855,7 +14183,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 103:
+    /// Semantic action for production 112:
     ///
     /// ExponentToken: ExponentTerm : Token Comments;
     ///
@@ -12881,7 +14209,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 104:
+    /// Semantic action for production 113:
     ///
     /// FixedPointToken: FixedPointTerm : Token Comments;
     ///
@@ -12908,7 +14236,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 105:
+    /// Semantic action for production 114:
     ///
     /// BasedToken: BasedTerm : Token Comments;
     ///
@@ -12934,7 +14262,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 106:
+    /// Semantic action for production 115:
     ///
     /// BaseLessToken: BaseLessTerm : Token Comments;
     ///
@@ -12960,7 +14288,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 107:
+    /// Semantic action for production 116:
     ///
     /// AllBitToken: AllBitTerm : Token Comments;
     ///
@@ -12986,7 +14314,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 108:
+    /// Semantic action for production 117:
     ///
     /// AssignmentOperatorToken: AssignmentOperatorTerm : Token Comments;
     ///
@@ -13021,7 +14349,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 109:
+    /// Semantic action for production 118:
     ///
     /// Operator01Token: Operator01Term : Token Comments;
     ///
@@ -13048,7 +14376,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 110:
+    /// Semantic action for production 119:
     ///
     /// Operator02Token: Operator02Term : Token Comments;
     ///
@@ -13075,7 +14403,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 111:
+    /// Semantic action for production 120:
     ///
     /// Operator03Token: Operator03Term : Token Comments;
     ///
@@ -13102,7 +14430,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 112:
+    /// Semantic action for production 121:
     ///
     /// Operator04Token: Operator04Term : Token Comments;
     ///
@@ -13129,7 +14457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 113:
+    /// Semantic action for production 122:
     ///
     /// Operator05Token: Operator05Term : Token Comments;
     ///
@@ -13156,7 +14484,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 114:
+    /// Semantic action for production 123:
     ///
     /// Operator06Token: Operator06Term : Token Comments;
     ///
@@ -13183,7 +14511,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 115:
+    /// Semantic action for production 124:
     ///
     /// Operator07Token: Operator07Term : Token Comments;
     ///
@@ -13210,7 +14538,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 116:
+    /// Semantic action for production 125:
     ///
     /// Operator08Token: Operator08Term : Token Comments;
     ///
@@ -13237,7 +14565,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 117:
+    /// Semantic action for production 126:
     ///
     /// Operator09Token: Operator09Term : Token Comments;
     ///
@@ -13264,7 +14592,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 118:
+    /// Semantic action for production 127:
     ///
     /// Operator10Token: Operator10Term : Token Comments;
     ///
@@ -13291,7 +14619,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 119:
+    /// Semantic action for production 128:
     ///
     /// Operator11Token: Operator11Term : Token Comments;
     ///
@@ -13318,7 +14646,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 120:
+    /// Semantic action for production 129:
     ///
     /// UnaryOperatorToken: UnaryOperatorTerm : Token Comments;
     ///
@@ -13348,7 +14676,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 121:
+    /// Semantic action for production 130:
     ///
     /// ColonToken: ColonTerm : Token Comments;
     ///
@@ -13374,7 +14702,42 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 122:
+    /// Semantic action for production 131:
+    ///
+    /// ColonColonLAngleToken: ColonColonLAngleTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn colon_colon_l_angle_token(
+        &mut self,
+        _colon_colon_l_angle_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let colon_colon_l_angle_term = pop_item!(
+            self,
+            colon_colon_l_angle_term,
+            ColonColonLAngleTerm,
+            context
+        );
+        let colon_colon_l_angle_token_built = ColonColonLAngleToken {
+            colon_colon_l_angle_term: (&colon_colon_l_angle_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar
+            .colon_colon_l_angle_token(&colon_colon_l_angle_token_built)?;
+        self.push(
+            ASTType::ColonColonLAngleToken(colon_colon_l_angle_token_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 132:
     ///
     /// ColonColonToken: ColonColonTerm : Token Comments;
     ///
@@ -13401,7 +14764,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 123:
+    /// Semantic action for production 133:
     ///
     /// CommaToken: CommaTerm : Token Comments;
     ///
@@ -13427,7 +14790,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 124:
+    /// Semantic action for production 134:
     ///
     /// DollarToken: DollarTerm : Token Comments;
     ///
@@ -13453,7 +14816,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 125:
+    /// Semantic action for production 135:
     ///
     /// DotDotToken: DotDotTerm : Token Comments;
     ///
@@ -13479,7 +14842,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 126:
+    /// Semantic action for production 136:
     ///
     /// DotDotEquToken: DotDotEquTerm : Token Comments;
     ///
@@ -13506,7 +14869,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 127:
+    /// Semantic action for production 137:
     ///
     /// DotToken: DotTerm : Token Comments;
     ///
@@ -13532,7 +14895,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 128:
+    /// Semantic action for production 138:
     ///
     /// EquToken: EquTerm : Token Comments;
     ///
@@ -13558,7 +14921,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 129:
+    /// Semantic action for production 139:
     ///
     /// HashToken: HashTerm : Token Comments;
     ///
@@ -13584,7 +14947,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 130:
+    /// Semantic action for production 140:
     ///
     /// LAngleToken: LAngleTerm : Token Comments;
     ///
@@ -13610,7 +14973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 131:
+    /// Semantic action for production 141:
     ///
     /// LBraceToken: LBraceTerm : Token Comments;
     ///
@@ -13636,7 +14999,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 132:
+    /// Semantic action for production 142:
     ///
     /// LBracketToken: LBracketTerm : Token Comments;
     ///
@@ -13662,7 +15025,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 133:
+    /// Semantic action for production 143:
     ///
     /// LParenToken: LParenTerm : Token Comments;
     ///
@@ -13688,7 +15051,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 134:
+    /// Semantic action for production 144:
     ///
     /// MinusColonToken: MinusColonTerm : Token Comments;
     ///
@@ -13715,7 +15078,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 135:
+    /// Semantic action for production 145:
     ///
     /// MinusGTToken: MinusGTTerm : Token Comments;
     ///
@@ -13741,7 +15104,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 136:
+    /// Semantic action for production 146:
     ///
     /// PlusColonToken: PlusColonTerm : Token Comments;
     ///
@@ -13768,7 +15131,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 137:
+    /// Semantic action for production 147:
+    ///
+    /// QuoteToken: QuoteTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn quote_token(
+        &mut self,
+        _quote_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let quote_term = pop_item!(self, quote_term, QuoteTerm, context);
+        let quote_token_built = QuoteToken {
+            quote_term: (&quote_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.quote_token(&quote_token_built)?;
+        self.push(ASTType::QuoteToken(quote_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 148:
     ///
     /// RAngleToken: RAngleTerm : Token Comments;
     ///
@@ -13794,7 +15183,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 138:
+    /// Semantic action for production 149:
     ///
     /// RBraceToken: RBraceTerm : Token Comments;
     ///
@@ -13820,7 +15209,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 139:
+    /// Semantic action for production 150:
     ///
     /// RBracketToken: RBracketTerm : Token Comments;
     ///
@@ -13846,7 +15235,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 140:
+    /// Semantic action for production 151:
     ///
     /// RParenToken: RParenTerm : Token Comments;
     ///
@@ -13872,7 +15261,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 141:
+    /// Semantic action for production 152:
     ///
     /// SemicolonToken: SemicolonTerm : Token Comments;
     ///
@@ -13898,7 +15287,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 142:
+    /// Semantic action for production 153:
     ///
     /// StarToken: StarTerm : Token Comments;
     ///
@@ -13924,7 +15313,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 143:
+    /// Semantic action for production 154:
     ///
     /// AlwaysCombToken: AlwaysCombTerm : Token Comments;
     ///
@@ -13951,7 +15340,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 144:
+    /// Semantic action for production 155:
     ///
     /// AlwaysFfToken: AlwaysFfTerm : Token Comments;
     ///
@@ -13977,7 +15366,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 145:
+    /// Semantic action for production 156:
     ///
     /// AsToken: AsTerm : Token Comments;
     ///
@@ -14003,7 +15392,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 146:
+    /// Semantic action for production 157:
     ///
     /// AssignToken: AssignTerm : Token Comments;
     ///
@@ -14029,7 +15418,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 147:
+    /// Semantic action for production 158:
     ///
     /// AsyncHighToken: AsyncHighTerm : Token Comments;
     ///
@@ -14056,7 +15445,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 148:
+    /// Semantic action for production 159:
     ///
     /// AsyncLowToken: AsyncLowTerm : Token Comments;
     ///
@@ -14082,7 +15471,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 149:
+    /// Semantic action for production 160:
     ///
     /// BitToken: BitTerm : Token Comments;
     ///
@@ -14108,7 +15497,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 150:
+    /// Semantic action for production 161:
     ///
     /// CaseToken: CaseTerm : Token Comments;
     ///
@@ -14134,7 +15523,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 151:
+    /// Semantic action for production 162:
+    ///
+    /// ClockToken: ClockTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn clock_token(
+        &mut self,
+        _clock_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let clock_term = pop_item!(self, clock_term, ClockTerm, context);
+        let clock_token_built = ClockToken {
+            clock_term: (&clock_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.clock_token(&clock_token_built)?;
+        self.push(ASTType::ClockToken(clock_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 163:
     ///
     /// DefaultToken: DefaultTerm : Token Comments;
     ///
@@ -14160,7 +15575,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 152:
+    /// Semantic action for production 164:
     ///
     /// ElseToken: ElseTerm : Token Comments;
     ///
@@ -14186,7 +15601,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 153:
+    /// Semantic action for production 165:
+    ///
+    /// EmbedToken: EmbedTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_token(
+        &mut self,
+        _embed_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let embed_term = pop_item!(self, embed_term, EmbedTerm, context);
+        let embed_token_built = EmbedToken {
+            embed_term: (&embed_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.embed_token(&embed_token_built)?;
+        self.push(ASTType::EmbedToken(embed_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 166:
     ///
     /// EnumToken: EnumTerm : Token Comments;
     ///
@@ -14212,7 +15653,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 154:
+    /// Semantic action for production 167:
     ///
     /// ExportToken: ExportTerm : Token Comments;
     ///
@@ -14238,7 +15679,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 155:
+    /// Semantic action for production 168:
+    ///
+    /// ExternToken: ExternTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn extern_token(
+        &mut self,
+        _extern_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let extern_term = pop_item!(self, extern_term, ExternTerm, context);
+        let extern_token_built = ExternToken {
+            extern_term: (&extern_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.extern_token(&extern_token_built)?;
+        self.push(ASTType::ExternToken(extern_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 169:
     ///
     /// F32Token: F32Term : Token Comments;
     ///
@@ -14264,7 +15731,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 156:
+    /// Semantic action for production 170:
     ///
     /// F64Token: F64Term : Token Comments;
     ///
@@ -14290,7 +15757,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 157:
+    /// Semantic action for production 171:
     ///
     /// FinalToken: FinalTerm : Token Comments;
     ///
@@ -14316,7 +15783,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 158:
+    /// Semantic action for production 172:
     ///
     /// ForToken: ForTerm : Token Comments;
     ///
@@ -14342,7 +15809,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 159:
+    /// Semantic action for production 173:
     ///
     /// FunctionToken: FunctionTerm : Token Comments;
     ///
@@ -14368,7 +15835,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 160:
+    /// Semantic action for production 174:
     ///
     /// I32Token: I32Term : Token Comments;
     ///
@@ -14394,7 +15861,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 161:
+    /// Semantic action for production 175:
     ///
     /// I64Token: I64Term : Token Comments;
     ///
@@ -14420,7 +15887,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 162:
+    /// Semantic action for production 176:
     ///
     /// IfResetToken: IfResetTerm : Token Comments;
     ///
@@ -14446,7 +15913,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 163:
+    /// Semantic action for production 177:
     ///
     /// IfToken: IfTerm : Token Comments;
     ///
@@ -14472,7 +15939,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 164:
+    /// Semantic action for production 178:
     ///
     /// ImportToken: ImportTerm : Token Comments;
     ///
@@ -14498,7 +15965,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 165:
+    /// Semantic action for production 179:
+    ///
+    /// IncludeToken: IncludeTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn include_token(
+        &mut self,
+        _include_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let include_term = pop_item!(self, include_term, IncludeTerm, context);
+        let include_token_built = IncludeToken {
+            include_term: (&include_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.include_token(&include_token_built)?;
+        self.push(ASTType::IncludeToken(include_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 180:
     ///
     /// InitialToken: InitialTerm : Token Comments;
     ///
@@ -14524,7 +16017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 166:
+    /// Semantic action for production 181:
     ///
     /// InoutToken: InoutTerm : Token Comments;
     ///
@@ -14550,7 +16043,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 167:
+    /// Semantic action for production 182:
     ///
     /// InputToken: InputTerm : Token Comments;
     ///
@@ -14576,7 +16069,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 168:
+    /// Semantic action for production 183:
     ///
     /// InsideToken: InsideTerm : Token Comments;
     ///
@@ -14602,7 +16095,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 169:
+    /// Semantic action for production 184:
     ///
     /// InstToken: InstTerm : Token Comments;
     ///
@@ -14628,7 +16121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 170:
+    /// Semantic action for production 185:
     ///
     /// InterfaceToken: InterfaceTerm : Token Comments;
     ///
@@ -14654,7 +16147,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 171:
+    /// Semantic action for production 186:
     ///
     /// InToken: InTerm : Token Comments;
     ///
@@ -14680,7 +16173,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 172:
+    /// Semantic action for production 187:
     ///
     /// LocalparamToken: LocalparamTerm : Token Comments;
     ///
@@ -14707,7 +16200,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 173:
+    /// Semantic action for production 188:
     ///
     /// LogicToken: LogicTerm : Token Comments;
     ///
@@ -14733,7 +16226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 174:
+    /// Semantic action for production 189:
     ///
     /// LsbToken: LsbTerm : Token Comments;
     ///
@@ -14759,7 +16252,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 175:
+    /// Semantic action for production 190:
     ///
     /// ModportToken: ModportTerm : Token Comments;
     ///
@@ -14785,7 +16278,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 176:
+    /// Semantic action for production 191:
     ///
     /// ModuleToken: ModuleTerm : Token Comments;
     ///
@@ -14811,7 +16304,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 177:
+    /// Semantic action for production 192:
     ///
     /// MsbToken: MsbTerm : Token Comments;
     ///
@@ -14837,7 +16330,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 178:
+    /// Semantic action for production 193:
     ///
     /// NegedgeToken: NegedgeTerm : Token Comments;
     ///
@@ -14863,7 +16356,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 179:
+    /// Semantic action for production 194:
     ///
     /// OutputToken: OutputTerm : Token Comments;
     ///
@@ -14889,7 +16382,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 180:
+    /// Semantic action for production 195:
     ///
     /// OutsideToken: OutsideTerm : Token Comments;
     ///
@@ -14915,7 +16408,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 181:
+    /// Semantic action for production 196:
     ///
     /// PackageToken: PackageTerm : Token Comments;
     ///
@@ -14941,7 +16434,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 182:
+    /// Semantic action for production 197:
     ///
     /// ParameterToken: ParameterTerm : Token Comments;
     ///
@@ -14967,7 +16460,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 183:
+    /// Semantic action for production 198:
     ///
     /// PosedgeToken: PosedgeTerm : Token Comments;
     ///
@@ -14993,7 +16486,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 184:
+    /// Semantic action for production 199:
     ///
     /// RefToken: RefTerm : Token Comments;
     ///
@@ -15019,7 +16512,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 185:
+    /// Semantic action for production 200:
     ///
     /// RepeatToken: RepeatTerm : Token Comments;
     ///
@@ -15045,7 +16538,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 186:
+    /// Semantic action for production 201:
+    ///
+    /// ResetToken: ResetTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn reset_token(
+        &mut self,
+        _reset_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let reset_term = pop_item!(self, reset_term, ResetTerm, context);
+        let reset_token_built = ResetToken {
+            reset_term: (&reset_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.reset_token(&reset_token_built)?;
+        self.push(ASTType::ResetToken(reset_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 202:
     ///
     /// ReturnToken: ReturnTerm : Token Comments;
     ///
@@ -15071,7 +16590,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 187:
+    /// Semantic action for production 203:
     ///
     /// SignedToken: SignedTerm : Token Comments;
     ///
@@ -15097,7 +16616,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 188:
+    /// Semantic action for production 204:
     ///
     /// StepToken: StepTerm : Token Comments;
     ///
@@ -15123,7 +16642,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 189:
+    /// Semantic action for production 205:
     ///
     /// StringToken: StringTerm : Token Comments;
     ///
@@ -15149,7 +16668,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 190:
+    /// Semantic action for production 206:
     ///
     /// StructToken: StructTerm : Token Comments;
     ///
@@ -15175,7 +16694,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 191:
+    /// Semantic action for production 207:
     ///
     /// SyncHighToken: SyncHighTerm : Token Comments;
     ///
@@ -15201,7 +16720,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 192:
+    /// Semantic action for production 208:
     ///
     /// SyncLowToken: SyncLowTerm : Token Comments;
     ///
@@ -15227,7 +16746,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 193:
+    /// Semantic action for production 209:
     ///
     /// TriToken: TriTerm : Token Comments;
     ///
@@ -15253,7 +16772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 194:
+    /// Semantic action for production 210:
     ///
     /// TypeToken: TypeTerm : Token Comments;
     ///
@@ -15279,7 +16798,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 195:
+    /// Semantic action for production 211:
+    ///
+    /// UnionToken: UnionTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn union_token(
+        &mut self,
+        _union_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let union_term = pop_item!(self, union_term, UnionTerm, context);
+        let union_token_built = UnionToken {
+            union_term: (&union_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.union_token(&union_token_built)?;
+        self.push(ASTType::UnionToken(union_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 212:
     ///
     /// U32Token: U32Term : Token Comments;
     ///
@@ -15305,7 +16850,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 196:
+    /// Semantic action for production 213:
     ///
     /// U64Token: U64Term : Token Comments;
     ///
@@ -15331,7 +16876,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 197:
+    /// Semantic action for production 214:
     ///
     /// VarToken: VarTerm : Token Comments;
     ///
@@ -15357,7 +16902,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 198:
+    /// Semantic action for production 215:
     ///
     /// IdentifierToken: IdentifierTerm : Token Comments;
     ///
@@ -15384,12 +16929,110 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 199:
+    /// Semantic action for production 216:
     ///
-    /// Start: StartToken : VerylToken;
+    /// EmbedContentToken: LBraceTerm %push(Embed) LBraceTerm LBraceTerm EmbedContentTokenList /* Vec */ RBraceTerm RBraceTerm RBraceTerm %pop() Comments;
     ///
     #[parol_runtime::function_name::named]
-    fn start(&mut self, _start_token: &ParseTreeType<'t>) -> Result<()> {
+    fn embed_content_token(
+        &mut self,
+        _l_brace_term: &ParseTreeType<'t>,
+        _l_brace_term0: &ParseTreeType<'t>,
+        _l_brace_term1: &ParseTreeType<'t>,
+        _embed_content_token_list: &ParseTreeType<'t>,
+        _r_brace_term: &ParseTreeType<'t>,
+        _r_brace_term0: &ParseTreeType<'t>,
+        _r_brace_term1: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let r_brace_term1 = pop_item!(self, r_brace_term1, RBraceTerm, context);
+        let r_brace_term0 = pop_item!(self, r_brace_term0, RBraceTerm, context);
+        let r_brace_term = pop_item!(self, r_brace_term, RBraceTerm, context);
+        let embed_content_token_list = pop_and_reverse_item!(
+            self,
+            embed_content_token_list,
+            EmbedContentTokenList,
+            context
+        );
+        let l_brace_term1 = pop_item!(self, l_brace_term1, LBraceTerm, context);
+        let l_brace_term0 = pop_item!(self, l_brace_term0, LBraceTerm, context);
+        let l_brace_term = pop_item!(self, l_brace_term, LBraceTerm, context);
+        let embed_content_token_built = EmbedContentToken {
+            l_brace_term: Box::new(l_brace_term),
+            l_brace_term0: Box::new(l_brace_term0),
+            l_brace_term1: Box::new(l_brace_term1),
+            embed_content_token_list,
+            r_brace_term: Box::new(r_brace_term),
+            r_brace_term0: Box::new(r_brace_term0),
+            r_brace_term1: Box::new(r_brace_term1),
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar
+            .embed_content_token(&embed_content_token_built)?;
+        self.push(
+            ASTType::EmbedContentToken(embed_content_token_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 217:
+    ///
+    /// EmbedContentTokenList /* `Vec<T>::Push` */: EmbedItem EmbedContentTokenList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_content_token_list_0(
+        &mut self,
+        _embed_item: &ParseTreeType<'t>,
+        _embed_content_token_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut embed_content_token_list = pop_item!(
+            self,
+            embed_content_token_list,
+            EmbedContentTokenList,
+            context
+        );
+        let embed_item = pop_item!(self, embed_item, EmbedItem, context);
+        let embed_content_token_list_0_built = EmbedContentTokenList {
+            embed_item: Box::new(embed_item),
+        };
+        // Add an element to the vector
+        embed_content_token_list.push(embed_content_token_list_0_built);
+        self.push(
+            ASTType::EmbedContentTokenList(embed_content_token_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 218:
+    ///
+    /// EmbedContentTokenList /* `Vec<T>::New` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_content_token_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_content_token_list_1_built = Vec::new();
+        self.push(
+            ASTType::EmbedContentTokenList(embed_content_token_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 219:
+    ///
+    /// Start: StartToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn start(&mut self, _start_token: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let start_token = pop_item!(self, start_token, StartToken, context);
@@ -15404,7 +17047,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 200:
+    /// Semantic action for production 220:
     ///
     /// StringLiteral: StringLiteralToken : VerylToken;
     ///
@@ -15425,7 +17068,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 201:
+    /// Semantic action for production 221:
     ///
     /// Exponent: ExponentToken : VerylToken;
     ///
@@ -15445,7 +17088,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 202:
+    /// Semantic action for production 222:
     ///
     /// FixedPoint: FixedPointToken : VerylToken;
     ///
@@ -15465,7 +17108,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 203:
+    /// Semantic action for production 223:
     ///
     /// Based: BasedToken : VerylToken;
     ///
@@ -15485,7 +17128,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 204:
+    /// Semantic action for production 224:
     ///
     /// BaseLess: BaseLessToken : VerylToken;
     ///
@@ -15505,7 +17148,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 205:
+    /// Semantic action for production 225:
     ///
     /// AllBit: AllBitToken : VerylToken;
     ///
@@ -15525,7 +17168,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 206:
+    /// Semantic action for production 226:
     ///
     /// AssignmentOperator: AssignmentOperatorToken : VerylToken;
     ///
@@ -15557,7 +17200,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 207:
+    /// Semantic action for production 227:
     ///
     /// Operator01: Operator01Token : VerylToken;
     ///
@@ -15577,7 +17220,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 208:
+    /// Semantic action for production 228:
     ///
     /// Operator02: Operator02Token : VerylToken;
     ///
@@ -15597,7 +17240,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 209:
+    /// Semantic action for production 229:
     ///
     /// Operator03: Operator03Token : VerylToken;
     ///
@@ -15617,7 +17260,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 210:
+    /// Semantic action for production 230:
     ///
     /// Operator04: Operator04Token : VerylToken;
     ///
@@ -15637,7 +17280,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 211:
+    /// Semantic action for production 231:
     ///
     /// Operator05: Operator05Token : VerylToken;
     ///
@@ -15657,7 +17300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 212:
+    /// Semantic action for production 232:
     ///
     /// Operator06: Operator06Token : VerylToken;
     ///
@@ -15677,7 +17320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 213:
+    /// Semantic action for production 233:
     ///
     /// Operator07: Operator07Token : VerylToken;
     ///
@@ -15697,7 +17340,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 214:
+    /// Semantic action for production 234:
     ///
     /// Operator08: Operator08Token : VerylToken;
     ///
@@ -15717,7 +17360,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 215:
+    /// Semantic action for production 235:
     ///
     /// Operator09: Operator09Token : VerylToken;
     ///
@@ -15737,7 +17380,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 216:
+    /// Semantic action for production 236:
     ///
     /// Operator10: Operator10Token : VerylToken;
     ///
@@ -15757,7 +17400,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 217:
+    /// Semantic action for production 237:
     ///
     /// Operator11: Operator11Token : VerylToken;
     ///
@@ -15777,7 +17420,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 218:
+    /// Semantic action for production 238:
     ///
     /// UnaryOperator: UnaryOperatorToken : VerylToken;
     ///
@@ -15798,7 +17441,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 219:
+    /// Semantic action for production 239:
     ///
     /// Colon: ColonToken : VerylToken;
     ///
@@ -15818,7 +17461,39 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 220:
+    /// Semantic action for production 240:
+    ///
+    /// ColonColonLAngle: ColonColonLAngleToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn colon_colon_l_angle(
+        &mut self,
+        _colon_colon_l_angle_token: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let colon_colon_l_angle_token = pop_item!(
+            self,
+            colon_colon_l_angle_token,
+            ColonColonLAngleToken,
+            context
+        );
+        let colon_colon_l_angle_built = ColonColonLAngle {
+            colon_colon_l_angle_token: (&colon_colon_l_angle_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar
+            .colon_colon_l_angle(&colon_colon_l_angle_built)?;
+        self.push(
+            ASTType::ColonColonLAngle(colon_colon_l_angle_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 241:
     ///
     /// ColonColon: ColonColonToken : VerylToken;
     ///
@@ -15838,7 +17513,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 221:
+    /// Semantic action for production 242:
     ///
     /// Comma: CommaToken : VerylToken;
     ///
@@ -15858,7 +17533,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 222:
+    /// Semantic action for production 243:
     ///
     /// Dollar: DollarToken : VerylToken;
     ///
@@ -15878,7 +17553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 223:
+    /// Semantic action for production 244:
     ///
     /// DotDot: DotDotToken : VerylToken;
     ///
@@ -15898,7 +17573,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 224:
+    /// Semantic action for production 245:
     ///
     /// DotDotEqu: DotDotEquToken : VerylToken;
     ///
@@ -15918,7 +17593,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 225:
+    /// Semantic action for production 246:
     ///
     /// Dot: DotToken : VerylToken;
     ///
@@ -15938,7 +17613,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 226:
+    /// Semantic action for production 247:
     ///
     /// Equ: EquToken : VerylToken;
     ///
@@ -15958,7 +17633,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 227:
+    /// Semantic action for production 248:
     ///
     /// Hash: HashToken : VerylToken;
     ///
@@ -15978,7 +17653,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 228:
+    /// Semantic action for production 249:
     ///
     /// LAngle: LAngleToken : VerylToken;
     ///
@@ -15998,7 +17673,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 229:
+    /// Semantic action for production 250:
     ///
     /// LBrace: LBraceToken : VerylToken;
     ///
@@ -16018,7 +17693,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 230:
+    /// Semantic action for production 251:
     ///
     /// LBracket: LBracketToken : VerylToken;
     ///
@@ -16038,7 +17713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 231:
+    /// Semantic action for production 252:
     ///
     /// LParen: LParenToken : VerylToken;
     ///
@@ -16058,7 +17733,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 232:
+    /// Semantic action for production 253:
     ///
     /// MinusColon: MinusColonToken : VerylToken;
     ///
@@ -16078,7 +17753,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 233:
+    /// Semantic action for production 254:
     ///
     /// MinusGT: MinusGTToken : VerylToken;
     ///
@@ -16098,7 +17773,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 234:
+    /// Semantic action for production 255:
     ///
     /// PlusColon: PlusColonToken : VerylToken;
     ///
@@ -16118,7 +17793,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 235:
+    /// Semantic action for production 256:
+    ///
+    /// Quote: QuoteToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn quote(&mut self, _quote_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let quote_token = pop_item!(self, quote_token, QuoteToken, context);
+        let quote_built = Quote {
+            quote_token: (&quote_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.quote(&quote_built)?;
+        self.push(ASTType::Quote(quote_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 257:
     ///
     /// RAngle: RAngleToken : VerylToken;
     ///
@@ -16138,7 +17833,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 236:
+    /// Semantic action for production 258:
     ///
     /// RBrace: RBraceToken : VerylToken;
     ///
@@ -16158,7 +17853,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 237:
+    /// Semantic action for production 259:
     ///
     /// RBracket: RBracketToken : VerylToken;
     ///
@@ -16178,7 +17873,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 238:
+    /// Semantic action for production 260:
     ///
     /// RParen: RParenToken : VerylToken;
     ///
@@ -16198,7 +17893,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 239:
+    /// Semantic action for production 261:
     ///
     /// Semicolon: SemicolonToken : VerylToken;
     ///
@@ -16218,7 +17913,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 240:
+    /// Semantic action for production 262:
     ///
     /// Star: StarToken : VerylToken;
     ///
@@ -16238,7 +17933,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 241:
+    /// Semantic action for production 263:
     ///
     /// AlwaysComb: AlwaysCombToken : VerylToken;
     ///
@@ -16258,7 +17953,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 242:
+    /// Semantic action for production 264:
     ///
     /// AlwaysFf: AlwaysFfToken : VerylToken;
     ///
@@ -16278,7 +17973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 243:
+    /// Semantic action for production 265:
     ///
     /// As: AsToken : VerylToken;
     ///
@@ -16298,7 +17993,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 244:
+    /// Semantic action for production 266:
     ///
     /// Assign: AssignToken : VerylToken;
     ///
@@ -16318,7 +18013,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 245:
+    /// Semantic action for production 267:
     ///
     /// AsyncHigh: AsyncHighToken : VerylToken;
     ///
@@ -16338,7 +18033,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 246:
+    /// Semantic action for production 268:
     ///
     /// AsyncLow: AsyncLowToken : VerylToken;
     ///
@@ -16358,7 +18053,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 247:
+    /// Semantic action for production 269:
     ///
     /// Bit: BitToken : VerylToken;
     ///
@@ -16378,7 +18073,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 248:
+    /// Semantic action for production 270:
     ///
     /// Case: CaseToken : VerylToken;
     ///
@@ -16398,7 +18093,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 249:
+    /// Semantic action for production 271:
+    ///
+    /// Clock: ClockToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn clock(&mut self, _clock_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let clock_token = pop_item!(self, clock_token, ClockToken, context);
+        let clock_built = Clock {
+            clock_token: (&clock_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.clock(&clock_built)?;
+        self.push(ASTType::Clock(clock_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 272:
     ///
     /// Defaul: DefaultToken : VerylToken;
     ///
@@ -16418,7 +18133,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 250:
+    /// Semantic action for production 273:
     ///
     /// Else: ElseToken : VerylToken;
     ///
@@ -16438,7 +18153,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 251:
+    /// Semantic action for production 274:
+    ///
+    /// Embed: EmbedToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed(&mut self, _embed_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_token = pop_item!(self, embed_token, EmbedToken, context);
+        let embed_built = Embed {
+            embed_token: (&embed_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.embed(&embed_built)?;
+        self.push(ASTType::Embed(embed_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 275:
     ///
     /// Enum: EnumToken : VerylToken;
     ///
@@ -16458,7 +18193,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 252:
+    /// Semantic action for production 276:
     ///
     /// Export: ExportToken : VerylToken;
     ///
@@ -16478,7 +18213,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 253:
+    /// Semantic action for production 277:
+    ///
+    /// Extern: ExternToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn r#extern(&mut self, _extern_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let extern_token = pop_item!(self, extern_token, ExternToken, context);
+        let r#extern_built = Extern {
+            extern_token: (&extern_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.r#extern(&r#extern_built)?;
+        self.push(ASTType::Extern(r#extern_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 278:
     ///
     /// F32: F32Token : VerylToken;
     ///
@@ -16498,7 +18253,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 254:
+    /// Semantic action for production 279:
     ///
     /// F64: F64Token : VerylToken;
     ///
@@ -16518,7 +18273,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 255:
+    /// Semantic action for production 280:
     ///
     /// Final: FinalToken : VerylToken;
     ///
@@ -16538,7 +18293,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 256:
+    /// Semantic action for production 281:
     ///
     /// For: ForToken : VerylToken;
     ///
@@ -16558,7 +18313,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 257:
+    /// Semantic action for production 282:
     ///
     /// Function: FunctionToken : VerylToken;
     ///
@@ -16578,7 +18333,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 258:
+    /// Semantic action for production 283:
     ///
     /// I32: I32Token : VerylToken;
     ///
@@ -16598,7 +18353,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 259:
+    /// Semantic action for production 284:
     ///
     /// I64: I64Token : VerylToken;
     ///
@@ -16618,7 +18373,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 260:
+    /// Semantic action for production 285:
     ///
     /// If: IfToken : VerylToken;
     ///
@@ -16638,7 +18393,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 261:
+    /// Semantic action for production 286:
     ///
     /// IfReset: IfResetToken : VerylToken;
     ///
@@ -16658,7 +18413,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 262:
+    /// Semantic action for production 287:
     ///
     /// Import: ImportToken : VerylToken;
     ///
@@ -16678,7 +18433,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 263:
+    /// Semantic action for production 288:
+    ///
+    /// Include: IncludeToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn include(&mut self, _include_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let include_token = pop_item!(self, include_token, IncludeToken, context);
+        let include_built = Include {
+            include_token: (&include_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.include(&include_built)?;
+        self.push(ASTType::Include(include_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 289:
     ///
     /// In: InToken : VerylToken;
     ///
@@ -16698,7 +18473,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 264:
+    /// Semantic action for production 290:
     ///
     /// Initial: InitialToken : VerylToken;
     ///
@@ -16718,7 +18493,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 265:
+    /// Semantic action for production 291:
     ///
     /// Inout: InoutToken : VerylToken;
     ///
@@ -16738,7 +18513,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 266:
+    /// Semantic action for production 292:
     ///
     /// Input: InputToken : VerylToken;
     ///
@@ -16758,7 +18533,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 267:
+    /// Semantic action for production 293:
     ///
     /// Inside: InsideToken : VerylToken;
     ///
@@ -16778,7 +18553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 268:
+    /// Semantic action for production 294:
     ///
     /// Inst: InstToken : VerylToken;
     ///
@@ -16798,7 +18573,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 269:
+    /// Semantic action for production 295:
     ///
     /// Interface: InterfaceToken : VerylToken;
     ///
@@ -16818,7 +18593,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 270:
+    /// Semantic action for production 296:
     ///
     /// Localparam: LocalparamToken : VerylToken;
     ///
@@ -16838,7 +18613,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 271:
+    /// Semantic action for production 297:
     ///
     /// Logic: LogicToken : VerylToken;
     ///
@@ -16858,7 +18633,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 272:
+    /// Semantic action for production 298:
     ///
     /// Lsb: LsbToken : VerylToken;
     ///
@@ -16878,7 +18653,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 273:
+    /// Semantic action for production 299:
     ///
     /// Modport: ModportToken : VerylToken;
     ///
@@ -16898,7 +18673,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 274:
+    /// Semantic action for production 300:
     ///
     /// Module: ModuleToken : VerylToken;
     ///
@@ -16918,7 +18693,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 275:
+    /// Semantic action for production 301:
     ///
     /// Msb: MsbToken : VerylToken;
     ///
@@ -16938,7 +18713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 276:
+    /// Semantic action for production 302:
     ///
     /// Negedge: NegedgeToken : VerylToken;
     ///
@@ -16958,7 +18733,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 277:
+    /// Semantic action for production 303:
     ///
     /// Output: OutputToken : VerylToken;
     ///
@@ -16978,7 +18753,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 278:
+    /// Semantic action for production 304:
     ///
     /// Outside: OutsideToken : VerylToken;
     ///
@@ -16998,7 +18773,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 279:
+    /// Semantic action for production 305:
     ///
     /// Package: PackageToken : VerylToken;
     ///
@@ -17018,7 +18793,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 280:
+    /// Semantic action for production 306:
     ///
     /// Parameter: ParameterToken : VerylToken;
     ///
@@ -17038,7 +18813,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 281:
+    /// Semantic action for production 307:
     ///
     /// Posedge: PosedgeToken : VerylToken;
     ///
@@ -17058,7 +18833,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 282:
+    /// Semantic action for production 308:
     ///
     /// Ref: RefToken : VerylToken;
     ///
@@ -17078,7 +18853,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 283:
+    /// Semantic action for production 309:
     ///
     /// Repeat: RepeatToken : VerylToken;
     ///
@@ -17098,7 +18873,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 284:
+    /// Semantic action for production 310:
+    ///
+    /// Reset: ResetToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn reset(&mut self, _reset_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let reset_token = pop_item!(self, reset_token, ResetToken, context);
+        let reset_built = Reset {
+            reset_token: (&reset_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.reset(&reset_built)?;
+        self.push(ASTType::Reset(reset_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 311:
     ///
     /// Return: ReturnToken : VerylToken;
     ///
@@ -17118,7 +18913,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 285:
+    /// Semantic action for production 312:
     ///
     /// Signed: SignedToken : VerylToken;
     ///
@@ -17138,7 +18933,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 286:
+    /// Semantic action for production 313:
     ///
     /// Step: StepToken : VerylToken;
     ///
@@ -17158,7 +18953,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 287:
+    /// Semantic action for production 314:
     ///
     /// Strin: StringToken : VerylToken;
     ///
@@ -17178,7 +18973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 288:
+    /// Semantic action for production 315:
     ///
     /// Struct: StructToken : VerylToken;
     ///
@@ -17198,7 +18993,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 289:
+    /// Semantic action for production 316:
     ///
     /// SyncHigh: SyncHighToken : VerylToken;
     ///
@@ -17218,7 +19013,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 290:
+    /// Semantic action for production 317:
     ///
     /// SyncLow: SyncLowToken : VerylToken;
     ///
@@ -17238,7 +19033,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 291:
+    /// Semantic action for production 318:
     ///
     /// Tri: TriToken : VerylToken;
     ///
@@ -17258,7 +19053,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 292:
+    /// Semantic action for production 319:
     ///
     /// Type: TypeToken : VerylToken;
     ///
@@ -17278,7 +19073,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 293:
+    /// Semantic action for production 320:
+    ///
+    /// Union: UnionToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn r#union(&mut self, _union_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let union_token = pop_item!(self, union_token, UnionToken, context);
+        let r#union_built = Union {
+            union_token: (&union_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.r#union(&r#union_built)?;
+        self.push(ASTType::Union(r#union_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 321:
     ///
     /// U32: U32Token : VerylToken;
     ///
@@ -17298,7 +19113,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 294:
+    /// Semantic action for production 322:
     ///
     /// U64: U64Token : VerylToken;
     ///
@@ -17318,7 +19133,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 295:
+    /// Semantic action for production 323:
     ///
     /// Var: VarToken : VerylToken;
     ///
@@ -17338,7 +19153,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 296:
+    /// Semantic action for production 324:
     ///
     /// Identifier: IdentifierToken : VerylToken;
     ///
@@ -17358,7 +19173,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 297:
+    /// Semantic action for production 325:
+    ///
+    /// EmbedContent: EmbedContentToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_content(&mut self, _embed_content_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_content_token = pop_item!(self, embed_content_token, EmbedContentToken, context);
+        let embed_content_built = EmbedContent {
+            embed_content_token: (&embed_content_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.embed_content(&embed_content_built)?;
+        self.push(ASTType::EmbedContent(embed_content_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 326:
     ///
     /// Number: IntegralNumber;
     ///
@@ -17377,7 +19212,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 298:
+    /// Semantic action for production 327:
     ///
     /// Number: RealNumber;
     ///
@@ -17396,7 +19231,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 299:
+    /// Semantic action for production 328:
     ///
     /// IntegralNumber: Based;
     ///
@@ -17416,7 +19251,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 300:
+    /// Semantic action for production 329:
     ///
     /// IntegralNumber: BaseLess;
     ///
@@ -17436,7 +19271,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 301:
+    /// Semantic action for production 330:
     ///
     /// IntegralNumber: AllBit;
     ///
@@ -17456,7 +19291,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 302:
+    /// Semantic action for production 331:
     ///
     /// RealNumber: FixedPoint;
     ///
@@ -17475,7 +19310,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 303:
+    /// Semantic action for production 332:
     ///
     /// RealNumber: Exponent;
     ///
@@ -17494,7 +19329,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 304:
+    /// Semantic action for production 333:
     ///
     /// HierarchicalIdentifier: Identifier HierarchicalIdentifierList /* Vec */ HierarchicalIdentifierList0 /* Vec */;
     ///
@@ -17535,9 +19370,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 305:
+    /// Semantic action for production 334:
     ///
-    /// HierarchicalIdentifierList0 /* Vec<T>::Push */: Dot Identifier HierarchicalIdentifierList0List /* Vec */ HierarchicalIdentifierList0;
+    /// HierarchicalIdentifierList0 /* `Vec<T>::Push` */: Dot Identifier HierarchicalIdentifierList0List /* Vec */ HierarchicalIdentifierList0;
     ///
     #[parol_runtime::function_name::named]
     fn hierarchical_identifier_list0_0(
@@ -17577,9 +19412,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 306:
+    /// Semantic action for production 335:
     ///
-    /// HierarchicalIdentifierList0List /* Vec<T>::Push */: Select HierarchicalIdentifierList0List;
+    /// HierarchicalIdentifierList0List /* `Vec<T>::Push` */: Select HierarchicalIdentifierList0List;
     ///
     #[parol_runtime::function_name::named]
     fn hierarchical_identifier_list0_list_0(
@@ -17608,9 +19443,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 307:
+    /// Semantic action for production 336:
     ///
-    /// HierarchicalIdentifierList0List /* Vec<T>::New */: ;
+    /// HierarchicalIdentifierList0List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn hierarchical_identifier_list0_list_1(&mut self) -> Result<()> {
@@ -17624,9 +19459,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 308:
+    /// Semantic action for production 337:
     ///
-    /// HierarchicalIdentifierList0 /* Vec<T>::New */: ;
+    /// HierarchicalIdentifierList0 /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn hierarchical_identifier_list0_1(&mut self) -> Result<()> {
@@ -17640,9 +19475,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 309:
+    /// Semantic action for production 338:
     ///
-    /// HierarchicalIdentifierList /* Vec<T>::Push */: Select HierarchicalIdentifierList;
+    /// HierarchicalIdentifierList /* `Vec<T>::Push` */: Select HierarchicalIdentifierList;
     ///
     #[parol_runtime::function_name::named]
     fn hierarchical_identifier_list_0(
@@ -17671,9 +19506,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 310:
+    /// Semantic action for production 339:
     ///
-    /// HierarchicalIdentifierList /* Vec<T>::New */: ;
+    /// HierarchicalIdentifierList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn hierarchical_identifier_list_1(&mut self) -> Result<()> {
@@ -17687,23 +19522,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 311:
+    /// Semantic action for production 340:
     ///
-    /// ScopedIdentifier: Identifier ScopedIdentifierList /* Vec */;
+    /// ScopedIdentifier: Identifier ScopedIdentifierOpt /* Option */ ScopedIdentifierList /* Vec */;
     ///
     #[parol_runtime::function_name::named]
     fn scoped_identifier(
         &mut self,
         _identifier: &ParseTreeType<'t>,
+        _scoped_identifier_opt: &ParseTreeType<'t>,
         _scoped_identifier_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let scoped_identifier_list =
             pop_and_reverse_item!(self, scoped_identifier_list, ScopedIdentifierList, context);
+        let scoped_identifier_opt =
+            pop_item!(self, scoped_identifier_opt, ScopedIdentifierOpt, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
         let scoped_identifier_built = ScopedIdentifier {
             identifier: Box::new(identifier),
+            scoped_identifier_opt,
             scoped_identifier_list,
         };
         // Calling user action here
@@ -17713,24 +19552,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 312:
+    /// Semantic action for production 341:
     ///
-    /// ScopedIdentifierList /* Vec<T>::Push */: ColonColon Identifier ScopedIdentifierList;
+    /// ScopedIdentifierList /* `Vec<T>::Push` */: ColonColon Identifier ScopedIdentifierOpt0 /* Option */ ScopedIdentifierList;
     ///
     #[parol_runtime::function_name::named]
     fn scoped_identifier_list_0(
         &mut self,
         _colon_colon: &ParseTreeType<'t>,
         _identifier: &ParseTreeType<'t>,
+        _scoped_identifier_opt0: &ParseTreeType<'t>,
         _scoped_identifier_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let mut scoped_identifier_list =
             pop_item!(self, scoped_identifier_list, ScopedIdentifierList, context);
+        let scoped_identifier_opt0 =
+            pop_item!(self, scoped_identifier_opt0, ScopedIdentifierOpt0, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
         let colon_colon = pop_item!(self, colon_colon, ColonColon, context);
         let scoped_identifier_list_0_built = ScopedIdentifierList {
+            scoped_identifier_opt0,
             identifier: Box::new(identifier),
             colon_colon: Box::new(colon_colon),
         };
@@ -17743,9 +19586,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 313:
+    /// Semantic action for production 342:
     ///
-    /// ScopedIdentifierList /* Vec<T>::New */: ;
+    /// ScopedIdentifierList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn scoped_identifier_list_1(&mut self) -> Result<()> {
@@ -17759,15 +19602,86 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 314:
+    /// Semantic action for production 343:
+    ///
+    /// ScopedIdentifierOpt0 /* `Option<T>::Some` */: WithGenericArgument;
+    ///
+    #[parol_runtime::function_name::named]
+    fn scoped_identifier_opt0_0(
+        &mut self,
+        _with_generic_argument: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_generic_argument =
+            pop_item!(self, with_generic_argument, WithGenericArgument, context);
+        let scoped_identifier_opt0_0_built = ScopedIdentifierOpt0 {
+            with_generic_argument: Box::new(with_generic_argument),
+        };
+        self.push(
+            ASTType::ScopedIdentifierOpt0(Some(Box::new(scoped_identifier_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 344:
+    ///
+    /// ScopedIdentifierOpt0 /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn scoped_identifier_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ScopedIdentifierOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 345:
+    ///
+    /// ScopedIdentifierOpt /* `Option<T>::Some` */: WithGenericArgument;
+    ///
+    #[parol_runtime::function_name::named]
+    fn scoped_identifier_opt_0(
+        &mut self,
+        _with_generic_argument: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_generic_argument =
+            pop_item!(self, with_generic_argument, WithGenericArgument, context);
+        let scoped_identifier_opt_0_built = ScopedIdentifierOpt {
+            with_generic_argument: Box::new(with_generic_argument),
+        };
+        self.push(
+            ASTType::ScopedIdentifierOpt(Some(Box::new(scoped_identifier_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 346:
+    ///
+    /// ScopedIdentifierOpt /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn scoped_identifier_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ScopedIdentifierOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 347:
     ///
-    /// ExpressionIdentifier: ExpressionIdentifierOpt /* Option */ Identifier ExpressionIdentifierGroup;
+    /// ExpressionIdentifier: ExpressionIdentifierOpt /* Option */ Identifier ExpressionIdentifierOpt0 /* Option */ ExpressionIdentifierGroup;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier(
         &mut self,
         _expression_identifier_opt: &ParseTreeType<'t>,
         _identifier: &ParseTreeType<'t>,
+        _expression_identifier_opt0: &ParseTreeType<'t>,
         _expression_identifier_group: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
@@ -17778,6 +19692,12 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             ExpressionIdentifierGroup,
             context
         );
+        let expression_identifier_opt0 = pop_item!(
+            self,
+            expression_identifier_opt0,
+            ExpressionIdentifierOpt0,
+            context
+        );
         let identifier = pop_item!(self, identifier, Identifier, context);
         let expression_identifier_opt = pop_item!(
             self,
@@ -17788,6 +19708,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let expression_identifier_built = ExpressionIdentifier {
             expression_identifier_opt,
             identifier: Box::new(identifier),
+            expression_identifier_opt0,
             expression_identifier_group: Box::new(expression_identifier_group),
         };
         // Calling user action here
@@ -17800,15 +19721,16 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 315:
+    /// Semantic action for production 348:
     ///
-    /// ExpressionIdentifierGroup: ColonColon Identifier ExpressionIdentifierGroupList /* Vec */ ExpressionIdentifierGroupList0 /* Vec */;
+    /// ExpressionIdentifierGroup: ColonColon Identifier ExpressionIdentifierOpt1 /* Option */ ExpressionIdentifierGroupList /* Vec */ ExpressionIdentifierGroupList0 /* Vec */;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_0(
         &mut self,
         _colon_colon: &ParseTreeType<'t>,
         _identifier: &ParseTreeType<'t>,
+        _expression_identifier_opt1: &ParseTreeType<'t>,
         _expression_identifier_group_list: &ParseTreeType<'t>,
         _expression_identifier_group_list0: &ParseTreeType<'t>,
     ) -> Result<()> {
@@ -17826,15 +19748,22 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             ExpressionIdentifierGroupList,
             context
         );
+        let expression_identifier_opt1 = pop_item!(
+            self,
+            expression_identifier_opt1,
+            ExpressionIdentifierOpt1,
+            context
+        );
         let identifier = pop_item!(self, identifier, Identifier, context);
         let colon_colon = pop_item!(self, colon_colon, ColonColon, context);
-        let expression_identifier_group_0_built = ExpressionIdentifierGroupColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0 {
+        let expression_identifier_group_0_built = ExpressionIdentifierGroupColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0 {
             colon_colon: Box::new(colon_colon),
             identifier: Box::new(identifier),
+            expression_identifier_opt1,
             expression_identifier_group_list,
             expression_identifier_group_list0,
         };
-        let expression_identifier_group_0_built = ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0(expression_identifier_group_0_built);
+        let expression_identifier_group_0_built = ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(expression_identifier_group_0_built);
         self.push(
             ASTType::ExpressionIdentifierGroup(expression_identifier_group_0_built),
             context,
@@ -17842,9 +19771,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 316:
+    /// Semantic action for production 349:
     ///
-    /// ExpressionIdentifierGroupList0 /* Vec<T>::Push */: Select ExpressionIdentifierGroupList0;
+    /// ExpressionIdentifierGroupList0 /* `Vec<T>::Push` */: Select ExpressionIdentifierGroupList0;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list0_0(
@@ -17873,9 +19802,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 317:
+    /// Semantic action for production 350:
     ///
-    /// ExpressionIdentifierGroupList0 /* Vec<T>::New */: ;
+    /// ExpressionIdentifierGroupList0 /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list0_1(&mut self) -> Result<()> {
@@ -17889,15 +19818,16 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 318:
+    /// Semantic action for production 351:
     ///
-    /// ExpressionIdentifierGroupList /* Vec<T>::Push */: ColonColon Identifier ExpressionIdentifierGroupList;
+    /// ExpressionIdentifierGroupList /* `Vec<T>::Push` */: ColonColon Identifier ExpressionIdentifierOpt2 /* Option */ ExpressionIdentifierGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list_0(
         &mut self,
         _colon_colon: &ParseTreeType<'t>,
         _identifier: &ParseTreeType<'t>,
+        _expression_identifier_opt2: &ParseTreeType<'t>,
         _expression_identifier_group_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
@@ -17908,9 +19838,16 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             ExpressionIdentifierGroupList,
             context
         );
+        let expression_identifier_opt2 = pop_item!(
+            self,
+            expression_identifier_opt2,
+            ExpressionIdentifierOpt2,
+            context
+        );
         let identifier = pop_item!(self, identifier, Identifier, context);
         let colon_colon = pop_item!(self, colon_colon, ColonColon, context);
         let expression_identifier_group_list_0_built = ExpressionIdentifierGroupList {
+            expression_identifier_opt2,
             identifier: Box::new(identifier),
             colon_colon: Box::new(colon_colon),
         };
@@ -17923,9 +19860,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 319:
+    /// Semantic action for production 352:
     ///
-    /// ExpressionIdentifierGroupList /* Vec<T>::New */: ;
+    /// ExpressionIdentifierGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list_1(&mut self) -> Result<()> {
@@ -17939,7 +19876,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 320:
+    /// Semantic action for production 353:
     ///
     /// ExpressionIdentifierGroup: ExpressionIdentifierGroupList1 /* Vec */ ExpressionIdentifierGroupList2 /* Vec */;
     ///
@@ -17979,9 +19916,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 321:
+    /// Semantic action for production 354:
     ///
-    /// ExpressionIdentifierGroupList2 /* Vec<T>::Push */: Dot Identifier ExpressionIdentifierGroupList2List /* Vec */ ExpressionIdentifierGroupList2;
+    /// ExpressionIdentifierGroupList2 /* `Vec<T>::Push` */: Dot Identifier ExpressionIdentifierGroupList2List /* Vec */ ExpressionIdentifierGroupList2;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list2_0(
@@ -18021,9 +19958,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 322:
+    /// Semantic action for production 355:
     ///
-    /// ExpressionIdentifierGroupList2List /* Vec<T>::Push */: Select ExpressionIdentifierGroupList2List;
+    /// ExpressionIdentifierGroupList2List /* `Vec<T>::Push` */: Select ExpressionIdentifierGroupList2List;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list2_list_0(
@@ -18052,9 +19989,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 323:
+    /// Semantic action for production 356:
     ///
-    /// ExpressionIdentifierGroupList2List /* Vec<T>::New */: ;
+    /// ExpressionIdentifierGroupList2List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list2_list_1(&mut self) -> Result<()> {
@@ -18070,9 +20007,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 324:
+    /// Semantic action for production 357:
     ///
-    /// ExpressionIdentifierGroupList2 /* Vec<T>::New */: ;
+    /// ExpressionIdentifierGroupList2 /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list2_1(&mut self) -> Result<()> {
@@ -18086,9 +20023,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 325:
+    /// Semantic action for production 358:
     ///
-    /// ExpressionIdentifierGroupList1 /* Vec<T>::Push */: Select ExpressionIdentifierGroupList1;
+    /// ExpressionIdentifierGroupList1 /* `Vec<T>::Push` */: Select ExpressionIdentifierGroupList1;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list1_0(
@@ -18117,9 +20054,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 326:
+    /// Semantic action for production 359:
     ///
-    /// ExpressionIdentifierGroupList1 /* Vec<T>::New */: ;
+    /// ExpressionIdentifierGroupList1 /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression_identifier_group_list1_1(&mut self) -> Result<()> {
@@ -18133,38 +20070,143 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 327:
+    /// Semantic action for production 360:
     ///
-    /// ExpressionIdentifierOpt /* Option<T>::Some */: Dollar;
+    /// ExpressionIdentifierOpt2 /* `Option<T>::Some` */: WithGenericArgument;
     ///
     #[parol_runtime::function_name::named]
-    fn expression_identifier_opt_0(&mut self, _dollar: &ParseTreeType<'t>) -> Result<()> {
+    fn expression_identifier_opt2_0(
+        &mut self,
+        _with_generic_argument: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let dollar = pop_item!(self, dollar, Dollar, context);
-        let expression_identifier_opt_0_built = ExpressionIdentifierOpt {
-            dollar: Box::new(dollar),
+        let with_generic_argument =
+            pop_item!(self, with_generic_argument, WithGenericArgument, context);
+        let expression_identifier_opt2_0_built = ExpressionIdentifierOpt2 {
+            with_generic_argument: Box::new(with_generic_argument),
         };
         self.push(
-            ASTType::ExpressionIdentifierOpt(Some(Box::new(expression_identifier_opt_0_built))),
+            ASTType::ExpressionIdentifierOpt2(Some(Box::new(expression_identifier_opt2_0_built))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 328:
+    /// Semantic action for production 361:
     ///
-    /// ExpressionIdentifierOpt /* Option<T>::None */: ;
+    /// ExpressionIdentifierOpt2 /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn expression_identifier_opt_1(&mut self) -> Result<()> {
+    fn expression_identifier_opt2_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::ExpressionIdentifierOpt(None), context);
+        self.push(ASTType::ExpressionIdentifierOpt2(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 329:
+    /// Semantic action for production 362:
+    ///
+    /// ExpressionIdentifierOpt1 /* `Option<T>::Some` */: WithGenericArgument;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression_identifier_opt1_0(
+        &mut self,
+        _with_generic_argument: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_generic_argument =
+            pop_item!(self, with_generic_argument, WithGenericArgument, context);
+        let expression_identifier_opt1_0_built = ExpressionIdentifierOpt1 {
+            with_generic_argument: Box::new(with_generic_argument),
+        };
+        self.push(
+            ASTType::ExpressionIdentifierOpt1(Some(Box::new(expression_identifier_opt1_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 363:
+    ///
+    /// ExpressionIdentifierOpt1 /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression_identifier_opt1_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ExpressionIdentifierOpt1(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 364:
+    ///
+    /// ExpressionIdentifierOpt0 /* `Option<T>::Some` */: WithGenericArgument;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression_identifier_opt0_0(
+        &mut self,
+        _with_generic_argument: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_generic_argument =
+            pop_item!(self, with_generic_argument, WithGenericArgument, context);
+        let expression_identifier_opt0_0_built = ExpressionIdentifierOpt0 {
+            with_generic_argument: Box::new(with_generic_argument),
+        };
+        self.push(
+            ASTType::ExpressionIdentifierOpt0(Some(Box::new(expression_identifier_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 365:
+    ///
+    /// ExpressionIdentifierOpt0 /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression_identifier_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ExpressionIdentifierOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 366:
+    ///
+    /// ExpressionIdentifierOpt /* `Option<T>::Some` */: Dollar;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression_identifier_opt_0(&mut self, _dollar: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dollar = pop_item!(self, dollar, Dollar, context);
+        let expression_identifier_opt_0_built = ExpressionIdentifierOpt {
+            dollar: Box::new(dollar),
+        };
+        self.push(
+            ASTType::ExpressionIdentifierOpt(Some(Box::new(expression_identifier_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 367:
+    ///
+    /// ExpressionIdentifierOpt /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression_identifier_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ExpressionIdentifierOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 368:
     ///
     /// Expression: Expression01 ExpressionList /* Vec */;
     ///
@@ -18188,9 +20230,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 330:
+    /// Semantic action for production 369:
     ///
-    /// ExpressionList /* Vec<T>::Push */: Operator01 Expression01 ExpressionList;
+    /// ExpressionList /* `Vec<T>::Push` */: Operator01 Expression01 ExpressionList;
     ///
     #[parol_runtime::function_name::named]
     fn expression_list_0(
@@ -18214,9 +20256,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 331:
+    /// Semantic action for production 370:
     ///
-    /// ExpressionList /* Vec<T>::New */: ;
+    /// ExpressionList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression_list_1(&mut self) -> Result<()> {
@@ -18227,7 +20269,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 332:
+    /// Semantic action for production 371:
     ///
     /// Expression01: Expression02 Expression01List /* Vec */;
     ///
@@ -18252,9 +20294,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 333:
+    /// Semantic action for production 372:
     ///
-    /// Expression01List /* Vec<T>::Push */: Operator02 Expression02 Expression01List;
+    /// Expression01List /* `Vec<T>::Push` */: Operator02 Expression02 Expression01List;
     ///
     #[parol_runtime::function_name::named]
     fn expression01_list_0(
@@ -18278,9 +20320,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 334:
+    /// Semantic action for production 373:
     ///
-    /// Expression01List /* Vec<T>::New */: ;
+    /// Expression01List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression01_list_1(&mut self) -> Result<()> {
@@ -18294,7 +20336,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 335:
+    /// Semantic action for production 374:
     ///
     /// Expression02: Expression03 Expression02List /* Vec */;
     ///
@@ -18319,9 +20361,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 336:
+    /// Semantic action for production 375:
     ///
-    /// Expression02List /* Vec<T>::Push */: Operator03 Expression03 Expression02List;
+    /// Expression02List /* `Vec<T>::Push` */: Operator03 Expression03 Expression02List;
     ///
     #[parol_runtime::function_name::named]
     fn expression02_list_0(
@@ -18345,9 +20387,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 337:
+    /// Semantic action for production 376:
     ///
-    /// Expression02List /* Vec<T>::New */: ;
+    /// Expression02List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression02_list_1(&mut self) -> Result<()> {
@@ -18361,7 +20403,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 338:
+    /// Semantic action for production 377:
     ///
     /// Expression03: Expression04 Expression03List /* Vec */;
     ///
@@ -18386,9 +20428,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 339:
+    /// Semantic action for production 378:
     ///
-    /// Expression03List /* Vec<T>::Push */: Operator04 Expression04 Expression03List;
+    /// Expression03List /* `Vec<T>::Push` */: Operator04 Expression04 Expression03List;
     ///
     #[parol_runtime::function_name::named]
     fn expression03_list_0(
@@ -18412,9 +20454,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 340:
+    /// Semantic action for production 379:
     ///
-    /// Expression03List /* Vec<T>::New */: ;
+    /// Expression03List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression03_list_1(&mut self) -> Result<()> {
@@ -18428,7 +20470,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 341:
+    /// Semantic action for production 380:
     ///
     /// Expression04: Expression05 Expression04List /* Vec */;
     ///
@@ -18453,9 +20495,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 342:
+    /// Semantic action for production 381:
     ///
-    /// Expression04List /* Vec<T>::Push */: Operator05 Expression05 Expression04List;
+    /// Expression04List /* `Vec<T>::Push` */: Operator05 Expression05 Expression04List;
     ///
     #[parol_runtime::function_name::named]
     fn expression04_list_0(
@@ -18479,9 +20521,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 343:
+    /// Semantic action for production 382:
     ///
-    /// Expression04List /* Vec<T>::New */: ;
+    /// Expression04List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression04_list_1(&mut self) -> Result<()> {
@@ -18495,7 +20537,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 344:
+    /// Semantic action for production 383:
     ///
     /// Expression05: Expression06 Expression05List /* Vec */;
     ///
@@ -18520,9 +20562,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 345:
+    /// Semantic action for production 384:
     ///
-    /// Expression05List /* Vec<T>::Push */: Operator06 Expression06 Expression05List;
+    /// Expression05List /* `Vec<T>::Push` */: Operator06 Expression06 Expression05List;
     ///
     #[parol_runtime::function_name::named]
     fn expression05_list_0(
@@ -18546,9 +20588,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 346:
+    /// Semantic action for production 385:
     ///
-    /// Expression05List /* Vec<T>::New */: ;
+    /// Expression05List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression05_list_1(&mut self) -> Result<()> {
@@ -18562,7 +20604,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 347:
+    /// Semantic action for production 386:
     ///
     /// Expression06: Expression07 Expression06List /* Vec */;
     ///
@@ -18587,9 +20629,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 348:
+    /// Semantic action for production 387:
     ///
-    /// Expression06List /* Vec<T>::Push */: Operator07 Expression07 Expression06List;
+    /// Expression06List /* `Vec<T>::Push` */: Operator07 Expression07 Expression06List;
     ///
     #[parol_runtime::function_name::named]
     fn expression06_list_0(
@@ -18613,9 +20655,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 349:
+    /// Semantic action for production 388:
     ///
-    /// Expression06List /* Vec<T>::New */: ;
+    /// Expression06List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression06_list_1(&mut self) -> Result<()> {
@@ -18629,7 +20671,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 350:
+    /// Semantic action for production 389:
     ///
     /// Expression07: Expression08 Expression07List /* Vec */;
     ///
@@ -18654,9 +20696,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 351:
+    /// Semantic action for production 390:
     ///
-    /// Expression07List /* Vec<T>::Push */: Operator08 Expression08 Expression07List;
+    /// Expression07List /* `Vec<T>::Push` */: Operator08 Expression08 Expression07List;
     ///
     #[parol_runtime::function_name::named]
     fn expression07_list_0(
@@ -18680,9 +20722,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 352:
+    /// Semantic action for production 391:
     ///
-    /// Expression07List /* Vec<T>::New */: ;
+    /// Expression07List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression07_list_1(&mut self) -> Result<()> {
@@ -18696,7 +20738,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 353:
+    /// Semantic action for production 392:
     ///
     /// Expression08: Expression09 Expression08List /* Vec */;
     ///
@@ -18721,9 +20763,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 354:
+    /// Semantic action for production 393:
     ///
-    /// Expression08List /* Vec<T>::Push */: Operator09 Expression09 Expression08List;
+    /// Expression08List /* `Vec<T>::Push` */: Operator09 Expression09 Expression08List;
     ///
     #[parol_runtime::function_name::named]
     fn expression08_list_0(
@@ -18747,9 +20789,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 355:
+    /// Semantic action for production 394:
     ///
-    /// Expression08List /* Vec<T>::New */: ;
+    /// Expression08List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression08_list_1(&mut self) -> Result<()> {
@@ -18763,7 +20805,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 356:
+    /// Semantic action for production 395:
     ///
     /// Expression09: Expression10 Expression09List /* Vec */;
     ///
@@ -18788,9 +20830,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 357:
+    /// Semantic action for production 396:
     ///
-    /// Expression09List /* Vec<T>::Push */: Expression09ListGroup Expression10 Expression09List;
+    /// Expression09List /* `Vec<T>::Push` */: Expression09ListGroup Expression10 Expression09List;
     ///
     #[parol_runtime::function_name::named]
     fn expression09_list_0(
@@ -18819,7 +20861,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 358:
+    /// Semantic action for production 397:
     ///
     /// Expression09ListGroup: Operator10;
     ///
@@ -18840,7 +20882,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 359:
+    /// Semantic action for production 398:
     ///
     /// Expression09ListGroup: Star;
     ///
@@ -18861,9 +20903,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 360:
+    /// Semantic action for production 399:
     ///
-    /// Expression09List /* Vec<T>::New */: ;
+    /// Expression09List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression09_list_1(&mut self) -> Result<()> {
@@ -18877,7 +20919,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 361:
+    /// Semantic action for production 400:
     ///
     /// Expression10: Expression11 Expression10List /* Vec */;
     ///
@@ -18902,9 +20944,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 362:
+    /// Semantic action for production 401:
     ///
-    /// Expression10List /* Vec<T>::Push */: Operator11 Expression11 Expression10List;
+    /// Expression10List /* `Vec<T>::Push` */: Operator11 Expression11 Expression10List;
     ///
     #[parol_runtime::function_name::named]
     fn expression10_list_0(
@@ -18928,9 +20970,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 363:
+    /// Semantic action for production 402:
     ///
-    /// Expression10List /* Vec<T>::New */: ;
+    /// Expression10List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression10_list_1(&mut self) -> Result<()> {
@@ -18944,7 +20986,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 364:
+    /// Semantic action for production 403:
     ///
     /// Expression11: Expression12 Expression11List /* Vec */;
     ///
@@ -18969,9 +21011,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 365:
+    /// Semantic action for production 404:
     ///
-    /// Expression11List /* Vec<T>::Push */: As ScopedIdentifier Expression11List;
+    /// Expression11List /* `Vec<T>::Push` */: As ScopedIdentifier Expression11List;
     ///
     #[parol_runtime::function_name::named]
     fn expression11_list_0(
@@ -18995,9 +21037,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 366:
+    /// Semantic action for production 405:
     ///
-    /// Expression11List /* Vec<T>::New */: ;
+    /// Expression11List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression11_list_1(&mut self) -> Result<()> {
@@ -19011,7 +21053,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 367:
+    /// Semantic action for production 406:
     ///
     /// Expression12: Expression12List /* Vec */ Factor;
     ///
@@ -19036,9 +21078,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 368:
+    /// Semantic action for production 407:
     ///
-    /// Expression12List /* Vec<T>::Push */: Expression12ListGroup Expression12List;
+    /// Expression12List /* `Vec<T>::Push` */: Expression12ListGroup Expression12List;
     ///
     #[parol_runtime::function_name::named]
     fn expression12_list_0(
@@ -19064,7 +21106,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 369:
+    /// Semantic action for production 408:
     ///
     /// Expression12ListGroup: UnaryOperator;
     ///
@@ -19085,7 +21127,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 370:
+    /// Semantic action for production 409:
     ///
     /// Expression12ListGroup: Operator09;
     ///
@@ -19106,7 +21148,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 371:
+    /// Semantic action for production 410:
     ///
     /// Expression12ListGroup: Operator05;
     ///
@@ -19127,7 +21169,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 372:
+    /// Semantic action for production 411:
     ///
     /// Expression12ListGroup: Operator03;
     ///
@@ -19148,7 +21190,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 373:
+    /// Semantic action for production 412:
     ///
     /// Expression12ListGroup: Operator04;
     ///
@@ -19169,9 +21211,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 374:
+    /// Semantic action for production 413:
     ///
-    /// Expression12List /* Vec<T>::New */: ;
+    /// Expression12List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn expression12_list_1(&mut self) -> Result<()> {
@@ -19185,7 +21227,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 375:
+    /// Semantic action for production 414:
     ///
     /// Factor: Number;
     ///
@@ -19204,7 +21246,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 376:
+    /// Semantic action for production 415:
     ///
     /// Factor: ExpressionIdentifier FactorOpt /* Option */;
     ///
@@ -19230,7 +21272,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 377:
+    /// Semantic action for production 416:
     ///
     /// Factor: LParen Expression RParen;
     ///
@@ -19258,7 +21300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 378:
+    /// Semantic action for production 417:
     ///
     /// Factor: LBrace ConcatenationList RBrace;
     ///
@@ -19286,7 +21328,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 379:
+    /// Semantic action for production 418:
     ///
     /// Factor: IfExpression;
     ///
@@ -19305,7 +21347,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 380:
+    /// Semantic action for production 419:
     ///
     /// Factor: CaseExpression;
     ///
@@ -19324,7 +21366,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 381:
+    /// Semantic action for production 420:
     ///
     /// Factor: StringLiteral;
     ///
@@ -19343,7 +21385,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 382:
+    /// Semantic action for production 421:
     ///
     /// Factor: FactorGroup;
     ///
@@ -19362,7 +21404,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 383:
+    /// Semantic action for production 422:
     ///
     /// FactorGroup: Msb;
     ///
@@ -19377,7 +21419,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 384:
+    /// Semantic action for production 423:
     ///
     /// FactorGroup: Lsb;
     ///
@@ -19392,7 +21434,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 385:
+    /// Semantic action for production 424:
     ///
     /// Factor: InsideExpression;
     ///
@@ -19411,7 +21453,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 386:
+    /// Semantic action for production 425:
     ///
     /// Factor: OutsideExpression;
     ///
@@ -19430,9 +21472,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 387:
+    /// Semantic action for production 426:
     ///
-    /// FactorOpt /* Option<T>::Some */: FunctionCall;
+    /// FactorOpt /* `Option<T>::Some` */: FunctionCall;
     ///
     #[parol_runtime::function_name::named]
     fn factor_opt_0(&mut self, _function_call: &ParseTreeType<'t>) -> Result<()> {
@@ -19449,9 +21491,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 388:
+    /// Semantic action for production 427:
     ///
-    /// FactorOpt /* Option<T>::None */: ;
+    /// FactorOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn factor_opt_1(&mut self) -> Result<()> {
@@ -19461,7 +21503,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 389:
+    /// Semantic action for production 428:
     ///
     /// FunctionCall: LParen FunctionCallOpt /* Option */ RParen;
     ///
@@ -19488,9 +21530,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 390:
+    /// Semantic action for production 429:
     ///
-    /// FunctionCallOpt /* Option<T>::Some */: ArgumentList;
+    /// FunctionCallOpt /* `Option<T>::Some` */: ArgumentList;
     ///
     #[parol_runtime::function_name::named]
     fn function_call_opt_0(&mut self, _argument_list: &ParseTreeType<'t>) -> Result<()> {
@@ -19507,9 +21549,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 391:
+    /// Semantic action for production 430:
     ///
-    /// FunctionCallOpt /* Option<T>::None */: ;
+    /// FunctionCallOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn function_call_opt_1(&mut self) -> Result<()> {
@@ -19519,7 +21561,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 392:
+    /// Semantic action for production 431:
     ///
     /// ArgumentList: ArgumentItem ArgumentListList /* Vec */ ArgumentListOpt /* Option */;
     ///
@@ -19547,9 +21589,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 393:
+    /// Semantic action for production 432:
     ///
-    /// ArgumentListList /* Vec<T>::Push */: Comma ArgumentItem ArgumentListList;
+    /// ArgumentListList /* `Vec<T>::Push` */: Comma ArgumentItem ArgumentListList;
     ///
     #[parol_runtime::function_name::named]
     fn argument_list_list_0(
@@ -19573,9 +21615,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 394:
+    /// Semantic action for production 433:
     ///
-    /// ArgumentListList /* Vec<T>::New */: ;
+    /// ArgumentListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn argument_list_list_1(&mut self) -> Result<()> {
@@ -19589,9 +21631,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 395:
+    /// Semantic action for production 434:
     ///
-    /// ArgumentListOpt /* Option<T>::Some */: Comma;
+    /// ArgumentListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn argument_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -19608,9 +21650,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 396:
+    /// Semantic action for production 435:
     ///
-    /// ArgumentListOpt /* Option<T>::None */: ;
+    /// ArgumentListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn argument_list_opt_1(&mut self) -> Result<()> {
@@ -19620,7 +21662,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 397:
+    /// Semantic action for production 436:
     ///
     /// ArgumentItem: Expression;
     ///
@@ -19638,7 +21680,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 398:
+    /// Semantic action for production 437:
     ///
     /// ConcatenationList: ConcatenationItem ConcatenationListList /* Vec */ ConcatenationListOpt /* Option */;
     ///
@@ -19675,9 +21717,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 399:
+    /// Semantic action for production 438:
     ///
-    /// ConcatenationListList /* Vec<T>::Push */: Comma ConcatenationItem ConcatenationListList;
+    /// ConcatenationListList /* `Vec<T>::Push` */: Comma ConcatenationItem ConcatenationListList;
     ///
     #[parol_runtime::function_name::named]
     fn concatenation_list_list_0(
@@ -19709,9 +21751,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 400:
+    /// Semantic action for production 439:
     ///
-    /// ConcatenationListList /* Vec<T>::New */: ;
+    /// ConcatenationListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn concatenation_list_list_1(&mut self) -> Result<()> {
@@ -19725,9 +21767,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 401:
+    /// Semantic action for production 440:
     ///
-    /// ConcatenationListOpt /* Option<T>::Some */: Comma;
+    /// ConcatenationListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn concatenation_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -19744,9 +21786,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 402:
+    /// Semantic action for production 441:
     ///
-    /// ConcatenationListOpt /* Option<T>::None */: ;
+    /// ConcatenationListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn concatenation_list_opt_1(&mut self) -> Result<()> {
@@ -19756,7 +21798,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 403:
+    /// Semantic action for production 442:
     ///
     /// ConcatenationItem: Expression ConcatenationItemOpt /* Option */;
     ///
@@ -19785,9 +21827,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 404:
+    /// Semantic action for production 443:
     ///
-    /// ConcatenationItemOpt /* Option<T>::Some */: Repeat Expression;
+    /// ConcatenationItemOpt /* `Option<T>::Some` */: Repeat Expression;
     ///
     #[parol_runtime::function_name::named]
     fn concatenation_item_opt_0(
@@ -19810,9 +21852,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 405:
+    /// Semantic action for production 444:
     ///
-    /// ConcatenationItemOpt /* Option<T>::None */: ;
+    /// ConcatenationItemOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn concatenation_item_opt_1(&mut self) -> Result<()> {
@@ -19822,7 +21864,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 406:
+    /// Semantic action for production 445:
     ///
     /// IfExpression: If Expression LBrace Expression RBrace IfExpressionList /* Vec */ Else LBrace Expression RBrace;
     ///
@@ -19871,9 +21913,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 407:
+    /// Semantic action for production 446:
     ///
-    /// IfExpressionList /* Vec<T>::Push */: Else If Expression LBrace Expression RBrace IfExpressionList;
+    /// IfExpressionList /* `Vec<T>::Push` */: Else If Expression LBrace Expression RBrace IfExpressionList;
     ///
     #[parol_runtime::function_name::named]
     fn if_expression_list_0(
@@ -19909,9 +21951,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 408:
+    /// Semantic action for production 447:
     ///
-    /// IfExpressionList /* Vec<T>::New */: ;
+    /// IfExpressionList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_expression_list_1(&mut self) -> Result<()> {
@@ -19925,7 +21967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 409:
+    /// Semantic action for production 448:
     ///
     /// CaseExpression: Case Expression LBrace Expression Colon Expression Comma CaseExpressionList /* Vec */ Defaul Colon Expression CaseExpressionOpt /* Option */ RBrace;
     ///
@@ -19983,9 +22025,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 410:
+    /// Semantic action for production 449:
     ///
-    /// CaseExpressionList /* Vec<T>::Push */: Expression Colon Expression Comma CaseExpressionList;
+    /// CaseExpressionList /* `Vec<T>::Push` */: Expression Colon Expression Comma CaseExpressionList;
     ///
     #[parol_runtime::function_name::named]
     fn case_expression_list_0(
@@ -20016,9 +22058,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 411:
+    /// Semantic action for production 450:
     ///
-    /// CaseExpressionList /* Vec<T>::New */: ;
+    /// CaseExpressionList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn case_expression_list_1(&mut self) -> Result<()> {
@@ -20032,9 +22074,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 412:
+    /// Semantic action for production 451:
     ///
-    /// CaseExpressionOpt /* Option<T>::Some */: Comma;
+    /// CaseExpressionOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn case_expression_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -20051,9 +22093,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 413:
+    /// Semantic action for production 452:
     ///
-    /// CaseExpressionOpt /* Option<T>::None */: ;
+    /// CaseExpressionOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn case_expression_opt_1(&mut self) -> Result<()> {
@@ -20063,7 +22105,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 414:
+    /// Semantic action for production 453:
     ///
     /// TypeExpression: ScalarType;
     ///
@@ -20083,7 +22125,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 415:
+    /// Semantic action for production 454:
     ///
     /// TypeExpression: Type LParen Expression RParen;
     ///
@@ -20116,7 +22158,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 416:
+    /// Semantic action for production 455:
     ///
     /// InsideExpression: Inside Expression LBrace RangeList RBrace;
     ///
@@ -20150,7 +22192,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 417:
+    /// Semantic action for production 456:
     ///
     /// OutsideExpression: Outside Expression LBrace RangeList RBrace;
     ///
@@ -20187,7 +22229,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 418:
+    /// Semantic action for production 457:
     ///
     /// RangeList: RangeItem RangeListList /* Vec */ RangeListOpt /* Option */;
     ///
@@ -20214,9 +22256,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 419:
+    /// Semantic action for production 458:
     ///
-    /// RangeListList /* Vec<T>::Push */: Comma RangeItem RangeListList;
+    /// RangeListList /* `Vec<T>::Push` */: Comma RangeItem RangeListList;
     ///
     #[parol_runtime::function_name::named]
     fn range_list_list_0(
@@ -20240,9 +22282,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 420:
+    /// Semantic action for production 459:
     ///
-    /// RangeListList /* Vec<T>::New */: ;
+    /// RangeListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn range_list_list_1(&mut self) -> Result<()> {
@@ -20253,9 +22295,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 421:
+    /// Semantic action for production 460:
     ///
-    /// RangeListOpt /* Option<T>::Some */: Comma;
+    /// RangeListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn range_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -20272,9 +22314,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 422:
+    /// Semantic action for production 461:
     ///
-    /// RangeListOpt /* Option<T>::None */: ;
+    /// RangeListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn range_list_opt_1(&mut self) -> Result<()> {
@@ -20284,7 +22326,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 423:
+    /// Semantic action for production 462:
     ///
     /// RangeItem: Range;
     ///
@@ -20302,7 +22344,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 424:
+    /// Semantic action for production 463:
     ///
     /// Select: LBracket Expression SelectOpt /* Option */ RBracket;
     ///
@@ -20332,9 +22374,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 425:
+    /// Semantic action for production 464:
     ///
-    /// SelectOpt /* Option<T>::Some */: SelectOperator Expression;
+    /// SelectOpt /* `Option<T>::Some` */: SelectOperator Expression;
     ///
     #[parol_runtime::function_name::named]
     fn select_opt_0(
@@ -20357,9 +22399,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 426:
+    /// Semantic action for production 465:
     ///
-    /// SelectOpt /* Option<T>::None */: ;
+    /// SelectOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn select_opt_1(&mut self) -> Result<()> {
@@ -20369,7 +22411,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 427:
+    /// Semantic action for production 466:
     ///
     /// SelectOperator: Colon;
     ///
@@ -20389,7 +22431,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 428:
+    /// Semantic action for production 467:
     ///
     /// SelectOperator: PlusColon;
     ///
@@ -20409,7 +22451,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 429:
+    /// Semantic action for production 468:
     ///
     /// SelectOperator: MinusColon;
     ///
@@ -20429,7 +22471,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 430:
+    /// Semantic action for production 469:
     ///
     /// SelectOperator: Step;
     ///
@@ -20449,7 +22491,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 431:
+    /// Semantic action for production 470:
     ///
     /// Width: LAngle Expression WidthList /* Vec */ RAngle;
     ///
@@ -20479,9 +22521,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 432:
+    /// Semantic action for production 471:
     ///
-    /// WidthList /* Vec<T>::Push */: Comma Expression WidthList;
+    /// WidthList /* `Vec<T>::Push` */: Comma Expression WidthList;
     ///
     #[parol_runtime::function_name::named]
     fn width_list_0(
@@ -20505,9 +22547,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 433:
+    /// Semantic action for production 472:
     ///
-    /// WidthList /* Vec<T>::New */: ;
+    /// WidthList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn width_list_1(&mut self) -> Result<()> {
@@ -20518,7 +22560,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 434:
+    /// Semantic action for production 473:
     ///
     /// Array: LBracket Expression ArrayList /* Vec */ RBracket;
     ///
@@ -20548,9 +22590,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 435:
+    /// Semantic action for production 474:
     ///
-    /// ArrayList /* Vec<T>::Push */: Comma Expression ArrayList;
+    /// ArrayList /* `Vec<T>::Push` */: Comma Expression ArrayList;
     ///
     #[parol_runtime::function_name::named]
     fn array_list_0(
@@ -20574,9 +22616,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 436:
+    /// Semantic action for production 475:
     ///
-    /// ArrayList /* Vec<T>::New */: ;
+    /// ArrayList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn array_list_1(&mut self) -> Result<()> {
@@ -20587,7 +22629,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 437:
+    /// Semantic action for production 476:
     ///
     /// Range: Expression RangeOpt /* Option */;
     ///
@@ -20611,9 +22653,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 438:
+    /// Semantic action for production 477:
     ///
-    /// RangeOpt /* Option<T>::Some */: RangeOperator Expression;
+    /// RangeOpt /* `Option<T>::Some` */: RangeOperator Expression;
     ///
     #[parol_runtime::function_name::named]
     fn range_opt_0(
@@ -20636,9 +22678,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 439:
+    /// Semantic action for production 478:
     ///
-    /// RangeOpt /* Option<T>::None */: ;
+    /// RangeOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn range_opt_1(&mut self) -> Result<()> {
@@ -20648,7 +22690,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 440:
+    /// Semantic action for production 479:
     ///
     /// RangeOperator: DotDot;
     ///
@@ -20667,7 +22709,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 441:
+    /// Semantic action for production 480:
     ///
     /// RangeOperator: DotDotEqu;
     ///
@@ -20686,7 +22728,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 442:
+    /// Semantic action for production 481:
     ///
     /// FixedType: U32;
     ///
@@ -20703,7 +22745,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 443:
+    /// Semantic action for production 482:
     ///
     /// FixedType: U64;
     ///
@@ -20720,7 +22762,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 444:
+    /// Semantic action for production 483:
     ///
     /// FixedType: I32;
     ///
@@ -20737,7 +22779,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 445:
+    /// Semantic action for production 484:
     ///
     /// FixedType: I64;
     ///
@@ -20754,7 +22796,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 446:
+    /// Semantic action for production 485:
     ///
     /// FixedType: F32;
     ///
@@ -20771,7 +22813,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 447:
+    /// Semantic action for production 486:
     ///
     /// FixedType: F64;
     ///
@@ -20788,7 +22830,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 448:
+    /// Semantic action for production 487:
     ///
     /// FixedType: Strin;
     ///
@@ -20807,7 +22849,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 449:
+    /// Semantic action for production 488:
     ///
     /// VariableType: VariableTypeGroup VariableTypeOpt /* Option */;
     ///
@@ -20831,68 +22873,108 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 450:
+    /// Semantic action for production 489:
+    ///
+    /// VariableTypeGroup: Clock;
+    ///
+    #[parol_runtime::function_name::named]
+    fn variable_type_group_0(&mut self, _clock: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let clock = pop_item!(self, clock, Clock, context);
+        let variable_type_group_0_built = VariableTypeGroupClock {
+            clock: Box::new(clock),
+        };
+        let variable_type_group_0_built = VariableTypeGroup::Clock(variable_type_group_0_built);
+        self.push(
+            ASTType::VariableTypeGroup(variable_type_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 490:
+    ///
+    /// VariableTypeGroup: Reset;
+    ///
+    #[parol_runtime::function_name::named]
+    fn variable_type_group_1(&mut self, _reset: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let reset = pop_item!(self, reset, Reset, context);
+        let variable_type_group_1_built = VariableTypeGroupReset {
+            reset: Box::new(reset),
+        };
+        let variable_type_group_1_built = VariableTypeGroup::Reset(variable_type_group_1_built);
+        self.push(
+            ASTType::VariableTypeGroup(variable_type_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 491:
     ///
     /// VariableTypeGroup: Logic;
     ///
     #[parol_runtime::function_name::named]
-    fn variable_type_group_0(&mut self, _logic: &ParseTreeType<'t>) -> Result<()> {
+    fn variable_type_group_2(&mut self, _logic: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let logic = pop_item!(self, logic, Logic, context);
-        let variable_type_group_0_built = VariableTypeGroupLogic {
+        let variable_type_group_2_built = VariableTypeGroupLogic {
             logic: Box::new(logic),
         };
-        let variable_type_group_0_built = VariableTypeGroup::Logic(variable_type_group_0_built);
+        let variable_type_group_2_built = VariableTypeGroup::Logic(variable_type_group_2_built);
         self.push(
-            ASTType::VariableTypeGroup(variable_type_group_0_built),
+            ASTType::VariableTypeGroup(variable_type_group_2_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 451:
+    /// Semantic action for production 492:
     ///
     /// VariableTypeGroup: Bit;
     ///
     #[parol_runtime::function_name::named]
-    fn variable_type_group_1(&mut self, _bit: &ParseTreeType<'t>) -> Result<()> {
+    fn variable_type_group_3(&mut self, _bit: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let bit = pop_item!(self, bit, Bit, context);
-        let variable_type_group_1_built = VariableTypeGroupBit { bit: Box::new(bit) };
-        let variable_type_group_1_built = VariableTypeGroup::Bit(variable_type_group_1_built);
+        let variable_type_group_3_built = VariableTypeGroupBit { bit: Box::new(bit) };
+        let variable_type_group_3_built = VariableTypeGroup::Bit(variable_type_group_3_built);
         self.push(
-            ASTType::VariableTypeGroup(variable_type_group_1_built),
+            ASTType::VariableTypeGroup(variable_type_group_3_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 452:
+    /// Semantic action for production 493:
     ///
     /// VariableTypeGroup: ScopedIdentifier;
     ///
     #[parol_runtime::function_name::named]
-    fn variable_type_group_2(&mut self, _scoped_identifier: &ParseTreeType<'t>) -> Result<()> {
+    fn variable_type_group_4(&mut self, _scoped_identifier: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let scoped_identifier = pop_item!(self, scoped_identifier, ScopedIdentifier, context);
-        let variable_type_group_2_built = VariableTypeGroupScopedIdentifier {
+        let variable_type_group_4_built = VariableTypeGroupScopedIdentifier {
             scoped_identifier: Box::new(scoped_identifier),
         };
-        let variable_type_group_2_built =
-            VariableTypeGroup::ScopedIdentifier(variable_type_group_2_built);
+        let variable_type_group_4_built =
+            VariableTypeGroup::ScopedIdentifier(variable_type_group_4_built);
         self.push(
-            ASTType::VariableTypeGroup(variable_type_group_2_built),
+            ASTType::VariableTypeGroup(variable_type_group_4_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 453:
+    /// Semantic action for production 494:
     ///
-    /// VariableTypeOpt /* Option<T>::Some */: Width;
+    /// VariableTypeOpt /* `Option<T>::Some` */: Width;
     ///
     #[parol_runtime::function_name::named]
     fn variable_type_opt_0(&mut self, _width: &ParseTreeType<'t>) -> Result<()> {
@@ -20909,9 +22991,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 454:
+    /// Semantic action for production 495:
     ///
-    /// VariableTypeOpt /* Option<T>::None */: ;
+    /// VariableTypeOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn variable_type_opt_1(&mut self) -> Result<()> {
@@ -20921,7 +23003,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 455:
+    /// Semantic action for production 496:
     ///
     /// TypeModifier: Tri;
     ///
@@ -20938,7 +23020,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 456:
+    /// Semantic action for production 497:
     ///
     /// TypeModifier: Signed;
     ///
@@ -20957,7 +23039,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 457:
+    /// Semantic action for production 498:
     ///
     /// ScalarType: ScalarTypeList /* Vec */ ScalarTypeGroup;
     ///
@@ -20982,7 +23064,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 458:
+    /// Semantic action for production 499:
     ///
     /// ScalarTypeGroup: VariableType;
     ///
@@ -20999,7 +23081,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 459:
+    /// Semantic action for production 500:
     ///
     /// ScalarTypeGroup: FixedType;
     ///
@@ -21016,9 +23098,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 460:
+    /// Semantic action for production 501:
     ///
-    /// ScalarTypeList /* Vec<T>::Push */: TypeModifier ScalarTypeList;
+    /// ScalarTypeList /* `Vec<T>::Push` */: TypeModifier ScalarTypeList;
     ///
     #[parol_runtime::function_name::named]
     fn scalar_type_list_0(
@@ -21039,9 +23121,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 461:
+    /// Semantic action for production 502:
     ///
-    /// ScalarTypeList /* Vec<T>::New */: ;
+    /// ScalarTypeList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn scalar_type_list_1(&mut self) -> Result<()> {
@@ -21052,7 +23134,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 462:
+    /// Semantic action for production 503:
     ///
     /// ArrayType: ScalarType ArrayTypeOpt /* Option */;
     ///
@@ -21076,9 +23158,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 463:
+    /// Semantic action for production 504:
     ///
-    /// ArrayTypeOpt /* Option<T>::Some */: Array;
+    /// ArrayTypeOpt /* `Option<T>::Some` */: Array;
     ///
     #[parol_runtime::function_name::named]
     fn array_type_opt_0(&mut self, _array: &ParseTreeType<'t>) -> Result<()> {
@@ -21095,9 +23177,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 464:
+    /// Semantic action for production 505:
     ///
-    /// ArrayTypeOpt /* Option<T>::None */: ;
+    /// ArrayTypeOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn array_type_opt_1(&mut self) -> Result<()> {
@@ -21107,7 +23189,31 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 465:
+    /// Semantic action for production 506:
+    ///
+    /// ClockDomain: Quote Identifier;
+    ///
+    #[parol_runtime::function_name::named]
+    fn clock_domain(
+        &mut self,
+        _quote: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let quote = pop_item!(self, quote, Quote, context);
+        let clock_domain_built = ClockDomain {
+            quote: Box::new(quote),
+            identifier: Box::new(identifier),
+        };
+        // Calling user action here
+        self.user_grammar.clock_domain(&clock_domain_built)?;
+        self.push(ASTType::ClockDomain(clock_domain_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 507:
     ///
     /// Statement: IdentifierStatement;
     ///
@@ -21127,7 +23233,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 466:
+    /// Semantic action for production 508:
     ///
     /// Statement: IfStatement;
     ///
@@ -21146,7 +23252,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 467:
+    /// Semantic action for production 509:
     ///
     /// Statement: IfResetStatement;
     ///
@@ -21165,7 +23271,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 468:
+    /// Semantic action for production 510:
     ///
     /// Statement: ReturnStatement;
     ///
@@ -21184,7 +23290,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 469:
+    /// Semantic action for production 511:
     ///
     /// Statement: ForStatement;
     ///
@@ -21203,7 +23309,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 470:
+    /// Semantic action for production 512:
     ///
     /// Statement: CaseStatement;
     ///
@@ -21222,7 +23328,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 471:
+    /// Semantic action for production 513:
     ///
     /// IdentifierStatement: ExpressionIdentifier IdentifierStatementGroup Semicolon;
     ///
@@ -21259,7 +23365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 472:
+    /// Semantic action for production 514:
     ///
     /// IdentifierStatementGroup: FunctionCall;
     ///
@@ -21280,7 +23386,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 473:
+    /// Semantic action for production 515:
     ///
     /// IdentifierStatementGroup: Assignment;
     ///
@@ -21301,7 +23407,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 474:
+    /// Semantic action for production 516:
     ///
     /// Assignment: AssignmentGroup Expression;
     ///
@@ -21325,7 +23431,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 475:
+    /// Semantic action for production 517:
     ///
     /// AssignmentGroup: Equ;
     ///
@@ -21340,7 +23446,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 476:
+    /// Semantic action for production 518:
     ///
     /// AssignmentGroup: AssignmentOperator;
     ///
@@ -21358,7 +23464,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 477:
+    /// Semantic action for production 519:
     ///
     /// IfStatement: If Expression LBrace IfStatementList /* Vec */ RBrace IfStatementList0 /* Vec */ IfStatementOpt /* Option */;
     ///
@@ -21399,9 +23505,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 478:
+    /// Semantic action for production 520:
     ///
-    /// IfStatementList0 /* Vec<T>::Push */: Else If Expression LBrace IfStatementList0List /* Vec */ RBrace IfStatementList0;
+    /// IfStatementList0 /* `Vec<T>::Push` */: Else If Expression LBrace IfStatementList0List /* Vec */ RBrace IfStatementList0;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_list0_0(
@@ -21438,9 +23544,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 479:
+    /// Semantic action for production 521:
     ///
-    /// IfStatementList0List /* Vec<T>::Push */: Statement IfStatementList0List;
+    /// IfStatementList0List /* `Vec<T>::Push` */: Statement IfStatementList0List;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_list0_list_0(
@@ -21465,9 +23571,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 480:
+    /// Semantic action for production 522:
     ///
-    /// IfStatementList0List /* Vec<T>::New */: ;
+    /// IfStatementList0List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_list0_list_1(&mut self) -> Result<()> {
@@ -21481,9 +23587,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 481:
+    /// Semantic action for production 523:
     ///
-    /// IfStatementList0 /* Vec<T>::New */: ;
+    /// IfStatementList0 /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_list0_1(&mut self) -> Result<()> {
@@ -21497,9 +23603,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 482:
+    /// Semantic action for production 524:
     ///
-    /// IfStatementList /* Vec<T>::Push */: Statement IfStatementList;
+    /// IfStatementList /* `Vec<T>::Push` */: Statement IfStatementList;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_list_0(
@@ -21520,9 +23626,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 483:
+    /// Semantic action for production 525:
     ///
-    /// IfStatementList /* Vec<T>::New */: ;
+    /// IfStatementList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_list_1(&mut self) -> Result<()> {
@@ -21533,9 +23639,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 484:
+    /// Semantic action for production 526:
     ///
-    /// IfStatementOpt /* Option<T>::Some */: Else LBrace IfStatementOptList /* Vec */ RBrace;
+    /// IfStatementOpt /* `Option<T>::Some` */: Else LBrace IfStatementOptList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_opt_0(
@@ -21565,9 +23671,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 485:
+    /// Semantic action for production 527:
     ///
-    /// IfStatementOptList /* Vec<T>::Push */: Statement IfStatementOptList;
+    /// IfStatementOptList /* `Vec<T>::Push` */: Statement IfStatementOptList;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_opt_list_0(
@@ -21589,9 +23695,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 486:
+    /// Semantic action for production 528:
     ///
-    /// IfStatementOptList /* Vec<T>::New */: ;
+    /// IfStatementOptList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_opt_list_1(&mut self) -> Result<()> {
@@ -21605,9 +23711,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 487:
+    /// Semantic action for production 529:
     ///
-    /// IfStatementOpt /* Option<T>::None */: ;
+    /// IfStatementOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_statement_opt_1(&mut self) -> Result<()> {
@@ -21617,7 +23723,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 488:
+    /// Semantic action for production 530:
     ///
     /// IfResetStatement: IfReset LBrace IfResetStatementList /* Vec */ RBrace IfResetStatementList0 /* Vec */ IfResetStatementOpt /* Option */;
     ///
@@ -21661,9 +23767,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 489:
+    /// Semantic action for production 531:
     ///
-    /// IfResetStatementList0 /* Vec<T>::Push */: Else If Expression LBrace IfResetStatementList0List /* Vec */ RBrace IfResetStatementList0;
+    /// IfResetStatementList0 /* `Vec<T>::Push` */: Else If Expression LBrace IfResetStatementList0List /* Vec */ RBrace IfResetStatementList0;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_list0_0(
@@ -21712,9 +23818,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 490:
+    /// Semantic action for production 532:
     ///
-    /// IfResetStatementList0List /* Vec<T>::Push */: Statement IfResetStatementList0List;
+    /// IfResetStatementList0List /* `Vec<T>::Push` */: Statement IfResetStatementList0List;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_list0_list_0(
@@ -21743,9 +23849,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 491:
+    /// Semantic action for production 533:
     ///
-    /// IfResetStatementList0List /* Vec<T>::New */: ;
+    /// IfResetStatementList0List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_list0_list_1(&mut self) -> Result<()> {
@@ -21759,9 +23865,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 492:
+    /// Semantic action for production 534:
     ///
-    /// IfResetStatementList0 /* Vec<T>::New */: ;
+    /// IfResetStatementList0 /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_list0_1(&mut self) -> Result<()> {
@@ -21775,9 +23881,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 493:
+    /// Semantic action for production 535:
     ///
-    /// IfResetStatementList /* Vec<T>::Push */: Statement IfResetStatementList;
+    /// IfResetStatementList /* `Vec<T>::Push` */: Statement IfResetStatementList;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_list_0(
@@ -21802,9 +23908,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 494:
+    /// Semantic action for production 536:
     ///
-    /// IfResetStatementList /* Vec<T>::New */: ;
+    /// IfResetStatementList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_list_1(&mut self) -> Result<()> {
@@ -21818,9 +23924,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 495:
+    /// Semantic action for production 537:
     ///
-    /// IfResetStatementOpt /* Option<T>::Some */: Else LBrace IfResetStatementOptList /* Vec */ RBrace;
+    /// IfResetStatementOpt /* `Option<T>::Some` */: Else LBrace IfResetStatementOptList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_opt_0(
@@ -21854,9 +23960,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 496:
+    /// Semantic action for production 538:
     ///
-    /// IfResetStatementOptList /* Vec<T>::Push */: Statement IfResetStatementOptList;
+    /// IfResetStatementOptList /* `Vec<T>::Push` */: Statement IfResetStatementOptList;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_opt_list_0(
@@ -21885,9 +23991,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 497:
+    /// Semantic action for production 539:
     ///
-    /// IfResetStatementOptList /* Vec<T>::New */: ;
+    /// IfResetStatementOptList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_opt_list_1(&mut self) -> Result<()> {
@@ -21901,9 +24007,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 498:
+    /// Semantic action for production 540:
     ///
-    /// IfResetStatementOpt /* Option<T>::None */: ;
+    /// IfResetStatementOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn if_reset_statement_opt_1(&mut self) -> Result<()> {
@@ -21913,7 +24019,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 499:
+    /// Semantic action for production 541:
     ///
     /// ReturnStatement: Return Expression Semicolon;
     ///
@@ -21941,7 +24047,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 500:
+    /// Semantic action for production 542:
     ///
     /// ForStatement: For Identifier Colon ScalarType In Range ForStatementOpt /* Option */ LBrace ForStatementList /* Vec */ RBrace;
     ///
@@ -21990,9 +24096,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 501:
+    /// Semantic action for production 543:
     ///
-    /// ForStatementList /* Vec<T>::Push */: Statement ForStatementList;
+    /// ForStatementList /* `Vec<T>::Push` */: Statement ForStatementList;
     ///
     #[parol_runtime::function_name::named]
     fn for_statement_list_0(
@@ -22013,9 +24119,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 502:
+    /// Semantic action for production 544:
     ///
-    /// ForStatementList /* Vec<T>::New */: ;
+    /// ForStatementList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn for_statement_list_1(&mut self) -> Result<()> {
@@ -22029,9 +24135,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 503:
+    /// Semantic action for production 545:
     ///
-    /// ForStatementOpt /* Option<T>::Some */: Step AssignmentOperator Expression;
+    /// ForStatementOpt /* `Option<T>::Some` */: Step AssignmentOperator Expression;
     ///
     #[parol_runtime::function_name::named]
     fn for_statement_opt_0(
@@ -22057,9 +24163,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 504:
+    /// Semantic action for production 546:
     ///
-    /// ForStatementOpt /* Option<T>::None */: ;
+    /// ForStatementOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn for_statement_opt_1(&mut self) -> Result<()> {
@@ -22069,7 +24175,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 505:
+    /// Semantic action for production 547:
     ///
     /// CaseStatement: Case Expression LBrace CaseStatementList /* Vec */ RBrace;
     ///
@@ -22103,9 +24209,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 506:
+    /// Semantic action for production 548:
     ///
-    /// CaseStatementList /* Vec<T>::Push */: CaseItem CaseStatementList;
+    /// CaseStatementList /* `Vec<T>::Push` */: CaseItem CaseStatementList;
     ///
     #[parol_runtime::function_name::named]
     fn case_statement_list_0(
@@ -22127,9 +24233,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 507:
+    /// Semantic action for production 549:
     ///
-    /// CaseStatementList /* Vec<T>::New */: ;
+    /// CaseStatementList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn case_statement_list_1(&mut self) -> Result<()> {
@@ -22143,7 +24249,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 508:
+    /// Semantic action for production 550:
     ///
     /// CaseItem: CaseItemGroup Colon CaseItemGroup0;
     ///
@@ -22170,7 +24276,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 509:
+    /// Semantic action for production 551:
     ///
     /// CaseItemGroup0: Statement;
     ///
@@ -22187,7 +24293,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 510:
+    /// Semantic action for production 552:
     ///
     /// CaseItemGroup0: LBrace CaseItemGroup0List /* Vec */ RBrace;
     ///
@@ -22215,9 +24321,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 511:
+    /// Semantic action for production 553:
     ///
-    /// CaseItemGroup0List /* Vec<T>::Push */: Statement CaseItemGroup0List;
+    /// CaseItemGroup0List /* `Vec<T>::Push` */: Statement CaseItemGroup0List;
     ///
     #[parol_runtime::function_name::named]
     fn case_item_group0_list_0(
@@ -22239,9 +24345,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 512:
+    /// Semantic action for production 554:
     ///
-    /// CaseItemGroup0List /* Vec<T>::New */: ;
+    /// CaseItemGroup0List /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn case_item_group0_list_1(&mut self) -> Result<()> {
@@ -22255,7 +24361,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 513:
+    /// Semantic action for production 555:
     ///
     /// CaseItemGroup: Expression;
     ///
@@ -22272,7 +24378,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 514:
+    /// Semantic action for production 556:
     ///
     /// CaseItemGroup: Defaul;
     ///
@@ -22289,7 +24395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 515:
+    /// Semantic action for production 557:
     ///
     /// Attribute: Hash LBracket Identifier AttributeOpt /* Option */ RBracket;
     ///
@@ -22322,9 +24428,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 516:
+    /// Semantic action for production 558:
     ///
-    /// AttributeOpt /* Option<T>::Some */: LParen AttributeList RParen;
+    /// AttributeOpt /* `Option<T>::Some` */: LParen AttributeList RParen;
     ///
     #[parol_runtime::function_name::named]
     fn attribute_opt_0(
@@ -22350,9 +24456,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 517:
+    /// Semantic action for production 559:
     ///
-    /// AttributeOpt /* Option<T>::None */: ;
+    /// AttributeOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn attribute_opt_1(&mut self) -> Result<()> {
@@ -22362,7 +24468,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 518:
+    /// Semantic action for production 560:
     ///
     /// AttributeList: AttributeItem AttributeListList /* Vec */ AttributeListOpt /* Option */;
     ///
@@ -22390,9 +24496,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 519:
+    /// Semantic action for production 561:
     ///
-    /// AttributeListList /* Vec<T>::Push */: Comma AttributeItem AttributeListList;
+    /// AttributeListList /* `Vec<T>::Push` */: Comma AttributeItem AttributeListList;
     ///
     #[parol_runtime::function_name::named]
     fn attribute_list_list_0(
@@ -22417,9 +24523,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 520:
+    /// Semantic action for production 562:
     ///
-    /// AttributeListList /* Vec<T>::New */: ;
+    /// AttributeListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn attribute_list_list_1(&mut self) -> Result<()> {
@@ -22433,9 +24539,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 521:
+    /// Semantic action for production 563:
     ///
-    /// AttributeListOpt /* Option<T>::Some */: Comma;
+    /// AttributeListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn attribute_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -22452,9 +24558,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 522:
+    /// Semantic action for production 564:
     ///
-    /// AttributeListOpt /* Option<T>::None */: ;
+    /// AttributeListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn attribute_list_opt_1(&mut self) -> Result<()> {
@@ -22464,26 +24570,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 523:
+    /// Semantic action for production 565:
     ///
-    /// AttributeItem: Identifier;
+    /// AttributeItem: Identifier AttributeItemOpt /* Option */;
     ///
     #[parol_runtime::function_name::named]
-    fn attribute_item_0(&mut self, _identifier: &ParseTreeType<'t>) -> Result<()> {
+    fn attribute_item_0(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        _attribute_item_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
+        let attribute_item_opt = pop_item!(self, attribute_item_opt, AttributeItemOpt, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
-        let attribute_item_0_built = AttributeItemIdentifier {
+        let attribute_item_0_built = AttributeItemIdentifierAttributeItemOpt {
             identifier: Box::new(identifier),
+            attribute_item_opt,
         };
-        let attribute_item_0_built = AttributeItem::Identifier(attribute_item_0_built);
+        let attribute_item_0_built =
+            AttributeItem::IdentifierAttributeItemOpt(attribute_item_0_built);
         // Calling user action here
         self.user_grammar.attribute_item(&attribute_item_0_built)?;
         self.push(ASTType::AttributeItem(attribute_item_0_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 524:
+    /// Semantic action for production 566:
     ///
     /// AttributeItem: StringLiteral;
     ///
@@ -22502,9 +24615,105 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 525:
+    /// Semantic action for production 567:
+    ///
+    /// AttributeItemOpt /* `Option<T>::Some` */: AttributeItemOptGroup;
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_item_opt_0(
+        &mut self,
+        _attribute_item_opt_group: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attribute_item_opt_group = pop_item!(
+            self,
+            attribute_item_opt_group,
+            AttributeItemOptGroup,
+            context
+        );
+        let attribute_item_opt_0_built = AttributeItemOpt {
+            attribute_item_opt_group: Box::new(attribute_item_opt_group),
+        };
+        self.push(
+            ASTType::AttributeItemOpt(Some(Box::new(attribute_item_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 568:
+    ///
+    /// AttributeItemOptGroup: Equ StringLiteral;
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_item_opt_group_0(
+        &mut self,
+        _equ: &ParseTreeType<'t>,
+        _string_literal: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string_literal = pop_item!(self, string_literal, StringLiteral, context);
+        let equ = pop_item!(self, equ, Equ, context);
+        let attribute_item_opt_group_0_built = AttributeItemOptGroupEquStringLiteral {
+            equ: Box::new(equ),
+            string_literal: Box::new(string_literal),
+        };
+        let attribute_item_opt_group_0_built =
+            AttributeItemOptGroup::EquStringLiteral(attribute_item_opt_group_0_built);
+        self.push(
+            ASTType::AttributeItemOptGroup(attribute_item_opt_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 569:
+    ///
+    /// AttributeItemOptGroup: LParen AttributeList RParen;
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_item_opt_group_1(
+        &mut self,
+        _l_paren: &ParseTreeType<'t>,
+        _attribute_list: &ParseTreeType<'t>,
+        _r_paren: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let attribute_list = pop_item!(self, attribute_list, AttributeList, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let attribute_item_opt_group_1_built = AttributeItemOptGroupLParenAttributeListRParen {
+            l_paren: Box::new(l_paren),
+            attribute_list: Box::new(attribute_list),
+            r_paren: Box::new(r_paren),
+        };
+        let attribute_item_opt_group_1_built =
+            AttributeItemOptGroup::LParenAttributeListRParen(attribute_item_opt_group_1_built);
+        self.push(
+            ASTType::AttributeItemOptGroup(attribute_item_opt_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 570:
+    ///
+    /// AttributeItemOpt /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_item_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AttributeItemOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 571:
     ///
-    /// VarDeclaration: Var Identifier Colon ArrayType VarDeclarationOpt /* Option */ Semicolon;
+    /// VarDeclaration: Var Identifier Colon VarDeclarationOpt /* Option */ ArrayType VarDeclarationOpt0 /* Option */ Semicolon;
     ///
     #[parol_runtime::function_name::named]
     fn var_declaration(
@@ -22512,15 +24721,18 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         _var: &ParseTreeType<'t>,
         _identifier: &ParseTreeType<'t>,
         _colon: &ParseTreeType<'t>,
-        _array_type: &ParseTreeType<'t>,
         _var_declaration_opt: &ParseTreeType<'t>,
+        _array_type: &ParseTreeType<'t>,
+        _var_declaration_opt0: &ParseTreeType<'t>,
         _semicolon: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let semicolon = pop_item!(self, semicolon, Semicolon, context);
-        let var_declaration_opt = pop_item!(self, var_declaration_opt, VarDeclarationOpt, context);
+        let var_declaration_opt0 =
+            pop_item!(self, var_declaration_opt0, VarDeclarationOpt0, context);
         let array_type = pop_item!(self, array_type, ArrayType, context);
+        let var_declaration_opt = pop_item!(self, var_declaration_opt, VarDeclarationOpt, context);
         let colon = pop_item!(self, colon, Colon, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
         let var = pop_item!(self, var, Var, context);
@@ -22528,8 +24740,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             var: Box::new(var),
             identifier: Box::new(identifier),
             colon: Box::new(colon),
-            array_type: Box::new(array_type),
             var_declaration_opt,
+            array_type: Box::new(array_type),
+            var_declaration_opt0,
             semicolon: Box::new(semicolon),
         };
         // Calling user action here
@@ -22538,12 +24751,12 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 526:
+    /// Semantic action for production 572:
     ///
-    /// VarDeclarationOpt /* Option<T>::Some */: Equ Expression;
+    /// VarDeclarationOpt0 /* `Option<T>::Some` */: Equ Expression;
     ///
     #[parol_runtime::function_name::named]
-    fn var_declaration_opt_0(
+    fn var_declaration_opt0_0(
         &mut self,
         _equ: &ParseTreeType<'t>,
         _expression: &ParseTreeType<'t>,
@@ -22552,10 +24765,41 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let expression = pop_item!(self, expression, Expression, context);
         let equ = pop_item!(self, equ, Equ, context);
-        let var_declaration_opt_0_built = VarDeclarationOpt {
+        let var_declaration_opt0_0_built = VarDeclarationOpt0 {
             equ: Box::new(equ),
             expression: Box::new(expression),
         };
+        self.push(
+            ASTType::VarDeclarationOpt0(Some(Box::new(var_declaration_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 573:
+    ///
+    /// VarDeclarationOpt0 /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn var_declaration_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::VarDeclarationOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 574:
+    ///
+    /// VarDeclarationOpt /* `Option<T>::Some` */: ClockDomain;
+    ///
+    #[parol_runtime::function_name::named]
+    fn var_declaration_opt_0(&mut self, _clock_domain: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let clock_domain = pop_item!(self, clock_domain, ClockDomain, context);
+        let var_declaration_opt_0_built = VarDeclarationOpt {
+            clock_domain: Box::new(clock_domain),
+        };
         self.push(
             ASTType::VarDeclarationOpt(Some(Box::new(var_declaration_opt_0_built))),
             context,
@@ -22563,9 +24807,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 527:
+    /// Semantic action for production 575:
     ///
-    /// VarDeclarationOpt /* Option<T>::None */: ;
+    /// VarDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn var_declaration_opt_1(&mut self) -> Result<()> {
@@ -22575,7 +24819,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 528:
+    /// Semantic action for production 576:
     ///
     /// LocalparamDeclaration: Localparam Identifier Colon LocalparamDeclarationGroup Semicolon;
     ///
@@ -22617,7 +24861,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 529:
+    /// Semantic action for production 577:
     ///
     /// LocalparamDeclarationGroup: ArrayType Equ Expression;
     ///
@@ -22650,7 +24894,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 530:
+    /// Semantic action for production 578:
     ///
     /// LocalparamDeclarationGroup: Type Equ TypeExpression;
     ///
@@ -22681,7 +24925,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 531:
+    /// Semantic action for production 579:
     ///
     /// AlwaysFfDeclaration: AlwaysFf LParen AlwaysFfClock AlwaysFfDeclarationOpt /* Option */ RParen LBrace AlwaysFfDeclarationList /* Vec */ RBrace;
     ///
@@ -22737,9 +24981,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 532:
+    /// Semantic action for production 580:
     ///
-    /// AlwaysFfDeclarationList /* Vec<T>::Push */: Statement AlwaysFfDeclarationList;
+    /// AlwaysFfDeclarationList /* `Vec<T>::Push` */: Statement AlwaysFfDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_declaration_list_0(
@@ -22768,9 +25012,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 533:
+    /// Semantic action for production 581:
     ///
-    /// AlwaysFfDeclarationList /* Vec<T>::New */: ;
+    /// AlwaysFfDeclarationList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_declaration_list_1(&mut self) -> Result<()> {
@@ -22784,9 +25028,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 534:
+    /// Semantic action for production 582:
     ///
-    /// AlwaysFfDeclarationOpt /* Option<T>::Some */: Comma AlwaysFfReset;
+    /// AlwaysFfDeclarationOpt /* `Option<T>::Some` */: Comma AlwaysFfReset;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_declaration_opt_0(
@@ -22809,9 +25053,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 535:
+    /// Semantic action for production 583:
     ///
-    /// AlwaysFfDeclarationOpt /* Option<T>::None */: ;
+    /// AlwaysFfDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_declaration_opt_1(&mut self) -> Result<()> {
@@ -22821,7 +25065,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 536:
+    /// Semantic action for production 584:
     ///
     /// AlwaysFfClock: AlwaysFfClockOpt /* Option */ HierarchicalIdentifier;
     ///
@@ -22850,9 +25094,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 537:
+    /// Semantic action for production 585:
     ///
-    /// AlwaysFfClockOpt /* Option<T>::Some */: AlwaysFfClockOptGroup;
+    /// AlwaysFfClockOpt /* `Option<T>::Some` */: AlwaysFfClockOptGroup;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_clock_opt_0(
@@ -22877,7 +25121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 538:
+    /// Semantic action for production 586:
     ///
     /// AlwaysFfClockOptGroup: Posedge;
     ///
@@ -22898,7 +25142,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 539:
+    /// Semantic action for production 587:
     ///
     /// AlwaysFfClockOptGroup: Negedge;
     ///
@@ -22919,9 +25163,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 540:
+    /// Semantic action for production 588:
     ///
-    /// AlwaysFfClockOpt /* Option<T>::None */: ;
+    /// AlwaysFfClockOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_clock_opt_1(&mut self) -> Result<()> {
@@ -22931,7 +25175,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 541:
+    /// Semantic action for production 589:
     ///
     /// AlwaysFfReset: AlwaysFfResetOpt /* Option */ HierarchicalIdentifier;
     ///
@@ -22960,9 +25204,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 542:
+    /// Semantic action for production 590:
     ///
-    /// AlwaysFfResetOpt /* Option<T>::Some */: AlwaysFfResetOptGroup;
+    /// AlwaysFfResetOpt /* `Option<T>::Some` */: AlwaysFfResetOptGroup;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_reset_opt_0(
@@ -22987,7 +25231,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 543:
+    /// Semantic action for production 591:
     ///
     /// AlwaysFfResetOptGroup: AsyncLow;
     ///
@@ -23008,7 +25252,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 544:
+    /// Semantic action for production 592:
     ///
     /// AlwaysFfResetOptGroup: AsyncHigh;
     ///
@@ -23029,7 +25273,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 545:
+    /// Semantic action for production 593:
     ///
     /// AlwaysFfResetOptGroup: SyncLow;
     ///
@@ -23050,7 +25294,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 546:
+    /// Semantic action for production 594:
     ///
     /// AlwaysFfResetOptGroup: SyncHigh;
     ///
@@ -23071,9 +25315,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 547:
+    /// Semantic action for production 595:
     ///
-    /// AlwaysFfResetOpt /* Option<T>::None */: ;
+    /// AlwaysFfResetOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn always_ff_reset_opt_1(&mut self) -> Result<()> {
@@ -23083,7 +25327,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 548:
+    /// Semantic action for production 596:
     ///
     /// AlwaysCombDeclaration: AlwaysComb LBrace AlwaysCombDeclarationList /* Vec */ RBrace;
     ///
@@ -23122,9 +25366,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 549:
+    /// Semantic action for production 597:
     ///
-    /// AlwaysCombDeclarationList /* Vec<T>::Push */: Statement AlwaysCombDeclarationList;
+    /// AlwaysCombDeclarationList /* `Vec<T>::Push` */: Statement AlwaysCombDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn always_comb_declaration_list_0(
@@ -23153,9 +25397,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 550:
+    /// Semantic action for production 598:
     ///
-    /// AlwaysCombDeclarationList /* Vec<T>::New */: ;
+    /// AlwaysCombDeclarationList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn always_comb_declaration_list_1(&mut self) -> Result<()> {
@@ -23169,7 +25413,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 551:
+    /// Semantic action for production 599:
     ///
     /// AssignDeclaration: Assign HierarchicalIdentifier Equ Expression Semicolon;
     ///
@@ -23211,7 +25455,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 552:
+    /// Semantic action for production 600:
     ///
     /// ModportDeclaration: Modport Identifier LBrace ModportList RBrace;
     ///
@@ -23248,7 +25492,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 553:
+    /// Semantic action for production 601:
     ///
     /// ModportList: ModportGroup ModportListList /* Vec */ ModportListOpt /* Option */;
     ///
@@ -23276,9 +25520,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 554:
+    /// Semantic action for production 602:
     ///
-    /// ModportListList /* Vec<T>::Push */: Comma ModportGroup ModportListList;
+    /// ModportListList /* `Vec<T>::Push` */: Comma ModportGroup ModportListList;
     ///
     #[parol_runtime::function_name::named]
     fn modport_list_list_0(
@@ -23302,9 +25546,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 555:
+    /// Semantic action for production 603:
     ///
-    /// ModportListList /* Vec<T>::New */: ;
+    /// ModportListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn modport_list_list_1(&mut self) -> Result<()> {
@@ -23315,9 +25559,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 556:
+    /// Semantic action for production 604:
     ///
-    /// ModportListOpt /* Option<T>::Some */: Comma;
+    /// ModportListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn modport_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -23334,9 +25578,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 557:
+    /// Semantic action for production 605:
     ///
-    /// ModportListOpt /* Option<T>::None */: ;
+    /// ModportListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn modport_list_opt_1(&mut self) -> Result<()> {
@@ -23346,7 +25590,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 558:
+    /// Semantic action for production 606:
     ///
     /// ModportGroup: ModportGroupList /* Vec */ ModportGroupGroup;
     ///
@@ -23371,7 +25615,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 559:
+    /// Semantic action for production 607:
     ///
     /// ModportGroupGroup: LBrace ModportList RBrace;
     ///
@@ -23401,7 +25645,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 560:
+    /// Semantic action for production 608:
     ///
     /// ModportGroupGroup: ModportItem;
     ///
@@ -23422,9 +25666,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 561:
+    /// Semantic action for production 609:
     ///
-    /// ModportGroupList /* Vec<T>::Push */: Attribute ModportGroupList;
+    /// ModportGroupList /* `Vec<T>::Push` */: Attribute ModportGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn modport_group_list_0(
@@ -23445,9 +25689,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 562:
+    /// Semantic action for production 610:
     ///
-    /// ModportGroupList /* Vec<T>::New */: ;
+    /// ModportGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn modport_group_list_1(&mut self) -> Result<()> {
@@ -23461,7 +25705,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 563:
+    /// Semantic action for production 611:
     ///
     /// ModportItem: Identifier Colon Direction;
     ///
@@ -23488,7 +25732,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 564:
+    /// Semantic action for production 612:
     ///
     /// EnumDeclaration: Enum Identifier Colon ScalarType LBrace EnumList RBrace;
     ///
@@ -23528,7 +25772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 565:
+    /// Semantic action for production 613:
     ///
     /// EnumList: EnumGroup EnumListList /* Vec */ EnumListOpt /* Option */;
     ///
@@ -23555,9 +25799,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 566:
+    /// Semantic action for production 614:
     ///
-    /// EnumListList /* Vec<T>::Push */: Comma EnumGroup EnumListList;
+    /// EnumListList /* `Vec<T>::Push` */: Comma EnumGroup EnumListList;
     ///
     #[parol_runtime::function_name::named]
     fn enum_list_list_0(
@@ -23581,9 +25825,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 567:
+    /// Semantic action for production 615:
     ///
-    /// EnumListList /* Vec<T>::New */: ;
+    /// EnumListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn enum_list_list_1(&mut self) -> Result<()> {
@@ -23594,9 +25838,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 568:
+    /// Semantic action for production 616:
     ///
-    /// EnumListOpt /* Option<T>::Some */: Comma;
+    /// EnumListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn enum_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -23613,9 +25857,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 569:
+    /// Semantic action for production 617:
     ///
-    /// EnumListOpt /* Option<T>::None */: ;
+    /// EnumListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn enum_list_opt_1(&mut self) -> Result<()> {
@@ -23625,7 +25869,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 570:
+    /// Semantic action for production 618:
     ///
     /// EnumGroup: EnumGroupList /* Vec */ EnumGroupGroup;
     ///
@@ -23649,7 +25893,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 571:
+    /// Semantic action for production 619:
     ///
     /// EnumGroupGroup: LBrace EnumList RBrace;
     ///
@@ -23676,7 +25920,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 572:
+    /// Semantic action for production 620:
     ///
     /// EnumGroupGroup: EnumItem;
     ///
@@ -23693,9 +25937,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 573:
+    /// Semantic action for production 621:
     ///
-    /// EnumGroupList /* Vec<T>::Push */: Attribute EnumGroupList;
+    /// EnumGroupList /* `Vec<T>::Push` */: Attribute EnumGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn enum_group_list_0(
@@ -23716,9 +25960,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 574:
+    /// Semantic action for production 622:
     ///
-    /// EnumGroupList /* Vec<T>::New */: ;
+    /// EnumGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn enum_group_list_1(&mut self) -> Result<()> {
@@ -23729,7 +25973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 575:
+    /// Semantic action for production 623:
     ///
     /// EnumItem: Identifier EnumItemOpt /* Option */;
     ///
@@ -23753,9 +25997,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 576:
+    /// Semantic action for production 624:
     ///
-    /// EnumItemOpt /* Option<T>::Some */: Equ Expression;
+    /// EnumItemOpt /* `Option<T>::Some` */: Equ Expression;
     ///
     #[parol_runtime::function_name::named]
     fn enum_item_opt_0(
@@ -23778,9 +26022,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 577:
+    /// Semantic action for production 625:
     ///
-    /// EnumItemOpt /* Option<T>::None */: ;
+    /// EnumItemOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn enum_item_opt_1(&mut self) -> Result<()> {
@@ -23790,7 +26034,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 578:
+    /// Semantic action for production 626:
     ///
     /// StructDeclaration: Struct Identifier LBrace StructList RBrace;
     ///
@@ -23827,7 +26071,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 579:
+    /// Semantic action for production 627:
     ///
     /// StructList: StructGroup StructListList /* Vec */ StructListOpt /* Option */;
     ///
@@ -23855,9 +26099,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 580:
+    /// Semantic action for production 628:
     ///
-    /// StructListList /* Vec<T>::Push */: Comma StructGroup StructListList;
+    /// StructListList /* `Vec<T>::Push` */: Comma StructGroup StructListList;
     ///
     #[parol_runtime::function_name::named]
     fn struct_list_list_0(
@@ -23881,9 +26125,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 581:
+    /// Semantic action for production 629:
     ///
-    /// StructListList /* Vec<T>::New */: ;
+    /// StructListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn struct_list_list_1(&mut self) -> Result<()> {
@@ -23894,9 +26138,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 582:
+    /// Semantic action for production 630:
     ///
-    /// StructListOpt /* Option<T>::Some */: Comma;
+    /// StructListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn struct_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -23913,9 +26157,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 583:
+    /// Semantic action for production 631:
     ///
-    /// StructListOpt /* Option<T>::None */: ;
+    /// StructListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn struct_list_opt_1(&mut self) -> Result<()> {
@@ -23925,7 +26169,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 584:
+    /// Semantic action for production 632:
     ///
     /// StructGroup: StructGroupList /* Vec */ StructGroupGroup;
     ///
@@ -23950,7 +26194,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 585:
+    /// Semantic action for production 633:
     ///
     /// StructGroupGroup: LBrace StructList RBrace;
     ///
@@ -23980,7 +26224,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 586:
+    /// Semantic action for production 634:
     ///
     /// StructGroupGroup: StructItem;
     ///
@@ -24000,9 +26244,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 587:
+    /// Semantic action for production 635:
     ///
-    /// StructGroupList /* Vec<T>::Push */: Attribute StructGroupList;
+    /// StructGroupList /* `Vec<T>::Push` */: Attribute StructGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn struct_group_list_0(
@@ -24023,9 +26267,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 588:
+    /// Semantic action for production 636:
     ///
-    /// StructGroupList /* Vec<T>::New */: ;
+    /// StructGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn struct_group_list_1(&mut self) -> Result<()> {
@@ -24036,7 +26280,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 589:
+    /// Semantic action for production 637:
     ///
     /// StructItem: Identifier Colon ScalarType;
     ///
@@ -24063,32 +26307,66 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 590:
+    /// Semantic action for production 638:
     ///
-    /// InitialDeclaration: Initial LBrace InitialDeclarationList /* Vec */ RBrace;
+    /// UnionDeclaration: Union Identifier LBrace StructList RBrace;
     ///
     #[parol_runtime::function_name::named]
-    fn initial_declaration(
+    fn union_declaration(
         &mut self,
-        _initial: &ParseTreeType<'t>,
+        _union: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
         _l_brace: &ParseTreeType<'t>,
-        _initial_declaration_list: &ParseTreeType<'t>,
+        _struct_list: &ParseTreeType<'t>,
         _r_brace: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let r_brace = pop_item!(self, r_brace, RBrace, context);
-        let initial_declaration_list = pop_and_reverse_item!(
-            self,
-            initial_declaration_list,
-            InitialDeclarationList,
-            context
-        );
+        let struct_list = pop_item!(self, struct_list, StructList, context);
         let l_brace = pop_item!(self, l_brace, LBrace, context);
-        let initial = pop_item!(self, initial, Initial, context);
-        let initial_declaration_built = InitialDeclaration {
-            initial: Box::new(initial),
-            l_brace: Box::new(l_brace),
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let r#union = pop_item!(self, r#union, Union, context);
+        let union_declaration_built = UnionDeclaration {
+            r#union: Box::new(r#union),
+            identifier: Box::new(identifier),
+            l_brace: Box::new(l_brace),
+            struct_list: Box::new(struct_list),
+            r_brace: Box::new(r_brace),
+        };
+        // Calling user action here
+        self.user_grammar
+            .union_declaration(&union_declaration_built)?;
+        self.push(ASTType::UnionDeclaration(union_declaration_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 639:
+    ///
+    /// InitialDeclaration: Initial LBrace InitialDeclarationList /* Vec */ RBrace;
+    ///
+    #[parol_runtime::function_name::named]
+    fn initial_declaration(
+        &mut self,
+        _initial: &ParseTreeType<'t>,
+        _l_brace: &ParseTreeType<'t>,
+        _initial_declaration_list: &ParseTreeType<'t>,
+        _r_brace: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let initial_declaration_list = pop_and_reverse_item!(
+            self,
+            initial_declaration_list,
+            InitialDeclarationList,
+            context
+        );
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let initial = pop_item!(self, initial, Initial, context);
+        let initial_declaration_built = InitialDeclaration {
+            initial: Box::new(initial),
+            l_brace: Box::new(l_brace),
             initial_declaration_list,
             r_brace: Box::new(r_brace),
         };
@@ -24102,9 +26380,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 591:
+    /// Semantic action for production 640:
     ///
-    /// InitialDeclarationList /* Vec<T>::Push */: Statement InitialDeclarationList;
+    /// InitialDeclarationList /* `Vec<T>::Push` */: Statement InitialDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn initial_declaration_list_0(
@@ -24133,9 +26411,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 592:
+    /// Semantic action for production 641:
     ///
-    /// InitialDeclarationList /* Vec<T>::New */: ;
+    /// InitialDeclarationList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn initial_declaration_list_1(&mut self) -> Result<()> {
@@ -24149,7 +26427,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 593:
+    /// Semantic action for production 642:
     ///
     /// FinalDeclaration: Final LBrace FinalDeclarationList /* Vec */ RBrace;
     ///
@@ -24181,9 +26459,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 594:
+    /// Semantic action for production 643:
     ///
-    /// FinalDeclarationList /* Vec<T>::Push */: Statement FinalDeclarationList;
+    /// FinalDeclarationList /* `Vec<T>::Push` */: Statement FinalDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn final_declaration_list_0(
@@ -24208,9 +26486,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 595:
+    /// Semantic action for production 644:
     ///
-    /// FinalDeclarationList /* Vec<T>::New */: ;
+    /// FinalDeclarationList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn final_declaration_list_1(&mut self) -> Result<()> {
@@ -24224,7 +26502,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 596:
+    /// Semantic action for production 645:
     ///
     /// InstDeclaration: Inst Identifier Colon ScopedIdentifier InstDeclarationOpt /* Option */ InstDeclarationOpt0 /* Option */ InstDeclarationOpt1 /* Option */ Semicolon;
     ///
@@ -24270,9 +26548,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 597:
+    /// Semantic action for production 646:
     ///
-    /// InstDeclarationOpt1 /* Option<T>::Some */: LParen InstDeclarationOpt2 /* Option */ RParen;
+    /// InstDeclarationOpt1 /* `Option<T>::Some` */: LParen InstDeclarationOpt2 /* Option */ RParen;
     ///
     #[parol_runtime::function_name::named]
     fn inst_declaration_opt1_0(
@@ -24299,9 +26577,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 598:
+    /// Semantic action for production 647:
     ///
-    /// InstDeclarationOpt2 /* Option<T>::Some */: InstPortList;
+    /// InstDeclarationOpt2 /* `Option<T>::Some` */: InstPortList;
     ///
     #[parol_runtime::function_name::named]
     fn inst_declaration_opt2_0(&mut self, _inst_port_list: &ParseTreeType<'t>) -> Result<()> {
@@ -24318,9 +26596,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 599:
+    /// Semantic action for production 648:
     ///
-    /// InstDeclarationOpt2 /* Option<T>::None */: ;
+    /// InstDeclarationOpt2 /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_declaration_opt2_1(&mut self) -> Result<()> {
@@ -24330,9 +26608,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 600:
+    /// Semantic action for production 649:
     ///
-    /// InstDeclarationOpt1 /* Option<T>::None */: ;
+    /// InstDeclarationOpt1 /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_declaration_opt1_1(&mut self) -> Result<()> {
@@ -24342,9 +26620,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 601:
+    /// Semantic action for production 650:
     ///
-    /// InstDeclarationOpt0 /* Option<T>::Some */: InstParameter;
+    /// InstDeclarationOpt0 /* `Option<T>::Some` */: InstParameter;
     ///
     #[parol_runtime::function_name::named]
     fn inst_declaration_opt0_0(&mut self, _inst_parameter: &ParseTreeType<'t>) -> Result<()> {
@@ -24361,9 +26639,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 602:
+    /// Semantic action for production 651:
     ///
-    /// InstDeclarationOpt0 /* Option<T>::None */: ;
+    /// InstDeclarationOpt0 /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_declaration_opt0_1(&mut self) -> Result<()> {
@@ -24373,9 +26651,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 603:
+    /// Semantic action for production 652:
     ///
-    /// InstDeclarationOpt /* Option<T>::Some */: Array;
+    /// InstDeclarationOpt /* `Option<T>::Some` */: Array;
     ///
     #[parol_runtime::function_name::named]
     fn inst_declaration_opt_0(&mut self, _array: &ParseTreeType<'t>) -> Result<()> {
@@ -24392,9 +26670,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 604:
+    /// Semantic action for production 653:
     ///
-    /// InstDeclarationOpt /* Option<T>::None */: ;
+    /// InstDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_declaration_opt_1(&mut self) -> Result<()> {
@@ -24404,7 +26682,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 605:
+    /// Semantic action for production 654:
     ///
     /// InstParameter: Hash LParen InstParameterOpt /* Option */ RParen;
     ///
@@ -24434,9 +26712,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 606:
+    /// Semantic action for production 655:
     ///
-    /// InstParameterOpt /* Option<T>::Some */: InstParameterList;
+    /// InstParameterOpt /* `Option<T>::Some` */: InstParameterList;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_opt_0(&mut self, _inst_parameter_list: &ParseTreeType<'t>) -> Result<()> {
@@ -24453,9 +26731,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 607:
+    /// Semantic action for production 656:
     ///
-    /// InstParameterOpt /* Option<T>::None */: ;
+    /// InstParameterOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_opt_1(&mut self) -> Result<()> {
@@ -24465,7 +26743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 608:
+    /// Semantic action for production 657:
     ///
     /// InstParameterList: InstParameterGroup InstParameterListList /* Vec */ InstParameterListOpt /* Option */;
     ///
@@ -24503,9 +26781,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 609:
+    /// Semantic action for production 658:
     ///
-    /// InstParameterListList /* Vec<T>::Push */: Comma InstParameterGroup InstParameterListList;
+    /// InstParameterListList /* `Vec<T>::Push` */: Comma InstParameterGroup InstParameterListList;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_list_list_0(
@@ -24538,9 +26816,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 610:
+    /// Semantic action for production 659:
     ///
-    /// InstParameterListList /* Vec<T>::New */: ;
+    /// InstParameterListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_list_list_1(&mut self) -> Result<()> {
@@ -24554,9 +26832,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 611:
+    /// Semantic action for production 660:
     ///
-    /// InstParameterListOpt /* Option<T>::Some */: Comma;
+    /// InstParameterListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -24573,9 +26851,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 612:
+    /// Semantic action for production 661:
     ///
-    /// InstParameterListOpt /* Option<T>::None */: ;
+    /// InstParameterListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_list_opt_1(&mut self) -> Result<()> {
@@ -24585,7 +26863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 613:
+    /// Semantic action for production 662:
     ///
     /// InstParameterGroup: InstParameterGroupList /* Vec */ InstParameterGroupGroup;
     ///
@@ -24623,7 +26901,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 614:
+    /// Semantic action for production 663:
     ///
     /// InstParameterGroupGroup: LBrace InstParameterList RBrace;
     ///
@@ -24656,7 +26934,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 615:
+    /// Semantic action for production 664:
     ///
     /// InstParameterGroupGroup: InstParameterItem;
     ///
@@ -24680,9 +26958,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 616:
+    /// Semantic action for production 665:
     ///
-    /// InstParameterGroupList /* Vec<T>::Push */: Attribute InstParameterGroupList;
+    /// InstParameterGroupList /* `Vec<T>::Push` */: Attribute InstParameterGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_group_list_0(
@@ -24711,9 +26989,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 617:
+    /// Semantic action for production 666:
     ///
-    /// InstParameterGroupList /* Vec<T>::New */: ;
+    /// InstParameterGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_group_list_1(&mut self) -> Result<()> {
@@ -24727,7 +27005,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 618:
+    /// Semantic action for production 667:
     ///
     /// InstParameterItem: Identifier InstParameterItemOpt /* Option */;
     ///
@@ -24756,9 +27034,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 619:
+    /// Semantic action for production 668:
     ///
-    /// InstParameterItemOpt /* Option<T>::Some */: Colon Expression;
+    /// InstParameterItemOpt /* `Option<T>::Some` */: Colon Expression;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_item_opt_0(
@@ -24781,9 +27059,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 620:
+    /// Semantic action for production 669:
     ///
-    /// InstParameterItemOpt /* Option<T>::None */: ;
+    /// InstParameterItemOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_item_opt_1(&mut self) -> Result<()> {
@@ -24793,7 +27071,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 621:
+    /// Semantic action for production 670:
     ///
     /// InstPortList: InstPortGroup InstPortListList /* Vec */ InstPortListOpt /* Option */;
     ///
@@ -24821,9 +27099,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 622:
+    /// Semantic action for production 671:
     ///
-    /// InstPortListList /* Vec<T>::Push */: Comma InstPortGroup InstPortListList;
+    /// InstPortListList /* `Vec<T>::Push` */: Comma InstPortGroup InstPortListList;
     ///
     #[parol_runtime::function_name::named]
     fn inst_port_list_list_0(
@@ -24848,9 +27126,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 623:
+    /// Semantic action for production 672:
     ///
-    /// InstPortListList /* Vec<T>::New */: ;
+    /// InstPortListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_port_list_list_1(&mut self) -> Result<()> {
@@ -24864,9 +27142,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 624:
+    /// Semantic action for production 673:
     ///
-    /// InstPortListOpt /* Option<T>::Some */: Comma;
+    /// InstPortListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn inst_port_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -24883,9 +27161,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 625:
+    /// Semantic action for production 674:
     ///
-    /// InstPortListOpt /* Option<T>::None */: ;
+    /// InstPortListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_port_list_opt_1(&mut self) -> Result<()> {
@@ -24895,7 +27173,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 626:
+    /// Semantic action for production 675:
     ///
     /// InstPortGroup: InstPortGroupList /* Vec */ InstPortGroupGroup;
     ///
@@ -24921,7 +27199,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 627:
+    /// Semantic action for production 676:
     ///
     /// InstPortGroupGroup: LBrace InstPortList RBrace;
     ///
@@ -24951,7 +27229,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 628:
+    /// Semantic action for production 677:
     ///
     /// InstPortGroupGroup: InstPortItem;
     ///
@@ -24972,9 +27250,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 629:
+    /// Semantic action for production 678:
     ///
-    /// InstPortGroupList /* Vec<T>::Push */: Attribute InstPortGroupList;
+    /// InstPortGroupList /* `Vec<T>::Push` */: Attribute InstPortGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn inst_port_group_list_0(
@@ -24996,9 +27274,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 630:
+    /// Semantic action for production 679:
     ///
-    /// InstPortGroupList /* Vec<T>::New */: ;
+    /// InstPortGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_port_group_list_1(&mut self) -> Result<()> {
@@ -25012,7 +27290,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 631:
+    /// Semantic action for production 680:
     ///
     /// InstPortItem: Identifier InstPortItemOpt /* Option */;
     ///
@@ -25036,9 +27314,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 632:
+    /// Semantic action for production 681:
     ///
-    /// InstPortItemOpt /* Option<T>::Some */: Colon Expression;
+    /// InstPortItemOpt /* `Option<T>::Some` */: Colon Expression;
     ///
     #[parol_runtime::function_name::named]
     fn inst_port_item_opt_0(
@@ -25061,9 +27339,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 633:
+    /// Semantic action for production 682:
     ///
-    /// InstPortItemOpt /* Option<T>::None */: ;
+    /// InstPortItemOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn inst_port_item_opt_1(&mut self) -> Result<()> {
@@ -25073,7 +27351,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 634:
+    /// Semantic action for production 683:
     ///
     /// WithParameter: Hash LParen WithParameterOpt /* Option */ RParen;
     ///
@@ -25103,9 +27381,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 635:
+    /// Semantic action for production 684:
     ///
-    /// WithParameterOpt /* Option<T>::Some */: WithParameterList;
+    /// WithParameterOpt /* `Option<T>::Some` */: WithParameterList;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_opt_0(&mut self, _with_parameter_list: &ParseTreeType<'t>) -> Result<()> {
@@ -25122,9 +27400,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 636:
+    /// Semantic action for production 685:
     ///
-    /// WithParameterOpt /* Option<T>::None */: ;
+    /// WithParameterOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_opt_1(&mut self) -> Result<()> {
@@ -25134,7 +27412,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 637:
+    /// Semantic action for production 686:
     ///
     /// WithParameterList: WithParameterGroup WithParameterListList /* Vec */ WithParameterListOpt /* Option */;
     ///
@@ -25172,9 +27450,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 638:
+    /// Semantic action for production 687:
     ///
-    /// WithParameterListList /* Vec<T>::Push */: Comma WithParameterGroup WithParameterListList;
+    /// WithParameterListList /* `Vec<T>::Push` */: Comma WithParameterGroup WithParameterListList;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_list_list_0(
@@ -25207,9 +27485,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 639:
+    /// Semantic action for production 688:
     ///
-    /// WithParameterListList /* Vec<T>::New */: ;
+    /// WithParameterListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_list_list_1(&mut self) -> Result<()> {
@@ -25223,9 +27501,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 640:
+    /// Semantic action for production 689:
     ///
-    /// WithParameterListOpt /* Option<T>::Some */: Comma;
+    /// WithParameterListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -25242,9 +27520,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 641:
+    /// Semantic action for production 690:
     ///
-    /// WithParameterListOpt /* Option<T>::None */: ;
+    /// WithParameterListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_list_opt_1(&mut self) -> Result<()> {
@@ -25254,7 +27532,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 642:
+    /// Semantic action for production 691:
     ///
     /// WithParameterGroup: WithParameterGroupList /* Vec */ WithParameterGroupGroup;
     ///
@@ -25292,7 +27570,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 643:
+    /// Semantic action for production 692:
     ///
     /// WithParameterGroupGroup: LBrace WithParameterList RBrace;
     ///
@@ -25325,7 +27603,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 644:
+    /// Semantic action for production 693:
     ///
     /// WithParameterGroupGroup: WithParameterItem;
     ///
@@ -25349,9 +27627,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 645:
+    /// Semantic action for production 694:
     ///
-    /// WithParameterGroupList /* Vec<T>::Push */: Attribute WithParameterGroupList;
+    /// WithParameterGroupList /* `Vec<T>::Push` */: Attribute WithParameterGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_group_list_0(
@@ -25380,9 +27658,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 646:
+    /// Semantic action for production 695:
     ///
-    /// WithParameterGroupList /* Vec<T>::New */: ;
+    /// WithParameterGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_group_list_1(&mut self) -> Result<()> {
@@ -25396,7 +27674,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 647:
+    /// Semantic action for production 696:
     ///
     /// WithParameterItem: WithParameterItemGroup Identifier Colon WithParameterItemGroup0;
     ///
@@ -25440,7 +27718,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 648:
+    /// Semantic action for production 697:
     ///
     /// WithParameterItemGroup0: ArrayType Equ Expression;
     ///
@@ -25470,7 +27748,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 649:
+    /// Semantic action for production 698:
     ///
     /// WithParameterItemGroup0: Type Equ TypeExpression;
     ///
@@ -25500,7 +27778,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 650:
+    /// Semantic action for production 699:
     ///
     /// WithParameterItemGroup: Parameter;
     ///
@@ -25521,7 +27799,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 651:
+    /// Semantic action for production 700:
     ///
     /// WithParameterItemGroup: Localparam;
     ///
@@ -25542,163 +27820,576 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 652:
+    /// Semantic action for production 701:
     ///
-    /// PortDeclaration: LParen PortDeclarationOpt /* Option */ RParen;
+    /// WithGenericParameter: ColonColonLAngle WithGenericParameterList RAngle;
     ///
     #[parol_runtime::function_name::named]
-    fn port_declaration(
+    fn with_generic_parameter(
         &mut self,
-        _l_paren: &ParseTreeType<'t>,
-        _port_declaration_opt: &ParseTreeType<'t>,
-        _r_paren: &ParseTreeType<'t>,
+        _colon_colon_l_angle: &ParseTreeType<'t>,
+        _with_generic_parameter_list: &ParseTreeType<'t>,
+        _r_angle: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let r_paren = pop_item!(self, r_paren, RParen, context);
-        let port_declaration_opt =
-            pop_item!(self, port_declaration_opt, PortDeclarationOpt, context);
-        let l_paren = pop_item!(self, l_paren, LParen, context);
-        let port_declaration_built = PortDeclaration {
-            l_paren: Box::new(l_paren),
-            port_declaration_opt,
-            r_paren: Box::new(r_paren),
+        let r_angle = pop_item!(self, r_angle, RAngle, context);
+        let with_generic_parameter_list = pop_item!(
+            self,
+            with_generic_parameter_list,
+            WithGenericParameterList,
+            context
+        );
+        let colon_colon_l_angle = pop_item!(self, colon_colon_l_angle, ColonColonLAngle, context);
+        let with_generic_parameter_built = WithGenericParameter {
+            colon_colon_l_angle: Box::new(colon_colon_l_angle),
+            with_generic_parameter_list: Box::new(with_generic_parameter_list),
+            r_angle: Box::new(r_angle),
         };
         // Calling user action here
         self.user_grammar
-            .port_declaration(&port_declaration_built)?;
-        self.push(ASTType::PortDeclaration(port_declaration_built), context);
-        Ok(())
-    }
-
-    /// Semantic action for production 653:
-    ///
-    /// PortDeclarationOpt /* Option<T>::Some */: PortDeclarationList;
-    ///
-    #[parol_runtime::function_name::named]
-    fn port_declaration_opt_0(&mut self, _port_declaration_list: &ParseTreeType<'t>) -> Result<()> {
-        let context = function_name!();
-        trace!("{}", self.trace_item_stack(context));
-        let port_declaration_list =
-            pop_item!(self, port_declaration_list, PortDeclarationList, context);
-        let port_declaration_opt_0_built = PortDeclarationOpt {
-            port_declaration_list: Box::new(port_declaration_list),
-        };
+            .with_generic_parameter(&with_generic_parameter_built)?;
         self.push(
-            ASTType::PortDeclarationOpt(Some(Box::new(port_declaration_opt_0_built))),
+            ASTType::WithGenericParameter(with_generic_parameter_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 654:
-    ///
-    /// PortDeclarationOpt /* Option<T>::None */: ;
-    ///
-    #[parol_runtime::function_name::named]
-    fn port_declaration_opt_1(&mut self) -> Result<()> {
-        let context = function_name!();
-        trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::PortDeclarationOpt(None), context);
-        Ok(())
-    }
-
-    /// Semantic action for production 655:
+    /// Semantic action for production 702:
     ///
-    /// PortDeclarationList: PortDeclarationGroup PortDeclarationListList /* Vec */ PortDeclarationListOpt /* Option */;
+    /// WithGenericParameterList: WithGenericParameterItem WithGenericParameterListList /* Vec */ WithGenericParameterListOpt /* Option */;
     ///
     #[parol_runtime::function_name::named]
-    fn port_declaration_list(
+    fn with_generic_parameter_list(
         &mut self,
-        _port_declaration_group: &ParseTreeType<'t>,
-        _port_declaration_list_list: &ParseTreeType<'t>,
-        _port_declaration_list_opt: &ParseTreeType<'t>,
+        _with_generic_parameter_item: &ParseTreeType<'t>,
+        _with_generic_parameter_list_list: &ParseTreeType<'t>,
+        _with_generic_parameter_list_opt: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let port_declaration_list_opt = pop_item!(
+        let with_generic_parameter_list_opt = pop_item!(
             self,
-            port_declaration_list_opt,
-            PortDeclarationListOpt,
+            with_generic_parameter_list_opt,
+            WithGenericParameterListOpt,
             context
         );
-        let port_declaration_list_list = pop_and_reverse_item!(
+        let with_generic_parameter_list_list = pop_and_reverse_item!(
             self,
-            port_declaration_list_list,
-            PortDeclarationListList,
+            with_generic_parameter_list_list,
+            WithGenericParameterListList,
             context
         );
-        let port_declaration_group =
-            pop_item!(self, port_declaration_group, PortDeclarationGroup, context);
-        let port_declaration_list_built = PortDeclarationList {
-            port_declaration_group: Box::new(port_declaration_group),
-            port_declaration_list_list,
-            port_declaration_list_opt,
+        let with_generic_parameter_item = pop_item!(
+            self,
+            with_generic_parameter_item,
+            WithGenericParameterItem,
+            context
+        );
+        let with_generic_parameter_list_built = WithGenericParameterList {
+            with_generic_parameter_item: Box::new(with_generic_parameter_item),
+            with_generic_parameter_list_list,
+            with_generic_parameter_list_opt,
         };
         // Calling user action here
         self.user_grammar
-            .port_declaration_list(&port_declaration_list_built)?;
+            .with_generic_parameter_list(&with_generic_parameter_list_built)?;
         self.push(
-            ASTType::PortDeclarationList(port_declaration_list_built),
+            ASTType::WithGenericParameterList(with_generic_parameter_list_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 656:
+    /// Semantic action for production 703:
     ///
-    /// PortDeclarationListList /* Vec<T>::Push */: Comma PortDeclarationGroup PortDeclarationListList;
+    /// WithGenericParameterListList /* `Vec<T>::Push` */: Comma WithGenericParameterItem WithGenericParameterListList;
     ///
     #[parol_runtime::function_name::named]
-    fn port_declaration_list_list_0(
+    fn with_generic_parameter_list_list_0(
         &mut self,
         _comma: &ParseTreeType<'t>,
-        _port_declaration_group: &ParseTreeType<'t>,
-        _port_declaration_list_list: &ParseTreeType<'t>,
+        _with_generic_parameter_item: &ParseTreeType<'t>,
+        _with_generic_parameter_list_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut port_declaration_list_list = pop_item!(
+        let mut with_generic_parameter_list_list = pop_item!(
             self,
-            port_declaration_list_list,
-            PortDeclarationListList,
+            with_generic_parameter_list_list,
+            WithGenericParameterListList,
+            context
+        );
+        let with_generic_parameter_item = pop_item!(
+            self,
+            with_generic_parameter_item,
+            WithGenericParameterItem,
             context
         );
-        let port_declaration_group =
-            pop_item!(self, port_declaration_group, PortDeclarationGroup, context);
         let comma = pop_item!(self, comma, Comma, context);
-        let port_declaration_list_list_0_built = PortDeclarationListList {
-            port_declaration_group: Box::new(port_declaration_group),
+        let with_generic_parameter_list_list_0_built = WithGenericParameterListList {
+            with_generic_parameter_item: Box::new(with_generic_parameter_item),
             comma: Box::new(comma),
         };
         // Add an element to the vector
-        port_declaration_list_list.push(port_declaration_list_list_0_built);
+        with_generic_parameter_list_list.push(with_generic_parameter_list_list_0_built);
         self.push(
-            ASTType::PortDeclarationListList(port_declaration_list_list),
+            ASTType::WithGenericParameterListList(with_generic_parameter_list_list),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 657:
+    /// Semantic action for production 704:
     ///
-    /// PortDeclarationListList /* Vec<T>::New */: ;
+    /// WithGenericParameterListList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn port_declaration_list_list_1(&mut self) -> Result<()> {
+    fn with_generic_parameter_list_list_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let port_declaration_list_list_1_built = Vec::new();
+        let with_generic_parameter_list_list_1_built = Vec::new();
         self.push(
-            ASTType::PortDeclarationListList(port_declaration_list_list_1_built),
+            ASTType::WithGenericParameterListList(with_generic_parameter_list_list_1_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 658:
+    /// Semantic action for production 705:
+    ///
+    /// WithGenericParameterListOpt /* `Option<T>::Some` */: Comma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_parameter_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comma = pop_item!(self, comma, Comma, context);
+        let with_generic_parameter_list_opt_0_built = WithGenericParameterListOpt {
+            comma: Box::new(comma),
+        };
+        self.push(
+            ASTType::WithGenericParameterListOpt(Some(Box::new(
+                with_generic_parameter_list_opt_0_built,
+            ))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 706:
+    ///
+    /// WithGenericParameterListOpt /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_parameter_list_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::WithGenericParameterListOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 707:
+    ///
+    /// WithGenericParameterItem: Identifier;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_parameter_item(&mut self, _identifier: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let with_generic_parameter_item_built = WithGenericParameterItem {
+            identifier: Box::new(identifier),
+        };
+        // Calling user action here
+        self.user_grammar
+            .with_generic_parameter_item(&with_generic_parameter_item_built)?;
+        self.push(
+            ASTType::WithGenericParameterItem(with_generic_parameter_item_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 708:
+    ///
+    /// WithGenericArgument: ColonColonLAngle %push(Generic) WithGenericArgumentList RAngle %pop();
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_argument(
+        &mut self,
+        _colon_colon_l_angle: &ParseTreeType<'t>,
+        _with_generic_argument_list: &ParseTreeType<'t>,
+        _r_angle: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_angle = pop_item!(self, r_angle, RAngle, context);
+        let with_generic_argument_list = pop_item!(
+            self,
+            with_generic_argument_list,
+            WithGenericArgumentList,
+            context
+        );
+        let colon_colon_l_angle = pop_item!(self, colon_colon_l_angle, ColonColonLAngle, context);
+        let with_generic_argument_built = WithGenericArgument {
+            colon_colon_l_angle: Box::new(colon_colon_l_angle),
+            with_generic_argument_list: Box::new(with_generic_argument_list),
+            r_angle: Box::new(r_angle),
+        };
+        // Calling user action here
+        self.user_grammar
+            .with_generic_argument(&with_generic_argument_built)?;
+        self.push(
+            ASTType::WithGenericArgument(with_generic_argument_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 709:
+    ///
+    /// WithGenericArgumentList: WithGenericArgumentItem WithGenericArgumentListList /* Vec */ WithGenericArgumentListOpt /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_argument_list(
+        &mut self,
+        _with_generic_argument_item: &ParseTreeType<'t>,
+        _with_generic_argument_list_list: &ParseTreeType<'t>,
+        _with_generic_argument_list_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_generic_argument_list_opt = pop_item!(
+            self,
+            with_generic_argument_list_opt,
+            WithGenericArgumentListOpt,
+            context
+        );
+        let with_generic_argument_list_list = pop_and_reverse_item!(
+            self,
+            with_generic_argument_list_list,
+            WithGenericArgumentListList,
+            context
+        );
+        let with_generic_argument_item = pop_item!(
+            self,
+            with_generic_argument_item,
+            WithGenericArgumentItem,
+            context
+        );
+        let with_generic_argument_list_built = WithGenericArgumentList {
+            with_generic_argument_item: Box::new(with_generic_argument_item),
+            with_generic_argument_list_list,
+            with_generic_argument_list_opt,
+        };
+        // Calling user action here
+        self.user_grammar
+            .with_generic_argument_list(&with_generic_argument_list_built)?;
+        self.push(
+            ASTType::WithGenericArgumentList(with_generic_argument_list_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 710:
+    ///
+    /// WithGenericArgumentListList /* `Vec<T>::Push` */: Comma WithGenericArgumentItem WithGenericArgumentListList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_argument_list_list_0(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _with_generic_argument_item: &ParseTreeType<'t>,
+        _with_generic_argument_list_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut with_generic_argument_list_list = pop_item!(
+            self,
+            with_generic_argument_list_list,
+            WithGenericArgumentListList,
+            context
+        );
+        let with_generic_argument_item = pop_item!(
+            self,
+            with_generic_argument_item,
+            WithGenericArgumentItem,
+            context
+        );
+        let comma = pop_item!(self, comma, Comma, context);
+        let with_generic_argument_list_list_0_built = WithGenericArgumentListList {
+            with_generic_argument_item: Box::new(with_generic_argument_item),
+            comma: Box::new(comma),
+        };
+        // Add an element to the vector
+        with_generic_argument_list_list.push(with_generic_argument_list_list_0_built);
+        self.push(
+            ASTType::WithGenericArgumentListList(with_generic_argument_list_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 711:
+    ///
+    /// WithGenericArgumentListList /* `Vec<T>::New` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_argument_list_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_generic_argument_list_list_1_built = Vec::new();
+        self.push(
+            ASTType::WithGenericArgumentListList(with_generic_argument_list_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 712:
     ///
-    /// PortDeclarationListOpt /* Option<T>::Some */: Comma;
+    /// WithGenericArgumentListOpt /* `Option<T>::Some` */: Comma;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_argument_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comma = pop_item!(self, comma, Comma, context);
+        let with_generic_argument_list_opt_0_built = WithGenericArgumentListOpt {
+            comma: Box::new(comma),
+        };
+        self.push(
+            ASTType::WithGenericArgumentListOpt(Some(Box::new(
+                with_generic_argument_list_opt_0_built,
+            ))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 713:
+    ///
+    /// WithGenericArgumentListOpt /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_argument_list_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::WithGenericArgumentListOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 714:
+    ///
+    /// WithGenericArgumentItem: ScopedIdentifier;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_argument_item_0(
+        &mut self,
+        _scoped_identifier: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let scoped_identifier = pop_item!(self, scoped_identifier, ScopedIdentifier, context);
+        let with_generic_argument_item_0_built = WithGenericArgumentItemScopedIdentifier {
+            scoped_identifier: Box::new(scoped_identifier),
+        };
+        let with_generic_argument_item_0_built =
+            WithGenericArgumentItem::ScopedIdentifier(with_generic_argument_item_0_built);
+        // Calling user action here
+        self.user_grammar
+            .with_generic_argument_item(&with_generic_argument_item_0_built)?;
+        self.push(
+            ASTType::WithGenericArgumentItem(with_generic_argument_item_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 715:
+    ///
+    /// WithGenericArgumentItem: Number;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_generic_argument_item_1(&mut self, _number: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number = pop_item!(self, number, Number, context);
+        let with_generic_argument_item_1_built = WithGenericArgumentItemNumber {
+            number: Box::new(number),
+        };
+        let with_generic_argument_item_1_built =
+            WithGenericArgumentItem::Number(with_generic_argument_item_1_built);
+        // Calling user action here
+        self.user_grammar
+            .with_generic_argument_item(&with_generic_argument_item_1_built)?;
+        self.push(
+            ASTType::WithGenericArgumentItem(with_generic_argument_item_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 716:
+    ///
+    /// PortDeclaration: LParen PortDeclarationOpt /* Option */ RParen;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration(
+        &mut self,
+        _l_paren: &ParseTreeType<'t>,
+        _port_declaration_opt: &ParseTreeType<'t>,
+        _r_paren: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let port_declaration_opt =
+            pop_item!(self, port_declaration_opt, PortDeclarationOpt, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let port_declaration_built = PortDeclaration {
+            l_paren: Box::new(l_paren),
+            port_declaration_opt,
+            r_paren: Box::new(r_paren),
+        };
+        // Calling user action here
+        self.user_grammar
+            .port_declaration(&port_declaration_built)?;
+        self.push(ASTType::PortDeclaration(port_declaration_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 717:
+    ///
+    /// PortDeclarationOpt /* `Option<T>::Some` */: PortDeclarationList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_opt_0(&mut self, _port_declaration_list: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let port_declaration_list =
+            pop_item!(self, port_declaration_list, PortDeclarationList, context);
+        let port_declaration_opt_0_built = PortDeclarationOpt {
+            port_declaration_list: Box::new(port_declaration_list),
+        };
+        self.push(
+            ASTType::PortDeclarationOpt(Some(Box::new(port_declaration_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 718:
+    ///
+    /// PortDeclarationOpt /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::PortDeclarationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 719:
+    ///
+    /// PortDeclarationList: PortDeclarationGroup PortDeclarationListList /* Vec */ PortDeclarationListOpt /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_list(
+        &mut self,
+        _port_declaration_group: &ParseTreeType<'t>,
+        _port_declaration_list_list: &ParseTreeType<'t>,
+        _port_declaration_list_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let port_declaration_list_opt = pop_item!(
+            self,
+            port_declaration_list_opt,
+            PortDeclarationListOpt,
+            context
+        );
+        let port_declaration_list_list = pop_and_reverse_item!(
+            self,
+            port_declaration_list_list,
+            PortDeclarationListList,
+            context
+        );
+        let port_declaration_group =
+            pop_item!(self, port_declaration_group, PortDeclarationGroup, context);
+        let port_declaration_list_built = PortDeclarationList {
+            port_declaration_group: Box::new(port_declaration_group),
+            port_declaration_list_list,
+            port_declaration_list_opt,
+        };
+        // Calling user action here
+        self.user_grammar
+            .port_declaration_list(&port_declaration_list_built)?;
+        self.push(
+            ASTType::PortDeclarationList(port_declaration_list_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 720:
+    ///
+    /// PortDeclarationListList /* `Vec<T>::Push` */: Comma PortDeclarationGroup PortDeclarationListList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_list_list_0(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _port_declaration_group: &ParseTreeType<'t>,
+        _port_declaration_list_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut port_declaration_list_list = pop_item!(
+            self,
+            port_declaration_list_list,
+            PortDeclarationListList,
+            context
+        );
+        let port_declaration_group =
+            pop_item!(self, port_declaration_group, PortDeclarationGroup, context);
+        let comma = pop_item!(self, comma, Comma, context);
+        let port_declaration_list_list_0_built = PortDeclarationListList {
+            port_declaration_group: Box::new(port_declaration_group),
+            comma: Box::new(comma),
+        };
+        // Add an element to the vector
+        port_declaration_list_list.push(port_declaration_list_list_0_built);
+        self.push(
+            ASTType::PortDeclarationListList(port_declaration_list_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 721:
+    ///
+    /// PortDeclarationListList /* `Vec<T>::New` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_list_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let port_declaration_list_list_1_built = Vec::new();
+        self.push(
+            ASTType::PortDeclarationListList(port_declaration_list_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 722:
+    ///
+    /// PortDeclarationListOpt /* `Option<T>::Some` */: Comma;
     ///
     #[parol_runtime::function_name::named]
     fn port_declaration_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
@@ -25715,9 +28406,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 659:
+    /// Semantic action for production 723:
     ///
-    /// PortDeclarationListOpt /* Option<T>::None */: ;
+    /// PortDeclarationListOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn port_declaration_list_opt_1(&mut self) -> Result<()> {
@@ -25727,7 +28418,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 660:
+    /// Semantic action for production 724:
     ///
     /// PortDeclarationGroup: PortDeclarationGroupList /* Vec */ PortDeclarationGroupGroup;
     ///
@@ -25765,7 +28456,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 661:
+    /// Semantic action for production 725:
     ///
     /// PortDeclarationGroupGroup: LBrace PortDeclarationList RBrace;
     ///
@@ -25799,7 +28490,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 662:
+    /// Semantic action for production 726:
     ///
     /// PortDeclarationGroupGroup: PortDeclarationItem;
     ///
@@ -25824,9 +28515,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 663:
+    /// Semantic action for production 727:
     ///
-    /// PortDeclarationGroupList /* Vec<T>::Push */: Attribute PortDeclarationGroupList;
+    /// PortDeclarationGroupList /* `Vec<T>::Push` */: Attribute PortDeclarationGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn port_declaration_group_list_0(
@@ -25855,9 +28546,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 664:
+    /// Semantic action for production 728:
     ///
-    /// PortDeclarationGroupList /* Vec<T>::New */: ;
+    /// PortDeclarationGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn port_declaration_group_list_1(&mut self) -> Result<()> {
@@ -25871,7 +28562,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 665:
+    /// Semantic action for production 729:
     ///
     /// PortDeclarationItem: Identifier Colon PortDeclarationItemGroup;
     ///
@@ -25907,26 +28598,37 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 666:
+    /// Semantic action for production 730:
     ///
-    /// PortDeclarationItemGroup: Direction ArrayType;
+    /// PortDeclarationItemGroup: Direction PortDeclarationItemOpt /* Option */ ArrayType;
     ///
     #[parol_runtime::function_name::named]
     fn port_declaration_item_group_0(
         &mut self,
         _direction: &ParseTreeType<'t>,
+        _port_declaration_item_opt: &ParseTreeType<'t>,
         _array_type: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let array_type = pop_item!(self, array_type, ArrayType, context);
+        let port_declaration_item_opt = pop_item!(
+            self,
+            port_declaration_item_opt,
+            PortDeclarationItemOpt,
+            context
+        );
         let direction = pop_item!(self, direction, Direction, context);
-        let port_declaration_item_group_0_built = PortDeclarationItemGroupDirectionArrayType {
-            direction: Box::new(direction),
-            array_type: Box::new(array_type),
-        };
         let port_declaration_item_group_0_built =
-            PortDeclarationItemGroup::DirectionArrayType(port_declaration_item_group_0_built);
+            PortDeclarationItemGroupDirectionPortDeclarationItemOptArrayType {
+                direction: Box::new(direction),
+                port_declaration_item_opt,
+                array_type: Box::new(array_type),
+            };
+        let port_declaration_item_group_0_built =
+            PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(
+                port_declaration_item_group_0_built,
+            );
         self.push(
             ASTType::PortDeclarationItemGroup(port_declaration_item_group_0_built),
             context,
@@ -25934,32 +28636,32 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 667:
+    /// Semantic action for production 731:
     ///
-    /// PortDeclarationItemGroup: Interface PortDeclarationItemOpt /* Option */;
+    /// PortDeclarationItemGroup: Interface PortDeclarationItemOpt0 /* Option */;
     ///
     #[parol_runtime::function_name::named]
     fn port_declaration_item_group_1(
         &mut self,
         _interface: &ParseTreeType<'t>,
-        _port_declaration_item_opt: &ParseTreeType<'t>,
+        _port_declaration_item_opt0: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let port_declaration_item_opt = pop_item!(
+        let port_declaration_item_opt0 = pop_item!(
             self,
-            port_declaration_item_opt,
-            PortDeclarationItemOpt,
+            port_declaration_item_opt0,
+            PortDeclarationItemOpt0,
             context
         );
         let interface = pop_item!(self, interface, Interface, context);
         let port_declaration_item_group_1_built =
-            PortDeclarationItemGroupInterfacePortDeclarationItemOpt {
+            PortDeclarationItemGroupInterfacePortDeclarationItemOpt0 {
                 interface: Box::new(interface),
-                port_declaration_item_opt,
+                port_declaration_item_opt0,
             };
         let port_declaration_item_group_1_built =
-            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(
+            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(
                 port_declaration_item_group_1_built,
             );
         self.push(
@@ -25969,18 +28671,49 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 668:
+    /// Semantic action for production 732:
     ///
-    /// PortDeclarationItemOpt /* Option<T>::Some */: Array;
+    /// PortDeclarationItemOpt0 /* `Option<T>::Some` */: Array;
     ///
     #[parol_runtime::function_name::named]
-    fn port_declaration_item_opt_0(&mut self, _array: &ParseTreeType<'t>) -> Result<()> {
+    fn port_declaration_item_opt0_0(&mut self, _array: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let array = pop_item!(self, array, Array, context);
-        let port_declaration_item_opt_0_built = PortDeclarationItemOpt {
+        let port_declaration_item_opt0_0_built = PortDeclarationItemOpt0 {
             array: Box::new(array),
         };
+        self.push(
+            ASTType::PortDeclarationItemOpt0(Some(Box::new(port_declaration_item_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 733:
+    ///
+    /// PortDeclarationItemOpt0 /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_item_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::PortDeclarationItemOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 734:
+    ///
+    /// PortDeclarationItemOpt /* `Option<T>::Some` */: ClockDomain;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_item_opt_0(&mut self, _clock_domain: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let clock_domain = pop_item!(self, clock_domain, ClockDomain, context);
+        let port_declaration_item_opt_0_built = PortDeclarationItemOpt {
+            clock_domain: Box::new(clock_domain),
+        };
         self.push(
             ASTType::PortDeclarationItemOpt(Some(Box::new(port_declaration_item_opt_0_built))),
             context,
@@ -25988,9 +28721,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 669:
+    /// Semantic action for production 735:
     ///
-    /// PortDeclarationItemOpt /* Option<T>::None */: ;
+    /// PortDeclarationItemOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn port_declaration_item_opt_1(&mut self) -> Result<()> {
@@ -26000,7 +28733,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 670:
+    /// Semantic action for production 736:
     ///
     /// Direction: Input;
     ///
@@ -26019,7 +28752,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 671:
+    /// Semantic action for production 737:
     ///
     /// Direction: Output;
     ///
@@ -26038,7 +28771,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 672:
+    /// Semantic action for production 738:
     ///
     /// Direction: Inout;
     ///
@@ -26057,7 +28790,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 673:
+    /// Semantic action for production 739:
     ///
     /// Direction: Ref;
     ///
@@ -26076,7 +28809,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 674:
+    /// Semantic action for production 740:
     ///
     /// Direction: Modport;
     ///
@@ -26095,9 +28828,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 675:
+    /// Semantic action for production 741:
     ///
-    /// FunctionDeclaration: Function Identifier FunctionDeclarationOpt /* Option */ FunctionDeclarationOpt0 /* Option */ MinusGT ScalarType LBrace FunctionDeclarationList /* Vec */ RBrace;
+    /// FunctionDeclaration: Function Identifier FunctionDeclarationOpt /* Option */ FunctionDeclarationOpt0 /* Option */ FunctionDeclarationOpt1 /* Option */ MinusGT ScalarType LBrace FunctionDeclarationList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
     fn function_declaration(
@@ -26106,6 +28839,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         _identifier: &ParseTreeType<'t>,
         _function_declaration_opt: &ParseTreeType<'t>,
         _function_declaration_opt0: &ParseTreeType<'t>,
+        _function_declaration_opt1: &ParseTreeType<'t>,
         _minus_g_t: &ParseTreeType<'t>,
         _scalar_type: &ParseTreeType<'t>,
         _l_brace: &ParseTreeType<'t>,
@@ -26124,6 +28858,12 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let l_brace = pop_item!(self, l_brace, LBrace, context);
         let scalar_type = pop_item!(self, scalar_type, ScalarType, context);
         let minus_g_t = pop_item!(self, minus_g_t, MinusGT, context);
+        let function_declaration_opt1 = pop_item!(
+            self,
+            function_declaration_opt1,
+            FunctionDeclarationOpt1,
+            context
+        );
         let function_declaration_opt0 = pop_item!(
             self,
             function_declaration_opt0,
@@ -26143,6 +28883,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             identifier: Box::new(identifier),
             function_declaration_opt,
             function_declaration_opt0,
+            function_declaration_opt1,
             minus_g_t: Box::new(minus_g_t),
             scalar_type: Box::new(scalar_type),
             l_brace: Box::new(l_brace),
@@ -26159,9 +28900,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 676:
+    /// Semantic action for production 742:
     ///
-    /// FunctionDeclarationList /* Vec<T>::Push */: FunctionItem FunctionDeclarationList;
+    /// FunctionDeclarationList /* `Vec<T>::Push` */: FunctionItem FunctionDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn function_declaration_list_0(
@@ -26184,39 +28925,70 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         // Add an element to the vector
         function_declaration_list.push(function_declaration_list_0_built);
         self.push(
-            ASTType::FunctionDeclarationList(function_declaration_list),
+            ASTType::FunctionDeclarationList(function_declaration_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 743:
+    ///
+    /// FunctionDeclarationList /* `Vec<T>::New` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn function_declaration_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let function_declaration_list_1_built = Vec::new();
+        self.push(
+            ASTType::FunctionDeclarationList(function_declaration_list_1_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 677:
+    /// Semantic action for production 744:
     ///
-    /// FunctionDeclarationList /* Vec<T>::New */: ;
+    /// FunctionDeclarationOpt1 /* `Option<T>::Some` */: PortDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn function_declaration_list_1(&mut self) -> Result<()> {
+    fn function_declaration_opt1_0(&mut self, _port_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let function_declaration_list_1_built = Vec::new();
+        let port_declaration = pop_item!(self, port_declaration, PortDeclaration, context);
+        let function_declaration_opt1_0_built = FunctionDeclarationOpt1 {
+            port_declaration: Box::new(port_declaration),
+        };
         self.push(
-            ASTType::FunctionDeclarationList(function_declaration_list_1_built),
+            ASTType::FunctionDeclarationOpt1(Some(Box::new(function_declaration_opt1_0_built))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 678:
+    /// Semantic action for production 745:
     ///
-    /// FunctionDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;
+    /// FunctionDeclarationOpt1 /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn function_declaration_opt0_0(&mut self, _port_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn function_declaration_opt1_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let port_declaration = pop_item!(self, port_declaration, PortDeclaration, context);
+        self.push(ASTType::FunctionDeclarationOpt1(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 746:
+    ///
+    /// FunctionDeclarationOpt0 /* `Option<T>::Some` */: WithParameter;
+    ///
+    #[parol_runtime::function_name::named]
+    fn function_declaration_opt0_0(&mut self, _with_parameter: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_parameter = pop_item!(self, with_parameter, WithParameter, context);
         let function_declaration_opt0_0_built = FunctionDeclarationOpt0 {
-            port_declaration: Box::new(port_declaration),
+            with_parameter: Box::new(with_parameter),
         };
         self.push(
             ASTType::FunctionDeclarationOpt0(Some(Box::new(function_declaration_opt0_0_built))),
@@ -26225,9 +28997,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 679:
+    /// Semantic action for production 747:
     ///
-    /// FunctionDeclarationOpt0 /* Option<T>::None */: ;
+    /// FunctionDeclarationOpt0 /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn function_declaration_opt0_1(&mut self) -> Result<()> {
@@ -26237,17 +29009,21 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 680:
+    /// Semantic action for production 748:
     ///
-    /// FunctionDeclarationOpt /* Option<T>::Some */: WithParameter;
+    /// FunctionDeclarationOpt /* `Option<T>::Some` */: WithGenericParameter;
     ///
     #[parol_runtime::function_name::named]
-    fn function_declaration_opt_0(&mut self, _with_parameter: &ParseTreeType<'t>) -> Result<()> {
+    fn function_declaration_opt_0(
+        &mut self,
+        _with_generic_parameter: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let with_parameter = pop_item!(self, with_parameter, WithParameter, context);
+        let with_generic_parameter =
+            pop_item!(self, with_generic_parameter, WithGenericParameter, context);
         let function_declaration_opt_0_built = FunctionDeclarationOpt {
-            with_parameter: Box::new(with_parameter),
+            with_generic_parameter: Box::new(with_generic_parameter),
         };
         self.push(
             ASTType::FunctionDeclarationOpt(Some(Box::new(function_declaration_opt_0_built))),
@@ -26256,9 +29032,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 681:
+    /// Semantic action for production 749:
     ///
-    /// FunctionDeclarationOpt /* Option<T>::None */: ;
+    /// FunctionDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn function_declaration_opt_1(&mut self) -> Result<()> {
@@ -26268,7 +29044,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 682:
+    /// Semantic action for production 750:
     ///
     /// FunctionItem: VarDeclaration;
     ///
@@ -26287,7 +29063,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 683:
+    /// Semantic action for production 751:
     ///
     /// FunctionItem: Statement;
     ///
@@ -26306,7 +29082,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 684:
+    /// Semantic action for production 752:
     ///
     /// ImportDeclaration: Import Identifier ColonColon ImportDeclarationGroup Semicolon;
     ///
@@ -26348,7 +29124,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 685:
+    /// Semantic action for production 753:
     ///
     /// ImportDeclarationGroup: Identifier;
     ///
@@ -26369,7 +29145,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 686:
+    /// Semantic action for production 754:
     ///
     /// ImportDeclarationGroup: Star;
     ///
@@ -26390,7 +29166,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 687:
+    /// Semantic action for production 755:
     ///
     /// ExportDeclaration: Export ExportDeclarationGroup ColonColon ExportDeclarationGroup0 Semicolon;
     ///
@@ -26437,7 +29213,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 688:
+    /// Semantic action for production 756:
     ///
     /// ExportDeclarationGroup0: Identifier;
     ///
@@ -26458,7 +29234,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 689:
+    /// Semantic action for production 757:
     ///
     /// ExportDeclarationGroup0: Star;
     ///
@@ -26479,7 +29255,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 690:
+    /// Semantic action for production 758:
     ///
     /// ExportDeclarationGroup: Identifier;
     ///
@@ -26500,7 +29276,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 691:
+    /// Semantic action for production 759:
     ///
     /// ExportDeclarationGroup: Star;
     ///
@@ -26521,9 +29297,172 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 692:
+    /// Semantic action for production 760:
+    ///
+    /// EmbedDeclaration: Embed LParen Identifier RParen Identifier EmbedContent;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_declaration(
+        &mut self,
+        _embed: &ParseTreeType<'t>,
+        _l_paren: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _r_paren: &ParseTreeType<'t>,
+        _identifier0: &ParseTreeType<'t>,
+        _embed_content: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_content = pop_item!(self, embed_content, EmbedContent, context);
+        let identifier0 = pop_item!(self, identifier0, Identifier, context);
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let embed = pop_item!(self, embed, Embed, context);
+        let embed_declaration_built = EmbedDeclaration {
+            embed: Box::new(embed),
+            l_paren: Box::new(l_paren),
+            identifier: Box::new(identifier),
+            r_paren: Box::new(r_paren),
+            identifier0: Box::new(identifier0),
+            embed_content: Box::new(embed_content),
+        };
+        // Calling user action here
+        self.user_grammar
+            .embed_declaration(&embed_declaration_built)?;
+        self.push(ASTType::EmbedDeclaration(embed_declaration_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 761:
+    ///
+    /// EmbedItem: LBraceTerm EmbedItemList /* Vec */ RBraceTerm;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_item_0(
+        &mut self,
+        _l_brace_term: &ParseTreeType<'t>,
+        _embed_item_list: &ParseTreeType<'t>,
+        _r_brace_term: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_brace_term = pop_item!(self, r_brace_term, RBraceTerm, context);
+        let embed_item_list = pop_and_reverse_item!(self, embed_item_list, EmbedItemList, context);
+        let l_brace_term = pop_item!(self, l_brace_term, LBraceTerm, context);
+        let embed_item_0_built = EmbedItemLBraceTermEmbedItemListRBraceTerm {
+            l_brace_term: Box::new(l_brace_term),
+            embed_item_list,
+            r_brace_term: Box::new(r_brace_term),
+        };
+        let embed_item_0_built = EmbedItem::LBraceTermEmbedItemListRBraceTerm(embed_item_0_built);
+        // Calling user action here
+        self.user_grammar.embed_item(&embed_item_0_built)?;
+        self.push(ASTType::EmbedItem(embed_item_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 762:
+    ///
+    /// EmbedItemList /* `Vec<T>::Push` */: EmbedItem EmbedItemList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_item_list_0(
+        &mut self,
+        _embed_item: &ParseTreeType<'t>,
+        _embed_item_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut embed_item_list = pop_item!(self, embed_item_list, EmbedItemList, context);
+        let embed_item = pop_item!(self, embed_item, EmbedItem, context);
+        let embed_item_list_0_built = EmbedItemList {
+            embed_item: Box::new(embed_item),
+        };
+        // Add an element to the vector
+        embed_item_list.push(embed_item_list_0_built);
+        self.push(ASTType::EmbedItemList(embed_item_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 763:
+    ///
+    /// EmbedItemList /* `Vec<T>::New` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_item_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_item_list_1_built = Vec::new();
+        self.push(ASTType::EmbedItemList(embed_item_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 764:
+    ///
+    /// EmbedItem: AnyTerm;
+    ///
+    #[parol_runtime::function_name::named]
+    fn embed_item_1(&mut self, _any_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let any_term = pop_item!(self, any_term, AnyTerm, context);
+        let embed_item_1_built = EmbedItemAnyTerm {
+            any_term: Box::new(any_term),
+        };
+        let embed_item_1_built = EmbedItem::AnyTerm(embed_item_1_built);
+        // Calling user action here
+        self.user_grammar.embed_item(&embed_item_1_built)?;
+        self.push(ASTType::EmbedItem(embed_item_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 765:
+    ///
+    /// IncludeDeclaration: Include LParen Identifier Comma StringLiteral RParen Semicolon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn include_declaration(
+        &mut self,
+        _include: &ParseTreeType<'t>,
+        _l_paren: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _comma: &ParseTreeType<'t>,
+        _string_literal: &ParseTreeType<'t>,
+        _r_paren: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let string_literal = pop_item!(self, string_literal, StringLiteral, context);
+        let comma = pop_item!(self, comma, Comma, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let include = pop_item!(self, include, Include, context);
+        let include_declaration_built = IncludeDeclaration {
+            include: Box::new(include),
+            l_paren: Box::new(l_paren),
+            identifier: Box::new(identifier),
+            comma: Box::new(comma),
+            string_literal: Box::new(string_literal),
+            r_paren: Box::new(r_paren),
+            semicolon: Box::new(semicolon),
+        };
+        // Calling user action here
+        self.user_grammar
+            .include_declaration(&include_declaration_built)?;
+        self.push(
+            ASTType::IncludeDeclaration(include_declaration_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 766:
     ///
-    /// ModuleDeclaration: Module Identifier ModuleDeclarationOpt /* Option */ ModuleDeclarationOpt0 /* Option */ LBrace ModuleDeclarationList /* Vec */ RBrace;
+    /// ModuleDeclaration: Module Identifier ModuleDeclarationOpt /* Option */ ModuleDeclarationOpt0 /* Option */ ModuleDeclarationOpt1 /* Option */ LBrace ModuleDeclarationList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
     fn module_declaration(
@@ -26532,6 +29471,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         _identifier: &ParseTreeType<'t>,
         _module_declaration_opt: &ParseTreeType<'t>,
         _module_declaration_opt0: &ParseTreeType<'t>,
+        _module_declaration_opt1: &ParseTreeType<'t>,
         _l_brace: &ParseTreeType<'t>,
         _module_declaration_list: &ParseTreeType<'t>,
         _r_brace: &ParseTreeType<'t>,
@@ -26546,6 +29486,12 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             context
         );
         let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let module_declaration_opt1 = pop_item!(
+            self,
+            module_declaration_opt1,
+            ModuleDeclarationOpt1,
+            context
+        );
         let module_declaration_opt0 = pop_item!(
             self,
             module_declaration_opt0,
@@ -26561,6 +29507,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             identifier: Box::new(identifier),
             module_declaration_opt,
             module_declaration_opt0,
+            module_declaration_opt1,
             l_brace: Box::new(l_brace),
             module_declaration_list,
             r_brace: Box::new(r_brace),
@@ -26575,116 +29522,273 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 693:
+    /// Semantic action for production 767:
+    ///
+    /// ModuleDeclarationList /* `Vec<T>::Push` */: ModuleGroup ModuleDeclarationList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_declaration_list_0(
+        &mut self,
+        _module_group: &ParseTreeType<'t>,
+        _module_declaration_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut module_declaration_list = pop_item!(
+            self,
+            module_declaration_list,
+            ModuleDeclarationList,
+            context
+        );
+        let module_group = pop_item!(self, module_group, ModuleGroup, context);
+        let module_declaration_list_0_built = ModuleDeclarationList {
+            module_group: Box::new(module_group),
+        };
+        // Add an element to the vector
+        module_declaration_list.push(module_declaration_list_0_built);
+        self.push(
+            ASTType::ModuleDeclarationList(module_declaration_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 768:
+    ///
+    /// ModuleDeclarationList /* `Vec<T>::New` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_declaration_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let module_declaration_list_1_built = Vec::new();
+        self.push(
+            ASTType::ModuleDeclarationList(module_declaration_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 769:
+    ///
+    /// ModuleDeclarationOpt1 /* `Option<T>::Some` */: PortDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_declaration_opt1_0(&mut self, _port_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let port_declaration = pop_item!(self, port_declaration, PortDeclaration, context);
+        let module_declaration_opt1_0_built = ModuleDeclarationOpt1 {
+            port_declaration: Box::new(port_declaration),
+        };
+        self.push(
+            ASTType::ModuleDeclarationOpt1(Some(Box::new(module_declaration_opt1_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 770:
+    ///
+    /// ModuleDeclarationOpt1 /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_declaration_opt1_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ModuleDeclarationOpt1(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 771:
+    ///
+    /// ModuleDeclarationOpt0 /* `Option<T>::Some` */: WithParameter;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_declaration_opt0_0(&mut self, _with_parameter: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_parameter = pop_item!(self, with_parameter, WithParameter, context);
+        let module_declaration_opt0_0_built = ModuleDeclarationOpt0 {
+            with_parameter: Box::new(with_parameter),
+        };
+        self.push(
+            ASTType::ModuleDeclarationOpt0(Some(Box::new(module_declaration_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 772:
     ///
-    /// ModuleDeclarationList /* Vec<T>::Push */: ModuleGroup ModuleDeclarationList;
+    /// ModuleDeclarationOpt0 /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn module_declaration_list_0(
+    fn module_declaration_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ModuleDeclarationOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 773:
+    ///
+    /// ModuleDeclarationOpt /* `Option<T>::Some` */: WithGenericParameter;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_declaration_opt_0(
         &mut self,
-        _module_group: &ParseTreeType<'t>,
-        _module_declaration_list: &ParseTreeType<'t>,
+        _with_generic_parameter: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut module_declaration_list = pop_item!(
-            self,
-            module_declaration_list,
-            ModuleDeclarationList,
-            context
-        );
-        let module_group = pop_item!(self, module_group, ModuleGroup, context);
-        let module_declaration_list_0_built = ModuleDeclarationList {
-            module_group: Box::new(module_group),
+        let with_generic_parameter =
+            pop_item!(self, with_generic_parameter, WithGenericParameter, context);
+        let module_declaration_opt_0_built = ModuleDeclarationOpt {
+            with_generic_parameter: Box::new(with_generic_parameter),
         };
-        // Add an element to the vector
-        module_declaration_list.push(module_declaration_list_0_built);
         self.push(
-            ASTType::ModuleDeclarationList(module_declaration_list),
+            ASTType::ModuleDeclarationOpt(Some(Box::new(module_declaration_opt_0_built))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 694:
+    /// Semantic action for production 774:
     ///
-    /// ModuleDeclarationList /* Vec<T>::New */: ;
+    /// ModuleDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn module_declaration_list_1(&mut self) -> Result<()> {
+    fn module_declaration_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let module_declaration_list_1_built = Vec::new();
+        self.push(ASTType::ModuleDeclarationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 775:
+    ///
+    /// ExternModuleDeclaration: Extern Module Identifier ExternModuleDeclarationOpt /* Option */ ExternModuleDeclarationOpt0 /* Option */ Semicolon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn extern_module_declaration(
+        &mut self,
+        _extern: &ParseTreeType<'t>,
+        _module: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _extern_module_declaration_opt: &ParseTreeType<'t>,
+        _extern_module_declaration_opt0: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let extern_module_declaration_opt0 = pop_item!(
+            self,
+            extern_module_declaration_opt0,
+            ExternModuleDeclarationOpt0,
+            context
+        );
+        let extern_module_declaration_opt = pop_item!(
+            self,
+            extern_module_declaration_opt,
+            ExternModuleDeclarationOpt,
+            context
+        );
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let module = pop_item!(self, module, Module, context);
+        let r#extern = pop_item!(self, r#extern, Extern, context);
+        let extern_module_declaration_built = ExternModuleDeclaration {
+            r#extern: Box::new(r#extern),
+            module: Box::new(module),
+            identifier: Box::new(identifier),
+            extern_module_declaration_opt,
+            extern_module_declaration_opt0,
+            semicolon: Box::new(semicolon),
+        };
+        // Calling user action here
+        self.user_grammar
+            .extern_module_declaration(&extern_module_declaration_built)?;
         self.push(
-            ASTType::ModuleDeclarationList(module_declaration_list_1_built),
+            ASTType::ExternModuleDeclaration(extern_module_declaration_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 695:
+    /// Semantic action for production 776:
     ///
-    /// ModuleDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;
+    /// ExternModuleDeclarationOpt0 /* `Option<T>::Some` */: PortDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_declaration_opt0_0(&mut self, _port_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn extern_module_declaration_opt0_0(
+        &mut self,
+        _port_declaration: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let port_declaration = pop_item!(self, port_declaration, PortDeclaration, context);
-        let module_declaration_opt0_0_built = ModuleDeclarationOpt0 {
+        let extern_module_declaration_opt0_0_built = ExternModuleDeclarationOpt0 {
             port_declaration: Box::new(port_declaration),
         };
         self.push(
-            ASTType::ModuleDeclarationOpt0(Some(Box::new(module_declaration_opt0_0_built))),
+            ASTType::ExternModuleDeclarationOpt0(Some(Box::new(
+                extern_module_declaration_opt0_0_built,
+            ))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 696:
+    /// Semantic action for production 777:
     ///
-    /// ModuleDeclarationOpt0 /* Option<T>::None */: ;
+    /// ExternModuleDeclarationOpt0 /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn module_declaration_opt0_1(&mut self) -> Result<()> {
+    fn extern_module_declaration_opt0_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::ModuleDeclarationOpt0(None), context);
+        self.push(ASTType::ExternModuleDeclarationOpt0(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 697:
+    /// Semantic action for production 778:
     ///
-    /// ModuleDeclarationOpt /* Option<T>::Some */: WithParameter;
+    /// ExternModuleDeclarationOpt /* `Option<T>::Some` */: WithParameter;
     ///
     #[parol_runtime::function_name::named]
-    fn module_declaration_opt_0(&mut self, _with_parameter: &ParseTreeType<'t>) -> Result<()> {
+    fn extern_module_declaration_opt_0(
+        &mut self,
+        _with_parameter: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let with_parameter = pop_item!(self, with_parameter, WithParameter, context);
-        let module_declaration_opt_0_built = ModuleDeclarationOpt {
+        let extern_module_declaration_opt_0_built = ExternModuleDeclarationOpt {
             with_parameter: Box::new(with_parameter),
         };
         self.push(
-            ASTType::ModuleDeclarationOpt(Some(Box::new(module_declaration_opt_0_built))),
+            ASTType::ExternModuleDeclarationOpt(Some(Box::new(
+                extern_module_declaration_opt_0_built,
+            ))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 698:
+    /// Semantic action for production 779:
     ///
-    /// ModuleDeclarationOpt /* Option<T>::None */: ;
+    /// ExternModuleDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn module_declaration_opt_1(&mut self) -> Result<()> {
+    fn extern_module_declaration_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::ModuleDeclarationOpt(None), context);
+        self.push(ASTType::ExternModuleDeclarationOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 699:
+    /// Semantic action for production 780:
     ///
     /// ModuleIfDeclaration: If Expression ModuleNamedBlock ModuleIfDeclarationList /* Vec */ ModuleIfDeclarationOpt /* Option */;
     ///
@@ -26731,9 +29835,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 700:
+    /// Semantic action for production 781:
     ///
-    /// ModuleIfDeclarationList /* Vec<T>::Push */: Else If Expression ModuleOptionalNamedBlock ModuleIfDeclarationList;
+    /// ModuleIfDeclarationList /* `Vec<T>::Push` */: Else If Expression ModuleOptionalNamedBlock ModuleIfDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn module_if_declaration_list_0(
@@ -26776,9 +29880,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 701:
+    /// Semantic action for production 782:
     ///
-    /// ModuleIfDeclarationList /* Vec<T>::New */: ;
+    /// ModuleIfDeclarationList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn module_if_declaration_list_1(&mut self) -> Result<()> {
@@ -26792,9 +29896,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 702:
+    /// Semantic action for production 783:
     ///
-    /// ModuleIfDeclarationOpt /* Option<T>::Some */: Else ModuleOptionalNamedBlock;
+    /// ModuleIfDeclarationOpt /* `Option<T>::Some` */: Else ModuleOptionalNamedBlock;
     ///
     #[parol_runtime::function_name::named]
     fn module_if_declaration_opt_0(
@@ -26822,9 +29926,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 703:
+    /// Semantic action for production 784:
     ///
-    /// ModuleIfDeclarationOpt /* Option<T>::None */: ;
+    /// ModuleIfDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn module_if_declaration_opt_1(&mut self) -> Result<()> {
@@ -26834,7 +29938,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 704:
+    /// Semantic action for production 785:
     ///
     /// ModuleForDeclaration: For Identifier In Range ModuleForDeclarationOpt /* Option */ ModuleNamedBlock;
     ///
@@ -26879,9 +29983,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 705:
+    /// Semantic action for production 786:
     ///
-    /// ModuleForDeclarationOpt /* Option<T>::Some */: Step AssignmentOperator Expression;
+    /// ModuleForDeclarationOpt /* `Option<T>::Some` */: Step AssignmentOperator Expression;
     ///
     #[parol_runtime::function_name::named]
     fn module_for_declaration_opt_0(
@@ -26907,9 +30011,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 706:
+    /// Semantic action for production 787:
     ///
-    /// ModuleForDeclarationOpt /* Option<T>::None */: ;
+    /// ModuleForDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn module_for_declaration_opt_1(&mut self) -> Result<()> {
@@ -26919,7 +30023,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 707:
+    /// Semantic action for production 788:
     ///
     /// ModuleNamedBlock: Colon Identifier LBrace ModuleNamedBlockList /* Vec */ RBrace;
     ///
@@ -26954,9 +30058,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 708:
+    /// Semantic action for production 789:
     ///
-    /// ModuleNamedBlockList /* Vec<T>::Push */: ModuleGroup ModuleNamedBlockList;
+    /// ModuleNamedBlockList /* `Vec<T>::Push` */: ModuleGroup ModuleNamedBlockList;
     ///
     #[parol_runtime::function_name::named]
     fn module_named_block_list_0(
@@ -26981,9 +30085,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 709:
+    /// Semantic action for production 790:
     ///
-    /// ModuleNamedBlockList /* Vec<T>::New */: ;
+    /// ModuleNamedBlockList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn module_named_block_list_1(&mut self) -> Result<()> {
@@ -26997,7 +30101,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 710:
+    /// Semantic action for production 791:
     ///
     /// ModuleOptionalNamedBlock: ModuleOptionalNamedBlockOpt /* Option */ LBrace ModuleOptionalNamedBlockList /* Vec */ RBrace;
     ///
@@ -27041,9 +30145,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 711:
+    /// Semantic action for production 792:
     ///
-    /// ModuleOptionalNamedBlockList /* Vec<T>::Push */: ModuleGroup ModuleOptionalNamedBlockList;
+    /// ModuleOptionalNamedBlockList /* `Vec<T>::Push` */: ModuleGroup ModuleOptionalNamedBlockList;
     ///
     #[parol_runtime::function_name::named]
     fn module_optional_named_block_list_0(
@@ -27072,9 +30176,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 712:
+    /// Semantic action for production 793:
     ///
-    /// ModuleOptionalNamedBlockList /* Vec<T>::New */: ;
+    /// ModuleOptionalNamedBlockList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn module_optional_named_block_list_1(&mut self) -> Result<()> {
@@ -27088,9 +30192,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 713:
+    /// Semantic action for production 794:
     ///
-    /// ModuleOptionalNamedBlockOpt /* Option<T>::Some */: Colon Identifier;
+    /// ModuleOptionalNamedBlockOpt /* `Option<T>::Some` */: Colon Identifier;
     ///
     #[parol_runtime::function_name::named]
     fn module_optional_named_block_opt_0(
@@ -27115,9 +30219,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 714:
+    /// Semantic action for production 795:
     ///
-    /// ModuleOptionalNamedBlockOpt /* Option<T>::None */: ;
+    /// ModuleOptionalNamedBlockOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn module_optional_named_block_opt_1(&mut self) -> Result<()> {
@@ -27127,7 +30231,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 715:
+    /// Semantic action for production 796:
     ///
     /// ModuleGroup: ModuleGroupList /* Vec */ ModuleGroupGroup;
     ///
@@ -27152,7 +30256,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 716:
+    /// Semantic action for production 797:
     ///
     /// ModuleGroupGroup: LBrace ModuleGroupGroupList /* Vec */ RBrace;
     ///
@@ -27183,9 +30287,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 717:
+    /// Semantic action for production 798:
     ///
-    /// ModuleGroupGroupList /* Vec<T>::Push */: ModuleGroup ModuleGroupGroupList;
+    /// ModuleGroupGroupList /* `Vec<T>::Push` */: ModuleGroup ModuleGroupGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn module_group_group_list_0(
@@ -27210,9 +30314,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 718:
+    /// Semantic action for production 799:
     ///
-    /// ModuleGroupGroupList /* Vec<T>::New */: ;
+    /// ModuleGroupGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn module_group_group_list_1(&mut self) -> Result<()> {
@@ -27226,7 +30330,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 719:
+    /// Semantic action for production 800:
     ///
     /// ModuleGroupGroup: ModuleItem;
     ///
@@ -27246,9 +30350,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 720:
+    /// Semantic action for production 801:
     ///
-    /// ModuleGroupList /* Vec<T>::Push */: Attribute ModuleGroupList;
+    /// ModuleGroupList /* `Vec<T>::Push` */: Attribute ModuleGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn module_group_list_0(
@@ -27269,9 +30373,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 721:
+    /// Semantic action for production 802:
     ///
-    /// ModuleGroupList /* Vec<T>::New */: ;
+    /// ModuleGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn module_group_list_1(&mut self) -> Result<()> {
@@ -27282,7 +30386,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 722:
+    /// Semantic action for production 803:
     ///
     /// ModuleItem: VarDeclaration;
     ///
@@ -27301,7 +30405,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 723:
+    /// Semantic action for production 804:
     ///
     /// ModuleItem: InstDeclaration;
     ///
@@ -27320,7 +30424,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 724:
+    /// Semantic action for production 805:
     ///
     /// ModuleItem: LocalparamDeclaration;
     ///
@@ -27340,7 +30444,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 725:
+    /// Semantic action for production 806:
     ///
     /// ModuleItem: AlwaysFfDeclaration;
     ///
@@ -27360,7 +30464,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 726:
+    /// Semantic action for production 807:
     ///
     /// ModuleItem: AlwaysCombDeclaration;
     ///
@@ -27384,7 +30488,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 727:
+    /// Semantic action for production 808:
     ///
     /// ModuleItem: AssignDeclaration;
     ///
@@ -27403,7 +30507,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 728:
+    /// Semantic action for production 809:
     ///
     /// ModuleItem: FunctionDeclaration;
     ///
@@ -27423,7 +30527,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 729:
+    /// Semantic action for production 810:
     ///
     /// ModuleItem: ModuleIfDeclaration;
     ///
@@ -27443,7 +30547,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 730:
+    /// Semantic action for production 811:
     ///
     /// ModuleItem: ModuleForDeclaration;
     ///
@@ -27463,7 +30567,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 731:
+    /// Semantic action for production 812:
     ///
     /// ModuleItem: EnumDeclaration;
     ///
@@ -27482,7 +30586,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 732:
+    /// Semantic action for production 813:
     ///
     /// ModuleItem: StructDeclaration;
     ///
@@ -27501,85 +30605,142 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 733:
+    /// Semantic action for production 814:
+    ///
+    /// ModuleItem: UnionDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_item_11(&mut self, _union_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let union_declaration = pop_item!(self, union_declaration, UnionDeclaration, context);
+        let module_item_11_built = ModuleItemUnionDeclaration {
+            union_declaration: Box::new(union_declaration),
+        };
+        let module_item_11_built = ModuleItem::UnionDeclaration(module_item_11_built);
+        // Calling user action here
+        self.user_grammar.module_item(&module_item_11_built)?;
+        self.push(ASTType::ModuleItem(module_item_11_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 815:
     ///
     /// ModuleItem: ModuleNamedBlock;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_11(&mut self, _module_named_block: &ParseTreeType<'t>) -> Result<()> {
+    fn module_item_12(&mut self, _module_named_block: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let module_named_block = pop_item!(self, module_named_block, ModuleNamedBlock, context);
-        let module_item_11_built = ModuleItemModuleNamedBlock {
+        let module_item_12_built = ModuleItemModuleNamedBlock {
             module_named_block: Box::new(module_named_block),
         };
-        let module_item_11_built = ModuleItem::ModuleNamedBlock(module_item_11_built);
+        let module_item_12_built = ModuleItem::ModuleNamedBlock(module_item_12_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_11_built)?;
-        self.push(ASTType::ModuleItem(module_item_11_built), context);
+        self.user_grammar.module_item(&module_item_12_built)?;
+        self.push(ASTType::ModuleItem(module_item_12_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 734:
+    /// Semantic action for production 816:
     ///
     /// ModuleItem: ImportDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_12(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn module_item_13(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
-        let module_item_12_built = ModuleItemImportDeclaration {
+        let module_item_13_built = ModuleItemImportDeclaration {
             import_declaration: Box::new(import_declaration),
         };
-        let module_item_12_built = ModuleItem::ImportDeclaration(module_item_12_built);
+        let module_item_13_built = ModuleItem::ImportDeclaration(module_item_13_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_12_built)?;
-        self.push(ASTType::ModuleItem(module_item_12_built), context);
+        self.user_grammar.module_item(&module_item_13_built)?;
+        self.push(ASTType::ModuleItem(module_item_13_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 735:
+    /// Semantic action for production 817:
     ///
     /// ModuleItem: InitialDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_13(&mut self, _initial_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn module_item_14(&mut self, _initial_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let initial_declaration = pop_item!(self, initial_declaration, InitialDeclaration, context);
-        let module_item_13_built = ModuleItemInitialDeclaration {
+        let module_item_14_built = ModuleItemInitialDeclaration {
             initial_declaration: Box::new(initial_declaration),
         };
-        let module_item_13_built = ModuleItem::InitialDeclaration(module_item_13_built);
+        let module_item_14_built = ModuleItem::InitialDeclaration(module_item_14_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_13_built)?;
-        self.push(ASTType::ModuleItem(module_item_13_built), context);
+        self.user_grammar.module_item(&module_item_14_built)?;
+        self.push(ASTType::ModuleItem(module_item_14_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 736:
+    /// Semantic action for production 818:
     ///
     /// ModuleItem: FinalDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_14(&mut self, _final_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn module_item_15(&mut self, _final_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let final_declaration = pop_item!(self, final_declaration, FinalDeclaration, context);
-        let module_item_14_built = ModuleItemFinalDeclaration {
+        let module_item_15_built = ModuleItemFinalDeclaration {
             final_declaration: Box::new(final_declaration),
         };
-        let module_item_14_built = ModuleItem::FinalDeclaration(module_item_14_built);
+        let module_item_15_built = ModuleItem::FinalDeclaration(module_item_15_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_14_built)?;
-        self.push(ASTType::ModuleItem(module_item_14_built), context);
+        self.user_grammar.module_item(&module_item_15_built)?;
+        self.push(ASTType::ModuleItem(module_item_15_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 737:
+    /// Semantic action for production 819:
+    ///
+    /// ModuleItem: EmbedDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_item_16(&mut self, _embed_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_declaration = pop_item!(self, embed_declaration, EmbedDeclaration, context);
+        let module_item_16_built = ModuleItemEmbedDeclaration {
+            embed_declaration: Box::new(embed_declaration),
+        };
+        let module_item_16_built = ModuleItem::EmbedDeclaration(module_item_16_built);
+        // Calling user action here
+        self.user_grammar.module_item(&module_item_16_built)?;
+        self.push(ASTType::ModuleItem(module_item_16_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 820:
+    ///
+    /// ModuleItem: IncludeDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_item_17(&mut self, _include_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let include_declaration = pop_item!(self, include_declaration, IncludeDeclaration, context);
+        let module_item_17_built = ModuleItemIncludeDeclaration {
+            include_declaration: Box::new(include_declaration),
+        };
+        let module_item_17_built = ModuleItem::IncludeDeclaration(module_item_17_built);
+        // Calling user action here
+        self.user_grammar.module_item(&module_item_17_built)?;
+        self.push(ASTType::ModuleItem(module_item_17_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 821:
     ///
-    /// InterfaceDeclaration: Interface Identifier InterfaceDeclarationOpt /* Option */ LBrace InterfaceDeclarationList /* Vec */ RBrace;
+    /// InterfaceDeclaration: Interface Identifier InterfaceDeclarationOpt /* Option */ InterfaceDeclarationOpt0 /* Option */ LBrace InterfaceDeclarationList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
     fn interface_declaration(
@@ -27587,6 +30748,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         _interface: &ParseTreeType<'t>,
         _identifier: &ParseTreeType<'t>,
         _interface_declaration_opt: &ParseTreeType<'t>,
+        _interface_declaration_opt0: &ParseTreeType<'t>,
         _l_brace: &ParseTreeType<'t>,
         _interface_declaration_list: &ParseTreeType<'t>,
         _r_brace: &ParseTreeType<'t>,
@@ -27601,6 +30763,12 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             context
         );
         let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let interface_declaration_opt0 = pop_item!(
+            self,
+            interface_declaration_opt0,
+            InterfaceDeclarationOpt0,
+            context
+        );
         let interface_declaration_opt = pop_item!(
             self,
             interface_declaration_opt,
@@ -27613,6 +30781,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             interface: Box::new(interface),
             identifier: Box::new(identifier),
             interface_declaration_opt,
+            interface_declaration_opt0,
             l_brace: Box::new(l_brace),
             interface_declaration_list,
             r_brace: Box::new(r_brace),
@@ -27627,9 +30796,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 738:
+    /// Semantic action for production 822:
     ///
-    /// InterfaceDeclarationList /* Vec<T>::Push */: InterfaceGroup InterfaceDeclarationList;
+    /// InterfaceDeclarationList /* `Vec<T>::Push` */: InterfaceGroup InterfaceDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn interface_declaration_list_0(
@@ -27658,9 +30827,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 739:
+    /// Semantic action for production 823:
     ///
-    /// InterfaceDeclarationList /* Vec<T>::New */: ;
+    /// InterfaceDeclarationList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_declaration_list_1(&mut self) -> Result<()> {
@@ -27674,18 +30843,53 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 740:
+    /// Semantic action for production 824:
     ///
-    /// InterfaceDeclarationOpt /* Option<T>::Some */: WithParameter;
+    /// InterfaceDeclarationOpt0 /* `Option<T>::Some` */: WithParameter;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_declaration_opt_0(&mut self, _with_parameter: &ParseTreeType<'t>) -> Result<()> {
+    fn interface_declaration_opt0_0(&mut self, _with_parameter: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let with_parameter = pop_item!(self, with_parameter, WithParameter, context);
-        let interface_declaration_opt_0_built = InterfaceDeclarationOpt {
+        let interface_declaration_opt0_0_built = InterfaceDeclarationOpt0 {
             with_parameter: Box::new(with_parameter),
         };
+        self.push(
+            ASTType::InterfaceDeclarationOpt0(Some(Box::new(interface_declaration_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 825:
+    ///
+    /// InterfaceDeclarationOpt0 /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_declaration_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::InterfaceDeclarationOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 826:
+    ///
+    /// InterfaceDeclarationOpt /* `Option<T>::Some` */: WithGenericParameter;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_declaration_opt_0(
+        &mut self,
+        _with_generic_parameter: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_generic_parameter =
+            pop_item!(self, with_generic_parameter, WithGenericParameter, context);
+        let interface_declaration_opt_0_built = InterfaceDeclarationOpt {
+            with_generic_parameter: Box::new(with_generic_parameter),
+        };
         self.push(
             ASTType::InterfaceDeclarationOpt(Some(Box::new(interface_declaration_opt_0_built))),
             context,
@@ -27693,9 +30897,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 741:
+    /// Semantic action for production 827:
     ///
-    /// InterfaceDeclarationOpt /* Option<T>::None */: ;
+    /// InterfaceDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_declaration_opt_1(&mut self) -> Result<()> {
@@ -27705,7 +30909,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 742:
+    /// Semantic action for production 828:
     ///
     /// InterfaceIfDeclaration: If Expression InterfaceNamedBlock InterfaceIfDeclarationList /* Vec */ InterfaceIfDeclarationOpt /* Option */;
     ///
@@ -27753,9 +30957,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 743:
+    /// Semantic action for production 829:
     ///
-    /// InterfaceIfDeclarationList /* Vec<T>::Push */: Else If Expression InterfaceOptionalNamedBlock InterfaceIfDeclarationList;
+    /// InterfaceIfDeclarationList /* `Vec<T>::Push` */: Else If Expression InterfaceOptionalNamedBlock InterfaceIfDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn interface_if_declaration_list_0(
@@ -27798,9 +31002,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 744:
+    /// Semantic action for production 830:
     ///
-    /// InterfaceIfDeclarationList /* Vec<T>::New */: ;
+    /// InterfaceIfDeclarationList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_if_declaration_list_1(&mut self) -> Result<()> {
@@ -27814,9 +31018,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 745:
+    /// Semantic action for production 831:
     ///
-    /// InterfaceIfDeclarationOpt /* Option<T>::Some */: Else InterfaceOptionalNamedBlock;
+    /// InterfaceIfDeclarationOpt /* `Option<T>::Some` */: Else InterfaceOptionalNamedBlock;
     ///
     #[parol_runtime::function_name::named]
     fn interface_if_declaration_opt_0(
@@ -27846,9 +31050,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 746:
+    /// Semantic action for production 832:
     ///
-    /// InterfaceIfDeclarationOpt /* Option<T>::None */: ;
+    /// InterfaceIfDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_if_declaration_opt_1(&mut self) -> Result<()> {
@@ -27858,7 +31062,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 747:
+    /// Semantic action for production 833:
     ///
     /// InterfaceForDeclaration: For Identifier In Range InterfaceForDeclarationOpt /* Option */ InterfaceNamedBlock;
     ///
@@ -27904,9 +31108,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 748:
+    /// Semantic action for production 834:
     ///
-    /// InterfaceForDeclarationOpt /* Option<T>::Some */: Step AssignmentOperator Expression;
+    /// InterfaceForDeclarationOpt /* `Option<T>::Some` */: Step AssignmentOperator Expression;
     ///
     #[parol_runtime::function_name::named]
     fn interface_for_declaration_opt_0(
@@ -27934,9 +31138,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 749:
+    /// Semantic action for production 835:
     ///
-    /// InterfaceForDeclarationOpt /* Option<T>::None */: ;
+    /// InterfaceForDeclarationOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_for_declaration_opt_1(&mut self) -> Result<()> {
@@ -27946,7 +31150,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 750:
+    /// Semantic action for production 836:
     ///
     /// InterfaceNamedBlock: Colon Identifier LBrace InterfaceNamedBlockList /* Vec */ RBrace;
     ///
@@ -27988,9 +31192,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 751:
+    /// Semantic action for production 837:
     ///
-    /// InterfaceNamedBlockList /* Vec<T>::Push */: InterfaceGroup InterfaceNamedBlockList;
+    /// InterfaceNamedBlockList /* `Vec<T>::Push` */: InterfaceGroup InterfaceNamedBlockList;
     ///
     #[parol_runtime::function_name::named]
     fn interface_named_block_list_0(
@@ -28019,9 +31223,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 752:
+    /// Semantic action for production 838:
     ///
-    /// InterfaceNamedBlockList /* Vec<T>::New */: ;
+    /// InterfaceNamedBlockList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_named_block_list_1(&mut self) -> Result<()> {
@@ -28035,7 +31239,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 753:
+    /// Semantic action for production 839:
     ///
     /// InterfaceOptionalNamedBlock: InterfaceOptionalNamedBlockOpt /* Option */ LBrace InterfaceOptionalNamedBlockList /* Vec */ RBrace;
     ///
@@ -28079,9 +31283,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 754:
+    /// Semantic action for production 840:
     ///
-    /// InterfaceOptionalNamedBlockList /* Vec<T>::Push */: InterfaceGroup InterfaceOptionalNamedBlockList;
+    /// InterfaceOptionalNamedBlockList /* `Vec<T>::Push` */: InterfaceGroup InterfaceOptionalNamedBlockList;
     ///
     #[parol_runtime::function_name::named]
     fn interface_optional_named_block_list_0(
@@ -28110,9 +31314,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 755:
+    /// Semantic action for production 841:
     ///
-    /// InterfaceOptionalNamedBlockList /* Vec<T>::New */: ;
+    /// InterfaceOptionalNamedBlockList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_optional_named_block_list_1(&mut self) -> Result<()> {
@@ -28126,9 +31330,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 756:
+    /// Semantic action for production 842:
     ///
-    /// InterfaceOptionalNamedBlockOpt /* Option<T>::Some */: Colon Identifier;
+    /// InterfaceOptionalNamedBlockOpt /* `Option<T>::Some` */: Colon Identifier;
     ///
     #[parol_runtime::function_name::named]
     fn interface_optional_named_block_opt_0(
@@ -28153,9 +31357,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 757:
+    /// Semantic action for production 843:
     ///
-    /// InterfaceOptionalNamedBlockOpt /* Option<T>::None */: ;
+    /// InterfaceOptionalNamedBlockOpt /* `Option<T>::None` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_optional_named_block_opt_1(&mut self) -> Result<()> {
@@ -28165,7 +31369,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 758:
+    /// Semantic action for production 844:
     ///
     /// InterfaceGroup: InterfaceGroupList /* Vec */ InterfaceGroupGroup;
     ///
@@ -28191,7 +31395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 759:
+    /// Semantic action for production 845:
     ///
     /// InterfaceGroupGroup: LBrace InterfaceGroupGroupList /* Vec */ RBrace;
     ///
@@ -28227,9 +31431,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 760:
+    /// Semantic action for production 846:
     ///
-    /// InterfaceGroupGroupList /* Vec<T>::Push */: InterfaceGroup InterfaceGroupGroupList;
+    /// InterfaceGroupGroupList /* `Vec<T>::Push` */: InterfaceGroup InterfaceGroupGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn interface_group_group_list_0(
@@ -28258,9 +31462,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 761:
+    /// Semantic action for production 847:
     ///
-    /// InterfaceGroupGroupList /* Vec<T>::New */: ;
+    /// InterfaceGroupGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_group_group_list_1(&mut self) -> Result<()> {
@@ -28274,7 +31478,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 762:
+    /// Semantic action for production 848:
     ///
     /// InterfaceGroupGroup: InterfaceItem;
     ///
@@ -28295,9 +31499,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 763:
+    /// Semantic action for production 849:
     ///
-    /// InterfaceGroupList /* Vec<T>::Push */: Attribute InterfaceGroupList;
+    /// InterfaceGroupList /* `Vec<T>::Push` */: Attribute InterfaceGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn interface_group_list_0(
@@ -28319,9 +31523,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 764:
+    /// Semantic action for production 850:
     ///
-    /// InterfaceGroupList /* Vec<T>::New */: ;
+    /// InterfaceGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn interface_group_list_1(&mut self) -> Result<()> {
@@ -28335,7 +31539,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 765:
+    /// Semantic action for production 851:
     ///
     /// InterfaceItem: VarDeclaration;
     ///
@@ -28354,7 +31558,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 766:
+    /// Semantic action for production 852:
     ///
     /// InterfaceItem: LocalparamDeclaration;
     ///
@@ -28374,7 +31578,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 767:
+    /// Semantic action for production 853:
     ///
     /// InterfaceItem: ModportDeclaration;
     ///
@@ -28393,7 +31597,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 768:
+    /// Semantic action for production 854:
     ///
     /// InterfaceItem: InterfaceIfDeclaration;
     ///
@@ -28417,7 +31621,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 769:
+    /// Semantic action for production 855:
     ///
     /// InterfaceItem: InterfaceForDeclaration;
     ///
@@ -28441,7 +31645,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 770:
+    /// Semantic action for production 856:
     ///
     /// InterfaceItem: EnumDeclaration;
     ///
@@ -28460,7 +31664,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 771:
+    /// Semantic action for production 857:
     ///
     /// InterfaceItem: StructDeclaration;
     ///
@@ -28479,112 +31683,170 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 772:
+    /// Semantic action for production 858:
+    ///
+    /// InterfaceItem: UnionDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_7(&mut self, _union_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let union_declaration = pop_item!(self, union_declaration, UnionDeclaration, context);
+        let interface_item_7_built = InterfaceItemUnionDeclaration {
+            union_declaration: Box::new(union_declaration),
+        };
+        let interface_item_7_built = InterfaceItem::UnionDeclaration(interface_item_7_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_7_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 859:
     ///
     /// InterfaceItem: InterfaceNamedBlock;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_7(&mut self, _interface_named_block: &ParseTreeType<'t>) -> Result<()> {
+    fn interface_item_8(&mut self, _interface_named_block: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let interface_named_block =
             pop_item!(self, interface_named_block, InterfaceNamedBlock, context);
-        let interface_item_7_built = InterfaceItemInterfaceNamedBlock {
+        let interface_item_8_built = InterfaceItemInterfaceNamedBlock {
             interface_named_block: Box::new(interface_named_block),
         };
-        let interface_item_7_built = InterfaceItem::InterfaceNamedBlock(interface_item_7_built);
+        let interface_item_8_built = InterfaceItem::InterfaceNamedBlock(interface_item_8_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_7_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_7_built), context);
+        self.user_grammar.interface_item(&interface_item_8_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_8_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 773:
+    /// Semantic action for production 860:
     ///
     /// InterfaceItem: FunctionDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_8(&mut self, _function_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn interface_item_9(&mut self, _function_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let function_declaration =
             pop_item!(self, function_declaration, FunctionDeclaration, context);
-        let interface_item_8_built = InterfaceItemFunctionDeclaration {
+        let interface_item_9_built = InterfaceItemFunctionDeclaration {
             function_declaration: Box::new(function_declaration),
         };
-        let interface_item_8_built = InterfaceItem::FunctionDeclaration(interface_item_8_built);
+        let interface_item_9_built = InterfaceItem::FunctionDeclaration(interface_item_9_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_8_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_8_built), context);
+        self.user_grammar.interface_item(&interface_item_9_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_9_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 774:
+    /// Semantic action for production 861:
     ///
     /// InterfaceItem: ImportDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_9(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn interface_item_10(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
-        let interface_item_9_built = InterfaceItemImportDeclaration {
+        let interface_item_10_built = InterfaceItemImportDeclaration {
             import_declaration: Box::new(import_declaration),
         };
-        let interface_item_9_built = InterfaceItem::ImportDeclaration(interface_item_9_built);
+        let interface_item_10_built = InterfaceItem::ImportDeclaration(interface_item_10_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_9_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_9_built), context);
+        self.user_grammar.interface_item(&interface_item_10_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_10_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 775:
+    /// Semantic action for production 862:
     ///
     /// InterfaceItem: InitialDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_10(&mut self, _initial_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn interface_item_11(&mut self, _initial_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let initial_declaration = pop_item!(self, initial_declaration, InitialDeclaration, context);
-        let interface_item_10_built = InterfaceItemInitialDeclaration {
+        let interface_item_11_built = InterfaceItemInitialDeclaration {
             initial_declaration: Box::new(initial_declaration),
         };
-        let interface_item_10_built = InterfaceItem::InitialDeclaration(interface_item_10_built);
+        let interface_item_11_built = InterfaceItem::InitialDeclaration(interface_item_11_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_10_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_10_built), context);
+        self.user_grammar.interface_item(&interface_item_11_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_11_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 776:
+    /// Semantic action for production 863:
     ///
     /// InterfaceItem: FinalDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_11(&mut self, _final_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn interface_item_12(&mut self, _final_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let final_declaration = pop_item!(self, final_declaration, FinalDeclaration, context);
-        let interface_item_11_built = InterfaceItemFinalDeclaration {
+        let interface_item_12_built = InterfaceItemFinalDeclaration {
             final_declaration: Box::new(final_declaration),
         };
-        let interface_item_11_built = InterfaceItem::FinalDeclaration(interface_item_11_built);
+        let interface_item_12_built = InterfaceItem::FinalDeclaration(interface_item_12_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_11_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_11_built), context);
+        self.user_grammar.interface_item(&interface_item_12_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_12_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 777:
+    /// Semantic action for production 864:
+    ///
+    /// InterfaceItem: EmbedDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_13(&mut self, _embed_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_declaration = pop_item!(self, embed_declaration, EmbedDeclaration, context);
+        let interface_item_13_built = InterfaceItemEmbedDeclaration {
+            embed_declaration: Box::new(embed_declaration),
+        };
+        let interface_item_13_built = InterfaceItem::EmbedDeclaration(interface_item_13_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_13_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_13_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 865:
+    ///
+    /// InterfaceItem: IncludeDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_14(&mut self, _include_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let include_declaration = pop_item!(self, include_declaration, IncludeDeclaration, context);
+        let interface_item_14_built = InterfaceItemIncludeDeclaration {
+            include_declaration: Box::new(include_declaration),
+        };
+        let interface_item_14_built = InterfaceItem::IncludeDeclaration(interface_item_14_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_14_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_14_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 866:
     ///
-    /// PackageDeclaration: Package Identifier LBrace PackageDeclarationList /* Vec */ RBrace;
+    /// PackageDeclaration: Package Identifier PackageDeclarationOpt /* Option */ LBrace PackageDeclarationList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
     fn package_declaration(
         &mut self,
         _package: &ParseTreeType<'t>,
         _identifier: &ParseTreeType<'t>,
+        _package_declaration_opt: &ParseTreeType<'t>,
         _l_brace: &ParseTreeType<'t>,
         _package_declaration_list: &ParseTreeType<'t>,
         _r_brace: &ParseTreeType<'t>,
@@ -28599,11 +31861,18 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             context
         );
         let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let package_declaration_opt = pop_item!(
+            self,
+            package_declaration_opt,
+            PackageDeclarationOpt,
+            context
+        );
         let identifier = pop_item!(self, identifier, Identifier, context);
         let package = pop_item!(self, package, Package, context);
         let package_declaration_built = PackageDeclaration {
             package: Box::new(package),
             identifier: Box::new(identifier),
+            package_declaration_opt,
             l_brace: Box::new(l_brace),
             package_declaration_list,
             r_brace: Box::new(r_brace),
@@ -28618,9 +31887,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 778:
+    /// Semantic action for production 867:
     ///
-    /// PackageDeclarationList /* Vec<T>::Push */: PackageGroup PackageDeclarationList;
+    /// PackageDeclarationList /* `Vec<T>::Push` */: PackageGroup PackageDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn package_declaration_list_0(
@@ -28649,9 +31918,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 779:
+    /// Semantic action for production 868:
     ///
-    /// PackageDeclarationList /* Vec<T>::New */: ;
+    /// PackageDeclarationList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn package_declaration_list_1(&mut self) -> Result<()> {
@@ -28665,7 +31934,42 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 780:
+    /// Semantic action for production 869:
+    ///
+    /// PackageDeclarationOpt /* `Option<T>::Some` */: WithGenericParameter;
+    ///
+    #[parol_runtime::function_name::named]
+    fn package_declaration_opt_0(
+        &mut self,
+        _with_generic_parameter: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let with_generic_parameter =
+            pop_item!(self, with_generic_parameter, WithGenericParameter, context);
+        let package_declaration_opt_0_built = PackageDeclarationOpt {
+            with_generic_parameter: Box::new(with_generic_parameter),
+        };
+        self.push(
+            ASTType::PackageDeclarationOpt(Some(Box::new(package_declaration_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 870:
+    ///
+    /// PackageDeclarationOpt /* `Option<T>::None` */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn package_declaration_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::PackageDeclarationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 871:
     ///
     /// PackageGroup: PackageGroupList /* Vec */ PackageGroupGroup;
     ///
@@ -28690,7 +31994,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 781:
+    /// Semantic action for production 872:
     ///
     /// PackageGroupGroup: LBrace PackageGroupGroupList /* Vec */ RBrace;
     ///
@@ -28725,9 +32029,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 782:
+    /// Semantic action for production 873:
     ///
-    /// PackageGroupGroupList /* Vec<T>::Push */: PackageGroup PackageGroupGroupList;
+    /// PackageGroupGroupList /* `Vec<T>::Push` */: PackageGroup PackageGroupGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn package_group_group_list_0(
@@ -28756,9 +32060,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 783:
+    /// Semantic action for production 874:
     ///
-    /// PackageGroupGroupList /* Vec<T>::New */: ;
+    /// PackageGroupGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn package_group_group_list_1(&mut self) -> Result<()> {
@@ -28772,7 +32076,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 784:
+    /// Semantic action for production 875:
     ///
     /// PackageGroupGroup: PackageItem;
     ///
@@ -28793,9 +32097,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 785:
+    /// Semantic action for production 876:
     ///
-    /// PackageGroupList /* Vec<T>::Push */: Attribute PackageGroupList;
+    /// PackageGroupList /* `Vec<T>::Push` */: Attribute PackageGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn package_group_list_0(
@@ -28816,9 +32120,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 786:
+    /// Semantic action for production 877:
     ///
-    /// PackageGroupList /* Vec<T>::New */: ;
+    /// PackageGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn package_group_list_1(&mut self) -> Result<()> {
@@ -28832,7 +32136,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 787:
+    /// Semantic action for production 878:
     ///
     /// PackageItem: VarDeclaration;
     ///
@@ -28851,7 +32155,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 788:
+    /// Semantic action for production 879:
     ///
     /// PackageItem: LocalparamDeclaration;
     ///
@@ -28871,7 +32175,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 789:
+    /// Semantic action for production 880:
     ///
     /// PackageItem: EnumDeclaration;
     ///
@@ -28890,7 +32194,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 790:
+    /// Semantic action for production 881:
     ///
     /// PackageItem: StructDeclaration;
     ///
@@ -28909,103 +32213,160 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 791:
+    /// Semantic action for production 882:
+    ///
+    /// PackageItem: UnionDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn package_item_4(&mut self, _union_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let union_declaration = pop_item!(self, union_declaration, UnionDeclaration, context);
+        let package_item_4_built = PackageItemUnionDeclaration {
+            union_declaration: Box::new(union_declaration),
+        };
+        let package_item_4_built = PackageItem::UnionDeclaration(package_item_4_built);
+        // Calling user action here
+        self.user_grammar.package_item(&package_item_4_built)?;
+        self.push(ASTType::PackageItem(package_item_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 883:
     ///
     /// PackageItem: FunctionDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_4(&mut self, _function_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn package_item_5(&mut self, _function_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let function_declaration =
             pop_item!(self, function_declaration, FunctionDeclaration, context);
-        let package_item_4_built = PackageItemFunctionDeclaration {
+        let package_item_5_built = PackageItemFunctionDeclaration {
             function_declaration: Box::new(function_declaration),
         };
-        let package_item_4_built = PackageItem::FunctionDeclaration(package_item_4_built);
+        let package_item_5_built = PackageItem::FunctionDeclaration(package_item_5_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_4_built)?;
-        self.push(ASTType::PackageItem(package_item_4_built), context);
+        self.user_grammar.package_item(&package_item_5_built)?;
+        self.push(ASTType::PackageItem(package_item_5_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 792:
+    /// Semantic action for production 884:
     ///
     /// PackageItem: ImportDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_5(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn package_item_6(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
-        let package_item_5_built = PackageItemImportDeclaration {
+        let package_item_6_built = PackageItemImportDeclaration {
             import_declaration: Box::new(import_declaration),
         };
-        let package_item_5_built = PackageItem::ImportDeclaration(package_item_5_built);
+        let package_item_6_built = PackageItem::ImportDeclaration(package_item_6_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_5_built)?;
-        self.push(ASTType::PackageItem(package_item_5_built), context);
+        self.user_grammar.package_item(&package_item_6_built)?;
+        self.push(ASTType::PackageItem(package_item_6_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 793:
+    /// Semantic action for production 885:
     ///
     /// PackageItem: ExportDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_6(&mut self, _export_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn package_item_7(&mut self, _export_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let export_declaration = pop_item!(self, export_declaration, ExportDeclaration, context);
-        let package_item_6_built = PackageItemExportDeclaration {
+        let package_item_7_built = PackageItemExportDeclaration {
             export_declaration: Box::new(export_declaration),
         };
-        let package_item_6_built = PackageItem::ExportDeclaration(package_item_6_built);
+        let package_item_7_built = PackageItem::ExportDeclaration(package_item_7_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_6_built)?;
-        self.push(ASTType::PackageItem(package_item_6_built), context);
+        self.user_grammar.package_item(&package_item_7_built)?;
+        self.push(ASTType::PackageItem(package_item_7_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 794:
+    /// Semantic action for production 886:
     ///
     /// PackageItem: InitialDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_7(&mut self, _initial_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn package_item_8(&mut self, _initial_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let initial_declaration = pop_item!(self, initial_declaration, InitialDeclaration, context);
-        let package_item_7_built = PackageItemInitialDeclaration {
+        let package_item_8_built = PackageItemInitialDeclaration {
             initial_declaration: Box::new(initial_declaration),
         };
-        let package_item_7_built = PackageItem::InitialDeclaration(package_item_7_built);
+        let package_item_8_built = PackageItem::InitialDeclaration(package_item_8_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_7_built)?;
-        self.push(ASTType::PackageItem(package_item_7_built), context);
+        self.user_grammar.package_item(&package_item_8_built)?;
+        self.push(ASTType::PackageItem(package_item_8_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 795:
+    /// Semantic action for production 887:
     ///
     /// PackageItem: FinalDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_8(&mut self, _final_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn package_item_9(&mut self, _final_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let final_declaration = pop_item!(self, final_declaration, FinalDeclaration, context);
-        let package_item_8_built = PackageItemFinalDeclaration {
+        let package_item_9_built = PackageItemFinalDeclaration {
             final_declaration: Box::new(final_declaration),
         };
-        let package_item_8_built = PackageItem::FinalDeclaration(package_item_8_built);
+        let package_item_9_built = PackageItem::FinalDeclaration(package_item_9_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_8_built)?;
-        self.push(ASTType::PackageItem(package_item_8_built), context);
+        self.user_grammar.package_item(&package_item_9_built)?;
+        self.push(ASTType::PackageItem(package_item_9_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 796:
+    /// Semantic action for production 888:
+    ///
+    /// PackageItem: EmbedDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn package_item_10(&mut self, _embed_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let embed_declaration = pop_item!(self, embed_declaration, EmbedDeclaration, context);
+        let package_item_10_built = PackageItemEmbedDeclaration {
+            embed_declaration: Box::new(embed_declaration),
+        };
+        let package_item_10_built = PackageItem::EmbedDeclaration(package_item_10_built);
+        // Calling user action here
+        self.user_grammar.package_item(&package_item_10_built)?;
+        self.push(ASTType::PackageItem(package_item_10_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 889:
+    ///
+    /// PackageItem: IncludeDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn package_item_11(&mut self, _include_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let include_declaration = pop_item!(self, include_declaration, IncludeDeclaration, context);
+        let package_item_11_built = PackageItemIncludeDeclaration {
+            include_declaration: Box::new(include_declaration),
+        };
+        let package_item_11_built = PackageItem::IncludeDeclaration(package_item_11_built);
+        // Calling user action here
+        self.user_grammar.package_item(&package_item_11_built)?;
+        self.push(ASTType::PackageItem(package_item_11_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 890:
     ///
     /// DescriptionGroup: DescriptionGroupList /* Vec */ DescriptionGroupGroup;
     ///
@@ -29036,7 +32397,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 797:
+    /// Semantic action for production 891:
     ///
     /// DescriptionGroupGroup: LBrace DescriptionGroupGroupList /* Vec */ RBrace;
     ///
@@ -29074,9 +32435,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 798:
+    /// Semantic action for production 892:
     ///
-    /// DescriptionGroupGroupList /* Vec<T>::Push */: DescriptionGroup DescriptionGroupGroupList;
+    /// DescriptionGroupGroupList /* `Vec<T>::Push` */: DescriptionGroup DescriptionGroupGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn description_group_group_list_0(
@@ -29105,9 +32466,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 799:
+    /// Semantic action for production 893:
     ///
-    /// DescriptionGroupGroupList /* Vec<T>::New */: ;
+    /// DescriptionGroupGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn description_group_group_list_1(&mut self) -> Result<()> {
@@ -29121,7 +32482,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 800:
+    /// Semantic action for production 894:
     ///
     /// DescriptionGroupGroup: DescriptionItem;
     ///
@@ -29142,9 +32503,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 801:
+    /// Semantic action for production 895:
     ///
-    /// DescriptionGroupList /* Vec<T>::Push */: Attribute DescriptionGroupList;
+    /// DescriptionGroupList /* `Vec<T>::Push` */: Attribute DescriptionGroupList;
     ///
     #[parol_runtime::function_name::named]
     fn description_group_list_0(
@@ -29169,9 +32530,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 802:
+    /// Semantic action for production 896:
     ///
-    /// DescriptionGroupList /* Vec<T>::New */: ;
+    /// DescriptionGroupList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn description_group_list_1(&mut self) -> Result<()> {
@@ -29185,7 +32546,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 803:
+    /// Semantic action for production 897:
     ///
     /// DescriptionItem: ModuleDeclaration;
     ///
@@ -29205,70 +32566,96 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 804:
+    /// Semantic action for production 898:
+    ///
+    /// DescriptionItem: ExternModuleDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn description_item_1(&mut self, _extern_module_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let extern_module_declaration = pop_item!(
+            self,
+            extern_module_declaration,
+            ExternModuleDeclaration,
+            context
+        );
+        let description_item_1_built = DescriptionItemExternModuleDeclaration {
+            extern_module_declaration: Box::new(extern_module_declaration),
+        };
+        let description_item_1_built =
+            DescriptionItem::ExternModuleDeclaration(description_item_1_built);
+        // Calling user action here
+        self.user_grammar
+            .description_item(&description_item_1_built)?;
+        self.push(ASTType::DescriptionItem(description_item_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 899:
     ///
     /// DescriptionItem: InterfaceDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn description_item_1(&mut self, _interface_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn description_item_2(&mut self, _interface_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let interface_declaration =
             pop_item!(self, interface_declaration, InterfaceDeclaration, context);
-        let description_item_1_built = DescriptionItemInterfaceDeclaration {
+        let description_item_2_built = DescriptionItemInterfaceDeclaration {
             interface_declaration: Box::new(interface_declaration),
         };
-        let description_item_1_built =
-            DescriptionItem::InterfaceDeclaration(description_item_1_built);
+        let description_item_2_built =
+            DescriptionItem::InterfaceDeclaration(description_item_2_built);
         // Calling user action here
         self.user_grammar
-            .description_item(&description_item_1_built)?;
-        self.push(ASTType::DescriptionItem(description_item_1_built), context);
+            .description_item(&description_item_2_built)?;
+        self.push(ASTType::DescriptionItem(description_item_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 805:
+    /// Semantic action for production 900:
     ///
     /// DescriptionItem: PackageDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn description_item_2(&mut self, _package_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn description_item_3(&mut self, _package_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let package_declaration = pop_item!(self, package_declaration, PackageDeclaration, context);
-        let description_item_2_built = DescriptionItemPackageDeclaration {
+        let description_item_3_built = DescriptionItemPackageDeclaration {
             package_declaration: Box::new(package_declaration),
         };
-        let description_item_2_built =
-            DescriptionItem::PackageDeclaration(description_item_2_built);
+        let description_item_3_built =
+            DescriptionItem::PackageDeclaration(description_item_3_built);
         // Calling user action here
         self.user_grammar
-            .description_item(&description_item_2_built)?;
-        self.push(ASTType::DescriptionItem(description_item_2_built), context);
+            .description_item(&description_item_3_built)?;
+        self.push(ASTType::DescriptionItem(description_item_3_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 806:
+    /// Semantic action for production 901:
     ///
     /// DescriptionItem: ImportDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn description_item_3(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn description_item_4(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
-        let description_item_3_built = DescriptionItemImportDeclaration {
+        let description_item_4_built = DescriptionItemImportDeclaration {
             import_declaration: Box::new(import_declaration),
         };
-        let description_item_3_built = DescriptionItem::ImportDeclaration(description_item_3_built);
+        let description_item_4_built = DescriptionItem::ImportDeclaration(description_item_4_built);
         // Calling user action here
         self.user_grammar
-            .description_item(&description_item_3_built)?;
-        self.push(ASTType::DescriptionItem(description_item_3_built), context);
+            .description_item(&description_item_4_built)?;
+        self.push(ASTType::DescriptionItem(description_item_4_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 807:
+    /// Semantic action for production 902:
     ///
     /// Veryl: Start VerylList /* Vec */;
     ///
@@ -29288,9 +32675,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 808:
+    /// Semantic action for production 903:
     ///
-    /// VerylList /* Vec<T>::Push */: DescriptionGroup VerylList;
+    /// VerylList /* `Vec<T>::Push` */: DescriptionGroup VerylList;
     ///
     #[parol_runtime::function_name::named]
     fn veryl_list_0(
@@ -29311,9 +32698,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 809:
+    /// Semantic action for production 904:
     ///
-    /// VerylList /* Vec<T>::New */: ;
+    /// VerylList /* `Vec<T>::New` */: ;
     ///
     #[parol_runtime::function_name::named]
     fn veryl_list_1(&mut self) -> Result<()> {
@@ -29358,407 +32745,466 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
             20 => self.operator04_term(&children[0]),
             21 => self.operator03_term(&children[0]),
             22 => self.unary_operator_term(&children[0]),
-            23 => self.colon_colon_term(&children[0]),
-            24 => self.colon_term(&children[0]),
-            25 => self.comma_term(&children[0]),
-            26 => self.dollar_term(&children[0]),
-            27 => self.dot_dot_equ_term(&children[0]),
-            28 => self.dot_dot_term(&children[0]),
-            29 => self.dot_term(&children[0]),
-            30 => self.equ_term(&children[0]),
-            31 => self.hash_term(&children[0]),
-            32 => self.l_angle_term(&children[0]),
-            33 => self.l_brace_term(&children[0]),
-            34 => self.l_bracket_term(&children[0]),
-            35 => self.l_paren_term(&children[0]),
-            36 => self.r_angle_term(&children[0]),
-            37 => self.r_brace_term(&children[0]),
-            38 => self.r_bracket_term(&children[0]),
-            39 => self.r_paren_term(&children[0]),
-            40 => self.semicolon_term(&children[0]),
-            41 => self.star_term(&children[0]),
-            42 => self.always_comb_term(&children[0]),
-            43 => self.always_ff_term(&children[0]),
-            44 => self.assign_term(&children[0]),
-            45 => self.async_high_term(&children[0]),
-            46 => self.async_low_term(&children[0]),
-            47 => self.as_term(&children[0]),
-            48 => self.bit_term(&children[0]),
-            49 => self.case_term(&children[0]),
-            50 => self.default_term(&children[0]),
-            51 => self.else_term(&children[0]),
-            52 => self.enum_term(&children[0]),
-            53 => self.export_term(&children[0]),
-            54 => self.f32_term(&children[0]),
-            55 => self.f64_term(&children[0]),
-            56 => self.final_term(&children[0]),
-            57 => self.for_term(&children[0]),
-            58 => self.function_term(&children[0]),
-            59 => self.i32_term(&children[0]),
-            60 => self.i64_term(&children[0]),
-            61 => self.if_reset_term(&children[0]),
-            62 => self.if_term(&children[0]),
-            63 => self.import_term(&children[0]),
-            64 => self.initial_term(&children[0]),
-            65 => self.inout_term(&children[0]),
-            66 => self.input_term(&children[0]),
-            67 => self.inside_term(&children[0]),
-            68 => self.inst_term(&children[0]),
-            69 => self.interface_term(&children[0]),
-            70 => self.in_term(&children[0]),
-            71 => self.localparam_term(&children[0]),
-            72 => self.logic_term(&children[0]),
-            73 => self.lsb_term(&children[0]),
-            74 => self.modport_term(&children[0]),
-            75 => self.module_term(&children[0]),
-            76 => self.msb_term(&children[0]),
-            77 => self.negedge_term(&children[0]),
-            78 => self.output_term(&children[0]),
-            79 => self.outside_term(&children[0]),
-            80 => self.package_term(&children[0]),
-            81 => self.parameter_term(&children[0]),
-            82 => self.posedge_term(&children[0]),
-            83 => self.ref_term(&children[0]),
-            84 => self.repeat_term(&children[0]),
-            85 => self.return_term(&children[0]),
-            86 => self.signed_term(&children[0]),
-            87 => self.step_term(&children[0]),
-            88 => self.string_term(&children[0]),
-            89 => self.struct_term(&children[0]),
-            90 => self.sync_high_term(&children[0]),
-            91 => self.sync_low_term(&children[0]),
-            92 => self.tri_term(&children[0]),
-            93 => self.type_term(&children[0]),
-            94 => self.u32_term(&children[0]),
-            95 => self.u64_term(&children[0]),
-            96 => self.var_term(&children[0]),
-            97 => self.identifier_term(&children[0]),
-            98 => self.comments(&children[0]),
-            99 => self.comments_opt_0(&children[0]),
-            100 => self.comments_opt_1(),
-            101 => self.start_token(&children[0]),
-            102 => self.string_literal_token(&children[0], &children[1]),
-            103 => self.exponent_token(&children[0], &children[1]),
-            104 => self.fixed_point_token(&children[0], &children[1]),
-            105 => self.based_token(&children[0], &children[1]),
-            106 => self.base_less_token(&children[0], &children[1]),
-            107 => self.all_bit_token(&children[0], &children[1]),
-            108 => self.assignment_operator_token(&children[0], &children[1]),
-            109 => self.operator01_token(&children[0], &children[1]),
-            110 => self.operator02_token(&children[0], &children[1]),
-            111 => self.operator03_token(&children[0], &children[1]),
-            112 => self.operator04_token(&children[0], &children[1]),
-            113 => self.operator05_token(&children[0], &children[1]),
-            114 => self.operator06_token(&children[0], &children[1]),
-            115 => self.operator07_token(&children[0], &children[1]),
-            116 => self.operator08_token(&children[0], &children[1]),
-            117 => self.operator09_token(&children[0], &children[1]),
-            118 => self.operator10_token(&children[0], &children[1]),
-            119 => self.operator11_token(&children[0], &children[1]),
-            120 => self.unary_operator_token(&children[0], &children[1]),
-            121 => self.colon_token(&children[0], &children[1]),
-            122 => self.colon_colon_token(&children[0], &children[1]),
-            123 => self.comma_token(&children[0], &children[1]),
-            124 => self.dollar_token(&children[0], &children[1]),
-            125 => self.dot_dot_token(&children[0], &children[1]),
-            126 => self.dot_dot_equ_token(&children[0], &children[1]),
-            127 => self.dot_token(&children[0], &children[1]),
-            128 => self.equ_token(&children[0], &children[1]),
-            129 => self.hash_token(&children[0], &children[1]),
-            130 => self.l_angle_token(&children[0], &children[1]),
-            131 => self.l_brace_token(&children[0], &children[1]),
-            132 => self.l_bracket_token(&children[0], &children[1]),
-            133 => self.l_paren_token(&children[0], &children[1]),
-            134 => self.minus_colon_token(&children[0], &children[1]),
-            135 => self.minus_g_t_token(&children[0], &children[1]),
-            136 => self.plus_colon_token(&children[0], &children[1]),
-            137 => self.r_angle_token(&children[0], &children[1]),
-            138 => self.r_brace_token(&children[0], &children[1]),
-            139 => self.r_bracket_token(&children[0], &children[1]),
-            140 => self.r_paren_token(&children[0], &children[1]),
-            141 => self.semicolon_token(&children[0], &children[1]),
-            142 => self.star_token(&children[0], &children[1]),
-            143 => self.always_comb_token(&children[0], &children[1]),
-            144 => self.always_ff_token(&children[0], &children[1]),
-            145 => self.as_token(&children[0], &children[1]),
-            146 => self.assign_token(&children[0], &children[1]),
-            147 => self.async_high_token(&children[0], &children[1]),
-            148 => self.async_low_token(&children[0], &children[1]),
-            149 => self.bit_token(&children[0], &children[1]),
-            150 => self.case_token(&children[0], &children[1]),
-            151 => self.default_token(&children[0], &children[1]),
-            152 => self.else_token(&children[0], &children[1]),
-            153 => self.enum_token(&children[0], &children[1]),
-            154 => self.export_token(&children[0], &children[1]),
-            155 => self.f32_token(&children[0], &children[1]),
-            156 => self.f64_token(&children[0], &children[1]),
-            157 => self.final_token(&children[0], &children[1]),
-            158 => self.for_token(&children[0], &children[1]),
-            159 => self.function_token(&children[0], &children[1]),
-            160 => self.i32_token(&children[0], &children[1]),
-            161 => self.i64_token(&children[0], &children[1]),
-            162 => self.if_reset_token(&children[0], &children[1]),
-            163 => self.if_token(&children[0], &children[1]),
-            164 => self.import_token(&children[0], &children[1]),
-            165 => self.initial_token(&children[0], &children[1]),
-            166 => self.inout_token(&children[0], &children[1]),
-            167 => self.input_token(&children[0], &children[1]),
-            168 => self.inside_token(&children[0], &children[1]),
-            169 => self.inst_token(&children[0], &children[1]),
-            170 => self.interface_token(&children[0], &children[1]),
-            171 => self.in_token(&children[0], &children[1]),
-            172 => self.localparam_token(&children[0], &children[1]),
-            173 => self.logic_token(&children[0], &children[1]),
-            174 => self.lsb_token(&children[0], &children[1]),
-            175 => self.modport_token(&children[0], &children[1]),
-            176 => self.module_token(&children[0], &children[1]),
-            177 => self.msb_token(&children[0], &children[1]),
-            178 => self.negedge_token(&children[0], &children[1]),
-            179 => self.output_token(&children[0], &children[1]),
-            180 => self.outside_token(&children[0], &children[1]),
-            181 => self.package_token(&children[0], &children[1]),
-            182 => self.parameter_token(&children[0], &children[1]),
-            183 => self.posedge_token(&children[0], &children[1]),
-            184 => self.ref_token(&children[0], &children[1]),
-            185 => self.repeat_token(&children[0], &children[1]),
-            186 => self.return_token(&children[0], &children[1]),
-            187 => self.signed_token(&children[0], &children[1]),
-            188 => self.step_token(&children[0], &children[1]),
-            189 => self.string_token(&children[0], &children[1]),
-            190 => self.struct_token(&children[0], &children[1]),
-            191 => self.sync_high_token(&children[0], &children[1]),
-            192 => self.sync_low_token(&children[0], &children[1]),
-            193 => self.tri_token(&children[0], &children[1]),
-            194 => self.type_token(&children[0], &children[1]),
-            195 => self.u32_token(&children[0], &children[1]),
-            196 => self.u64_token(&children[0], &children[1]),
-            197 => self.var_token(&children[0], &children[1]),
-            198 => self.identifier_token(&children[0], &children[1]),
-            199 => self.start(&children[0]),
-            200 => self.string_literal(&children[0]),
-            201 => self.exponent(&children[0]),
-            202 => self.fixed_point(&children[0]),
-            203 => self.based(&children[0]),
-            204 => self.base_less(&children[0]),
-            205 => self.all_bit(&children[0]),
-            206 => self.assignment_operator(&children[0]),
-            207 => self.operator01(&children[0]),
-            208 => self.operator02(&children[0]),
-            209 => self.operator03(&children[0]),
-            210 => self.operator04(&children[0]),
-            211 => self.operator05(&children[0]),
-            212 => self.operator06(&children[0]),
-            213 => self.operator07(&children[0]),
-            214 => self.operator08(&children[0]),
-            215 => self.operator09(&children[0]),
-            216 => self.operator10(&children[0]),
-            217 => self.operator11(&children[0]),
-            218 => self.unary_operator(&children[0]),
-            219 => self.colon(&children[0]),
-            220 => self.colon_colon(&children[0]),
-            221 => self.comma(&children[0]),
-            222 => self.dollar(&children[0]),
-            223 => self.dot_dot(&children[0]),
-            224 => self.dot_dot_equ(&children[0]),
-            225 => self.dot(&children[0]),
-            226 => self.equ(&children[0]),
-            227 => self.hash(&children[0]),
-            228 => self.l_angle(&children[0]),
-            229 => self.l_brace(&children[0]),
-            230 => self.l_bracket(&children[0]),
-            231 => self.l_paren(&children[0]),
-            232 => self.minus_colon(&children[0]),
-            233 => self.minus_g_t(&children[0]),
-            234 => self.plus_colon(&children[0]),
-            235 => self.r_angle(&children[0]),
-            236 => self.r_brace(&children[0]),
-            237 => self.r_bracket(&children[0]),
-            238 => self.r_paren(&children[0]),
-            239 => self.semicolon(&children[0]),
-            240 => self.star(&children[0]),
-            241 => self.always_comb(&children[0]),
-            242 => self.always_ff(&children[0]),
-            243 => self.r#as(&children[0]),
-            244 => self.assign(&children[0]),
-            245 => self.async_high(&children[0]),
-            246 => self.async_low(&children[0]),
-            247 => self.bit(&children[0]),
-            248 => self.case(&children[0]),
-            249 => self.defaul(&children[0]),
-            250 => self.r#else(&children[0]),
-            251 => self.r#enum(&children[0]),
-            252 => self.export(&children[0]),
-            253 => self.f32(&children[0]),
-            254 => self.f64(&children[0]),
-            255 => self.r#final(&children[0]),
-            256 => self.r#for(&children[0]),
-            257 => self.function(&children[0]),
-            258 => self.i32(&children[0]),
-            259 => self.i64(&children[0]),
-            260 => self.r#if(&children[0]),
-            261 => self.if_reset(&children[0]),
-            262 => self.import(&children[0]),
-            263 => self.r#in(&children[0]),
-            264 => self.initial(&children[0]),
-            265 => self.inout(&children[0]),
-            266 => self.input(&children[0]),
-            267 => self.inside(&children[0]),
-            268 => self.inst(&children[0]),
-            269 => self.interface(&children[0]),
-            270 => self.localparam(&children[0]),
-            271 => self.logic(&children[0]),
-            272 => self.lsb(&children[0]),
-            273 => self.modport(&children[0]),
-            274 => self.module(&children[0]),
-            275 => self.msb(&children[0]),
-            276 => self.negedge(&children[0]),
-            277 => self.output(&children[0]),
-            278 => self.outside(&children[0]),
-            279 => self.package(&children[0]),
-            280 => self.parameter(&children[0]),
-            281 => self.posedge(&children[0]),
-            282 => self.r#ref(&children[0]),
-            283 => self.repeat(&children[0]),
-            284 => self.r#return(&children[0]),
-            285 => self.signed(&children[0]),
-            286 => self.step(&children[0]),
-            287 => self.strin(&children[0]),
-            288 => self.r#struct(&children[0]),
-            289 => self.sync_high(&children[0]),
-            290 => self.sync_low(&children[0]),
-            291 => self.tri(&children[0]),
-            292 => self.r#type(&children[0]),
-            293 => self.u32(&children[0]),
-            294 => self.u64(&children[0]),
-            295 => self.var(&children[0]),
-            296 => self.identifier(&children[0]),
-            297 => self.number_0(&children[0]),
-            298 => self.number_1(&children[0]),
-            299 => self.integral_number_0(&children[0]),
-            300 => self.integral_number_1(&children[0]),
-            301 => self.integral_number_2(&children[0]),
-            302 => self.real_number_0(&children[0]),
-            303 => self.real_number_1(&children[0]),
-            304 => self.hierarchical_identifier(&children[0], &children[1], &children[2]),
-            305 => self.hierarchical_identifier_list0_0(
+            23 => self.colon_colon_l_angle_term(&children[0]),
+            24 => self.colon_colon_term(&children[0]),
+            25 => self.colon_term(&children[0]),
+            26 => self.comma_term(&children[0]),
+            27 => self.dollar_term(&children[0]),
+            28 => self.dot_dot_equ_term(&children[0]),
+            29 => self.dot_dot_term(&children[0]),
+            30 => self.dot_term(&children[0]),
+            31 => self.equ_term(&children[0]),
+            32 => self.hash_term(&children[0]),
+            33 => self.l_angle_term(&children[0]),
+            34 => self.l_brace_term(&children[0]),
+            35 => self.l_bracket_term(&children[0]),
+            36 => self.l_paren_term(&children[0]),
+            37 => self.quote_term(&children[0]),
+            38 => self.r_angle_term(&children[0]),
+            39 => self.r_brace_term(&children[0]),
+            40 => self.r_bracket_term(&children[0]),
+            41 => self.r_paren_term(&children[0]),
+            42 => self.semicolon_term(&children[0]),
+            43 => self.star_term(&children[0]),
+            44 => self.always_comb_term(&children[0]),
+            45 => self.always_ff_term(&children[0]),
+            46 => self.assign_term(&children[0]),
+            47 => self.async_high_term(&children[0]),
+            48 => self.async_low_term(&children[0]),
+            49 => self.as_term(&children[0]),
+            50 => self.bit_term(&children[0]),
+            51 => self.case_term(&children[0]),
+            52 => self.clock_term(&children[0]),
+            53 => self.default_term(&children[0]),
+            54 => self.else_term(&children[0]),
+            55 => self.embed_term(&children[0]),
+            56 => self.enum_term(&children[0]),
+            57 => self.export_term(&children[0]),
+            58 => self.extern_term(&children[0]),
+            59 => self.f32_term(&children[0]),
+            60 => self.f64_term(&children[0]),
+            61 => self.final_term(&children[0]),
+            62 => self.for_term(&children[0]),
+            63 => self.function_term(&children[0]),
+            64 => self.i32_term(&children[0]),
+            65 => self.i64_term(&children[0]),
+            66 => self.if_reset_term(&children[0]),
+            67 => self.if_term(&children[0]),
+            68 => self.import_term(&children[0]),
+            69 => self.include_term(&children[0]),
+            70 => self.initial_term(&children[0]),
+            71 => self.inout_term(&children[0]),
+            72 => self.input_term(&children[0]),
+            73 => self.inside_term(&children[0]),
+            74 => self.inst_term(&children[0]),
+            75 => self.interface_term(&children[0]),
+            76 => self.in_term(&children[0]),
+            77 => self.localparam_term(&children[0]),
+            78 => self.logic_term(&children[0]),
+            79 => self.lsb_term(&children[0]),
+            80 => self.modport_term(&children[0]),
+            81 => self.module_term(&children[0]),
+            82 => self.msb_term(&children[0]),
+            83 => self.negedge_term(&children[0]),
+            84 => self.output_term(&children[0]),
+            85 => self.outside_term(&children[0]),
+            86 => self.package_term(&children[0]),
+            87 => self.parameter_term(&children[0]),
+            88 => self.posedge_term(&children[0]),
+            89 => self.ref_term(&children[0]),
+            90 => self.repeat_term(&children[0]),
+            91 => self.reset_term(&children[0]),
+            92 => self.return_term(&children[0]),
+            93 => self.signed_term(&children[0]),
+            94 => self.step_term(&children[0]),
+            95 => self.string_term(&children[0]),
+            96 => self.struct_term(&children[0]),
+            97 => self.sync_high_term(&children[0]),
+            98 => self.sync_low_term(&children[0]),
+            99 => self.tri_term(&children[0]),
+            100 => self.type_term(&children[0]),
+            101 => self.union_term(&children[0]),
+            102 => self.u32_term(&children[0]),
+            103 => self.u64_term(&children[0]),
+            104 => self.var_term(&children[0]),
+            105 => self.identifier_term(&children[0]),
+            106 => self.any_term(&children[0]),
+            107 => self.comments(&children[0]),
+            108 => self.comments_opt_0(&children[0]),
+            109 => self.comments_opt_1(),
+            110 => self.start_token(&children[0]),
+            111 => self.string_literal_token(&children[0], &children[1]),
+            112 => self.exponent_token(&children[0], &children[1]),
+            113 => self.fixed_point_token(&children[0], &children[1]),
+            114 => self.based_token(&children[0], &children[1]),
+            115 => self.base_less_token(&children[0], &children[1]),
+            116 => self.all_bit_token(&children[0], &children[1]),
+            117 => self.assignment_operator_token(&children[0], &children[1]),
+            118 => self.operator01_token(&children[0], &children[1]),
+            119 => self.operator02_token(&children[0], &children[1]),
+            120 => self.operator03_token(&children[0], &children[1]),
+            121 => self.operator04_token(&children[0], &children[1]),
+            122 => self.operator05_token(&children[0], &children[1]),
+            123 => self.operator06_token(&children[0], &children[1]),
+            124 => self.operator07_token(&children[0], &children[1]),
+            125 => self.operator08_token(&children[0], &children[1]),
+            126 => self.operator09_token(&children[0], &children[1]),
+            127 => self.operator10_token(&children[0], &children[1]),
+            128 => self.operator11_token(&children[0], &children[1]),
+            129 => self.unary_operator_token(&children[0], &children[1]),
+            130 => self.colon_token(&children[0], &children[1]),
+            131 => self.colon_colon_l_angle_token(&children[0], &children[1]),
+            132 => self.colon_colon_token(&children[0], &children[1]),
+            133 => self.comma_token(&children[0], &children[1]),
+            134 => self.dollar_token(&children[0], &children[1]),
+            135 => self.dot_dot_token(&children[0], &children[1]),
+            136 => self.dot_dot_equ_token(&children[0], &children[1]),
+            137 => self.dot_token(&children[0], &children[1]),
+            138 => self.equ_token(&children[0], &children[1]),
+            139 => self.hash_token(&children[0], &children[1]),
+            140 => self.l_angle_token(&children[0], &children[1]),
+            141 => self.l_brace_token(&children[0], &children[1]),
+            142 => self.l_bracket_token(&children[0], &children[1]),
+            143 => self.l_paren_token(&children[0], &children[1]),
+            144 => self.minus_colon_token(&children[0], &children[1]),
+            145 => self.minus_g_t_token(&children[0], &children[1]),
+            146 => self.plus_colon_token(&children[0], &children[1]),
+            147 => self.quote_token(&children[0], &children[1]),
+            148 => self.r_angle_token(&children[0], &children[1]),
+            149 => self.r_brace_token(&children[0], &children[1]),
+            150 => self.r_bracket_token(&children[0], &children[1]),
+            151 => self.r_paren_token(&children[0], &children[1]),
+            152 => self.semicolon_token(&children[0], &children[1]),
+            153 => self.star_token(&children[0], &children[1]),
+            154 => self.always_comb_token(&children[0], &children[1]),
+            155 => self.always_ff_token(&children[0], &children[1]),
+            156 => self.as_token(&children[0], &children[1]),
+            157 => self.assign_token(&children[0], &children[1]),
+            158 => self.async_high_token(&children[0], &children[1]),
+            159 => self.async_low_token(&children[0], &children[1]),
+            160 => self.bit_token(&children[0], &children[1]),
+            161 => self.case_token(&children[0], &children[1]),
+            162 => self.clock_token(&children[0], &children[1]),
+            163 => self.default_token(&children[0], &children[1]),
+            164 => self.else_token(&children[0], &children[1]),
+            165 => self.embed_token(&children[0], &children[1]),
+            166 => self.enum_token(&children[0], &children[1]),
+            167 => self.export_token(&children[0], &children[1]),
+            168 => self.extern_token(&children[0], &children[1]),
+            169 => self.f32_token(&children[0], &children[1]),
+            170 => self.f64_token(&children[0], &children[1]),
+            171 => self.final_token(&children[0], &children[1]),
+            172 => self.for_token(&children[0], &children[1]),
+            173 => self.function_token(&children[0], &children[1]),
+            174 => self.i32_token(&children[0], &children[1]),
+            175 => self.i64_token(&children[0], &children[1]),
+            176 => self.if_reset_token(&children[0], &children[1]),
+            177 => self.if_token(&children[0], &children[1]),
+            178 => self.import_token(&children[0], &children[1]),
+            179 => self.include_token(&children[0], &children[1]),
+            180 => self.initial_token(&children[0], &children[1]),
+            181 => self.inout_token(&children[0], &children[1]),
+            182 => self.input_token(&children[0], &children[1]),
+            183 => self.inside_token(&children[0], &children[1]),
+            184 => self.inst_token(&children[0], &children[1]),
+            185 => self.interface_token(&children[0], &children[1]),
+            186 => self.in_token(&children[0], &children[1]),
+            187 => self.localparam_token(&children[0], &children[1]),
+            188 => self.logic_token(&children[0], &children[1]),
+            189 => self.lsb_token(&children[0], &children[1]),
+            190 => self.modport_token(&children[0], &children[1]),
+            191 => self.module_token(&children[0], &children[1]),
+            192 => self.msb_token(&children[0], &children[1]),
+            193 => self.negedge_token(&children[0], &children[1]),
+            194 => self.output_token(&children[0], &children[1]),
+            195 => self.outside_token(&children[0], &children[1]),
+            196 => self.package_token(&children[0], &children[1]),
+            197 => self.parameter_token(&children[0], &children[1]),
+            198 => self.posedge_token(&children[0], &children[1]),
+            199 => self.ref_token(&children[0], &children[1]),
+            200 => self.repeat_token(&children[0], &children[1]),
+            201 => self.reset_token(&children[0], &children[1]),
+            202 => self.return_token(&children[0], &children[1]),
+            203 => self.signed_token(&children[0], &children[1]),
+            204 => self.step_token(&children[0], &children[1]),
+            205 => self.string_token(&children[0], &children[1]),
+            206 => self.struct_token(&children[0], &children[1]),
+            207 => self.sync_high_token(&children[0], &children[1]),
+            208 => self.sync_low_token(&children[0], &children[1]),
+            209 => self.tri_token(&children[0], &children[1]),
+            210 => self.type_token(&children[0], &children[1]),
+            211 => self.union_token(&children[0], &children[1]),
+            212 => self.u32_token(&children[0], &children[1]),
+            213 => self.u64_token(&children[0], &children[1]),
+            214 => self.var_token(&children[0], &children[1]),
+            215 => self.identifier_token(&children[0], &children[1]),
+            216 => self.embed_content_token(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+                &children[7],
+            ),
+            217 => self.embed_content_token_list_0(&children[0], &children[1]),
+            218 => self.embed_content_token_list_1(),
+            219 => self.start(&children[0]),
+            220 => self.string_literal(&children[0]),
+            221 => self.exponent(&children[0]),
+            222 => self.fixed_point(&children[0]),
+            223 => self.based(&children[0]),
+            224 => self.base_less(&children[0]),
+            225 => self.all_bit(&children[0]),
+            226 => self.assignment_operator(&children[0]),
+            227 => self.operator01(&children[0]),
+            228 => self.operator02(&children[0]),
+            229 => self.operator03(&children[0]),
+            230 => self.operator04(&children[0]),
+            231 => self.operator05(&children[0]),
+            232 => self.operator06(&children[0]),
+            233 => self.operator07(&children[0]),
+            234 => self.operator08(&children[0]),
+            235 => self.operator09(&children[0]),
+            236 => self.operator10(&children[0]),
+            237 => self.operator11(&children[0]),
+            238 => self.unary_operator(&children[0]),
+            239 => self.colon(&children[0]),
+            240 => self.colon_colon_l_angle(&children[0]),
+            241 => self.colon_colon(&children[0]),
+            242 => self.comma(&children[0]),
+            243 => self.dollar(&children[0]),
+            244 => self.dot_dot(&children[0]),
+            245 => self.dot_dot_equ(&children[0]),
+            246 => self.dot(&children[0]),
+            247 => self.equ(&children[0]),
+            248 => self.hash(&children[0]),
+            249 => self.l_angle(&children[0]),
+            250 => self.l_brace(&children[0]),
+            251 => self.l_bracket(&children[0]),
+            252 => self.l_paren(&children[0]),
+            253 => self.minus_colon(&children[0]),
+            254 => self.minus_g_t(&children[0]),
+            255 => self.plus_colon(&children[0]),
+            256 => self.quote(&children[0]),
+            257 => self.r_angle(&children[0]),
+            258 => self.r_brace(&children[0]),
+            259 => self.r_bracket(&children[0]),
+            260 => self.r_paren(&children[0]),
+            261 => self.semicolon(&children[0]),
+            262 => self.star(&children[0]),
+            263 => self.always_comb(&children[0]),
+            264 => self.always_ff(&children[0]),
+            265 => self.r#as(&children[0]),
+            266 => self.assign(&children[0]),
+            267 => self.async_high(&children[0]),
+            268 => self.async_low(&children[0]),
+            269 => self.bit(&children[0]),
+            270 => self.case(&children[0]),
+            271 => self.clock(&children[0]),
+            272 => self.defaul(&children[0]),
+            273 => self.r#else(&children[0]),
+            274 => self.embed(&children[0]),
+            275 => self.r#enum(&children[0]),
+            276 => self.export(&children[0]),
+            277 => self.r#extern(&children[0]),
+            278 => self.f32(&children[0]),
+            279 => self.f64(&children[0]),
+            280 => self.r#final(&children[0]),
+            281 => self.r#for(&children[0]),
+            282 => self.function(&children[0]),
+            283 => self.i32(&children[0]),
+            284 => self.i64(&children[0]),
+            285 => self.r#if(&children[0]),
+            286 => self.if_reset(&children[0]),
+            287 => self.import(&children[0]),
+            288 => self.include(&children[0]),
+            289 => self.r#in(&children[0]),
+            290 => self.initial(&children[0]),
+            291 => self.inout(&children[0]),
+            292 => self.input(&children[0]),
+            293 => self.inside(&children[0]),
+            294 => self.inst(&children[0]),
+            295 => self.interface(&children[0]),
+            296 => self.localparam(&children[0]),
+            297 => self.logic(&children[0]),
+            298 => self.lsb(&children[0]),
+            299 => self.modport(&children[0]),
+            300 => self.module(&children[0]),
+            301 => self.msb(&children[0]),
+            302 => self.negedge(&children[0]),
+            303 => self.output(&children[0]),
+            304 => self.outside(&children[0]),
+            305 => self.package(&children[0]),
+            306 => self.parameter(&children[0]),
+            307 => self.posedge(&children[0]),
+            308 => self.r#ref(&children[0]),
+            309 => self.repeat(&children[0]),
+            310 => self.reset(&children[0]),
+            311 => self.r#return(&children[0]),
+            312 => self.signed(&children[0]),
+            313 => self.step(&children[0]),
+            314 => self.strin(&children[0]),
+            315 => self.r#struct(&children[0]),
+            316 => self.sync_high(&children[0]),
+            317 => self.sync_low(&children[0]),
+            318 => self.tri(&children[0]),
+            319 => self.r#type(&children[0]),
+            320 => self.r#union(&children[0]),
+            321 => self.u32(&children[0]),
+            322 => self.u64(&children[0]),
+            323 => self.var(&children[0]),
+            324 => self.identifier(&children[0]),
+            325 => self.embed_content(&children[0]),
+            326 => self.number_0(&children[0]),
+            327 => self.number_1(&children[0]),
+            328 => self.integral_number_0(&children[0]),
+            329 => self.integral_number_1(&children[0]),
+            330 => self.integral_number_2(&children[0]),
+            331 => self.real_number_0(&children[0]),
+            332 => self.real_number_1(&children[0]),
+            333 => self.hierarchical_identifier(&children[0], &children[1], &children[2]),
+            334 => self.hierarchical_identifier_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            306 => self.hierarchical_identifier_list0_list_0(&children[0], &children[1]),
-            307 => self.hierarchical_identifier_list0_list_1(),
-            308 => self.hierarchical_identifier_list0_1(),
-            309 => self.hierarchical_identifier_list_0(&children[0], &children[1]),
-            310 => self.hierarchical_identifier_list_1(),
-            311 => self.scoped_identifier(&children[0], &children[1]),
-            312 => self.scoped_identifier_list_0(&children[0], &children[1], &children[2]),
-            313 => self.scoped_identifier_list_1(),
-            314 => self.expression_identifier(&children[0], &children[1], &children[2]),
-            315 => self.expression_identifier_group_0(
+            335 => self.hierarchical_identifier_list0_list_0(&children[0], &children[1]),
+            336 => self.hierarchical_identifier_list0_list_1(),
+            337 => self.hierarchical_identifier_list0_1(),
+            338 => self.hierarchical_identifier_list_0(&children[0], &children[1]),
+            339 => self.hierarchical_identifier_list_1(),
+            340 => self.scoped_identifier(&children[0], &children[1], &children[2]),
+            341 => self.scoped_identifier_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            316 => self.expression_identifier_group_list0_0(&children[0], &children[1]),
-            317 => self.expression_identifier_group_list0_1(),
-            318 => {
-                self.expression_identifier_group_list_0(&children[0], &children[1], &children[2])
+            342 => self.scoped_identifier_list_1(),
+            343 => self.scoped_identifier_opt0_0(&children[0]),
+            344 => self.scoped_identifier_opt0_1(),
+            345 => self.scoped_identifier_opt_0(&children[0]),
+            346 => self.scoped_identifier_opt_1(),
+            347 => {
+                self.expression_identifier(&children[0], &children[1], &children[2], &children[3])
             }
-            319 => self.expression_identifier_group_list_1(),
-            320 => self.expression_identifier_group_1(&children[0], &children[1]),
-            321 => self.expression_identifier_group_list2_0(
+            348 => self.expression_identifier_group_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+            ),
+            349 => self.expression_identifier_group_list0_0(&children[0], &children[1]),
+            350 => self.expression_identifier_group_list0_1(),
+            351 => self.expression_identifier_group_list_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+            ),
+            352 => self.expression_identifier_group_list_1(),
+            353 => self.expression_identifier_group_1(&children[0], &children[1]),
+            354 => self.expression_identifier_group_list2_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            322 => self.expression_identifier_group_list2_list_0(&children[0], &children[1]),
-            323 => self.expression_identifier_group_list2_list_1(),
-            324 => self.expression_identifier_group_list2_1(),
-            325 => self.expression_identifier_group_list1_0(&children[0], &children[1]),
-            326 => self.expression_identifier_group_list1_1(),
-            327 => self.expression_identifier_opt_0(&children[0]),
-            328 => self.expression_identifier_opt_1(),
-            329 => self.expression(&children[0], &children[1]),
-            330 => self.expression_list_0(&children[0], &children[1], &children[2]),
-            331 => self.expression_list_1(),
-            332 => self.expression01(&children[0], &children[1]),
-            333 => self.expression01_list_0(&children[0], &children[1], &children[2]),
-            334 => self.expression01_list_1(),
-            335 => self.expression02(&children[0], &children[1]),
-            336 => self.expression02_list_0(&children[0], &children[1], &children[2]),
-            337 => self.expression02_list_1(),
-            338 => self.expression03(&children[0], &children[1]),
-            339 => self.expression03_list_0(&children[0], &children[1], &children[2]),
-            340 => self.expression03_list_1(),
-            341 => self.expression04(&children[0], &children[1]),
-            342 => self.expression04_list_0(&children[0], &children[1], &children[2]),
-            343 => self.expression04_list_1(),
-            344 => self.expression05(&children[0], &children[1]),
-            345 => self.expression05_list_0(&children[0], &children[1], &children[2]),
-            346 => self.expression05_list_1(),
-            347 => self.expression06(&children[0], &children[1]),
-            348 => self.expression06_list_0(&children[0], &children[1], &children[2]),
-            349 => self.expression06_list_1(),
-            350 => self.expression07(&children[0], &children[1]),
-            351 => self.expression07_list_0(&children[0], &children[1], &children[2]),
-            352 => self.expression07_list_1(),
-            353 => self.expression08(&children[0], &children[1]),
-            354 => self.expression08_list_0(&children[0], &children[1], &children[2]),
-            355 => self.expression08_list_1(),
-            356 => self.expression09(&children[0], &children[1]),
-            357 => self.expression09_list_0(&children[0], &children[1], &children[2]),
-            358 => self.expression09_list_group_0(&children[0]),
-            359 => self.expression09_list_group_1(&children[0]),
-            360 => self.expression09_list_1(),
-            361 => self.expression10(&children[0], &children[1]),
-            362 => self.expression10_list_0(&children[0], &children[1], &children[2]),
-            363 => self.expression10_list_1(),
-            364 => self.expression11(&children[0], &children[1]),
-            365 => self.expression11_list_0(&children[0], &children[1], &children[2]),
-            366 => self.expression11_list_1(),
-            367 => self.expression12(&children[0], &children[1]),
-            368 => self.expression12_list_0(&children[0], &children[1]),
-            369 => self.expression12_list_group_0(&children[0]),
-            370 => self.expression12_list_group_1(&children[0]),
-            371 => self.expression12_list_group_2(&children[0]),
-            372 => self.expression12_list_group_3(&children[0]),
-            373 => self.expression12_list_group_4(&children[0]),
-            374 => self.expression12_list_1(),
-            375 => self.factor_0(&children[0]),
-            376 => self.factor_1(&children[0], &children[1]),
-            377 => self.factor_2(&children[0], &children[1], &children[2]),
-            378 => self.factor_3(&children[0], &children[1], &children[2]),
-            379 => self.factor_4(&children[0]),
-            380 => self.factor_5(&children[0]),
-            381 => self.factor_6(&children[0]),
-            382 => self.factor_7(&children[0]),
-            383 => self.factor_group_0(&children[0]),
-            384 => self.factor_group_1(&children[0]),
-            385 => self.factor_8(&children[0]),
-            386 => self.factor_9(&children[0]),
-            387 => self.factor_opt_0(&children[0]),
-            388 => self.factor_opt_1(),
-            389 => self.function_call(&children[0], &children[1], &children[2]),
-            390 => self.function_call_opt_0(&children[0]),
-            391 => self.function_call_opt_1(),
-            392 => self.argument_list(&children[0], &children[1], &children[2]),
-            393 => self.argument_list_list_0(&children[0], &children[1], &children[2]),
-            394 => self.argument_list_list_1(),
-            395 => self.argument_list_opt_0(&children[0]),
-            396 => self.argument_list_opt_1(),
-            397 => self.argument_item(&children[0]),
-            398 => self.concatenation_list(&children[0], &children[1], &children[2]),
-            399 => self.concatenation_list_list_0(&children[0], &children[1], &children[2]),
-            400 => self.concatenation_list_list_1(),
-            401 => self.concatenation_list_opt_0(&children[0]),
-            402 => self.concatenation_list_opt_1(),
-            403 => self.concatenation_item(&children[0], &children[1]),
-            404 => self.concatenation_item_opt_0(&children[0], &children[1]),
-            405 => self.concatenation_item_opt_1(),
-            406 => self.if_expression(
+            355 => self.expression_identifier_group_list2_list_0(&children[0], &children[1]),
+            356 => self.expression_identifier_group_list2_list_1(),
+            357 => self.expression_identifier_group_list2_1(),
+            358 => self.expression_identifier_group_list1_0(&children[0], &children[1]),
+            359 => self.expression_identifier_group_list1_1(),
+            360 => self.expression_identifier_opt2_0(&children[0]),
+            361 => self.expression_identifier_opt2_1(),
+            362 => self.expression_identifier_opt1_0(&children[0]),
+            363 => self.expression_identifier_opt1_1(),
+            364 => self.expression_identifier_opt0_0(&children[0]),
+            365 => self.expression_identifier_opt0_1(),
+            366 => self.expression_identifier_opt_0(&children[0]),
+            367 => self.expression_identifier_opt_1(),
+            368 => self.expression(&children[0], &children[1]),
+            369 => self.expression_list_0(&children[0], &children[1], &children[2]),
+            370 => self.expression_list_1(),
+            371 => self.expression01(&children[0], &children[1]),
+            372 => self.expression01_list_0(&children[0], &children[1], &children[2]),
+            373 => self.expression01_list_1(),
+            374 => self.expression02(&children[0], &children[1]),
+            375 => self.expression02_list_0(&children[0], &children[1], &children[2]),
+            376 => self.expression02_list_1(),
+            377 => self.expression03(&children[0], &children[1]),
+            378 => self.expression03_list_0(&children[0], &children[1], &children[2]),
+            379 => self.expression03_list_1(),
+            380 => self.expression04(&children[0], &children[1]),
+            381 => self.expression04_list_0(&children[0], &children[1], &children[2]),
+            382 => self.expression04_list_1(),
+            383 => self.expression05(&children[0], &children[1]),
+            384 => self.expression05_list_0(&children[0], &children[1], &children[2]),
+            385 => self.expression05_list_1(),
+            386 => self.expression06(&children[0], &children[1]),
+            387 => self.expression06_list_0(&children[0], &children[1], &children[2]),
+            388 => self.expression06_list_1(),
+            389 => self.expression07(&children[0], &children[1]),
+            390 => self.expression07_list_0(&children[0], &children[1], &children[2]),
+            391 => self.expression07_list_1(),
+            392 => self.expression08(&children[0], &children[1]),
+            393 => self.expression08_list_0(&children[0], &children[1], &children[2]),
+            394 => self.expression08_list_1(),
+            395 => self.expression09(&children[0], &children[1]),
+            396 => self.expression09_list_0(&children[0], &children[1], &children[2]),
+            397 => self.expression09_list_group_0(&children[0]),
+            398 => self.expression09_list_group_1(&children[0]),
+            399 => self.expression09_list_1(),
+            400 => self.expression10(&children[0], &children[1]),
+            401 => self.expression10_list_0(&children[0], &children[1], &children[2]),
+            402 => self.expression10_list_1(),
+            403 => self.expression11(&children[0], &children[1]),
+            404 => self.expression11_list_0(&children[0], &children[1], &children[2]),
+            405 => self.expression11_list_1(),
+            406 => self.expression12(&children[0], &children[1]),
+            407 => self.expression12_list_0(&children[0], &children[1]),
+            408 => self.expression12_list_group_0(&children[0]),
+            409 => self.expression12_list_group_1(&children[0]),
+            410 => self.expression12_list_group_2(&children[0]),
+            411 => self.expression12_list_group_3(&children[0]),
+            412 => self.expression12_list_group_4(&children[0]),
+            413 => self.expression12_list_1(),
+            414 => self.factor_0(&children[0]),
+            415 => self.factor_1(&children[0], &children[1]),
+            416 => self.factor_2(&children[0], &children[1], &children[2]),
+            417 => self.factor_3(&children[0], &children[1], &children[2]),
+            418 => self.factor_4(&children[0]),
+            419 => self.factor_5(&children[0]),
+            420 => self.factor_6(&children[0]),
+            421 => self.factor_7(&children[0]),
+            422 => self.factor_group_0(&children[0]),
+            423 => self.factor_group_1(&children[0]),
+            424 => self.factor_8(&children[0]),
+            425 => self.factor_9(&children[0]),
+            426 => self.factor_opt_0(&children[0]),
+            427 => self.factor_opt_1(),
+            428 => self.function_call(&children[0], &children[1], &children[2]),
+            429 => self.function_call_opt_0(&children[0]),
+            430 => self.function_call_opt_1(),
+            431 => self.argument_list(&children[0], &children[1], &children[2]),
+            432 => self.argument_list_list_0(&children[0], &children[1], &children[2]),
+            433 => self.argument_list_list_1(),
+            434 => self.argument_list_opt_0(&children[0]),
+            435 => self.argument_list_opt_1(),
+            436 => self.argument_item(&children[0]),
+            437 => self.concatenation_list(&children[0], &children[1], &children[2]),
+            438 => self.concatenation_list_list_0(&children[0], &children[1], &children[2]),
+            439 => self.concatenation_list_list_1(),
+            440 => self.concatenation_list_opt_0(&children[0]),
+            441 => self.concatenation_list_opt_1(),
+            442 => self.concatenation_item(&children[0], &children[1]),
+            443 => self.concatenation_item_opt_0(&children[0], &children[1]),
+            444 => self.concatenation_item_opt_1(),
+            445 => self.if_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -29770,7 +33216,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[8],
                 &children[9],
             ),
-            407 => self.if_expression_list_0(
+            446 => self.if_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -29779,8 +33225,8 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            408 => self.if_expression_list_1(),
-            409 => self.case_expression(
+            447 => self.if_expression_list_1(),
+            448 => self.case_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -29795,92 +33241,95 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[11],
                 &children[12],
             ),
-            410 => self.case_expression_list_0(
+            449 => self.case_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            411 => self.case_expression_list_1(),
-            412 => self.case_expression_opt_0(&children[0]),
-            413 => self.case_expression_opt_1(),
-            414 => self.type_expression_0(&children[0]),
-            415 => self.type_expression_1(&children[0], &children[1], &children[2], &children[3]),
-            416 => self.inside_expression(
+            450 => self.case_expression_list_1(),
+            451 => self.case_expression_opt_0(&children[0]),
+            452 => self.case_expression_opt_1(),
+            453 => self.type_expression_0(&children[0]),
+            454 => self.type_expression_1(&children[0], &children[1], &children[2], &children[3]),
+            455 => self.inside_expression(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            417 => self.outside_expression(
+            456 => self.outside_expression(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            418 => self.range_list(&children[0], &children[1], &children[2]),
-            419 => self.range_list_list_0(&children[0], &children[1], &children[2]),
-            420 => self.range_list_list_1(),
-            421 => self.range_list_opt_0(&children[0]),
-            422 => self.range_list_opt_1(),
-            423 => self.range_item(&children[0]),
-            424 => self.select(&children[0], &children[1], &children[2], &children[3]),
-            425 => self.select_opt_0(&children[0], &children[1]),
-            426 => self.select_opt_1(),
-            427 => self.select_operator_0(&children[0]),
-            428 => self.select_operator_1(&children[0]),
-            429 => self.select_operator_2(&children[0]),
-            430 => self.select_operator_3(&children[0]),
-            431 => self.width(&children[0], &children[1], &children[2], &children[3]),
-            432 => self.width_list_0(&children[0], &children[1], &children[2]),
-            433 => self.width_list_1(),
-            434 => self.array(&children[0], &children[1], &children[2], &children[3]),
-            435 => self.array_list_0(&children[0], &children[1], &children[2]),
-            436 => self.array_list_1(),
-            437 => self.range(&children[0], &children[1]),
-            438 => self.range_opt_0(&children[0], &children[1]),
-            439 => self.range_opt_1(),
-            440 => self.range_operator_0(&children[0]),
-            441 => self.range_operator_1(&children[0]),
-            442 => self.fixed_type_0(&children[0]),
-            443 => self.fixed_type_1(&children[0]),
-            444 => self.fixed_type_2(&children[0]),
-            445 => self.fixed_type_3(&children[0]),
-            446 => self.fixed_type_4(&children[0]),
-            447 => self.fixed_type_5(&children[0]),
-            448 => self.fixed_type_6(&children[0]),
-            449 => self.variable_type(&children[0], &children[1]),
-            450 => self.variable_type_group_0(&children[0]),
-            451 => self.variable_type_group_1(&children[0]),
-            452 => self.variable_type_group_2(&children[0]),
-            453 => self.variable_type_opt_0(&children[0]),
-            454 => self.variable_type_opt_1(),
-            455 => self.type_modifier_0(&children[0]),
-            456 => self.type_modifier_1(&children[0]),
-            457 => self.scalar_type(&children[0], &children[1]),
-            458 => self.scalar_type_group_0(&children[0]),
-            459 => self.scalar_type_group_1(&children[0]),
-            460 => self.scalar_type_list_0(&children[0], &children[1]),
-            461 => self.scalar_type_list_1(),
-            462 => self.array_type(&children[0], &children[1]),
-            463 => self.array_type_opt_0(&children[0]),
-            464 => self.array_type_opt_1(),
-            465 => self.statement_0(&children[0]),
-            466 => self.statement_1(&children[0]),
-            467 => self.statement_2(&children[0]),
-            468 => self.statement_3(&children[0]),
-            469 => self.statement_4(&children[0]),
-            470 => self.statement_5(&children[0]),
-            471 => self.identifier_statement(&children[0], &children[1], &children[2]),
-            472 => self.identifier_statement_group_0(&children[0]),
-            473 => self.identifier_statement_group_1(&children[0]),
-            474 => self.assignment(&children[0], &children[1]),
-            475 => self.assignment_group_0(&children[0]),
-            476 => self.assignment_group_1(&children[0]),
-            477 => self.if_statement(
+            457 => self.range_list(&children[0], &children[1], &children[2]),
+            458 => self.range_list_list_0(&children[0], &children[1], &children[2]),
+            459 => self.range_list_list_1(),
+            460 => self.range_list_opt_0(&children[0]),
+            461 => self.range_list_opt_1(),
+            462 => self.range_item(&children[0]),
+            463 => self.select(&children[0], &children[1], &children[2], &children[3]),
+            464 => self.select_opt_0(&children[0], &children[1]),
+            465 => self.select_opt_1(),
+            466 => self.select_operator_0(&children[0]),
+            467 => self.select_operator_1(&children[0]),
+            468 => self.select_operator_2(&children[0]),
+            469 => self.select_operator_3(&children[0]),
+            470 => self.width(&children[0], &children[1], &children[2], &children[3]),
+            471 => self.width_list_0(&children[0], &children[1], &children[2]),
+            472 => self.width_list_1(),
+            473 => self.array(&children[0], &children[1], &children[2], &children[3]),
+            474 => self.array_list_0(&children[0], &children[1], &children[2]),
+            475 => self.array_list_1(),
+            476 => self.range(&children[0], &children[1]),
+            477 => self.range_opt_0(&children[0], &children[1]),
+            478 => self.range_opt_1(),
+            479 => self.range_operator_0(&children[0]),
+            480 => self.range_operator_1(&children[0]),
+            481 => self.fixed_type_0(&children[0]),
+            482 => self.fixed_type_1(&children[0]),
+            483 => self.fixed_type_2(&children[0]),
+            484 => self.fixed_type_3(&children[0]),
+            485 => self.fixed_type_4(&children[0]),
+            486 => self.fixed_type_5(&children[0]),
+            487 => self.fixed_type_6(&children[0]),
+            488 => self.variable_type(&children[0], &children[1]),
+            489 => self.variable_type_group_0(&children[0]),
+            490 => self.variable_type_group_1(&children[0]),
+            491 => self.variable_type_group_2(&children[0]),
+            492 => self.variable_type_group_3(&children[0]),
+            493 => self.variable_type_group_4(&children[0]),
+            494 => self.variable_type_opt_0(&children[0]),
+            495 => self.variable_type_opt_1(),
+            496 => self.type_modifier_0(&children[0]),
+            497 => self.type_modifier_1(&children[0]),
+            498 => self.scalar_type(&children[0], &children[1]),
+            499 => self.scalar_type_group_0(&children[0]),
+            500 => self.scalar_type_group_1(&children[0]),
+            501 => self.scalar_type_list_0(&children[0], &children[1]),
+            502 => self.scalar_type_list_1(),
+            503 => self.array_type(&children[0], &children[1]),
+            504 => self.array_type_opt_0(&children[0]),
+            505 => self.array_type_opt_1(),
+            506 => self.clock_domain(&children[0], &children[1]),
+            507 => self.statement_0(&children[0]),
+            508 => self.statement_1(&children[0]),
+            509 => self.statement_2(&children[0]),
+            510 => self.statement_3(&children[0]),
+            511 => self.statement_4(&children[0]),
+            512 => self.statement_5(&children[0]),
+            513 => self.identifier_statement(&children[0], &children[1], &children[2]),
+            514 => self.identifier_statement_group_0(&children[0]),
+            515 => self.identifier_statement_group_1(&children[0]),
+            516 => self.assignment(&children[0], &children[1]),
+            517 => self.assignment_group_0(&children[0]),
+            518 => self.assignment_group_1(&children[0]),
+            519 => self.if_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -29889,7 +33338,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            478 => self.if_statement_list0_0(
+            520 => self.if_statement_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -29898,16 +33347,16 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            479 => self.if_statement_list0_list_0(&children[0], &children[1]),
-            480 => self.if_statement_list0_list_1(),
-            481 => self.if_statement_list0_1(),
-            482 => self.if_statement_list_0(&children[0], &children[1]),
-            483 => self.if_statement_list_1(),
-            484 => self.if_statement_opt_0(&children[0], &children[1], &children[2], &children[3]),
-            485 => self.if_statement_opt_list_0(&children[0], &children[1]),
-            486 => self.if_statement_opt_list_1(),
-            487 => self.if_statement_opt_1(),
-            488 => self.if_reset_statement(
+            521 => self.if_statement_list0_list_0(&children[0], &children[1]),
+            522 => self.if_statement_list0_list_1(),
+            523 => self.if_statement_list0_1(),
+            524 => self.if_statement_list_0(&children[0], &children[1]),
+            525 => self.if_statement_list_1(),
+            526 => self.if_statement_opt_0(&children[0], &children[1], &children[2], &children[3]),
+            527 => self.if_statement_opt_list_0(&children[0], &children[1]),
+            528 => self.if_statement_opt_list_1(),
+            529 => self.if_statement_opt_1(),
+            530 => self.if_reset_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -29915,7 +33364,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            489 => self.if_reset_statement_list0_0(
+            531 => self.if_reset_statement_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -29924,22 +33373,22 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            490 => self.if_reset_statement_list0_list_0(&children[0], &children[1]),
-            491 => self.if_reset_statement_list0_list_1(),
-            492 => self.if_reset_statement_list0_1(),
-            493 => self.if_reset_statement_list_0(&children[0], &children[1]),
-            494 => self.if_reset_statement_list_1(),
-            495 => self.if_reset_statement_opt_0(
+            532 => self.if_reset_statement_list0_list_0(&children[0], &children[1]),
+            533 => self.if_reset_statement_list0_list_1(),
+            534 => self.if_reset_statement_list0_1(),
+            535 => self.if_reset_statement_list_0(&children[0], &children[1]),
+            536 => self.if_reset_statement_list_1(),
+            537 => self.if_reset_statement_opt_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            496 => self.if_reset_statement_opt_list_0(&children[0], &children[1]),
-            497 => self.if_reset_statement_opt_list_1(),
-            498 => self.if_reset_statement_opt_1(),
-            499 => self.return_statement(&children[0], &children[1], &children[2]),
-            500 => self.for_statement(
+            538 => self.if_reset_statement_opt_list_0(&children[0], &children[1]),
+            539 => self.if_reset_statement_opt_list_1(),
+            540 => self.if_reset_statement_opt_1(),
+            541 => self.return_statement(&children[0], &children[1], &children[2]),
+            542 => self.for_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -29951,62 +33400,69 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[8],
                 &children[9],
             ),
-            501 => self.for_statement_list_0(&children[0], &children[1]),
-            502 => self.for_statement_list_1(),
-            503 => self.for_statement_opt_0(&children[0], &children[1], &children[2]),
-            504 => self.for_statement_opt_1(),
-            505 => self.case_statement(
+            543 => self.for_statement_list_0(&children[0], &children[1]),
+            544 => self.for_statement_list_1(),
+            545 => self.for_statement_opt_0(&children[0], &children[1], &children[2]),
+            546 => self.for_statement_opt_1(),
+            547 => self.case_statement(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            506 => self.case_statement_list_0(&children[0], &children[1]),
-            507 => self.case_statement_list_1(),
-            508 => self.case_item(&children[0], &children[1], &children[2]),
-            509 => self.case_item_group0_0(&children[0]),
-            510 => self.case_item_group0_1(&children[0], &children[1], &children[2]),
-            511 => self.case_item_group0_list_0(&children[0], &children[1]),
-            512 => self.case_item_group0_list_1(),
-            513 => self.case_item_group_0(&children[0]),
-            514 => self.case_item_group_1(&children[0]),
-            515 => self.attribute(
+            548 => self.case_statement_list_0(&children[0], &children[1]),
+            549 => self.case_statement_list_1(),
+            550 => self.case_item(&children[0], &children[1], &children[2]),
+            551 => self.case_item_group0_0(&children[0]),
+            552 => self.case_item_group0_1(&children[0], &children[1], &children[2]),
+            553 => self.case_item_group0_list_0(&children[0], &children[1]),
+            554 => self.case_item_group0_list_1(),
+            555 => self.case_item_group_0(&children[0]),
+            556 => self.case_item_group_1(&children[0]),
+            557 => self.attribute(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            516 => self.attribute_opt_0(&children[0], &children[1], &children[2]),
-            517 => self.attribute_opt_1(),
-            518 => self.attribute_list(&children[0], &children[1], &children[2]),
-            519 => self.attribute_list_list_0(&children[0], &children[1], &children[2]),
-            520 => self.attribute_list_list_1(),
-            521 => self.attribute_list_opt_0(&children[0]),
-            522 => self.attribute_list_opt_1(),
-            523 => self.attribute_item_0(&children[0]),
-            524 => self.attribute_item_1(&children[0]),
-            525 => self.var_declaration(
+            558 => self.attribute_opt_0(&children[0], &children[1], &children[2]),
+            559 => self.attribute_opt_1(),
+            560 => self.attribute_list(&children[0], &children[1], &children[2]),
+            561 => self.attribute_list_list_0(&children[0], &children[1], &children[2]),
+            562 => self.attribute_list_list_1(),
+            563 => self.attribute_list_opt_0(&children[0]),
+            564 => self.attribute_list_opt_1(),
+            565 => self.attribute_item_0(&children[0], &children[1]),
+            566 => self.attribute_item_1(&children[0]),
+            567 => self.attribute_item_opt_0(&children[0]),
+            568 => self.attribute_item_opt_group_0(&children[0], &children[1]),
+            569 => self.attribute_item_opt_group_1(&children[0], &children[1], &children[2]),
+            570 => self.attribute_item_opt_1(),
+            571 => self.var_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
                 &children[5],
+                &children[6],
             ),
-            526 => self.var_declaration_opt_0(&children[0], &children[1]),
-            527 => self.var_declaration_opt_1(),
-            528 => self.localparam_declaration(
+            572 => self.var_declaration_opt0_0(&children[0], &children[1]),
+            573 => self.var_declaration_opt0_1(),
+            574 => self.var_declaration_opt_0(&children[0]),
+            575 => self.var_declaration_opt_1(),
+            576 => self.localparam_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            529 => self.localparam_declaration_group_0(&children[0], &children[1], &children[2]),
-            530 => self.localparam_declaration_group_1(&children[0], &children[1], &children[2]),
-            531 => self.always_ff_declaration(
+            577 => self.localparam_declaration_group_0(&children[0], &children[1], &children[2]),
+            578 => self.localparam_declaration_group_1(&children[0], &children[1], &children[2]),
+            579 => self.always_ff_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -30016,53 +33472,53 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            532 => self.always_ff_declaration_list_0(&children[0], &children[1]),
-            533 => self.always_ff_declaration_list_1(),
-            534 => self.always_ff_declaration_opt_0(&children[0], &children[1]),
-            535 => self.always_ff_declaration_opt_1(),
-            536 => self.always_ff_clock(&children[0], &children[1]),
-            537 => self.always_ff_clock_opt_0(&children[0]),
-            538 => self.always_ff_clock_opt_group_0(&children[0]),
-            539 => self.always_ff_clock_opt_group_1(&children[0]),
-            540 => self.always_ff_clock_opt_1(),
-            541 => self.always_ff_reset(&children[0], &children[1]),
-            542 => self.always_ff_reset_opt_0(&children[0]),
-            543 => self.always_ff_reset_opt_group_0(&children[0]),
-            544 => self.always_ff_reset_opt_group_1(&children[0]),
-            545 => self.always_ff_reset_opt_group_2(&children[0]),
-            546 => self.always_ff_reset_opt_group_3(&children[0]),
-            547 => self.always_ff_reset_opt_1(),
-            548 => {
+            580 => self.always_ff_declaration_list_0(&children[0], &children[1]),
+            581 => self.always_ff_declaration_list_1(),
+            582 => self.always_ff_declaration_opt_0(&children[0], &children[1]),
+            583 => self.always_ff_declaration_opt_1(),
+            584 => self.always_ff_clock(&children[0], &children[1]),
+            585 => self.always_ff_clock_opt_0(&children[0]),
+            586 => self.always_ff_clock_opt_group_0(&children[0]),
+            587 => self.always_ff_clock_opt_group_1(&children[0]),
+            588 => self.always_ff_clock_opt_1(),
+            589 => self.always_ff_reset(&children[0], &children[1]),
+            590 => self.always_ff_reset_opt_0(&children[0]),
+            591 => self.always_ff_reset_opt_group_0(&children[0]),
+            592 => self.always_ff_reset_opt_group_1(&children[0]),
+            593 => self.always_ff_reset_opt_group_2(&children[0]),
+            594 => self.always_ff_reset_opt_group_3(&children[0]),
+            595 => self.always_ff_reset_opt_1(),
+            596 => {
                 self.always_comb_declaration(&children[0], &children[1], &children[2], &children[3])
             }
-            549 => self.always_comb_declaration_list_0(&children[0], &children[1]),
-            550 => self.always_comb_declaration_list_1(),
-            551 => self.assign_declaration(
+            597 => self.always_comb_declaration_list_0(&children[0], &children[1]),
+            598 => self.always_comb_declaration_list_1(),
+            599 => self.assign_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            552 => self.modport_declaration(
+            600 => self.modport_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            553 => self.modport_list(&children[0], &children[1], &children[2]),
-            554 => self.modport_list_list_0(&children[0], &children[1], &children[2]),
-            555 => self.modport_list_list_1(),
-            556 => self.modport_list_opt_0(&children[0]),
-            557 => self.modport_list_opt_1(),
-            558 => self.modport_group(&children[0], &children[1]),
-            559 => self.modport_group_group_0(&children[0], &children[1], &children[2]),
-            560 => self.modport_group_group_1(&children[0]),
-            561 => self.modport_group_list_0(&children[0], &children[1]),
-            562 => self.modport_group_list_1(),
-            563 => self.modport_item(&children[0], &children[1], &children[2]),
-            564 => self.enum_declaration(
+            601 => self.modport_list(&children[0], &children[1], &children[2]),
+            602 => self.modport_list_list_0(&children[0], &children[1], &children[2]),
+            603 => self.modport_list_list_1(),
+            604 => self.modport_list_opt_0(&children[0]),
+            605 => self.modport_list_opt_1(),
+            606 => self.modport_group(&children[0], &children[1]),
+            607 => self.modport_group_group_0(&children[0], &children[1], &children[2]),
+            608 => self.modport_group_group_1(&children[0]),
+            609 => self.modport_group_list_0(&children[0], &children[1]),
+            610 => self.modport_group_list_1(),
+            611 => self.modport_item(&children[0], &children[1], &children[2]),
+            612 => self.enum_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -30071,44 +33527,51 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            565 => self.enum_list(&children[0], &children[1], &children[2]),
-            566 => self.enum_list_list_0(&children[0], &children[1], &children[2]),
-            567 => self.enum_list_list_1(),
-            568 => self.enum_list_opt_0(&children[0]),
-            569 => self.enum_list_opt_1(),
-            570 => self.enum_group(&children[0], &children[1]),
-            571 => self.enum_group_group_0(&children[0], &children[1], &children[2]),
-            572 => self.enum_group_group_1(&children[0]),
-            573 => self.enum_group_list_0(&children[0], &children[1]),
-            574 => self.enum_group_list_1(),
-            575 => self.enum_item(&children[0], &children[1]),
-            576 => self.enum_item_opt_0(&children[0], &children[1]),
-            577 => self.enum_item_opt_1(),
-            578 => self.struct_declaration(
+            613 => self.enum_list(&children[0], &children[1], &children[2]),
+            614 => self.enum_list_list_0(&children[0], &children[1], &children[2]),
+            615 => self.enum_list_list_1(),
+            616 => self.enum_list_opt_0(&children[0]),
+            617 => self.enum_list_opt_1(),
+            618 => self.enum_group(&children[0], &children[1]),
+            619 => self.enum_group_group_0(&children[0], &children[1], &children[2]),
+            620 => self.enum_group_group_1(&children[0]),
+            621 => self.enum_group_list_0(&children[0], &children[1]),
+            622 => self.enum_group_list_1(),
+            623 => self.enum_item(&children[0], &children[1]),
+            624 => self.enum_item_opt_0(&children[0], &children[1]),
+            625 => self.enum_item_opt_1(),
+            626 => self.struct_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+            ),
+            627 => self.struct_list(&children[0], &children[1], &children[2]),
+            628 => self.struct_list_list_0(&children[0], &children[1], &children[2]),
+            629 => self.struct_list_list_1(),
+            630 => self.struct_list_opt_0(&children[0]),
+            631 => self.struct_list_opt_1(),
+            632 => self.struct_group(&children[0], &children[1]),
+            633 => self.struct_group_group_0(&children[0], &children[1], &children[2]),
+            634 => self.struct_group_group_1(&children[0]),
+            635 => self.struct_group_list_0(&children[0], &children[1]),
+            636 => self.struct_group_list_1(),
+            637 => self.struct_item(&children[0], &children[1], &children[2]),
+            638 => self.union_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            579 => self.struct_list(&children[0], &children[1], &children[2]),
-            580 => self.struct_list_list_0(&children[0], &children[1], &children[2]),
-            581 => self.struct_list_list_1(),
-            582 => self.struct_list_opt_0(&children[0]),
-            583 => self.struct_list_opt_1(),
-            584 => self.struct_group(&children[0], &children[1]),
-            585 => self.struct_group_group_0(&children[0], &children[1], &children[2]),
-            586 => self.struct_group_group_1(&children[0]),
-            587 => self.struct_group_list_0(&children[0], &children[1]),
-            588 => self.struct_group_list_1(),
-            589 => self.struct_item(&children[0], &children[1], &children[2]),
-            590 => self.initial_declaration(&children[0], &children[1], &children[2], &children[3]),
-            591 => self.initial_declaration_list_0(&children[0], &children[1]),
-            592 => self.initial_declaration_list_1(),
-            593 => self.final_declaration(&children[0], &children[1], &children[2], &children[3]),
-            594 => self.final_declaration_list_0(&children[0], &children[1]),
-            595 => self.final_declaration_list_1(),
-            596 => self.inst_declaration(
+            639 => self.initial_declaration(&children[0], &children[1], &children[2], &children[3]),
+            640 => self.initial_declaration_list_0(&children[0], &children[1]),
+            641 => self.initial_declaration_list_1(),
+            642 => self.final_declaration(&children[0], &children[1], &children[2], &children[3]),
+            643 => self.final_declaration_list_0(&children[0], &children[1]),
+            644 => self.final_declaration_list_1(),
+            645 => self.inst_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -30118,85 +33581,104 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            597 => self.inst_declaration_opt1_0(&children[0], &children[1], &children[2]),
-            598 => self.inst_declaration_opt2_0(&children[0]),
-            599 => self.inst_declaration_opt2_1(),
-            600 => self.inst_declaration_opt1_1(),
-            601 => self.inst_declaration_opt0_0(&children[0]),
-            602 => self.inst_declaration_opt0_1(),
-            603 => self.inst_declaration_opt_0(&children[0]),
-            604 => self.inst_declaration_opt_1(),
-            605 => self.inst_parameter(&children[0], &children[1], &children[2], &children[3]),
-            606 => self.inst_parameter_opt_0(&children[0]),
-            607 => self.inst_parameter_opt_1(),
-            608 => self.inst_parameter_list(&children[0], &children[1], &children[2]),
-            609 => self.inst_parameter_list_list_0(&children[0], &children[1], &children[2]),
-            610 => self.inst_parameter_list_list_1(),
-            611 => self.inst_parameter_list_opt_0(&children[0]),
-            612 => self.inst_parameter_list_opt_1(),
-            613 => self.inst_parameter_group(&children[0], &children[1]),
-            614 => self.inst_parameter_group_group_0(&children[0], &children[1], &children[2]),
-            615 => self.inst_parameter_group_group_1(&children[0]),
-            616 => self.inst_parameter_group_list_0(&children[0], &children[1]),
-            617 => self.inst_parameter_group_list_1(),
-            618 => self.inst_parameter_item(&children[0], &children[1]),
-            619 => self.inst_parameter_item_opt_0(&children[0], &children[1]),
-            620 => self.inst_parameter_item_opt_1(),
-            621 => self.inst_port_list(&children[0], &children[1], &children[2]),
-            622 => self.inst_port_list_list_0(&children[0], &children[1], &children[2]),
-            623 => self.inst_port_list_list_1(),
-            624 => self.inst_port_list_opt_0(&children[0]),
-            625 => self.inst_port_list_opt_1(),
-            626 => self.inst_port_group(&children[0], &children[1]),
-            627 => self.inst_port_group_group_0(&children[0], &children[1], &children[2]),
-            628 => self.inst_port_group_group_1(&children[0]),
-            629 => self.inst_port_group_list_0(&children[0], &children[1]),
-            630 => self.inst_port_group_list_1(),
-            631 => self.inst_port_item(&children[0], &children[1]),
-            632 => self.inst_port_item_opt_0(&children[0], &children[1]),
-            633 => self.inst_port_item_opt_1(),
-            634 => self.with_parameter(&children[0], &children[1], &children[2], &children[3]),
-            635 => self.with_parameter_opt_0(&children[0]),
-            636 => self.with_parameter_opt_1(),
-            637 => self.with_parameter_list(&children[0], &children[1], &children[2]),
-            638 => self.with_parameter_list_list_0(&children[0], &children[1], &children[2]),
-            639 => self.with_parameter_list_list_1(),
-            640 => self.with_parameter_list_opt_0(&children[0]),
-            641 => self.with_parameter_list_opt_1(),
-            642 => self.with_parameter_group(&children[0], &children[1]),
-            643 => self.with_parameter_group_group_0(&children[0], &children[1], &children[2]),
-            644 => self.with_parameter_group_group_1(&children[0]),
-            645 => self.with_parameter_group_list_0(&children[0], &children[1]),
-            646 => self.with_parameter_group_list_1(),
-            647 => self.with_parameter_item(&children[0], &children[1], &children[2], &children[3]),
-            648 => self.with_parameter_item_group0_0(&children[0], &children[1], &children[2]),
-            649 => self.with_parameter_item_group0_1(&children[0], &children[1], &children[2]),
-            650 => self.with_parameter_item_group_0(&children[0]),
-            651 => self.with_parameter_item_group_1(&children[0]),
-            652 => self.port_declaration(&children[0], &children[1], &children[2]),
-            653 => self.port_declaration_opt_0(&children[0]),
-            654 => self.port_declaration_opt_1(),
-            655 => self.port_declaration_list(&children[0], &children[1], &children[2]),
-            656 => self.port_declaration_list_list_0(&children[0], &children[1], &children[2]),
-            657 => self.port_declaration_list_list_1(),
-            658 => self.port_declaration_list_opt_0(&children[0]),
-            659 => self.port_declaration_list_opt_1(),
-            660 => self.port_declaration_group(&children[0], &children[1]),
-            661 => self.port_declaration_group_group_0(&children[0], &children[1], &children[2]),
-            662 => self.port_declaration_group_group_1(&children[0]),
-            663 => self.port_declaration_group_list_0(&children[0], &children[1]),
-            664 => self.port_declaration_group_list_1(),
-            665 => self.port_declaration_item(&children[0], &children[1], &children[2]),
-            666 => self.port_declaration_item_group_0(&children[0], &children[1]),
-            667 => self.port_declaration_item_group_1(&children[0], &children[1]),
-            668 => self.port_declaration_item_opt_0(&children[0]),
-            669 => self.port_declaration_item_opt_1(),
-            670 => self.direction_0(&children[0]),
-            671 => self.direction_1(&children[0]),
-            672 => self.direction_2(&children[0]),
-            673 => self.direction_3(&children[0]),
-            674 => self.direction_4(&children[0]),
-            675 => self.function_declaration(
+            646 => self.inst_declaration_opt1_0(&children[0], &children[1], &children[2]),
+            647 => self.inst_declaration_opt2_0(&children[0]),
+            648 => self.inst_declaration_opt2_1(),
+            649 => self.inst_declaration_opt1_1(),
+            650 => self.inst_declaration_opt0_0(&children[0]),
+            651 => self.inst_declaration_opt0_1(),
+            652 => self.inst_declaration_opt_0(&children[0]),
+            653 => self.inst_declaration_opt_1(),
+            654 => self.inst_parameter(&children[0], &children[1], &children[2], &children[3]),
+            655 => self.inst_parameter_opt_0(&children[0]),
+            656 => self.inst_parameter_opt_1(),
+            657 => self.inst_parameter_list(&children[0], &children[1], &children[2]),
+            658 => self.inst_parameter_list_list_0(&children[0], &children[1], &children[2]),
+            659 => self.inst_parameter_list_list_1(),
+            660 => self.inst_parameter_list_opt_0(&children[0]),
+            661 => self.inst_parameter_list_opt_1(),
+            662 => self.inst_parameter_group(&children[0], &children[1]),
+            663 => self.inst_parameter_group_group_0(&children[0], &children[1], &children[2]),
+            664 => self.inst_parameter_group_group_1(&children[0]),
+            665 => self.inst_parameter_group_list_0(&children[0], &children[1]),
+            666 => self.inst_parameter_group_list_1(),
+            667 => self.inst_parameter_item(&children[0], &children[1]),
+            668 => self.inst_parameter_item_opt_0(&children[0], &children[1]),
+            669 => self.inst_parameter_item_opt_1(),
+            670 => self.inst_port_list(&children[0], &children[1], &children[2]),
+            671 => self.inst_port_list_list_0(&children[0], &children[1], &children[2]),
+            672 => self.inst_port_list_list_1(),
+            673 => self.inst_port_list_opt_0(&children[0]),
+            674 => self.inst_port_list_opt_1(),
+            675 => self.inst_port_group(&children[0], &children[1]),
+            676 => self.inst_port_group_group_0(&children[0], &children[1], &children[2]),
+            677 => self.inst_port_group_group_1(&children[0]),
+            678 => self.inst_port_group_list_0(&children[0], &children[1]),
+            679 => self.inst_port_group_list_1(),
+            680 => self.inst_port_item(&children[0], &children[1]),
+            681 => self.inst_port_item_opt_0(&children[0], &children[1]),
+            682 => self.inst_port_item_opt_1(),
+            683 => self.with_parameter(&children[0], &children[1], &children[2], &children[3]),
+            684 => self.with_parameter_opt_0(&children[0]),
+            685 => self.with_parameter_opt_1(),
+            686 => self.with_parameter_list(&children[0], &children[1], &children[2]),
+            687 => self.with_parameter_list_list_0(&children[0], &children[1], &children[2]),
+            688 => self.with_parameter_list_list_1(),
+            689 => self.with_parameter_list_opt_0(&children[0]),
+            690 => self.with_parameter_list_opt_1(),
+            691 => self.with_parameter_group(&children[0], &children[1]),
+            692 => self.with_parameter_group_group_0(&children[0], &children[1], &children[2]),
+            693 => self.with_parameter_group_group_1(&children[0]),
+            694 => self.with_parameter_group_list_0(&children[0], &children[1]),
+            695 => self.with_parameter_group_list_1(),
+            696 => self.with_parameter_item(&children[0], &children[1], &children[2], &children[3]),
+            697 => self.with_parameter_item_group0_0(&children[0], &children[1], &children[2]),
+            698 => self.with_parameter_item_group0_1(&children[0], &children[1], &children[2]),
+            699 => self.with_parameter_item_group_0(&children[0]),
+            700 => self.with_parameter_item_group_1(&children[0]),
+            701 => self.with_generic_parameter(&children[0], &children[1], &children[2]),
+            702 => self.with_generic_parameter_list(&children[0], &children[1], &children[2]),
+            703 => {
+                self.with_generic_parameter_list_list_0(&children[0], &children[1], &children[2])
+            }
+            704 => self.with_generic_parameter_list_list_1(),
+            705 => self.with_generic_parameter_list_opt_0(&children[0]),
+            706 => self.with_generic_parameter_list_opt_1(),
+            707 => self.with_generic_parameter_item(&children[0]),
+            708 => self.with_generic_argument(&children[0], &children[1], &children[2]),
+            709 => self.with_generic_argument_list(&children[0], &children[1], &children[2]),
+            710 => self.with_generic_argument_list_list_0(&children[0], &children[1], &children[2]),
+            711 => self.with_generic_argument_list_list_1(),
+            712 => self.with_generic_argument_list_opt_0(&children[0]),
+            713 => self.with_generic_argument_list_opt_1(),
+            714 => self.with_generic_argument_item_0(&children[0]),
+            715 => self.with_generic_argument_item_1(&children[0]),
+            716 => self.port_declaration(&children[0], &children[1], &children[2]),
+            717 => self.port_declaration_opt_0(&children[0]),
+            718 => self.port_declaration_opt_1(),
+            719 => self.port_declaration_list(&children[0], &children[1], &children[2]),
+            720 => self.port_declaration_list_list_0(&children[0], &children[1], &children[2]),
+            721 => self.port_declaration_list_list_1(),
+            722 => self.port_declaration_list_opt_0(&children[0]),
+            723 => self.port_declaration_list_opt_1(),
+            724 => self.port_declaration_group(&children[0], &children[1]),
+            725 => self.port_declaration_group_group_0(&children[0], &children[1], &children[2]),
+            726 => self.port_declaration_group_group_1(&children[0]),
+            727 => self.port_declaration_group_list_0(&children[0], &children[1]),
+            728 => self.port_declaration_group_list_1(),
+            729 => self.port_declaration_item(&children[0], &children[1], &children[2]),
+            730 => self.port_declaration_item_group_0(&children[0], &children[1], &children[2]),
+            731 => self.port_declaration_item_group_1(&children[0], &children[1]),
+            732 => self.port_declaration_item_opt0_0(&children[0]),
+            733 => self.port_declaration_item_opt0_1(),
+            734 => self.port_declaration_item_opt_0(&children[0]),
+            735 => self.port_declaration_item_opt_1(),
+            736 => self.direction_0(&children[0]),
+            737 => self.direction_1(&children[0]),
+            738 => self.direction_2(&children[0]),
+            739 => self.direction_3(&children[0]),
+            740 => self.direction_4(&children[0]),
+            741 => self.function_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -30206,36 +33688,60 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
                 &children[8],
+                &children[9],
+            ),
+            742 => self.function_declaration_list_0(&children[0], &children[1]),
+            743 => self.function_declaration_list_1(),
+            744 => self.function_declaration_opt1_0(&children[0]),
+            745 => self.function_declaration_opt1_1(),
+            746 => self.function_declaration_opt0_0(&children[0]),
+            747 => self.function_declaration_opt0_1(),
+            748 => self.function_declaration_opt_0(&children[0]),
+            749 => self.function_declaration_opt_1(),
+            750 => self.function_item_0(&children[0]),
+            751 => self.function_item_1(&children[0]),
+            752 => self.import_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+            ),
+            753 => self.import_declaration_group_0(&children[0]),
+            754 => self.import_declaration_group_1(&children[0]),
+            755 => self.export_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
             ),
-            676 => self.function_declaration_list_0(&children[0], &children[1]),
-            677 => self.function_declaration_list_1(),
-            678 => self.function_declaration_opt0_0(&children[0]),
-            679 => self.function_declaration_opt0_1(),
-            680 => self.function_declaration_opt_0(&children[0]),
-            681 => self.function_declaration_opt_1(),
-            682 => self.function_item_0(&children[0]),
-            683 => self.function_item_1(&children[0]),
-            684 => self.import_declaration(
+            756 => self.export_declaration_group0_0(&children[0]),
+            757 => self.export_declaration_group0_1(&children[0]),
+            758 => self.export_declaration_group_0(&children[0]),
+            759 => self.export_declaration_group_1(&children[0]),
+            760 => self.embed_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
+                &children[5],
             ),
-            685 => self.import_declaration_group_0(&children[0]),
-            686 => self.import_declaration_group_1(&children[0]),
-            687 => self.export_declaration(
+            761 => self.embed_item_0(&children[0], &children[1], &children[2]),
+            762 => self.embed_item_list_0(&children[0], &children[1]),
+            763 => self.embed_item_list_1(),
+            764 => self.embed_item_1(&children[0]),
+            765 => self.include_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
+                &children[5],
+                &children[6],
             ),
-            688 => self.export_declaration_group0_0(&children[0]),
-            689 => self.export_declaration_group0_1(&children[0]),
-            690 => self.export_declaration_group_0(&children[0]),
-            691 => self.export_declaration_group_1(&children[0]),
-            692 => self.module_declaration(
+            766 => self.module_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -30243,31 +33749,46 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
                 &children[6],
+                &children[7],
+            ),
+            767 => self.module_declaration_list_0(&children[0], &children[1]),
+            768 => self.module_declaration_list_1(),
+            769 => self.module_declaration_opt1_0(&children[0]),
+            770 => self.module_declaration_opt1_1(),
+            771 => self.module_declaration_opt0_0(&children[0]),
+            772 => self.module_declaration_opt0_1(),
+            773 => self.module_declaration_opt_0(&children[0]),
+            774 => self.module_declaration_opt_1(),
+            775 => self.extern_module_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
             ),
-            693 => self.module_declaration_list_0(&children[0], &children[1]),
-            694 => self.module_declaration_list_1(),
-            695 => self.module_declaration_opt0_0(&children[0]),
-            696 => self.module_declaration_opt0_1(),
-            697 => self.module_declaration_opt_0(&children[0]),
-            698 => self.module_declaration_opt_1(),
-            699 => self.module_if_declaration(
+            776 => self.extern_module_declaration_opt0_0(&children[0]),
+            777 => self.extern_module_declaration_opt0_1(),
+            778 => self.extern_module_declaration_opt_0(&children[0]),
+            779 => self.extern_module_declaration_opt_1(),
+            780 => self.module_if_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            700 => self.module_if_declaration_list_0(
+            781 => self.module_if_declaration_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            701 => self.module_if_declaration_list_1(),
-            702 => self.module_if_declaration_opt_0(&children[0], &children[1]),
-            703 => self.module_if_declaration_opt_1(),
-            704 => self.module_for_declaration(
+            782 => self.module_if_declaration_list_1(),
+            783 => self.module_if_declaration_opt_0(&children[0], &children[1]),
+            784 => self.module_if_declaration_opt_1(),
+            785 => self.module_for_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -30275,79 +33796,85 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            705 => self.module_for_declaration_opt_0(&children[0], &children[1], &children[2]),
-            706 => self.module_for_declaration_opt_1(),
-            707 => self.module_named_block(
+            786 => self.module_for_declaration_opt_0(&children[0], &children[1], &children[2]),
+            787 => self.module_for_declaration_opt_1(),
+            788 => self.module_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            708 => self.module_named_block_list_0(&children[0], &children[1]),
-            709 => self.module_named_block_list_1(),
-            710 => self.module_optional_named_block(
+            789 => self.module_named_block_list_0(&children[0], &children[1]),
+            790 => self.module_named_block_list_1(),
+            791 => self.module_optional_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            711 => self.module_optional_named_block_list_0(&children[0], &children[1]),
-            712 => self.module_optional_named_block_list_1(),
-            713 => self.module_optional_named_block_opt_0(&children[0], &children[1]),
-            714 => self.module_optional_named_block_opt_1(),
-            715 => self.module_group(&children[0], &children[1]),
-            716 => self.module_group_group_0(&children[0], &children[1], &children[2]),
-            717 => self.module_group_group_list_0(&children[0], &children[1]),
-            718 => self.module_group_group_list_1(),
-            719 => self.module_group_group_1(&children[0]),
-            720 => self.module_group_list_0(&children[0], &children[1]),
-            721 => self.module_group_list_1(),
-            722 => self.module_item_0(&children[0]),
-            723 => self.module_item_1(&children[0]),
-            724 => self.module_item_2(&children[0]),
-            725 => self.module_item_3(&children[0]),
-            726 => self.module_item_4(&children[0]),
-            727 => self.module_item_5(&children[0]),
-            728 => self.module_item_6(&children[0]),
-            729 => self.module_item_7(&children[0]),
-            730 => self.module_item_8(&children[0]),
-            731 => self.module_item_9(&children[0]),
-            732 => self.module_item_10(&children[0]),
-            733 => self.module_item_11(&children[0]),
-            734 => self.module_item_12(&children[0]),
-            735 => self.module_item_13(&children[0]),
-            736 => self.module_item_14(&children[0]),
-            737 => self.interface_declaration(
+            792 => self.module_optional_named_block_list_0(&children[0], &children[1]),
+            793 => self.module_optional_named_block_list_1(),
+            794 => self.module_optional_named_block_opt_0(&children[0], &children[1]),
+            795 => self.module_optional_named_block_opt_1(),
+            796 => self.module_group(&children[0], &children[1]),
+            797 => self.module_group_group_0(&children[0], &children[1], &children[2]),
+            798 => self.module_group_group_list_0(&children[0], &children[1]),
+            799 => self.module_group_group_list_1(),
+            800 => self.module_group_group_1(&children[0]),
+            801 => self.module_group_list_0(&children[0], &children[1]),
+            802 => self.module_group_list_1(),
+            803 => self.module_item_0(&children[0]),
+            804 => self.module_item_1(&children[0]),
+            805 => self.module_item_2(&children[0]),
+            806 => self.module_item_3(&children[0]),
+            807 => self.module_item_4(&children[0]),
+            808 => self.module_item_5(&children[0]),
+            809 => self.module_item_6(&children[0]),
+            810 => self.module_item_7(&children[0]),
+            811 => self.module_item_8(&children[0]),
+            812 => self.module_item_9(&children[0]),
+            813 => self.module_item_10(&children[0]),
+            814 => self.module_item_11(&children[0]),
+            815 => self.module_item_12(&children[0]),
+            816 => self.module_item_13(&children[0]),
+            817 => self.module_item_14(&children[0]),
+            818 => self.module_item_15(&children[0]),
+            819 => self.module_item_16(&children[0]),
+            820 => self.module_item_17(&children[0]),
+            821 => self.interface_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
                 &children[5],
+                &children[6],
             ),
-            738 => self.interface_declaration_list_0(&children[0], &children[1]),
-            739 => self.interface_declaration_list_1(),
-            740 => self.interface_declaration_opt_0(&children[0]),
-            741 => self.interface_declaration_opt_1(),
-            742 => self.interface_if_declaration(
+            822 => self.interface_declaration_list_0(&children[0], &children[1]),
+            823 => self.interface_declaration_list_1(),
+            824 => self.interface_declaration_opt0_0(&children[0]),
+            825 => self.interface_declaration_opt0_1(),
+            826 => self.interface_declaration_opt_0(&children[0]),
+            827 => self.interface_declaration_opt_1(),
+            828 => self.interface_if_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            743 => self.interface_if_declaration_list_0(
+            829 => self.interface_if_declaration_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            744 => self.interface_if_declaration_list_1(),
-            745 => self.interface_if_declaration_opt_0(&children[0], &children[1]),
-            746 => self.interface_if_declaration_opt_1(),
-            747 => self.interface_for_declaration(
+            830 => self.interface_if_declaration_list_1(),
+            831 => self.interface_if_declaration_opt_0(&children[0], &children[1]),
+            832 => self.interface_if_declaration_opt_1(),
+            833 => self.interface_for_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -30355,85 +33882,95 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            748 => self.interface_for_declaration_opt_0(&children[0], &children[1], &children[2]),
-            749 => self.interface_for_declaration_opt_1(),
-            750 => self.interface_named_block(
+            834 => self.interface_for_declaration_opt_0(&children[0], &children[1], &children[2]),
+            835 => self.interface_for_declaration_opt_1(),
+            836 => self.interface_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            751 => self.interface_named_block_list_0(&children[0], &children[1]),
-            752 => self.interface_named_block_list_1(),
-            753 => self.interface_optional_named_block(
+            837 => self.interface_named_block_list_0(&children[0], &children[1]),
+            838 => self.interface_named_block_list_1(),
+            839 => self.interface_optional_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            754 => self.interface_optional_named_block_list_0(&children[0], &children[1]),
-            755 => self.interface_optional_named_block_list_1(),
-            756 => self.interface_optional_named_block_opt_0(&children[0], &children[1]),
-            757 => self.interface_optional_named_block_opt_1(),
-            758 => self.interface_group(&children[0], &children[1]),
-            759 => self.interface_group_group_0(&children[0], &children[1], &children[2]),
-            760 => self.interface_group_group_list_0(&children[0], &children[1]),
-            761 => self.interface_group_group_list_1(),
-            762 => self.interface_group_group_1(&children[0]),
-            763 => self.interface_group_list_0(&children[0], &children[1]),
-            764 => self.interface_group_list_1(),
-            765 => self.interface_item_0(&children[0]),
-            766 => self.interface_item_1(&children[0]),
-            767 => self.interface_item_2(&children[0]),
-            768 => self.interface_item_3(&children[0]),
-            769 => self.interface_item_4(&children[0]),
-            770 => self.interface_item_5(&children[0]),
-            771 => self.interface_item_6(&children[0]),
-            772 => self.interface_item_7(&children[0]),
-            773 => self.interface_item_8(&children[0]),
-            774 => self.interface_item_9(&children[0]),
-            775 => self.interface_item_10(&children[0]),
-            776 => self.interface_item_11(&children[0]),
-            777 => self.package_declaration(
+            840 => self.interface_optional_named_block_list_0(&children[0], &children[1]),
+            841 => self.interface_optional_named_block_list_1(),
+            842 => self.interface_optional_named_block_opt_0(&children[0], &children[1]),
+            843 => self.interface_optional_named_block_opt_1(),
+            844 => self.interface_group(&children[0], &children[1]),
+            845 => self.interface_group_group_0(&children[0], &children[1], &children[2]),
+            846 => self.interface_group_group_list_0(&children[0], &children[1]),
+            847 => self.interface_group_group_list_1(),
+            848 => self.interface_group_group_1(&children[0]),
+            849 => self.interface_group_list_0(&children[0], &children[1]),
+            850 => self.interface_group_list_1(),
+            851 => self.interface_item_0(&children[0]),
+            852 => self.interface_item_1(&children[0]),
+            853 => self.interface_item_2(&children[0]),
+            854 => self.interface_item_3(&children[0]),
+            855 => self.interface_item_4(&children[0]),
+            856 => self.interface_item_5(&children[0]),
+            857 => self.interface_item_6(&children[0]),
+            858 => self.interface_item_7(&children[0]),
+            859 => self.interface_item_8(&children[0]),
+            860 => self.interface_item_9(&children[0]),
+            861 => self.interface_item_10(&children[0]),
+            862 => self.interface_item_11(&children[0]),
+            863 => self.interface_item_12(&children[0]),
+            864 => self.interface_item_13(&children[0]),
+            865 => self.interface_item_14(&children[0]),
+            866 => self.package_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
+                &children[5],
             ),
-            778 => self.package_declaration_list_0(&children[0], &children[1]),
-            779 => self.package_declaration_list_1(),
-            780 => self.package_group(&children[0], &children[1]),
-            781 => self.package_group_group_0(&children[0], &children[1], &children[2]),
-            782 => self.package_group_group_list_0(&children[0], &children[1]),
-            783 => self.package_group_group_list_1(),
-            784 => self.package_group_group_1(&children[0]),
-            785 => self.package_group_list_0(&children[0], &children[1]),
-            786 => self.package_group_list_1(),
-            787 => self.package_item_0(&children[0]),
-            788 => self.package_item_1(&children[0]),
-            789 => self.package_item_2(&children[0]),
-            790 => self.package_item_3(&children[0]),
-            791 => self.package_item_4(&children[0]),
-            792 => self.package_item_5(&children[0]),
-            793 => self.package_item_6(&children[0]),
-            794 => self.package_item_7(&children[0]),
-            795 => self.package_item_8(&children[0]),
-            796 => self.description_group(&children[0], &children[1]),
-            797 => self.description_group_group_0(&children[0], &children[1], &children[2]),
-            798 => self.description_group_group_list_0(&children[0], &children[1]),
-            799 => self.description_group_group_list_1(),
-            800 => self.description_group_group_1(&children[0]),
-            801 => self.description_group_list_0(&children[0], &children[1]),
-            802 => self.description_group_list_1(),
-            803 => self.description_item_0(&children[0]),
-            804 => self.description_item_1(&children[0]),
-            805 => self.description_item_2(&children[0]),
-            806 => self.description_item_3(&children[0]),
-            807 => self.veryl(&children[0], &children[1]),
-            808 => self.veryl_list_0(&children[0], &children[1]),
-            809 => self.veryl_list_1(),
+            867 => self.package_declaration_list_0(&children[0], &children[1]),
+            868 => self.package_declaration_list_1(),
+            869 => self.package_declaration_opt_0(&children[0]),
+            870 => self.package_declaration_opt_1(),
+            871 => self.package_group(&children[0], &children[1]),
+            872 => self.package_group_group_0(&children[0], &children[1], &children[2]),
+            873 => self.package_group_group_list_0(&children[0], &children[1]),
+            874 => self.package_group_group_list_1(),
+            875 => self.package_group_group_1(&children[0]),
+            876 => self.package_group_list_0(&children[0], &children[1]),
+            877 => self.package_group_list_1(),
+            878 => self.package_item_0(&children[0]),
+            879 => self.package_item_1(&children[0]),
+            880 => self.package_item_2(&children[0]),
+            881 => self.package_item_3(&children[0]),
+            882 => self.package_item_4(&children[0]),
+            883 => self.package_item_5(&children[0]),
+            884 => self.package_item_6(&children[0]),
+            885 => self.package_item_7(&children[0]),
+            886 => self.package_item_8(&children[0]),
+            887 => self.package_item_9(&children[0]),
+            888 => self.package_item_10(&children[0]),
+            889 => self.package_item_11(&children[0]),
+            890 => self.description_group(&children[0], &children[1]),
+            891 => self.description_group_group_0(&children[0], &children[1], &children[2]),
+            892 => self.description_group_group_list_0(&children[0], &children[1]),
+            893 => self.description_group_group_list_1(),
+            894 => self.description_group_group_1(&children[0]),
+            895 => self.description_group_list_0(&children[0], &children[1]),
+            896 => self.description_group_list_1(),
+            897 => self.description_item_0(&children[0]),
+            898 => self.description_item_1(&children[0]),
+            899 => self.description_item_2(&children[0]),
+            900 => self.description_item_3(&children[0]),
+            901 => self.description_item_4(&children[0]),
+            902 => self.veryl(&children[0], &children[1]),
+            903 => self.veryl_list_0(&children[0], &children[1]),
+            904 => self.veryl_list_1(),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {}",
                 prod_num
@@ -148,3 +148,12 @@ fn assignment_statement() {
     success("always_comb { a <<<= 1; }");
     success("always_comb { a >>>= 1; }");
 }
+
+#[test]
+fn embed() {
+    success("embed (inline) sv{{{ }}}");
+    success("embed (inline) sv{{{ assign a = {b, c}; }}}");
+    success("embed (inline) sv{{{\n    // comment with { braces }\n}}}");
+    failure("embed (inline) sv{{{ { }}}");
+    failure("embed (inline) sv{{ }}");
+}
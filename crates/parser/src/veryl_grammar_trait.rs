@@ -73,6 +73,51 @@ impl From<&AttributeList> for Vec<AttributeItem> {
     }
 }
 
+impl AttributeItem {
+    /// Returns the identifier if the item is a single identifier without value
+    pub fn identifier(&self) -> Option<&Identifier> {
+        match self {
+            AttributeItem::IdentifierAttributeItemOpt(x) if x.attribute_item_opt.is_none() => {
+                Some(&x.identifier)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<&ArgumentList> for Vec<ArgumentItem> {
+    fn from(x: &ArgumentList) -> Self {
+        let mut ret = Vec::new();
+        ret.push(x.argument_item.as_ref().clone());
+        for x in &x.argument_list_list {
+            ret.push(x.argument_item.as_ref().clone());
+        }
+        ret
+    }
+}
+
+impl From<&WithGenericParameterList> for Vec<WithGenericParameterItem> {
+    fn from(x: &WithGenericParameterList) -> Self {
+        let mut ret = Vec::new();
+        ret.push(x.with_generic_parameter_item.as_ref().clone());
+        for x in &x.with_generic_parameter_list_list {
+            ret.push(x.with_generic_parameter_item.as_ref().clone());
+        }
+        ret
+    }
+}
+
+impl From<&WithGenericArgumentList> for Vec<WithGenericArgumentItem> {
+    fn from(x: &WithGenericArgumentList) -> Self {
+        let mut ret = Vec::new();
+        ret.push(x.with_generic_argument_item.as_ref().clone());
+        for x in &x.with_generic_argument_list_list {
+            ret.push(x.with_generic_argument_item.as_ref().clone());
+        }
+        ret
+    }
+}
+
 list_to_item!(Modport);
 list_to_item!(Enum);
 list_to_item!(Struct);
@@ -84,3 +129,56 @@ group_to_item!(Module);
 group_to_item!(Interface);
 group_to_item!(Package);
 group_to_item!(Description);
+
+/// Identifier and generic argument of each segment of scoped path
+pub type GenericSegment = (Identifier, Option<WithGenericArgument>);
+
+impl From<&ScopedIdentifier> for Vec<GenericSegment> {
+    fn from(x: &ScopedIdentifier) -> Self {
+        let mut ret = Vec::new();
+        ret.push((
+            x.identifier.as_ref().clone(),
+            x.scoped_identifier_opt
+                .as_ref()
+                .map(|x| x.with_generic_argument.as_ref().clone()),
+        ));
+        for x in &x.scoped_identifier_list {
+            ret.push((
+                x.identifier.as_ref().clone(),
+                x.scoped_identifier_opt0
+                    .as_ref()
+                    .map(|x| x.with_generic_argument.as_ref().clone()),
+            ));
+        }
+        ret
+    }
+}
+
+impl From<&ExpressionIdentifier> for Vec<GenericSegment> {
+    fn from(x: &ExpressionIdentifier) -> Self {
+        let mut ret = Vec::new();
+        ret.push((
+            x.identifier.as_ref().clone(),
+            x.expression_identifier_opt0
+                .as_ref()
+                .map(|x| x.with_generic_argument.as_ref().clone()),
+        ));
+        if let ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) = x.expression_identifier_group.as_ref() {
+            ret.push((
+                x.identifier.as_ref().clone(),
+                x.expression_identifier_opt1
+                    .as_ref()
+                    .map(|x| x.with_generic_argument.as_ref().clone()),
+            ));
+            for x in &x.expression_identifier_group_list {
+                ret.push((
+                    x.identifier.as_ref().clone(),
+                    x.expression_identifier_opt2
+                        .as_ref()
+                        .map(|x| x.with_generic_argument.as_ref().clone()),
+                ));
+            }
+        }
+        ret
+    }
+}
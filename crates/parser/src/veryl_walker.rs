@@ -27,6 +27,11 @@ pub trait VerylWalker {
     /// Semantic action for non-terminal 'VerylToken'
     fn veryl_token(&mut self, _arg: &VerylToken) {}
 
+    /// Returns whether the group annotated by the attribute is walked
+    fn is_enabled(&mut self, _arg: &Attribute) -> bool {
+        true
+    }
+
     /// Semantic action for non-terminal 'Start'
     fn start(&mut self, arg: &Start) {
         before!(self, start, arg);
@@ -174,6 +179,13 @@ pub trait VerylWalker {
         after!(self, colon, arg);
     }
 
+    /// Semantic action for non-terminal 'ColonColonLAngle'
+    fn colon_colon_l_angle(&mut self, arg: &ColonColonLAngle) {
+        before!(self, colon_colon_l_angle, arg);
+        self.veryl_token(&arg.colon_colon_l_angle_token);
+        after!(self, colon_colon_l_angle, arg);
+    }
+
     /// Semantic action for non-terminal 'ColonColon'
     fn colon_colon(&mut self, arg: &ColonColon) {
         before!(self, colon_colon, arg);
@@ -279,6 +291,13 @@ pub trait VerylWalker {
         after!(self, plus_colon, arg);
     }
 
+    /// Semantic action for non-terminal 'Quote'
+    fn quote(&mut self, arg: &Quote) {
+        before!(self, quote, arg);
+        self.veryl_token(&arg.quote_token);
+        after!(self, quote, arg);
+    }
+
     /// Semantic action for non-terminal 'RAngle'
     fn r_angle(&mut self, arg: &RAngle) {
         before!(self, r_angle, arg);
@@ -377,6 +396,13 @@ pub trait VerylWalker {
         after!(self, case, arg);
     }
 
+    /// Semantic action for non-terminal 'Clock'
+    fn clock(&mut self, arg: &Clock) {
+        before!(self, clock, arg);
+        self.veryl_token(&arg.clock_token);
+        after!(self, clock, arg);
+    }
+
     /// Semantic action for non-terminal 'Defaul'
     fn defaul(&mut self, arg: &Defaul) {
         before!(self, defaul, arg);
@@ -391,6 +417,13 @@ pub trait VerylWalker {
         after!(self, r#else, arg);
     }
 
+    /// Semantic action for non-terminal 'Embed'
+    fn embed(&mut self, arg: &Embed) {
+        before!(self, embed, arg);
+        self.veryl_token(&arg.embed_token);
+        after!(self, embed, arg);
+    }
+
     /// Semantic action for non-terminal 'Enum'
     fn r#enum(&mut self, arg: &Enum) {
         before!(self, r#enum, arg);
@@ -405,6 +438,13 @@ pub trait VerylWalker {
         after!(self, export, arg);
     }
 
+    /// Semantic action for non-terminal 'Extern'
+    fn r#extern(&mut self, arg: &Extern) {
+        before!(self, r#extern, arg);
+        self.veryl_token(&arg.extern_token);
+        after!(self, r#extern, arg);
+    }
+
     /// Semantic action for non-terminal 'F32'
     fn f32(&mut self, arg: &F32) {
         before!(self, f32, arg);
@@ -475,6 +515,13 @@ pub trait VerylWalker {
         after!(self, import, arg);
     }
 
+    /// Semantic action for non-terminal 'Include'
+    fn include(&mut self, arg: &Include) {
+        before!(self, include, arg);
+        self.veryl_token(&arg.include_token);
+        after!(self, include, arg);
+    }
+
     /// Semantic action for non-terminal 'In'
     fn r#in(&mut self, arg: &In) {
         before!(self, r#in, arg);
@@ -622,6 +669,13 @@ pub trait VerylWalker {
         after!(self, repeat, arg);
     }
 
+    /// Semantic action for non-terminal 'Reset'
+    fn reset(&mut self, arg: &Reset) {
+        before!(self, reset, arg);
+        self.veryl_token(&arg.reset_token);
+        after!(self, reset, arg);
+    }
+
     /// Semantic action for non-terminal 'Return'
     fn r#return(&mut self, arg: &Return) {
         before!(self, r#return, arg);
@@ -685,6 +739,13 @@ pub trait VerylWalker {
         after!(self, r#type, arg);
     }
 
+    /// Semantic action for non-terminal 'Union'
+    fn union(&mut self, arg: &Union) {
+        before!(self, union, arg);
+        self.veryl_token(&arg.union_token);
+        after!(self, union, arg);
+    }
+
     /// Semantic action for non-terminal 'U32'
     fn u32(&mut self, arg: &U32) {
         before!(self, u32, arg);
@@ -713,6 +774,13 @@ pub trait VerylWalker {
         after!(self, identifier, arg);
     }
 
+    /// Semantic action for non-terminal 'EmbedContent'
+    fn embed_content(&mut self, arg: &EmbedContent) {
+        before!(self, embed_content, arg);
+        self.veryl_token(&arg.embed_content_token);
+        after!(self, embed_content, arg);
+    }
+
     /// Semantic action for non-terminal 'Number'
     fn number(&mut self, arg: &Number) {
         before!(self, number, arg);
@@ -765,9 +833,15 @@ pub trait VerylWalker {
     fn scoped_identifier(&mut self, arg: &ScopedIdentifier) {
         before!(self, scoped_identifier, arg);
         self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.scoped_identifier_opt {
+            self.with_generic_argument(&x.with_generic_argument);
+        }
         for x in &arg.scoped_identifier_list {
             self.colon_colon(&x.colon_colon);
             self.identifier(&x.identifier);
+            if let Some(ref x) = x.scoped_identifier_opt0 {
+                self.with_generic_argument(&x.with_generic_argument);
+            }
         }
         after!(self, scoped_identifier, arg);
     }
@@ -779,13 +853,22 @@ pub trait VerylWalker {
             self.dollar(&x.dollar);
         }
         self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.expression_identifier_opt0 {
+            self.with_generic_argument(&x.with_generic_argument);
+        }
         match &*arg.expression_identifier_group {
-            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
+            ExpressionIdentifierGroup::ColonColonIdentifierExpressionIdentifierOpt1ExpressionIdentifierGroupListExpressionIdentifierGroupList0(x) => {
                 self.colon_colon(&x.colon_colon);
                 self.identifier(&x.identifier);
+                if let Some(ref x) = x.expression_identifier_opt1 {
+                    self.with_generic_argument(&x.with_generic_argument);
+                }
                 for x in &x.expression_identifier_group_list {
                     self.colon_colon(&x.colon_colon);
                     self.identifier(&x.identifier);
+                    if let Some(ref x) = x.expression_identifier_opt2 {
+                        self.with_generic_argument(&x.with_generic_argument);
+                    }
                 }
                 for x in &x.expression_identifier_group_list0 {
                     self.select(&x.select);
@@ -1257,6 +1340,8 @@ pub trait VerylWalker {
     fn variable_type(&mut self, arg: &VariableType) {
         before!(self, variable_type, arg);
         match &*arg.variable_type_group {
+            VariableTypeGroup::Clock(x) => self.clock(&x.clock),
+            VariableTypeGroup::Reset(x) => self.reset(&x.reset),
             VariableTypeGroup::Logic(x) => self.logic(&x.logic),
             VariableTypeGroup::Bit(x) => self.bit(&x.bit),
             VariableTypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
@@ -1300,6 +1385,14 @@ pub trait VerylWalker {
         after!(self, array_type, arg);
     }
 
+    /// Semantic action for non-terminal 'ClockDomain'
+    fn clock_domain(&mut self, arg: &ClockDomain) {
+        before!(self, clock_domain, arg);
+        self.quote(&arg.quote);
+        self.identifier(&arg.identifier);
+        after!(self, clock_domain, arg);
+    }
+
     /// Semantic action for non-terminal 'Statement'
     fn statement(&mut self, arg: &Statement) {
         before!(self, statement, arg);
@@ -1502,7 +1595,22 @@ pub trait VerylWalker {
     fn attribute_item(&mut self, arg: &AttributeItem) {
         before!(self, attribute_item, arg);
         match arg {
-            AttributeItem::Identifier(x) => self.identifier(&x.identifier),
+            AttributeItem::IdentifierAttributeItemOpt(x) => {
+                self.identifier(&x.identifier);
+                if let Some(ref x) = x.attribute_item_opt {
+                    match &*x.attribute_item_opt_group {
+                        AttributeItemOptGroup::EquStringLiteral(x) => {
+                            self.equ(&x.equ);
+                            self.string_literal(&x.string_literal);
+                        }
+                        AttributeItemOptGroup::LParenAttributeListRParen(x) => {
+                            self.l_paren(&x.l_paren);
+                            self.attribute_list(&x.attribute_list);
+                            self.r_paren(&x.r_paren);
+                        }
+                    }
+                }
+            }
             AttributeItem::StringLiteral(x) => self.string_literal(&x.string_literal),
         }
         after!(self, attribute_item, arg);
@@ -1514,8 +1622,11 @@ pub trait VerylWalker {
         self.var(&arg.var);
         self.identifier(&arg.identifier);
         self.colon(&arg.colon);
-        self.array_type(&arg.array_type);
         if let Some(ref x) = arg.var_declaration_opt {
+            self.clock_domain(&x.clock_domain);
+        }
+        self.array_type(&arg.array_type);
+        if let Some(ref x) = arg.var_declaration_opt0 {
             self.equ(&x.equ);
             self.expression(&x.expression);
         }
@@ -1772,6 +1883,17 @@ pub trait VerylWalker {
         after!(self, struct_item, arg);
     }
 
+    /// Semantic action for non-terminal 'UnionDeclaration'
+    fn union_declaration(&mut self, arg: &UnionDeclaration) {
+        before!(self, union_declaration, arg);
+        self.union(&arg.union);
+        self.identifier(&arg.identifier);
+        self.l_brace(&arg.l_brace);
+        self.struct_list(&arg.struct_list);
+        self.r_brace(&arg.r_brace);
+        after!(self, union_declaration, arg);
+    }
+
     /// Semantic action for non-terminal 'InitialDeclaration'
     fn initial_declaration(&mut self, arg: &InitialDeclaration) {
         before!(self, initial_declaration, arg);
@@ -1987,6 +2109,71 @@ pub trait VerylWalker {
         after!(self, with_parameter_item, arg);
     }
 
+    /// Semantic action for non-terminal 'WithGenericParameter'
+    fn with_generic_parameter(&mut self, arg: &WithGenericParameter) {
+        before!(self, with_generic_parameter, arg);
+        self.colon_colon_l_angle(&arg.colon_colon_l_angle);
+        self.with_generic_parameter_list(&arg.with_generic_parameter_list);
+        self.r_angle(&arg.r_angle);
+        after!(self, with_generic_parameter, arg);
+    }
+
+    /// Semantic action for non-terminal 'WithGenericParameterList'
+    fn with_generic_parameter_list(&mut self, arg: &WithGenericParameterList) {
+        before!(self, with_generic_parameter_list, arg);
+        self.with_generic_parameter_item(&arg.with_generic_parameter_item);
+        for x in &arg.with_generic_parameter_list_list {
+            self.comma(&x.comma);
+            self.with_generic_parameter_item(&x.with_generic_parameter_item);
+        }
+        if let Some(ref x) = arg.with_generic_parameter_list_opt {
+            self.comma(&x.comma);
+        }
+        after!(self, with_generic_parameter_list, arg);
+    }
+
+    /// Semantic action for non-terminal 'WithGenericParameterItem'
+    fn with_generic_parameter_item(&mut self, arg: &WithGenericParameterItem) {
+        before!(self, with_generic_parameter_item, arg);
+        self.identifier(&arg.identifier);
+        after!(self, with_generic_parameter_item, arg);
+    }
+
+    /// Semantic action for non-terminal 'WithGenericArgument'
+    fn with_generic_argument(&mut self, arg: &WithGenericArgument) {
+        before!(self, with_generic_argument, arg);
+        self.colon_colon_l_angle(&arg.colon_colon_l_angle);
+        self.with_generic_argument_list(&arg.with_generic_argument_list);
+        self.r_angle(&arg.r_angle);
+        after!(self, with_generic_argument, arg);
+    }
+
+    /// Semantic action for non-terminal 'WithGenericArgumentList'
+    fn with_generic_argument_list(&mut self, arg: &WithGenericArgumentList) {
+        before!(self, with_generic_argument_list, arg);
+        self.with_generic_argument_item(&arg.with_generic_argument_item);
+        for x in &arg.with_generic_argument_list_list {
+            self.comma(&x.comma);
+            self.with_generic_argument_item(&x.with_generic_argument_item);
+        }
+        if let Some(ref x) = arg.with_generic_argument_list_opt {
+            self.comma(&x.comma);
+        }
+        after!(self, with_generic_argument_list, arg);
+    }
+
+    /// Semantic action for non-terminal 'WithGenericArgumentItem'
+    fn with_generic_argument_item(&mut self, arg: &WithGenericArgumentItem) {
+        before!(self, with_generic_argument_item, arg);
+        match arg {
+            WithGenericArgumentItem::ScopedIdentifier(x) => {
+                self.scoped_identifier(&x.scoped_identifier)
+            }
+            WithGenericArgumentItem::Number(x) => self.number(&x.number),
+        };
+        after!(self, with_generic_argument_item, arg);
+    }
+
     /// Semantic action for non-terminal 'PortDeclaration'
     fn port_declaration(&mut self, arg: &PortDeclaration) {
         before!(self, port_declaration, arg);
@@ -2037,13 +2224,16 @@ pub trait VerylWalker {
         self.identifier(&arg.identifier);
         self.colon(&arg.colon);
         match &*arg.port_declaration_item_group {
-            PortDeclarationItemGroup::DirectionArrayType(x) => {
+            PortDeclarationItemGroup::DirectionPortDeclarationItemOptArrayType(x) => {
                 self.direction(&x.direction);
+                if let Some(ref x) = x.port_declaration_item_opt {
+                    self.clock_domain(&x.clock_domain);
+                }
                 self.array_type(&x.array_type);
             }
-            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt(x) => {
+            PortDeclarationItemGroup::InterfacePortDeclarationItemOpt0(x) => {
                 self.interface(&x.interface);
-                if let Some(ref x) = x.port_declaration_item_opt {
+                if let Some(ref x) = x.port_declaration_item_opt0 {
                     self.array(&x.array);
                 }
             }
@@ -2070,9 +2260,12 @@ pub trait VerylWalker {
         self.function(&arg.function);
         self.identifier(&arg.identifier);
         if let Some(ref x) = arg.function_declaration_opt {
-            self.with_parameter(&x.with_parameter);
+            self.with_generic_parameter(&x.with_generic_parameter);
         }
         if let Some(ref x) = arg.function_declaration_opt0 {
+            self.with_parameter(&x.with_parameter);
+        }
+        if let Some(ref x) = arg.function_declaration_opt1 {
             self.port_declaration(&x.port_declaration);
         }
         self.minus_g_t(&arg.minus_g_t);
@@ -2126,15 +2319,43 @@ pub trait VerylWalker {
         after!(self, export_declaration, arg);
     }
 
+    /// Semantic action for non-terminal 'EmbedDeclaration'
+    fn embed_declaration(&mut self, arg: &EmbedDeclaration) {
+        before!(self, embed_declaration, arg);
+        self.embed(&arg.embed);
+        self.l_paren(&arg.l_paren);
+        self.identifier(&arg.identifier);
+        self.r_paren(&arg.r_paren);
+        self.identifier(&arg.identifier0);
+        self.embed_content(&arg.embed_content);
+        after!(self, embed_declaration, arg);
+    }
+
+    /// Semantic action for non-terminal 'IncludeDeclaration'
+    fn include_declaration(&mut self, arg: &IncludeDeclaration) {
+        before!(self, include_declaration, arg);
+        self.include(&arg.include);
+        self.l_paren(&arg.l_paren);
+        self.identifier(&arg.identifier);
+        self.comma(&arg.comma);
+        self.string_literal(&arg.string_literal);
+        self.r_paren(&arg.r_paren);
+        self.semicolon(&arg.semicolon);
+        after!(self, include_declaration, arg);
+    }
+
     /// Semantic action for non-terminal 'ModuleDeclaration'
     fn module_declaration(&mut self, arg: &ModuleDeclaration) {
         before!(self, module_declaration, arg);
         self.module(&arg.module);
         self.identifier(&arg.identifier);
         if let Some(ref x) = arg.module_declaration_opt {
-            self.with_parameter(&x.with_parameter);
+            self.with_generic_parameter(&x.with_generic_parameter);
         }
         if let Some(ref x) = arg.module_declaration_opt0 {
+            self.with_parameter(&x.with_parameter);
+        }
+        if let Some(ref x) = arg.module_declaration_opt1 {
             self.port_declaration(&x.port_declaration);
         }
         self.l_brace(&arg.l_brace);
@@ -2145,6 +2366,22 @@ pub trait VerylWalker {
         after!(self, module_declaration, arg);
     }
 
+    /// Semantic action for non-terminal 'ExternModuleDeclaration'
+    fn extern_module_declaration(&mut self, arg: &ExternModuleDeclaration) {
+        before!(self, extern_module_declaration, arg);
+        self.r#extern(&arg.r#extern);
+        self.module(&arg.module);
+        self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.extern_module_declaration_opt {
+            self.with_parameter(&x.with_parameter);
+        }
+        if let Some(ref x) = arg.extern_module_declaration_opt0 {
+            self.port_declaration(&x.port_declaration);
+        }
+        self.semicolon(&arg.semicolon);
+        after!(self, extern_module_declaration, arg);
+    }
+
     /// Semantic action for non-terminal 'ModuleIfDeclaration'
     fn module_if_declaration(&mut self, arg: &ModuleIfDeclaration) {
         before!(self, module_if_declaration, arg);
@@ -2210,6 +2447,13 @@ pub trait VerylWalker {
 
     /// Semantic action for non-terminal 'ModuleGroup'
     fn module_group(&mut self, arg: &ModuleGroup) {
+        if !arg
+            .module_group_list
+            .iter()
+            .all(|x| self.is_enabled(&x.attribute))
+        {
+            return;
+        }
         before!(self, module_group, arg);
         for x in &arg.module_group_list {
             self.attribute(&x.attribute);
@@ -2254,10 +2498,13 @@ pub trait VerylWalker {
             }
             ModuleItem::EnumDeclaration(x) => self.enum_declaration(&x.enum_declaration),
             ModuleItem::StructDeclaration(x) => self.struct_declaration(&x.struct_declaration),
+            ModuleItem::UnionDeclaration(x) => self.union_declaration(&x.union_declaration),
             ModuleItem::ModuleNamedBlock(x) => self.module_named_block(&x.module_named_block),
             ModuleItem::ImportDeclaration(x) => self.import_declaration(&x.import_declaration),
             ModuleItem::InitialDeclaration(x) => self.initial_declaration(&x.initial_declaration),
             ModuleItem::FinalDeclaration(x) => self.final_declaration(&x.final_declaration),
+            ModuleItem::EmbedDeclaration(x) => self.embed_declaration(&x.embed_declaration),
+            ModuleItem::IncludeDeclaration(x) => self.include_declaration(&x.include_declaration),
         };
         after!(self, module_item, arg);
     }
@@ -2268,6 +2515,9 @@ pub trait VerylWalker {
         self.interface(&arg.interface);
         self.identifier(&arg.identifier);
         if let Some(ref x) = arg.interface_declaration_opt {
+            self.with_generic_parameter(&x.with_generic_parameter);
+        }
+        if let Some(ref x) = arg.interface_declaration_opt0 {
             self.with_parameter(&x.with_parameter);
         }
         self.l_brace(&arg.l_brace);
@@ -2343,6 +2593,13 @@ pub trait VerylWalker {
 
     /// Semantic action for non-terminal 'InterfaceGroup'
     fn interface_group(&mut self, arg: &InterfaceGroup) {
+        if !arg
+            .interface_group_list
+            .iter()
+            .all(|x| self.is_enabled(&x.attribute))
+        {
+            return;
+        }
         before!(self, interface_group, arg);
         for x in &arg.interface_group_list {
             self.attribute(&x.attribute);
@@ -2379,6 +2636,7 @@ pub trait VerylWalker {
             }
             InterfaceItem::EnumDeclaration(x) => self.enum_declaration(&x.enum_declaration),
             InterfaceItem::StructDeclaration(x) => self.struct_declaration(&x.struct_declaration),
+            InterfaceItem::UnionDeclaration(x) => self.union_declaration(&x.union_declaration),
             InterfaceItem::InterfaceNamedBlock(x) => {
                 self.interface_named_block(&x.interface_named_block)
             }
@@ -2390,6 +2648,10 @@ pub trait VerylWalker {
                 self.initial_declaration(&x.initial_declaration)
             }
             InterfaceItem::FinalDeclaration(x) => self.final_declaration(&x.final_declaration),
+            InterfaceItem::EmbedDeclaration(x) => self.embed_declaration(&x.embed_declaration),
+            InterfaceItem::IncludeDeclaration(x) => {
+                self.include_declaration(&x.include_declaration)
+            }
         };
         after!(self, interface_item, arg);
     }
@@ -2399,6 +2661,9 @@ pub trait VerylWalker {
         before!(self, package_declaration, arg);
         self.package(&arg.package);
         self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.package_declaration_opt {
+            self.with_generic_parameter(&x.with_generic_parameter);
+        }
         self.l_brace(&arg.l_brace);
         for x in &arg.package_declaration_list {
             self.package_group(&x.package_group);
@@ -2409,6 +2674,13 @@ pub trait VerylWalker {
 
     /// Semantic action for non-terminal 'PackageGroup'
     fn package_group(&mut self, arg: &PackageGroup) {
+        if !arg
+            .package_group_list
+            .iter()
+            .all(|x| self.is_enabled(&x.attribute))
+        {
+            return;
+        }
         before!(self, package_group, arg);
         for x in &arg.package_group_list {
             self.attribute(&x.attribute);
@@ -2436,6 +2708,7 @@ pub trait VerylWalker {
             }
             PackageItem::EnumDeclaration(x) => self.enum_declaration(&x.enum_declaration),
             PackageItem::StructDeclaration(x) => self.struct_declaration(&x.struct_declaration),
+            PackageItem::UnionDeclaration(x) => self.union_declaration(&x.union_declaration),
             PackageItem::FunctionDeclaration(x) => {
                 self.function_declaration(&x.function_declaration)
             }
@@ -2443,12 +2716,21 @@ pub trait VerylWalker {
             PackageItem::ExportDeclaration(x) => self.export_declaration(&x.export_declaration),
             PackageItem::InitialDeclaration(x) => self.initial_declaration(&x.initial_declaration),
             PackageItem::FinalDeclaration(x) => self.final_declaration(&x.final_declaration),
+            PackageItem::EmbedDeclaration(x) => self.embed_declaration(&x.embed_declaration),
+            PackageItem::IncludeDeclaration(x) => self.include_declaration(&x.include_declaration),
         }
         after!(self, package_item, arg);
     }
 
     /// Semantic action for non-terminal 'DescriptionGroup'
     fn description_group(&mut self, arg: &DescriptionGroup) {
+        if !arg
+            .description_group_list
+            .iter()
+            .all(|x| self.is_enabled(&x.attribute))
+        {
+            return;
+        }
         before!(self, description_group, arg);
         for x in &arg.description_group_list {
             self.attribute(&x.attribute);
@@ -2471,6 +2753,9 @@ pub trait VerylWalker {
         before!(self, description_item, arg);
         match arg {
             DescriptionItem::ModuleDeclaration(x) => self.module_declaration(&x.module_declaration),
+            DescriptionItem::ExternModuleDeclaration(x) => {
+                self.extern_module_declaration(&x.extern_module_declaration)
+            }
             DescriptionItem::InterfaceDeclaration(x) => {
                 self.interface_declaration(&x.interface_declaration)
             }
@@ -131,3 +131,680 @@ mod emitter {
 
     include!(concat!(env!("OUT_DIR"), "/test.rs"));
 }
+
+#[cfg(test)]
+mod fixture {
+    use veryl_analyzer::Analyzer;
+    use veryl_emitter::Emitter;
+    use veryl_metadata::{Build, Metadata};
+    use veryl_parser::Parser;
+
+    /// Emits the code given as string with the build configuration modified by `build`
+    pub fn emit<F: FnOnce(&mut Build)>(name: &str, input: &str, build: F) -> Emitter {
+        let metadata_path = Metadata::search_from_current().unwrap();
+        let mut metadata = Metadata::load(&metadata_path).unwrap();
+        build(&mut metadata.build);
+
+        let file = format!("{name}.vl");
+        let ret = Parser::parse(input, &file).unwrap();
+        let prj = &metadata.project.name;
+        let analyzer = Analyzer::new(&prj, &metadata);
+        let _ = analyzer.analyze_pass1(input, &file, &ret.veryl);
+        let _ = analyzer.analyze_pass2(input, &file, &ret.veryl);
+        let mut emitter = Emitter::new(&metadata);
+        emitter.emit(&ret.veryl);
+        emitter
+    }
+}
+
+#[cfg(test)]
+mod verilog {
+    use crate::fixture;
+    use veryl_metadata::TargetLanguage;
+
+    fn test(name: &str, input: &str, expected: &str) {
+        let emitter = fixture::emit(name, input, |x| {
+            x.target_language = TargetLanguage::Verilog;
+        });
+        assert_eq!(expected, emitter.as_str());
+    }
+
+    #[test]
+    fn module() {
+        let input = r#"module VerilogModuleA (
+    i_clk: input  clock   ,
+    i_rst: input  reset   ,
+    i_a  : input  logic<8>,
+    o_b  : output logic<8>,
+    o_c  : output logic<8>,
+) {
+    var r: logic<8>;
+
+    always_ff (i_clk, i_rst) {
+        if_reset {
+            r = '0;
+        } else {
+            r += i_a;
+        }
+    }
+
+    always_comb {
+        o_b = r;
+    }
+
+    assign o_c = r;
+}
+"#;
+        let expected = r#"module veryl_testcase_VerilogModuleA (
+    input wire i_clk,
+    input wire i_rst,
+    input wire [7:0] i_a,
+    output reg [7:0] o_b,
+    output wire [7:0] o_c
+);
+    reg [7:0] r;
+
+    always @ (posedge i_clk or negedge i_rst) begin
+        if (!i_rst) begin
+            r <= 0;
+        end else begin
+            r <= r + (i_a);
+        end
+    end
+
+    always @* begin
+        o_b = r;
+    end
+
+    assign o_c = r;
+endmodule
+"#;
+        test("module", input, expected);
+    }
+
+    #[test]
+    fn package() {
+        let input = r#"package VerilogPackageB {
+    localparam W: u32 = 4;
+    enum Kind: logic<2> {
+        A,
+        B = 2,
+        C,
+    }
+    struct Pair {
+        hi: logic<W>,
+        lo: logic<2>,
+    }
+}
+
+module VerilogModuleB (
+    o_a: output logic<VerilogPackageB::W>,
+    o_b: output logic<2>,
+) {
+    var p: VerilogPackageB::Pair;
+
+    assign p.hi = 1;
+    assign p.lo = VerilogPackageB::Kind::C;
+    assign o_a  = p.hi;
+    assign o_b  = p.lo[1:0];
+}
+"#;
+        let expected = r#"module veryl_testcase_VerilogModuleB (
+    output wire [(4)-1:0] o_a,
+    output wire [1:0] o_b
+);
+    localparam [31:0] veryl_testcase_VerilogPackageB_W = 4;
+    localparam [1:0] veryl_testcase_VerilogPackageB_Kind_A = 0;
+    localparam [1:0] veryl_testcase_VerilogPackageB_Kind_B = 2;
+    localparam [1:0] veryl_testcase_VerilogPackageB_Kind_C = veryl_testcase_VerilogPackageB_Kind_B + 1;
+    wire [((veryl_testcase_VerilogPackageB_W)+2)-1:0] p;
+
+    assign p[((veryl_testcase_VerilogPackageB_W)+2)-1:2] = 1;
+    assign p[1:0] = veryl_testcase_VerilogPackageB_Kind_C;
+    assign o_a = p[((veryl_testcase_VerilogPackageB_W)+2)-1:2];
+    assign o_b = p[1:0];
+endmodule
+"#;
+        test("package", input, expected);
+    }
+
+    #[test]
+    fn interface() {
+        let input = r#"interface VerilogInterfaceC {
+    var valid: logic   ;
+    var data : logic<8>;
+
+    modport master {
+        valid: output,
+        data : output,
+    }
+}
+
+module VerilogModuleC (
+    bus: modport VerilogInterfaceC::master,
+) {
+    assign bus.valid = 1;
+    assign bus.data  = 0;
+}
+
+module VerilogModuleD {
+    inst bus: VerilogInterfaceC;
+    inst u  : VerilogModuleC (
+        bus,
+    );
+}
+"#;
+        let expected = r#"module veryl_testcase_VerilogModuleC (
+    output wire bus_valid,
+    output wire [7:0] bus_data
+);
+    assign bus_valid = 1;
+    assign bus_data = 0;
+endmodule
+
+module veryl_testcase_VerilogModuleD;
+    wire bus_valid;
+    wire [7:0] bus_data;
+    veryl_testcase_VerilogModuleC u (
+        .bus_valid (bus_valid),
+        .bus_data (bus_data)
+    );
+endmodule
+"#;
+        test("interface", input, expected);
+    }
+
+    #[test]
+    fn header_localparam() {
+        let input = r#"module VerilogModuleE #(
+    parameter  A: u32 = 1    ,
+    localparam B: u32 = A * 2, // doubled
+    parameter  C: u32 = 3    ,
+) (
+    i_a: input logic<A>,
+) {
+    var r: logic<B>;
+}
+
+module VerilogModuleF #(
+    localparam A: u32 = 1,
+) {
+    var r: logic<A>;
+}
+"#;
+        let expected = r#"module veryl_testcase_VerilogModuleE #(
+    parameter [31:0] A = 1,
+    parameter [31:0] C = 3
+) (
+    input wire [A-1:0] i_a
+);
+    localparam [31:0] B = A * 2; // doubled
+    wire [B-1:0] r;
+endmodule
+
+module veryl_testcase_VerilogModuleF;
+    localparam [31:0] A = 1;
+    wire [A-1:0] r;
+endmodule
+"#;
+        test("header_localparam", input, expected);
+    }
+}
+
+#[cfg(test)]
+mod vhdl {
+    use crate::fixture;
+    use veryl_metadata::TargetLanguage;
+
+    fn test(name: &str, input: &str, expected: &str) {
+        let emitter = fixture::emit(name, input, |x| {
+            x.target_language = TargetLanguage::Vhdl;
+        });
+        assert_eq!(expected, emitter.as_str());
+    }
+
+    #[test]
+    fn module() {
+        let input = r#"module VhdlModuleA #(
+    parameter N: u32 = 8,
+) (
+    i_clk: input  clock   ,
+    i_rst: input  reset   ,
+    i_a  : input  logic<N>,
+    o_b  : output logic<N>,
+    o_c  : output logic   ,
+) {
+    var r: logic<N>;
+
+    always_ff (i_clk, i_rst) {
+        if_reset {
+            r = 0;
+        } else if i_a[0] == 1 {
+            r += i_a;
+        }
+    }
+
+    always_comb {
+        o_b = r;
+    }
+
+    assign o_c = r[N - 1];
+}
+"#;
+        let expected = r#"library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity veryl_testcase_VhdlModuleA is
+    generic (
+        N : natural := 8
+    );
+    port (
+        i_clk : in std_logic;
+        i_rst : in std_logic;
+        i_a : in unsigned(N - 1 downto 0);
+        o_b : out unsigned(N - 1 downto 0);
+        o_c : out std_logic
+    );
+end entity veryl_testcase_VhdlModuleA;
+
+architecture rtl of veryl_testcase_VhdlModuleA is
+    signal r : unsigned(N - 1 downto 0);
+begin
+    process (i_clk, i_rst)
+    begin
+        if i_rst = '0' then
+            r <= (others => '0');
+        elsif rising_edge(i_clk) then
+            if i_a(0) ?= '1' then
+                r <= r + i_a;
+            end if;
+        end if;
+    end process;
+
+    process (all)
+    begin
+        o_b <= r;
+    end process;
+
+    o_c <= r(N - 1);
+end architecture rtl;
+"#;
+        test("module", input, expected);
+    }
+
+    #[test]
+    fn package() {
+        let input = r#"package VhdlPackageB {
+    localparam W: u32 = 4;
+    enum Kind: logic<2> {
+        A,
+        B,
+        C,
+    }
+    struct Pair {
+        hi: logic<W>,
+        lo: logic<2>,
+    }
+    function Inc (
+        a: input logic<W>,
+    ) -> logic<W> {
+        return a + 1;
+    }
+}
+
+module VhdlModuleB (
+    i_s: input  logic<2>             ,
+    o_k: output VhdlPackageB::Kind   ,
+    o_a: output logic<VhdlPackageB::W>,
+) {
+    var p: VhdlPackageB::Pair;
+
+    assign p.hi = VhdlPackageB::Inc(4'h3);
+    assign p.lo = i_s;
+    assign o_a  = p.hi;
+
+    always_comb {
+        case i_s {
+            0      : o_k = VhdlPackageB::Kind::A;
+            1      : o_k = VhdlPackageB::Kind::B;
+            default: o_k = VhdlPackageB::Kind::C;
+        }
+    }
+}
+"#;
+        let expected = r#"library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+package veryl_testcase_VhdlPackageB is
+    constant W : natural := 4;
+
+    type Kind is (
+        Kind_A,
+        Kind_B,
+        Kind_C
+    );
+
+    type Pair is record
+        hi : unsigned(W - 1 downto 0);
+        lo : unsigned(1 downto 0);
+    end record;
+
+    function Inc(a : unsigned(W - 1 downto 0)) return unsigned;
+end package veryl_testcase_VhdlPackageB;
+
+package body veryl_testcase_VhdlPackageB is
+    function Inc(a : unsigned(W - 1 downto 0)) return unsigned is
+    begin
+        return a + 1;
+    end function Inc;
+end package body veryl_testcase_VhdlPackageB;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use work.veryl_testcase_VhdlPackageB.all;
+
+entity veryl_testcase_VhdlModuleB is
+    port (
+        i_s : in unsigned(1 downto 0);
+        o_k : out Kind;
+        o_a : out unsigned(W - 1 downto 0)
+    );
+end entity veryl_testcase_VhdlModuleB;
+
+architecture rtl of veryl_testcase_VhdlModuleB is
+    signal p : Pair;
+begin
+    p.hi <= Inc(4x"3");
+    p.lo <= i_s;
+    o_a <= p.hi;
+
+    process (all)
+    begin
+        case to_integer(i_s) is
+            when 0 =>
+                o_k <= Kind_A;
+            when 1 =>
+                o_k <= Kind_B;
+            when others =>
+                o_k <= Kind_C;
+        end case;
+    end process;
+end architecture rtl;
+"#;
+        test("package", input, expected);
+    }
+
+    #[test]
+    fn instance() {
+        let input = r#"module VhdlModuleC #(
+    parameter W: u32 = 4,
+) (
+    i_a: input  logic<4>,
+    o_b: output logic<4>,
+) {
+    assign o_b = i_a;
+}
+
+module VhdlModuleD (
+    o_b: output logic<4>,
+) {
+    for i in 0..2 :g {
+        inst u: VhdlModuleC #(
+            W: 4,
+        ) (
+            i_a: 1,
+            o_b: _,
+        );
+    }
+    inst u: VhdlModuleC (
+        i_a: 0,
+        o_b   ,
+    );
+}
+"#;
+        let expected = r#"library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity veryl_testcase_VhdlModuleC is
+    generic (
+        W : natural := 4
+    );
+    port (
+        i_a : in unsigned(3 downto 0);
+        o_b : out unsigned(3 downto 0)
+    );
+end entity veryl_testcase_VhdlModuleC;
+
+architecture rtl of veryl_testcase_VhdlModuleC is
+begin
+    o_b <= i_a;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity veryl_testcase_VhdlModuleD is
+    port (
+        o_b : out unsigned(3 downto 0)
+    );
+end entity veryl_testcase_VhdlModuleD;
+
+architecture rtl of veryl_testcase_VhdlModuleD is
+begin
+    g: for i in 0 to 1 generate
+        u: entity work.veryl_testcase_VhdlModuleC
+            generic map (
+                W => 4
+            )
+            port map (
+                i_a => to_unsigned(1, 4),
+                o_b => open
+            );
+    end generate g;
+
+    u: entity work.veryl_testcase_VhdlModuleC
+        port map (
+            i_a => (others => '0'),
+            o_b => o_b
+        );
+end architecture rtl;
+"#;
+        test("instance", input, expected);
+    }
+
+    #[test]
+    fn comparison() {
+        let input = r#"module VhdlModuleE (
+    i_clk : input  clock   ,
+    i_rst : input  reset   ,
+    i_a   : input  logic<2>,
+    o_done: output logic   ,
+    o_in  : output logic   ,
+    o_eq  : output logic   ,
+) {
+    enum State: logic<2> {
+        Idle,
+        Done,
+    }
+    var state: State;
+
+    always_ff (i_clk, i_rst) {
+        if_reset {
+            state = State::Idle;
+        } else if i_a == 3 {
+            state = State::Done;
+        }
+    }
+
+    assign o_done = state == State::Done;
+    assign o_in   = inside 1 + 2 {0..4};
+
+    always_comb {
+        o_eq = i_a == 1;
+    }
+}
+"#;
+        let expected = r#"library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity veryl_testcase_VhdlModuleE is
+    port (
+        i_clk : in std_logic;
+        i_rst : in std_logic;
+        i_a : in unsigned(1 downto 0);
+        o_done : out std_logic;
+        o_in : out std_logic;
+        o_eq : out std_logic
+    );
+end entity veryl_testcase_VhdlModuleE;
+
+architecture rtl of veryl_testcase_VhdlModuleE is
+    type State is (
+        State_Idle,
+        State_Done
+    );
+
+    signal state : State;
+begin
+    process (i_clk, i_rst)
+    begin
+        if i_rst = '0' then
+            state <= State_Idle;
+        elsif rising_edge(i_clk) then
+            if i_a ?= 3 then
+                state <= State_Done;
+            end if;
+        end if;
+    end process;
+
+    o_done <= '1' when state = State_Done else '0';
+    o_in <= '1' when ((1 + 2) >= 0 and (1 + 2) < 4) else '0';
+
+    process (all)
+    begin
+        o_eq <= i_a ?= 1;
+    end process;
+end architecture rtl;
+"#;
+        test("comparison", input, expected);
+    }
+}
+
+#[cfg(test)]
+mod source_map {
+    use crate::fixture;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn lookup() {
+        let input = r#"module SourceMapA (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    var a: logic;
+
+    assign a   = i_a;
+    assign o_b = a;
+}
+"#;
+        let emitter = fixture::emit("source_map", input, |_| ());
+        let map = emitter.source_map(Path::new("source_map.sv"));
+        let lines: Vec<_> = emitter.as_str().lines().collect();
+        assert_eq!(lines[1], "    input  logic i_a,");
+        assert_eq!(lines[7], "    assign o_b = a;");
+        assert_eq!(lines[8], "endmodule");
+        assert_eq!(lines.len(), 9);
+
+        // `i_a` is preferred to `input`
+        let loc = map.lookup(2, None).unwrap();
+        assert_eq!(loc.path, PathBuf::from("source_map.vl"));
+        assert_eq!((loc.line, loc.column), (2, 5));
+
+        // `o_b` is preferred to `assign`
+        let loc = map.lookup(8, None).unwrap();
+        assert_eq!((loc.line, loc.column), (8, 12));
+
+        // `assign` by column
+        let loc = map.lookup(8, Some(5)).unwrap();
+        assert_eq!((loc.line, loc.column), (8, 5));
+
+        // `a` of the right hand side
+        let loc = map.lookup(8, Some(18)).unwrap();
+        assert_eq!((loc.line, loc.column), (8, 18));
+
+        // `endmodule` is the closing brace
+        let loc = map.lookup(9, None).unwrap();
+        assert_eq!((loc.line, loc.column), (9, 1));
+
+        // lines out of the generated code
+        assert!(map.lookup(0, None).is_none());
+        assert!(map.lookup(10, None).is_none());
+    }
+}
+
+#[cfg(test)]
+mod prefix {
+    use crate::fixture;
+
+    #[test]
+    fn prefix_suffix() {
+        let input = r#"package PrefixPackageA {
+    localparam A: u32 = 1;
+}
+
+interface PrefixInterfaceA {
+    var a: logic;
+    modport mp {
+        a: input,
+    }
+}
+
+module PrefixModuleB {}
+
+module PrefixModuleA (
+    p: modport PrefixInterfaceA::mp,
+) {
+    import PrefixPackageA::*;
+    var a: logic;
+    assign a = PrefixPackageA::A;
+    inst u0: PrefixModuleB;
+    inst u1: PrefixInterfaceA;
+}
+"#;
+        let expected = r#"package pre_veryl_testcase_PrefixPackageA_post;
+    localparam int unsigned A = 1;
+endpackage
+
+interface pre_veryl_testcase_PrefixInterfaceA_post;
+    logic a;
+    modport mp (
+        input a
+    );
+endinterface
+
+module pre_veryl_testcase_PrefixModuleB_post;
+endmodule
+
+module pre_veryl_testcase_PrefixModuleA_post (
+    pre_veryl_testcase_PrefixInterfaceA_post.mp p
+);
+    import pre_veryl_testcase_PrefixPackageA_post::*;
+    logic a;
+    assign a = pre_veryl_testcase_PrefixPackageA_post::A;
+    pre_veryl_testcase_PrefixModuleB_post u0 ();
+    pre_veryl_testcase_PrefixInterfaceA_post u1 ();
+endmodule
+"#;
+        let emitter = fixture::emit("prefix", input, |x| {
+            x.prefix = "pre_".to_string();
+            x.suffix = "_post".to_string();
+        });
+        assert_eq!(expected, emitter.as_str());
+    }
+}
@@ -0,0 +1,334 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Identifier,
+    SystemIdentifier,
+    Number,
+    StringLiteral,
+    Symbol,
+    Macro,
+    Directive,
+    Eof,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    pub text: String,
+    pub beg: usize,
+    pub end: usize,
+    /// The comment starts at the line where the previous token ends
+    pub trailing: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub beg: usize,
+    pub end: usize,
+    pub comments: Vec<Comment>,
+}
+
+impl Token {
+    pub fn is(&self, text: &str) -> bool {
+        matches!(self.kind, TokenKind::Identifier | TokenKind::Symbol) && self.text == text
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LexerError {
+    pub cause: String,
+    pub beg: usize,
+    pub end: usize,
+}
+
+// Longest match should be first
+const SYMBOLS: [&str; 61] = [
+    "<<<=", ">>>=", "===", "!==", "==?", "!=?", "<<<", ">>>", "<<=", ">>=", "<->", "->", "+:",
+    "-:", "::", "**", "==", "!=", "<=", ">=", "&&", "||", "<<", ">>", "++", "--", "+=", "-=", "*=",
+    "/=", "%=", "&=", "|=", "^=", "~&", "~|", "~^", "^~", "+", "-", "*", "/", "%", "<", ">", "=",
+    "!", "~", "&", "|", "^", "?", ":", ";", ",", ".", "#", "@", "(", ")", "'",
+];
+
+const BRACKETS: [char; 4] = ['[', ']', '{', '}'];
+
+/// Directives which occupy the rest of the line
+const DIRECTIVES: [&str; 20] = [
+    "begin_keywords",
+    "celldefine",
+    "default_nettype",
+    "define",
+    "else",
+    "elsif",
+    "end_keywords",
+    "endcelldefine",
+    "endif",
+    "ifdef",
+    "ifndef",
+    "include",
+    "line",
+    "nounconnected_drive",
+    "pragma",
+    "resetall",
+    "timescale",
+    "unconnected_drive",
+    "undef",
+    "undefineall",
+];
+
+struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+    tokens: Vec<Token>,
+    comments: Vec<Comment>,
+    last_line_end: Option<usize>,
+}
+
+impl<'a> Lexer<'a> {
+    fn peek(&self, n: usize) -> Option<char> {
+        self.input[self.pos..].chars().nth(n)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn eat_while<F: Fn(char) -> bool>(&mut self, f: F) {
+        while let Some(c) = self.peek(0) {
+            if f(c) {
+                self.pos += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn push(&mut self, kind: TokenKind, beg: usize) {
+        let comments = std::mem::take(&mut self.comments);
+        self.tokens.push(Token {
+            kind,
+            text: self.input[beg..self.pos].to_string(),
+            beg,
+            end: self.pos,
+            comments,
+        });
+        self.last_line_end = Some(self.pos);
+    }
+
+    fn is_trailing(&self, beg: usize) -> bool {
+        match self.last_line_end {
+            Some(x) => !self.input[x..beg].contains('\n'),
+            None => false,
+        }
+    }
+
+    fn error(&self, cause: &str, beg: usize) -> LexerError {
+        LexerError {
+            cause: cause.to_string(),
+            beg,
+            end: self.pos.max(beg + 1).min(self.input.len()),
+        }
+    }
+
+    fn comment(&mut self) -> Result<bool, LexerError> {
+        let beg = self.pos;
+        if self.rest().starts_with("//") {
+            self.eat_while(|c| c != '\n');
+        } else if self.rest().starts_with("/*") {
+            match self.rest()[2..].find("*/") {
+                Some(x) => self.pos += x + 4,
+                None => {
+                    self.pos = self.input.len();
+                    return Err(self.error("unterminated block comment", beg));
+                }
+            }
+        } else {
+            return Ok(false);
+        }
+        let text = self.input[beg..self.pos].trim_end().to_string();
+        let end = beg + text.len();
+        let trailing = self.is_trailing(beg);
+        self.comments.push(Comment {
+            text,
+            beg,
+            end,
+            trailing,
+        });
+        Ok(true)
+    }
+
+    fn number(&mut self) {
+        let beg = self.pos;
+        if self.peek(0) != Some('\'') {
+            self.eat_while(|c| c.is_ascii_digit() || c == '_');
+            if self.peek(0) == Some('.') && self.peek(1).is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1;
+                self.eat_while(|c| c.is_ascii_digit() || c == '_');
+            }
+            if matches!(self.peek(0), Some('e' | 'E'))
+                && self
+                    .peek(1)
+                    .is_some_and(|c| c.is_ascii_digit() || c == '+' || c == '-')
+            {
+                self.pos += 2;
+                self.eat_while(|c| c.is_ascii_digit() || c == '_');
+            }
+
+            // based number may have spaces between size and base
+            let size_end = self.pos;
+            self.eat_while(|c| c == ' ' || c == '\t');
+            if !self.is_based() {
+                self.pos = size_end;
+                // time literals like `1ns` are kept as a number and rejected later
+                self.eat_while(|c| c.is_ascii_alphabetic());
+                self.push(TokenKind::Number, beg);
+                return;
+            }
+        }
+
+        // skip '
+        self.pos += 1;
+        match self.peek(0) {
+            Some('0' | '1' | 'x' | 'X' | 'z' | 'Z') => {
+                self.pos += 1;
+            }
+            _ => {
+                if matches!(self.peek(0), Some('s' | 'S')) {
+                    self.pos += 1;
+                }
+                self.pos += 1;
+                self.eat_while(|c| c == ' ' || c == '\t');
+                self.eat_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '?');
+            }
+        }
+        self.push(TokenKind::Number, beg);
+    }
+
+    fn is_based(&self) -> bool {
+        let mut chars = self.rest().chars();
+        if chars.next() != Some('\'') {
+            return false;
+        }
+        let mut c = chars.next();
+        if matches!(c, Some('s' | 'S')) {
+            c = chars.next();
+        }
+        matches!(c, Some('b' | 'B' | 'o' | 'O' | 'd' | 'D' | 'h' | 'H'))
+    }
+
+    fn is_all_bit(&self) -> bool {
+        let mut chars = self.rest().chars();
+        chars.next() == Some('\'')
+            && matches!(chars.next(), Some('0' | '1' | 'x' | 'X' | 'z' | 'Z'))
+            && !chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    fn string(&mut self) -> Result<(), LexerError> {
+        let beg = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek(0) {
+                Some('"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some('\\') => {
+                    self.pos += 1;
+                    if let Some(c) = self.peek(0) {
+                        self.pos += c.len_utf8();
+                    }
+                }
+                Some('\n') | None => return Err(self.error("unterminated string literal", beg)),
+                Some(c) => self.pos += c.len_utf8(),
+            }
+        }
+        self.push(TokenKind::StringLiteral, beg);
+        Ok(())
+    }
+
+    fn directive(&mut self) {
+        let beg = self.pos;
+        self.pos += 1;
+        self.eat_while(|c| c.is_ascii_alphanumeric() || c == '_');
+        let name = &self.input[beg + 1..self.pos];
+        if DIRECTIVES.contains(&name) {
+            // consume the rest of the line including continued lines
+            loop {
+                self.eat_while(|c| c != '\n');
+                if self.input[beg..self.pos].trim_end().ends_with('\\') {
+                    self.pos += 1;
+                } else {
+                    break;
+                }
+            }
+            let end = self.input[..self.pos].trim_end().len();
+            let pos = self.pos;
+            self.pos = end;
+            self.push(TokenKind::Directive, beg);
+            self.pos = pos;
+        } else {
+            self.push(TokenKind::Macro, beg);
+        }
+    }
+
+    fn lex(&mut self) -> Result<(), LexerError> {
+        loop {
+            self.eat_while(|c| c.is_whitespace());
+            let c = match self.peek(0) {
+                Some(c) => c,
+                None => break,
+            };
+            let beg = self.pos;
+
+            if c == '/' && self.comment()? {
+                continue;
+            }
+
+            if c.is_ascii_digit() || self.is_based() || self.is_all_bit() {
+                self.number();
+            } else if c.is_ascii_alphabetic() || c == '_' {
+                self.eat_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+                self.push(TokenKind::Identifier, beg);
+            } else if c == '\\' {
+                self.eat_while(|c| !c.is_whitespace());
+                self.push(TokenKind::Identifier, beg);
+            } else if c == '$' {
+                self.pos += 1;
+                self.eat_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+                self.push(TokenKind::SystemIdentifier, beg);
+            } else if c == '"' {
+                self.string()?;
+            } else if c == '`' {
+                self.directive();
+            } else if BRACKETS.contains(&c) {
+                self.pos += 1;
+                self.push(TokenKind::Symbol, beg);
+            } else if let Some(x) = SYMBOLS.iter().find(|x| self.rest().starts_with(*x)) {
+                self.pos += x.len();
+                self.push(TokenKind::Symbol, beg);
+            } else {
+                self.pos += c.len_utf8();
+                return Err(self.error(&format!("unexpected character '{c}'"), beg));
+            }
+        }
+
+        let beg = self.input.len();
+        self.push(TokenKind::Eof, beg);
+        Ok(())
+    }
+}
+
+/// Splits SystemVerilog source into tokens.
+/// Comments are attached to the following token.
+pub fn lex(input: &str) -> Result<Vec<Token>, LexerError> {
+    let mut lexer = Lexer {
+        input,
+        pos: 0,
+        tokens: Vec::new(),
+        comments: Vec::new(),
+        last_line_end: None,
+    };
+    lexer.lex()?;
+    Ok(lexer.tokens)
+}
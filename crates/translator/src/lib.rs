@@ -0,0 +1,7 @@
+pub mod lexer;
+pub mod translator;
+pub mod translator_error;
+pub use translator::{Translated, Translator};
+pub use translator_error::TranslatorError;
+#[cfg(test)]
+mod tests;
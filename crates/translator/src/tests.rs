@@ -0,0 +1,304 @@
+use crate::{Translator, TranslatorError};
+use std::fs;
+use veryl_metadata::Metadata;
+use veryl_parser::Parser;
+
+fn translator() -> Translator {
+    let metadata_path = Metadata::search_from_current().unwrap();
+    let metadata = Metadata::load(&metadata_path).unwrap();
+    Translator::new(&metadata)
+}
+
+fn translate(code: &str) -> (String, Vec<TranslatorError>) {
+    let mut translator = translator();
+    translator.scan(code, &"test.sv");
+    let ret = translator.translate(code, &"test.sv");
+    dbg!(&ret.text);
+    (ret.text, ret.errors)
+}
+
+fn success(code: &str, expect: &str) {
+    let (text, errors) = translate(code);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(text, expect);
+}
+
+#[test]
+fn module() {
+    let code = r#"
+module ModuleA #(
+    parameter int WIDTH = 8 // width
+) (
+    input  logic             i_clk,
+    input  logic             i_rst_n,
+    input  logic [WIDTH-1:0] i_data,
+    output logic [WIDTH-1:0] o_data
+);
+    logic [WIDTH-1:0] r_data;
+
+    always_ff @(posedge i_clk or negedge i_rst_n) begin
+        if (!i_rst_n) begin
+            r_data <= '0;
+        end else begin
+            r_data <= i_data;
+        end
+    end
+
+    assign o_data = (r_data > 10) ? 10 : r_data;
+endmodule
+"#;
+    let expect = r#"module ModuleA #(
+    parameter WIDTH: i32 = 8, // width
+) (
+    i_clk  : input  clock       ,
+    i_rst_n: input  reset       ,
+    i_data : input  logic<WIDTH>,
+    o_data : output logic<WIDTH>,
+) {
+    var r_data: logic<WIDTH>;
+
+    always_ff (i_clk, i_rst_n) {
+        if_reset {
+            r_data = '0;
+        } else {
+            r_data = i_data;
+        }
+    }
+
+    assign o_data = if (r_data >: 10) {
+        10
+    } else {
+        r_data
+    };
+}
+"#;
+    success(code, expect);
+}
+
+#[test]
+fn package() {
+    let code = r#"
+package PackageA;
+    localparam int A = 1;
+    typedef enum logic [1:0] {
+        StateA,
+        StateB
+    } state_t;
+    typedef struct packed {
+        logic [7:0] a;
+        logic       b;
+    } data_t;
+endpackage
+
+module ModuleB
+    import PackageA::*;
+(
+    input  state_t i_state,
+    output logic   o_a
+);
+    assign o_a = i_state == StateB;
+endmodule
+"#;
+    let expect = r#"package PackageA {
+    localparam A: i32      = 1;
+    enum state_t: logic<2> {
+        StateA,
+        StateB,
+    }
+    struct data_t {
+        a: logic<8>,
+        b: logic   ,
+    }
+}
+
+module ModuleB (
+    i_state: input  state_t,
+    o_a    : output logic  ,
+) {
+    import PackageA::*;
+    assign o_a = i_state == PackageA::state_t::StateB;
+}
+"#;
+    success(code, expect);
+}
+
+#[test]
+fn generate_and_instance() {
+    let code = r#"
+module ModuleC (
+    input  logic [3:0] i_a,
+    output logic [3:0] o_b
+);
+    for (genvar i = 0; i < 4; i++) begin : g_loop
+        ModuleD #(.W(1)) u_d (.i_a(i_a[i]), .o_b);
+    end
+endmodule
+"#;
+    let expect = r#"module ModuleC (
+    i_a: input  logic<4>,
+    o_b: output logic<4>,
+) {
+    for i in 0..4 :g_loop {
+        inst u_d: ModuleD #(
+            W: 1,
+        ) (
+            i_a: i_a[i],
+            o_b        ,
+        );
+    }
+}
+"#;
+    success(code, expect);
+}
+
+#[test]
+fn unsupported() {
+    let code = r#"
+module ModuleE (
+    input logic i_clk
+);
+    always_latch begin
+        if (i_clk) a = 1;
+    end
+endmodule
+"#;
+    let (text, errors) = translate(code);
+    assert!(text.contains("embed (inline) sv{{{"));
+    assert!(matches!(
+        errors[0],
+        TranslatorError::UnsupportedConstruct { .. }
+    ));
+}
+
+#[test]
+fn renamed_identifier() {
+    let code = r#"
+module ModuleF (
+    input  logic step,
+    output logic o
+);
+    assign o = step;
+endmodule
+"#;
+    let (text, errors) = translate(code);
+    assert!(text.contains("assign o = step_;"));
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0],
+        TranslatorError::RenamedIdentifier { .. }
+    ));
+}
+
+#[test]
+fn syntax_error() {
+    let (_, errors) = translate("module ModuleG; \"aaa\nendmodule");
+    assert!(matches!(errors[0], TranslatorError::SyntaxError { .. }));
+}
+
+#[test]
+fn testcases() {
+    // SystemVerilog generated from testcases should be translated to valid Veryl
+    for entry in fs::read_dir("../../testcases/sv").unwrap() {
+        let path = entry.unwrap().path();
+        let input = fs::read_to_string(&path).unwrap();
+        let mut translator = translator();
+        translator.scan(&input, &path);
+        let ret = translator.translate(&input, &path);
+        dbg!(&path);
+        assert!(!ret
+            .errors
+            .iter()
+            .any(|x| matches!(x, TranslatorError::InvalidOutput { .. })));
+        assert!(Parser::parse(&ret.text, &path).is_ok());
+    }
+}
+
+#[test]
+fn conditional_directive() {
+    let code = r#"
+`ifdef ENABLE_A
+module ModuleH #(
+    parameter int A = 1
+`ifdef WIDE
+  , parameter int B = 2
+`endif
+) (
+    input  logic i_a,
+`ifdef DEBUG
+    output logic o_debug,
+    output logic o_trace,
+`endif
+    output logic o_b
+);
+`ifdef DEBUG
+    assign o_debug = i_a;
+`elsif TRACE
+    logic a;
+`else
+    assign o_b = i_a;
+`endif
+`ifndef DEBUG
+    assign o_b = ~i_a;
+`endif
+endmodule
+`endif
+"#;
+    let expect = r#"#[ifdef(ENABLE_A)]
+{
+    module ModuleH #(
+        parameter A: i32 = 1,
+        #[ifdef(WIDE)]
+        parameter B: i32 = 2,
+    ) (
+        i_a: input logic,
+        #[ifdef(DEBUG)]
+        o_debug: output logic,
+        #[ifdef(DEBUG)]
+        o_trace: output logic,
+        o_b    : output logic,
+    ) {
+        #[ifdef(DEBUG)]
+        {
+            assign o_debug = i_a;
+        }
+        #[ifndef(DEBUG)]
+        #[ifdef(TRACE)]
+        {
+            var a: logic;
+        }
+        #[ifndef(DEBUG)]
+        #[ifndef(TRACE)]
+        {
+            assign o_b = i_a;
+        }
+        #[ifndef(DEBUG)]
+        {
+            assign o_b = ~i_a;
+        }
+    }
+}
+"#;
+    success(code, expect);
+
+    // conditional directives in statements are kept as embedded SystemVerilog
+    let code = r#"
+module ModuleI (
+    input  logic i_a,
+    output logic o_b
+);
+    always_comb begin
+`ifdef DEBUG
+        o_b = i_a;
+`else
+        o_b = 0;
+`endif
+    end
+endmodule
+"#;
+    let (text, errors) = translate(code);
+    assert!(text.contains("embed (inline) sv{{{\n    always_comb begin\n`ifdef DEBUG"));
+    assert!(matches!(
+        errors[0],
+        TranslatorError::UnsupportedConstruct { .. }
+    ));
+}
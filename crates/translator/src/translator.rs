@@ -0,0 +1,3325 @@
+use crate::lexer::{self, Comment, Token, TokenKind};
+use crate::translator_error::TranslatorError;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use veryl_formatter::Formatter;
+use veryl_metadata::{ClockType, Metadata, ResetType};
+use veryl_parser::Parser;
+
+/// Keywords of Veryl which are valid identifiers in SystemVerilog
+const VERYL_KEYWORDS: [&str; 22] = [
+    "as",
+    "async_high",
+    "async_low",
+    "clock",
+    "embed",
+    "f32",
+    "f64",
+    "i32",
+    "i64",
+    "if_reset",
+    "in",
+    "include",
+    "inst",
+    "lsb",
+    "msb",
+    "outside",
+    "reset",
+    "step",
+    "sync_high",
+    "sync_low",
+    "u32",
+    "u64",
+];
+
+/// Keywords of SystemVerilog which can't be used as identifiers
+const SV_KEYWORDS: [&str; 130] = [
+    "always",
+    "always_comb",
+    "always_ff",
+    "always_latch",
+    "and",
+    "assert",
+    "assign",
+    "assume",
+    "automatic",
+    "begin",
+    "bit",
+    "break",
+    "byte",
+    "case",
+    "casex",
+    "casez",
+    "chandle",
+    "checker",
+    "class",
+    "clocking",
+    "config",
+    "const",
+    "continue",
+    "cover",
+    "covergroup",
+    "default",
+    "defparam",
+    "disable",
+    "do",
+    "else",
+    "end",
+    "endcase",
+    "endchecker",
+    "endclass",
+    "endclocking",
+    "endconfig",
+    "endfunction",
+    "endgenerate",
+    "endgroup",
+    "endinterface",
+    "endmodule",
+    "endpackage",
+    "endprimitive",
+    "endprogram",
+    "endproperty",
+    "endsequence",
+    "endspecify",
+    "endtable",
+    "endtask",
+    "enum",
+    "event",
+    "expect",
+    "export",
+    "extern",
+    "final",
+    "for",
+    "force",
+    "foreach",
+    "forever",
+    "fork",
+    "function",
+    "generate",
+    "genvar",
+    "if",
+    "iff",
+    "import",
+    "initial",
+    "inout",
+    "input",
+    "inside",
+    "int",
+    "integer",
+    "interface",
+    "join",
+    "join_any",
+    "join_none",
+    "localparam",
+    "logic",
+    "longint",
+    "macromodule",
+    "modport",
+    "module",
+    "negedge",
+    "or",
+    "output",
+    "package",
+    "packed",
+    "parameter",
+    "posedge",
+    "primitive",
+    "priority",
+    "program",
+    "property",
+    "randcase",
+    "real",
+    "realtime",
+    "ref",
+    "reg",
+    "release",
+    "repeat",
+    "restrict",
+    "return",
+    "sequence",
+    "shortint",
+    "shortreal",
+    "signed",
+    "specify",
+    "static",
+    "string",
+    "struct",
+    "table",
+    "task",
+    "time",
+    "tri",
+    "type",
+    "typedef",
+    "union",
+    "unique",
+    "unique0",
+    "unsigned",
+    "uwire",
+    "var",
+    "virtual",
+    "void",
+    "wait",
+    "wand",
+    "while",
+    "wire",
+    "with",
+    "wor",
+];
+
+const TYPE_KEYWORDS: [&str; 19] = [
+    "bit",
+    "byte",
+    "int",
+    "integer",
+    "logic",
+    "longint",
+    "real",
+    "realtime",
+    "reg",
+    "shortint",
+    "shortreal",
+    "signed",
+    "string",
+    "time",
+    "tri",
+    "unsigned",
+    "uwire",
+    "var",
+    "wire",
+];
+
+const PREC_TERNARY: u8 = 0;
+const PREC_AS: u8 = 11;
+const PREC_UNARY: u8 = 12;
+const PREC_PRIMARY: u8 = 13;
+
+pub struct Translated {
+    pub text: String,
+    pub errors: Vec<TranslatorError>,
+}
+
+pub struct Translator {
+    metadata: Metadata,
+    /// Enum declarations of each package for qualifying enum members
+    packages: HashMap<String, Vec<(String, Vec<String>)>>,
+}
+
+impl Translator {
+    pub fn new(metadata: &Metadata) -> Self {
+        Self {
+            metadata: metadata.clone(),
+            packages: HashMap::new(),
+        }
+    }
+
+    /// Collects declarations which are referred from other files.
+    /// This should be called for all files before `translate`.
+    pub fn scan<T: AsRef<Path>>(&mut self, input: &str, path: &T) {
+        let _ = self.convert(input, path);
+    }
+
+    /// Translates SystemVerilog source into formatted Veryl source.
+    /// Constructs which can't be translated are reported as errors.
+    pub fn translate<T: AsRef<Path>>(&mut self, input: &str, path: &T) -> Translated {
+        let (text, mut errors) = self.convert(input, path);
+        if text.is_empty() {
+            return Translated { text, errors };
+        }
+
+        let mut dst = path.as_ref().to_path_buf();
+        dst.set_extension("vl");
+        let text = match Parser::parse(&text, &dst) {
+            Ok(parser) => {
+                let mut formatter = Formatter::new(&self.metadata);
+                formatter.format(&parser.veryl);
+                formatter.as_str().to_string()
+            }
+            Err(err) => {
+                errors.push(TranslatorError::invalid_output(&err.to_string()));
+                text
+            }
+        };
+        Translated { text, errors }
+    }
+
+    fn convert<T: AsRef<Path>>(&mut self, input: &str, path: &T) -> (String, Vec<TranslatorError>) {
+        let path = path.as_ref().to_string_lossy();
+        let tokens = match lexer::lex(input) {
+            Ok(x) => x,
+            Err(err) => {
+                let error =
+                    TranslatorError::syntax_error(&err.cause, &path, input, err.beg, err.end);
+                return (String::new(), vec![error]);
+            }
+        };
+
+        let mut converter = Converter {
+            text: input,
+            path: &path,
+            tokens,
+            pos: 0,
+            errors: Vec::new(),
+            clock_type: self.metadata.build.clock_type,
+            reset_type: self.metadata.build.reset_type,
+            packages: &mut self.packages,
+            global_members: HashMap::new(),
+            members: HashMap::new(),
+            renamed: HashSet::new(),
+            clocks: HashSet::new(),
+            resets: HashSet::new(),
+            ports: Vec::new(),
+            params: Vec::new(),
+            has_param_list: false,
+            generate_count: 0,
+            function: None,
+            function_result: false,
+            conditions: Vec::new(),
+        };
+        converter.remove_directives();
+        let text = converter.description();
+        (text, converter.errors)
+    }
+}
+
+struct Unsupported {
+    name: String,
+    beg: usize,
+    end: usize,
+}
+
+type Result<T> = std::result::Result<T, Unsupported>;
+
+#[derive(Clone, Debug)]
+struct Expr {
+    text: String,
+    prec: u8,
+    /// `x` if the expression is `x - 1`
+    minus_one: Option<String>,
+    /// value if the expression is a decimal number
+    value: Option<u64>,
+}
+
+impl Expr {
+    fn new(text: String, prec: u8) -> Self {
+        Self {
+            text,
+            prec,
+            minus_one: None,
+            value: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Type {
+    signed: bool,
+    tri: bool,
+    base: String,
+    packed: Vec<String>,
+    unpacked: Vec<String>,
+}
+
+impl Type {
+    fn logic() -> Self {
+        Self {
+            base: "logic".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn is_single_bit(&self) -> bool {
+        !self.signed
+            && !self.tri
+            && (self.base == "logic" || self.base == "bit")
+            && self.packed.is_empty()
+            && self.unpacked.is_empty()
+    }
+
+    fn scalar(&self) -> String {
+        let mut ret = String::new();
+        if self.signed {
+            ret.push_str("signed ");
+        }
+        if self.tri {
+            ret.push_str("tri ");
+        }
+        ret.push_str(&self.base);
+        if !self.packed.is_empty() {
+            ret.push_str(&format!("<{}>", self.packed.join(", ")));
+        }
+        ret
+    }
+
+    fn array(&self) -> String {
+        let mut ret = self.scalar();
+        if !self.unpacked.is_empty() {
+            ret.push_str(&format!(" [{}]", self.unpacked.join(", ")));
+        }
+        ret
+    }
+}
+
+enum Item {
+    Text(String),
+    Var {
+        name: String,
+        ty: Type,
+        init: Option<String>,
+        comment: String,
+    },
+    Block {
+        head: String,
+        items: Vec<Item>,
+        tail: String,
+    },
+}
+
+/// An element of comma separated list like ports
+#[derive(Default)]
+struct Line {
+    leading: Vec<String>,
+    text: String,
+    comment: String,
+}
+
+/// A branch of conditional compilation like `` `ifdef `` and `` `else ``
+#[derive(Clone)]
+struct Condition {
+    /// Macros checked by the previous branches and whether they are defined in this branch
+    previous: Vec<(String, bool)>,
+    /// Macro checked by this branch, or `None` for `` `else ``
+    current: Option<(String, bool)>,
+}
+
+impl Condition {
+    fn attributes(&self) -> Vec<String> {
+        self.previous
+            .iter()
+            .chain(self.current.iter())
+            .map(|(name, defined)| {
+                if *defined {
+                    format!("#[ifdef({name})]")
+                } else {
+                    format!("#[ifndef({name})]")
+                }
+            })
+            .collect()
+    }
+}
+
+struct Port {
+    name: String,
+    direction: Option<String>,
+    ty: Type,
+    leading: Vec<String>,
+    comment: String,
+}
+
+struct IfChain {
+    branches: Vec<(String, Vec<String>)>,
+    default: Option<Vec<String>>,
+}
+
+struct LoopHeader {
+    var: String,
+    ty: Option<String>,
+    range: String,
+    step: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Posedge,
+    Negedge,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Block {
+    AlwaysFf,
+    AlwaysComb,
+    Initial,
+    Function,
+}
+
+struct Converter<'a> {
+    text: &'a str,
+    path: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    errors: Vec<TranslatorError>,
+    clock_type: ClockType,
+    reset_type: ResetType,
+    packages: &'a mut HashMap<String, Vec<(String, Vec<String>)>>,
+    /// Enum members imported at the top level
+    global_members: HashMap<String, String>,
+    /// Enum members which are visible in the current scope and their enum types
+    members: HashMap<String, String>,
+    renamed: HashSet<String>,
+    clocks: HashSet<String>,
+    resets: HashSet<String>,
+    ports: Vec<Port>,
+    params: Vec<Line>,
+    has_param_list: bool,
+    generate_count: usize,
+    /// The function name which can be used as the return value
+    function: Option<String>,
+    function_result: bool,
+    /// Conditional compilation branches surrounding the current position
+    conditions: Vec<Condition>,
+}
+
+impl<'a> Converter<'a> {
+    // -----------------------------------------------------------------------------------------------------------------
+    // Token operations
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn tok(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn peek(&self, n: usize) -> &Token {
+        &self.tokens[(self.pos + n).min(self.tokens.len() - 1)]
+    }
+
+    fn is(&self, text: &str) -> bool {
+        self.tok().is(text)
+    }
+
+    fn is_eof(&self) -> bool {
+        self.tok().kind == TokenKind::Eof
+    }
+
+    fn is_ident(&self, n: usize) -> bool {
+        let t = self.peek(n);
+        t.kind == TokenKind::Identifier && !SV_KEYWORDS.contains(&t.text.as_str())
+    }
+
+    fn advance(&mut self) -> Token {
+        let ret = self.tok().clone();
+        if !self.is_eof() {
+            self.pos += 1;
+        }
+        ret
+    }
+
+    fn eat(&mut self, text: &str) -> bool {
+        if self.is(text) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, text: &str) -> Result<()> {
+        if self.eat(text) {
+            Ok(())
+        } else {
+            self.unexpected()
+        }
+    }
+
+    fn unsupported<T>(&self, name: &str) -> Result<T> {
+        let t = self.tok();
+        Err(Unsupported {
+            name: name.to_string(),
+            beg: t.beg,
+            end: t.end,
+        })
+    }
+
+    fn unexpected<T>(&self) -> Result<T> {
+        if self.is_eof() {
+            self.unsupported("unexpected end of file")
+        } else if self.tok().kind == TokenKind::Directive {
+            let name = self
+                .tok()
+                .text
+                .split_whitespace()
+                .next()
+                .unwrap_or_default();
+            self.unsupported(&format!("`{}` directive", &name[1..]))
+        } else {
+            self.unsupported(&format!("`{}`", self.tok().text))
+        }
+    }
+
+    fn inexact(&mut self, cause: &str, beg: usize, end: usize) {
+        self.errors.push(TranslatorError::inexact_translation(
+            cause, self.path, self.text, beg, end,
+        ));
+    }
+
+    fn prev_end(&self) -> usize {
+        if self.pos == 0 {
+            0
+        } else {
+            self.tokens[self.pos - 1].end
+        }
+    }
+
+    /// Removes directives except conditional compilation which is translated to attributes
+    fn remove_directives(&mut self) {
+        let mut tokens = Vec::new();
+        let mut comments: Vec<Comment> = Vec::new();
+        for mut t in std::mem::take(&mut self.tokens) {
+            if t.kind == TokenKind::Directive && !is_conditional_directive(&t) {
+                let name = t.text.split_whitespace().next().unwrap_or_default();
+                self.errors.push(TranslatorError::dropped_construct(
+                    &format!("`{}` directive", &name[1..]),
+                    self.path,
+                    self.text,
+                    t.beg,
+                    t.end,
+                ));
+                comments.append(&mut t.comments);
+            } else {
+                if !comments.is_empty() {
+                    comments.append(&mut t.comments);
+                    t.comments = std::mem::take(&mut comments);
+                }
+                tokens.push(t);
+            }
+        }
+        self.tokens = tokens;
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Comments
+    // -----------------------------------------------------------------------------------------------------------------
+
+    /// Takes comments before the current token.
+    /// Trailing comments of the previous line are returned as the first element.
+    fn comments(&mut self) -> (Vec<String>, Vec<String>, bool) {
+        let mut trailing = Vec::new();
+        let mut leading = Vec::new();
+        let mut last = self.prev_end();
+        let mut blank = false;
+        let comments = std::mem::take(&mut self.tokens[self.pos].comments);
+        for c in comments {
+            if c.trailing && leading.is_empty() {
+                trailing.push(c.text);
+            } else {
+                if leading.is_empty() {
+                    blank = self.text[last..c.beg].matches('\n').count() >= 2;
+                }
+                leading.push(c.text);
+            }
+            last = c.end;
+        }
+        if leading.is_empty() && self.pos > 0 {
+            blank = self.text[last..self.tok().beg].matches('\n').count() >= 2;
+        }
+        (trailing, leading, blank)
+    }
+
+    fn item_comments(&mut self, items: &mut Vec<Item>) {
+        let (trailing, leading, blank) = self.comments();
+        for c in trailing {
+            match items.last_mut() {
+                Some(Item::Text(x)) => {
+                    x.push(' ');
+                    x.push_str(&c);
+                }
+                Some(Item::Var { comment, .. }) => {
+                    comment.push(' ');
+                    comment.push_str(&c);
+                }
+                Some(Item::Block { tail, .. }) => {
+                    tail.push(' ');
+                    tail.push_str(&c);
+                }
+                None => items.push(Item::Text(c)),
+            }
+        }
+        if blank && !items.is_empty() {
+            items.push(Item::Text(String::new()));
+        }
+        for c in leading {
+            items.push(Item::Text(c));
+        }
+    }
+
+    fn statement_comments(&mut self, lines: &mut Vec<String>) {
+        let (trailing, leading, blank) = self.comments();
+        for c in trailing {
+            match lines.last_mut() {
+                Some(x) => {
+                    x.push(' ');
+                    x.push_str(&c);
+                }
+                None => lines.push(c),
+            }
+        }
+        if blank && !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(leading);
+    }
+
+    fn list_comments(&mut self, lines: &mut [Line]) -> Vec<String> {
+        let (trailing, leading, _) = self.comments();
+        for c in trailing {
+            if let Some(x) = lines.last_mut() {
+                x.comment.push(' ');
+                x.comment.push_str(&c);
+            }
+        }
+        leading
+    }
+
+    fn param_comments(&mut self) -> Vec<String> {
+        let mut params = std::mem::take(&mut self.params);
+        let ret = self.list_comments(&mut params);
+        self.params = params;
+        ret
+    }
+
+    fn port_comments(&mut self) -> Vec<String> {
+        let (trailing, leading, _) = self.comments();
+        if let Some(port) = self.ports.last_mut() {
+            for c in trailing {
+                port.comment.push(' ');
+                port.comment.push_str(&c);
+            }
+        }
+        leading
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Identifier / Number
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn ident(&mut self) -> Result<String> {
+        if !self.is_ident(0) {
+            return self.unexpected();
+        }
+        let t = self.tok().clone();
+        if t.text.starts_with('\\') || t.text.contains('$') {
+            return self.unsupported(&format!("identifier `{}`", t.text));
+        }
+        self.advance();
+        if VERYL_KEYWORDS.contains(&t.text.as_str()) {
+            let renamed = format!("{}_", t.text);
+            if self.renamed.insert(t.text.clone()) {
+                self.errors.push(TranslatorError::renamed_identifier(
+                    &t.text, &renamed, self.path, self.text, t.beg, t.end,
+                ));
+            }
+            Ok(renamed)
+        } else {
+            Ok(t.text)
+        }
+    }
+
+    /// Skips optional `: label` after end keywords
+    fn end_label(&mut self) -> Result<()> {
+        if self.eat(":") {
+            self.ident()?;
+        }
+        Ok(())
+    }
+
+    fn number(&self) -> Result<String> {
+        let text: String = self
+            .tok()
+            .text
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+
+        if let Some(i) = text.find('\'') {
+            let size = normalize_digits(&text[..i]);
+            let rest = &text[i + 1..];
+            let base = rest.chars().next().unwrap_or_default();
+            if rest.len() == 1 {
+                return Ok(format!("{size}'{rest}"));
+            }
+            if base == 's' || base == 'S' {
+                return self.unsupported("signed based number");
+            }
+            let digits = &rest[1..];
+            if digits.contains('?') {
+                return self.unsupported("`?` in number");
+            }
+            if digits.is_empty() {
+                return self.unexpected();
+            }
+            Ok(format!(
+                "{size}'{}{}",
+                base.to_ascii_lowercase(),
+                normalize_digits(digits)
+            ))
+        } else if text
+            .chars()
+            .any(|c| c.is_ascii_alphabetic() && c != 'e' && c != 'E')
+        {
+            self.unsupported("time literal")
+        } else if text.contains(['e', 'E']) {
+            let text = text.to_ascii_lowercase();
+            if text.contains('.') {
+                Ok(text)
+            } else {
+                Ok(text.replacen('e', ".0e", 1))
+            }
+        } else if text.contains('.') {
+            Ok(text)
+        } else {
+            Ok(normalize_digits(&text))
+        }
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Expression
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn expression(&mut self) -> Result<Expr> {
+        let cond = self.binary(1)?;
+        if self.eat("?") {
+            let x = self.expression()?;
+            self.expect(":")?;
+            let y = self.expression()?;
+            let y = if y.prec == PREC_TERNARY {
+                y.text
+            } else {
+                format!("{{ {} }}", y.text)
+            };
+            let text = format!("if {} {{ {} }} else {}", cond.text, x.text, y);
+            Ok(Expr::new(text, PREC_TERNARY))
+        } else {
+            Ok(cond)
+        }
+    }
+
+    fn binary(&mut self, min: u8) -> Result<Expr> {
+        let mut lhs = self.unary()?;
+        loop {
+            if self.is("inside") && min <= 7 {
+                lhs = self.inside(lhs)?;
+                continue;
+            }
+            let t = self.tok();
+            if t.kind != TokenKind::Symbol {
+                break;
+            }
+            let prec = match binary_precedence(&t.text) {
+                Some(x) if x >= min => x,
+                _ => break,
+            };
+            if matches!(t.text.as_str(), "->" | "<->") {
+                return self.unsupported("logical implication");
+            }
+            let op = match self.advance().text.as_str() {
+                "<" => "<:".to_string(),
+                ">" => ">:".to_string(),
+                x => x.to_string(),
+            };
+            let rhs = self.binary(prec + 1)?;
+            let minus_one = if op == "-" && rhs.text == "1" {
+                Some(lhs.text.clone())
+            } else {
+                None
+            };
+            let value = match (lhs.value, rhs.value) {
+                (Some(x), Some(y)) => match op.as_str() {
+                    "+" => x.checked_add(y),
+                    "-" => x.checked_sub(y),
+                    "*" => x.checked_mul(y),
+                    _ => None,
+                },
+                _ => None,
+            };
+            lhs = Expr {
+                text: format!("{} {} {}", lhs.text, op, rhs.text),
+                prec,
+                minus_one,
+                value,
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        const UNARY: [&str; 11] = ["+", "-", "!", "~", "&", "~&", "|", "~|", "^", "~^", "^~"];
+        if self.tok().kind == TokenKind::Symbol && UNARY.contains(&self.tok().text.as_str()) {
+            let op = self.advance().text;
+            let x = self.unary()?;
+            let x = if x.prec < PREC_UNARY {
+                format!("({})", x.text)
+            } else {
+                x.text
+            };
+            let sep = if x.starts_with(['+', '-', '!', '~', '&', '|', '^']) {
+                " "
+            } else {
+                ""
+            };
+            return Ok(Expr::new(format!("{op}{sep}{x}"), PREC_UNARY));
+        }
+        if self.is("++") || self.is("--") {
+            return self.unsupported("increment operator in expression");
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        match self.tok().kind {
+            TokenKind::Number => {
+                let text = self.number()?;
+                self.advance();
+                let mut ret = Expr::new(text, PREC_PRIMARY);
+                if ret.text.chars().all(|c| c.is_ascii_digit() || c == '_') {
+                    ret.value = ret.text.replace('_', "").parse().ok();
+                }
+                Ok(ret)
+            }
+            TokenKind::StringLiteral => Ok(Expr::new(self.advance().text, PREC_PRIMARY)),
+            TokenKind::SystemIdentifier => {
+                if self.tok().text == "$" {
+                    return self.unsupported("`$` in expression");
+                }
+                let mut text = self.advance().text;
+                if self.is("(") {
+                    text.push_str(&format!("({})", self.arguments()?));
+                }
+                Ok(Expr::new(text, PREC_PRIMARY))
+            }
+            TokenKind::Macro => self.unsupported(&format!("macro `{}`", self.tok().text)),
+            TokenKind::Identifier => self.identifier_expression(),
+            _ => {
+                if self.eat("(") {
+                    let x = self.expression()?;
+                    self.expect(")")?;
+                    Ok(Expr {
+                        text: format!("({})", x.text),
+                        prec: PREC_PRIMARY,
+                        minus_one: x.minus_one,
+                        value: x.value,
+                    })
+                } else if self.is("{") {
+                    self.concatenation()
+                } else if self.is("'") {
+                    self.unsupported("assignment pattern")
+                } else {
+                    self.unexpected()
+                }
+            }
+        }
+    }
+
+    fn arguments(&mut self) -> Result<String> {
+        self.expect("(")?;
+        let mut args = Vec::new();
+        if !self.is(")") {
+            loop {
+                if self.is(".") {
+                    return self.unsupported("named argument");
+                }
+                args.push(self.expression()?.text);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+        }
+        self.expect(")")?;
+        Ok(args.join(", "))
+    }
+
+    /// Parses scoped identifier like `pkg::name`
+    fn scoped_identifier(&mut self) -> Result<Vec<String>> {
+        let mut path = vec![self.ident()?];
+        while self.eat("::") {
+            path.push(self.ident()?);
+        }
+        Ok(path)
+    }
+
+    /// Parses selects and member accesses following identifier
+    fn selects(&mut self, text: &mut String) -> Result<()> {
+        loop {
+            if self.eat("[") {
+                let x = self.expression()?;
+                text.push('[');
+                text.push_str(&x.text);
+                if self.is(":") || self.is("+:") || self.is("-:") {
+                    let op = self.advance().text;
+                    let y = self.expression()?;
+                    text.push_str(&op);
+                    text.push_str(&y.text);
+                }
+                self.expect("]")?;
+                text.push(']');
+            } else if self.eat(".") {
+                let x = self.ident()?;
+                text.push('.');
+                text.push_str(&x);
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_expression(&mut self) -> Result<Expr> {
+        let path = self.scoped_identifier()?;
+        let mut text = self.qualify(&path);
+        if self.is_function_result(&path) {
+            text = "ret".to_string();
+        }
+
+        if self.is("(") {
+            text.push_str(&format!("({})", self.arguments()?));
+            return Ok(Expr::new(text, PREC_PRIMARY));
+        }
+
+        if self.is("'") && self.peek(1).is("(") {
+            self.advance();
+            self.advance();
+            let x = self.expression()?;
+            self.expect(")")?;
+            let x = if x.prec >= PREC_UNARY {
+                x.text
+            } else {
+                format!("({})", x.text)
+            };
+            return Ok(Expr::new(format!("{x} as {text}"), PREC_AS));
+        }
+
+        self.selects(&mut text)?;
+        Ok(Expr::new(text, PREC_PRIMARY))
+    }
+
+    /// Checks reference to the function name as the return value
+    fn is_function_result(&mut self, path: &[String]) -> bool {
+        let ret = path.len() == 1 && self.function.as_ref() == Some(&path[0]) && !self.is("(");
+        self.function_result |= ret;
+        ret
+    }
+
+    /// Qualifies enum members by the enum type
+    fn qualify(&self, path: &[String]) -> String {
+        if path.len() == 1 {
+            if let Some(x) = self.members.get(&path[0]) {
+                return format!("{}::{}", x, path[0]);
+            }
+        } else if path.len() == 2 {
+            if let Some(enums) = self.packages.get(&path[0]) {
+                for (name, members) in enums {
+                    if members.contains(&path[1]) {
+                        return format!("{}::{}::{}", path[0], name, path[1]);
+                    }
+                }
+            }
+        }
+        path.join("::")
+    }
+
+    fn concatenation(&mut self) -> Result<Expr> {
+        let (text, _) = self.concatenation_inner()?;
+        Ok(Expr::new(format!("{{{text}}}"), PREC_PRIMARY))
+    }
+
+    /// Returns the inner text of concatenation and whether it is a replication
+    fn concatenation_inner(&mut self) -> Result<(String, bool)> {
+        self.expect("{")?;
+        if self.is("<<") || self.is(">>") {
+            return self.unsupported("streaming operator");
+        }
+        let first = self.concatenation_item()?;
+        let ret = if self.eat("{") {
+            let mut items = Vec::new();
+            loop {
+                items.push(self.concatenation_item()?);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect("}")?;
+            if items.len() == 1 {
+                (format!("{} repeat {first}", items[0]), true)
+            } else {
+                (format!("{{{}}} repeat {first}", items.join(", ")), true)
+            }
+        } else {
+            let mut items = vec![first];
+            while self.eat(",") {
+                items.push(self.concatenation_item()?);
+            }
+            (items.join(", "), false)
+        };
+        self.expect("}")?;
+        Ok(ret)
+    }
+
+    /// Nested replication like `{a, {4{b}}}` is flattened to `{a, b repeat 4}`
+    fn concatenation_item(&mut self) -> Result<String> {
+        if self.is("{") {
+            let pos = self.pos;
+            if let Ok((text, true)) = self.concatenation_inner() {
+                if self.is(",") || self.is("}") {
+                    return Ok(text);
+                }
+            }
+            self.pos = pos;
+        }
+        Ok(self.expression()?.text)
+    }
+
+    fn inside(&mut self, lhs: Expr) -> Result<Expr> {
+        self.expect("inside")?;
+        self.expect("{")?;
+        let mut items = Vec::new();
+        loop {
+            if self.eat("[") {
+                let x = self.expression()?;
+                self.expect(":")?;
+                let y = self.expression()?;
+                self.expect("]")?;
+                items.push(format!("{}..={}", x.text, y.text));
+            } else {
+                items.push(self.expression()?.text);
+            }
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect("}")?;
+        let text = format!("inside {} {{{}}}", lhs.text, items.join(", "));
+        Ok(Expr::new(text, PREC_PRIMARY))
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Type
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn is_type_start(&self) -> bool {
+        TYPE_KEYWORDS.contains(&self.tok().text.as_str()) || self.is("[") || self.is_user_type()
+    }
+
+    /// Checks identifier followed by identifier like `type_t name`
+    fn is_user_type(&self) -> bool {
+        self.is_ident(0) && (self.is_ident(1) || self.peek(1).is("::"))
+    }
+
+    fn data_type(&mut self) -> Result<Type> {
+        let mut ty = Type::logic();
+        let t = self.tok().text.clone();
+        let fixed = |x: &str| Type {
+            base: x.to_string(),
+            ..Default::default()
+        };
+        match t.as_str() {
+            "logic" | "reg" | "uwire" => {
+                self.advance();
+            }
+            "wire" | "var" => {
+                self.advance();
+                let _ = self.eat("logic") || self.eat("reg");
+            }
+            "bit" => {
+                self.advance();
+                ty.base = "bit".to_string();
+            }
+            "int" | "integer" | "longint" => {
+                self.advance();
+                let unsigned = self.eat("unsigned");
+                if !unsigned {
+                    self.eat("signed");
+                }
+                let base = match (t.as_str(), unsigned) {
+                    ("longint", true) => "u64",
+                    ("longint", false) => "i64",
+                    (_, true) => "u32",
+                    (_, false) => "i32",
+                };
+                return Ok(fixed(base));
+            }
+            "byte" | "shortint" => {
+                self.advance();
+                ty.base = "bit".to_string();
+                ty.signed = !self.eat("unsigned");
+                if ty.signed {
+                    self.eat("signed");
+                }
+                let width = if t == "byte" { "8" } else { "16" };
+                ty.packed.push(width.to_string());
+                return Ok(ty);
+            }
+            "real" | "realtime" => {
+                self.advance();
+                return Ok(fixed("f64"));
+            }
+            "shortreal" => {
+                self.advance();
+                return Ok(fixed("f32"));
+            }
+            "string" => {
+                self.advance();
+                return Ok(fixed("string"));
+            }
+            "signed" | "unsigned" | "[" => (),
+            "tri" => {
+                self.advance();
+                self.eat("logic");
+                ty.tri = true;
+            }
+            "time" => return self.unsupported("`time` type"),
+            _ if self.is_ident(0) => {
+                ty.base = self.scoped_identifier()?.join("::");
+            }
+            _ => return self.unexpected(),
+        }
+        if self.eat("signed") {
+            ty.signed = true;
+        } else {
+            self.eat("unsigned");
+        }
+        while self.is("[") {
+            let x = self.dimension(true)?;
+            ty.packed.push(x);
+        }
+        Ok(ty)
+    }
+
+    fn dimension(&mut self, packed: bool) -> Result<String> {
+        let beg = self.tok().beg;
+        self.expect("[")?;
+        let msb = self.expression()?;
+        let ret = if self.eat(":") {
+            let lsb = self.expression()?;
+            let end = self.tok().end;
+            self.width(msb, lsb, packed, beg, end)
+        } else if packed {
+            return self.unexpected();
+        } else {
+            msb.text
+        };
+        self.expect("]")?;
+        Ok(ret)
+    }
+
+    /// Converts range `[msb:lsb]` to width
+    fn width(&mut self, msb: Expr, lsb: Expr, packed: bool, beg: usize, end: usize) -> String {
+        let plus_one = |x: Expr| {
+            if let Some(x) = x.minus_one {
+                x
+            } else if let Some(x) = x.value {
+                (x + 1).to_string()
+            } else if x.prec < 9 {
+                format!("({}) + 1", x.text)
+            } else {
+                format!("{} + 1", x.text)
+            }
+        };
+        match (msb.value, lsb.value) {
+            (_, Some(0)) => plus_one(msb),
+            (Some(0), _) => {
+                if packed {
+                    self.inexact(
+                        "ascending range is translated to descending width",
+                        beg,
+                        end,
+                    );
+                }
+                plus_one(lsb)
+            }
+            (Some(x), Some(y)) => {
+                if packed {
+                    self.inexact(
+                        "range is translated to width, so the index starts from 0",
+                        beg,
+                        end,
+                    );
+                }
+                (x.abs_diff(y) + 1).to_string()
+            }
+            _ => {
+                if packed {
+                    self.inexact(
+                        "range is translated to width, so the index starts from 0",
+                        beg,
+                        end,
+                    );
+                }
+                format!("({}) - ({}) + 1", msb.text, lsb.text)
+            }
+        }
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Statement
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn statement(&mut self, block: Block, lines: &mut Vec<String>) -> Result<()> {
+        self.statement_comments(lines);
+        let t = self.tok().text.clone();
+        if self.tok().kind == TokenKind::SystemIdentifier {
+            return self.identifier_statement(block, lines);
+        }
+        if self.tok().kind != TokenKind::Identifier && self.tok().kind != TokenKind::Symbol {
+            return self.unexpected();
+        }
+        match t.as_str() {
+            ";" => {
+                self.advance();
+            }
+            "begin" => {
+                self.advance();
+                self.end_label()?;
+                loop {
+                    self.statement_comments(lines);
+                    if self.is("end") {
+                        break;
+                    }
+                    if self.is_eof() {
+                        return self.unexpected();
+                    }
+                    if self.is_type_start() && !self.is("[") {
+                        return self.unsupported("declaration in statement block");
+                    }
+                    self.statement(block, lines)?;
+                }
+                self.advance();
+                self.end_label()?;
+            }
+            "unique" | "unique0" | "priority" => {
+                let x = self.advance();
+                self.inexact(&format!("`{t}` is removed"), x.beg, x.end);
+                if !self.is("if") && !self.is("case") {
+                    return self.unexpected();
+                }
+                self.statement(block, lines)?;
+            }
+            "if" => {
+                let x = self.if_chain(block)?;
+                lines.push(print_if(&x, false));
+            }
+            "case" => {
+                let x = self.case_statement(block)?;
+                lines.push(x);
+            }
+            "for" => {
+                let x = self.for_statement(block)?;
+                lines.push(x);
+            }
+            "return" => {
+                self.advance();
+                if self.is(";") {
+                    return self.unsupported("`return` without value");
+                }
+                let x = self.expression()?;
+                self.expect(";")?;
+                lines.push(format!("return {};", x.text));
+            }
+            "{" => return self.unsupported("assignment to concatenation"),
+            "#" => return self.unsupported("delay control"),
+            "@" => return self.unsupported("event control"),
+            "->" => return self.unsupported("event trigger"),
+            _ if self.is_ident(0) || self.is("++") || self.is("--") => {
+                self.identifier_statement(block, lines)?;
+            }
+            _ if self.tok().kind == TokenKind::Identifier => {
+                return self.unsupported(&format!("`{t}` statement"));
+            }
+            _ => return self.unexpected(),
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, block: Block, lines: &mut Vec<String>) -> Result<()> {
+        if self.tok().kind == TokenKind::SystemIdentifier {
+            let name = self.advance().text;
+            let args = if self.is("(") {
+                self.arguments()?
+            } else {
+                String::new()
+            };
+            self.expect(";")?;
+            lines.push(format!("{name}({args});"));
+            return Ok(());
+        }
+
+        if self.is("++") || self.is("--") {
+            let op = self.advance().text;
+            let mut lhs = self.scoped_identifier()?.join("::");
+            self.selects(&mut lhs)?;
+            self.expect(";")?;
+            lines.push(format!("{lhs} {}= 1;", &op[..1]));
+            return Ok(());
+        }
+
+        let path = self.scoped_identifier()?;
+        let mut lhs = path.join("::");
+        if self.is_function_result(&path) {
+            lhs = "ret".to_string();
+        }
+        if self.is("(") || self.is(";") {
+            let args = if self.is("(") {
+                self.arguments()?
+            } else {
+                String::new()
+            };
+            self.expect(";")?;
+            lines.push(format!("{lhs}({args});"));
+            return Ok(());
+        }
+        self.selects(&mut lhs)?;
+
+        let op = self.tok().clone();
+        let text = match op.text.as_str() {
+            "=" | "<=" => {
+                if block == Block::AlwaysFf && op.text == "=" {
+                    self.inexact(
+                        "blocking assignment is translated to non-blocking assignment",
+                        op.beg,
+                        op.end,
+                    );
+                }
+                self.advance();
+                format!("{lhs} = {};", self.expression()?.text)
+            }
+            "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "|=" | "^=" | "<<=" | ">>=" | "<<<="
+            | ">>>=" => {
+                self.advance();
+                format!("{lhs} {} {};", op.text, self.expression()?.text)
+            }
+            "++" | "--" => {
+                self.advance();
+                format!("{lhs} {}= 1;", &op.text[..1])
+            }
+            _ => return self.unexpected(),
+        };
+        self.expect(";")?;
+        lines.push(text);
+        Ok(())
+    }
+
+    fn if_chain(&mut self, block: Block) -> Result<IfChain> {
+        let mut ret = IfChain {
+            branches: Vec::new(),
+            default: None,
+        };
+        loop {
+            self.expect("if")?;
+            self.expect("(")?;
+            let cond = self.expression()?;
+            self.expect(")")?;
+            let mut body = Vec::new();
+            self.statement(block, &mut body)?;
+            self.statement_comments(&mut body);
+            ret.branches.push((cond.text, body));
+
+            if self.eat("else") {
+                if self.is("if") {
+                    continue;
+                }
+                let mut body = Vec::new();
+                self.statement(block, &mut body)?;
+                ret.default = Some(body);
+            }
+            break;
+        }
+        Ok(ret)
+    }
+
+    fn case_statement(&mut self, block: Block) -> Result<String> {
+        self.expect("case")?;
+        self.expect("(")?;
+        let x = self.expression()?;
+        self.expect(")")?;
+        if self.is("inside") {
+            return self.unsupported("`case inside` statement");
+        }
+        let mut lines = vec![format!("case {} {{", x.text)];
+        loop {
+            self.statement_comments(&mut lines);
+            if self.eat("endcase") {
+                break;
+            }
+            if self.is_eof() {
+                return self.unexpected();
+            }
+            let mut labels = Vec::new();
+            if self.eat("default") {
+                self.eat(":");
+                labels.push("default".to_string());
+            } else {
+                loop {
+                    labels.push(self.expression()?.text);
+                    if !self.eat(",") {
+                        break;
+                    }
+                }
+                self.expect(":")?;
+            }
+            let mut body = Vec::new();
+            self.statement(block, &mut body)?;
+            let body = if body.len() == 1 && !body[0].contains('\n') {
+                body.remove(0)
+            } else if body.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{\n{}\n}}", body.join("\n"))
+            };
+            for label in labels {
+                lines.push(format!("{label}: {body}"));
+            }
+        }
+        lines.push("}".to_string());
+        Ok(lines.join("\n"))
+    }
+
+    fn loop_header(&mut self) -> Result<LoopHeader> {
+        self.expect("for")?;
+        self.expect("(")?;
+        let ty = match self.tok().text.as_str() {
+            "genvar" => {
+                self.advance();
+                None
+            }
+            "int" | "integer" | "longint" => Some(self.data_type()?.base),
+            _ if self.is_type_start() && !self.is_ident(0) => {
+                return self.unsupported("type of loop variable");
+            }
+            _ => None,
+        };
+        let var = self.ident()?;
+        self.expect("=")?;
+        let init = self.expression()?;
+        if self.is(",") {
+            return self.unsupported("multiple loop variables");
+        }
+        self.expect(";")?;
+
+        if self.ident()? != var {
+            return self.unsupported("loop condition");
+        }
+        let range = if self.eat("<") {
+            ".."
+        } else if self.eat("<=") {
+            "..="
+        } else {
+            return self.unsupported("loop condition");
+        };
+        let last = self.binary(8)?;
+        self.expect(";")?;
+        let range = format!("{}{}{}", init.text, range, last.text);
+
+        let step = if self.eat("++") {
+            if self.ident()? != var {
+                return self.unsupported("loop step");
+            }
+            String::new()
+        } else {
+            if self.ident()? != var {
+                return self.unsupported("loop step");
+            }
+            let op = self.advance().text;
+            match op.as_str() {
+                "++" => String::new(),
+                "+=" | "*=" | "<<=" => {
+                    let x = self.expression()?;
+                    if op == "+=" && x.text == "1" {
+                        String::new()
+                    } else {
+                        format!(" step {op} {}", x.text)
+                    }
+                }
+                "=" => {
+                    let x = self.expression()?;
+                    let mut step = None;
+                    for op in ["+", "*", "<<"] {
+                        if let Some(y) = x.text.strip_prefix(&format!("{var} {op} ")) {
+                            step = Some(if op == "+" && y == "1" {
+                                String::new()
+                            } else {
+                                format!(" step {op}= {y}")
+                            });
+                        }
+                    }
+                    match step {
+                        Some(x) => x,
+                        None => return self.unsupported("loop step"),
+                    }
+                }
+                _ => return self.unsupported("loop step"),
+            }
+        };
+        self.expect(")")?;
+
+        Ok(LoopHeader {
+            var,
+            ty,
+            range,
+            step,
+        })
+    }
+
+    fn for_statement(&mut self, block: Block) -> Result<String> {
+        let header = self.loop_header()?;
+        let mut body = Vec::new();
+        self.statement(block, &mut body)?;
+        let ty = header.ty.unwrap_or_else(|| "i32".to_string());
+        Ok(format!(
+            "for {}: {} in {}{} {{\n{}\n}}",
+            header.var,
+            ty,
+            header.range,
+            header.step,
+            body.join("\n")
+        ))
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Fallback
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn skip_parens(&mut self) {
+        if !self.is("(") {
+            return;
+        }
+        let mut depth = 0;
+        loop {
+            if self.is("(") {
+                depth += 1;
+            } else if self.is(")") {
+                depth -= 1;
+            }
+            self.advance();
+            if depth == 0 || self.is_eof() {
+                break;
+            }
+        }
+    }
+
+    fn skip_pair(&mut self, open: &[&str], close: &[&str]) {
+        let mut depth = 0;
+        loop {
+            let t = &self.tok().text;
+            if open.contains(&t.as_str()) {
+                depth += 1;
+            } else if close.contains(&t.as_str()) {
+                depth -= 1;
+            }
+            self.advance();
+            if depth <= 0 || self.is_eof() {
+                break;
+            }
+        }
+        let _ = self.end_label();
+    }
+
+    fn skip_to_semicolon(&mut self) {
+        let mut depth = 0usize;
+        while !self.is_eof() {
+            let t = self.advance().text;
+            match t.as_str() {
+                "(" | "[" | "{" | "begin" => depth += 1,
+                ")" | "]" | "}" | "end" => depth = depth.saturating_sub(1),
+                ";" if depth == 0 => break,
+                _ => (),
+            }
+        }
+    }
+
+    fn skip_statement(&mut self) {
+        while self.is("@") || self.is("#") {
+            self.advance();
+            if self.is("(") {
+                self.skip_parens();
+            } else {
+                self.advance();
+            }
+        }
+        match self.tok().text.as_str() {
+            "begin" => self.skip_pair(&["begin"], &["end"]),
+            "fork" => self.skip_pair(&["fork"], &["join", "join_any", "join_none"]),
+            "case" | "casez" | "casex" | "randcase" => {
+                self.skip_pair(&["case", "casez", "casex", "randcase"], &["endcase"])
+            }
+            "unique" | "unique0" | "priority" | "forever" => {
+                self.advance();
+                self.skip_statement();
+            }
+            "if" => {
+                self.advance();
+                self.skip_parens();
+                self.skip_statement();
+                if self.eat("else") {
+                    self.skip_statement();
+                }
+            }
+            "for" | "while" | "repeat" | "foreach" => {
+                self.advance();
+                self.skip_parens();
+                self.skip_statement();
+            }
+            "do" => {
+                self.advance();
+                self.skip_statement();
+                self.eat("while");
+                self.skip_parens();
+                self.eat(";");
+            }
+            _ => self.skip_to_semicolon(),
+        }
+    }
+
+    fn skip_item(&mut self) {
+        let end = match self.tok().text.as_str() {
+            "function" => "endfunction",
+            "task" => "endtask",
+            "generate" => "endgenerate",
+            "module" | "macromodule" => "endmodule",
+            "package" => "endpackage",
+            "interface" => "endinterface",
+            "program" => "endprogram",
+            "class" => "endclass",
+            "checker" => "endchecker",
+            "config" => "endconfig",
+            "primitive" => "endprimitive",
+            "specify" => "endspecify",
+            "clocking" => "endclocking",
+            "covergroup" => "endgroup",
+            "property" => "endproperty",
+            "sequence" => "endsequence",
+            "always" | "always_ff" | "always_comb" | "always_latch" | "initial" | "final" => {
+                self.advance();
+                self.skip_statement();
+                return;
+            }
+            "for" | "if" | "begin" | "case" => {
+                self.skip_statement();
+                return;
+            }
+            _ => {
+                self.skip_to_semicolon();
+                return;
+            }
+        };
+        while !self.is_eof() && !self.is(end) {
+            self.advance();
+        }
+        self.advance();
+        let _ = self.end_label();
+    }
+
+    /// Keeps the item from `start` to the current position as embedded SystemVerilog
+    fn embed(&mut self, items: &mut Vec<Item>, start: usize, err: Unsupported) {
+        let beg = self.tokens[start].beg;
+        let line_beg = self.text[..beg].rfind('\n').map(|x| x + 1).unwrap_or(0);
+        let beg = if self.text[line_beg..beg].trim().is_empty() {
+            line_beg
+        } else {
+            beg
+        };
+        let end = self.prev_end();
+        let code = &self.text[beg..end];
+
+        let mut depth = 0i32;
+        let mut balanced = true;
+        for c in code.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => (),
+            }
+            if depth < 0 {
+                balanced = false;
+            }
+        }
+
+        if balanced && depth == 0 {
+            items.push(Item::Text(format!(
+                "embed (inline) sv{{{{{{\n{code}\n}}}}}}"
+            )));
+            self.errors.push(TranslatorError::unsupported_construct(
+                &err.name, self.path, self.text, err.beg, err.end,
+            ));
+        } else {
+            self.errors.push(TranslatorError::dropped_construct(
+                &err.name, self.path, self.text, err.beg, err.end,
+            ));
+        }
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Conditional compilation
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn is_conditional(&self, names: &[&str]) -> bool {
+        let t = self.tok();
+        is_conditional_directive(t)
+            && names.contains(&&t.text.split_whitespace().next().unwrap_or_default()[1..])
+    }
+
+    /// Consumes a conditional directive and updates the current branches
+    fn conditional(&mut self) -> Result<()> {
+        let t = self.tok().clone();
+        let mut words = t.text.split_whitespace();
+        let directive = &words.next().unwrap_or_default()[1..];
+        let name = words.next().map(|x| x.to_string());
+        let last = self.conditions.last_mut();
+        match (directive, name, last) {
+            ("ifdef", Some(x), _) => self.conditions.push(Condition {
+                previous: Vec::new(),
+                current: Some((x, true)),
+            }),
+            ("ifndef", Some(x), _) => self.conditions.push(Condition {
+                previous: Vec::new(),
+                current: Some((x, false)),
+            }),
+            ("elsif", Some(x), Some(last)) if last.current.is_some() => {
+                let (name, defined) = last.current.take().unwrap();
+                last.previous.push((name, !defined));
+                last.current = Some((x, true));
+            }
+            ("else", _, Some(last)) if last.current.is_some() => {
+                let (name, defined) = last.current.take().unwrap();
+                last.previous.push((name, !defined));
+            }
+            ("endif", _, Some(_)) => {
+                self.conditions.pop();
+            }
+            _ => return self.unsupported(&format!("unbalanced `{directive}` directive")),
+        }
+        self.advance();
+        Ok(())
+    }
+
+    /// Returns attributes of the conditional branches opened after `base`
+    fn condition_attributes(&self, base: usize) -> Vec<String> {
+        self.conditions[base..]
+            .iter()
+            .flat_map(|x| x.attributes())
+            .collect()
+    }
+
+    /// Consumes conditional directives in comma separated list like ports,
+    /// and returns comments around the directives.
+    /// Each element of the list is annotated by the attributes of the current branches.
+    fn list_conditions(
+        &mut self,
+        base: usize,
+        comments: fn(&mut Self) -> Vec<String>,
+    ) -> Result<Vec<String>> {
+        let mut ret = Vec::new();
+        while is_conditional_directive(self.tok()) {
+            if self.is_conditional(&["elsif", "else", "endif"]) && self.conditions.len() == base {
+                return self.unsupported("unbalanced `ifdef` directive");
+            }
+            self.conditional()?;
+            ret.append(&mut comments(self));
+        }
+        Ok(ret)
+    }
+
+    /// Translates a conditional directive at the position of item.
+    /// Unbalanced directives are removed.
+    fn directive_item<F>(&mut self, items: &mut Vec<Item>, item: &mut F)
+    where
+        F: FnMut(&mut Self, &mut Vec<Item>),
+    {
+        let conditions = self.conditions.clone();
+        let ret = if self.is_conditional(&["ifdef", "ifndef"]) {
+            self.conditional_items(items, item)
+        } else {
+            self.unexpected()
+        };
+        if let Err(err) = ret {
+            // items before the error are already translated
+            self.conditions = conditions;
+            if !self.is_eof() {
+                self.advance();
+            }
+            self.errors.push(TranslatorError::dropped_construct(
+                &err.name, self.path, self.text, err.beg, err.end,
+            ));
+        }
+    }
+
+    /// Translates items surrounded by `` `ifdef `` into groups with `#[ifdef]` / `#[ifndef]`
+    fn conditional_items<F>(&mut self, items: &mut Vec<Item>, item: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Self, &mut Vec<Item>),
+    {
+        self.conditional()?;
+        let base = self.conditions.len() - 1;
+        loop {
+            let head = format!("{} {{", self.condition_attributes(base).join(" "));
+            let mut group = Vec::new();
+            loop {
+                self.item_comments(&mut group);
+                if self.is_conditional(&["elsif", "else", "endif"]) {
+                    break;
+                }
+                if self.is_eof() {
+                    return self.unexpected();
+                }
+                if self.is_conditional(&["ifdef", "ifndef"]) {
+                    self.conditional_items(&mut group, item)?;
+                } else {
+                    item(self, &mut group);
+                }
+            }
+            if !group.is_empty() {
+                items.push(Item::Block {
+                    head,
+                    items: group,
+                    tail: "}".to_string(),
+                });
+            }
+            let endif = self.is_conditional(&["endif"]);
+            self.conditional()?;
+            if endif {
+                return Ok(());
+            }
+        }
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Description
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn description(&mut self) -> String {
+        let mut items = Vec::new();
+        loop {
+            self.item_comments(&mut items);
+            if self.is_eof() {
+                break;
+            }
+            self.description_item(&mut items);
+        }
+
+        let mut ret = String::new();
+        self.print_items(&items, &mut ret);
+        ret
+    }
+
+    fn description_item(&mut self, items: &mut Vec<Item>) {
+        if self.tok().kind == TokenKind::Directive {
+            let mut item = |x: &mut Self, items: &mut Vec<Item>| x.description_item(items);
+            self.directive_item(items, &mut item);
+            return;
+        }
+
+        let start = self.pos;
+        let conditions = self.conditions.clone();
+        let ret = match self.tok().text.as_str() {
+            "module" | "macromodule" => self.module(),
+            "package" => self.package(),
+            "import" => {
+                let ret = self.import();
+                self.global_members = self.members.clone();
+                ret
+            }
+            ";" => {
+                self.advance();
+                return;
+            }
+            _ => self.unexpected(),
+        };
+        match ret {
+            Ok(x) => items.push(Item::Text(x)),
+            Err(err) => {
+                self.pos = start;
+                self.conditions = conditions;
+                self.skip_item();
+                if self.pos == start {
+                    self.advance();
+                }
+                self.errors.push(TranslatorError::dropped_construct(
+                    &err.name, self.path, self.text, err.beg, err.end,
+                ));
+            }
+        }
+    }
+
+    fn print_items(&self, items: &[Item], out: &mut String) {
+        for item in items {
+            match item {
+                Item::Text(x) => {
+                    out.push_str(x);
+                    out.push('\n');
+                }
+                Item::Var {
+                    name,
+                    ty,
+                    init,
+                    comment,
+                } => {
+                    out.push_str(&format!("var {}: {}", name, self.var_type(name, ty)));
+                    if let Some(x) = init {
+                        out.push_str(&format!(" = {x}"));
+                    }
+                    out.push(';');
+                    out.push_str(comment);
+                    out.push('\n');
+                }
+                Item::Block { head, items, tail } => {
+                    out.push_str(head);
+                    out.push('\n');
+                    self.print_items(items, out);
+                    if !tail.is_empty() {
+                        out.push_str(tail);
+                        out.push('\n');
+                    }
+                }
+            }
+        }
+    }
+
+    /// Replaces type of signals which are used as clock or reset
+    fn var_type(&self, name: &str, ty: &Type) -> String {
+        if ty.is_single_bit() && self.clocks.contains(name) {
+            "clock".to_string()
+        } else if ty.is_single_bit() && self.resets.contains(name) {
+            "reset".to_string()
+        } else {
+            ty.array()
+        }
+    }
+
+    fn import(&mut self) -> Result<String> {
+        self.expect("import")?;
+        let package = self.ident()?;
+        self.expect("::")?;
+        let item = if self.eat("*") {
+            "*".to_string()
+        } else {
+            self.ident()?
+        };
+        if self.is(",") {
+            return self.unsupported("multiple imports in a declaration");
+        }
+        self.expect(";")?;
+
+        if let Some(enums) = self.packages.get(&package) {
+            for (name, members) in enums {
+                for member in members {
+                    if item == "*" || &item == member {
+                        self.members
+                            .insert(member.clone(), format!("{package}::{name}"));
+                    }
+                }
+            }
+        }
+        Ok(format!("import {package}::{item};"))
+    }
+
+    fn package(&mut self) -> Result<String> {
+        self.expect("package")?;
+        let _ = self.eat("static") || self.eat("automatic");
+        let name = self.ident()?;
+        self.expect(";")?;
+        self.members = self.global_members.clone();
+        self.packages.insert(name.clone(), Vec::new());
+
+        let mut items = Vec::new();
+        loop {
+            self.item_comments(&mut items);
+            if self.is("endpackage") {
+                break;
+            }
+            if self.is_eof() {
+                return self.unexpected();
+            }
+            self.item_with_fallback(&mut items, Some(&name));
+        }
+        self.advance();
+        self.end_label()?;
+
+        let mut body = String::new();
+        self.print_items(&items, &mut body);
+        Ok(format!("package {name} {{\n{body}}}"))
+    }
+
+    fn module(&mut self) -> Result<String> {
+        self.advance();
+        let _ = self.eat("static") || self.eat("automatic");
+        let name = self.ident()?;
+        self.members = self.global_members.clone();
+        self.clocks.clear();
+        self.resets.clear();
+        self.ports.clear();
+        self.params.clear();
+        self.has_param_list = false;
+        self.generate_count = 0;
+
+        let mut items = Vec::new();
+        while self.is("import") {
+            let x = self.import()?;
+            items.push(Item::Text(x));
+        }
+        if self.is("#") {
+            self.has_param_list = true;
+            self.parameter_port_list()?;
+        }
+        if self.eat("(") {
+            if self.is_ident(0) && (self.peek(1).is(",") || self.peek(1).is(")")) {
+                self.non_ansi_port_list()?;
+            } else if !self.is(")") {
+                self.ansi_port_list()?;
+            }
+            self.expect(")")?;
+        }
+        self.expect(";")?;
+
+        loop {
+            self.item_comments(&mut items);
+            if self.is("endmodule") {
+                break;
+            }
+            if self.is_eof() {
+                return self.unexpected();
+            }
+            self.item_with_fallback(&mut items, None);
+        }
+        let end = self.advance();
+        self.end_label()?;
+
+        let mut ret = format!("module {name} ");
+        if !self.params.is_empty() {
+            ret.push_str(&format!("#(\n{}) ", print_list(&self.params)));
+        }
+        if !self.ports.is_empty() {
+            let mut ports = Vec::new();
+            for port in &self.ports {
+                let direction = match &port.direction {
+                    Some(x) => x,
+                    None => {
+                        return Err(Unsupported {
+                            name: format!("port `{}` without direction", port.name),
+                            beg: end.beg,
+                            end: end.end,
+                        })
+                    }
+                };
+                if ty_is_interface(direction) {
+                    ports.push(Line {
+                        leading: port.leading.clone(),
+                        text: format!("{}: {}", port.name, direction),
+                        comment: port.comment.clone(),
+                    });
+                    continue;
+                }
+                let ty = if direction == "inout" {
+                    let mut ty = port.ty.clone();
+                    ty.tri = true;
+                    ty.array()
+                } else if direction == "input" {
+                    self.var_type(&port.name, &port.ty)
+                } else {
+                    port.ty.array()
+                };
+                ports.push(Line {
+                    leading: port.leading.clone(),
+                    text: format!("{}: {} {}", port.name, direction, ty),
+                    comment: port.comment.clone(),
+                });
+            }
+            ret.push_str(&format!("(\n{}) ", print_list(&ports)));
+        }
+        let mut body = String::new();
+        self.print_items(&items, &mut body);
+        ret.push_str(&format!("{{\n{body}}}"));
+        Ok(ret)
+    }
+
+    fn parameter_port_list(&mut self) -> Result<()> {
+        self.expect("#")?;
+        self.expect("(")?;
+        let mut kind = "parameter";
+        let mut ty = None;
+        let base = self.conditions.len();
+        let mut leading = Vec::new();
+        loop {
+            leading.append(&mut self.param_comments());
+            leading.append(&mut self.list_conditions(base, Self::param_comments)?);
+            if self.is(")") {
+                break;
+            }
+            leading.append(&mut self.condition_attributes(base));
+            if self.eat("parameter") {
+                kind = "parameter";
+                ty = None;
+            } else if self.eat("localparam") {
+                kind = "localparam";
+                ty = None;
+            }
+            let (text, new_ty) = self.parameter(ty.clone())?;
+            ty = new_ty;
+            self.params.push(Line {
+                leading: std::mem::take(&mut leading),
+                text: format!("{kind} {text}"),
+                comment: String::new(),
+            });
+            // comma can follow the directives like `` `ifdef A , parameter B = 1 `endif ``
+            leading.append(&mut self.param_comments());
+            leading.append(&mut self.list_conditions(base, Self::param_comments)?);
+            if !self.eat(",") {
+                break;
+            }
+        }
+        leading.append(&mut self.param_comments());
+        if self.conditions.len() != base {
+            return self.unsupported("unbalanced `ifdef` directive");
+        }
+        self.params.push(Line {
+            leading,
+            ..Default::default()
+        });
+        self.expect(")")
+    }
+
+    /// Parses `[type] name = value` and returns `name: type = value`
+    fn parameter(&mut self, ty: Option<Type>) -> Result<(String, Option<Type>)> {
+        if self.eat("type") {
+            let name = self.ident()?;
+            if !self.eat("=") {
+                return self.unsupported("type parameter without default value");
+            }
+            let value = self.data_type()?;
+            return Ok((format!("{name}: type = {}", value.array()), None));
+        }
+
+        let ty = if self.is_type_start() {
+            Some(self.data_type()?)
+        } else {
+            ty
+        };
+        let name = self.ident()?;
+        if self.is("[") {
+            return self.unsupported("unpacked array parameter");
+        }
+        if !self.eat("=") {
+            return self.unsupported("parameter without default value");
+        }
+        let value = self.expression()?;
+        let ty_text = match &ty {
+            Some(x) => x.array(),
+            None => {
+                if value.text.starts_with('"') {
+                    "string".to_string()
+                } else if value.text.starts_with('-') {
+                    "i32".to_string()
+                } else if value.text.contains('.') && value.text.starts_with(char::is_numeric) {
+                    "f64".to_string()
+                } else {
+                    "u32".to_string()
+                }
+            }
+        };
+        Ok((format!("{name}: {ty_text} = {}", value.text), ty))
+    }
+
+    fn ansi_port_list(&mut self) -> Result<()> {
+        let mut direction: Option<String> = None;
+        let mut ty = Type::logic();
+        let base = self.conditions.len();
+        let mut leading = Vec::new();
+        loop {
+            leading.append(&mut self.port_comments());
+            leading.append(&mut self.list_conditions(base, Self::port_comments)?);
+            if self.is(")") {
+                break;
+            }
+            leading.append(&mut self.condition_attributes(base));
+
+            if self.eat("interface") {
+                direction = Some("interface".to_string());
+                ty = Type::default();
+            } else if self.is_ident(0) && self.peek(1).is(".") {
+                let interface = self.ident()?;
+                self.expect(".")?;
+                let modport = self.ident()?;
+                direction = Some(format!("modport {interface}::{modport}"));
+                ty = Type::default();
+            }
+            let new_direction = if self.is("input") || self.is("output") || self.is("inout") {
+                Some(self.advance().text)
+            } else if self.is("ref") {
+                return self.unsupported("`ref` port");
+            } else {
+                None
+            };
+            let new_ty = if self.is_type_start() {
+                Some(self.data_type()?)
+            } else {
+                None
+            };
+            let has_direction = new_direction.is_some();
+            let has_type = new_ty.is_some();
+            if has_direction || has_type {
+                direction = new_direction.or(direction);
+                ty = new_ty.unwrap_or_else(Type::logic);
+            }
+            match &direction {
+                None => return self.unsupported("port without direction"),
+                Some(x) if !has_direction && has_type && ty_is_interface(x) => {
+                    return self.unsupported("port without direction");
+                }
+                _ => (),
+            }
+
+            let name = self.ident()?;
+            let mut port_ty = ty.clone();
+            while self.is("[") {
+                let x = self.dimension(false)?;
+                port_ty.unpacked.push(x);
+            }
+            if self.is("=") {
+                return self.unsupported("default value of port");
+            }
+            self.ports.push(Port {
+                name,
+                direction: direction.clone(),
+                ty: port_ty,
+                leading: std::mem::take(&mut leading),
+                comment: String::new(),
+            });
+            // comma can follow the directives like `` `ifdef A , input logic b `endif ``
+            leading.append(&mut self.port_comments());
+            leading.append(&mut self.list_conditions(base, Self::port_comments)?);
+            if !self.eat(",") {
+                break;
+            }
+        }
+        leading.append(&mut self.port_comments());
+        if self.conditions.len() != base {
+            return self.unsupported("unbalanced `ifdef` directive");
+        }
+        if let Some(port) = self.ports.last_mut() {
+            for c in leading {
+                port.comment.push(' ');
+                port.comment.push_str(&c);
+            }
+        }
+        Ok(())
+    }
+
+    fn non_ansi_port_list(&mut self) -> Result<()> {
+        loop {
+            let name = self.ident()?;
+            self.ports.push(Port {
+                name,
+                direction: None,
+                ty: Type::logic(),
+                leading: Vec::new(),
+                comment: String::new(),
+            });
+            if !self.eat(",") {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------------------------------------------------
+    // Item
+    // -----------------------------------------------------------------------------------------------------------------
+
+    fn item_with_fallback(&mut self, items: &mut Vec<Item>, package: Option<&str>) {
+        if self.tok().kind == TokenKind::Directive {
+            let mut item =
+                |x: &mut Self, items: &mut Vec<Item>| x.item_with_fallback(items, package);
+            self.directive_item(items, &mut item);
+            return;
+        }
+
+        let start = self.pos;
+        let conditions = self.conditions.clone();
+        let errors = self.errors.len();
+        let mut new_items = Vec::new();
+        match self.item(&mut new_items, package) {
+            Ok(_) => items.append(&mut new_items),
+            Err(err) => {
+                // warnings in the embedded item are meaningless
+                self.errors.truncate(errors);
+                self.pos = start;
+                self.conditions = conditions;
+                self.skip_item();
+                if self.pos == start {
+                    self.advance();
+                }
+                self.embed(items, start, err);
+            }
+        }
+    }
+
+    fn item(&mut self, items: &mut Vec<Item>, package: Option<&str>) -> Result<()> {
+        let t = self.tok().text.clone();
+        if self.tok().kind == TokenKind::Macro {
+            return self.unsupported(&format!("macro `{t}`"));
+        }
+        if self.is("(") && self.peek(1).is("*") {
+            return self.unsupported("attribute");
+        }
+        match t.as_str() {
+            ";" => {
+                self.advance();
+            }
+            "parameter" | "localparam" => self.parameter_declaration(items, package.is_some())?,
+            "typedef" => self.typedef(items, package)?,
+            "function" => {
+                let x = self.function()?;
+                items.push(Item::Text(x));
+            }
+            "import" => {
+                let x = self.import()?;
+                items.push(Item::Text(x));
+            }
+            _ if package.is_some() && self.is_type_start() && !self.is("wire") => {
+                self.var_declaration(items)?;
+            }
+            _ if package.is_some() => return self.unsupported(&format!("`{t}`")),
+            "generate" => {
+                self.advance();
+                loop {
+                    self.item_comments(items);
+                    if self.is("endgenerate") {
+                        break;
+                    }
+                    if self.is_eof() {
+                        return self.unexpected();
+                    }
+                    self.item_with_fallback(items, None);
+                }
+                self.advance();
+            }
+            "genvar" => self.skip_to_semicolon(),
+            "input" | "output" | "inout" => self.port_declaration()?,
+            "assign" => self.assign_declaration(items)?,
+            "always" | "always_ff" | "always_comb" | "always_latch" => {
+                let x = self.always()?;
+                items.push(Item::Text(x));
+            }
+            "initial" | "final" => {
+                self.advance();
+                let mut body = Vec::new();
+                self.statement(Block::Initial, &mut body)?;
+                items.push(Item::Text(format!("{t} {{\n{}\n}}", body.join("\n"))));
+            }
+            "for" => {
+                let x = self.generate_for()?;
+                items.push(x);
+            }
+            "if" => {
+                let mut x = self.generate_if()?;
+                items.append(&mut x);
+            }
+            "begin" => {
+                let (label, block) = self.generate_block()?;
+                match label {
+                    Some(x) => items.push(Item::Block {
+                        head: format!(":{x} {{"),
+                        items: block,
+                        tail: "}".to_string(),
+                    }),
+                    None => items.push(Item::Block {
+                        head: "{".to_string(),
+                        items: block,
+                        tail: "}".to_string(),
+                    }),
+                }
+            }
+            _ if self.is_ident(0) && self.is_instance() => {
+                let x = self.instance()?;
+                items.push(Item::Text(x));
+            }
+            _ if self.is_type_start() && !self.is("[") => self.var_declaration(items)?,
+            _ => return self.unsupported(&format!("`{t}`")),
+        }
+        Ok(())
+    }
+
+    fn is_instance(&self) -> bool {
+        let mut i = 1;
+        while self.peek(i).is("::") {
+            i += 2;
+        }
+        if self.peek(i).is("#") {
+            return true;
+        }
+        if !self.is_ident(i) {
+            return false;
+        }
+        i += 1;
+        let mut depth = 0;
+        while self.peek(i).is("[") || depth > 0 {
+            let t = self.peek(i);
+            if t.kind == TokenKind::Eof {
+                return false;
+            }
+            if t.is("[") {
+                depth += 1;
+            } else if t.is("]") {
+                depth -= 1;
+            }
+            i += 1;
+        }
+        self.peek(i).is("(")
+    }
+
+    fn parameter_declaration(&mut self, items: &mut Vec<Item>, package: bool) -> Result<()> {
+        let kind = self.advance().text;
+        let header = kind == "parameter" && !package && !self.has_param_list;
+        let mut ty = None;
+        let mut lines = Vec::new();
+        loop {
+            let (text, new_ty) = self.parameter(ty)?;
+            ty = new_ty;
+            lines.push(text);
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(";")?;
+        for x in lines {
+            if header {
+                self.params.push(Line {
+                    text: format!("parameter {x}"),
+                    ..Default::default()
+                });
+            } else {
+                items.push(Item::Text(format!("localparam {x};")));
+            }
+        }
+        Ok(())
+    }
+
+    fn typedef(&mut self, items: &mut Vec<Item>, package: Option<&str>) -> Result<()> {
+        self.expect("typedef")?;
+        if self.eat("enum") {
+            let ty = if self.is("{") {
+                "logic<32>".to_string()
+            } else {
+                self.data_type()?.array()
+            };
+            self.expect("{")?;
+            let mut lines: Vec<Line> = Vec::new();
+            let mut members = Vec::new();
+            loop {
+                let leading = self.list_comments(&mut lines);
+                let member = self.ident()?;
+                if self.is("[") {
+                    return self.unsupported("enum member range");
+                }
+                let text = if self.eat("=") {
+                    format!("{member} = {}", self.expression()?.text)
+                } else {
+                    member.clone()
+                };
+                members.push(member);
+                lines.push(Line {
+                    leading,
+                    text,
+                    comment: String::new(),
+                });
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            let leading = self.list_comments(&mut lines);
+            lines.push(Line {
+                leading,
+                ..Default::default()
+            });
+            self.expect("}")?;
+            let name = self.ident()?;
+            self.expect(";")?;
+
+            for member in &members {
+                self.members.insert(member.clone(), name.clone());
+            }
+            if let Some(package) = package {
+                if let Some(x) = self.packages.get_mut(package) {
+                    x.push((name.clone(), members));
+                }
+            }
+            items.push(Item::Text(format!(
+                "enum {name}: {ty} {{\n{}}}",
+                print_list(&lines)
+            )));
+        } else if self.is("struct") || self.is("union") {
+            let kind = self.advance().text;
+            if !self.eat("packed") {
+                return self.unsupported(&format!("unpacked {kind}"));
+            }
+            if self.is("signed") {
+                return self.unsupported(&format!("signed {kind}"));
+            }
+            self.eat("unsigned");
+            self.expect("{")?;
+            let mut lines: Vec<Line> = Vec::new();
+            loop {
+                let leading = self.list_comments(&mut lines);
+                if self.eat("}") {
+                    lines.push(Line {
+                        leading,
+                        ..Default::default()
+                    });
+                    break;
+                }
+                let ty = self.data_type()?;
+                let mut first = true;
+                loop {
+                    let member = self.ident()?;
+                    if self.is("[") {
+                        return self.unsupported(&format!("unpacked array in {kind}"));
+                    }
+                    lines.push(Line {
+                        leading: if first { leading.clone() } else { Vec::new() },
+                        text: format!("{member}: {}", ty.array()),
+                        comment: String::new(),
+                    });
+                    first = false;
+                    if !self.eat(",") {
+                        break;
+                    }
+                }
+                self.expect(";")?;
+            }
+            let name = self.ident()?;
+            self.expect(";")?;
+            items.push(Item::Text(format!(
+                "{kind} {name} {{\n{}}}",
+                print_list(&lines)
+            )));
+        } else {
+            let ty = self.data_type()?;
+            let name = self.ident()?;
+            if self.is("[") {
+                return self.unsupported("typedef of unpacked array");
+            }
+            self.expect(";")?;
+            items.push(Item::Text(format!(
+                "localparam {name}: type = {};",
+                ty.array()
+            )));
+        }
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, items: &mut Vec<Item>) -> Result<()> {
+        let is_net = self.is("wire") || self.is("uwire");
+        let ty = self.data_type()?;
+        if self.is("#") {
+            return self.unsupported("delay");
+        }
+        let mut vars = Vec::new();
+        loop {
+            let name = self.ident()?;
+            let mut var_ty = ty.clone();
+            while self.is("[") {
+                let x = self.dimension(false)?;
+                var_ty.unpacked.push(x);
+            }
+            let init = if self.is("=") {
+                if !is_net {
+                    return self.unsupported("initial value of variable");
+                }
+                self.advance();
+                Some(self.expression()?.text)
+            } else {
+                None
+            };
+            vars.push((name, var_ty, init));
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(";")?;
+
+        for (name, ty, init) in vars {
+            // re-declaration of non-ANSI port
+            if let Some(port) = self.ports.iter_mut().find(|x| x.name == name) {
+                if init.is_some() {
+                    return self.unsupported("initial value of port");
+                }
+                if !ty.packed.is_empty() || ty.base != "logic" || ty.signed {
+                    port.ty = ty;
+                }
+                continue;
+            }
+            items.push(Item::Var {
+                name,
+                ty,
+                init,
+                comment: String::new(),
+            });
+        }
+        Ok(())
+    }
+
+    fn port_declaration(&mut self) -> Result<()> {
+        let direction = self.advance().text;
+        let ty = if self.is_type_start() {
+            self.data_type()?
+        } else {
+            Type::logic()
+        };
+        loop {
+            let beg = self.tok().beg;
+            let name = self.ident()?;
+            let mut port_ty = ty.clone();
+            while self.is("[") {
+                let x = self.dimension(false)?;
+                port_ty.unpacked.push(x);
+            }
+            match self.ports.iter_mut().find(|x| x.name == name) {
+                Some(port) if port.direction.is_none() => {
+                    port.direction = Some(direction.clone());
+                    port.ty = port_ty;
+                }
+                _ => {
+                    return Err(Unsupported {
+                        name: format!("declaration of unknown port `{name}`"),
+                        beg,
+                        end: self.prev_end(),
+                    })
+                }
+            }
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(";")
+    }
+
+    fn assign_declaration(&mut self, items: &mut Vec<Item>) -> Result<()> {
+        self.expect("assign")?;
+        if self.is("#") || self.is("(") {
+            return self.unsupported("delay or strength of assignment");
+        }
+        let mut lines = Vec::new();
+        loop {
+            if self.is("{") {
+                return self.unsupported("assignment to concatenation");
+            }
+            let mut lhs = self.ident()?;
+            self.selects(&mut lhs)?;
+            self.expect("=")?;
+            let rhs = self.expression()?;
+            lines.push(format!("assign {lhs} = {};", rhs.text));
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(";")?;
+        items.extend(lines.into_iter().map(Item::Text));
+        Ok(())
+    }
+
+    fn always(&mut self) -> Result<String> {
+        let kw = self.advance();
+        match kw.text.as_str() {
+            "always_comb" => return self.always_comb(),
+            "always_latch" => {
+                return Err(Unsupported {
+                    name: "`always_latch`".to_string(),
+                    beg: kw.beg,
+                    end: kw.end,
+                })
+            }
+            _ => (),
+        }
+
+        if !self.is("@") {
+            return self.unsupported("`always` without event control");
+        }
+        self.advance();
+        if self.eat("*") {
+            return self.always_comb();
+        }
+        if self.is("(") && self.peek(1).is("*") && self.peek(2).is(")") {
+            self.advance();
+            self.advance();
+            self.advance();
+            return self.always_comb();
+        }
+
+        let beg = self.tok().beg;
+        self.expect("(")?;
+        let mut events = Vec::new();
+        loop {
+            let edge = if self.eat("posedge") {
+                Some(Edge::Posedge)
+            } else if self.eat("negedge") {
+                Some(Edge::Negedge)
+            } else {
+                None
+            };
+            let mut signal = self.ident()?;
+            self.selects(&mut signal)?;
+            events.push((edge, signal));
+            if !self.eat("or") && !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(")")?;
+        let end = self.prev_end();
+
+        let unsupported = |name: &str| {
+            Err(Unsupported {
+                name: name.to_string(),
+                beg,
+                end,
+            })
+        };
+
+        if events.iter().all(|x| x.0.is_none()) {
+            self.inexact("sensitivity list is replaced by always_comb", beg, end);
+            return self.always_comb();
+        } else if events.iter().any(|x| x.0.is_none()) {
+            return unsupported("sensitivity list with and without edge");
+        }
+
+        match events.len() {
+            1 => {
+                let (edge, clock) = &events[0];
+                let mut body = Vec::new();
+                self.statement(Block::AlwaysFf, &mut body)?;
+                self.clocks.insert(clock.clone());
+                Ok(format!(
+                    "always_ff ({}{clock}) {{\n{}\n}}",
+                    self.clock_edge(edge.unwrap()),
+                    body.join("\n")
+                ))
+            }
+            2 => {
+                let mut body = Vec::new();
+                let has_begin = self.eat("begin");
+                if has_begin {
+                    self.end_label()?;
+                    self.statement_comments(&mut body);
+                }
+                if !self.is("if") {
+                    return unsupported("asynchronous reset without if statement");
+                }
+                let chain = self.if_chain(Block::AlwaysFf)?;
+                if has_begin {
+                    self.statement_comments(&mut body);
+                    self.expect("end")?;
+                    self.end_label()?;
+                }
+
+                let cond = &chain.branches[0].0;
+                let mut reset = None;
+                for (i, (edge, signal)) in events.iter().enumerate() {
+                    if let Some(low) = reset_polarity(cond, signal) {
+                        let reset_type = match (edge, low) {
+                            (Some(Edge::Negedge), true) => ResetType::AsyncLow,
+                            (Some(Edge::Posedge), false) => ResetType::AsyncHigh,
+                            _ => return unsupported("mismatch between reset edge and condition"),
+                        };
+                        reset = Some((i, reset_type));
+                    }
+                }
+                let (reset_index, reset_type) = match reset {
+                    Some(x) => x,
+                    None => return unsupported("asynchronous reset"),
+                };
+                let (clock_edge, clock) = &events[1 - reset_index];
+                let reset = &events[reset_index].1;
+                self.clocks.insert(clock.clone());
+                self.resets.insert(reset.clone());
+
+                let reset_type = if reset_type == self.reset_type {
+                    ""
+                } else if reset_type == ResetType::AsyncLow {
+                    "async_low "
+                } else {
+                    "async_high "
+                };
+                body.push(print_if(&chain, true));
+                Ok(format!(
+                    "always_ff ({}{clock}, {reset_type}{reset}) {{\n{}\n}}",
+                    self.clock_edge(clock_edge.unwrap()),
+                    body.join("\n")
+                ))
+            }
+            _ => unsupported("multiple asynchronous signals"),
+        }
+    }
+
+    fn clock_edge(&self, edge: Edge) -> &'static str {
+        match (edge, self.clock_type) {
+            (Edge::Posedge, ClockType::PosEdge) | (Edge::Negedge, ClockType::NegEdge) => "",
+            (Edge::Posedge, _) => "posedge ",
+            (Edge::Negedge, _) => "negedge ",
+        }
+    }
+
+    fn always_comb(&mut self) -> Result<String> {
+        let mut body = Vec::new();
+        self.statement(Block::AlwaysComb, &mut body)?;
+        Ok(format!("always_comb {{\n{}\n}}", body.join("\n")))
+    }
+
+    fn function(&mut self) -> Result<String> {
+        self.expect("function")?;
+        self.function_result = false;
+        let _ = self.eat("automatic") || self.eat("static");
+        if self.is("void") {
+            return self.unsupported("void function");
+        }
+        let ret = if self.is_ident(0) && (self.peek(1).is("(") || self.peek(1).is(";")) {
+            Type::logic()
+        } else {
+            self.data_type()?
+        };
+        let name = self.ident()?;
+
+        let mut ports: Vec<Line> = Vec::new();
+        if self.eat("(") {
+            let mut direction = "input".to_string();
+            let mut ty = Type::logic();
+            while !self.is(")") {
+                if self.is("input") || self.is("output") || self.is("inout") {
+                    direction = self.advance().text;
+                    ty = Type::logic();
+                } else if self.is("ref") {
+                    return self.unsupported("`ref` argument");
+                }
+                if self.is_type_start() {
+                    ty = self.data_type()?;
+                }
+                let port = self.ident()?;
+                if self.is("[") || self.is("=") {
+                    return self.unsupported("unpacked array or default value of argument");
+                }
+                ports.push(Line {
+                    text: format!("{port}: {direction} {}", ty.array()),
+                    ..Default::default()
+                });
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect(")")?;
+        }
+        self.expect(";")?;
+
+        let mut body = Vec::new();
+        // declarations
+        loop {
+            self.statement_comments(&mut body);
+            if self.is("input") || self.is("output") || self.is("inout") {
+                let direction = self.advance().text;
+                let ty = if self.is_type_start() {
+                    self.data_type()?
+                } else {
+                    Type::logic()
+                };
+                loop {
+                    let port = self.ident()?;
+                    ports.push(Line {
+                        text: format!("{port}: {direction} {}", ty.array()),
+                        ..Default::default()
+                    });
+                    if !self.eat(",") {
+                        break;
+                    }
+                }
+                self.expect(";")?;
+            } else if self.is("automatic") || (self.is_type_start() && !self.is("[")) {
+                self.eat("automatic");
+                let ty = self.data_type()?;
+                loop {
+                    let var = self.ident()?;
+                    if self.is("[") {
+                        return self.unsupported("unpacked array in function");
+                    }
+                    let init = if self.eat("=") {
+                        format!(" = {}", self.expression()?.text)
+                    } else {
+                        String::new()
+                    };
+                    body.push(format!("var {var}: {}{init};", ty.array()));
+                    if !self.eat(",") {
+                        break;
+                    }
+                }
+                self.expect(";")?;
+            } else {
+                break;
+            }
+        }
+
+        // statements
+        loop {
+            self.statement_comments(&mut body);
+            if self.is("endfunction") {
+                break;
+            }
+            if self.is_eof() {
+                return self.unexpected();
+            }
+            // assignment to the function name at the end is translated to return statement
+            if self.tok().text == name && self.peek(1).is("=") {
+                let pos = self.pos;
+                self.advance();
+                self.advance();
+                let x = self.expression()?;
+                self.expect(";")?;
+                if self.is("endfunction") {
+                    body.push(format!("return {};", x.text));
+                    continue;
+                }
+                self.pos = pos;
+            }
+            self.function = Some(name.clone());
+            let ret = self.statement(Block::Function, &mut body);
+            self.function = None;
+            ret?;
+        }
+        self.advance();
+        self.end_label()?;
+
+        // other assignments to the function name are translated to local variable
+        if self.function_result {
+            self.function_result = false;
+            let declared = body.iter().any(|x| x.starts_with("var ret:"))
+                || ports.iter().any(|x| x.text.starts_with("ret:"));
+            if declared {
+                return self.unsupported("assignment to function name");
+            }
+            body.insert(0, format!("var ret: {};", ret.array()));
+            body.push("return ret;".to_string());
+        }
+
+        let ports = if ports.is_empty() {
+            String::new()
+        } else {
+            format!("(\n{}) ", print_list(&ports))
+        };
+        Ok(format!(
+            "function {name} {ports}-> {} {{\n{}\n}}",
+            ret.array(),
+            body.join("\n")
+        ))
+    }
+
+    fn instance(&mut self) -> Result<String> {
+        let module = self.scoped_identifier()?.join("::");
+        let mut params: Vec<Line> = Vec::new();
+        if self.eat("#") {
+            self.expect("(")?;
+            while !self.is(")") {
+                let leading = self.list_comments(&mut params);
+                if !self.eat(".") {
+                    return self.unsupported("ordered parameter assignment");
+                }
+                let name = self.ident()?;
+                self.expect("(")?;
+                if !self.is(")") {
+                    let value = self.expression()?.text;
+                    params.push(Line {
+                        leading,
+                        text: connection(name, value),
+                        comment: String::new(),
+                    });
+                }
+                self.expect(")")?;
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            let leading = self.list_comments(&mut params);
+            params.push(Line {
+                leading,
+                ..Default::default()
+            });
+            self.expect(")")?;
+        }
+
+        let name = self.ident()?;
+        let mut array = Vec::new();
+        while self.is("[") {
+            array.push(self.dimension(false)?);
+        }
+        self.expect("(")?;
+        let mut ports: Vec<Line> = Vec::new();
+        while !self.is(")") {
+            let leading = self.list_comments(&mut ports);
+            if !self.eat(".") {
+                return self.unsupported("ordered port connection");
+            }
+            if self.is("*") {
+                return self.unsupported("wildcard port connection");
+            }
+            let beg = self.tok().beg;
+            let port = self.ident()?;
+            let text = if self.eat("(") {
+                if self.eat(")") {
+                    let end = self.prev_end();
+                    self.inexact(&format!("unconnected port `{port}` is removed"), beg, end);
+                    None
+                } else {
+                    let value = self.expression()?.text;
+                    self.expect(")")?;
+                    Some(connection(port, value))
+                }
+            } else {
+                Some(port)
+            };
+            if let Some(text) = text {
+                ports.push(Line {
+                    leading,
+                    text,
+                    comment: String::new(),
+                });
+            }
+            if !self.eat(",") {
+                break;
+            }
+        }
+        let leading = self.list_comments(&mut ports);
+        ports.push(Line {
+            leading,
+            ..Default::default()
+        });
+        self.expect(")")?;
+        if self.is(",") {
+            return self.unsupported("multiple instances in a declaration");
+        }
+        self.expect(";")?;
+
+        let mut ret = format!("inst {name}: {module}");
+        if !array.is_empty() {
+            ret.push_str(&format!(" [{}]", array.join(", ")));
+        }
+        if params.iter().any(|x| !x.text.is_empty()) {
+            ret.push_str(&format!(" #(\n{})", print_list(&params)));
+        }
+        if ports
+            .iter()
+            .any(|x| !x.text.is_empty() || !x.leading.is_empty())
+        {
+            ret.push_str(&format!(" (\n{})", print_list(&ports)));
+        }
+        ret.push(';');
+        Ok(ret)
+    }
+
+    fn generate_label(&mut self) -> String {
+        self.generate_count += 1;
+        format!("genblk{}", self.generate_count)
+    }
+
+    fn generate_block(&mut self) -> Result<(Option<String>, Vec<Item>)> {
+        let mut items = Vec::new();
+        if self.eat("begin") {
+            let label = if self.eat(":") {
+                Some(self.ident()?)
+            } else {
+                None
+            };
+            loop {
+                self.item_comments(&mut items);
+                if self.is("end") {
+                    break;
+                }
+                if self.is_eof() {
+                    return self.unexpected();
+                }
+                self.item_with_fallback(&mut items, None);
+            }
+            self.advance();
+            self.end_label()?;
+            Ok((label, items))
+        } else {
+            self.item_with_fallback(&mut items, None);
+            Ok((None, items))
+        }
+    }
+
+    fn generate_for(&mut self) -> Result<Item> {
+        let header = self.loop_header()?;
+        let (label, items) = self.generate_block()?;
+        let label = label.unwrap_or_else(|| self.generate_label());
+        Ok(Item::Block {
+            head: format!(
+                "for {} in {}{} :{label} {{",
+                header.var, header.range, header.step
+            ),
+            items,
+            tail: "}".to_string(),
+        })
+    }
+
+    fn generate_if(&mut self) -> Result<Vec<Item>> {
+        let mut ret = Vec::new();
+        let mut head = String::new();
+        // labels of the following clauses are omitted if they are the same as the first one
+        let mut first = String::new();
+        loop {
+            self.expect("if")?;
+            self.expect("(")?;
+            let cond = self.expression()?;
+            self.expect(")")?;
+            let (label, items) = self.generate_block()?;
+            let label = match label {
+                Some(x) if ret.is_empty() => {
+                    first.clone_from(&x);
+                    format!(" :{x}")
+                }
+                Some(x) if x != first => format!(" :{x}"),
+                None if ret.is_empty() => {
+                    first = self.generate_label();
+                    format!(" :{first}")
+                }
+                _ => String::new(),
+            };
+            ret.push(Item::Block {
+                head: format!("{head}if {}{label} {{", cond.text),
+                items,
+                tail: String::new(),
+            });
+            if self.eat("else") {
+                if self.is("if") {
+                    head = "} else ".to_string();
+                    continue;
+                }
+                let (label, items) = self.generate_block()?;
+                let label = match label {
+                    Some(x) if x != first => format!(" :{x}"),
+                    _ => String::new(),
+                };
+                ret.push(Item::Block {
+                    head: format!("}} else{label} {{"),
+                    items,
+                    tail: String::new(),
+                });
+            }
+            break;
+        }
+        if let Some(Item::Block { tail, .. }) = ret.last_mut() {
+            *tail = "}".to_string();
+        }
+        Ok(ret)
+    }
+}
+
+fn binary_precedence(op: &str) -> Option<u8> {
+    match op {
+        "->" | "<->" => Some(1),
+        "||" => Some(1),
+        "&&" => Some(2),
+        "|" => Some(3),
+        "^" | "~^" | "^~" => Some(4),
+        "&" => Some(5),
+        "==" | "!=" | "===" | "!==" | "==?" | "!=?" => Some(6),
+        "<" | "<=" | ">" | ">=" => Some(7),
+        "<<" | ">>" | "<<<" | ">>>" => Some(8),
+        "+" | "-" => Some(9),
+        "*" | "/" | "%" => Some(10),
+        "**" => Some(11),
+        _ => None,
+    }
+}
+
+fn normalize_digits(x: &str) -> String {
+    x.split('_')
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Returns `Some(true)` if `cond` checks active-low `signal`
+fn reset_polarity(cond: &str, signal: &str) -> Option<bool> {
+    let mut cond = cond;
+    while cond.starts_with('(') && cond.ends_with(')') {
+        cond = &cond[1..cond.len() - 1];
+    }
+    let low = [
+        format!("!{signal}"),
+        format!("~{signal}"),
+        format!("!({signal})"),
+        format!("~({signal})"),
+        format!("{signal} == 0"),
+        format!("{signal} == 1'b0"),
+        format!("{signal} == '0"),
+        format!("{signal} != 1"),
+        format!("{signal} != 1'b1"),
+    ];
+    let high = [
+        signal.to_string(),
+        format!("{signal} == 1"),
+        format!("{signal} == 1'b1"),
+        format!("{signal} == '1"),
+        format!("{signal} != 0"),
+        format!("{signal} != 1'b0"),
+    ];
+    if low.iter().any(|x| x == cond) {
+        Some(true)
+    } else if high.iter().any(|x| x == cond) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn ty_is_interface(direction: &str) -> bool {
+    direction == "interface" || direction.starts_with("modport ")
+}
+
+fn connection(name: String, value: String) -> String {
+    if name == value {
+        name
+    } else {
+        format!("{name}: {value}")
+    }
+}
+
+fn print_list(lines: &[Line]) -> String {
+    let mut ret = String::new();
+    for line in lines {
+        for x in &line.leading {
+            ret.push_str(x);
+            ret.push('\n');
+        }
+        if !line.text.is_empty() {
+            ret.push_str(&line.text);
+            ret.push(',');
+            ret.push_str(&line.comment);
+            ret.push('\n');
+        }
+    }
+    ret
+}
+
+fn print_if(chain: &IfChain, reset: bool) -> String {
+    let mut ret = String::new();
+    for (i, (cond, body)) in chain.branches.iter().enumerate() {
+        if i == 0 {
+            if reset {
+                ret.push_str("if_reset {\n");
+            } else {
+                ret.push_str(&format!("if {cond} {{\n"));
+            }
+        } else {
+            ret.push_str(&format!("}} else if {cond} {{\n"));
+        }
+        for x in body {
+            ret.push_str(x);
+            ret.push('\n');
+        }
+    }
+    if let Some(body) = &chain.default {
+        ret.push_str("} else {\n");
+        for x in body {
+            ret.push_str(x);
+            ret.push('\n');
+        }
+    }
+    ret.push('}');
+    ret
+}
+
+fn is_conditional_directive(t: &Token) -> bool {
+    let name = t.text.split_whitespace().next().unwrap_or_default();
+    t.kind == TokenKind::Directive
+        && matches!(name, "`ifdef" | "`ifndef" | "`elsif" | "`else" | "`endif")
+}
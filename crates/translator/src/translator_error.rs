@@ -0,0 +1,160 @@
+use miette::{self, Diagnostic, NamedSource, SourceSpan};
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum TranslatorError {
+    #[diagnostic(
+        severity(Warning),
+        code(dropped_construct),
+        help("translate it to Veryl manually")
+    )]
+    #[error("{name} can't be translated and is removed")]
+    DroppedConstruct {
+        name: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(inexact_translation),
+        help("check whether the translated code has the intended behavior")
+    )]
+    #[error("{cause}")]
+    InexactTranslation {
+        cause: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_output),
+        help("the translated code is written without formatting")
+    )]
+    #[error("translated code can't be parsed as Veryl: {cause}")]
+    InvalidOutput { cause: String },
+
+    #[diagnostic(
+        severity(Warning),
+        code(renamed_identifier),
+        help("fix the references from SystemVerilog code")
+    )]
+    #[error("\"{identifier}\" is a keyword of Veryl, so it is renamed to \"{renamed}\"")]
+    RenamedIdentifier {
+        identifier: String,
+        renamed: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(syntax_error),
+        help("fix the syntax error of SystemVerilog code")
+    )]
+    #[error("{cause}")]
+    SyntaxError {
+        cause: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unsupported_construct),
+        help("the construct is kept as SystemVerilog by embed declaration")
+    )]
+    #[error("{name} can't be translated")]
+    UnsupportedConstruct {
+        name: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+}
+
+fn source(path: &str, text: &str) -> NamedSource {
+    NamedSource::new(path, text.to_string())
+}
+
+fn span(beg: usize, end: usize) -> SourceSpan {
+    SourceSpan::new(beg.into(), (end - beg).into())
+}
+
+impl TranslatorError {
+    pub fn dropped_construct(name: &str, path: &str, text: &str, beg: usize, end: usize) -> Self {
+        TranslatorError::DroppedConstruct {
+            name: name.to_string(),
+            input: source(path, text),
+            error_location: span(beg, end),
+        }
+    }
+
+    pub fn inexact_translation(
+        cause: &str,
+        path: &str,
+        text: &str,
+        beg: usize,
+        end: usize,
+    ) -> Self {
+        TranslatorError::InexactTranslation {
+            cause: cause.to_string(),
+            input: source(path, text),
+            error_location: span(beg, end),
+        }
+    }
+
+    pub fn invalid_output(cause: &str) -> Self {
+        TranslatorError::InvalidOutput {
+            cause: cause.to_string(),
+        }
+    }
+
+    pub fn renamed_identifier(
+        identifier: &str,
+        renamed: &str,
+        path: &str,
+        text: &str,
+        beg: usize,
+        end: usize,
+    ) -> Self {
+        TranslatorError::RenamedIdentifier {
+            identifier: identifier.to_string(),
+            renamed: renamed.to_string(),
+            input: source(path, text),
+            error_location: span(beg, end),
+        }
+    }
+
+    pub fn syntax_error(cause: &str, path: &str, text: &str, beg: usize, end: usize) -> Self {
+        TranslatorError::SyntaxError {
+            cause: cause.to_string(),
+            input: source(path, text),
+            error_location: span(beg, end),
+        }
+    }
+
+    pub fn unsupported_construct(
+        name: &str,
+        path: &str,
+        text: &str,
+        beg: usize,
+        end: usize,
+    ) -> Self {
+        TranslatorError::UnsupportedConstruct {
+            name: name.to_string(),
+            input: source(path, text),
+            error_location: span(beg, end),
+        }
+    }
+}
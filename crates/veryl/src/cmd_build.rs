@@ -5,10 +5,11 @@ use miette::{IntoDiagnostic, Result, WrapErr};
 use std::fs;
 use std::fs::OpenOptions;
 use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 use veryl_analyzer::Analyzer;
 use veryl_emitter::Emitter;
-use veryl_metadata::{FilelistType, Metadata, PathPair};
+use veryl_metadata::{FilelistType, Metadata, PathPair, TargetLanguage};
 use veryl_parser::Parser;
 
 pub struct CmdBuild {
@@ -23,6 +24,7 @@ impl CmdBuild {
     pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
         let now = Instant::now();
 
+        metadata.enable_features(&self.opt.features)?;
         let paths = metadata.paths(&self.opt.files)?;
 
         let mut check_error = CheckError::default();
@@ -73,6 +75,11 @@ impl CmdBuild {
             file.flush().into_diagnostic()?;
 
             debug!("Output file ({})", path.dst.to_string_lossy());
+
+            // VHDL emitter doesn't track source tokens
+            if metadata.build.sourcemap && metadata.build.target_language != TargetLanguage::Vhdl {
+                self.gen_sourcemap(&emitter, &path.dst)?;
+            }
         }
 
         self.gen_filelist(metadata, &paths)?;
@@ -84,6 +91,24 @@ impl CmdBuild {
         Ok(true)
     }
 
+    fn gen_sourcemap(&self, emitter: &Emitter, dst: &Path) -> Result<()> {
+        let map_path = PathBuf::from(format!("{}.map", dst.to_string_lossy()));
+        let source_map = emitter.source_map(dst);
+        let text = serde_json::to_string(&source_map).into_diagnostic()?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&map_path)
+            .into_diagnostic()?;
+        file.write_all(text.as_bytes()).into_diagnostic()?;
+        file.flush().into_diagnostic()?;
+
+        debug!("Output source map ({})", map_path.to_string_lossy());
+        Ok(())
+    }
+
     fn gen_filelist(&self, metadata: &Metadata, paths: &[PathPair]) -> Result<()> {
         let filelist_name = match metadata.build.filelist_type {
             FilelistType::Absolute => format!("{}.f", metadata.project.name),
@@ -54,6 +54,7 @@ impl CmdCheck {
     pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
         let now = Instant::now();
 
+        metadata.enable_features(&self.opt.features)?;
         let paths = metadata.paths(&self.opt.files)?;
 
         let mut check_error = CheckError::default();
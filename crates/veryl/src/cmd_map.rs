@@ -0,0 +1,80 @@
+use crate::OptMap;
+use log::warn;
+use miette::{IntoDiagnostic, Result};
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+use veryl_emitter::source_map::{SourceLocation, SourceMap};
+
+pub struct CmdMap {
+    opt: OptMap,
+}
+
+/// References like `file.sv:12`, `file.sv:12:5` and `file.sv(12)`
+const REFERENCE: &str = r#"(?P<path>[^\s:"'(),\[\]]+\.(?:sv|svh|v|vh))(?::(?P<line>\d+)(?::(?P<column>\d+))?|\((?P<paren_line>\d+)\))"#;
+
+impl CmdMap {
+    pub fn new(opt: OptMap) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self) -> Result<bool> {
+        let re = Regex::new(REFERENCE).into_diagnostic()?;
+        let mut maps = HashMap::new();
+
+        if self.opt.references.is_empty() {
+            for line in io::stdin().lock().lines() {
+                let line = line.into_diagnostic()?;
+                let line = re.replace_all(&line, |x: &Captures| match Self::lookup(x, &mut maps) {
+                    Some(x) => Self::format(&x),
+                    None => x[0].to_string(),
+                });
+                println!("{line}");
+            }
+            return Ok(true);
+        }
+
+        let mut ret = true;
+        for reference in &self.opt.references {
+            let location = match re.captures(reference) {
+                Some(x) if x[0].len() == reference.len() => Self::lookup(&x, &mut maps),
+                _ => None,
+            };
+            match location {
+                Some(x) => println!("{}", Self::format(&x)),
+                None => {
+                    warn!("Source location of {reference} is not found");
+                    ret = false;
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    fn lookup(
+        x: &Captures,
+        maps: &mut HashMap<PathBuf, Option<SourceMap>>,
+    ) -> Option<SourceLocation> {
+        let path = PathBuf::from(&x["path"]);
+        let line = x
+            .name("line")
+            .or(x.name("paren_line"))?
+            .as_str()
+            .parse()
+            .ok()?;
+        let column = x.name("column").and_then(|x| x.as_str().parse().ok());
+
+        let map = maps.entry(path.clone()).or_insert_with(|| {
+            let map_path = PathBuf::from(format!("{}.map", path.to_string_lossy()));
+            let text = fs::read_to_string(map_path).ok()?;
+            serde_json::from_str(&text).ok()
+        });
+        map.as_ref()?.lookup(line, column)
+    }
+
+    fn format(x: &SourceLocation) -> String {
+        format!("{}:{}:{}", x.path.to_string_lossy(), x.line, x.column)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::OptTranslate;
+use log::{debug, info};
+use miette::{self, Diagnostic, IntoDiagnostic, Report, Result, Severity, WrapErr};
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::Instant;
+use thiserror::Error;
+use veryl_metadata::Metadata;
+use veryl_translator::{Translator, TranslatorError};
+
+pub struct CmdTranslate {
+    opt: OptTranslate,
+}
+
+#[derive(Error, Diagnostic, Debug, Default)]
+#[error("veryl translate failed")]
+pub struct TranslateError {
+    #[related]
+    pub related: Vec<TranslatorError>,
+}
+
+impl TranslateError {
+    fn has_error(&self) -> bool {
+        self.related
+            .iter()
+            .any(|x| matches!(x.severity(), Some(Severity::Error) | None))
+    }
+}
+
+impl CmdTranslate {
+    pub fn new(opt: OptTranslate) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
+        let now = Instant::now();
+
+        let mut translator = Translator::new(metadata);
+        let mut contexts = Vec::new();
+
+        for src in &self.opt.files {
+            let dst = src.with_extension("vl");
+            if dst.exists() && !self.opt.force {
+                return Err(miette::miette!(
+                    "{} already exists (use --force to overwrite)",
+                    dst.to_string_lossy()
+                ));
+            }
+
+            let input = fs::read_to_string(src)
+                .into_diagnostic()
+                .wrap_err(format!("failed to read {}", src.to_string_lossy()))?;
+            translator.scan(&input, src);
+            contexts.push((src, dst, input));
+        }
+
+        let mut translate_error = TranslateError::default();
+
+        for (src, dst, input) in &contexts {
+            info!("Processing file ({})", src.to_string_lossy());
+
+            let mut ret = translator.translate(input, src);
+            translate_error.related.append(&mut ret.errors);
+
+            let mut file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(dst)
+                .into_diagnostic()?;
+            file.write_all(ret.text.as_bytes()).into_diagnostic()?;
+            file.flush().into_diagnostic()?;
+
+            debug!("Output file ({})", dst.to_string_lossy());
+        }
+
+        let elapsed_time = now.elapsed();
+        debug!("Elapsed time ({} milliseconds)", elapsed_time.as_millis());
+
+        if translate_error.has_error() {
+            Err(translate_error.into())
+        } else {
+            for x in translate_error.related {
+                eprintln!("{:?}", Report::new(x));
+            }
+            Ok(true)
+        }
+    }
+}
@@ -395,7 +395,7 @@ impl DocBuilder {
     }
 
     fn build_package(&self, name: &str, symbol: &Symbol) -> String {
-        if let SymbolKind::Package = &symbol.kind {
+        if let SymbolKind::Package(_) = &symbol.kind {
             let data = PackageData {
                 name: name.to_string(),
                 description: format_doc_comment(&symbol.doc_comment, false),
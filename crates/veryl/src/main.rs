@@ -14,9 +14,11 @@ mod cmd_doc;
 mod cmd_dump;
 mod cmd_fmt;
 mod cmd_init;
+mod cmd_map;
 mod cmd_metadata;
 mod cmd_new;
 mod cmd_publish;
+mod cmd_translate;
 mod cmd_update;
 mod doc_builder;
 
@@ -52,6 +54,8 @@ enum Commands {
     Doc(OptDoc),
     Metadata(OptMetadata),
     Dump(OptDump),
+    Translate(OptTranslate),
+    Map(OptMap),
 }
 
 /// Create a new project
@@ -83,6 +87,10 @@ pub struct OptFmt {
 pub struct OptCheck {
     /// Target files
     pub files: Vec<PathBuf>,
+
+    /// Comma separated list of features to enable
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
 }
 
 /// Build the target codes corresponding to the current project
@@ -90,6 +98,10 @@ pub struct OptCheck {
 pub struct OptBuild {
     /// Target files
     pub files: Vec<PathBuf>,
+
+    /// Comma separated list of features to enable
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
 }
 
 /// Update dependencies
@@ -166,6 +178,25 @@ pub struct OptDump {
     pub namespace_table: bool,
 }
 
+/// Translate SystemVerilog files to Veryl
+#[derive(Args)]
+pub struct OptTranslate {
+    /// Target files
+    #[arg(required = true)]
+    pub files: Vec<PathBuf>,
+
+    /// Overwrite existing Veryl files
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Translate locations in the generated codes to Veryl locations through source maps
+#[derive(Args)]
+pub struct OptMap {
+    /// Locations like `file.sv:LINE` or `file.sv:LINE:COLUMN` (tool log from stdin if omitted)
+    pub references: Vec<String>,
+}
+
 // ---------------------------------------------------------------------------------------------------------------------
 // Main
 // ---------------------------------------------------------------------------------------------------------------------
@@ -232,6 +263,8 @@ fn main() -> Result<ExitCode> {
         Commands::Doc(x) => cmd_doc::CmdDoc::new(x).exec(&mut metadata)?,
         Commands::Metadata(x) => cmd_metadata::CmdMetadata::new(x).exec(&metadata)?,
         Commands::Dump(x) => cmd_dump::CmdDump::new(x).exec(&mut metadata)?,
+        Commands::Translate(x) => cmd_translate::CmdTranslate::new(x).exec(&mut metadata)?,
+        Commands::Map(x) => cmd_map::CmdMap::new(x).exec()?,
     };
     if ret {
         Ok(ExitCode::SUCCESS)
@@ -1,8 +1,8 @@
 use miette::{ErrReport, GraphicalReportHandler, GraphicalTheme, ThemeCharacters, ThemeStyles};
 use semver::Version;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use veryl_analyzer::{namespace_table, symbol_table, Analyzer};
+use veryl_analyzer::{dependency_table, namespace_table, symbol_table, Analyzer};
 use veryl_emitter::Emitter;
 use veryl_formatter::Formatter;
 use veryl_metadata::{Build, Format, Lint, Lockfile, Metadata, Project, Pubfile, Publish};
@@ -60,6 +60,8 @@ fn metadata() -> Metadata {
         lint: Lint::default(),
         publish: Publish::default(),
         dependencies: HashMap::new(),
+        features: HashMap::new(),
+        enabled_features: HashSet::new(),
         metadata_path: "".into(),
         pubfile_path: "".into(),
         pubfile: Pubfile::default(),
@@ -76,6 +78,7 @@ pub fn build(source: &str) -> Result {
             if let Some(path) = resource_table::get_path_id(PathBuf::from("")) {
                 symbol_table::drop(path);
                 namespace_table::drop(path);
+                dependency_table::drop(path);
             }
 
             let analyzer = Analyzer::new::<&str>(&"project", &metadata);
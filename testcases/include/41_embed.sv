@@ -0,0 +1 @@
+cover property (@(posedge i_a) o_b);
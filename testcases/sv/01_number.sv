@@ -19,6 +19,9 @@ module veryl_testcase_Module01;
     localparam int unsigned e  = 128'h0123456789abcdefxzABCDEFXZ;
     localparam int unsigned ee = 128'h01234_5678_9abc_defxz_ABCD_EFXZ;
 
+    // unsized over 128bit
+    localparam bit [160-1:0] h = 160'hffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff;
+
     // all0, all1, allx, allz
     localparam int unsigned f      = '0;
     localparam int unsigned ff     = '1;
@@ -6,11 +6,10 @@ module veryl_testcase_Module04 #(
     localparam type         aaa = logic [10-1:0]
 ) (
     // module port
-    input  logic     [10-1:0] b    ,
-    output logic     [10-1:0] bb   ,
-    inout  tri logic [10-1:0] bbb  ,
-    interface bbbb ,
-    Interface05.d bbbbb
+    input  logic     [10-1:0] b   ,
+    output logic     [10-1:0] bb  ,
+    inout  tri logic [10-1:0] bbb ,
+    interface bbbb
 );
     // localparam declaration
     localparam int unsigned     c  = 1;
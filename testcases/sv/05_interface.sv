@@ -21,3 +21,9 @@ interface veryl_testcase_Interface05 #(
         inout  ccc
     );
 endinterface
+
+// modport port
+module veryl_testcase_Module05 (
+    veryl_testcase_Interface05.d a
+);
+endmodule
@@ -7,9 +7,8 @@ module veryl_testcase_Module14;
 
     // module instantiation with parameter and port
     veryl_testcase_Module14C #(
-        .a  (a  ),
-        .aa (10 ),
-        .aa (100)
+        .aa  (10 ),
+        .aaa (100)
     ) xx (
         .a    (a  ),
         .bb   (aa ),
@@ -20,7 +19,7 @@ module veryl_testcase_Module14;
     veryl_testcase_InterfaceA y ();
 
     // interface instantiation with parameter
-    veryl_testcase_InterfaceA #(.a (a), .b (10)) yy  ();
+    veryl_testcase_InterfaceA #(.a (a), .b (10)) yy ();
     veryl_testcase_InterfaceA #(.a (a), .b (10)) xxx ();
 
     // interface array
@@ -30,7 +29,10 @@ endmodule
 module veryl_testcase_Module14B;
 endmodule
 
-module veryl_testcase_Module14C (
+module veryl_testcase_Module14C #(
+    parameter int unsigned aa  = 1,
+    parameter int unsigned aaa = 1
+) (
     input int unsigned a   ,
     input int unsigned bb  ,
     input int unsigned bbbb
@@ -0,0 +1,57 @@
+module veryl_testcase_Module37;
+    // generic module instantiation
+    veryl_testcase_Module37B__10 u0 ();
+    veryl_testcase_Module37B__20 u1 ();
+    veryl_testcase_Module37C__Package37A_StructA u2 ();
+
+    logic [veryl_testcase_Package37B__4::Width-1:0] a;
+    logic [2-1:0]                                   b;
+
+    // generic package reference
+    assign a = veryl_testcase_Package37B__4::Width;
+
+    // generic function call
+    assign b = FuncA__2(1);
+
+    function automatic logic [2-1:0] FuncA__2(
+        input logic [2-1:0] a
+    ) ;
+        return a + 1;
+    endfunction
+endmodule
+
+/// Generic module with value parameter
+module veryl_testcase_Module37B__10;
+    veryl_testcase_Module37D__10 u ();
+    logic [10-1:0] _a;
+endmodule
+
+module veryl_testcase_Module37B__20;
+    veryl_testcase_Module37D__20 u ();
+    logic [20-1:0] _a;
+endmodule
+
+/// Generic module with type parameter
+module veryl_testcase_Module37C__Package37A_StructA;
+    veryl_testcase_Package37A::StructA _a;
+endmodule
+
+/// Generic module instantiated by generic module
+module veryl_testcase_Module37D__10;
+    logic [10-1:0] _a;
+endmodule
+
+module veryl_testcase_Module37D__20;
+    logic [20-1:0] _a;
+endmodule
+
+package veryl_testcase_Package37A;
+    typedef struct packed {
+        logic a;
+    } StructA;
+endpackage
+
+/// Generic package
+package veryl_testcase_Package37B__4;
+    localparam int unsigned Width = 4;
+endpackage
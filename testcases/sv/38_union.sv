@@ -0,0 +1,14 @@
+module veryl_testcase_Module38;
+    // union declaration
+    typedef union packed {
+        logic        [8-1:0][4-1:0] a  ;
+        logic        [32-1:0]       aa ;
+        int unsigned                aaa;
+    } A;
+
+    A             a;
+    logic [8-1:0] b;
+
+    assign a.aaa = 1;
+    assign b     = a.a[0];
+endmodule
@@ -0,0 +1,43 @@
+module veryl_testcase_Module39 (
+    input  logic         i_clk_a,
+    input  logic         i_rst_a,
+    input  logic [8-1:0] i_dat_a,
+    input  logic         i_clk_b,
+    input  logic         i_rst_b,
+    output logic [8-1:0] o_dat_b
+);
+    logic [8-1:0] r_dat_a;
+    logic [8-1:0] w_dat_a;
+    logic [8-1:0] r_sync0;
+    logic [8-1:0] r_sync1;
+
+    always_ff @ (posedge i_clk_a, negedge i_rst_a) begin
+        if (!i_rst_a) begin
+            r_dat_a <= 0;
+        end else begin
+            r_dat_a <= i_dat_a;
+        end
+    end
+
+    // domain 'a is propagated through combinational logic
+    assign w_dat_a = r_dat_a + 1;
+
+    // two-stage synchronizer
+    always_ff @ (posedge i_clk_b, negedge i_rst_b) begin
+        if (!i_rst_b) begin
+            r_sync0 <= 0;
+        end else begin
+            r_sync0 <= w_dat_a;
+        end
+    end
+
+    always_ff @ (posedge i_clk_b, negedge i_rst_b) begin
+        if (!i_rst_b) begin
+            r_sync1 <= 0;
+        end else begin
+            r_sync1 <= r_sync0;
+        end
+    end
+
+    assign o_dat_b = r_sync1;
+endmodule
@@ -0,0 +1,8 @@
+module veryl_testcase_Module40 (
+    input  logic i_a,
+    output logic o_b
+);
+    logic a;
+    assign a   = i_a;
+    assign o_b = a;
+endmodule
@@ -0,0 +1,21 @@
+module veryl_testcase_Module41 (
+    input  logic i_a,
+    output logic o_b
+);
+    assign o_b = i_a;
+
+    property p_a_b;
+        @(posedge i_a) o_b;
+    endproperty
+    assert property (p_a_b);
+
+cover property (@(posedge i_a) o_b);
+endmodule
+
+interface veryl_testcase_Interface41;
+    logic a;
+endinterface
+
+package veryl_testcase_Package41;
+    import "DPI-C" function int get_value(input int x);
+endpackage
@@ -0,0 +1,13 @@
+module veryl_testcase_Module42 (
+    input  logic         i_clk ,
+    input  logic [4-1:0] i_addr,
+    output logic [8-1:0] o_d   
+);
+    vendor_ram #(
+        .WIDTH (8)
+    ) u_ram (
+        .clk  (i_clk ),
+        .addr (i_addr),
+        .dout (o_d   )
+    );
+endmodule
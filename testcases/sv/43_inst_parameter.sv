@@ -0,0 +1,23 @@
+module veryl_testcase_Module43;
+    // value parameter override
+    veryl_testcase_Module43A #(.Width (8)) u0 ();
+
+    // type parameter override
+    veryl_testcase_Module43A #(.Width (16), .Type (veryl_testcase_Package43::StructA)) u1 ();
+endmodule
+
+module veryl_testcase_Module43A #(
+    parameter  int unsigned Width = 1        ,
+    parameter  type         Type  = logic    ,
+    localparam int unsigned Depth = Width * 2
+);
+    logic [Width-1:0] _a;
+    Type              _b;
+    logic [Depth-1:0] _c;
+endmodule
+
+package veryl_testcase_Package43;
+    typedef struct packed {
+        logic a;
+    } StructA;
+endpackage